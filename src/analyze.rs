@@ -6,8 +6,11 @@
 //! - Recommend extraction targets
 
 use crate::ast::*;
+use crate::completeness::FailOn;
+use crate::config::ValidationConfig;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Analyze code AST
 pub fn analyze(code: &CodeAst) -> AnalysisReport {
@@ -28,6 +31,16 @@ pub struct AnalyzerConfig {
     pub max_lines: usize,
 }
 
+impl AnalyzerConfig {
+    /// Defaults with the thresholds a project sets in `.imacs_root`
+    pub fn from_validation(validation: &ValidationConfig) -> Self {
+        Self {
+            max_nesting: validation.max_nesting_depth,
+            ..Self::default()
+        }
+    }
+}
+
 impl Default for AnalyzerConfig {
     fn default() -> Self {
         Self {
@@ -61,10 +74,63 @@ pub struct FunctionAnalysis {
 pub struct FunctionMetrics {
     pub lines: usize,
     pub cyclomatic_complexity: usize,
-    pub max_nesting: usize,
+    /// Deepest chain of if/match nested inside one another
+    #[serde(alias = "max_nesting")]
+    pub max_nesting_depth: usize,
     pub parameters: usize,
     pub decision_points: usize,
     pub return_points: usize,
+    #[serde(default)]
+    pub halstead: HalsteadMetrics,
+    /// 0-100, higher is easier to maintain (normalized SEI formula over
+    /// Halstead volume, cyclomatic complexity and lines)
+    #[serde(default)]
+    pub maintainability_index: f64,
+}
+
+/// Halstead size metrics for a function
+///
+/// Operators are binary/unary operations and calls; operands are literals
+/// and variable references.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct HalsteadMetrics {
+    pub distinct_operators: usize,
+    pub distinct_operands: usize,
+    pub total_operators: usize,
+    pub total_operands: usize,
+    /// Program volume: `N * log2(n)` for length `N` and vocabulary `n`
+    pub volume: f64,
+}
+
+impl HalsteadMetrics {
+    fn from_counts(operators: &[String], operands: &[String]) -> Self {
+        let distinct = |items: &[String]| items.iter().collect::<HashSet<_>>().len();
+        let distinct_operators = distinct(operators);
+        let distinct_operands = distinct(operands);
+        let vocabulary = distinct_operators + distinct_operands;
+        let length = operators.len() + operands.len();
+        let volume = if vocabulary == 0 {
+            0.0
+        } else {
+            length as f64 * (vocabulary as f64).log2()
+        };
+        Self {
+            distinct_operators,
+            distinct_operands,
+            total_operators: operators.len(),
+            total_operands: operands.len(),
+            volume,
+        }
+    }
+}
+
+/// Maintainability index, normalized to 0-100
+fn maintainability_index(volume: f64, complexity: usize, lines: usize) -> f64 {
+    let raw = 171.0
+        - 5.2 * volume.max(1.0).ln()
+        - 0.23 * complexity as f64
+        - 16.2 * (lines.max(1) as f64).ln();
+    (raw * 100.0 / 171.0).clamp(0.0, 100.0)
 }
 
 /// Overall file metrics
@@ -78,11 +144,19 @@ pub struct OverallMetrics {
 }
 
 /// An issue found in code
+///
+/// The JSON shape is stable for tooling: `code` is a fixed identifier per
+/// kind (see [`IssueKind::code`]), `severity` is one of `Info`/`Warning`/`Error`,
+/// and `span` anchors the issue in the source. `line` mirrors `span.start_line`.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Issue {
+    /// Stable issue code, e.g. `IMACS-A001`
+    pub code: String,
     pub kind: IssueKind,
     pub severity: Severity,
     pub line: usize,
+    /// Source location the issue refers to
+    pub span: Span,
     pub message: String,
     pub suggestion: Option<String>,
 }
@@ -97,6 +171,41 @@ pub enum IssueKind {
     MissingDefault,
 }
 
+impl IssueKind {
+    /// Stable code for this kind of issue
+    pub fn code(&self) -> &'static str {
+        match self {
+            IssueKind::HighComplexity => "IMACS-A001",
+            IssueKind::DeepNesting => "IMACS-A002",
+            IssueKind::LongFunction => "IMACS-A003",
+            IssueKind::MagicNumber => "IMACS-A004",
+            IssueKind::TooManyParams => "IMACS-A005",
+            IssueKind::MissingDefault => "IMACS-A006",
+        }
+    }
+}
+
+impl Issue {
+    /// Create an issue anchored at `span`
+    pub fn new(kind: IssueKind, severity: Severity, span: Span, message: String) -> Self {
+        Self {
+            code: kind.code().to_string(),
+            kind,
+            severity,
+            line: span.start_line,
+            span,
+            message,
+            suggestion: None,
+        }
+    }
+
+    /// Attach a suggestion
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum Severity {
     Info,
@@ -211,61 +320,75 @@ impl Analyzer {
         let mut issues = Vec::new();
 
         let lines = func.span.end_line.saturating_sub(func.span.start_line) + 1;
-        let (complexity, max_nesting, decisions) = self.calculate_complexity(&func.body);
+        let (complexity, decisions) = self.calculate_complexity(&func.body);
+        let (max_nesting_depth, deepest) = self.nesting_depth(&func.body, 0);
         let return_points = self.count_returns(&func.body);
+        let (mut operators, mut operands) = (Vec::new(), Vec::new());
+        self.halstead_tokens(&func.body, &mut operators, &mut operands);
+        let halstead = HalsteadMetrics::from_counts(&operators, &operands);
 
         if complexity > self.config.complexity_error {
-            issues.push(Issue {
-                kind: IssueKind::HighComplexity,
-                severity: Severity::Error,
-                line: func.span.start_line,
-                message: format!(
-                    "Cyclomatic complexity {} exceeds {}",
-                    complexity, self.config.complexity_error
-                ),
-                suggestion: Some("Extract decision logic into spec".into()),
-            });
+            issues.push(
+                Issue::new(
+                    IssueKind::HighComplexity,
+                    Severity::Error,
+                    func.span,
+                    format!(
+                        "Cyclomatic complexity {} exceeds {}",
+                        complexity, self.config.complexity_error
+                    ),
+                )
+                .with_suggestion("Extract decision logic into spec"),
+            );
         } else if complexity > self.config.complexity_warn {
-            issues.push(Issue {
-                kind: IssueKind::HighComplexity,
-                severity: Severity::Warning,
-                line: func.span.start_line,
-                message: format!("Cyclomatic complexity {} is high", complexity),
-                suggestion: Some("Consider extracting complex logic".into()),
-            });
+            issues.push(
+                Issue::new(
+                    IssueKind::HighComplexity,
+                    Severity::Warning,
+                    func.span,
+                    format!("Cyclomatic complexity {} is high", complexity),
+                )
+                .with_suggestion("Consider extracting complex logic"),
+            );
         }
 
-        if max_nesting > self.config.max_nesting {
-            issues.push(Issue {
-                kind: IssueKind::DeepNesting,
-                severity: Severity::Warning,
-                line: func.span.start_line,
-                message: format!(
-                    "Nesting depth {} exceeds {}",
-                    max_nesting, self.config.max_nesting
-                ),
-                suggestion: Some("Flatten with early returns".into()),
-            });
+        if max_nesting_depth > self.config.max_nesting {
+            issues.push(
+                Issue::new(
+                    IssueKind::DeepNesting,
+                    Severity::Warning,
+                    deepest.unwrap_or(func.span),
+                    format!(
+                        "Nesting depth {} exceeds {}",
+                        max_nesting_depth, self.config.max_nesting
+                    ),
+                )
+                .with_suggestion("Flatten with early returns"),
+            );
         }
 
         if lines > self.config.max_lines {
-            issues.push(Issue {
-                kind: IssueKind::LongFunction,
-                severity: Severity::Warning,
-                line: func.span.start_line,
-                message: format!("Function has {} lines", lines),
-                suggestion: Some("Split into smaller functions".into()),
-            });
+            issues.push(
+                Issue::new(
+                    IssueKind::LongFunction,
+                    Severity::Warning,
+                    func.span,
+                    format!("Function has {} lines", lines),
+                )
+                .with_suggestion("Split into smaller functions"),
+            );
         }
 
         if func.params.len() > 5 {
-            issues.push(Issue {
-                kind: IssueKind::TooManyParams,
-                severity: Severity::Warning,
-                line: func.span.start_line,
-                message: format!("Function has {} parameters", func.params.len()),
-                suggestion: Some("Group into struct".into()),
-            });
+            issues.push(
+                Issue::new(
+                    IssueKind::TooManyParams,
+                    Severity::Warning,
+                    func.span,
+                    format!("Function has {} parameters", func.params.len()),
+                )
+                .with_suggestion("Group into struct"),
+            );
         }
 
         self.find_magic_numbers(&func.body, &mut issues);
@@ -275,20 +398,19 @@ impl Analyzer {
             metrics: FunctionMetrics {
                 lines,
                 cyclomatic_complexity: complexity,
-                max_nesting,
+                max_nesting_depth,
                 parameters: func.params.len(),
                 decision_points: decisions,
                 return_points,
+                maintainability_index: maintainability_index(halstead.volume, complexity, lines),
+                halstead,
             },
             issues,
         }
     }
 
-    fn calculate_complexity(&self, node: &AstNode) -> (usize, usize, usize) {
-        self.calc_recursive(node, 0)
-    }
-
-    fn calc_recursive(&self, node: &AstNode, depth: usize) -> (usize, usize, usize) {
+    /// Cyclomatic complexity and decision points
+    fn calculate_complexity(&self, node: &AstNode) -> (usize, usize) {
         match node {
             AstNode::If {
                 condition,
@@ -296,33 +418,27 @@ impl Analyzer {
                 else_branch,
                 ..
             } => {
-                let (c1, n1, d1) = self.calc_recursive(condition, depth);
-                let (c2, n2, d2) = self.calc_recursive(then_branch, depth + 1);
-                let (c3, n3, d3) = else_branch
+                let (c1, d1) = self.calculate_complexity(condition);
+                let (c2, d2) = self.calculate_complexity(then_branch);
+                let (c3, d3) = else_branch
                     .as_ref()
-                    .map(|e| self.calc_recursive(e, depth + 1))
-                    .unwrap_or((0, 0, 0));
+                    .map(|e| self.calculate_complexity(e))
+                    .unwrap_or((0, 0));
 
-                (
-                    1 + c1 + c2 + c3,
-                    (depth + 1).max(n1).max(n2).max(n3),
-                    1 + d1 + d2 + d3,
-                )
+                (1 + c1 + c2 + c3, 1 + d1 + d2 + d3)
             }
 
             AstNode::Match { arms, .. } => {
                 let mut total_c = arms.len().saturating_sub(1);
-                let mut max_n = depth + 1;
                 let mut total_d = 1;
 
                 for arm in arms {
-                    let (c, n, d) = self.calc_recursive(&arm.body, depth + 1);
+                    let (c, d) = self.calculate_complexity(&arm.body);
                     total_c += c;
-                    max_n = max_n.max(n);
                     total_d += d;
                 }
 
-                (total_c, max_n, total_d)
+                (total_c, total_d)
             }
 
             AstNode::Binary {
@@ -331,31 +447,221 @@ impl Analyzer {
                 right,
                 ..
             } => {
-                let (c1, n1, d1) = self.calc_recursive(left, depth);
-                let (c2, n2, d2) = self.calc_recursive(right, depth);
-                (1 + c1 + c2, n1.max(n2), d1 + d2)
+                let (c1, d1) = self.calculate_complexity(left);
+                let (c2, d2) = self.calculate_complexity(right);
+                (1 + c1 + c2, d1 + d2)
             }
 
             AstNode::Block {
                 statements, result, ..
             } => {
-                let mut total = (0, depth, 0);
-                for stmt in statements {
-                    let (c, n, d) = self.calc_recursive(stmt, depth);
+                let mut total = (0, 0);
+                for node in statements.iter().chain(result.as_deref()) {
+                    let (c, d) = self.calculate_complexity(node);
                     total.0 += c;
-                    total.1 = total.1.max(n);
-                    total.2 += d;
-                }
-                if let Some(r) = result {
-                    let (c, n, d) = self.calc_recursive(r, depth);
-                    total.0 += c;
-                    total.1 = total.1.max(n);
-                    total.2 += d;
+                    total.1 += d;
                 }
                 total
             }
 
-            _ => (0, depth, 0),
+            _ => (0, 0),
+        }
+    }
+
+    /// Deepest if/match nesting below `node` and the span of the innermost
+    /// if/match at that depth
+    ///
+    /// Walks every kind of node, so conditionals inside `let`s, loops,
+    /// closures and calls count toward the depth of their enclosing branch.
+    fn nesting_depth(&self, node: &AstNode, depth: usize) -> (usize, Option<Span>) {
+        let (inner, children): (usize, Vec<&AstNode>) = match node {
+            AstNode::If {
+                condition,
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                // `else if` continues the chain rather than nesting deeper
+                let (else_depth, else_span) = match else_branch.as_deref() {
+                    Some(e @ AstNode::If { .. }) => self.nesting_depth(e, depth),
+                    Some(e) => self.nesting_depth(e, depth + 1),
+                    None => (0, None),
+                };
+                let mut best = (depth + 1, Some(node.span()));
+                for (d, span) in [
+                    self.nesting_depth(condition, depth),
+                    self.nesting_depth(then_branch, depth + 1),
+                    (else_depth, else_span),
+                ] {
+                    if d > best.0 {
+                        best = (d, span);
+                    }
+                }
+                return best;
+            }
+            AstNode::Match {
+                scrutinee, arms, ..
+            } => {
+                let mut best = (depth + 1, Some(node.span()));
+                let (d, span) = self.nesting_depth(scrutinee, depth);
+                if d > best.0 {
+                    best = (d, span);
+                }
+                for arm in arms {
+                    for (d, span) in arm
+                        .guard
+                        .iter()
+                        .chain(std::iter::once(&arm.body))
+                        .map(|n| self.nesting_depth(n, depth + 1))
+                    {
+                        if d > best.0 {
+                            best = (d, span);
+                        }
+                    }
+                }
+                return best;
+            }
+            AstNode::Binary { left, right, .. } => (depth, vec![left.as_ref(), right.as_ref()]),
+            AstNode::Unary { operand, .. } => (depth, vec![operand.as_ref()]),
+            AstNode::Block {
+                statements, result, ..
+            } => (depth, statements.iter().chain(result.as_deref()).collect()),
+            AstNode::Return { value, .. } => (depth, value.as_deref().into_iter().collect()),
+            AstNode::Let { value, .. } => (depth, vec![value.as_ref()]),
+            AstNode::Call { args, .. } => (depth, args.iter().collect()),
+            AstNode::Field { object, .. } => (depth, vec![object.as_ref()]),
+            AstNode::Index { object, index, .. } => (depth, vec![object.as_ref(), index.as_ref()]),
+            AstNode::Tuple { elements, .. } | AstNode::Array { elements, .. } => {
+                (depth, elements.iter().collect())
+            }
+            AstNode::For {
+                start, end, body, ..
+            } => (depth, vec![start.as_ref(), end.as_ref(), body.as_ref()]),
+            AstNode::ForEach {
+                collection, body, ..
+            } => (depth, vec![collection.as_ref(), body.as_ref()]),
+            AstNode::While {
+                condition, body, ..
+            } => (depth, vec![condition.as_ref(), body.as_ref()]),
+            AstNode::Try {
+                try_block,
+                catch_block,
+                finally_block,
+                ..
+            } => (
+                depth,
+                std::iter::once(try_block.as_ref())
+                    .chain(catch_block.as_deref())
+                    .chain(finally_block.as_deref())
+                    .collect(),
+            ),
+            AstNode::Assign { target, value, .. } => (depth, vec![target.as_ref(), value.as_ref()]),
+            AstNode::Await { expr, .. } => (depth, vec![expr.as_ref()]),
+            AstNode::Closure { body, .. } => (depth, vec![body.as_ref()]),
+            AstNode::Literal { .. }
+            | AstNode::Var { .. }
+            | AstNode::Unknown { .. }
+            | AstNode::SyntaxError { .. } => (depth, vec![]),
+        };
+
+        children
+            .into_iter()
+            .map(|child| self.nesting_depth(child, inner))
+            .fold(
+                (depth, None),
+                |best, next| {
+                    if next.0 > best.0 {
+                        next
+                    } else {
+                        best
+                    }
+                },
+            )
+    }
+
+    /// Operator and operand tokens below `node`, in source order
+    fn halstead_tokens(
+        &self,
+        node: &AstNode,
+        operators: &mut Vec<String>,
+        operands: &mut Vec<String>,
+    ) {
+        let children: Vec<&AstNode> = match node {
+            AstNode::Literal { value, .. } => {
+                operands.push(format!("{:?}", value));
+                vec![]
+            }
+            AstNode::Var { name, .. } => {
+                operands.push(name.clone());
+                vec![]
+            }
+            AstNode::Binary {
+                op, left, right, ..
+            } => {
+                operators.push(format!("{:?}", op));
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AstNode::Unary { op, operand, .. } => {
+                operators.push(format!("{:?}", op));
+                vec![operand.as_ref()]
+            }
+            AstNode::Call { function, args, .. } => {
+                operators.push(format!("{}()", function));
+                args.iter().collect()
+            }
+            AstNode::If {
+                condition,
+                then_branch,
+                else_branch,
+                ..
+            } => std::iter::once(condition.as_ref())
+                .chain(std::iter::once(then_branch.as_ref()))
+                .chain(else_branch.as_deref())
+                .collect(),
+            AstNode::Match {
+                scrutinee, arms, ..
+            } => std::iter::once(scrutinee.as_ref())
+                .chain(
+                    arms.iter()
+                        .flat_map(|arm| arm.guard.iter().chain(std::iter::once(&arm.body))),
+                )
+                .collect(),
+            AstNode::Block {
+                statements, result, ..
+            } => statements.iter().chain(result.as_deref()).collect(),
+            AstNode::Return { value, .. } => value.as_deref().into_iter().collect(),
+            AstNode::Let { value, .. } => vec![value.as_ref()],
+            AstNode::Field { object, .. } => vec![object.as_ref()],
+            AstNode::Index { object, index, .. } => vec![object.as_ref(), index.as_ref()],
+            AstNode::Tuple { elements, .. } | AstNode::Array { elements, .. } => {
+                elements.iter().collect()
+            }
+            AstNode::For {
+                start, end, body, ..
+            } => vec![start.as_ref(), end.as_ref(), body.as_ref()],
+            AstNode::ForEach {
+                collection, body, ..
+            } => vec![collection.as_ref(), body.as_ref()],
+            AstNode::While {
+                condition, body, ..
+            } => vec![condition.as_ref(), body.as_ref()],
+            AstNode::Try {
+                try_block,
+                catch_block,
+                finally_block,
+                ..
+            } => std::iter::once(try_block.as_ref())
+                .chain(catch_block.as_deref())
+                .chain(finally_block.as_deref())
+                .collect(),
+            AstNode::Assign { target, value, .. } => vec![target.as_ref(), value.as_ref()],
+            AstNode::Await { expr, .. } => vec![expr.as_ref()],
+            AstNode::Closure { body, .. } => vec![body.as_ref()],
+            AstNode::Unknown { .. } | AstNode::SyntaxError { .. } => vec![],
+        };
+
+        for child in children {
+            self.halstead_tokens(child, operators, operands);
         }
     }
 
@@ -393,13 +699,15 @@ impl Analyzer {
                 value: LiteralValue::Int(n),
                 span,
             } if *n > 1 && *n != 100 && *n != 1000 => {
-                issues.push(Issue {
-                    kind: IssueKind::MagicNumber,
-                    severity: Severity::Info,
-                    line: span.start_line,
-                    message: format!("Magic number: {}", n),
-                    suggestion: Some("Extract to named constant".into()),
-                });
+                issues.push(
+                    Issue::new(
+                        IssueKind::MagicNumber,
+                        Severity::Info,
+                        *span,
+                        format!("Magic number: {}", n),
+                    )
+                    .with_suggestion("Extract to named constant"),
+                );
             }
 
             AstNode::Block {
@@ -449,6 +757,31 @@ impl Default for Analyzer {
 }
 
 impl AnalysisReport {
+    /// Whether any issue has exactly this severity
+    pub fn has_severity(&self, severity: Severity) -> bool {
+        self.issues.iter().any(|i| i.severity == severity)
+    }
+
+    /// Issues of exactly this severity, in report order
+    pub fn issues_of(&self, severity: Severity) -> Vec<&Issue> {
+        self.issues
+            .iter()
+            .filter(|i| i.severity == severity)
+            .collect()
+    }
+
+    /// Whether the report fails a `--fail-on` threshold; `Info` issues
+    /// never do
+    pub fn fails(&self, threshold: FailOn) -> bool {
+        match threshold {
+            FailOn::Never => false,
+            FailOn::Warning => {
+                self.has_severity(Severity::Warning) || self.has_severity(Severity::Error)
+            }
+            FailOn::Error => self.has_severity(Severity::Error),
+        }
+    }
+
     pub fn to_report(&self) -> String {
         let mut out = String::new();
 
@@ -475,16 +808,32 @@ impl AnalysisReport {
                 "  Complexity: {}\n",
                 func.metrics.cyclomatic_complexity
             ));
-            out.push_str(&format!("  Max nesting: {}\n", func.metrics.max_nesting));
+            out.push_str(&format!(
+                "  Max nesting depth: {}\n",
+                func.metrics.max_nesting_depth
+            ));
             out.push_str(&format!(
                 "  Decision points: {}\n",
                 func.metrics.decision_points
             ));
+            out.push_str(&format!(
+                "  Halstead volume: {:.1} ({} operators, {} operands)\n",
+                func.metrics.halstead.volume,
+                func.metrics.halstead.total_operators,
+                func.metrics.halstead.total_operands
+            ));
+            out.push_str(&format!(
+                "  Maintainability index: {:.1}\n",
+                func.metrics.maintainability_index
+            ));
 
             if !func.issues.is_empty() {
                 out.push_str("  Issues:\n");
                 for issue in &func.issues {
-                    out.push_str(&format!("    [{}] {}\n", issue.severity, issue.message));
+                    out.push_str(&format!(
+                        "    [{}] {} (line {}): {}\n",
+                        issue.severity, issue.code, issue.line, issue.message
+                    ));
                 }
             }
             out.push('\n');
@@ -541,6 +890,25 @@ fn simple(x: bool) -> i32 {
         assert!(report.functions[0].metrics.cyclomatic_complexity >= 1);
     }
 
+    #[test]
+    fn test_halstead_and_maintainability() {
+        let code = r#"
+fn fee(amount: i64, vip: bool) -> i64 {
+    if vip && amount > 100 { amount - 10 } else { round(amount) }
+}
+"#;
+        let report = analyze(&parse_rust(code).unwrap());
+        let metrics = &report.functions[0].metrics;
+
+        // &&, >, - and round(); vip, amount, 100, 10
+        assert_eq!(metrics.halstead.distinct_operators, 4);
+        assert_eq!(metrics.halstead.distinct_operands, 4);
+        assert!(metrics.halstead.total_operands > metrics.halstead.distinct_operands);
+        assert!(metrics.halstead.volume > 0.0);
+        assert!((0.0..=100.0).contains(&metrics.maintainability_index));
+        assert!(report.to_report().contains("Maintainability index:"));
+    }
+
     #[test]
     fn test_analyze_complex() {
         let code = r#"
@@ -557,7 +925,7 @@ fn complex(a: bool, b: bool, c: bool) -> i32 {
 
         assert_eq!(report.functions.len(), 1);
         assert!(report.functions[0].metrics.cyclomatic_complexity >= 3);
-        assert!(report.functions[0].metrics.max_nesting >= 3);
+        assert!(report.functions[0].metrics.max_nesting_depth >= 3);
     }
 
     #[test]
@@ -578,4 +946,136 @@ fn with_match(x: i32) -> &'static str {
         assert_eq!(report.functions.len(), 1);
         assert!(report.functions[0].metrics.decision_points >= 1);
     }
+
+    #[test]
+    fn test_deep_nesting_reported() {
+        let code = r#"
+fn deep(a: bool, b: bool, c: bool, d: bool, e: bool) -> i32 {
+    if a {
+        if b {
+            if c {
+                if d {
+                    if e {
+                        return 1;
+                    }
+                }
+            }
+        }
+    }
+    0
+}
+"#;
+        let ast = parse_rust(code).unwrap();
+        let report = analyze(&ast);
+
+        let func = &report.functions[0];
+        assert_eq!(func.metrics.max_nesting_depth, 5);
+        let issue = func
+            .issues
+            .iter()
+            .find(|i| i.kind == IssueKind::DeepNesting)
+            .expect("deep nesting issue");
+        assert_eq!(issue.severity, Severity::Warning);
+        assert_eq!(issue.message, "Nesting depth 5 exceeds 4");
+        // Points at the innermost `if`, not the whole function
+        assert_eq!(issue.span.start_line, 7);
+
+        let relaxed = ValidationConfig {
+            max_nesting_depth: 5,
+            ..Default::default()
+        };
+        let report = Analyzer::with_config(AnalyzerConfig::from_validation(&relaxed)).analyze(&ast);
+        assert!(!report
+            .issues
+            .iter()
+            .any(|i| i.kind == IssueKind::DeepNesting));
+    }
+
+    #[test]
+    fn test_issues_have_code_and_span() {
+        let code = r#"
+fn noisy(a: i32, b: i32, c: i32, d: i32, e: i32, f: i32) -> i32 {
+    if a > 42 { 7 } else { 13 }
+}
+"#;
+        let ast = parse_rust(code).unwrap();
+        let report = analyze(&ast);
+
+        assert!(!report.issues.is_empty());
+        for issue in &report.issues {
+            assert!(!issue.code.is_empty());
+            assert_eq!(issue.code, issue.kind.code());
+            assert!(issue.span.start_line >= 1);
+            assert!(issue.span.end_line >= issue.span.start_line);
+            assert_eq!(issue.line, issue.span.start_line);
+        }
+        assert!(report
+            .issues
+            .iter()
+            .any(|i| i.kind == IssueKind::TooManyParams && i.code == "IMACS-A005"));
+        assert!(report
+            .issues
+            .iter()
+            .any(|i| i.kind == IssueKind::MagicNumber && i.code == "IMACS-A004"));
+    }
+
+    #[test]
+    fn test_severity_queries_and_fail_on() {
+        let issue = |kind: IssueKind, severity: Severity, line: usize| {
+            let span = Span {
+                start_line: line,
+                end_line: line,
+                ..Default::default()
+            };
+            Issue::new(kind, severity, span, format!("issue on line {}", line))
+        };
+        let mut report = AnalysisReport {
+            functions: vec![],
+            overall: OverallMetrics {
+                total_lines: 40,
+                total_functions: 1,
+                avg_complexity: 12.0,
+                max_complexity: 12,
+                total_issues: 3,
+            },
+            issues: vec![
+                issue(IssueKind::MagicNumber, Severity::Info, 3),
+                issue(IssueKind::HighComplexity, Severity::Error, 1),
+                issue(IssueKind::MagicNumber, Severity::Info, 7),
+            ],
+            recommendations: vec![],
+        };
+
+        assert!(report.has_severity(Severity::Error));
+        assert!(report.has_severity(Severity::Info));
+        assert!(!report.has_severity(Severity::Warning));
+        let infos: Vec<usize> = report
+            .issues_of(Severity::Info)
+            .iter()
+            .map(|i| i.line)
+            .collect();
+        assert_eq!(infos, vec![3, 7]);
+        assert_eq!(report.issues_of(Severity::Error).len(), 1);
+        assert!(report.issues_of(Severity::Warning).is_empty());
+
+        assert!(report.fails(FailOn::Error));
+        assert!(report.fails(FailOn::Warning));
+        assert!(!report.fails(FailOn::Never));
+
+        // Warnings fail `warning` but not `error`; info fails neither
+        report.issues[1].severity = Severity::Warning;
+        assert!(!report.fails(FailOn::Error));
+        assert!(report.fails(FailOn::Warning));
+        report.issues.remove(1);
+        assert!(!report.fails(FailOn::Warning));
+    }
+
+    #[test]
+    fn test_issue_schema_has_stable_fields() {
+        let schema = serde_json::to_string(&schemars::schema_for!(Issue)).unwrap();
+        assert!(schema.contains("\"code\""));
+        assert!(schema.contains("\"span\""));
+        assert!(schema.contains("\"severity\""));
+        assert!(schema.contains("start_line"));
+    }
 }
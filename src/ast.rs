@@ -3,6 +3,7 @@
 //! Language-agnostic AST that captures decision logic structure.
 //! Parsed from source code via tree-sitter.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parsed code AST
@@ -14,12 +15,16 @@ pub struct CodeAst {
     /// Top-level functions
     pub functions: Vec<Function>,
 
+    /// Struct, interface and class declarations, for resolving field types
+    #[serde(default)]
+    pub types: Vec<TypeDef>,
+
     /// Hash of source for change detection
     pub source_hash: String,
 }
 
 /// Source language
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum Language {
     Rust,
@@ -28,9 +33,23 @@ pub enum Language {
     Go,
     CSharp,
     Java,
+    #[default]
     Unknown,
 }
 
+/// A record type declaration (Rust `struct`, TS `interface`/`class`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeDef {
+    /// Type name
+    pub name: String,
+
+    /// Fields in declaration order
+    pub fields: Vec<Parameter>,
+
+    /// Source location
+    pub span: Span,
+}
+
 /// A function definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Function {
@@ -48,6 +67,22 @@ pub struct Function {
 
     /// Source location
     pub span: Span,
+
+    /// Enclosing type for methods (Rust `impl`, class); `None` for free
+    /// functions
+    #[serde(default)]
+    pub owner: Option<String>,
+}
+
+impl Function {
+    /// Name that stays unique across types: `order_validator_validate` for
+    /// `OrderValidator::validate`, the plain name for free functions
+    pub fn qualified_name(&self) -> String {
+        match &self.owner {
+            Some(owner) => format!("{}_{}", crate::util::to_snake_case(owner), self.name),
+            None => self.name.clone(),
+        }
+    }
 }
 
 /// Function parameter
@@ -57,8 +92,8 @@ pub struct Parameter {
     pub typ: String,
 }
 
-/// Source location
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+/// Source location (1-based lines)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Span {
     pub start_line: usize,
     pub start_col: usize,
@@ -205,6 +240,9 @@ pub enum AstNode {
 
     /// Unknown/unparsed node
     Unknown { kind: String, span: Span },
+
+    /// Source the parser could not make sense of
+    SyntaxError { text: String, span: Span },
 }
 
 impl AstNode {
@@ -233,6 +271,68 @@ impl AstNode {
             AstNode::Await { span, .. } => *span,
             AstNode::Closure { span, .. } => *span,
             AstNode::Unknown { span, .. } => *span,
+            AstNode::SyntaxError { span, .. } => *span,
+        }
+    }
+
+    /// Direct child nodes, in source order
+    pub fn children(&self) -> Vec<&AstNode> {
+        match self {
+            AstNode::Binary { left, right, .. } => vec![left.as_ref(), right.as_ref()],
+            AstNode::Unary { operand, .. } => vec![operand.as_ref()],
+            AstNode::Call { args, .. } => args.iter().collect(),
+            AstNode::If {
+                condition,
+                then_branch,
+                else_branch,
+                ..
+            } => std::iter::once(condition.as_ref())
+                .chain(std::iter::once(then_branch.as_ref()))
+                .chain(else_branch.as_deref())
+                .collect(),
+            AstNode::Match {
+                scrutinee, arms, ..
+            } => std::iter::once(scrutinee.as_ref())
+                .chain(
+                    arms.iter()
+                        .flat_map(|arm| arm.guard.iter().chain(std::iter::once(&arm.body))),
+                )
+                .collect(),
+            AstNode::Block {
+                statements, result, ..
+            } => statements.iter().chain(result.as_deref()).collect(),
+            AstNode::Return { value, .. } => value.as_deref().into_iter().collect(),
+            AstNode::Let { value, .. } => vec![value.as_ref()],
+            AstNode::Field { object, .. } => vec![object.as_ref()],
+            AstNode::Index { object, index, .. } => vec![object.as_ref(), index.as_ref()],
+            AstNode::Tuple { elements, .. } | AstNode::Array { elements, .. } => {
+                elements.iter().collect()
+            }
+            AstNode::For {
+                start, end, body, ..
+            } => vec![start.as_ref(), end.as_ref(), body.as_ref()],
+            AstNode::ForEach {
+                collection, body, ..
+            } => vec![collection.as_ref(), body.as_ref()],
+            AstNode::While {
+                condition, body, ..
+            } => vec![condition.as_ref(), body.as_ref()],
+            AstNode::Try {
+                try_block,
+                catch_block,
+                finally_block,
+                ..
+            } => std::iter::once(try_block.as_ref())
+                .chain(catch_block.as_deref())
+                .chain(finally_block.as_deref())
+                .collect(),
+            AstNode::Assign { target, value, .. } => vec![target.as_ref(), value.as_ref()],
+            AstNode::Await { expr, .. } => vec![expr.as_ref()],
+            AstNode::Closure { body, .. } => vec![body.as_ref()],
+            AstNode::Literal { .. }
+            | AstNode::Var { .. }
+            | AstNode::Unknown { .. }
+            | AstNode::SyntaxError { .. } => vec![],
         }
     }
 }
@@ -387,4 +487,9 @@ impl CodeAst {
     pub fn function_names(&self) -> Vec<&str> {
         self.functions.iter().map(|f| f.name.as_str()).collect()
     }
+
+    /// Find type declaration by name
+    pub fn get_type(&self, name: &str) -> Option<&TypeDef> {
+        self.types.iter().find(|t| t.name == name)
+    }
 }
@@ -4,6 +4,7 @@
 //! - Parses CEL strings to AST (using cel-parser crate)
 //! - Evaluates CEL expressions at runtime (using cel-interpreter)
 //! - Compiles CEL AST to target languages (Rust, TypeScript, Python, etc.)
+//! - Simplifies CEL AST (De Morgan, double negation, constant folding)
 //!
 //! CEL evaluation is used for validation and testing.
 //! Generated code has no CEL dependency - only the compiled target language code.
@@ -25,7 +26,15 @@ use cel_interpreter::{Context, Program, Value};
 
 /// Target language for CEL compilation
 #[derive(
-    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
 )]
 #[serde(rename_all = "lowercase")]
 pub enum Target {
@@ -35,6 +44,11 @@ pub enum Target {
     CSharp,
     Java,
     Go,
+    Kotlin,
+    Swift,
+    Php,
+    Ruby,
+    Elixir,
 }
 
 /// CEL compiler - parses, evaluates, and renders to target languages
@@ -47,7 +61,7 @@ impl CelCompiler {
     /// Parse CEL expression string to AST (using cel-parser)
     pub fn parse(expr: &str) -> Result<CelExpr> {
         Parser::new()
-            .parse(expr)
+            .parse(&lower_bitwise(expr))
             .map_err(|e| Error::CelParse(format!("{}: {}", expr, e)))
     }
 
@@ -55,16 +69,35 @@ impl CelCompiler {
     /// Uses cel-parser for validation (cel-interpreter's parser panics on syntax errors)
     /// Catches panics from the parser and treats them as invalid expressions
     pub fn is_valid(expr: &str) -> bool {
-        std::panic::catch_unwind(|| Parser::new().parse(expr).is_ok()).unwrap_or(false)
+        std::panic::catch_unwind(|| Parser::new().parse(&lower_bitwise(expr)).is_ok())
+            .unwrap_or(false)
+    }
+
+    /// Check if a string is a CEL conditional expression (`cond ? a : b`)
+    pub fn is_conditional(expr: &str) -> bool {
+        if !expr.contains('?') || !expr.contains(':') {
+            return false;
+        }
+        std::panic::catch_unwind(|| {
+            Parser::new().parse(expr).is_ok_and(|ast| {
+                matches!(&ast.expr, Expr::Call(call) if call.func_name == operators::CONDITIONAL)
+            })
+        })
+        .unwrap_or(false)
     }
 
     /// Evaluate a CEL expression with the given variable bindings
     /// Returns the evaluated Value
     pub fn eval(expr: &str, vars: &HashMap<String, CelValue>) -> Result<CelValue> {
-        let program =
-            Program::compile(expr).map_err(|e| Error::CelParse(format!("{}: {:?}", expr, e)))?;
+        let program = Program::compile(&lower_bitwise(expr))
+            .map_err(|e| Error::CelParse(format!("{}: {:?}", expr, e)))?;
 
         let mut context = Context::default();
+        context.add_function("bitAnd", |a: i64, b: i64| a & b);
+        context.add_function("bitOr", |a: i64, b: i64| a | b);
+        context.add_function("bitXor", |a: i64, b: i64| a ^ b);
+        context.add_function("shiftLeft", |a: i64, b: i64| a.wrapping_shl(b as u32));
+        context.add_function("shiftRight", |a: i64, b: i64| a.wrapping_shr(b as u32));
         for (name, value) in vars {
             context.add_variable_from_value(name.clone(), value.clone());
         }
@@ -169,12 +202,78 @@ impl CelCompiler {
             Expr::Literal(_) => {
                 // Literals are values, no variables
             }
+            Expr::Comprehension(comp) => {
+                // Macro loop variables are bound locally, not inputs
+                Self::collect_variables(&comp.iter_range, vars);
+                let mut inner = Vec::new();
+                Self::collect_variables(&comp.loop_step, &mut inner);
+                vars.extend(inner.into_iter().filter(|v| {
+                    v.as_str() != comp.iter_var.as_str() && v.as_str() != comp.accu_var.as_str()
+                }));
+            }
             _ => {
                 // Other expression types - recurse if they contain expressions
             }
         }
     }
 
+    /// Variables an expression compares with `null` (`x != null`,
+    /// `null == x`) or tests with `has(x)`
+    pub fn null_checked_variables(expr: &str) -> Result<Vec<String>> {
+        let ast = Self::parse(expr)?;
+        let mut vars = Vec::new();
+        Self::collect_null_checks(&ast, &mut vars);
+        vars.sort();
+        vars.dedup();
+        Ok(vars)
+    }
+
+    fn collect_null_checks(expr: &CelExpr, vars: &mut Vec<String>) {
+        let is_null = |e: &CelExpr| match &e.expr {
+            Expr::Literal(Val::Null) => true,
+            Expr::Ident(name) => name.as_str() == "null",
+            _ => false,
+        };
+        let ident = |e: &CelExpr| match &e.expr {
+            Expr::Ident(name) if name.as_str() != "null" => Some(name.to_string()),
+            _ => None,
+        };
+
+        match &expr.expr {
+            Expr::Call(call) => {
+                match (call.func_name.as_str(), call.args.as_slice()) {
+                    (operators::EQUALS | operators::NOT_EQUALS, [left, right]) => {
+                        if is_null(right) {
+                            vars.extend(ident(left));
+                        } else if is_null(left) {
+                            vars.extend(ident(right));
+                        }
+                    }
+                    (operators::HAS, [arg]) => vars.extend(ident(arg)),
+                    _ => {}
+                }
+                for arg in call.target.iter().map(|t| &**t).chain(&call.args) {
+                    Self::collect_null_checks(arg, vars);
+                }
+            }
+            Expr::Select(select) => Self::collect_null_checks(&select.operand, vars),
+            Expr::List(list) => {
+                for item in &list.elements {
+                    Self::collect_null_checks(item, vars);
+                }
+            }
+            Expr::Comprehension(comp) => {
+                Self::collect_null_checks(&comp.iter_range, vars);
+                let mut inner = Vec::new();
+                Self::collect_null_checks(&comp.loop_step, &mut inner);
+                vars.extend(inner.into_iter().filter(|v| {
+                    v.as_str() != comp.iter_var.as_str() && v.as_str() != comp.accu_var.as_str()
+                }));
+            }
+            _ => {}
+        }
+    }
+
     /// PY-4: Validate that all variables in a CEL expression are defined
     pub fn validate_variables(expr: &str, valid_names: &[&str]) -> Result<()> {
         let referenced = Self::extract_variables(expr)?;
@@ -227,6 +326,98 @@ impl CelCompiler {
         Self::render(expr, Target::Go)
     }
 
+    /// Compile to Go, typing a top-level conditional as `typ`
+    ///
+    /// Go has no `?:`, so a conditional becomes a closure; nested ones take
+    /// their return type from their branches, which bare variables leave
+    /// open, so callers that know the result type pass it here.
+    pub fn compile_go_as(expr: &str, typ: &str) -> Result<String> {
+        let ast = Self::parse(expr)?;
+        Ok(match &ast.expr {
+            Expr::Call(call) if call.func_name == operators::CONDITIONAL => match &call.args[..] {
+                [cond, if_true, if_false] => Self::go_conditional(
+                    &Self::to_go(cond),
+                    &Self::to_go(if_true),
+                    &Self::to_go(if_false),
+                    typ,
+                ),
+                _ => Self::to_go(&ast),
+            },
+            _ => Self::to_go(&ast),
+        })
+    }
+
+    /// `cond ? if_true : if_false` as an immediately called Go closure
+    fn go_conditional(cond: &str, if_true: &str, if_false: &str, typ: &str) -> String {
+        format!(
+            "func() {} {{ if {} {{ return {} }}; return {} }}()",
+            typ, cond, if_true, if_false
+        )
+    }
+
+    /// Go type of the given branches, from the first one whose shape
+    /// gives it away; `float64` wins so an int literal beside it still fits
+    fn go_branch_type(branches: &[CelExpr]) -> Option<&'static str> {
+        let types: Vec<_> = branches.iter().filter_map(Self::go_type).collect();
+        types
+            .iter()
+            .find(|t| **t == "float64")
+            .or(types.first())
+            .copied()
+    }
+
+    fn go_type(expr: &CelExpr) -> Option<&'static str> {
+        match &expr.expr {
+            Expr::Literal(Val::Int(_)) => Some("int64"),
+            Expr::Literal(Val::UInt(_)) => Some("uint64"),
+            Expr::Literal(Val::Double(_)) => Some("float64"),
+            Expr::Literal(Val::String(_)) => Some("string"),
+            Expr::Literal(Val::Boolean(_)) => Some("bool"),
+            Expr::Call(call) => match call.func_name.as_str() {
+                operators::CONDITIONAL => Self::go_branch_type(call.args.get(1..)?),
+                operators::NEGATE => Self::go_branch_type(&call.args),
+                _ if Self::is_arithmetic(call).is_some() => Self::go_branch_type(&call.args),
+                _ if Self::is_relation(call).is_some() => Some("bool"),
+                operators::LOGICAL_AND
+                | operators::LOGICAL_OR
+                | operators::LOGICAL_NOT
+                | operators::IN
+                | "matches"
+                | "startsWith"
+                | "endsWith"
+                | "contains" => Some("bool"),
+                "size" => Some("int64"),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Render CEL AST to Kotlin
+    pub fn to_kotlin(expr: &CelExpr) -> String {
+        Self::render(expr, Target::Kotlin)
+    }
+
+    /// Render CEL AST to Swift
+    pub fn to_swift(expr: &CelExpr) -> String {
+        Self::render(expr, Target::Swift)
+    }
+
+    /// Render CEL AST to PHP
+    pub fn to_php(expr: &CelExpr) -> String {
+        Self::render(expr, Target::Php)
+    }
+
+    /// Render CEL AST to Ruby
+    pub fn to_ruby(expr: &CelExpr) -> String {
+        Self::render(expr, Target::Ruby)
+    }
+
+    /// Render CEL AST to Elixir
+    pub fn to_elixir(expr: &CelExpr) -> String {
+        Self::render(expr, Target::Elixir)
+    }
+
     /// Helper: Check if a CallExpr is a logical AND operation
     fn is_logical_and(call: &CallExpr) -> bool {
         call.func_name == operators::LOGICAL_AND
@@ -283,7 +474,10 @@ impl CelCompiler {
     pub fn render(expr: &CelExpr, target: Target) -> String {
         // In cel-parser 0.10, Expression is IdedExpr with expr field
         match &expr.expr {
-            Expr::Ident(name) => name.to_string(),
+            Expr::Ident(name) => match target {
+                Target::Php => format!("${}", name),
+                _ => name.to_string(),
+            },
 
             Expr::Literal(val) => Self::render_literal(val, target),
 
@@ -294,7 +488,7 @@ impl CelCompiler {
                         let l = Self::render(left, target);
                         let r = Self::render(right, target);
                         return match target {
-                            Target::Python => format!("({} and {})", l, r),
+                            Target::Python | Target::Elixir => format!("({} and {})", l, r),
                             _ => format!("({} && {})", l, r),
                         };
                     }
@@ -303,7 +497,7 @@ impl CelCompiler {
                         let l = Self::render(left, target);
                         let r = Self::render(right, target);
                         return match target {
-                            Target::Python => format!("({} or {})", l, r),
+                            Target::Python | Target::Elixir => format!("({} or {})", l, r),
                             _ => format!("({} || {})", l, r),
                         };
                     }
@@ -315,6 +509,10 @@ impl CelCompiler {
                     if let Some((left, right)) = Self::binary_operands(call) {
                         let l = Self::render(left, target);
                         let r = Self::render(right, target);
+                        // Elixir has no `%` operator
+                        if target == Target::Elixir && op == operators::MODULO {
+                            return format!("rem({}, {})", l, r);
+                        }
                         let op_str = Self::arith_op_from_str(op);
                         return format!("({} {} {})", l, op_str, r);
                     }
@@ -323,7 +521,7 @@ impl CelCompiler {
                         let inner_str = Self::render(inner, target);
                         return match op {
                             operators::LOGICAL_NOT => match target {
-                                Target::Python => format!("(not {})", inner_str),
+                                Target::Python | Target::Elixir => format!("(not {})", inner_str),
                                 _ => format!("(!{})", inner_str),
                             },
                             operators::NEGATE => format!("(-{})", inner_str),
@@ -337,9 +535,24 @@ impl CelCompiler {
                         let if_true = Self::render(&call.args[1], target);
                         let if_false = Self::render(&call.args[2], target);
                         return match target {
+                            Target::Rust => {
+                                format!("(if {} {{ {} }} else {{ {} }})", cond, if_true, if_false)
+                            }
                             Target::Python => {
                                 format!("({} if {} else {})", if_true, cond, if_false)
                             }
+                            Target::Kotlin => {
+                                format!("(if ({}) {} else {})", cond, if_true, if_false)
+                            }
+                            Target::Elixir => {
+                                format!("(if {}, do: {}, else: {})", cond, if_true, if_false)
+                            }
+                            Target::Go => Self::go_conditional(
+                                &cond,
+                                &if_true,
+                                &if_false,
+                                Self::go_branch_type(&call.args[1..]).unwrap_or("interface{}"),
+                            ),
                             _ => format!("({} ? {} : {})", cond, if_true, if_false),
                         };
                     }
@@ -356,14 +569,33 @@ impl CelCompiler {
                                 format!("{}.contains({})", right, left)
                             }
                             Target::Go => format!("contains({}, {})", right, left),
+                            Target::Kotlin => format!("({} in {})", left, right),
+                            Target::Swift => format!("{}.contains({})", right, left),
+                            Target::Php => format!("in_array({}, {}, true)", left, right),
+                            Target::Ruby => format!("{}.include?({})", right, left),
+                            Target::Elixir => format!("({} in {})", left, right),
                         };
                     }
                 }
 
+                if let Some((subject, pattern)) = Self::matches_operands(call) {
+                    let regex = Self::regex_constructor(&Self::render(pattern, target), target);
+                    let subject = Self::render(subject, target);
+                    return Self::render_regex_match(&regex, &subject, target);
+                }
+
                 // Regular function call
                 if let Some(func_expr) = call.target.as_ref() {
                     // Method call: obj.method(args)
                     let obj_str = Self::render(func_expr, target);
+                    if let [arg] = call.args.as_slice() {
+                        let arg_str = Self::render(arg, target);
+                        if let Some(code) =
+                            Self::render_string_method(&call.func_name, &obj_str, &arg_str, target)
+                        {
+                            return code;
+                        }
+                    }
                     let args_str: Vec<_> =
                         call.args.iter().map(|a| Self::render(a, target)).collect();
                     format!("{}.{}({})", obj_str, call.func_name, args_str.join(", "))
@@ -375,6 +607,27 @@ impl CelCompiler {
 
             Expr::Select(select) => {
                 let base_str = Self::render(&select.operand, target);
+                // PHP objects are associative arrays
+                if target == Target::Php && !select.field.is_empty() {
+                    let access = format!("{}['{}']", base_str, select.field);
+                    return if select.test {
+                        format!("isset({})", access)
+                    } else {
+                        access
+                    };
+                }
+                // Ruby objects are hashes with symbol keys
+                if target == Target::Ruby && !select.field.is_empty() {
+                    return if select.test {
+                        format!("{}.key?(:{})", base_str, select.field)
+                    } else {
+                        format!("{}[:{}]", base_str, select.field)
+                    };
+                }
+                // Elixir objects are maps with atom keys
+                if target == Target::Elixir && select.test {
+                    return format!("is_map_key({}, :{})", base_str, select.field);
+                }
                 // Field access: base.field
                 if !select.field.is_empty() {
                     format!("{}.{}", base_str, select.field)
@@ -389,7 +642,10 @@ impl CelCompiler {
                     .iter()
                     .map(|i| Self::render(i, target))
                     .collect();
-                format!("[{}]", items_str.join(", "))
+                match target {
+                    Target::Kotlin => format!("listOf({})", items_str.join(", ")),
+                    _ => format!("[{}]", items_str.join(", ")),
+                }
             }
 
             Expr::Map(_map) => {
@@ -397,10 +653,22 @@ impl CelCompiler {
                 // For now, return empty map
                 match target {
                     Target::Rust => "HashMap::new()".to_string(),
+                    Target::Kotlin => "mapOf()".to_string(),
+                    Target::Swift => "[:]".to_string(),
+                    Target::Php => "[]".to_string(),
+                    Target::Elixir => "%{}".to_string(),
                     _ => "{}".to_string(),
                 }
             }
 
+            Expr::Comprehension(comp) => Self::render_comprehension(
+                &comp.iter_range,
+                &comp.iter_var,
+                &comp.accu_var,
+                &comp.loop_step,
+                target,
+            ),
+
             _ => "/* unsupported expr type */".to_string(),
         }
     }
@@ -410,7 +678,7 @@ impl CelCompiler {
             Val::Int(i) => i.to_string(),
             Val::UInt(u) => u.to_string(),
             Val::Double(f) => format!("{:?}", f), // Ensure decimal point
-            Val::String(s) => format!("\"{}\"", s.escape_default()),
+            Val::String(s) => Self::string_literal(s, target),
             Val::Bytes(b) => format!("{:?}", b),
             Val::Boolean(b) => match target {
                 Target::Python => {
@@ -424,25 +692,181 @@ impl CelCompiler {
             },
             Val::Null => match target {
                 Target::Python => "None".to_string(),
-                Target::TypeScript | Target::CSharp | Target::Java | Target::Go => {
-                    "null".to_string()
-                }
+                Target::TypeScript
+                | Target::CSharp
+                | Target::Java
+                | Target::Go
+                | Target::Kotlin
+                | Target::Php => "null".to_string(),
                 Target::Rust => "None".to_string(),
+                Target::Swift | Target::Ruby | Target::Elixir => "nil".to_string(),
             },
         }
     }
 
+    fn string_literal(s: &str, target: Target) -> String {
+        match target {
+            // `$` starts a string template in Kotlin
+            Target::Kotlin => {
+                format!("\"{}\"", s.escape_default().to_string().replace('$', "\\$"))
+            }
+            // Single quotes keep `$` literal; only `\` and `'` need escaping
+            Target::Php | Target::Ruby => {
+                format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+            }
+            // `#{` starts an interpolation in Elixir
+            Target::Elixir => {
+                format!(
+                    "\"{}\"",
+                    s.escape_default().to_string().replace("#{", "\\#{")
+                )
+            }
+            _ => format!("\"{}\"", s.escape_default()),
+        }
+    }
+
+    /// Subject and pattern of `x.matches(p)` or `matches(x, p)`
+    fn matches_operands(call: &CallExpr) -> Option<(&CelExpr, &CelExpr)> {
+        if call.func_name != "matches" {
+            return None;
+        }
+        match (call.target.as_deref(), call.args.as_slice()) {
+            (Some(subject), [pattern]) => Some((subject, pattern)),
+            (None, [subject, pattern]) => Some((subject, pattern)),
+            _ => None,
+        }
+    }
+
+    /// Expression that builds a compiled regex from rendered pattern code
+    ///
+    /// Templates hoist this exact text into a module-level constant, so the
+    /// inline and hoisted forms must stay identical.
+    pub fn regex_constructor(pattern: &str, target: Target) -> String {
+        match target {
+            Target::Rust => format!("regex::Regex::new({}).unwrap()", pattern),
+            Target::TypeScript => format!("new RegExp({})", pattern),
+            Target::Python => format!("re.compile({})", pattern),
+            Target::Go => format!("regexp.MustCompile({})", pattern),
+            Target::Java => format!("java.util.regex.Pattern.compile({})", pattern),
+            Target::CSharp => format!("new System.Text.RegularExpressions.Regex({})", pattern),
+            Target::Kotlin => format!("Regex({})", pattern),
+            Target::Swift => format!("(try! Regex({}))", pattern),
+            // PCRE patterns carry their own delimiters
+            Target::Php => match pattern
+                .strip_prefix('\'')
+                .and_then(|p| p.strip_suffix('\''))
+            {
+                Some(inner) => format!("'/{}/'", inner.replace('/', "\\/")),
+                None => format!("'/' . str_replace('/', '\\/', {}) . '/'", pattern),
+            },
+            Target::Ruby => format!("Regexp.new({})", pattern),
+            Target::Elixir => format!("Regex.compile!({})", pattern),
+        }
+    }
+
+    /// Regex constructor for a literal pattern, as rendered inside conditions
+    pub fn regex_literal_constructor(pattern: &str, target: Target) -> String {
+        Self::regex_constructor(&Self::string_literal(pattern, target), target)
+    }
+
+    /// Unanchored match, following CEL's RE2 `matches` semantics
+    fn render_regex_match(regex: &str, subject: &str, target: Target) -> String {
+        match target {
+            Target::Rust => format!("{}.is_match(&{})", regex, subject),
+            Target::TypeScript => format!("{}.test({})", regex, subject),
+            Target::Python => format!("({}.search({}) is not None)", regex, subject),
+            Target::Go => format!("{}.MatchString({})", regex, subject),
+            Target::Java => format!("{}.matcher({}).find()", regex, subject),
+            Target::CSharp => format!("{}.IsMatch({})", regex, subject),
+            Target::Kotlin => format!("{}.containsMatchIn({})", regex, subject),
+            Target::Swift => format!("{}.contains({})", subject, regex),
+            Target::Php => format!("(preg_match({}, {}) === 1)", regex, subject),
+            Target::Ruby => format!("{}.match?({})", regex, subject),
+            Target::Elixir => format!("Regex.match?({}, {})", regex, subject),
+        }
+    }
+
+    /// Literal patterns passed to `matches`, in order of first appearance
+    ///
+    /// Returns `None` if the expression does not use `matches` at all, so
+    /// callers can tell "no regex" apart from "only dynamic patterns".
+    pub fn regex_patterns(expr: &str) -> Option<Vec<String>> {
+        fn walk(expr: &CelExpr, found: &mut bool, out: &mut Vec<String>) {
+            match &expr.expr {
+                Expr::Call(call) => {
+                    if let Some((_, pattern)) = CelCompiler::matches_operands(call) {
+                        *found = true;
+                        if let Expr::Literal(Val::String(p)) = &pattern.expr {
+                            let p = p.to_string();
+                            if !out.contains(&p) {
+                                out.push(p);
+                            }
+                        }
+                    }
+                    if let Some(t) = &call.target {
+                        walk(t, found, out);
+                    }
+                    for arg in &call.args {
+                        walk(arg, found, out);
+                    }
+                }
+                Expr::Select(select) => walk(&select.operand, found, out),
+                Expr::List(list) => {
+                    for item in &list.elements {
+                        walk(item, found, out);
+                    }
+                }
+                Expr::Comprehension(comp) => {
+                    walk(&comp.iter_range, found, out);
+                    walk(&comp.loop_step, found, out);
+                }
+                _ => {}
+            }
+        }
+
+        let ast = Self::parse(expr).ok()?;
+        let mut found = false;
+        let mut patterns = Vec::new();
+        walk(&ast, &mut found, &mut patterns);
+        found.then_some(patterns)
+    }
+
+    /// Whether the expression uses a bitwise operator (`allow_bitwise`)
+    pub fn uses_bitwise(expr: &str) -> bool {
+        fn walk(expr: &CelExpr) -> bool {
+            match &expr.expr {
+                Expr::Call(call) => {
+                    bitwise_symbol(&call.func_name).is_some()
+                        || call.target.as_deref().is_some_and(walk)
+                        || call.args.iter().any(walk)
+                }
+                Expr::Select(select) => walk(&select.operand),
+                Expr::List(list) => list.elements.iter().any(walk),
+                Expr::Comprehension(comp) => walk(&comp.iter_range) || walk(&comp.loop_step),
+                _ => false,
+            }
+        }
+
+        Self::parse(expr).is_ok_and(|ast| walk(&ast))
+    }
+
     fn render_relation_op(op: &str, left: &CelExpr, right: &CelExpr, target: Target) -> String {
         let l = Self::render(left, target);
         let r = Self::render(right, target);
+        // Python compares against `None` by identity
+        let null_check = [left, right]
+            .iter()
+            .any(|e| matches!(e.expr, Expr::Literal(Val::Null)));
 
         match op {
             operators::EQUALS => match target {
-                Target::TypeScript => format!("({} === {})", l, r),
+                Target::Python if null_check => format!("({} is {})", l, r),
+                Target::TypeScript | Target::Php => format!("({} === {})", l, r),
                 _ => format!("({} == {})", l, r),
             },
             operators::NOT_EQUALS => match target {
-                Target::TypeScript => format!("({} !== {})", l, r),
+                Target::Python if null_check => format!("({} is not {})", l, r),
+                Target::TypeScript | Target::Php => format!("({} !== {})", l, r),
                 _ => format!("({} != {})", l, r),
             },
             operators::LESS => format!("({} < {})", l, r),
@@ -464,6 +888,56 @@ impl CelCompiler {
         }
     }
 
+    /// Lower CEL string methods (`s.startsWith(x)` etc.) to the target idiom
+    fn render_string_method(
+        name: &str,
+        receiver: &str,
+        arg: &str,
+        target: Target,
+    ) -> Option<String> {
+        let code = match (name, target) {
+            ("startsWith", Target::Rust) => format!("{}.starts_with({})", receiver, arg),
+            ("startsWith", Target::Python) => format!("{}.startswith({})", receiver, arg),
+            ("startsWith", Target::TypeScript | Target::Java | Target::Kotlin) => {
+                format!("{}.startsWith({})", receiver, arg)
+            }
+            ("startsWith", Target::CSharp) => format!("{}.StartsWith({})", receiver, arg),
+            ("startsWith", Target::Go) => format!("strings.HasPrefix({}, {})", receiver, arg),
+            ("startsWith", Target::Swift) => format!("{}.hasPrefix({})", receiver, arg),
+            ("startsWith", Target::Php) => format!("str_starts_with({}, {})", receiver, arg),
+            ("startsWith", Target::Ruby) => format!("{}.start_with?({})", receiver, arg),
+            ("startsWith", Target::Elixir) => {
+                format!("String.starts_with?({}, {})", receiver, arg)
+            }
+
+            ("endsWith", Target::Rust) => format!("{}.ends_with({})", receiver, arg),
+            ("endsWith", Target::Python) => format!("{}.endswith({})", receiver, arg),
+            ("endsWith", Target::TypeScript | Target::Java | Target::Kotlin) => {
+                format!("{}.endsWith({})", receiver, arg)
+            }
+            ("endsWith", Target::CSharp) => format!("{}.EndsWith({})", receiver, arg),
+            ("endsWith", Target::Go) => format!("strings.HasSuffix({}, {})", receiver, arg),
+            ("endsWith", Target::Swift) => format!("{}.hasSuffix({})", receiver, arg),
+            ("endsWith", Target::Php) => format!("str_ends_with({}, {})", receiver, arg),
+            ("endsWith", Target::Ruby) => format!("{}.end_with?({})", receiver, arg),
+            ("endsWith", Target::Elixir) => format!("String.ends_with?({}, {})", receiver, arg),
+
+            ("contains", Target::Rust | Target::Java | Target::Kotlin | Target::Swift) => {
+                format!("{}.contains({})", receiver, arg)
+            }
+            ("contains", Target::Python) => format!("({} in {})", arg, receiver),
+            ("contains", Target::TypeScript) => format!("{}.includes({})", receiver, arg),
+            ("contains", Target::CSharp) => format!("{}.Contains({})", receiver, arg),
+            ("contains", Target::Go) => format!("strings.Contains({}, {})", receiver, arg),
+            ("contains", Target::Php) => format!("str_contains({}, {})", receiver, arg),
+            ("contains", Target::Ruby) => format!("{}.include?({})", receiver, arg),
+            ("contains", Target::Elixir) => format!("String.contains?({}, {})", receiver, arg),
+
+            _ => return None,
+        };
+        Some(code)
+    }
+
     fn render_function(name: &str, args: &[CelExpr], target: Target) -> String {
         let args_rendered: Vec<_> = args.iter().map(|a| Self::render(a, target)).collect();
 
@@ -474,13 +948,23 @@ impl CelCompiler {
             ("size", Target::Python) => format!("len({})", args_rendered[0]),
             ("size", Target::CSharp | Target::Java) => format!("{}.size()", args_rendered[0]),
             ("size", Target::Go) => format!("len({})", args_rendered[0]),
+            ("size", Target::Kotlin) => format!("{}.size", args_rendered[0]),
+            ("size", Target::Swift) => format!("{}.count", args_rendered[0]),
+            ("size", Target::Php) => format!("count({})", args_rendered[0]),
+            ("size", Target::Ruby) => format!("{}.size", args_rendered[0]),
+            ("size", Target::Elixir) => format!("length({})", args_rendered[0]),
 
             // has() function
             ("has", Target::Rust) => format!("{}.is_some()", args_rendered[0]),
             ("has", Target::TypeScript) => format!("({} !== undefined)", args_rendered[0]),
             ("has", Target::Python) => format!("({} is not None)", args_rendered[0]),
-            ("has", Target::CSharp | Target::Java) => format!("({} != null)", args_rendered[0]),
-            ("has", Target::Go) => format!("({} != nil)", args_rendered[0]),
+            ("has", Target::CSharp | Target::Java | Target::Kotlin) => {
+                format!("({} != null)", args_rendered[0])
+            }
+            ("has", Target::Go | Target::Swift) => format!("({} != nil)", args_rendered[0]),
+            ("has", Target::Php) => format!("isset({})", args_rendered[0]),
+            ("has", Target::Ruby) => format!("(!{}.nil?)", args_rendered[0]),
+            ("has", Target::Elixir) => format!("(not is_nil({}))", args_rendered[0]),
 
             // type() function
             ("type", Target::Rust) => format!("type_of({})", args_rendered[0]),
@@ -489,64 +973,16 @@ impl CelCompiler {
             ("type", Target::CSharp) => format!("{}.GetType()", args_rendered[0]),
             ("type", Target::Java) => format!("{}.getClass()", args_rendered[0]),
             ("type", Target::Go) => format!("reflect.TypeOf({})", args_rendered[0]),
+            ("type", Target::Kotlin) => format!("{}::class", args_rendered[0]),
+            ("type", Target::Swift) => format!("type(of: {})", args_rendered[0]),
+            ("type", Target::Php) => format!("gettype({})", args_rendered[0]),
+            ("type", Target::Ruby) => format!("{}.class", args_rendered[0]),
+            ("type", Target::Elixir) => format!("type_of({})", args_rendered[0]),
 
             // string functions
-            ("contains", _) if args.len() >= 2 => {
-                format!("{}.contains({})", args_rendered[0], args_rendered[1])
-            }
-            ("startsWith", Target::Rust) => {
-                format!("{}.starts_with({})", args_rendered[0], args_rendered[1])
-            }
-            ("startsWith", Target::Python) => {
-                format!("{}.startswith({})", args_rendered[0], args_rendered[1])
-            }
-            ("startsWith", Target::TypeScript | Target::CSharp | Target::Java) => {
-                format!("{}.startsWith({})", args_rendered[0], args_rendered[1])
-            }
-            ("startsWith", Target::Go) => {
-                format!(
-                    "strings.HasPrefix({}, {})",
-                    args_rendered[0], args_rendered[1]
-                )
-            }
-            ("endsWith", Target::Rust) => {
-                format!("{}.ends_with({})", args_rendered[0], args_rendered[1])
-            }
-            ("endsWith", Target::Python) => {
-                format!("{}.endswith({})", args_rendered[0], args_rendered[1])
-            }
-            ("endsWith", Target::TypeScript | Target::CSharp | Target::Java) => {
-                format!("{}.endsWith({})", args_rendered[0], args_rendered[1])
-            }
-            ("endsWith", Target::Go) => {
-                format!(
-                    "strings.HasSuffix({}, {})",
-                    args_rendered[0], args_rendered[1]
-                )
-            }
-            ("matches", Target::Rust) => {
-                format!(
-                    "Regex::new({}).unwrap().is_match({})",
-                    args_rendered[1], args_rendered[0]
-                )
-            }
-            ("matches", Target::Python) => {
-                format!("re.match({}, {})", args_rendered[1], args_rendered[0])
-            }
-            ("matches", Target::TypeScript) => {
-                format!("{}.match({})", args_rendered[0], args_rendered[1])
-            }
-            ("matches", Target::CSharp) => {
-                format!("Regex.IsMatch({}, {})", args_rendered[0], args_rendered[1])
-            }
-            ("matches", Target::Java) => {
-                format!("{}.matches({})", args_rendered[0], args_rendered[1])
-            }
-            ("matches", Target::Go) => {
-                format!(
-                    "regexp.MatchString({}, {})",
-                    args_rendered[1], args_rendered[0]
-                )
+            ("contains" | "startsWith" | "endsWith", _) if args.len() >= 2 => {
+                Self::render_string_method(name, &args_rendered[0], &args_rendered[1], target)
+                    .unwrap_or_default()
             }
 
             // int/float conversion
@@ -556,6 +992,11 @@ impl CelCompiler {
             ("int", Target::CSharp) => format!("(long){}", args_rendered[0]),
             ("int", Target::Java) => format!("(long){}", args_rendered[0]),
             ("int", Target::Go) => format!("int64({})", args_rendered[0]),
+            ("int", Target::Kotlin) => format!("{}.toLong()", args_rendered[0]),
+            ("int", Target::Swift) => format!("Int64({})", args_rendered[0]),
+            ("int", Target::Php) => format!("(int){}", args_rendered[0]),
+            ("int", Target::Ruby) => format!("Integer({})", args_rendered[0]),
+            ("int", Target::Elixir) => format!("trunc({})", args_rendered[0]),
 
             ("double" | "float", Target::Rust) => format!("{} as f64", args_rendered[0]),
             ("double" | "float", Target::TypeScript) => format!("parseFloat({})", args_rendered[0]),
@@ -564,13 +1005,39 @@ impl CelCompiler {
                 format!("(double){}", args_rendered[0])
             }
             ("double" | "float", Target::Go) => format!("float64({})", args_rendered[0]),
+            ("double" | "float", Target::Kotlin) => format!("{}.toDouble()", args_rendered[0]),
+            ("double" | "float", Target::Swift) => format!("Double({})", args_rendered[0]),
+            ("double" | "float", Target::Php) => format!("(float){}", args_rendered[0]),
+            ("double" | "float", Target::Ruby) => format!("Float({})", args_rendered[0]),
+            ("double" | "float", Target::Elixir) => format!(":erlang.float({})", args_rendered[0]),
 
             // string conversion
             ("string", Target::Rust) => format!("{}.to_string()", args_rendered[0]),
             ("string", Target::TypeScript) => format!("String({})", args_rendered[0]),
             ("string", Target::Python) => format!("str({})", args_rendered[0]),
-            ("string", Target::CSharp | Target::Java) => format!("{}.toString()", args_rendered[0]),
+            ("string", Target::CSharp | Target::Java | Target::Kotlin) => {
+                format!("{}.toString()", args_rendered[0])
+            }
             ("string", Target::Go) => format!("fmt.Sprintf(\"%v\", {})", args_rendered[0]),
+            ("string", Target::Swift) => format!("String(describing: {})", args_rendered[0]),
+            ("string", Target::Php) => format!("(string){}", args_rendered[0]),
+            ("string", Target::Ruby) => format!("{}.to_s", args_rendered[0]),
+            ("string", Target::Elixir) => format!("to_string({})", args_rendered[0]),
+
+            // bitwise extension (`allow_bitwise`)
+            (_, Target::Elixir) if args.len() == 2 && bitwise_symbol(name).is_some() => format!(
+                "Bitwise.{}({}, {})",
+                bitwise_function_elixir(name),
+                args_rendered[0],
+                args_rendered[1]
+            ),
+            (_, _) if args.len() == 2 && bitwise_symbol(name).is_some() => {
+                let symbol = match target {
+                    Target::Kotlin => bitwise_infix_kotlin(name),
+                    _ => bitwise_symbol(name).unwrap_or_default(),
+                };
+                format!("({} {} {})", args_rendered[0], symbol, args_rendered[1])
+            }
 
             // Default: preserve as function call
             _ => format!("{}({})", name, args_rendered.join(", ")),
@@ -580,6 +1047,57 @@ impl CelCompiler {
 
 /// Render macros for comprehensions
 impl CelCompiler {
+    /// Recover the macro (`all`, `exists`, `map`, `filter`) behind a parsed
+    /// comprehension from the shape of its loop step
+    fn render_comprehension(
+        range: &CelExpr,
+        var: &str,
+        accu: &str,
+        step: &CelExpr,
+        target: Target,
+    ) -> String {
+        let list = Self::render(range, target);
+        let is_ident =
+            |e: &CelExpr, name: &str| matches!(&e.expr, Expr::Ident(n) if n.as_str() == name);
+
+        if let Expr::Call(call) = &step.expr {
+            if let Some((left, right)) = Self::binary_operands(call) {
+                if is_ident(left, accu) {
+                    // all: accu && pred / exists: accu || pred
+                    if Self::is_logical_and(call) {
+                        return Self::render_all(&list, var, right, target);
+                    }
+                    if Self::is_logical_or(call) {
+                        return Self::render_exists(&list, var, right, target);
+                    }
+                    // map: accu + [transform]
+                    if call.func_name == operators::ADD {
+                        if let Expr::List(items) = &right.expr {
+                            if let Some(transform) = items.elements.first() {
+                                return Self::render_map(&list, var, transform, target);
+                            }
+                        }
+                    }
+                }
+            }
+
+            // filter: pred ? accu + [x] : accu
+            if call.func_name == operators::CONDITIONAL && call.args.len() == 3 {
+                if let Expr::Call(add) = &call.args[1].expr {
+                    let appends_var = add.args.get(1).is_some_and(|a| {
+                        matches!(&a.expr, Expr::List(items)
+                            if items.elements.len() == 1 && is_ident(&items.elements[0], var))
+                    });
+                    if add.func_name == operators::ADD && appends_var {
+                        return Self::render_filter(&list, var, &call.args[0], target);
+                    }
+                }
+            }
+        }
+
+        "/* unsupported comprehension */".to_string()
+    }
+
     /// Render list.all(x, predicate)
     pub fn render_all(list: &str, var: &str, predicate: &CelExpr, target: Target) -> String {
         let pred = Self::render(predicate, target);
@@ -589,7 +1107,18 @@ impl CelCompiler {
             Target::Python => format!("all({} for {} in {})", pred, var, list),
             Target::CSharp => format!("{}.All({} => {})", list, var, pred),
             Target::Java => format!("{}.stream().allMatch({} -> {})", list, var, pred),
-            Target::Go => format!("all({}, func({} T) bool {{ return {} }})", list, var, pred),
+            Target::Go => format!(
+                "func() bool {{ for _, {} := range {} {{ if !({}) {{ return false }} }}; return true }}()",
+                var, list, pred
+            ),
+            Target::Kotlin => format!("{}.all {{ {} -> {} }}", list, var, pred),
+            Target::Swift => format!("{}.allSatisfy {{ {} in {} }}", list, var, pred),
+            Target::Php => format!(
+                "(count(array_filter({}, fn(${}) => !({}))) === 0)",
+                list, var, pred
+            ),
+            Target::Ruby => format!("{}.all? {{ |{}| {} }}", list, var, pred),
+            Target::Elixir => format!("Enum.all?({}, fn {} -> {} end)", list, var, pred),
         }
     }
 
@@ -602,7 +1131,18 @@ impl CelCompiler {
             Target::Python => format!("any({} for {} in {})", pred, var, list),
             Target::CSharp => format!("{}.Any({} => {})", list, var, pred),
             Target::Java => format!("{}.stream().anyMatch({} -> {})", list, var, pred),
-            Target::Go => format!("any({}, func({} T) bool {{ return {} }})", list, var, pred),
+            Target::Go => format!(
+                "func() bool {{ for _, {} := range {} {{ if {} {{ return true }} }}; return false }}()",
+                var, list, pred
+            ),
+            Target::Kotlin => format!("{}.any {{ {} -> {} }}", list, var, pred),
+            Target::Swift => format!("{}.contains {{ {} in {} }}", list, var, pred),
+            Target::Php => format!(
+                "(count(array_filter({}, fn(${}) => {})) > 0)",
+                list, var, pred
+            ),
+            Target::Ruby => format!("{}.any? {{ |{}| {} }}", list, var, pred),
+            Target::Elixir => format!("Enum.any?({}, fn {} -> {} end)", list, var, pred),
         }
     }
 
@@ -625,6 +1165,11 @@ impl CelCompiler {
                 "mapSlice({}, func({} T) R {{ return {} }})",
                 list, var, trans
             ),
+            Target::Kotlin => format!("{}.map {{ {} -> {} }}", list, var, trans),
+            Target::Swift => format!("{}.map {{ {} in {} }}", list, var, trans),
+            Target::Php => format!("array_map(fn(${}) => {}, {})", var, trans, list),
+            Target::Ruby => format!("{}.map {{ |{}| {} }}", list, var, trans),
+            Target::Elixir => format!("Enum.map({}, fn {} -> {} end)", list, var, trans),
         }
     }
 
@@ -647,10 +1192,508 @@ impl CelCompiler {
                 "filter({}, func({} T) bool {{ return {} }})",
                 list, var, pred
             ),
+            Target::Kotlin => format!("{}.filter {{ {} -> {} }}", list, var, pred),
+            Target::Swift => format!("{}.filter {{ {} in {} }}", list, var, pred),
+            Target::Php => format!(
+                "array_values(array_filter({}, fn(${}) => {}))",
+                list, var, pred
+            ),
+            Target::Ruby => format!("{}.select {{ |{}| {} }}", list, var, pred),
+            Target::Elixir => format!("Enum.filter({}, fn {} -> {} end)", list, var, pred),
         }
     }
 }
 
+// ============================================================================
+// Bitwise extension
+// ============================================================================
+
+/// Bitwise operators accepted when a spec sets `allow_bitwise: true`, with
+/// the extension function each lowers to, loosest-binding level first
+const BITWISE_LEVELS: [&[(&str, &str)]; 4] = [
+    &[("|", "bitOr")],
+    &[("^", "bitXor")],
+    &[("&", "bitAnd")],
+    &[("<<", "shiftLeft"), (">>", "shiftRight")],
+];
+
+/// Tokens that end a bitwise operand: everything binding looser than `|`
+const BITWISE_BOUNDARIES: [&str; 11] =
+    ["&&", "||", "==", "!=", "<", "<=", ">", ">=", "?", ":", ","];
+
+/// Operator a bitwise extension function stands for (`bitAnd` -> `&`)
+pub fn bitwise_symbol(function: &str) -> Option<&'static str> {
+    BITWISE_LEVELS
+        .iter()
+        .flat_map(|level| level.iter())
+        .find(|(_, name)| *name == function)
+        .map(|(symbol, _)| *symbol)
+}
+
+/// Kotlin spells bitwise operators as infix functions
+fn bitwise_infix_kotlin(function: &str) -> &'static str {
+    match function {
+        "bitAnd" => "and",
+        "bitOr" => "or",
+        "bitXor" => "xor",
+        "shiftLeft" => "shl",
+        _ => "shr",
+    }
+}
+
+/// Elixir's `Bitwise` module function for a bitwise extension function
+fn bitwise_function_elixir(function: &str) -> &'static str {
+    match function {
+        "bitAnd" => "band",
+        "bitOr" => "bor",
+        "bitXor" => "bxor",
+        "shiftLeft" => "bsl",
+        _ => "bsr",
+    }
+}
+
+/// Rewrite bitwise operators (`&`, `|`, `^`, `<<`, `>>`) into the
+/// `bitAnd`/`bitOr`/`bitXor`/`shiftLeft`/`shiftRight` calls standard CEL can
+/// parse
+///
+/// As in Rust and Go, bitwise operators bind tighter than comparisons and
+/// looser than arithmetic, so `flags & 4 != 0` becomes
+/// `bitAnd(flags, 4) != 0`. Expressions without them are returned unchanged.
+pub fn lower_bitwise(expr: &str) -> std::borrow::Cow<'_, str> {
+    let tokens = bitwise_tokens(expr);
+    let is_bitwise = |t: &&str| BITWISE_LEVELS.iter().any(|l| l.iter().any(|(s, _)| s == t));
+    if !tokens.iter().any(is_bitwise) {
+        return std::borrow::Cow::Borrowed(expr);
+    }
+    let mut lowered = String::new();
+    let mut pos = 0;
+    loop {
+        lowered.push_str(&lower_group(&tokens, &mut pos));
+        // An unbalanced closing bracket; keep it for the parser to report
+        let Some(stray) = tokens.get(pos) else { break };
+        lowered.push_str(stray);
+        pos += 1;
+    }
+    std::borrow::Cow::Owned(lowered)
+}
+
+/// Split CEL source into string literals, brackets, operators, whitespace
+/// runs and words, so concatenating the tokens gives back the source
+fn bitwise_tokens(expr: &str) -> Vec<&str> {
+    const OPERATORS: [&str; 17] = [
+        "&&", "||", "==", "!=", "<=", ">=", "<<", ">>", "<", ">", "&", "|", "^", "?", ":", ",", "!",
+    ];
+    let mut tokens = Vec::new();
+    let mut rest = expr;
+    while let Some(c) = rest.chars().next() {
+        let len = if c == '"' || c == '\'' {
+            let mut escaped = false;
+            rest.char_indices()
+                .skip(1)
+                .find(|&(_, ch)| {
+                    let closes = ch == c && !escaped;
+                    escaped = ch == '\\' && !escaped;
+                    closes
+                })
+                .map_or(rest.len(), |(i, _)| i + 1)
+        } else if "()[]{}".contains(c) {
+            1
+        } else if let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(**op)) {
+            op.len()
+        } else if c.is_whitespace() {
+            rest.find(|ch: char| !ch.is_whitespace())
+                .unwrap_or(rest.len())
+        } else {
+            rest.find(|ch: char| ch.is_whitespace() || "()[]{}\"'&|^<>=!?:,+-*/%".contains(ch))
+                .unwrap_or(rest.len())
+                .max(c.len_utf8())
+        };
+        tokens.push(&rest[..len]);
+        rest = &rest[len..];
+    }
+    tokens
+}
+
+/// Lower tokens up to the bracket closing the current group, collapsing
+/// nested groups into single items
+fn lower_group(tokens: &[&str], pos: &mut usize) -> String {
+    let mut items: Vec<String> = Vec::new();
+    while let Some(token) = tokens.get(*pos) {
+        *pos += 1;
+        match *token {
+            ")" | "]" | "}" => {
+                *pos -= 1;
+                break;
+            }
+            "(" | "[" | "{" => {
+                let inner = lower_group(tokens, pos);
+                let close = tokens.get(*pos).copied().unwrap_or_default();
+                *pos += 1;
+                items.push(format!("{}{}{}", token, inner, close));
+            }
+            _ => items.push(token.to_string()),
+        }
+    }
+
+    let mut out = String::new();
+    let mut segment: Vec<String> = Vec::new();
+    for item in items {
+        if BITWISE_BOUNDARIES.contains(&item.as_str()) || item == "in" {
+            out.push_str(&lower_segment(&segment));
+            out.push_str(&item);
+            segment.clear();
+        } else {
+            segment.push(item);
+        }
+    }
+    out.push_str(&lower_segment(&segment));
+    out
+}
+
+/// Lower one operand run, splitting at the loosest bitwise operator (the
+/// last one, since they are left-associative)
+fn lower_segment(items: &[String]) -> String {
+    let is_space = |item: &String| item.trim().is_empty();
+    let start = items
+        .iter()
+        .position(|i| !is_space(i))
+        .unwrap_or(items.len());
+    let end = items
+        .iter()
+        .rposition(|i| !is_space(i))
+        .map_or(start, |i| i + 1);
+    let core = &items[start..end];
+
+    let split = BITWISE_LEVELS.iter().find_map(|level| {
+        core.iter()
+            .rposition(|item| level.iter().any(|(s, _)| *s == item.as_str()))
+            .map(|i| {
+                let function = level.iter().find(|(s, _)| *s == core[i]).map(|(_, f)| *f);
+                (i, function.unwrap_or_default())
+            })
+    });
+    let lowered = match split {
+        Some((i, function)) => format!(
+            "{}({}, {})",
+            function,
+            lower_segment(&core[..i]).trim(),
+            lower_segment(&core[i + 1..]).trim()
+        ),
+        None => core.concat(),
+    };
+    format!(
+        "{}{}{}",
+        items[..start].concat(),
+        lowered,
+        items[end..].concat()
+    )
+}
+
+// ============================================================================
+// Simplification
+// ============================================================================
+
+/// Algebraic simplification of a parsed CEL expression
+///
+/// `CelExpr` is cel-parser's type, so this lives on an extension trait:
+/// bring `Simplify` into scope to call `expr.simplify()`.
+pub trait Simplify {
+    /// Push negations inward (De Morgan), drop double negations and fold
+    /// constant sub-expressions such as `true && x` or `1 + 2 > 2`
+    fn simplify(&self) -> CelExpr;
+}
+
+impl Simplify for CelExpr {
+    fn simplify(&self) -> CelExpr {
+        simplify_expr(self.clone())
+    }
+}
+
+/// Simplify a CEL source string, e.g. `!(a || b)` to `!a && !b`
+///
+/// Expressions containing constructs that cannot be printed back as CEL
+/// (maps, macro comprehensions) are returned unchanged.
+pub fn simplify_cel(expr: &str) -> Result<String> {
+    let ast = CelCompiler::parse(expr)?;
+    Ok(cel_source(&ast.simplify())
+        .map(|(source, _)| source)
+        .unwrap_or_else(|| expr.to_string()))
+}
+
+fn simplify_expr(mut expr: CelExpr) -> CelExpr {
+    let Expr::Call(call) = &mut expr.expr else {
+        return expr;
+    };
+    call.args = std::mem::take(&mut call.args)
+        .into_iter()
+        .map(simplify_expr)
+        .collect();
+    if let Some(target) = call.target.take() {
+        call.target = Some(Box::new(simplify_expr(*target)));
+    }
+    let op = call.func_name.clone();
+
+    match op.as_str() {
+        operators::LOGICAL_NOT => simplify_not(expr),
+        operators::LOGICAL_AND => fold_junction(expr, false),
+        operators::LOGICAL_OR => fold_junction(expr, true),
+        operators::CONDITIONAL => {
+            let Expr::Call(call) = &expr.expr else {
+                return expr;
+            };
+            match (
+                call.args.as_slice(),
+                call.args.first().and_then(bool_literal),
+            ) {
+                ([_, then, _], Some(true)) => then.clone(),
+                ([_, _, otherwise], Some(false)) => otherwise.clone(),
+                _ => expr,
+            }
+        }
+        _ => fold_constant(expr),
+    }
+}
+
+/// `!` over an already simplified operand
+fn simplify_not(expr: CelExpr) -> CelExpr {
+    let Expr::Call(not) = &expr.expr else {
+        return expr;
+    };
+    let [inner] = not.args.as_slice() else {
+        return expr;
+    };
+    if let Some(b) = bool_literal(inner) {
+        return with_literal(expr, Val::Boolean(!b));
+    }
+    let Expr::Call(inner_call) = &inner.expr else {
+        return expr;
+    };
+
+    match (inner_call.func_name.as_str(), inner_call.args.as_slice()) {
+        // !!x
+        (operators::LOGICAL_NOT, [operand]) => operand.clone(),
+        // !(a && b) => !a || !b, !(a || b) => !a && !b
+        (op @ (operators::LOGICAL_AND | operators::LOGICAL_OR), [left, right]) => {
+            let is_or = op == operators::LOGICAL_AND;
+            let negate = |operand: &CelExpr| {
+                let mut negated = expr.clone();
+                if let Expr::Call(call) = &mut negated.expr {
+                    call.args = vec![operand.clone()];
+                }
+                simplify_not(negated)
+            };
+            let mut flipped = inner.clone();
+            if let Expr::Call(call) = &mut flipped.expr {
+                call.func_name = if is_or {
+                    operators::LOGICAL_OR
+                } else {
+                    operators::LOGICAL_AND
+                }
+                .to_string();
+                call.args = vec![negate(left), negate(right)];
+            }
+            fold_junction(flipped, is_or)
+        }
+        _ => expr,
+    }
+}
+
+/// `&&` (`is_or == false`) or `||` with a literal operand
+fn fold_junction(expr: CelExpr, is_or: bool) -> CelExpr {
+    let Expr::Call(call) = &expr.expr else {
+        return expr;
+    };
+    let [left, right] = call.args.as_slice() else {
+        return expr;
+    };
+    let (l, r) = (bool_literal(left), bool_literal(right));
+    if l == Some(is_or) || r == Some(is_or) {
+        // false && x, true || x
+        return with_literal(expr, Val::Boolean(is_or));
+    }
+    if l.is_some() {
+        // true && x, false || x
+        return right.clone();
+    }
+    if r.is_some() {
+        return left.clone();
+    }
+    expr
+}
+
+/// Comparisons and integer arithmetic between two literals
+fn fold_constant(expr: CelExpr) -> CelExpr {
+    let Expr::Call(call) = &expr.expr else {
+        return expr;
+    };
+    let [left, right] = call.args.as_slice() else {
+        return expr;
+    };
+    let (Expr::Literal(a), Expr::Literal(b)) = (&left.expr, &right.expr) else {
+        return expr;
+    };
+
+    let folded = match (call.func_name.as_str(), a, b) {
+        (operators::ADD, Val::Int(x), Val::Int(y)) => x.checked_add(*y).map(Val::Int),
+        (operators::SUBSTRACT, Val::Int(x), Val::Int(y)) => x.checked_sub(*y).map(Val::Int),
+        (operators::MULTIPLY, Val::Int(x), Val::Int(y)) => x.checked_mul(*y).map(Val::Int),
+        (operators::DIVIDE, Val::Int(x), Val::Int(y)) => x.checked_div(*y).map(Val::Int),
+        (operators::MODULO, Val::Int(x), Val::Int(y)) => x.checked_rem(*y).map(Val::Int),
+        (op, a, b) => {
+            let ordering = match (a, b) {
+                (Val::Int(x), Val::Int(y)) => x.partial_cmp(y),
+                (Val::UInt(x), Val::UInt(y)) => x.partial_cmp(y),
+                (Val::Double(x), Val::Double(y)) => x.partial_cmp(y),
+                (Val::String(x), Val::String(y)) => x.partial_cmp(y),
+                (Val::Boolean(x), Val::Boolean(y)) => x.partial_cmp(y),
+                _ => None,
+            };
+            ordering.and_then(|o| {
+                let result = match op {
+                    operators::EQUALS => o.is_eq(),
+                    operators::NOT_EQUALS => o.is_ne(),
+                    operators::LESS => o.is_lt(),
+                    operators::LESS_EQUALS => o.is_le(),
+                    operators::GREATER => o.is_gt(),
+                    operators::GREATER_EQUALS => o.is_ge(),
+                    _ => return None,
+                };
+                Some(Val::Boolean(result))
+            })
+        }
+    };
+
+    match folded {
+        Some(val) => with_literal(expr, val),
+        None => expr,
+    }
+}
+
+fn bool_literal(expr: &CelExpr) -> Option<bool> {
+    match &expr.expr {
+        Expr::Literal(Val::Boolean(b)) => Some(*b),
+        Expr::Ident(name) if name.as_str() == "true" => Some(true),
+        Expr::Ident(name) if name.as_str() == "false" => Some(false),
+        _ => None,
+    }
+}
+
+fn with_literal(mut expr: CelExpr, val: Val) -> CelExpr {
+    expr.expr = Expr::Literal(val);
+    expr
+}
+
+/// Print a CEL AST back to CEL source with minimal parentheses, along with
+/// its binding strength (higher binds tighter)
+fn cel_source(expr: &CelExpr) -> Option<(String, u8)> {
+    const PRIMARY: u8 = 8;
+    let operand = |e: &CelExpr, min: u8| -> Option<String> {
+        let (source, prec) = cel_source(e)?;
+        Some(if prec < min {
+            format!("({})", source)
+        } else {
+            source
+        })
+    };
+    let list = |items: &[CelExpr]| -> Option<String> {
+        let items: Option<Vec<_>> = items.iter().map(|i| operand(i, 0)).collect();
+        Some(items?.join(", "))
+    };
+
+    match &expr.expr {
+        Expr::Ident(name) => Some((name.to_string(), PRIMARY)),
+        Expr::Literal(val) => {
+            let source = match val {
+                Val::Int(i) => i.to_string(),
+                Val::UInt(u) => format!("{}u", u),
+                Val::Double(f) => format!("{:?}", f),
+                Val::String(s) => format!(
+                    "\"{}\"",
+                    s.replace('\\', "\\\\")
+                        .replace('"', "\\\"")
+                        .replace('\n', "\\n")
+                        .replace('\t', "\\t")
+                        .replace('\r', "\\r")
+                ),
+                Val::Boolean(b) => b.to_string(),
+                Val::Null => "null".to_string(),
+                Val::Bytes(_) => return None,
+            };
+            Some((source, PRIMARY))
+        }
+        Expr::Select(select) => {
+            let base = operand(&select.operand, PRIMARY)?;
+            if select.test {
+                Some((format!("has({}.{})", base, select.field), PRIMARY))
+            } else {
+                Some((format!("{}.{}", base, select.field), PRIMARY))
+            }
+        }
+        Expr::List(items) => Some((format!("[{}]", list(&items.elements)?), PRIMARY)),
+        Expr::Call(call) => {
+            let binary = |symbol: &str, prec: u8, associative: bool| -> Option<(String, u8)> {
+                let [left, right] = call.args.as_slice() else {
+                    return None;
+                };
+                let right_min = if associative { prec } else { prec + 1 };
+                Some((
+                    format!(
+                        "{} {} {}",
+                        operand(left, prec)?,
+                        symbol,
+                        operand(right, right_min)?
+                    ),
+                    prec,
+                ))
+            };
+
+            match (call.func_name.as_str(), call.args.as_slice()) {
+                (operators::CONDITIONAL, [cond, then, otherwise]) => Some((
+                    format!(
+                        "{} ? {} : {}",
+                        operand(cond, 2)?,
+                        operand(then, 1)?,
+                        operand(otherwise, 1)?
+                    ),
+                    1,
+                )),
+                (operators::LOGICAL_OR, _) => binary("||", 2, true),
+                (operators::LOGICAL_AND, _) => binary("&&", 3, true),
+                (operators::EQUALS, _) => binary("==", 4, false),
+                (operators::NOT_EQUALS, _) => binary("!=", 4, false),
+                (operators::LESS, _) => binary("<", 4, false),
+                (operators::LESS_EQUALS, _) => binary("<=", 4, false),
+                (operators::GREATER, _) => binary(">", 4, false),
+                (operators::GREATER_EQUALS, _) => binary(">=", 4, false),
+                (operators::IN, _) => binary("in", 4, false),
+                (operators::ADD, _) => binary("+", 5, false),
+                (operators::SUBSTRACT, _) => binary("-", 5, false),
+                (operators::MULTIPLY, _) => binary("*", 6, false),
+                (operators::DIVIDE, _) => binary("/", 6, false),
+                (operators::MODULO, _) => binary("%", 6, false),
+                (operators::LOGICAL_NOT, [inner]) => Some((format!("!{}", operand(inner, 7)?), 7)),
+                (operators::NEGATE, [inner]) => Some((format!("-{}", operand(inner, 7)?), 7)),
+                ("_[_]", [base, index]) => Some((
+                    format!("{}[{}]", operand(base, PRIMARY)?, operand(index, 0)?),
+                    PRIMARY,
+                )),
+                (name, args) => {
+                    let args = list(args)?;
+                    match call.target.as_deref() {
+                        Some(target) => Some((
+                            format!("{}.{}({})", operand(target, PRIMARY)?, name, args),
+                            PRIMARY,
+                        )),
+                        None => Some((format!("{}({})", name, args), PRIMARY)),
+                    }
+                }
+            }
+        }
+        // Maps and macro comprehensions have no source form here
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -699,6 +1742,15 @@ mod tests {
         assert!(py_false.contains("False"));
     }
 
+    #[test]
+    fn test_python_null_comparison_uses_identity() {
+        let present = CelCompiler::compile("code != null", Target::Python).unwrap();
+        let absent = CelCompiler::compile("null == code", Target::Python).unwrap();
+
+        assert_eq!(present, "(code is not None)");
+        assert_eq!(absent, "(None is code)");
+    }
+
     #[test]
     fn test_ternary() {
         let rust = CelCompiler::compile("x > 0 ? 1 : 0", Target::Rust).unwrap();
@@ -706,6 +1758,99 @@ mod tests {
 
         assert!(rust.contains("?"));
         assert!(python.contains("if") && python.contains("else"));
+
+        // Go has no `?:`; the closure's type comes from the branches
+        let go = CelCompiler::compile("x > 0 ? 1 : 0.5", Target::Go).unwrap();
+        assert_eq!(
+            go,
+            "func() float64 { if (x > 0) { return 1 }; return 0.5 }()"
+        );
+        let go = CelCompiler::compile_go_as("vip ? gold : silver", "string").unwrap();
+        assert_eq!(
+            go,
+            "func() string { if vip { return gold }; return silver }()"
+        );
+    }
+
+    #[test]
+    fn test_list_macros() {
+        let expr = "items.exists(i, i > 5)";
+        assert_eq!(
+            CelCompiler::compile(expr, Target::Rust).unwrap(),
+            "items.iter().any(|i| (i > 5))"
+        );
+        assert_eq!(
+            CelCompiler::compile(expr, Target::TypeScript).unwrap(),
+            "items.some(i => (i > 5))"
+        );
+        assert_eq!(
+            CelCompiler::compile(expr, Target::Python).unwrap(),
+            "any((i > 5) for i in items)"
+        );
+        assert!(CelCompiler::compile(expr, Target::Go)
+            .unwrap()
+            .contains("for _, i := range items"));
+
+        let all = CelCompiler::compile("items.all(i, i > 5)", Target::Rust).unwrap();
+        assert_eq!(all, "items.iter().all(|i| (i > 5))");
+
+        let vars = CelCompiler::extract_variables("items.all(i, i > limit)").unwrap();
+        assert_eq!(vars, vec!["items".to_string(), "limit".to_string()]);
+    }
+
+    #[test]
+    fn test_kotlin_rendering() {
+        let ternary = CelCompiler::compile("x > 0 ? 1 : 0", Target::Kotlin).unwrap();
+        let membership = CelCompiler::compile("x in [1, 2, 3]", Target::Kotlin).unwrap();
+        let size = CelCompiler::compile("size(items)", Target::Kotlin).unwrap();
+
+        assert_eq!(ternary, "(if ((x > 0)) 1 else 0)");
+        assert!(membership.contains(" in listOf(1, 2, 3)"));
+        assert!(size.contains(".size") && !size.contains("size()"));
+    }
+
+    #[test]
+    fn test_ruby_rendering() {
+        let membership = CelCompiler::compile("tier in ['free', 'pro']", Target::Ruby).unwrap();
+        let field = CelCompiler::compile("user.account.verified", Target::Ruby).unwrap();
+        let prefix = CelCompiler::compile("name.startsWith('a')", Target::Ruby).unwrap();
+
+        assert_eq!(membership, "['free', 'pro'].include?(tier)");
+        assert_eq!(field, "user[:account][:verified]");
+        assert_eq!(prefix, "name.start_with?('a')");
+    }
+
+    #[test]
+    fn test_elixir_rendering() {
+        let logic =
+            CelCompiler::compile("!locked && (tier in ['free', 'pro'])", Target::Elixir).unwrap();
+        let modulo = CelCompiler::compile("count % 2 == 0", Target::Elixir).unwrap();
+        let interpolation = CelCompiler::compile("name == '#{x}'", Target::Elixir).unwrap();
+
+        assert_eq!(logic, "((not locked) and (tier in [\"free\", \"pro\"]))");
+        assert_eq!(modulo, "(rem(count, 2) == 0)");
+        assert_eq!(interpolation, "(name == \"\\#{x}\")");
+    }
+
+    #[test]
+    fn test_regex_matches() {
+        let method = CelCompiler::compile("email.matches('.+@.+')", Target::Rust).unwrap();
+        let function = CelCompiler::compile("matches(email, '.+@.+')", Target::Rust).unwrap();
+        assert_eq!(
+            method,
+            "regex::Regex::new(\".+@.+\").unwrap().is_match(&email)"
+        );
+        assert_eq!(method, function);
+
+        let py = CelCompiler::compile("email.matches('^a')", Target::Python).unwrap();
+        assert_eq!(py, "(re.compile(\"^a\").search(email) is not None)");
+
+        assert_eq!(
+            CelCompiler::regex_patterns("a.matches('x') || b.matches('y') || c.matches('x')"),
+            Some(vec!["x".to_string(), "y".to_string()])
+        );
+        assert_eq!(CelCompiler::regex_patterns("a.matches(p)"), Some(vec![]));
+        assert_eq!(CelCompiler::regex_patterns("a == 'x'"), None);
     }
 
     #[test]
@@ -828,4 +1973,68 @@ mod tests {
             CelCompiler::eval_bool("role == \"member\" && verified && level >= 50", &vars).unwrap();
         assert!(!result);
     }
+
+    #[test]
+    fn test_simplify_de_morgan() {
+        assert_eq!(simplify_cel("!(a || b)").unwrap(), "!a && !b");
+        assert_eq!(simplify_cel("!(a && !b)").unwrap(), "!a || b");
+        assert_eq!(simplify_cel("!(a || b || c)").unwrap(), "!a && !b && !c");
+    }
+
+    #[test]
+    fn test_simplify_constant_folding() {
+        assert_eq!(simplify_cel("true && x").unwrap(), "x");
+        assert_eq!(simplify_cel("x || false").unwrap(), "x");
+        assert_eq!(simplify_cel("false && x").unwrap(), "false");
+        assert_eq!(simplify_cel("1 + 2 > 2 && y").unwrap(), "y");
+        assert_eq!(simplify_cel("true ? a : b").unwrap(), "a");
+    }
+
+    #[test]
+    fn test_simplify_double_negation() {
+        assert_eq!(simplify_cel("!!x").unwrap(), "x");
+        assert_eq!(simplify_cel("!(!x)").unwrap(), "x");
+
+        let ast = CelCompiler::parse("!!(a && b)").unwrap();
+        assert_eq!(
+            CelCompiler::render(&ast.simplify(), Target::Rust),
+            CelCompiler::compile("a && b", Target::Rust).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_simplify_keeps_precedence() {
+        assert_eq!(simplify_cel("(a || b) && c").unwrap(), "(a || b) && c");
+        assert_eq!(simplify_cel("a - (b - 1)").unwrap(), "a - (b - 1)");
+        assert_eq!(simplify_cel("!(x > 5)").unwrap(), "!(x > 5)");
+        assert_eq!(
+            simplify_cel("role == \"admin\" && user.age >= 18").unwrap(),
+            "role == \"admin\" && user.age >= 18"
+        );
+    }
+
+    #[test]
+    fn test_bitwise_extension() {
+        assert_eq!(lower_bitwise("flags & 4 != 0"), "bitAnd(flags, 4) != 0");
+        assert_eq!(
+            lower_bitwise("a | b & c == 1 && (x << 2) > y"),
+            "bitOr(a, bitAnd(b, c)) == 1 && (shiftLeft(x, 2)) > y"
+        );
+        assert_eq!(lower_bitwise("role == 'a & b'"), "role == 'a & b'");
+        assert!(CelCompiler::uses_bitwise("(flags >> 2) == 1"));
+        assert!(!CelCompiler::uses_bitwise("role == 'a & b'"));
+
+        assert_eq!(
+            CelCompiler::compile("flags & 4 != 0", Target::Rust).unwrap(),
+            "((flags & 4) != 0)"
+        );
+        assert_eq!(
+            CelCompiler::compile("flags | 1 == 3", Target::Kotlin).unwrap(),
+            "((flags or 1) == 3)"
+        );
+
+        let mut vars = HashMap::new();
+        vars.insert("flags".to_string(), Value::Int(6));
+        assert!(CelCompiler::eval_bool("flags & 4 != 0 && flags << 1 == 12", &vars).unwrap());
+    }
 }
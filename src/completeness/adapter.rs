@@ -11,7 +11,6 @@ use cel_parser::{
     ast::operators,
     ast::{CallExpr, Expr},
     reference::Val,
-    Parser,
 };
 
 /// Convert a set of rules with predicates into an Espresso Cover
@@ -46,7 +45,7 @@ pub fn expression_to_cube(expr: &str, predicate_set: &PredicateSet) -> Result<Cu
     cube.set_output(0, CubeValue::One);
 
     // Parse the expression and set cube values
-    if let Ok(ast) = Parser::new().parse(expr) {
+    if let Ok(ast) = crate::cel::CelCompiler::parse(expr) {
         set_cube_from_ast(&ast, &mut cube, predicate_set, false);
     }
 
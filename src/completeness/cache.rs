@@ -0,0 +1,92 @@
+//! Completeness result cache
+//!
+//! Suite analysis stores each spec's `IncompletenessReport` in
+//! `.imacs_cache/completeness.json`, keyed by the SHA256 of the spec's
+//! content, so unchanged specs are not re-analyzed on the next run.
+
+use crate::completeness::analysis::{analyze_completeness, IncompletenessReport};
+use crate::error::{Error, Result};
+use crate::meta::compute_content_hash;
+use crate::spec::Spec;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Directory (relative to the analyzed directory) holding cache files
+pub const CACHE_DIR: &str = ".imacs_cache";
+
+const CACHE_FILE: &str = "completeness.json";
+
+/// Completeness reports keyed by spec content hash
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompletenessCache {
+    /// IMACS version that produced the reports; other versions are ignored
+    tool_version: String,
+
+    /// Spec hash -> report
+    entries: HashMap<String, IncompletenessReport>,
+
+    /// Hashes looked up during this run (everything else is pruned on save)
+    #[serde(skip)]
+    used: HashSet<String>,
+}
+
+impl CompletenessCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self {
+            tool_version: crate::VERSION.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Load the cache for a directory
+    ///
+    /// A missing, unreadable, or outdated cache yields an empty one.
+    pub fn load(dir: &Path) -> Self {
+        std::fs::read_to_string(dir.join(CACHE_DIR).join(CACHE_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .filter(|cache| cache.tool_version == crate::VERSION)
+            .unwrap_or_else(Self::new)
+    }
+
+    /// Save the cache for a directory, dropping entries for specs not seen this run
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let cache_dir = dir.join(CACHE_DIR);
+        std::fs::create_dir_all(&cache_dir).map_err(Error::Io)?;
+
+        let mut pruned = self.clone();
+        pruned.entries.retain(|hash, _| self.used.contains(hash));
+        let json = serde_json::to_string(&pruned)?;
+        std::fs::write(cache_dir.join(CACHE_FILE), json).map_err(Error::Io)?;
+        Ok(())
+    }
+
+    /// Analyze a spec, reusing the cached report if its content is unchanged
+    ///
+    /// Returns the report and whether it came from the cache.
+    pub fn analyze(&mut self, spec: &Spec) -> (IncompletenessReport, bool) {
+        let hash = spec_hash(spec);
+        self.used.insert(hash.clone());
+
+        if let Some(report) = self.entries.get(&hash) {
+            return (report.clone(), true);
+        }
+
+        let report = analyze_completeness(spec);
+        self.entries.insert(hash, report.clone());
+        (report, false)
+    }
+}
+
+/// Content hash of a spec
+///
+/// Hashes the spec's canonical JSON form (object keys sorted), so the
+/// same spec always hashes the same regardless of YAML formatting.
+pub fn spec_hash(spec: &Spec) -> String {
+    let canonical = serde_json::to_value(spec)
+        .map(|value| value.to_string())
+        .unwrap_or_default();
+    compute_content_hash(canonical.as_bytes())
+}
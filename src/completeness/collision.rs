@@ -119,6 +119,8 @@ mod tests {
                     typ: VarType::String,
                     description: None,
                     values: Some(vec!["standard".into()]),
+                    optional: false,
+                    range: None,
                 }],
             ),
             (
@@ -128,6 +130,8 @@ mod tests {
                     typ: VarType::Int,
                     description: None,
                     values: None,
+                    optional: false,
+                    range: None,
                 }],
             ),
         ];
@@ -150,6 +154,8 @@ mod tests {
                     typ: VarType::String,
                     description: None,
                     values: Some(vec!["standard".into(), "premium".into()]),
+                    optional: false,
+                    range: None,
                 }],
             ),
             (
@@ -159,6 +165,8 @@ mod tests {
                     typ: VarType::String,
                     description: None,
                     values: Some(vec!["new".into(), "returning".into()]),
+                    optional: false,
+                    range: None,
                 }],
             ),
         ];
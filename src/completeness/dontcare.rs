@@ -0,0 +1,126 @@
+//! Don't-care detection per rule
+//!
+//! Lists, for each rule, the inputs its condition never looks at. Those
+//! inputs are don't-cares for the rule: any value satisfies it. Useful for
+//! documenting what a rule actually decides on, and as a hint when
+//! minimizing.
+
+use crate::cel::CelCompiler;
+use crate::completeness::predicates::extract_predicates;
+use crate::spec::{Rule, Spec, Variable};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Inputs constrained and left free by one rule
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RuleDontCares {
+    pub rule_id: String,
+    /// Inputs the rule's condition constrains, in declaration order
+    pub constrained: Vec<String>,
+    /// Inputs the rule accepts with any value, in declaration order
+    pub dont_cares: Vec<String>,
+}
+
+impl Rule {
+    /// Declared inputs that this rule's condition constrains
+    ///
+    /// Variables are taken from the condition's atomic predicates; any that
+    /// the predicate extraction cannot model (e.g. inside function calls)
+    /// are still picked up from the raw expression. A rule without a
+    /// condition constrains nothing.
+    pub fn constrained_vars(&self, inputs: &[Variable]) -> HashSet<String> {
+        let Some(cel) = self.as_cel() else {
+            return HashSet::new();
+        };
+
+        let mut vars: HashSet<String> = extract_predicates(&cel)
+            .unwrap_or_default()
+            .iter()
+            .map(|p| root_var(&p.variable_name()))
+            .collect();
+        vars.extend(CelCompiler::extract_variables(&cel).unwrap_or_default());
+
+        vars.retain(|v| inputs.iter().any(|i| &i.name == v));
+        vars
+    }
+}
+
+/// Per-rule constrained inputs and don't-cares for a spec
+pub fn analyze_dont_cares(spec: &Spec) -> Vec<RuleDontCares> {
+    spec.rules
+        .iter()
+        .map(|rule| {
+            let constrained = rule.constrained_vars(&spec.inputs);
+            let (constrained, dont_cares): (Vec<_>, Vec<_>) = spec
+                .inputs
+                .iter()
+                .map(|i| i.name.clone())
+                .partition(|name| constrained.contains(name));
+            RuleDontCares {
+                rule_id: rule.id.clone(),
+                constrained,
+                dont_cares,
+            }
+        })
+        .collect()
+}
+
+/// Human-readable don't-care listing, one line per rule
+pub fn dont_cares_report(rules: &[RuleDontCares]) -> String {
+    let mut out = String::from("Don't-care inputs per rule:\n");
+    for rule in rules {
+        let free = if rule.dont_cares.is_empty() {
+            "(none)".to_string()
+        } else {
+            rule.dont_cares.join(", ")
+        };
+        out.push_str(&format!("  {}: {}\n", rule.rule_id, free));
+    }
+    out
+}
+
+/// `user.tier` constrains the `user` input
+fn root_var(name: &str) -> String {
+    name.split('.').next().unwrap_or(name).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unreferenced_input_is_dont_care() {
+        let spec = Spec::from_yaml(
+            r#"
+id: dc
+inputs:
+  - name: a
+    type: bool
+  - name: b
+    type: bool
+  - name: c
+    type: bool
+outputs:
+  - name: result
+    type: int
+rules:
+  - id: R1
+    when: "a && b"
+    then: 1
+"#,
+        )
+        .unwrap();
+
+        let constrained = spec.rules[0].constrained_vars(&spec.inputs);
+        assert_eq!(
+            constrained,
+            HashSet::from(["a".to_string(), "b".to_string()])
+        );
+
+        let report = analyze_dont_cares(&spec);
+        assert_eq!(report[0].rule_id, "R1");
+        assert_eq!(report[0].constrained, ["a", "b"]);
+        assert_eq!(report[0].dont_cares, ["c"]);
+    }
+}
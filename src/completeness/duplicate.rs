@@ -143,15 +143,24 @@ mod tests {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             outputs: vec![Variable {
                 name: "result".into(),
                 typ: VarType::Int,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             rules,
             default: None,
+            mode: Default::default(),
+            on_no_match: None,
+            ensures: Vec::new(),
+            allow_bitwise: false,
+            schema_version: None,
             meta: Default::default(),
             scoping: None,
         }
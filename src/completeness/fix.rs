@@ -134,6 +134,21 @@ fn apply_fix(spec: &mut Spec, fix: &SpecFix) -> Result<(), Error> {
             }
             Ok(())
         }
+        FixOperation::AddDefault { value } => {
+            if spec.default.is_some() {
+                return Err(Error::Other("Spec already has a default".into()));
+            }
+            spec.default = Some(value.clone());
+            Ok(())
+        }
+        FixOperation::RemoveInput { name } => {
+            let before = spec.inputs.len();
+            spec.inputs.retain(|input| input.name != *name);
+            if spec.inputs.len() == before {
+                return Err(Error::Other(format!("Input {} not found", name)));
+            }
+            Ok(())
+        }
     }
 }
 
@@ -170,12 +185,16 @@ mod tests {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             outputs: vec![crate::spec::Variable {
                 name: "result".into(),
                 typ: VarType::Int,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             rules: vec![
                 crate::spec::Rule {
@@ -196,6 +215,11 @@ mod tests {
                 },
             ],
             default: None,
+            mode: Default::default(),
+            on_no_match: None,
+            ensures: Vec::new(),
+            allow_bitwise: false,
+            schema_version: None,
             meta: Default::default(),
             scoping: None,
         }
@@ -46,7 +46,9 @@
 
 mod adapter;
 mod analysis;
+mod cache;
 mod collision;
+mod dontcare;
 mod duplicate;
 pub mod espresso;
 mod fix;
@@ -64,12 +66,15 @@ pub use analysis::{
     RuleOverlap,
 };
 pub use predicates::{
-    extract_predicates, ComparisonOp, LiteralValue, Predicate, PredicateSet, StringOpKind,
+    expand_enum_predicates, extract_predicates, ComparisonOp, EnumDomain, LiteralValue, Predicate,
+    PredicateSet, StringOpKind,
 };
 
 // Re-export key espresso types
 pub use espresso::{espresso as espresso_minimize, Cover, Cube, CubeValue, EspressoOptions};
 
+pub use cache::{spec_hash, CompletenessCache, CACHE_DIR};
+
 // Re-export adapter functions
 pub use adapter::{cover_to_cel, cube_to_cel, expression_to_cube, minimize_rules, rules_to_cover};
 
@@ -96,11 +101,13 @@ pub use refactor::{
 
 // Re-export suite analysis APIs
 pub use collision::{detect_collisions, Collision, CollisionType, VariableOccurrence};
+pub use dontcare::{analyze_dont_cares, dont_cares_report, RuleDontCares};
 pub use duplicate::{detect_duplicates, Duplicate, RuleRef};
 pub use fix::{apply_fixes, apply_fixes_to_yaml, FixApplicationResult};
 pub use orchestrator_suite::{
-    analyze_directory_with_orchestrators, analyze_orchestrator_suite, DirectorySuiteResult,
-    MappingIssue, MappingIssueType, OrchestratorSuiteResult,
+    analyze_directory_with_orchestrators, analyze_directory_with_progress,
+    analyze_orchestrator_suite, DirectorySuiteResult, MappingIssue, MappingIssueType,
+    OrchestratorSuiteResult,
 };
 pub use relationship::{
     detect_relationships, OutputInputMapping, RelationshipDetails, RelationshipType,
@@ -108,10 +115,11 @@ pub use relationship::{
 };
 pub use suggestions::{generate_suggestions, SuggestedFix, Suggestion, SuggestionCategory};
 pub use suite::{
-    analyze_suite, AnalysisMode, ComplexityReport, SpecResult, SuiteAnalysisResult, SuiteGap,
+    analyze_suite, analyze_suite_cached, AnalysisMode, ComplexityReport, SpecResult,
+    SuiteAnalysisResult, SuiteGap,
 };
 pub use validate::{
-    validate_spec, FixConfidence, FixOperation, IssueType, Severity, SpecFix, ValidationIssue,
-    ValidationReport,
+    validate_spec, validate_spec_with, FailOn, FixConfidence, FixOperation, IssueType, Severity,
+    SpecFix, ValidationIssue, ValidationReport,
 };
 pub use variable_match::{match_variables, MatchType, VariableMatch, VariableMatchResult};
@@ -5,12 +5,14 @@
 //! 2. Load and analyze those specs together
 //! 3. Check for issues across the entire orchestrated workflow
 
-use crate::completeness::suite::{analyze_suite, SuiteAnalysisResult};
+use crate::completeness::cache::CompletenessCache;
+use crate::completeness::suite::{analyze_suite_with, SuiteAnalysisResult};
 use crate::orchestrate::Orchestrator;
 use crate::spec::Spec;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 
 /// Result of analyzing an orchestrator and its referenced specs
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -56,6 +58,15 @@ pub fn analyze_orchestrator_suite(
     orchestrator: &Orchestrator,
     available_specs: &HashMap<String, Spec>,
     full: bool,
+) -> OrchestratorSuiteResult {
+    analyze_orchestrator_suite_with(orchestrator, available_specs, full, None)
+}
+
+fn analyze_orchestrator_suite_with(
+    orchestrator: &Orchestrator,
+    available_specs: &HashMap<String, Spec>,
+    full: bool,
+    cache: Option<&mut CompletenessCache>,
 ) -> OrchestratorSuiteResult {
     // 1. Get all referenced spec IDs
     let referenced_spec_ids = orchestrator.referenced_specs();
@@ -75,7 +86,7 @@ pub fn analyze_orchestrator_suite(
     }
 
     // 3. Analyze the suite of referenced specs
-    let suite_result = analyze_suite(&specs_to_analyze, full);
+    let suite_result = analyze_suite_with(&specs_to_analyze, full, cache, None);
 
     // 4. Check input/output mappings
     let mapping_issues = check_mappings(orchestrator, available_specs);
@@ -176,13 +187,30 @@ fn check_chain_mappings(
 }
 
 /// Analyze a directory that may contain both specs and orchestrators
+///
+/// With `use_cache`, per-spec completeness reports are read from and written
+/// to the directory's `.imacs_cache`, so unchanged specs are not re-analyzed.
 pub fn analyze_directory_with_orchestrators(
     dir_path: &str,
     full: bool,
+    use_cache: bool,
+) -> Result<DirectorySuiteResult, String> {
+    analyze_directory_with_progress(dir_path, full, use_cache, |_, _, _| {})
+}
+
+/// [`analyze_directory_with_orchestrators`], calling `progress` with the
+/// spec's file name, its 1-based position and the number of specs before
+/// each spec is analyzed
+pub fn analyze_directory_with_progress(
+    dir_path: &str,
+    full: bool,
+    use_cache: bool,
+    progress: impl Fn(&str, usize, usize),
 ) -> Result<DirectorySuiteResult, String> {
     use std::fs;
 
     let mut specs = HashMap::new();
+    let mut spec_files: HashMap<String, String> = HashMap::new();
     let mut orchestrators = Vec::new();
 
     // Load all YAML files
@@ -212,6 +240,7 @@ pub fn analyze_directory_with_orchestrators(
                     Err(_) => {
                         // Try as spec
                         if let Ok(spec) = Spec::from_yaml(&content) {
+                            spec_files.insert(spec.id.clone(), file_name(&path));
                             specs.insert(spec.id.clone(), spec);
                         }
                     }
@@ -219,16 +248,19 @@ pub fn analyze_directory_with_orchestrators(
             } else {
                 // Try as spec
                 if let Ok(spec) = Spec::from_yaml(&content) {
+                    spec_files.insert(spec.id.clone(), file_name(&path));
                     specs.insert(spec.id.clone(), spec);
                 }
             }
         }
     }
 
+    let mut cache = use_cache.then(|| CompletenessCache::load(Path::new(dir_path)));
+
     // Analyze each orchestrator and its referenced specs
     let mut orchestrator_results = Vec::new();
     for (_orch_id, orch) in &orchestrators {
-        let result = analyze_orchestrator_suite(orch, &specs, full);
+        let result = analyze_orchestrator_suite_with(orch, &specs, full, cache.as_mut());
         orchestrator_results.push(result);
     }
 
@@ -237,7 +269,16 @@ pub fn analyze_directory_with_orchestrators(
         .iter()
         .map(|(id, spec)| (id.clone(), spec.clone()))
         .collect();
-    let suite_result = analyze_suite(&all_specs, full);
+    let report_progress = |spec_id: &str, n: usize, total: usize| {
+        let file = spec_files.get(spec_id).map_or(spec_id, String::as_str);
+        progress(file, n, total);
+    };
+    let suite_result = analyze_suite_with(&all_specs, full, cache.as_mut(), Some(&report_progress));
+
+    // The cache is best-effort: a read-only directory still gets a result
+    if let Some(cache) = &cache {
+        let _ = cache.save(Path::new(dir_path));
+    }
 
     Ok(DirectorySuiteResult {
         specs_found: specs.len(),
@@ -247,6 +288,12 @@ pub fn analyze_directory_with_orchestrators(
     })
 }
 
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
 /// Result of analyzing a directory
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DirectorySuiteResult {
@@ -296,6 +343,11 @@ mod tests {
                 outputs: vec![],
                 rules: vec![],
                 default: None,
+                mode: Default::default(),
+                on_no_match: None,
+                ensures: Vec::new(),
+                allow_bitwise: false,
+                schema_version: None,
                 meta: Default::default(),
                 scoping: None,
             },
@@ -308,4 +360,60 @@ mod tests {
         assert_eq!(result.found_specs.len(), 1); // Only spec_a is found
         assert_eq!(result.missing_specs.len(), 1); // spec_b is missing
     }
+
+    #[test]
+    fn test_directory_analysis_reuses_cache() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("flag.yaml"),
+            "id: flag\ninputs:\n  - name: a\n    type: bool\noutputs:\n  - name: r\n    type: int\nrules:\n  - id: R1\n    when: a\n    then: 1\n",
+        )
+        .unwrap();
+        let dir = temp.path().to_str().unwrap();
+
+        let first = analyze_directory_with_orchestrators(dir, false, true).unwrap();
+        let results = &first.overall_suite_result.individual_results;
+        assert_eq!(results.len(), 1);
+        assert!(!results[0].cached);
+        assert!(temp.path().join(crate::completeness::CACHE_DIR).exists());
+
+        let second = analyze_directory_with_orchestrators(dir, false, true).unwrap();
+        let results = &second.overall_suite_result.individual_results;
+        assert!(results[0].cached);
+        assert!(!results[0].report.is_complete);
+
+        let uncached = analyze_directory_with_orchestrators(dir, false, false).unwrap();
+        assert!(!uncached.overall_suite_result.individual_results[0].cached);
+    }
+
+    #[test]
+    fn test_directory_analysis_reports_progress() {
+        let temp = tempfile::TempDir::new().unwrap();
+        for id in ["alpha", "beta", "gamma"] {
+            std::fs::write(
+                temp.path().join(format!("{}.yaml", id)),
+                format!("id: {}\ninputs:\n  - name: a\n    type: bool\noutputs:\n  - name: r\n    type: int\nrules:\n  - id: R1\n    when: a\n    then: 1\n", id),
+            )
+            .unwrap();
+        }
+        let dir = temp.path().to_str().unwrap();
+
+        let calls = std::cell::RefCell::new(Vec::new());
+        let result = analyze_directory_with_progress(dir, false, false, |file, n, total| {
+            calls.borrow_mut().push((file.to_string(), n, total));
+        })
+        .unwrap();
+        assert_eq!(result.specs_found, 3);
+
+        let mut calls = calls.into_inner();
+        assert_eq!(calls.len(), 3);
+        assert_eq!(
+            calls.iter().map(|(_, n, _)| *n).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(calls.iter().all(|(_, _, total)| *total == 3));
+        calls.sort();
+        let files: Vec<_> = calls.iter().map(|(file, _, _)| file.as_str()).collect();
+        assert_eq!(files, vec!["alpha.yaml", "beta.yaml", "gamma.yaml"]);
+    }
 }
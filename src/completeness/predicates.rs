@@ -11,6 +11,7 @@
 
 use crate::cel::CelCompiler;
 use crate::error::Result;
+use crate::spec::{VarType, Variable};
 use cel_parser::ast::{operators, CallExpr, Expr};
 use cel_parser::reference::Val;
 pub use cel_parser::Expression as CelExpr;
@@ -115,6 +116,8 @@ pub enum LiteralValue {
     Int(i64),
     Float(f64),
     String(String),
+    /// `null`, compared against optional inputs
+    Null,
 }
 
 impl std::fmt::Display for LiteralValue {
@@ -124,6 +127,7 @@ impl std::fmt::Display for LiteralValue {
             LiteralValue::Int(i) => write!(f, "{}", i),
             LiteralValue::Float(fl) => write!(f, "{}", fl),
             LiteralValue::String(s) => write!(f, "\"{}\"", s),
+            LiteralValue::Null => write!(f, "null"),
         }
     }
 }
@@ -291,6 +295,163 @@ impl Default for PredicateSet {
     }
 }
 
+/// The finite domain of an enum-typed input, or of an int input with a
+/// declared `range`
+///
+/// Equality and membership predicates on the input are not independent:
+/// exactly one variant holds for any input, so only the predicate
+/// combinations consistent with a single variant are feasible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumDomain {
+    /// Input variable name
+    pub var: String,
+    /// Declared variants, in declaration order
+    pub variants: Vec<String>,
+    /// Variants are the integers of a bounded int input, not strings
+    pub numeric: bool,
+}
+
+impl EnumDomain {
+    /// Truth value of `pred` when the input holds `variant`
+    ///
+    /// Returns `None` for predicates that do not constrain this input.
+    pub fn truth(&self, pred: &Predicate, variant: &str) -> Option<bool> {
+        match pred {
+            Predicate::Equality {
+                var,
+                value,
+                negated,
+            } if *var == self.var => Some(self.holds(value, variant)? != *negated),
+            Predicate::Membership {
+                var,
+                values,
+                negated,
+            } if *var == self.var => {
+                let member = values.iter().any(|v| self.holds(v, variant) == Some(true));
+                Some(member != *negated)
+            }
+            Predicate::Comparison { var, op, value } if *var == self.var && self.numeric => {
+                let n = variant.parse::<f64>().ok()?;
+                let bound = match value {
+                    LiteralValue::Int(i) => *i as f64,
+                    LiteralValue::Float(f) => *f,
+                    _ => return None,
+                };
+                Some(match op {
+                    ComparisonOp::Lt => n < bound,
+                    ComparisonOp::Le => n <= bound,
+                    ComparisonOp::Gt => n > bound,
+                    ComparisonOp::Ge => n >= bound,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Does `value` name `variant`?
+    fn holds(&self, value: &LiteralValue, variant: &str) -> Option<bool> {
+        match value {
+            LiteralValue::String(s) if !self.numeric => Some(s == variant),
+            LiteralValue::Int(n) if self.numeric => Some(n.to_string() == variant),
+            _ => None,
+        }
+    }
+
+    /// The literal an equality predicate compares against for `variant`
+    pub fn literal(&self, variant: &str) -> LiteralValue {
+        match variant.parse() {
+            Ok(n) if self.numeric => LiteralValue::Int(n),
+            _ => LiteralValue::String(variant.to_string()),
+        }
+    }
+
+    /// Does `pred` test this input?
+    pub fn constrains(&self, pred: &Predicate) -> bool {
+        self.variants
+            .first()
+            .is_some_and(|v| self.truth(pred, v).is_some())
+    }
+
+    /// The variant selected by a predicate combination
+    ///
+    /// Returns `None` when the combination is infeasible, i.e. no single
+    /// variant makes every predicate on this input take its assigned value.
+    pub fn selected_variant(&self, combo: u64, predicate_set: &PredicateSet) -> Option<&str> {
+        self.variants
+            .iter()
+            .find(|variant| {
+                predicate_set
+                    .predicates
+                    .iter()
+                    .enumerate()
+                    .all(|(idx, pred)| match self.truth(pred, variant) {
+                        Some(expected) => ((combo >> idx) & 1 == 1) == expected,
+                        None => true,
+                    })
+            })
+            .map(|v| v.as_str())
+    }
+}
+
+/// Add one equality predicate per variant for each enum input the rules test
+///
+/// Int inputs with a declared `range` are expanded the same way, one
+/// predicate per value. Inputs that no predicate mentions are left out so
+/// they do not inflate the combination space. Returns the domains that
+/// were expanded.
+pub fn expand_enum_predicates(
+    inputs: &[Variable],
+    predicate_set: &mut PredicateSet,
+) -> Vec<EnumDomain> {
+    let mut domains = Vec::new();
+    for input in inputs {
+        let (variants, numeric) = match (&input.typ, &input.values) {
+            (VarType::Enum(variants), _) => (variants.clone(), false),
+            (VarType::String, Some(values)) => (values.clone(), false),
+            // Wide ranges would blow the predicate budget; leave them as
+            // plain comparisons
+            (VarType::Int, _) => match input.range_values() {
+                Some(range) if range.end().abs_diff(*range.start()) < MAX_RANGE_VALUES => {
+                    (range.map(|n| n.to_string()).collect(), true)
+                }
+                _ => continue,
+            },
+            _ => continue,
+        };
+        if variants.is_empty() {
+            continue;
+        }
+        let domain = EnumDomain {
+            var: input.name.clone(),
+            variants,
+            numeric,
+        };
+        if !predicate_set
+            .predicates
+            .iter()
+            .any(|p| domain.constrains(p))
+        {
+            continue;
+        }
+        for variant in &domain.variants {
+            let pred = Predicate::Equality {
+                var: domain.var.clone(),
+                value: domain.literal(variant),
+                negated: false,
+            };
+            if predicate_set.index_of(&pred.negated()).is_none() {
+                predicate_set.add(pred);
+            }
+        }
+        domains.push(domain);
+    }
+    domains
+}
+
+/// Most values an int `range` may span and still be expanded into one
+/// predicate per value
+const MAX_RANGE_VALUES: u64 = 16;
+
 /// Extract all atomic predicates from a CEL expression string
 pub fn extract_predicates(cel_expr: &str) -> Result<Vec<Predicate>> {
     let ast = CelCompiler::parse(cel_expr)?;
@@ -383,7 +544,7 @@ fn is_relation_op(func_name: &str) -> bool {
 }
 
 /// Extract predicate from a relation Call expression
-fn extract_relation_from_call(call: &CallExpr, negated: bool) -> Option<Predicate> {
+pub(crate) fn extract_relation_from_call(call: &CallExpr, negated: bool) -> Option<Predicate> {
     if call.args.len() != 2 {
         return None;
     }
@@ -482,7 +643,7 @@ fn extract_literal(expr: &CelExpr) -> Option<LiteralValue> {
             Val::Double(f) => Some(LiteralValue::Float(*f)),
             Val::String(s) => Some(LiteralValue::String(s.to_string())),
             Val::Boolean(b) => Some(LiteralValue::Bool(*b)),
-            Val::Null => None,
+            Val::Null => Some(LiteralValue::Null),
             _ => None,
         },
         Expr::Ident(name) => {
@@ -500,6 +661,10 @@ fn extract_literal(expr: &CelExpr) -> Option<LiteralValue> {
 }
 
 /// Format member path like user.profile.status
+///
+/// Object inputs are not expanded into a structured domain: each field path
+/// becomes its own predicate variable, so input-space sizing counts
+/// `user.tier` and `user.total` as independent inputs.
 fn format_member_path(expr: &CelExpr) -> String {
     match &expr.expr {
         Expr::Ident(name) => name.to_string(),
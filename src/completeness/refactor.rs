@@ -364,6 +364,11 @@ pub fn decompose(spec: &Spec) -> DecompositionResult {
             outputs: spec.outputs.clone(), // Each sub-spec can produce the same output
             rules,
             default: spec.default.clone(),
+            mode: spec.mode,
+            on_no_match: spec.on_no_match,
+            ensures: Vec::new(),
+            allow_bitwise: spec.allow_bitwise,
+            schema_version: None,
             meta: spec.meta.clone(),
             scoping: spec.scoping.clone(),
         };
@@ -760,7 +765,7 @@ fn extract_spec_from_branch(step: &ChainStep, orch_id: &str, idx: usize) -> Opti
         let mut rules = Vec::new();
         let mut rule_idx = 1;
 
-        for condition in branch.cases.keys() {
+        for (condition, _) in branch.sorted_cases() {
             rules.push(Rule {
                 id: format!("R{}", rule_idx),
                 when: Some(crate::spec::WhenClause::Single(condition.clone())),
@@ -804,6 +809,8 @@ fn extract_spec_from_branch(step: &ChainStep, orch_id: &str, idx: usize) -> Opti
                                 typ: pred.infer_type(),
                                 description: None,
                                 values: None,
+                                optional: false,
+                                range: None,
                             });
                         }
                     }
@@ -821,9 +828,16 @@ fn extract_spec_from_branch(step: &ChainStep, orch_id: &str, idx: usize) -> Opti
                 typ: VarType::String,
                 description: Some("Branch result".into()),
                 values: None,
+                optional: false,
+                range: None,
             }],
             rules,
             default: None,
+            mode: Default::default(),
+            on_no_match: None,
+            ensures: Vec::new(),
+            allow_bitwise: false,
+            schema_version: None,
             meta: Default::default(),
             scoping: None,
         })
@@ -869,6 +883,8 @@ fn extract_spec_from_gates(steps: &[&ChainStep], orch_id: &str) -> Option<Spec>
                             typ: pred.infer_type(),
                             description: None,
                             values: None,
+                            optional: false,
+                            range: None,
                         });
                     }
                 }
@@ -886,9 +902,16 @@ fn extract_spec_from_gates(steps: &[&ChainStep], orch_id: &str) -> Option<Spec>
             typ: VarType::Bool,
             description: Some("Whether the gate condition passed".into()),
             values: None,
+            optional: false,
+            range: None,
         }],
         rules,
         default: Some(Output::Single(ConditionValue::Bool(false))),
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         meta: Default::default(),
         scoping: None,
     })
@@ -919,12 +942,14 @@ impl Predicate {
                 super::predicates::LiteralValue::Int(_) => VarType::Int,
                 super::predicates::LiteralValue::Float(_) => VarType::Float,
                 super::predicates::LiteralValue::String(_) => VarType::String,
+                super::predicates::LiteralValue::Null => VarType::String,
             },
             Predicate::Equality { value, .. } => match value {
                 super::predicates::LiteralValue::Bool(_) => VarType::Bool,
                 super::predicates::LiteralValue::Int(_) => VarType::Int,
                 super::predicates::LiteralValue::Float(_) => VarType::Float,
                 super::predicates::LiteralValue::String(_) => VarType::String,
+                super::predicates::LiteralValue::Null => VarType::String,
             },
             Predicate::Membership { .. } => VarType::String,
             Predicate::StringOp { .. } => VarType::String,
@@ -946,12 +971,16 @@ mod tests {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             outputs: vec![Variable {
                 name: "result".into(),
                 typ: VarType::Int,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             rules: vec![
                 Rule {
@@ -972,6 +1001,11 @@ mod tests {
                 },
             ],
             default: None,
+            mode: Default::default(),
+            on_no_match: None,
+            ensures: Vec::new(),
+            allow_bitwise: false,
+            schema_version: None,
             meta: Default::default(),
             scoping: None,
         }
@@ -1000,12 +1034,16 @@ mod tests {
                     typ: VarType::Bool,
                     description: None,
                     values: None,
+                    optional: false,
+                    range: None,
                 },
                 Variable {
                     name: "b".into(),
                     typ: VarType::Bool,
                     description: None,
                     values: None,
+                    optional: false,
+                    range: None,
                 },
             ],
             outputs: vec![Variable {
@@ -1013,6 +1051,8 @@ mod tests {
                 typ: VarType::Int,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             rules: vec![
                 Rule {
@@ -1033,6 +1073,11 @@ mod tests {
                 },
             ],
             default: None,
+            mode: Default::default(),
+            on_no_match: None,
+            ensures: Vec::new(),
+            allow_bitwise: false,
+            schema_version: None,
             meta: Default::default(),
             scoping: None,
         };
@@ -1057,12 +1102,16 @@ mod tests {
                     typ: VarType::Bool,
                     description: None,
                     values: None,
+                    optional: false,
+                    range: None,
                 },
                 Variable {
                     name: "b".into(),
                     typ: VarType::Bool,
                     description: None,
                     values: None,
+                    optional: false,
+                    range: None,
                 },
             ],
             outputs: vec![Variable {
@@ -1070,6 +1119,8 @@ mod tests {
                 typ: VarType::Int,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             rules: vec![Rule {
                 id: "R1".into(),
@@ -1080,6 +1131,11 @@ mod tests {
                 description: None,
             }],
             default: None,
+            mode: Default::default(),
+            on_no_match: None,
+            ensures: Vec::new(),
+            allow_bitwise: false,
+            schema_version: None,
             meta: Default::default(),
             scoping: None,
         };
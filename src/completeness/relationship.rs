@@ -1,7 +1,8 @@
 //! Spec relationship detection
 //!
 //! Detects:
-//! - Chains: output of spec A matches input of spec B
+//! - Chains: output of spec A matches input of spec B, by name or (at lower
+//!   confidence) by type alone
 //! - Merge opportunities: specs with overlapping variables that could be combined
 
 use crate::spec::Spec;
@@ -40,8 +41,15 @@ pub struct RelationshipDetails {
 pub struct OutputInputMapping {
     pub output_name: String,
     pub input_name: String,
+    /// How likely the output is meant to feed the input (0.0-1.0)
+    pub confidence: f64,
+    /// Matched on type only; the names differ
+    pub by_type: bool,
 }
 
+/// Confidence of a type-only match with a single candidate output
+const BY_TYPE_CONFIDENCE: f64 = 0.5;
+
 /// Detect relationships between specs
 pub fn detect_relationships(specs: &[(String, &Spec)]) -> Vec<SpecRelationship> {
     let mut relationships = Vec::new();
@@ -74,6 +82,11 @@ pub fn detect_relationships(specs: &[(String, &Spec)]) -> Vec<SpecRelationship>
 }
 
 /// Check if spec A's outputs match spec B's inputs (chain relationship)
+///
+/// An output and input with the same name and type match with full
+/// confidence. Inputs left unmatched then pair with outputs of the same
+/// type that were not matched by name; these are flagged `by_type`, and
+/// their confidence is split between the candidate outputs.
 fn check_chain(spec_a: &Spec, spec_b: &Spec) -> Option<RelationshipDetails> {
     let mut mappings = Vec::new();
     let mut shared = Vec::new();
@@ -84,12 +97,33 @@ fn check_chain(spec_a: &Spec, spec_b: &Spec) -> Option<RelationshipDetails> {
                 mappings.push(OutputInputMapping {
                     output_name: output.name.clone(),
                     input_name: input.name.clone(),
+                    confidence: 1.0,
+                    by_type: false,
                 });
                 shared.push(output.name.clone());
             }
         }
     }
 
+    for input in &spec_b.inputs {
+        if shared.contains(&input.name) {
+            continue;
+        }
+        let candidates: Vec<_> = spec_a
+            .outputs
+            .iter()
+            .filter(|output| output.typ == input.typ && !shared.contains(&output.name))
+            .collect();
+        for output in &candidates {
+            mappings.push(OutputInputMapping {
+                output_name: output.name.clone(),
+                input_name: input.name.clone(),
+                confidence: BY_TYPE_CONFIDENCE / candidates.len() as f64,
+                by_type: true,
+            });
+        }
+    }
+
     if !mappings.is_empty() {
         Some(RelationshipDetails {
             shared_variables: shared,
@@ -141,6 +175,11 @@ mod tests {
             outputs,
             rules: vec![],
             default: None,
+            mode: Default::default(),
+            on_no_match: None,
+            ensures: Vec::new(),
+            allow_bitwise: false,
+            schema_version: None,
             meta: Default::default(),
             scoping: None,
         }
@@ -156,6 +195,8 @@ mod tests {
                 typ: VarType::Float,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
         );
         let spec_b = make_test_spec(
@@ -165,6 +206,8 @@ mod tests {
                 typ: VarType::Float,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             vec![],
         );
@@ -178,6 +221,34 @@ mod tests {
             .any(|r| matches!(r.relationship_type, RelationshipType::Chain)));
     }
 
+    #[test]
+    fn test_detect_chain_by_type() {
+        let float = |name: &str| Variable {
+            name: name.into(),
+            typ: VarType::Float,
+            description: None,
+            values: None,
+            optional: false,
+            range: None,
+        };
+        let spec_a = make_test_spec("spec_a", vec![], vec![float("total")]);
+        let spec_b = make_test_spec("spec_b", vec![float("amount")], vec![]);
+
+        let specs = vec![("spec_a".into(), &spec_a), ("spec_b".into(), &spec_b)];
+
+        let relationships = detect_relationships(&specs);
+        let chain = relationships
+            .iter()
+            .find(|r| matches!(r.relationship_type, RelationshipType::Chain))
+            .expect("type-based chain");
+        let mapping = &chain.details.output_to_input_mapping[0];
+        assert_eq!(mapping.output_name, "total");
+        assert_eq!(mapping.input_name, "amount");
+        assert!(mapping.by_type);
+        assert!(mapping.confidence < 1.0);
+        assert!(chain.details.shared_variables.is_empty());
+    }
+
     #[test]
     fn test_detect_merge_opportunity() {
         let spec_a = make_test_spec(
@@ -188,18 +259,24 @@ mod tests {
                     typ: VarType::Bool,
                     description: None,
                     values: None,
+                    optional: false,
+                    range: None,
                 },
                 Variable {
                     name: "b".into(),
                     typ: VarType::Bool,
                     description: None,
                     values: None,
+                    optional: false,
+                    range: None,
                 },
                 Variable {
                     name: "c".into(),
                     typ: VarType::Bool,
                     description: None,
                     values: None,
+                    optional: false,
+                    range: None,
                 },
             ],
             vec![],
@@ -212,18 +289,24 @@ mod tests {
                     typ: VarType::Bool,
                     description: None,
                     values: None,
+                    optional: false,
+                    range: None,
                 },
                 Variable {
                     name: "b".into(),
                     typ: VarType::Bool,
                     description: None,
                     values: None,
+                    optional: false,
+                    range: None,
                 },
                 Variable {
                     name: "d".into(),
                     typ: VarType::Bool,
                     description: None,
                     values: None,
+                    optional: false,
+                    range: None,
                 },
             ],
             vec![],
@@ -5,7 +5,7 @@
 
 use crate::completeness::collision::{Collision, CollisionType};
 use crate::completeness::duplicate::Duplicate;
-use crate::completeness::relationship::{RelationshipType, SpecRelationship};
+use crate::completeness::relationship::{OutputInputMapping, RelationshipType, SpecRelationship};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -52,6 +52,8 @@ pub enum SuggestedFix {
     DefineChain {
         specs: Vec<String>,
         as_orchestrator: String,
+        /// Which output of the first spec feeds which input of the second
+        mappings: Vec<OutputInputMapping>,
     },
 }
 
@@ -182,6 +184,7 @@ pub fn generate_suggestions(
                     fix: SuggestedFix::DefineChain {
                         specs: vec![relationship.spec_a.clone(), relationship.spec_b.clone()],
                         as_orchestrator: format!("{}_chain", relationship.spec_a),
+                        mappings: relationship.details.output_to_input_mapping.clone(),
                     },
                 });
                 code_counter += 1;
@@ -227,6 +230,8 @@ mod tests {
                     typ: VarType::String,
                     description: None,
                     values: Some(vec!["standard".into()]),
+                    optional: false,
+                    range: None,
                 },
             }],
             collision_type: CollisionType::SameNameDifferentValues,
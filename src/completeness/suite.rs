@@ -7,6 +7,7 @@
 //! - Suite-level gaps (combinations not covered by any spec)
 
 use crate::completeness::analysis::analyze_completeness;
+use crate::completeness::cache::CompletenessCache;
 use crate::completeness::collision::detect_collisions;
 use crate::completeness::duplicate::detect_duplicates;
 use crate::completeness::relationship::detect_relationships;
@@ -34,6 +35,9 @@ pub struct SpecResult {
     pub spec_file: Option<String>,
     pub report: crate::completeness::IncompletenessReport,
     pub passed: bool,
+    /// Report was reused from the completeness cache
+    #[serde(default)]
+    pub cached: bool,
 }
 
 /// A gap in the suite (not covered by any spec)
@@ -59,18 +63,76 @@ pub enum AnalysisMode {
     Full,
 }
 
+impl SuiteAnalysisResult {
+    /// JSON Lines: one object per spec result, then one per collision,
+    /// duplicate and suite gap, each tagged with a `kind` field
+    pub fn to_jsonl(&self) -> serde_json::Result<String> {
+        let records = self
+            .individual_results
+            .iter()
+            .map(SuiteRecord::Spec)
+            .chain(self.collisions.iter().map(SuiteRecord::Collision))
+            .chain(self.duplicates.iter().map(SuiteRecord::Duplicate))
+            .chain(self.suite_gaps.iter().map(SuiteRecord::Gap));
+        let mut out = String::new();
+        for record in records {
+            out.push_str(&serde_json::to_string(&record)?);
+            out.push('\n');
+        }
+        Ok(out)
+    }
+}
+
+/// One line of `SuiteAnalysisResult::to_jsonl`
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum SuiteRecord<'a> {
+    Spec(&'a SpecResult),
+    Collision(&'a crate::completeness::collision::Collision),
+    Duplicate(&'a crate::completeness::duplicate::Duplicate),
+    Gap(&'a SuiteGap),
+}
+
 /// Analyze a suite of specs
 pub fn analyze_suite(specs: &[(String, Spec)], full: bool) -> SuiteAnalysisResult {
+    analyze_suite_with(specs, full, None, None)
+}
+
+/// Analyze a suite of specs, reusing cached reports for unchanged specs
+pub fn analyze_suite_cached(
+    specs: &[(String, Spec)],
+    full: bool,
+    cache: &mut CompletenessCache,
+) -> SuiteAnalysisResult {
+    analyze_suite_with(specs, full, Some(cache), None)
+}
+
+/// `progress` is called before each spec is analyzed with its ID, 1-based
+/// position and the number of specs
+pub(crate) fn analyze_suite_with(
+    specs: &[(String, Spec)],
+    full: bool,
+    mut cache: Option<&mut CompletenessCache>,
+    progress: Option<&dyn Fn(&str, usize, usize)>,
+) -> SuiteAnalysisResult {
     // 1. Individual analysis for each spec
     let individual_results: Vec<SpecResult> = specs
         .iter()
-        .map(|(spec_id, spec)| {
-            let report = analyze_completeness(spec);
+        .enumerate()
+        .map(|(i, (spec_id, spec))| {
+            if let Some(progress) = progress {
+                progress(spec_id, i + 1, specs.len());
+            }
+            let (report, cached) = match cache.as_deref_mut() {
+                Some(cache) => cache.analyze(spec),
+                None => (analyze_completeness(spec), false),
+            };
             SpecResult {
                 spec_id: spec_id.clone(),
                 spec_file: None,
-                report: report.clone(),
                 passed: report.is_complete && report.overlaps.is_empty(),
+                report,
+                cached,
             }
         })
         .collect();
@@ -188,12 +250,16 @@ mod tests {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             outputs: vec![Variable {
                 name: "result".into(),
                 typ: VarType::Int,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             rules: vec![Rule {
                 id: "R1".into(),
@@ -204,6 +270,11 @@ mod tests {
                 description: None,
             }],
             default: None,
+            mode: Default::default(),
+            on_no_match: None,
+            ensures: Vec::new(),
+            allow_bitwise: false,
+            schema_version: None,
             meta: Default::default(),
             scoping: None,
         }
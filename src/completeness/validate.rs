@@ -1,15 +1,24 @@
 //! Spec validation - detect impossible/invalid specs
 //!
 //! Detects:
-//! - Contradictory rules (same condition, different outputs, no priority)
-//! - Unsatisfiable conditions (can never be true)
-//! - Tautology conditions (always match, not marked as default)
+//! - Contradictory rules (overlapping conditions, different outputs, same priority)
+//! - Shadowed rules (overlapping conditions resolved only by priority)
+//! - Unsatisfiable conditions (`ALWAYS_FALSE`, can never be true)
+//! - Tautology conditions (`ALWAYS_TRUE`, always match, not marked as default)
 //! - Dead rules (covered by earlier rules)
+//! - Unreachable rules (implied by a single rule tried before them)
 //! - Type mismatches (wrong types in comparisons)
-
-use super::adapter::rules_to_cover;
+//! - Exact equality on float inputs (`price == 9.99`)
+//! - Incomplete specs without a `default` to fall back on
+//! - Inputs no rule, output or default refers to
+//! - Literals outside an int input's declared `range`
+//! - Specs over the project's `max_rules_per_spec`, and rules without a
+//!   `description` when `require_descriptions` is set
+
+use super::adapter::{cover_to_cel, rules_to_cover};
 use super::espresso::Cover;
 use super::predicates::{extract_predicates, PredicateSet};
+use crate::config::ValidationConfig;
 use crate::spec::{Rule, Spec};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -79,12 +88,58 @@ pub struct IssueContext {
 }
 
 /// Severity of a validation issue
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum Severity {
     Error,
     Warning,
 }
 
+/// Lowest severity that fails a check in CI (`--fail-on`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailOn {
+    /// Fail on any warning or error
+    Warning,
+    /// Fail on errors only
+    Error,
+    /// Report, but never fail
+    Never,
+}
+
+impl FailOn {
+    /// Parse a `--fail-on` value (`warning`, `error` or `never`)
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "warning" | "warn" => Some(FailOn::Warning),
+            "error" => Some(FailOn::Error),
+            "never" => Some(FailOn::Never),
+            _ => None,
+        }
+    }
+
+    /// Whether a report whose worst issue is `max` fails this threshold
+    pub fn fails(self, max: Option<Severity>) -> bool {
+        match (self, max) {
+            (FailOn::Never, _) | (_, None) => false,
+            (FailOn::Warning, Some(_)) => true,
+            (FailOn::Error, Some(severity)) => severity == Severity::Error,
+        }
+    }
+}
+
+impl ValidationReport {
+    /// Most severe issue in the report, if any
+    pub fn max_severity(&self) -> Option<Severity> {
+        let mut max = None;
+        for issue in &self.issues {
+            match issue.severity {
+                Severity::Error => return Some(Severity::Error),
+                Severity::Warning => max = Some(Severity::Warning),
+            }
+        }
+        max
+    }
+}
+
 /// Type of validation issue
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum IssueType {
@@ -93,6 +148,14 @@ pub enum IssueType {
     TautologyCondition,
     DeadRule,
     TypeMismatch,
+    ShadowedRule,
+    UnreachableRule,
+    FloatEquality,
+    MissingDefault,
+    UnusedInput,
+    OutOfRange,
+    TooManyRules,
+    MissingDescription,
 }
 
 /// A concrete fix that can be applied to a spec
@@ -138,27 +201,57 @@ pub enum FixOperation {
         old_expression: String,
         new_expression: String,
     },
+    /// Add a `default` output to a spec that has none
+    AddDefault { value: crate::spec::Output },
+    /// Remove an input nothing refers to
+    RemoveInput { name: String },
 }
 
 /// Validate a spec for impossible/invalid situations
 pub fn validate_spec(spec: &Spec, strict: bool) -> ValidationReport {
+    validate_spec_with(spec, strict, &ValidationConfig::default())
+}
+
+/// `validate_spec` with a project's `validation` settings from `.imacs_root`
+pub fn validate_spec_with(
+    spec: &Spec,
+    strict: bool,
+    config: &ValidationConfig,
+) -> ValidationReport {
     let mut issues = Vec::new();
     let mut code_counter = 1;
 
     // 1. Type mismatch detection
-    issues.extend(detect_type_mismatches(spec, &mut code_counter));
+    issues.extend(detect_type_mismatches(spec));
+
+    // 2-3. Conditions that are always false or always true
+    issues.extend(detect_constant_conditions(spec));
+
+    // 4-6. Dead, contradictory and unreachable rules only matter when the
+    // first match wins; accumulate specs sum every matching rule
+    if spec.mode == crate::spec::SpecMode::FirstMatch {
+        issues.extend(detect_dead_rules(spec, &mut code_counter));
+        issues.extend(detect_contradictions(spec));
+        issues.extend(detect_unreachable(spec));
+    }
+
+    // 7. Float equality detection
+    issues.extend(detect_float_equality(spec));
 
-    // 2. Unsatisfiable condition detection
-    issues.extend(detect_unsatisfiable(spec, &mut code_counter));
+    // 8. Incomplete specs with nothing to fall back on
+    issues.extend(detect_missing_default(spec));
 
-    // 3. Tautology detection
-    issues.extend(detect_tautologies(spec, &mut code_counter));
+    // 9. Inputs nothing refers to
+    issues.extend(detect_unused_inputs(spec));
 
-    // 4. Dead rule detection
-    issues.extend(detect_dead_rules(spec, &mut code_counter));
+    // 10. Literals a ranged input can never equal
+    issues.extend(detect_out_of_range(spec));
 
-    // 5. Contradictory rules detection
-    issues.extend(detect_contradictions(spec, &mut code_counter));
+    // 11-12. Project limits: rule count and required descriptions
+    issues.extend(detect_too_many_rules(spec, config.max_rules_per_spec));
+    if config.require_descriptions {
+        issues.extend(detect_missing_descriptions(spec));
+    }
 
     // Generate fixes for each issue
     let fixes = generate_fixes(&issues, spec);
@@ -233,6 +326,28 @@ fn generate_fixes(issues: &[ValidationIssue], spec: &Spec) -> Vec<SpecFix> {
                     fixes.push(fix);
                 }
             }
+            // Priority already decides the winner; nothing to apply
+            IssueType::ShadowedRule => {}
+            // Deleting the rule and reprioritising it are both plausible
+            IssueType::UnreachableRule => {}
+            // The right tolerance depends on the domain
+            IssueType::FloatEquality => {}
+            IssueType::MissingDefault => {
+                // Low confidence - the modal output is only a guess
+                if let Some(fix) = generate_missing_default_fix(issue, spec) {
+                    fixes.push(fix);
+                }
+            }
+            IssueType::UnusedInput => {
+                // Low confidence - the input may be meant for a rule yet to come
+                if let Some(fix) = generate_unused_input_fix(issue) {
+                    fixes.push(fix);
+                }
+            }
+            // Either the rule or the declared range is wrong
+            IssueType::OutOfRange => {}
+            // Splitting a spec and writing descriptions take a human
+            IssueType::TooManyRules | IssueType::MissingDescription => {}
         }
     }
 
@@ -281,6 +396,48 @@ fn generate_dead_rule_fix(issue: &ValidationIssue) -> Option<SpecFix> {
     }
 }
 
+/// Generate fix for a missing default: the most common `then` value,
+/// ties going to the earliest rule
+fn generate_missing_default_fix(issue: &ValidationIssue, spec: &Spec) -> Option<SpecFix> {
+    let value = modal_output(spec)?;
+    Some(SpecFix {
+        issue_code: issue.code.clone(),
+        confidence: FixConfidence::Low,
+        description: format!("Add default: {} (the most common rule output)", value),
+        operation: FixOperation::AddDefault { value },
+    })
+}
+
+/// Generate fix for an unused input: remove it from the spec
+fn generate_unused_input_fix(issue: &ValidationIssue) -> Option<SpecFix> {
+    let name = issue.context.as_ref()?.variables.as_ref()?.first()?.clone();
+    Some(SpecFix {
+        issue_code: issue.code.clone(),
+        confidence: FixConfidence::Low,
+        description: format!("Remove unused input '{}'", name),
+        operation: FixOperation::RemoveInput { name },
+    })
+}
+
+/// Most common `then` value among the rules, ties going to the earliest
+fn modal_output(spec: &Spec) -> Option<crate::spec::Output> {
+    let mut counts: Vec<(&crate::spec::Output, usize)> = Vec::new();
+    for rule in &spec.rules {
+        match counts.iter_mut().find(|(output, _)| **output == rule.then) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((&rule.then, 1)),
+        }
+    }
+
+    let mut best: Option<(&crate::spec::Output, usize)> = None;
+    for (output, count) in counts {
+        if best.is_none_or(|(_, most)| count > most) {
+            best = Some((output, count));
+        }
+    }
+    best.map(|(output, _)| output.clone())
+}
+
 /// Generate fix for tautology condition
 fn generate_tautology_fix(issue: &ValidationIssue, spec: &Spec) -> Option<SpecFix> {
     if !issue.affected_rules.is_empty() {
@@ -378,318 +535,229 @@ fn generate_unsatisfiable_fix(issue: &ValidationIssue) -> Option<SpecFix> {
 }
 
 /// Detect type mismatches in CEL expressions
-fn detect_type_mismatches(spec: &Spec, code_counter: &mut usize) -> Vec<ValidationIssue> {
-    let mut issues = Vec::new();
-    let var_types: HashMap<String, crate::spec::VarType> = spec
-        .inputs
-        .iter()
-        .map(|v| (v.name.clone(), v.typ.clone()))
-        .collect();
-
-    for rule in &spec.rules {
-        if let Some(when) = rule.as_cel() {
-            if let Err(e) = check_cel_types(&when, &var_types) {
-                // Extract variable names from the expression using CEL AST
-                let vars_in_expr: Vec<String> = crate::cel::CelCompiler::extract_variables(&when)
-                    .unwrap_or_default()
-                    .into_iter()
-                    .filter(|v| var_types.contains_key(v))
-                    .collect();
-
-                // Get type information
-                let type_info = extract_type_mismatch_info(&when, &var_types);
-
-                issues.push(ValidationIssue {
-                    code: format!("V{:03}", {
-                        let c = *code_counter;
-                        *code_counter += 1;
-                        c
-                    }),
-                    severity: Severity::Error,
-                    issue_type: IssueType::TypeMismatch,
-                    message: format!("Type mismatch in rule {}: {}", rule.id, e),
-                    affected_rules: vec![rule.id.clone()],
-                    explanation: Some(format!(
-                        "The condition '{}' compares incompatible types. This will cause a runtime error when evaluating the expression.",
-                        when
-                    )),
-                    suggestion: Some(
-                        "1. Check the variable types in your spec inputs\n2. Ensure comparisons use compatible types (int with int/float, string with string, etc.)\n3. Convert types explicitly if needed (e.g., int(user.age) or string(user.id))".into()
-                    ),
-                    fix_example: Some(format!(
-                        "# Example fix - ensure types match:\nrules:\n  - id: {}\n    when: \"# Fix: Use compatible types\"\n    then: ...",
-                        rule.id
-                    )),
-                    context: Some(IssueContext {
-                        cel_expressions: Some(vec![when.clone()]),
-                        variables: if vars_in_expr.is_empty() { None } else { Some(vars_in_expr) },
-                        type_info,
-                        example_input: None,
-                        current_behavior: Some("Expression will fail to evaluate due to type mismatch".into()),
-                        expected_behavior: Some("Expression should compare compatible types".into()),
-                    }),
-                });
-            }
-        }
-    }
-
-    issues
-}
-
-/// Check CEL expression for type mismatches
-fn check_cel_types(
-    expr: &str,
-    var_types: &HashMap<String, crate::spec::VarType>,
-) -> Result<(), String> {
-    // Parse the expression
-    let ast = match cel_parser::Parser::new().parse(expr) {
-        Ok(ast) => ast,
-        Err(_) => return Ok(()), // Can't parse - skip type checking
-    };
-
-    // Walk AST and check types
-    check_ast_types(&ast, var_types)
-}
-
-/// Recursively check AST for type mismatches
-fn check_ast_types(
-    expr: &cel_parser::Expression,
-    var_types: &HashMap<String, crate::spec::VarType>,
-) -> Result<(), String> {
-    use cel_parser::ast::operators;
-    use cel_parser::ast::Expr as E;
-
-    match &expr.expr {
-        E::Ident(id) => {
-            // Check if variable exists and has valid type
-            if let Some(_var_type) = var_types.get(id.as_str()) {
-                Ok(())
-            } else {
-                Err(format!("Unknown variable: {}", id))
-            }
-        }
-        E::Call(call) => {
-            // Check if this is a relation operator
-            if call.func_name == operators::EQUALS || call.func_name == operators::NOT_EQUALS {
-                if call.args.len() == 2 {
-                    check_same_types(&call.args[0], &call.args[1], var_types)?;
-                }
-            } else if call.func_name == operators::GREATER
-                || call.func_name == operators::LESS
-                || call.func_name == operators::GREATER_EQUALS
-                || call.func_name == operators::LESS_EQUALS
-            {
-                if call.args.len() == 2 {
-                    check_comparable_types(&call.args[0], &call.args[1], var_types)?;
-                }
-            } else if call.func_name == operators::LOGICAL_AND
-                || call.func_name == operators::LOGICAL_OR
-            {
-                if call.args.len() == 2 {
-                    check_ast_types(&call.args[0], var_types)?;
-                    check_ast_types(&call.args[1], var_types)?;
-                }
-            } else if call.func_name == operators::LOGICAL_NOT {
-                if let Some(operand) = call.args.first() {
-                    check_ast_types(operand, var_types)?;
-                }
-            }
-            Ok(())
-        }
-        _ => Ok(()),
-    }
-}
-
-/// Check if two expressions have comparable types
-fn check_comparable_types(
-    left: &cel_parser::Expression,
-    right: &cel_parser::Expression,
-    var_types: &HashMap<String, crate::spec::VarType>,
-) -> Result<(), String> {
-    let left_type = infer_type(left, var_types);
-    let right_type = infer_type(right, var_types);
-
-    match (left_type, right_type) {
-        (Some(crate::spec::VarType::Int), Some(crate::spec::VarType::Int)) => Ok(()),
-        (Some(crate::spec::VarType::Float), Some(crate::spec::VarType::Float)) => Ok(()),
-        (Some(crate::spec::VarType::Int), Some(crate::spec::VarType::Float)) => Ok(()),
-        (Some(crate::spec::VarType::Float), Some(crate::spec::VarType::Int)) => Ok(()),
-        (Some(l), Some(r)) => Err(format!(
-            "Cannot compare {} with {}",
-            format_type(&l),
-            format_type(&r)
-        )),
-        _ => Ok(()), // Can't infer - skip
-    }
-}
-
-/// Check if two expressions have the same type
-fn check_same_types(
-    left: &cel_parser::Expression,
-    right: &cel_parser::Expression,
-    var_types: &HashMap<String, crate::spec::VarType>,
-) -> Result<(), String> {
-    let left_type = infer_type(left, var_types);
-    let right_type = infer_type(right, var_types);
-
-    if let (Some(l), Some(r)) = (left_type, right_type) {
-        if l != r {
-            return Err(format!(
-                "Type mismatch: {} vs {}",
-                format_type(&l),
-                format_type(&r)
-            ));
-        }
-    }
-
-    Ok(())
-}
-
-/// Infer the type of an expression
-fn infer_type(
-    expr: &cel_parser::Expression,
-    var_types: &HashMap<String, crate::spec::VarType>,
-) -> Option<crate::spec::VarType> {
-    use cel_parser::ast::Expr as E;
-    use cel_parser::reference::Val;
-
-    match &expr.expr {
-        E::Ident(id) => var_types.get(id.as_str()).cloned(),
-        E::Literal(val) => match val {
-            Val::Int(_) | Val::UInt(_) => Some(crate::spec::VarType::Int),
-            Val::Double(_) => Some(crate::spec::VarType::Float),
-            Val::String(_) => Some(crate::spec::VarType::String),
-            Val::Boolean(_) => Some(crate::spec::VarType::Bool),
-            _ => None,
-        },
-        _ => None,
-    }
-}
-
-/// Format a type for error messages
-fn format_type(typ: &crate::spec::VarType) -> &str {
-    match typ {
-        crate::spec::VarType::Int => "int",
-        crate::spec::VarType::Float => "float",
-        crate::spec::VarType::String => "string",
-        crate::spec::VarType::Bool => "bool",
-        crate::spec::VarType::Enum(_) => "enum",
-        crate::spec::VarType::List(_) => "list",
-        crate::spec::VarType::Object => "object",
-    }
-}
-
-/// Extract type mismatch information from a CEL expression
-fn extract_type_mismatch_info(
-    _expr: &str,
-    _var_types: &HashMap<String, crate::spec::VarType>,
-) -> Option<String> {
-    // Placeholder - could be enhanced to provide detailed type info
-    None
+fn detect_type_mismatches(spec: &Spec) -> Vec<ValidationIssue> {
+    spec.typecheck()
+        .into_iter()
+        .map(|error| ValidationIssue {
+            code: "TYPE_MISMATCH".into(),
+            severity: Severity::Error,
+            issue_type: IssueType::TypeMismatch,
+            message: format!("Type mismatch in rule {}: {}", error.rule_id, error.message),
+            affected_rules: vec![error.rule_id.clone()],
+            explanation: Some(format!(
+                "The condition '{}' {}. This will cause a runtime error when evaluating the expression.",
+                error.expression, error.message
+            )),
+            suggestion: Some(
+                "1. Check the variable types in your spec inputs\n2. Ensure comparisons use compatible types (int with int/float, string with string, etc.)\n3. Declare every variable the condition references as an input".into()
+            ),
+            fix_example: Some(format!(
+                "# Example fix - ensure types match:\nrules:\n  - id: {}\n    when: \"# Fix: Use compatible types\"\n    then: ...",
+                error.rule_id
+            )),
+            context: Some(IssueContext {
+                cel_expressions: Some(vec![error.expression]),
+                variables: if error.variables.is_empty() {
+                    None
+                } else {
+                    Some(error.variables)
+                },
+                type_info: Some(error.message),
+                example_input: None,
+                current_behavior: Some("Expression will fail to evaluate due to type mismatch".into()),
+                expected_behavior: Some("Expression should compare compatible types".into()),
+            }),
+        })
+        .collect()
 }
 
-/// Detect unsatisfiable conditions (can never be true)
-fn detect_unsatisfiable(spec: &Spec, code_counter: &mut usize) -> Vec<ValidationIssue> {
-    let mut issues = Vec::new();
-
-    // Build predicate set
+/// Detect conditions that are constant over every input: always false
+/// (`x && !x`, the rule is dead) or always true (`x || !x`, later rules are
+/// shadowed)
+///
+/// Each condition is evaluated under every feasible assignment of the
+/// predicates the rules test. Sub-expressions that are not one of those
+/// predicates are unknown, so a condition is only reported when its value
+/// does not depend on them. A condition that tests no predicate at all,
+/// such as a literal `true` catch-all, is taken as intended.
+fn detect_constant_conditions(spec: &Spec) -> Vec<ValidationIssue> {
+    use super::predicates::expand_enum_predicates;
+
+    // Build predicate set, keeping `x < 5` and `x >= 5` on a single bit
     let mut predicate_set = PredicateSet::new();
     for rule in &spec.rules {
         if let Some(cel_expr) = rule.as_cel() {
             if let Ok(preds) = extract_predicates(&cel_expr) {
                 for pred in preds {
-                    predicate_set.add(pred);
+                    if predicate_set.index_of(&pred.negated()).is_none() {
+                        predicate_set.add(pred);
+                    }
                 }
             }
         }
     }
+    let enum_domains = expand_enum_predicates(&spec.inputs, &mut predicate_set);
 
-    // Check each rule
-    for rule in &spec.rules {
-        if let Some(cel_expr) = rule.as_cel() {
-            // Convert to cover
-            let rule_cover = rules_to_cover(std::slice::from_ref(rule), &predicate_set);
+    // Same budget as completeness analysis
+    if predicate_set.is_empty() || predicate_set.len() > 20 {
+        return Vec::new();
+    }
+    let feasible: Vec<u64> = (0..1u64 << predicate_set.len())
+        .filter(|&combo| {
+            enum_domains
+                .iter()
+                .all(|d| d.selected_variant(combo, &predicate_set).is_some())
+        })
+        .collect();
 
-            // Check if cover has zero minterms (unsatisfiable)
-            if !predicate_set.is_empty() {
-                let combinations_covered =
-                    count_combinations_in_cover(&rule_cover, predicate_set.len());
-                if combinations_covered == 0 {
-                    issues.push(ValidationIssue {
-                        code: format!("V{:03}", {
-                            let c = *code_counter;
-                            *code_counter += 1;
-                            c
-                        }),
-                        severity: Severity::Error,
-                        issue_type: IssueType::UnsatisfiableCondition,
-                        message: format!(
-                            "Rule {} has unsatisfiable condition: {}",
-                            rule.id, cel_expr
-                        ),
-                        affected_rules: vec![rule.id.clone()],
-                        explanation: None,
-                        suggestion: Some("Fix the condition logic - it can never be true".into()),
-                        fix_example: None,
-                        context: None,
-                    });
-                }
-            }
+    let mut issues = Vec::new();
+    for rule in &spec.rules {
+        let Some(cel_expr) = rule.as_cel() else {
+            continue;
+        };
+        if extract_predicates(&cel_expr).map_or(true, |preds| preds.is_empty()) {
+            continue;
+        }
+        let Ok(ast) = crate::cel::CelCompiler::parse(&cel_expr) else {
+            continue;
+        };
+        let values: Vec<Option<bool>> = feasible
+            .iter()
+            .map(|&combo| condition_value(&ast, combo, &predicate_set))
+            .collect();
+
+        if values.iter().all(|v| *v == Some(false)) {
+            issues.push(ValidationIssue {
+                code: "ALWAYS_FALSE".into(),
+                severity: Severity::Error,
+                issue_type: IssueType::UnsatisfiableCondition,
+                message: format!(
+                    "Rule {} can never match, its condition is always false: {}",
+                    rule.id, cel_expr
+                ),
+                affected_rules: vec![rule.id.clone()],
+                explanation: Some(
+                    "No input satisfies the condition, so the rule is dead and its output is never returned.".into(),
+                ),
+                suggestion: Some("Fix the condition logic - it can never be true".into()),
+                fix_example: None,
+                context: Some(IssueContext {
+                    cel_expressions: Some(vec![cel_expr]),
+                    variables: None,
+                    type_info: None,
+                    example_input: None,
+                    current_behavior: Some("Condition is false for every input".into()),
+                    expected_behavior: None,
+                }),
+            });
+        } else if values.iter().all(|v| *v == Some(true)) {
+            // Under first-match every rule evaluated after this one is shadowed
+            let shadowed: Vec<String> = if spec.mode == crate::spec::SpecMode::FirstMatch {
+                spec.rules_in_order()
+                    .into_iter()
+                    .skip_while(|other| !std::ptr::eq(*other, rule))
+                    .skip(1)
+                    .map(|other| other.id.clone())
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            let message = if shadowed.is_empty() {
+                format!(
+                    "Rule {} always matches, its condition is always true: {}",
+                    rule.id, cel_expr
+                )
+            } else {
+                format!(
+                    "Rule {} always matches, its condition is always true: {} (shadows {})",
+                    rule.id,
+                    cel_expr,
+                    shadowed.join(", ")
+                )
+            };
+            let mut affected_rules = vec![rule.id.clone()];
+            affected_rules.extend(shadowed.iter().cloned());
+            issues.push(ValidationIssue {
+                code: "ALWAYS_TRUE".into(),
+                severity: Severity::Warning,
+                issue_type: IssueType::TautologyCondition,
+                message,
+                affected_rules,
+                explanation: Some(if shadowed.is_empty() {
+                    "Every input satisfies the condition, so it is a catch-all in disguise.".into()
+                } else {
+                    "Every input satisfies the condition, so the rules evaluated after it can never fire.".into()
+                }),
+                suggestion: Some(
+                    "Consider removing the condition or marking as default rule".into(),
+                ),
+                fix_example: None,
+                context: Some(IssueContext {
+                    cel_expressions: Some(vec![cel_expr]),
+                    variables: None,
+                    type_info: None,
+                    example_input: None,
+                    current_behavior: Some("Condition is true for every input".into()),
+                    expected_behavior: None,
+                }),
+            });
         }
     }
 
     issues
 }
 
-/// Detect tautology conditions (always match)
-fn detect_tautologies(spec: &Spec, code_counter: &mut usize) -> Vec<ValidationIssue> {
-    let mut issues = Vec::new();
+/// Value of a condition under one predicate combination
+///
+/// Returns `None` when the value depends on something outside the
+/// predicate set; `&&` and `||` still decide when one side does.
+fn condition_value(
+    expr: &cel_parser::Expression,
+    combo: u64,
+    predicate_set: &PredicateSet,
+) -> Option<bool> {
+    use super::predicates::{extract_relation_from_call, Predicate};
+    use cel_parser::ast::operators;
+    use cel_parser::ast::Expr as E;
+    use cel_parser::reference::Val;
 
-    // Build predicate set
-    let mut predicate_set = PredicateSet::new();
-    for rule in &spec.rules {
-        if let Some(cel_expr) = rule.as_cel() {
-            if let Ok(preds) = extract_predicates(&cel_expr) {
-                for pred in preds {
-                    predicate_set.add(pred);
-                }
-            }
+    let bit = |pred: &Predicate| {
+        if let Some(idx) = predicate_set.index_of(pred) {
+            Some((combo >> idx) & 1 == 1)
+        } else {
+            let idx = predicate_set.index_of(&pred.negated())?;
+            Some((combo >> idx) & 1 == 0)
         }
-    }
-
-    let total_combinations = 1u64 << predicate_set.len();
-
-    // Check each rule
-    for rule in &spec.rules {
-        if let Some(cel_expr) = rule.as_cel() {
-            // Count how many combinations this rule covers
-            let cover = rules_to_cover(std::slice::from_ref(rule), &predicate_set);
-            let combinations_covered = count_combinations_in_cover(&cover, predicate_set.len());
+    };
 
-            // If it covers all combinations, it's a tautology
-            if combinations_covered == total_combinations && total_combinations > 0 {
-                issues.push(ValidationIssue {
-                    code: format!("V{:03}", {
-                        let c = *code_counter;
-                        *code_counter += 1;
-                        c
-                    }),
-                    severity: Severity::Warning,
-                    issue_type: IssueType::TautologyCondition,
-                    message: format!("Rule {} always matches (tautology): {}", rule.id, cel_expr),
-                    affected_rules: vec![rule.id.clone()],
-                    explanation: None,
-                    suggestion: Some(
-                        "Consider removing the condition or marking as default rule".into(),
-                    ),
-                    fix_example: None,
-                    context: None,
-                });
+    match &expr.expr {
+        E::Literal(Val::Boolean(b)) => Some(*b),
+        E::Ident(name) => match name.as_str() {
+            "true" => Some(true),
+            "false" => Some(false),
+            name => bit(&Predicate::BoolVar(name.to_string())),
+        },
+        E::Call(call) if call.func_name == operators::LOGICAL_NOT => {
+            condition_value(call.args.first()?, combo, predicate_set).map(|v| !v)
+        }
+        E::Call(call)
+            if call.args.len() == 2
+                && (call.func_name == operators::LOGICAL_AND
+                    || call.func_name == operators::LOGICAL_OR) =>
+        {
+            // The value that decides the operator on its own
+            let decisive = call.func_name == operators::LOGICAL_OR;
+            let left = condition_value(&call.args[0], combo, predicate_set);
+            let right = condition_value(&call.args[1], combo, predicate_set);
+            match (left, right) {
+                (Some(v), _) | (_, Some(v)) if v == decisive => Some(decisive),
+                (Some(_), Some(_)) => Some(!decisive),
+                _ => None,
             }
         }
+        E::Call(call) => bit(&extract_relation_from_call(call, false)?),
+        _ => None,
     }
-
-    issues
 }
 
 /// Count combinations covered by a cover
@@ -833,8 +901,12 @@ fn union_covers(cover_a: &Cover, cover_b: &Cover) -> Cover {
     result
 }
 
-/// Detect contradictory rules (same condition, different outputs, no priority)
-fn detect_contradictions(spec: &Spec, code_counter: &mut usize) -> Vec<ValidationIssue> {
+/// Detect rules whose conditions overlap but whose outputs differ
+///
+/// Equal-priority overlaps are `CONFLICT` errors: nothing decides which rule
+/// wins. When priorities differ the rule tried first (the lower priority)
+/// wins, so the overlap is a `SHADOWED` warning on the other rule.
+fn detect_contradictions(spec: &Spec) -> Vec<ValidationIssue> {
     let mut issues = Vec::new();
 
     // Build predicate set
@@ -852,43 +924,494 @@ fn detect_contradictions(spec: &Spec, code_counter: &mut usize) -> Vec<Validatio
     // Compare all pairs of rules
     for (i, rule_a) in spec.rules.iter().enumerate() {
         for rule_b in spec.rules.iter().skip(i + 1) {
-            if rule_a.as_cel().is_some() && rule_b.as_cel().is_some() {
-                // Check if they have overlapping covers
-                let cover_a = rules_to_cover(std::slice::from_ref(rule_a), &predicate_set);
-                let cover_b = rules_to_cover(std::slice::from_ref(rule_b), &predicate_set);
-
-                if covers_intersect(&cover_a, &cover_b, predicate_set.len()) {
-                    // Check if outputs differ and priorities are same
-                    if rule_a.then != rule_b.then && rule_a.priority == rule_b.priority {
-                        issues.push(ValidationIssue {
-                            code: format!("V{:03}", {
-                                let c = *code_counter;
-                                *code_counter += 1;
-                                c
-                            }),
-                            severity: Severity::Error,
-                            issue_type: IssueType::ContradictoryRules,
-                            message: format!(
-                                "Contradictory rules: {} and {} match same inputs with different outputs",
-                                rule_a.id, rule_b.id
-                            ),
-                            affected_rules: vec![rule_a.id.clone(), rule_b.id.clone()],
-                            explanation: None,
-                            suggestion: Some(
-                                "Set priority on one rule or merge with conditional output".into(),
-                            ),
-                            fix_example: None,
-                            context: None,
-                        });
-                    }
+            if rule_a.as_cel().is_none() || rule_b.as_cel().is_none() || rule_a.then == rule_b.then
+            {
+                continue;
+            }
+
+            let cover_a = rules_to_cover(std::slice::from_ref(rule_a), &predicate_set);
+            let cover_b = rules_to_cover(std::slice::from_ref(rule_b), &predicate_set);
+            if !covers_intersect(&cover_a, &cover_b, predicate_set.len()) {
+                continue;
+            }
+
+            let overlap = cover_to_cel(&cover_a.intersect(&cover_b), &predicate_set).join(" || ");
+            let behavior = format!(
+                "{} returns {}, {} returns {}",
+                rule_a.id, rule_a.then, rule_b.id, rule_b.then
+            );
+            let context = Some(IssueContext {
+                cel_expressions: Some(vec![overlap.clone()]),
+                variables: None,
+                type_info: None,
+                example_input: None,
+                current_behavior: Some(behavior),
+                expected_behavior: None,
+            });
+
+            if rule_a.priority == rule_b.priority {
+                issues.push(ValidationIssue {
+                    code: "CONFLICT".into(),
+                    severity: Severity::Error,
+                    issue_type: IssueType::ContradictoryRules,
+                    message: format!(
+                        "Conflicting rules: {} and {} both match when {} ({} vs {})",
+                        rule_a.id, rule_b.id, overlap, rule_a.then, rule_b.then
+                    ),
+                    affected_rules: vec![rule_a.id.clone(), rule_b.id.clone()],
+                    explanation: Some(
+                        "Both rules have the same priority, so the winner depends on rule order in each generated implementation.".into(),
+                    ),
+                    suggestion: Some(
+                        "Set priority on one rule or merge with conditional output".into(),
+                    ),
+                    fix_example: None,
+                    context,
+                });
+            } else {
+                let (winner, loser) = if Rule::evaluation_order(rule_a, rule_b).is_lt() {
+                    (rule_a, rule_b)
+                } else {
+                    (rule_b, rule_a)
+                };
+                issues.push(ValidationIssue {
+                    code: "SHADOWED".into(),
+                    severity: Severity::Warning,
+                    issue_type: IssueType::ShadowedRule,
+                    message: format!(
+                        "Rule {} (priority {}) shadows rule {} (priority {}) when {}",
+                        winner.id, winner.priority, loser.id, loser.priority, overlap
+                    ),
+                    affected_rules: vec![loser.id.clone(), winner.id.clone()],
+                    explanation: None,
+                    suggestion: Some(format!(
+                        "Narrow rule {}'s condition if it should not apply when {}",
+                        loser.id, overlap
+                    )),
+                    fix_example: None,
+                    context,
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+/// Detect rules that one earlier-tried rule fully subsumes
+///
+/// Rules are tried lowest priority first, rule order breaking ties. A rule
+/// whose condition implies the condition of a rule tried before it can never
+/// fire. Unlike `SHADOWED`, which flags any overlap with a different output,
+/// this needs full subsumption and ignores outputs.
+fn detect_unreachable(spec: &Spec) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    let mut predicate_set = PredicateSet::new();
+    for rule in &spec.rules {
+        if let Some(cel_expr) = rule.as_cel() {
+            if let Ok(preds) = extract_predicates(&cel_expr) {
+                for pred in preds {
+                    predicate_set.add(pred);
                 }
             }
         }
     }
+    let num_predicates = predicate_set.len();
+
+    let mut order: Vec<&Rule> = spec.rules.iter().filter(|r| r.as_cel().is_some()).collect();
+    order.sort_by(|a, b| Rule::evaluation_order(a, b));
+    let covers: Vec<Cover> = order
+        .iter()
+        .map(|rule| rules_to_cover(std::slice::from_ref(*rule), &predicate_set))
+        .collect();
+
+    for (idx, rule) in order.iter().enumerate() {
+        // Unsatisfiable rules are reported on their own
+        if count_combinations_in_cover(&covers[idx], num_predicates) == 0 {
+            continue;
+        }
+        let Some(shadow) =
+            (0..idx).find(|&earlier| covers_cover(&covers[earlier], &covers[idx], num_predicates))
+        else {
+            continue;
+        };
+        let shadow = order[shadow];
+
+        issues.push(ValidationIssue {
+            code: "UNREACHABLE_RULE".into(),
+            severity: Severity::Warning,
+            issue_type: IssueType::UnreachableRule,
+            message: format!(
+                "Rule {} is unreachable: rule {} (priority {}) matches whenever it does",
+                rule.id, shadow.id, shadow.priority
+            ),
+            affected_rules: vec![rule.id.clone(), shadow.id.clone()],
+            explanation: Some(format!(
+                "Rules are tried lowest priority first, and {}'s condition implies {}'s.",
+                rule.id, shadow.id
+            )),
+            suggestion: Some(format!(
+                "Delete rule {}, or give it a lower priority than {} if it should win",
+                rule.id, shadow.id
+            )),
+            fix_example: None,
+            context: Some(IssueContext {
+                cel_expressions: Some(vec![
+                    rule.as_cel().unwrap_or_default(),
+                    shadow.as_cel().unwrap_or_default(),
+                ]),
+                variables: None,
+                type_info: None,
+                example_input: None,
+                current_behavior: Some(format!("{} always returns {}", shadow.id, shadow.then)),
+                expected_behavior: None,
+            }),
+        });
+    }
+
+    issues
+}
+
+/// Detect `==`/`!=` comparisons on float inputs, in structured conditions
+/// and in CEL `when` clauses
+fn detect_float_equality(spec: &Spec) -> Vec<ValidationIssue> {
+    use crate::spec::{ConditionOp, VarType};
+
+    let float_vars: Vec<&str> = spec
+        .inputs
+        .iter()
+        .filter(|v| v.typ == VarType::Float)
+        .map(|v| v.name.as_str())
+        .collect();
+    if float_vars.is_empty() {
+        return Vec::new();
+    }
+
+    let mut issues = Vec::new();
+    for rule in &spec.rules {
+        let mut offending: Vec<String> = Vec::new();
+        if let Some(when) = &rule.when {
+            if let Ok(ast) = crate::cel::CelCompiler::parse(&when.to_cel()) {
+                collect_float_equality(&ast, &float_vars, &mut offending);
+            }
+        } else if let Some(conditions) = &rule.conditions {
+            for cond in conditions {
+                if matches!(cond.op, ConditionOp::Eq | ConditionOp::Ne)
+                    && float_vars.contains(&cond.var.as_str())
+                    && !offending.contains(&cond.var)
+                {
+                    offending.push(cond.var.clone());
+                }
+            }
+        }
+
+        for var in offending {
+            issues.push(ValidationIssue {
+                code: "FLOAT_EQUALITY".into(),
+                severity: Severity::Warning,
+                issue_type: IssueType::FloatEquality,
+                message: format!(
+                    "Rule {} compares float input '{}' with ==/!=",
+                    rule.id, var
+                ),
+                affected_rules: vec![rule.id.clone()],
+                explanation: Some(
+                    "Floating-point values rarely compare exactly equal after arithmetic or parsing, so this rule may silently never match.".into(),
+                ),
+                suggestion: Some(format!(
+                    "Use a range condition instead, e.g. {var} >= low && {var} < high"
+                )),
+                fix_example: Some(format!(
+                    "rules:\n  - id: {}\n    when: \"{} >= 9.985 && {} < 9.995\"\n    then: ...",
+                    rule.id, var, var
+                )),
+                context: Some(IssueContext {
+                    cel_expressions: rule.as_cel().map(|c| vec![c]),
+                    variables: Some(vec![var]),
+                    type_info: Some("float".into()),
+                    example_input: None,
+                    current_behavior: None,
+                    expected_behavior: None,
+                }),
+            });
+        }
+    }
+
+    issues
+}
+
+/// Detect first-match specs that leave inputs uncovered with no `default`,
+/// unless `on_no_match` already says to panic or error
+fn detect_missing_default(spec: &Spec) -> Option<ValidationIssue> {
+    use crate::spec::{OnNoMatch, SpecMode};
+
+    if spec.default.is_some()
+        || spec.rules.is_empty()
+        || spec.mode != SpecMode::FirstMatch
+        || matches!(spec.on_no_match, Some(OnNoMatch::Panic | OnNoMatch::Error))
+    {
+        return None;
+    }
+
+    let report = super::analyze_completeness(spec);
+    if report.is_complete {
+        return None;
+    }
+
+    let uncovered: Vec<String> = report
+        .missing_cases
+        .iter()
+        .map(|case| case.cel_conditions.join(" && "))
+        .collect();
+    let example = modal_output(spec)
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| "...".into());
+
+    Some(ValidationIssue {
+        code: "MISSING_DEFAULT".into(),
+        severity: Severity::Warning,
+        issue_type: IssueType::MissingDefault,
+        message: format!(
+            "Spec has no default but {} input combination(s) match no rule",
+            uncovered.len()
+        ),
+        affected_rules: vec![],
+        explanation: Some(
+            "Generated code panics when no rule matches, so these inputs fail at runtime.".into(),
+        ),
+        suggestion: Some(
+            "Add rules for the uncovered inputs, add a default output, or set on_no_match: panic if they cannot occur".into(),
+        ),
+        fix_example: Some(format!("default: {}", example)),
+        context: Some(IssueContext {
+            cel_expressions: Some(uncovered),
+            variables: None,
+            type_info: None,
+            example_input: None,
+            current_behavior: Some("No rule matches; generated code panics".into()),
+            expected_behavior: Some("Every input produces an output".into()),
+        }),
+    })
+}
+
+/// Detect inputs that no rule condition, output expression or default
+/// refers to
+fn detect_unused_inputs(spec: &Spec) -> Vec<ValidationIssue> {
+    use crate::cel::CelCompiler;
+    use crate::spec::{ConditionValue, Output};
+
+    if spec.rules.is_empty() {
+        return Vec::new();
+    }
+
+    // Every CEL the spec evaluates: rule guards, then output expressions
+    let mut cel: Vec<String> = spec.rules.iter().filter_map(|r| r.as_cel()).collect();
+    let outputs = spec.rules.iter().map(|r| &r.then).chain(&spec.default);
+    for output in outputs {
+        let values: Vec<&ConditionValue> = match output {
+            Output::Single(value) => vec![value],
+            Output::Named(map) => map.values().collect(),
+        };
+        cel.extend(values.into_iter().filter_map(|value| match value {
+            ConditionValue::String(s) if crate::render::is_expression(s) => Some(s.clone()),
+            _ => None,
+        }));
+    }
+    let used: Vec<String> = cel
+        .iter()
+        .filter_map(|expr| CelCompiler::extract_variables(expr).ok())
+        .flatten()
+        .collect();
+
+    spec.inputs
+        .iter()
+        .filter(|input| !used.contains(&input.name))
+        .map(|input| ValidationIssue {
+            code: "UNUSED_INPUT".into(),
+            severity: Severity::Warning,
+            issue_type: IssueType::UnusedInput,
+            message: format!("Input '{}' is not used by any rule", input.name),
+            affected_rules: vec![],
+            explanation: Some(
+                "No rule condition, output or default reads this input, so it cannot change the result; often a rule that should use it is missing.".into(),
+            ),
+            suggestion: Some(format!(
+                "Add a rule that uses '{}', or remove it from inputs",
+                input.name
+            )),
+            fix_example: None,
+            context: Some(IssueContext {
+                cel_expressions: None,
+                variables: Some(vec![input.name.clone()]),
+                type_info: None,
+                example_input: None,
+                current_behavior: None,
+                expected_behavior: None,
+            }),
+        })
+        .collect()
+}
+
+/// Detect rules comparing an int input that declares a `range` against a
+/// literal outside that range
+fn detect_out_of_range(spec: &Spec) -> Vec<ValidationIssue> {
+    use super::predicates::{LiteralValue, Predicate};
+
+    let mut issues = Vec::new();
+    for rule in &spec.rules {
+        let Some(cel) = rule.as_cel() else { continue };
+        let Ok(predicates) = extract_predicates(&cel) else {
+            continue;
+        };
+        for pred in predicates {
+            let (var, literals) = match &pred {
+                Predicate::Comparison { var, value, .. }
+                | Predicate::Equality { var, value, .. } => (var, vec![value]),
+                Predicate::Membership { var, values, .. } => (var, values.iter().collect()),
+                _ => continue,
+            };
+            let Some(range) = spec
+                .inputs
+                .iter()
+                .find(|input| input.name == *var)
+                .and_then(|input| input.range_values())
+            else {
+                continue;
+            };
+            let outside = literals.into_iter().find(|literal| match literal {
+                LiteralValue::Int(n) => !range.contains(n),
+                LiteralValue::Float(f) => *f < *range.start() as f64 || *f > *range.end() as f64,
+                _ => false,
+            });
+            let Some(outside) = outside else { continue };
+
+            issues.push(ValidationIssue {
+                code: "OUT_OF_RANGE".into(),
+                severity: Severity::Warning,
+                issue_type: IssueType::OutOfRange,
+                message: format!(
+                    "Rule {} compares '{}' with {}, outside its range [{}, {}]",
+                    rule.id,
+                    var,
+                    outside,
+                    range.start(),
+                    range.end()
+                ),
+                affected_rules: vec![rule.id.clone()],
+                explanation: Some(
+                    "The input never takes this value, so the comparison is constant and the rule either never matches or always does.".into(),
+                ),
+                suggestion: Some(format!(
+                    "Compare '{}' with a value in [{}, {}], or widen its declared range",
+                    var,
+                    range.start(),
+                    range.end()
+                )),
+                fix_example: None,
+                context: Some(IssueContext {
+                    cel_expressions: Some(vec![pred.to_cel_string()]),
+                    variables: Some(vec![var.clone()]),
+                    type_info: Some(format!("int in [{}, {}]", range.start(), range.end())),
+                    example_input: None,
+                    current_behavior: None,
+                    expected_behavior: None,
+                }),
+            });
+        }
+    }
 
     issues
 }
 
+/// Detect a spec with more rules than the project allows
+fn detect_too_many_rules(spec: &Spec, max_rules: usize) -> Option<ValidationIssue> {
+    if spec.rules.len() <= max_rules {
+        return None;
+    }
+
+    Some(ValidationIssue {
+        code: "TOO_MANY_RULES".into(),
+        severity: Severity::Warning,
+        issue_type: IssueType::TooManyRules,
+        message: format!(
+            "Spec has {} rules, more than the configured maximum of {}",
+            spec.rules.len(),
+            max_rules
+        ),
+        affected_rules: vec![],
+        explanation: Some(
+            "Large decision tables are hard to review and usually mix several decisions.".into(),
+        ),
+        suggestion: Some(
+            "Decompose the spec: split it by output with `imacs split`, or chain smaller specs in an orchestrator".into(),
+        ),
+        fix_example: None,
+        context: Some(IssueContext {
+            cel_expressions: None,
+            variables: None,
+            type_info: None,
+            example_input: None,
+            current_behavior: Some(format!("{} rules", spec.rules.len())),
+            expected_behavior: Some(format!(
+                "At most {} rules (validation.max_rules_per_spec)",
+                max_rules
+            )),
+        }),
+    })
+}
+
+/// Detect rules without a `description`
+fn detect_missing_descriptions(spec: &Spec) -> Vec<ValidationIssue> {
+    spec.rules
+        .iter()
+        .filter(|rule| rule.description.as_deref().is_none_or(|d| d.trim().is_empty()))
+        .map(|rule| ValidationIssue {
+            code: "MISSING_DESCRIPTION".into(),
+            severity: Severity::Warning,
+            issue_type: IssueType::MissingDescription,
+            message: format!("Rule {} has no description", rule.id),
+            affected_rules: vec![rule.id.clone()],
+            explanation: Some(
+                "The project sets validation.require_descriptions; descriptions end up in generated code and docs.".into(),
+            ),
+            suggestion: Some(format!("Say why rule {} exists in a `description`", rule.id)),
+            fix_example: Some(format!(
+                "- id: {}\n  description: \"...\"",
+                rule.id
+            )),
+            context: None,
+        })
+        .collect()
+}
+
+/// Collect float variables compared with `==`/`!=` anywhere in a CEL AST
+fn collect_float_equality(
+    expr: &cel_parser::Expression,
+    float_vars: &[&str],
+    offending: &mut Vec<String>,
+) {
+    use cel_parser::ast::operators;
+    use cel_parser::ast::Expr as E;
+
+    if let E::Call(call) = &expr.expr {
+        if call.func_name == operators::EQUALS || call.func_name == operators::NOT_EQUALS {
+            for arg in &call.args {
+                if let E::Ident(id) = &arg.expr {
+                    if float_vars.contains(&id.as_str())
+                        && !offending.iter().any(|v| v.as_str() == id.as_str())
+                    {
+                        offending.push(id.to_string());
+                    }
+                }
+            }
+        }
+        for arg in &call.args {
+            collect_float_equality(arg, float_vars, offending);
+        }
+    }
+}
+
 /// Check if two covers intersect (have overlapping minterms)
 fn covers_intersect(cover_a: &Cover, cover_b: &Cover, num_predicates: usize) -> bool {
     let total = 1u64 << num_predicates;
@@ -915,15 +1438,24 @@ mod tests {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             outputs: vec![crate::spec::Variable {
                 name: "result".into(),
                 typ: VarType::Int,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
             rules: vec![],
             default: None,
+            mode: Default::default(),
+            on_no_match: None,
+            ensures: Vec::new(),
+            allow_bitwise: false,
+            schema_version: None,
             meta: Default::default(),
             scoping: None,
         }
@@ -965,4 +1497,265 @@ mod tests {
             .iter()
             .any(|i| matches!(i.issue_type, IssueType::ContradictoryRules)));
     }
+
+    fn overlapping_rule(id: &str, when: &str, then: i64, priority: i32) -> Rule {
+        Rule {
+            id: id.into(),
+            when: Some(WhenClause::from(when)),
+            conditions: None,
+            then: Output::Single(ConditionValue::Int(then)),
+            priority,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_equal_priority_overlap_is_conflict() {
+        let mut spec = make_test_spec();
+        spec.inputs.push(crate::spec::Variable {
+            name: "b".into(),
+            typ: VarType::Bool,
+            description: None,
+            values: None,
+            optional: false,
+            range: None,
+        });
+        spec.rules = vec![
+            overlapping_rule("R1", "a", 1, 0),
+            overlapping_rule("R2", "b", 2, 0),
+        ];
+
+        let report = validate_spec(&spec, false);
+        assert!(!report.is_valid);
+        let conflict = report.issues.iter().find(|i| i.code == "CONFLICT").unwrap();
+        assert!(matches!(conflict.severity, Severity::Error));
+        assert_eq!(conflict.affected_rules, vec!["R1", "R2"]);
+        assert!(conflict.message.contains("a && b"));
+        assert!(conflict.message.contains("(1 vs 2)"));
+    }
+
+    #[test]
+    fn test_priority_overlap_is_shadowing_warning() {
+        let mut spec = make_test_spec();
+        spec.inputs.push(crate::spec::Variable {
+            name: "b".into(),
+            typ: VarType::Bool,
+            description: None,
+            values: None,
+            optional: false,
+            range: None,
+        });
+        spec.rules = vec![
+            overlapping_rule("R1", "a", 1, 0),
+            overlapping_rule("R2", "b", 2, 10),
+        ];
+
+        let report = validate_spec(&spec, false);
+        assert!(report.is_valid);
+        assert!(!report.issues.iter().any(|i| i.code == "CONFLICT"));
+        let shadowed = report.issues.iter().find(|i| i.code == "SHADOWED").unwrap();
+        assert!(matches!(shadowed.severity, Severity::Warning));
+        assert!(matches!(shadowed.issue_type, IssueType::ShadowedRule));
+        assert_eq!(shadowed.affected_rules, vec!["R2", "R1"]);
+        assert!(shadowed
+            .message
+            .starts_with("Rule R1 (priority 0) shadows rule R2"));
+    }
+
+    #[test]
+    fn test_implied_rule_is_unreachable() {
+        let mut spec = make_test_spec();
+        spec.inputs.push(crate::spec::Variable {
+            name: "b".into(),
+            typ: VarType::Bool,
+            description: None,
+            values: None,
+            optional: false,
+            range: None,
+        });
+        spec.rules = vec![
+            overlapping_rule("R1", "a", 1, 0),
+            overlapping_rule("R2", "a && b", 2, 0),
+            overlapping_rule("R3", "b", 3, 0),
+        ];
+
+        let report = validate_spec(&spec, false);
+        let unreachable: Vec<_> = report
+            .issues
+            .iter()
+            .filter(|i| i.code == "UNREACHABLE_RULE")
+            .collect();
+        assert_eq!(unreachable.len(), 1);
+        assert!(matches!(unreachable[0].severity, Severity::Warning));
+        assert!(matches!(
+            unreachable[0].issue_type,
+            IssueType::UnreachableRule
+        ));
+        assert_eq!(unreachable[0].affected_rules, vec!["R2", "R1"]);
+
+        // With a lower priority R2 is tried first and can fire again
+        spec.rules[1].priority = -1;
+        let report = validate_spec(&spec, false);
+        assert!(!report.issues.iter().any(|i| i.code == "UNREACHABLE_RULE"));
+    }
+
+    #[test]
+    fn test_float_equality_warning() {
+        let spec = Spec::from_yaml(
+            r#"
+id: pricing
+inputs:
+  - name: price
+    type: float
+  - name: weight
+    type: float
+outputs:
+  - name: tier
+    type: int
+rules:
+  - id: R1
+    when: "price == 9.99"
+    then: 1
+  - id: R2
+    when: "price > 100.0"
+    then: 2
+  - id: R3
+    conditions:
+      - var: weight
+        op: "!="
+        value: 0.5
+    then: 3
+default: 0
+"#,
+        )
+        .unwrap();
+
+        let report = validate_spec(&spec, false);
+        let floats: Vec<_> = report
+            .issues
+            .iter()
+            .filter(|i| i.code == "FLOAT_EQUALITY")
+            .collect();
+        assert_eq!(floats.len(), 2);
+        assert!(matches!(floats[0].severity, Severity::Warning));
+        assert_eq!(floats[0].affected_rules, vec!["R1"]);
+        assert!(floats[0].message.contains("'price'"));
+        assert_eq!(floats[1].affected_rules, vec!["R3"]);
+        assert!(floats[1].message.contains("'weight'"));
+        assert!(!floats.iter().any(|i| i.affected_rules == vec!["R2"]));
+    }
+
+    #[test]
+    fn test_project_rule_limit_and_descriptions() {
+        let spec = Spec::from_yaml(
+            r#"
+id: tiers
+inputs:
+  - name: points
+    type: int
+outputs:
+  - name: tier
+    type: int
+rules:
+  - id: R1
+    when: "points >= 100"
+    then: 3
+    description: "Gold members"
+  - id: R2
+    when: "points >= 50 && points < 100"
+    then: 2
+  - id: R3
+    when: "points < 50"
+    then: 1
+"#,
+        )
+        .unwrap();
+        let config = ValidationConfig {
+            max_rules_per_spec: 2,
+            ..Default::default()
+        };
+
+        let report = validate_spec_with(&spec, false, &config);
+        let too_many: Vec<_> = report
+            .issues
+            .iter()
+            .filter(|i| i.code == "TOO_MANY_RULES")
+            .collect();
+        assert_eq!(too_many.len(), 1);
+        assert!(matches!(too_many[0].severity, Severity::Warning));
+        assert!(too_many[0].message.contains("3 rules"));
+        assert!(too_many[0]
+            .suggestion
+            .as_ref()
+            .unwrap()
+            .contains("Decompose"));
+        assert!(report.is_valid);
+        // Descriptions are only required when the project asks for them
+        assert!(!report
+            .issues
+            .iter()
+            .any(|i| i.code == "MISSING_DESCRIPTION"));
+
+        let strict = validate_spec_with(&spec, true, &config);
+        assert!(strict
+            .issues
+            .iter()
+            .any(|i| i.code == "TOO_MANY_RULES" && matches!(i.severity, Severity::Error)));
+        assert!(!strict.is_valid);
+
+        // The default limit of 50 leaves a 3-rule spec alone
+        assert!(!validate_spec(&spec, false)
+            .issues
+            .iter()
+            .any(|i| i.code == "TOO_MANY_RULES"));
+
+        let config = ValidationConfig {
+            require_descriptions: true,
+            ..Default::default()
+        };
+        let report = validate_spec_with(&spec, false, &config);
+        let missing: Vec<_> = report
+            .issues
+            .iter()
+            .filter(|i| i.code == "MISSING_DESCRIPTION")
+            .map(|i| i.affected_rules.clone())
+            .collect();
+        assert_eq!(missing, vec![vec!["R2"], vec!["R3"]]);
+    }
+
+    #[test]
+    fn test_type_mismatch_code() {
+        let spec = Spec::from_yaml(
+            r#"
+id: shipping
+inputs:
+  - name: cart_total
+    type: int
+outputs:
+  - name: free
+    type: bool
+rules:
+  - id: R1
+    when: 'cart_total > "x"'
+    then: true
+  - id: R2
+    when: "cart_total > 100"
+    then: true
+default: false
+"#,
+        )
+        .unwrap();
+
+        let report = validate_spec(&spec, false);
+        let mismatches: Vec<_> = report
+            .issues
+            .iter()
+            .filter(|i| i.code == "TYPE_MISMATCH")
+            .collect();
+        assert_eq!(mismatches.len(), 1);
+        assert!(matches!(mismatches[0].severity, Severity::Error));
+        assert_eq!(mismatches[0].affected_rules, vec!["R1"]);
+        assert!(mismatches[0].message.contains("int and string"));
+        assert!(!report.is_valid);
+    }
 }
@@ -152,12 +152,16 @@ mod tests {
             typ: VarType::String,
             description: None,
             values: Some(vec!["standard".into(), "premium".into()]),
+            optional: false,
+            range: None,
         };
         let var_b = Variable {
             name: "customer_type".into(),
             typ: VarType::String,
             description: None,
             values: Some(vec!["standard".into(), "premium".into()]),
+            optional: false,
+            range: None,
         };
 
         let score = compute_match_score(&var_a, &var_b);
@@ -171,12 +175,16 @@ mod tests {
             typ: VarType::String,
             description: None,
             values: Some(vec!["standard".into(), "premium".into()]),
+            optional: false,
+            range: None,
         };
         let var_b = Variable {
             name: "customer_type".into(),
             typ: VarType::String,
             description: None,
             values: Some(vec!["new".into(), "returning".into()]),
+            optional: false,
+            range: None,
         };
 
         let match_type = classify_match(&var_a, &var_b);
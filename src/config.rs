@@ -7,6 +7,7 @@ use crate::cel::Target;
 use crate::error::{Error, Result};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Root project configuration (`.imacs_root`)
@@ -59,6 +60,10 @@ pub struct DefaultsConfig {
     /// Output directory configuration
     #[serde(default)]
     pub output: Option<OutputConfig>,
+
+    /// Output directory and naming overrides for individual targets
+    #[serde(default)]
+    pub per_target: HashMap<Target, TargetConfig>,
 }
 
 fn default_targets() -> Vec<Target> {
@@ -70,7 +75,7 @@ fn default_true() -> bool {
 }
 
 /// Naming convention for generated files
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct NamingConfig {
     /// Code file pattern: {spec_id}, {lang}, {ext}
     #[serde(default = "default_code_naming")]
@@ -95,6 +100,25 @@ pub struct OutputConfig {
     pub go: Option<String>,
     pub java: Option<String>,
     pub csharp: Option<String>,
+    pub kotlin: Option<String>,
+    pub swift: Option<String>,
+    pub php: Option<String>,
+    pub ruby: Option<String>,
+    pub elixir: Option<String>,
+}
+
+/// Overrides for one target language (`per_target.<lang>`)
+///
+/// Takes precedence over `output` and `naming` for that target.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TargetConfig {
+    /// Output directory (relative to imacs folder parent)
+    #[serde(default)]
+    pub output_dir: Option<String>,
+
+    /// File naming conventions
+    #[serde(default)]
+    pub naming: Option<NamingConfig>,
 }
 
 fn default_code_naming() -> String {
@@ -132,12 +156,20 @@ pub struct ValidationConfig {
     /// Detect output path conflicts (multiple specs writing to same file)
     #[serde(default = "default_true")]
     pub detect_output_conflicts: bool,
+
+    /// Maximum if/match nesting depth in analyzed code (warn if exceeded)
+    #[serde(default = "default_max_nesting_depth")]
+    pub max_nesting_depth: usize,
 }
 
 fn default_max_rules() -> usize {
     50
 }
 
+fn default_max_nesting_depth() -> usize {
+    4
+}
+
 impl Default for ValidationConfig {
     fn default() -> Self {
         Self {
@@ -145,6 +177,7 @@ impl Default for ValidationConfig {
             require_descriptions: false,
             max_rules_per_spec: 50,
             detect_output_conflicts: true,
+            max_nesting_depth: 4,
         }
     }
 }
@@ -163,6 +196,9 @@ pub struct LocalConfig {
 
     /// Override output directory configuration
     pub output: Option<OutputConfig>,
+
+    /// Override per-target settings (merged key by key over the root's)
+    pub per_target: Option<HashMap<Target, TargetConfig>>,
 }
 
 /// Merged configuration for a specific imacs folder
@@ -174,6 +210,7 @@ pub struct MergedConfig {
     pub validation: ValidationConfig,
     pub spec_id_prefix: String,
     pub output: OutputConfig,
+    pub per_target: HashMap<Target, TargetConfig>,
 }
 
 impl ImacRoot {
@@ -206,6 +243,7 @@ impl ImacRoot {
             auto_format: None,
             naming: None,
             output: None,
+            per_target: None,
         });
 
         // Merge output config: local overrides root, root defaults to empty
@@ -223,11 +261,28 @@ impl ImacRoot {
                 go: local_output.go.clone().or(root_output.go.clone()),
                 java: local_output.java.clone().or(root_output.java.clone()),
                 csharp: local_output.csharp.clone().or(root_output.csharp.clone()),
+                kotlin: local_output.kotlin.clone().or(root_output.kotlin.clone()),
+                swift: local_output.swift.clone().or(root_output.swift.clone()),
+                php: local_output.php.clone().or(root_output.php.clone()),
+                ruby: local_output.ruby.clone().or(root_output.ruby.clone()),
+                elixir: local_output.elixir.clone().or(root_output.elixir.clone()),
             }
         } else {
             root_output
         };
 
+        // Merge per-target overrides: a local field wins over the root's
+        let mut per_target = self.defaults.per_target.clone();
+        for (target, local_target) in local.per_target.iter().flatten() {
+            let entry = per_target.entry(*target).or_default();
+            if local_target.output_dir.is_some() {
+                entry.output_dir = local_target.output_dir.clone();
+            }
+            if local_target.naming.is_some() {
+                entry.naming = local_target.naming.clone();
+            }
+        }
+
         MergedConfig {
             targets: local
                 .targets
@@ -241,6 +296,7 @@ impl ImacRoot {
             validation: self.validation.clone(),
             spec_id_prefix: self.project.spec_id_prefix.clone(),
             output: merged_output,
+            per_target,
         }
     }
 }
@@ -263,12 +319,15 @@ impl LocalConfig {
 
 impl MergedConfig {
     /// Apply naming pattern to generate output filename
+    ///
+    /// A `per_target` naming for `lang` takes precedence over `naming`.
     pub fn apply_naming(&self, spec_id: &str, lang: &Target, is_test: bool) -> String {
-        let pattern = if is_test {
-            &self.naming.tests
-        } else {
-            &self.naming.code
-        };
+        let naming = self
+            .per_target
+            .get(lang)
+            .and_then(|t| t.naming.as_ref())
+            .unwrap_or(&self.naming);
+        let pattern = if is_test { &naming.tests } else { &naming.code };
 
         let ext = match lang {
             Target::Rust => "rs",
@@ -277,6 +336,11 @@ impl MergedConfig {
             Target::Go => "go",
             Target::Java => "java",
             Target::CSharp => "cs",
+            Target::Kotlin => "kt",
+            Target::Swift => "swift",
+            Target::Php => "php",
+            Target::Ruby => "rb",
+            Target::Elixir => "ex",
         };
 
         pattern
@@ -299,6 +363,7 @@ mod tests {
             validation: ValidationConfig::default(),
             spec_id_prefix: "".to_string(),
             output: OutputConfig::default(),
+            per_target: HashMap::new(),
         };
 
         assert_eq!(
@@ -329,6 +394,7 @@ mod tests {
                 auto_format: true,
                 naming: NamingConfig::default(),
                 output: None,
+                per_target: HashMap::new(),
             },
             validation: ValidationConfig::default(),
         };
@@ -338,6 +404,7 @@ mod tests {
             auto_format: None,
             naming: None,
             output: None,
+            per_target: None,
         };
 
         let merged = root.merge(Some(&local));
@@ -365,6 +432,7 @@ mod tests {
                 auto_format: true,
                 naming: NamingConfig::default(),
                 output: Some(root_output),
+                per_target: HashMap::new(),
             },
             validation: ValidationConfig::default(),
         };
@@ -379,6 +447,7 @@ mod tests {
             auto_format: None,
             naming: None,
             output: Some(local_output),
+            per_target: None,
         };
 
         let merged = root.merge(Some(&local));
@@ -390,4 +459,52 @@ mod tests {
         // Root default should be present (not overridden)
         assert_eq!(merged.output.default, Some("./generated".to_string()));
     }
+
+    #[test]
+    fn test_per_target_overrides() {
+        let root: ImacRoot = serde_norway::from_str(
+            r#"
+version: 1
+project:
+  name: test
+defaults:
+  targets: [rust, typescript]
+  per_target:
+    typescript:
+      output_dir: ../frontend/src/gen
+      naming:
+        code: "{spec_id}.gen.{ext}"
+"#,
+        )
+        .unwrap();
+        let local: LocalConfig =
+            serde_norway::from_str("per_target:\n  rust:\n    output_dir: src/generated\n")
+                .unwrap();
+
+        let merged = root.merge(Some(&local));
+        assert_eq!(
+            merged.per_target[&Target::TypeScript].output_dir.as_deref(),
+            Some("../frontend/src/gen")
+        );
+        assert_eq!(
+            merged.per_target[&Target::Rust].output_dir.as_deref(),
+            Some("src/generated")
+        );
+        assert_eq!(
+            merged.apply_naming("login", &Target::TypeScript, false),
+            "login.gen.ts"
+        );
+        assert_eq!(
+            merged.apply_naming("login", &Target::Rust, false),
+            "login.rs"
+        );
+    }
+
+    #[test]
+    fn test_per_target_unknown_target_rejected() {
+        let result = serde_norway::from_str::<ImacRoot>(
+            "version: 1\nproject:\n  name: test\ndefaults:\n  per_target:\n    cobol:\n      output_dir: out\n",
+        );
+        assert!(result.is_err());
+    }
 }
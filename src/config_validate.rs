@@ -144,6 +144,14 @@ pub fn validate_imacs_root(path: &Path) -> ConfigValidationResult {
 
     // Validate naming patterns
     validate_naming_pattern(&root.defaults.naming, &file_str, &mut result);
+    for naming in root
+        .defaults
+        .per_target
+        .values()
+        .filter_map(|t| t.naming.as_ref())
+    {
+        validate_naming_pattern(naming, &file_str, &mut result);
+    }
 
     // Validate imacs_version constraint (if specified)
     if !root.imacs_version.is_empty() {
@@ -5,15 +5,183 @@
 
 use crate::ast::*;
 use crate::spec::*;
+use crate::util::to_snake_case;
+use crate::verify::{self, CoverageGap};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Compare two code implementations
 pub fn compare(code_a: &CodeAst, code_b: &CodeAst) -> DriftReport {
     DriftDetector::new().compare(code_a, code_b)
 }
 
+/// Verify two implementations against the same spec and classify where they
+/// drift from it
+///
+/// Each file is verified on its own (see [`verify::verify_semantic`]); then
+/// both are evaluated on the spec's inputs to tell apart rules that only one
+/// file gets wrong, rules both get wrong the same way, and rules where the
+/// two disagree.
+pub fn compare_with_spec(
+    spec: &Spec,
+    file_a: &str,
+    code_a: &CodeAst,
+    file_b: &str,
+    code_b: &CodeAst,
+) -> SpecDriftReport {
+    let result_a = verify::verify_semantic(spec, code_a);
+    let result_b = verify::verify_semantic(spec, code_b);
+
+    let mut evidence: BTreeMap<String, RuleEvidence> = BTreeMap::new();
+    if let (Some(func_a), Some(func_b)) = (
+        verify::find_function(spec, code_a),
+        verify::find_function(spec, code_b),
+    ) {
+        let (samples, _) = verify::semantic_inputs(spec);
+        for values in samples {
+            let inputs: HashMap<String, ConditionValue> = spec
+                .inputs
+                .iter()
+                .map(|i| i.name.clone())
+                .zip(values)
+                .collect();
+
+            let Some(expected) = verify::eval_spec(spec, &inputs) else {
+                continue;
+            };
+            let (Some(out_a), Some(out_b)) = (
+                verify::eval_function(func_a, &inputs),
+                verify::eval_function(func_b, &inputs),
+            ) else {
+                continue;
+            };
+
+            let wrong_a = !verify::values_equal(&expected, &out_a);
+            let wrong_b = !verify::values_equal(&expected, &out_b);
+            if !wrong_a && !wrong_b {
+                continue;
+            }
+
+            let rule_id = verify::firing_rule(spec, &inputs)
+                .map(|r| r.id.clone())
+                .unwrap_or_else(|| "default".into());
+            let entry = evidence.entry(rule_id).or_default();
+            entry.wrong_a |= wrong_a;
+            entry.wrong_b |= wrong_b;
+            let disagree = !verify::values_equal(&out_a, &out_b);
+            // Prefer an input where the two files disagree as the witness
+            if entry.witness.is_none() || (disagree && !entry.disagree) {
+                entry.witness = Some((
+                    inputs
+                        .iter()
+                        .map(|(k, v)| (k.clone(), v.to_string()))
+                        .collect(),
+                    expected.to_string(),
+                    out_a.to_string(),
+                    out_b.to_string(),
+                ));
+            }
+            entry.disagree |= disagree;
+        }
+    }
+
+    let gap_ids = |gaps: &[CoverageGap]| -> HashSet<String> {
+        gaps.iter().map(|g| g.rule_id.clone()).collect()
+    };
+    let gaps_a = gap_ids(&result_a.gaps);
+    let gaps_b = gap_ids(&result_b.gaps);
+
+    let mut rule_ids: Vec<String> = evidence.keys().cloned().collect();
+    for id in spec.rules.iter().map(|r| &r.id) {
+        if (gaps_a.contains(id) || gaps_b.contains(id)) && !rule_ids.contains(id) {
+            rule_ids.push(id.clone());
+        }
+    }
+    // Spec order, with the default last
+    let position = |id: &str| {
+        spec.rules
+            .iter()
+            .position(|r| r.id == id)
+            .unwrap_or(spec.rules.len())
+    };
+    rule_ids.sort_by_key(|id| position(id));
+
+    let rules: Vec<RuleDrift> = rule_ids
+        .into_iter()
+        .map(|rule_id| {
+            let ev = evidence.remove(&rule_id).unwrap_or_default();
+            let in_a = ev.wrong_a || gaps_a.contains(&rule_id);
+            let in_b = ev.wrong_b || gaps_b.contains(&rule_id);
+            let both_claim_coverage = !gaps_a.contains(&rule_id) && !gaps_b.contains(&rule_id);
+
+            let kind = if in_a && in_b && !ev.disagree {
+                RuleDriftKind::BothWrongSameWay
+            } else if (in_a && in_b) || (ev.disagree && both_claim_coverage) {
+                RuleDriftKind::Diverged
+            } else {
+                RuleDriftKind::OneSided
+            };
+
+            let mut files = Vec::new();
+            if in_a {
+                files.push(file_a.to_string());
+            }
+            if in_b {
+                files.push(file_b.to_string());
+            }
+
+            let (witness, expected, output_a, output_b) = match ev.witness {
+                Some((w, e, a, b)) => (Some(w), Some(e), Some(a), Some(b)),
+                None => (None, None, None, None),
+            };
+
+            RuleDrift {
+                rule_id,
+                kind,
+                files,
+                both_claim_coverage,
+                witness,
+                expected,
+                output_a,
+                output_b,
+            }
+        })
+        .collect();
+
+    let status = if rules
+        .iter()
+        .any(|r| r.kind != RuleDriftKind::BothWrongSameWay)
+        || result_a.passed != result_b.passed
+    {
+        SpecDriftStatus::Diverged
+    } else if !rules.is_empty() || !result_a.passed {
+        SpecDriftStatus::BothWrongSameWay
+    } else {
+        SpecDriftStatus::Conforming
+    };
+
+    SpecDriftReport {
+        spec_id: spec.id.clone(),
+        status,
+        implementations: vec![
+            ImplementationCheck::new(file_a, &result_a),
+            ImplementationCheck::new(file_b, &result_b),
+        ],
+        rules,
+    }
+}
+
+/// What the sampled inputs showed about one rule
+#[derive(Default)]
+struct RuleEvidence {
+    wrong_a: bool,
+    wrong_b: bool,
+    disagree: bool,
+    /// Input, spec output, output of A, output of B
+    witness: Option<(BTreeMap<String, String>, String, String, String)>,
+}
+
 /// Drift detector
 pub struct DriftDetector {
     config: DriftConfig,
@@ -52,6 +220,105 @@ pub struct DriftReport {
     pub summary: DriftSummary,
 }
 
+/// Result of checking two implementations against one spec
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpecDriftReport {
+    pub spec_id: String,
+    /// Overall status
+    pub status: SpecDriftStatus,
+    /// Verification of each file against the spec, A then B
+    pub implementations: Vec<ImplementationCheck>,
+    /// Rules where either file departs from the spec
+    pub rules: Vec<RuleDrift>,
+}
+
+/// One implementation verified against the spec
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImplementationCheck {
+    pub file: String,
+    pub language: String,
+    pub passed: bool,
+    /// Rules this file does not cover
+    pub gaps: Vec<CoverageGap>,
+}
+
+impl ImplementationCheck {
+    fn new(file: &str, result: &verify::VerificationResult) -> Self {
+        Self {
+            file: file.to_string(),
+            language: format!("{:?}", result.language),
+            passed: result.passed,
+            gaps: result.gaps.clone(),
+        }
+    }
+}
+
+/// A rule at least one implementation gets wrong
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RuleDrift {
+    /// Rule id, or `default` for inputs no rule matches
+    pub rule_id: String,
+    pub kind: RuleDriftKind,
+    /// Files that depart from the spec on this rule
+    pub files: Vec<String>,
+    /// Neither file reports a coverage gap for this rule
+    pub both_claim_coverage: bool,
+    /// An input the rule fires on where the files go wrong
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub witness: Option<BTreeMap<String, String>>,
+    /// Spec output for the witness
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    /// Output of file A for the witness
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_a: Option<String>,
+    /// Output of file B for the witness
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_b: Option<String>,
+}
+
+/// How the two implementations relate on a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum RuleDriftKind {
+    /// Only one file departs from the spec
+    OneSided,
+    /// Both files depart from the spec and agree with each other
+    BothWrongSameWay,
+    /// The files disagree with each other and at least one with the spec
+    Diverged,
+}
+
+impl std::fmt::Display for RuleDriftKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleDriftKind::OneSided => write!(f, "one-sided"),
+            RuleDriftKind::BothWrongSameWay => write!(f, "both wrong the same way"),
+            RuleDriftKind::Diverged => write!(f, "diverged"),
+        }
+    }
+}
+
+/// Overall status of a spec-anchored comparison
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum SpecDriftStatus {
+    /// Both files implement the spec
+    Conforming,
+    /// Both files depart from the spec, identically
+    BothWrongSameWay,
+    /// The files behave differently from each other
+    Diverged,
+}
+
+impl std::fmt::Display for SpecDriftStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpecDriftStatus::Conforming => write!(f, "CONFORMING"),
+            SpecDriftStatus::BothWrongSameWay => write!(f, "BOTH WRONG THE SAME WAY"),
+            SpecDriftStatus::Diverged => write!(f, "DIVERGED"),
+        }
+    }
+}
+
 /// File information
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FileInfo {
@@ -60,19 +327,30 @@ pub struct FileInfo {
     pub hash: String,
 }
 
-/// Overall drift status
+/// Overall drift status, derived from the most severe difference
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum DriftStatus {
     /// Implementations match
     Synced,
-    /// Minor differences (warnings only)
+    /// Only info or warning differences (e.g. reordered branches)
     MinorDrift,
-    /// Significant differences
+    /// At least one error difference (e.g. a missing branch)
     MajorDrift,
     /// Cannot compare
     Incomparable,
 }
 
+impl DriftStatus {
+    /// Status for a report whose most severe difference is `max`
+    pub fn from_max_severity(max: Option<DiffSeverity>) -> Self {
+        match max {
+            None => DriftStatus::Synced,
+            Some(DiffSeverity::Info | DiffSeverity::Warning) => DriftStatus::MinorDrift,
+            Some(DiffSeverity::Error) => DriftStatus::MajorDrift,
+        }
+    }
+}
+
 impl std::fmt::Display for DriftStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -101,27 +379,62 @@ pub struct Difference {
     pub location: Option<String>,
 }
 
+impl Difference {
+    /// A difference whose severity follows from its kind
+    fn new(
+        kind: DifferenceKind,
+        description: String,
+        value_a: Option<String>,
+        value_b: Option<String>,
+        location: Option<String>,
+    ) -> Self {
+        Self {
+            kind,
+            severity: kind.severity(),
+            description,
+            value_a,
+            value_b,
+            location,
+        }
+    }
+}
+
 /// Types of differences
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum DifferenceKind {
-    /// Different number of rules/branches
-    RuleCount,
-    /// Condition differs
-    Condition,
-    /// Output value differs
-    Output,
-    /// Rule ordering differs
-    Order,
-    /// Missing case in one implementation
-    MissingCase,
-    /// Extra case in one implementation
-    ExtraCase,
-    /// Structural difference
+    /// A branch of A has no counterpart in B
+    MissingBranch,
+    /// Matching branches return different outputs
+    DifferentOutput,
+    /// A branch of B has no counterpart in A
+    ExtraBranch,
+    /// Matching branches appear in a different order
+    ConditionReordered,
+    /// A parameter has a different name in B
+    VariableRenamed,
+    /// The implementations cannot be compared
     Structure,
 }
 
-/// Severity of difference
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+impl DifferenceKind {
+    /// How much a difference of this kind matters
+    pub fn severity(self) -> DiffSeverity {
+        match self {
+            DifferenceKind::ConditionReordered | DifferenceKind::VariableRenamed => {
+                DiffSeverity::Info
+            }
+            DifferenceKind::ExtraBranch => DiffSeverity::Warning,
+            DifferenceKind::MissingBranch
+            | DifferenceKind::DifferentOutput
+            | DifferenceKind::Structure => DiffSeverity::Error,
+        }
+    }
+}
+
+/// Severity of difference, from low to high
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
 pub enum DiffSeverity {
     Info,
     Warning,
@@ -203,7 +516,7 @@ impl DriftDetector {
                     },
                     differences: vec![Difference {
                         kind: DifferenceKind::Structure,
-                        severity: DiffSeverity::Error,
+                        severity: DifferenceKind::Structure.severity(),
                         description: "Cannot find matching functions to compare".into(),
                         value_a: None,
                         value_b: None,
@@ -221,26 +534,35 @@ impl DriftDetector {
             }
         };
 
-        // Extract normalized rules from both
-        let rules_a = self.extract_normalized_rules(&func_a.body, &func_a.params);
-        let rules_b = self.extract_normalized_rules(&func_b.body, &func_b.params);
-
         let mut differences = Vec::new();
 
-        // Compare rule counts
-        if rules_a.len() != rules_b.len() {
-            differences.push(Difference {
-                kind: DifferenceKind::RuleCount,
-                severity: DiffSeverity::Warning,
-                description: format!(
-                    "Different number of rules: {} vs {}",
-                    rules_a.len(),
-                    rules_b.len()
-                ),
-                value_a: Some(rules_a.len().to_string()),
-                value_b: Some(rules_b.len().to_string()),
-                location: None,
-            });
+        // Parameters renamed position-for-position are compared under A's names
+        let mut renames = HashMap::new();
+        if func_a.params.len() == func_b.params.len() {
+            for (i, (param_a, param_b)) in func_a.params.iter().zip(&func_b.params).enumerate() {
+                let (name_a, name_b) =
+                    (normalize_name(&param_a.name), normalize_name(&param_b.name));
+                if name_a != name_b {
+                    differences.push(Difference::new(
+                        DifferenceKind::VariableRenamed,
+                        format!("Parameter {} is named differently", i + 1),
+                        Some(param_a.name.clone()),
+                        Some(param_b.name.clone()),
+                        Some(format!("Parameter {}", i + 1)),
+                    ));
+                    renames.insert(name_b, name_a);
+                }
+            }
+        }
+
+        // Extract normalized rules from both
+        let rules_a = self.extract_normalized_rules(&func_a.body, &func_a.params);
+        let mut rules_b = self.extract_normalized_rules(&func_b.body, &func_b.params);
+        for rule in &mut rules_b {
+            rule.conditions = std::mem::take(&mut rule.conditions)
+                .into_iter()
+                .map(|(name, cond)| (renames.get(&name).cloned().unwrap_or(name), cond))
+                .collect();
         }
 
         // Compare individual rules
@@ -261,32 +583,30 @@ impl DriftDetector {
 
                     // Check if outputs match
                     if !self.outputs_equivalent(&rule_a.output, &rule_b.output) {
-                        differences.push(Difference {
-                            kind: DifferenceKind::Output,
-                            severity: DiffSeverity::Error,
-                            description: format!("Rule {} has different output", i + 1),
-                            value_a: Some(format!("{:?}", rule_a.output)),
-                            value_b: Some(format!("{:?}", rule_b.output)),
-                            location: Some(format!("Rule {}", i + 1)),
-                        });
+                        differences.push(Difference::new(
+                            DifferenceKind::DifferentOutput,
+                            format!("Rule {} has different output", i + 1),
+                            Some(format!("{:?}", rule_a.output)),
+                            Some(format!("{:?}", rule_b.output)),
+                            Some(format!("Rule {}", i + 1)),
+                        ));
                     } else {
                         matching_rules += 1;
                     }
 
                     // Check ordering
                     if i != j {
-                        differences.push(Difference {
-                            kind: DifferenceKind::Order,
-                            severity: DiffSeverity::Warning,
-                            description: format!(
+                        differences.push(Difference::new(
+                            DifferenceKind::ConditionReordered,
+                            format!(
                                 "Rule ordering differs: rule {} in A matches rule {} in B",
                                 i + 1,
                                 j + 1
                             ),
-                            value_a: Some((i + 1).to_string()),
-                            value_b: Some((j + 1).to_string()),
-                            location: None,
-                        });
+                            Some((i + 1).to_string()),
+                            Some((j + 1).to_string()),
+                            None,
+                        ));
                     }
 
                     break;
@@ -294,28 +614,26 @@ impl DriftDetector {
             }
 
             if !found_match {
-                differences.push(Difference {
-                    kind: DifferenceKind::MissingCase,
-                    severity: DiffSeverity::Error,
-                    description: format!("Rule {} in A has no match in B", i + 1),
-                    value_a: Some(format!("{:?}", rule_a.conditions)),
-                    value_b: None,
-                    location: Some(format!("Rule {}", i + 1)),
-                });
+                differences.push(Difference::new(
+                    DifferenceKind::MissingBranch,
+                    format!("Rule {} in A has no match in B", i + 1),
+                    Some(format!("{:?}", rule_a.conditions)),
+                    None,
+                    Some(format!("Rule {}", i + 1)),
+                ));
             }
         }
 
         // Check for extra rules in B
         for (j, rule_b) in rules_b.iter().enumerate() {
             if !matched_b.contains(&j) {
-                differences.push(Difference {
-                    kind: DifferenceKind::ExtraCase,
-                    severity: DiffSeverity::Warning,
-                    description: format!("Rule {} in B has no match in A", j + 1),
-                    value_a: None,
-                    value_b: Some(format!("{:?}", rule_b.conditions)),
-                    location: Some(format!("Rule {}", j + 1)),
-                });
+                differences.push(Difference::new(
+                    DifferenceKind::ExtraBranch,
+                    format!("Rule {} in B has no match in A", j + 1),
+                    None,
+                    Some(format!("{:?}", rule_b.conditions)),
+                    Some(format!("Rule {}", j + 1)),
+                ));
             }
         }
 
@@ -329,13 +647,7 @@ impl DriftDetector {
             .filter(|d| d.severity == DiffSeverity::Warning)
             .count();
 
-        let status = if differences.is_empty() {
-            DriftStatus::Synced
-        } else if errors > 0 {
-            DriftStatus::MajorDrift
-        } else {
-            DriftStatus::MinorDrift
-        };
+        let status = DriftStatus::from_max_severity(differences.iter().map(|d| d.severity).max());
 
         DriftReport {
             status,
@@ -349,15 +661,15 @@ impl DriftDetector {
                 function: func_b.name.clone(),
                 hash: code_b.source_hash.clone(),
             },
-            differences,
             summary: DriftSummary {
-                total_differences: errors + warnings,
+                total_differences: differences.len(),
                 errors,
                 warnings,
                 rules_a: rules_a.len(),
                 rules_b: rules_b.len(),
                 matching_rules,
             },
+            differences,
         }
     }
 
@@ -456,7 +768,7 @@ impl DriftDetector {
             Pattern::Literal(lit) => {
                 if let Some(param) = params.first() {
                     conditions.insert(
-                        param.name.clone(),
+                        normalize_name(&param.name),
                         NormalizedCondition {
                             op: ConditionOp::Eq,
                             value: self.literal_to_normalized(lit),
@@ -468,7 +780,7 @@ impl DriftDetector {
                 // Matches anything - insert wildcard
                 if let Some(param) = params.first() {
                     conditions.insert(
-                        param.name.clone(),
+                        normalize_name(&param.name),
                         NormalizedCondition {
                             op: ConditionOp::Eq,
                             value: NormalizedValue::Any,
@@ -489,7 +801,7 @@ impl DriftDetector {
         match pattern {
             Pattern::Literal(lit) => {
                 conditions.insert(
-                    var.to_string(),
+                    normalize_name(var),
                     NormalizedCondition {
                         op: ConditionOp::Eq,
                         value: self.literal_to_normalized(lit),
@@ -498,7 +810,7 @@ impl DriftDetector {
             }
             Pattern::Wildcard | Pattern::Binding(_) => {
                 conditions.insert(
-                    var.to_string(),
+                    normalize_name(var),
                     NormalizedCondition {
                         op: ConditionOp::Eq,
                         value: NormalizedValue::Any,
@@ -536,7 +848,10 @@ impl DriftDetector {
                         } else {
                             self.binary_to_op(*op)
                         };
-                        conditions.insert(name.clone(), NormalizedCondition { op: cond_op, value });
+                        conditions.insert(
+                            normalize_name(name),
+                            NormalizedCondition { op: cond_op, value },
+                        );
                     }
                 }
             }
@@ -551,7 +866,7 @@ impl DriftDetector {
 
             AstNode::Var { name, .. } => {
                 conditions.insert(
-                    name.clone(),
+                    normalize_name(name),
                     NormalizedCondition {
                         op: ConditionOp::Eq,
                         value: NormalizedValue::Bool(!negated),
@@ -684,6 +999,11 @@ impl DriftDetector {
     }
 }
 
+/// Canonical variable name so `rate_exceeded` and `rateExceeded` compare equal
+fn normalize_name(name: &str) -> String {
+    to_snake_case(name)
+}
+
 impl Default for DriftDetector {
     fn default() -> Self {
         Self::new()
@@ -710,6 +1030,13 @@ impl DriftReport {
             self.file_b.function, self.file_b.language, self.file_b.hash
         ));
 
+        if self.file_a.language != self.file_b.language {
+            out.push_str(&format!(
+                "Cross-language: {} vs {} (variable names compared case-insensitively)\n\n",
+                self.file_a.language, self.file_b.language
+            ));
+        }
+
         out.push_str(&format!(
             "Rules: {} in A, {} in B, {} matching\n\n",
             self.summary.rules_a, self.summary.rules_b, self.summary.matching_rules
@@ -737,6 +1064,66 @@ impl DriftReport {
     }
 }
 
+impl SpecDriftReport {
+    /// Format as human-readable report
+    pub fn to_report(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("SPEC DRIFT REPORT\n");
+        out.push_str("═══════════════════════════════════════════════════════════════\n\n");
+
+        out.push_str(&format!("Spec: {}\n", self.spec_id));
+        out.push_str(&format!("Status: {}\n\n", self.status));
+
+        for (label, imp) in ["A", "B"].iter().zip(&self.implementations) {
+            let verdict = if imp.passed { "✓" } else { "✗" };
+            out.push_str(&format!(
+                "{} {}: {} ({})\n",
+                verdict, label, imp.file, imp.language
+            ));
+            for gap in &imp.gaps {
+                out.push_str(&format!(
+                    "    gap {}: {:?} — {} → {}\n",
+                    gap.rule_id, gap.reason, gap.expected_condition, gap.expected_output
+                ));
+            }
+        }
+
+        if !self.rules.is_empty() {
+            out.push_str("\nRules:\n");
+            for rule in &self.rules {
+                out.push_str(&format!(
+                    "  {} [{}] {}\n",
+                    rule.rule_id,
+                    rule.kind,
+                    rule.files.join(", ")
+                ));
+                if rule.kind == RuleDriftKind::Diverged && rule.both_claim_coverage {
+                    out.push_str("    both files claim to cover this rule\n");
+                }
+                if let Some(witness) = &rule.witness {
+                    let inputs: Vec<String> = witness
+                        .iter()
+                        .map(|(k, v)| format!("{} = {}", k, v))
+                        .collect();
+                    out.push_str(&format!("    input: {}\n", inputs.join(", ")));
+                }
+                if let Some(expected) = &rule.expected {
+                    out.push_str(&format!("    spec: {}\n", expected));
+                }
+                if let Some(a) = &rule.output_a {
+                    out.push_str(&format!("    A: {}\n", a));
+                }
+                if let Some(b) = &rule.output_b {
+                    out.push_str(&format!("    B: {}\n", b));
+                }
+            }
+        }
+
+        out
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -785,6 +1172,202 @@ fn check(x: bool) -> i32 {
         assert!(report
             .differences
             .iter()
-            .any(|d| d.kind == DifferenceKind::Output));
+            .any(|d| d.kind == DifferenceKind::DifferentOutput));
+    }
+
+    #[test]
+    fn test_compare_rust_and_typescript() {
+        use crate::parse::parse_typescript;
+
+        let rust = r#"
+fn check_rate(rate_exceeded: bool, request_count: i64) -> i32 {
+    if rate_exceeded {
+        429
+    } else if request_count > 100 {
+        503
+    } else {
+        200
+    }
+}
+"#;
+        let ts = r#"
+function checkRate(rateExceeded: boolean, requestCount: number): number {
+    if (rateExceeded) {
+        return 429;
+    } else if (requestCount > 100) {
+        return 503;
+    } else {
+        return 200;
+    }
+}
+"#;
+        let ast_a = parse_rust(rust).unwrap();
+        let ast_b = parse_typescript(ts).unwrap();
+        let report = compare(&ast_a, &ast_b);
+
+        assert_eq!(report.status, DriftStatus::Synced);
+        assert_eq!(report.file_a.language, "Rust");
+        assert_eq!(report.file_b.language, "TypeScript");
+        assert!(report
+            .to_report()
+            .contains("Cross-language: Rust vs TypeScript"));
+    }
+
+    #[test]
+    fn test_reordered_branches_are_low_severity() {
+        let code_a = r#"
+fn check(x: i32) -> i32 {
+    match x {
+        1 => 10,
+        2 => 20,
+        _ => 0,
+    }
+}
+"#;
+        let code_b = r#"
+fn check(x: i32) -> i32 {
+    match x {
+        2 => 20,
+        1 => 10,
+        _ => 0,
+    }
+}
+"#;
+        let report = compare(&parse_rust(code_a).unwrap(), &parse_rust(code_b).unwrap());
+
+        assert!(!report.differences.is_empty());
+        assert!(report.differences.iter().all(|d| {
+            d.kind == DifferenceKind::ConditionReordered && d.severity == DiffSeverity::Info
+        }));
+        assert_eq!(report.status, DriftStatus::MinorDrift);
+    }
+
+    #[test]
+    fn test_missing_branch_is_high_severity() {
+        let code_a = r#"
+fn check(a: bool, b: bool) -> i32 {
+    if a {
+        1
+    } else if b {
+        2
+    } else {
+        0
+    }
+}
+"#;
+        let code_b = r#"
+fn check(a: bool, b: bool) -> i32 {
+    if a {
+        1
+    } else {
+        0
+    }
+}
+"#;
+        let report = compare(&parse_rust(code_a).unwrap(), &parse_rust(code_b).unwrap());
+
+        let missing = report
+            .differences
+            .iter()
+            .find(|d| d.kind == DifferenceKind::MissingBranch)
+            .expect("missing branch reported");
+        assert_eq!(missing.severity, DiffSeverity::Error);
+        assert_eq!(report.status, DriftStatus::MajorDrift);
+    }
+
+    #[test]
+    fn test_renamed_parameter() {
+        let code_a =
+            "fn check(rate_exceeded: bool) -> i32 { if rate_exceeded { 429 } else { 200 } }";
+        let code_b = "fn check(limited: bool) -> i32 { if limited { 429 } else { 200 } }";
+        let report = compare(&parse_rust(code_a).unwrap(), &parse_rust(code_b).unwrap());
+
+        assert_eq!(report.differences.len(), 1);
+        assert_eq!(report.differences[0].kind, DifferenceKind::VariableRenamed);
+        assert_eq!(report.differences[0].severity, DiffSeverity::Info);
+        assert_eq!(report.summary.matching_rules, 1);
+        assert_eq!(report.status, DriftStatus::MinorDrift);
+    }
+
+    #[test]
+    fn test_compare_with_spec_attributes_gap() {
+        let spec = Spec::from_yaml(
+            r#"
+id: check
+inputs:
+  - name: a
+    type: bool
+  - name: b
+    type: bool
+outputs:
+  - name: result
+    type: int
+rules:
+  - id: R1
+    when: a
+    then: 1
+  - id: R2
+    when: "!a && b"
+    then: 2
+default: 0
+"#,
+        )
+        .unwrap();
+
+        let complete = parse_rust(
+            r#"
+fn check(a: bool, b: bool) -> i32 {
+    if a {
+        1
+    } else if b {
+        2
+    } else {
+        0
+    }
+}
+"#,
+        )
+        .unwrap();
+        let missing_r2 = parse_rust(
+            r#"
+fn check(a: bool, b: bool) -> i32 {
+    if a {
+        1
+    } else {
+        0
+    }
+}
+"#,
+        )
+        .unwrap();
+
+        let report = compare_with_spec(&spec, "a.rs", &missing_r2, "b.rs", &complete);
+        assert_eq!(report.status, SpecDriftStatus::Diverged);
+
+        assert!(!report.implementations[0].passed);
+        assert!(report.implementations[0]
+            .gaps
+            .iter()
+            .any(|g| g.rule_id == "R2"));
+        assert!(report.implementations[1].passed);
+        assert!(report.implementations[1].gaps.is_empty());
+
+        assert_eq!(report.rules.len(), 1);
+        let r2 = &report.rules[0];
+        assert_eq!(r2.rule_id, "R2");
+        assert_eq!(r2.kind, RuleDriftKind::OneSided);
+        assert_eq!(r2.files, vec!["a.rs".to_string()]);
+        assert_eq!(r2.output_a.as_deref(), Some("0"));
+        assert_eq!(r2.output_b.as_deref(), Some("2"));
+
+        // Both files against themselves conform
+        let same = compare_with_spec(&spec, "b.rs", &complete, "c.rs", &complete);
+        assert_eq!(same.status, SpecDriftStatus::Conforming);
+        assert!(same.rules.is_empty());
+
+        // The same omission in both files is not divergence
+        let both = compare_with_spec(&spec, "a.rs", &missing_r2, "c.rs", &missing_r2);
+        assert_eq!(both.status, SpecDriftStatus::BothWrongSameWay);
+        assert_eq!(both.rules[0].kind, RuleDriftKind::BothWrongSameWay);
     }
 }
@@ -20,6 +20,9 @@ pub enum Error {
     #[error("CEL evaluation error: {0}")]
     CelEval(String),
 
+    #[error("Input error: {0}")]
+    Input(String),
+
     #[error("Verification error: {0}")]
     Verification(String),
 
@@ -38,6 +41,9 @@ pub enum Error {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("Merge error: {0}")]
+    Merge(#[from] crate::merge::MergeError),
+
     #[error("{0}")]
     Other(String),
 }
@@ -0,0 +1,840 @@
+//! Spec interpreter — evaluate a spec directly against concrete inputs
+//!
+//! Runs the rules of a spec over JSON inputs with the CEL interpreter, so a
+//! spec can be sanity-checked without a codegen/compile cycle.
+
+use crate::cel::{CelCompiler, CelValue};
+use crate::error::{Error, Result};
+use crate::spec::{
+    guard_optionals, ConditionValue, Output, Rule, Spec, SpecMode, VarType, WhenClause,
+};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+impl Spec {
+    /// Evaluate the spec against concrete inputs
+    ///
+    /// Rules are tried in [`Spec::rules_in_order`] (lowest priority first,
+    /// declaration order among equals); the first matching rule's output is
+    /// returned, else the default. Output expressions such as `amount * 2`, in rules or the
+    /// default, are evaluated.
+    /// In `mode: accumulate` the outputs of every matching rule are summed
+    /// onto the default (0 when absent) instead.
+    ///
+    /// The output must satisfy every `ensures` postcondition; one that does
+    /// not hold is an error.
+    ///
+    /// Every declared input must be present with a value of its declared
+    /// type, except optional inputs, which may be missing or `null`;
+    /// undeclared keys are rejected.
+    pub fn evaluate(&self, inputs: &Map<String, Value>) -> Result<Output> {
+        self.evaluate_vars(&self.input_vars(inputs)?)
+    }
+
+    /// [`Spec::evaluate`] over inputs already converted for CEL
+    pub(crate) fn evaluate_vars(&self, vars: &HashMap<String, CelValue>) -> Result<Output> {
+        let output = if self.mode == SpecMode::Accumulate {
+            self.accumulate(vars)?
+        } else {
+            let matched = self.first_match(vars)?.map(|rule| &rule.then);
+            let output = matched
+                .or(self.default.as_ref())
+                .ok_or_else(|| Error::Other(format!("No rule of '{}' matched", self.id)))?;
+
+            match output {
+                Output::Single(v) => Output::Single(resolve_output_value(v, vars)?),
+                Output::Named(map) => Output::Named(
+                    map.iter()
+                        .map(|(k, v)| Ok((k.clone(), resolve_output_value(v, vars)?)))
+                        .collect::<Result<_>>()?,
+                ),
+            }
+        };
+        self.check_ensures(&output)?;
+        Ok(output)
+    }
+
+    /// Fail on the first `ensures` postcondition `output` violates
+    fn check_ensures(&self, output: &Output) -> Result<()> {
+        if self.ensures.is_empty() {
+            return Ok(());
+        }
+        let result = match output {
+            Output::Single(v) => to_cel_value(v),
+            Output::Named(map) => CelValue::from(
+                map.iter()
+                    .map(|(k, v)| (k.clone(), to_cel_value(v)))
+                    .collect::<HashMap<_, _>>(),
+            ),
+        };
+        let vars = HashMap::from([("result".to_string(), result)]);
+        for ensure in &self.ensures {
+            if !CelCompiler::eval_bool(ensure, &vars)? {
+                return Err(Error::Other(format!(
+                    "Postcondition '{}' of '{}' violated by output {}",
+                    ensure, self.id, output
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The rule [`Spec::evaluate`] would use, or `None` when the default applies
+    pub fn matching_rule(&self, inputs: &Map<String, Value>) -> Result<Option<&Rule>> {
+        self.first_match(&self.input_vars(inputs)?)
+    }
+
+    /// Explain how the rules fare against concrete inputs
+    ///
+    /// Every rule is tried in priority order, even after one has matched, so
+    /// rules shadowed by a higher-priority match show up too. For a rule that
+    /// does not match, each top-level `&&` part that is false is reported
+    /// along with the values of the inputs it reads.
+    pub fn explain(&self, inputs: &Map<String, Value>) -> Result<Explanation> {
+        let vars = self.input_vars(inputs)?;
+        let mut steps = Vec::new();
+        for rule in self.rules_in_order() {
+            let mut failed = Vec::new();
+            for part in rule_conjuncts(rule) {
+                let guarded = guard_optionals(&part, &self.inputs);
+                if CelCompiler::eval_bool(&guarded, &vars)? {
+                    continue;
+                }
+                let actual = CelCompiler::extract_variables(&part)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|name| self.inputs.iter().any(|i| &i.name == name))
+                    .map(|name| {
+                        let value = inputs.get(&name).cloned().unwrap_or(Value::Null);
+                        (name, value)
+                    })
+                    .collect();
+                failed.push(FailedCondition {
+                    condition: part,
+                    actual,
+                });
+            }
+            steps.push(RuleExplanation {
+                rule_id: rule.id.clone(),
+                priority: rule.priority,
+                matched: failed.is_empty(),
+                failed,
+            });
+        }
+
+        let winner = steps.iter().find(|s| s.matched).map(|s| s.rule_id.clone());
+        Ok(Explanation {
+            spec_id: self.id.clone(),
+            steps,
+            winner,
+            output: self.evaluate(inputs).ok(),
+        })
+    }
+
+    /// Check inputs against their declared types and convert them for CEL
+    fn input_vars(&self, inputs: &Map<String, Value>) -> Result<HashMap<String, CelValue>> {
+        let mut vars = HashMap::new();
+        for input in &self.inputs {
+            let value = match inputs.get(&input.name) {
+                None | Some(Value::Null) if input.optional => CelValue::Null,
+                Some(value) => json_to_cel(&input.name, value, &input.typ)?,
+                None => {
+                    return Err(Error::Input(format!("missing input '{}'", input.name)));
+                }
+            };
+            vars.insert(input.name.clone(), value);
+        }
+        if let Some(unknown) = inputs
+            .keys()
+            .find(|k| !self.inputs.iter().any(|i| &i.name == *k))
+        {
+            return Err(Error::Input(format!("unknown input '{}'", unknown)));
+        }
+        Ok(vars)
+    }
+
+    /// First rule that matches, lowest priority first
+    pub(crate) fn first_match(&self, vars: &HashMap<String, CelValue>) -> Result<Option<&Rule>> {
+        for rule in self.rules_in_order() {
+            if self.rule_matches(rule, vars)? {
+                return Ok(Some(rule));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Sum of every matching rule's output, starting from the default
+    fn accumulate(&self, vars: &HashMap<String, CelValue>) -> Result<Output> {
+        let single = |output: &Output, what: &str| match output {
+            Output::Single(v) => resolve_output_value(v, vars),
+            Output::Named(_) => Err(Error::Other(format!(
+                "{} of accumulate spec '{}' must be a single number",
+                what, self.id
+            ))),
+        };
+
+        let mut total = match &self.default {
+            Some(default) => single(default, "The default")?,
+            None => ConditionValue::Int(0),
+        };
+        for rule in &self.rules {
+            if !self.rule_matches(rule, vars)? {
+                continue;
+            }
+            let value = single(&rule.then, &format!("Rule {}", rule.id))?;
+            total = add_numbers(&total, &value).ok_or_else(|| {
+                Error::Other(format!(
+                    "Cannot add {} from rule {} to {} in '{}'",
+                    value, rule.id, total, self.id
+                ))
+            })?;
+        }
+        Ok(Output::Single(total))
+    }
+
+    fn rule_matches(&self, rule: &Rule, vars: &HashMap<String, CelValue>) -> Result<bool> {
+        match rule.as_cel() {
+            Some(cel) => CelCompiler::eval_bool(&guard_optionals(&cel, &self.inputs), vars),
+            None => Ok(true),
+        }
+    }
+}
+
+/// Numeric sum, widening to float when either side is a float
+fn add_numbers(a: &ConditionValue, b: &ConditionValue) -> Option<ConditionValue> {
+    match (a, b) {
+        (ConditionValue::Int(x), ConditionValue::Int(y)) => {
+            x.checked_add(*y).map(ConditionValue::Int)
+        }
+        (ConditionValue::Int(x), ConditionValue::Float(y)) => {
+            Some(ConditionValue::Float(*x as f64 + y))
+        }
+        (ConditionValue::Float(x), ConditionValue::Int(y)) => {
+            Some(ConditionValue::Float(x + *y as f64))
+        }
+        (ConditionValue::Float(x), ConditionValue::Float(y)) => Some(ConditionValue::Float(x + y)),
+        _ => None,
+    }
+}
+
+/// How a spec's rules fared against one set of inputs, from [`Spec::explain`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    pub spec_id: String,
+    /// Every rule, in the order they are tried
+    pub steps: Vec<RuleExplanation>,
+    /// The rule whose output is used, `None` when the default applies
+    pub winner: Option<String>,
+    /// The evaluated output, `None` when nothing matched and there is no default
+    pub output: Option<Output>,
+}
+
+/// One rule's outcome within an [`Explanation`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleExplanation {
+    pub rule_id: String,
+    pub priority: i32,
+    pub matched: bool,
+    /// Conditions that were false (empty when the rule matched)
+    pub failed: Vec<FailedCondition>,
+}
+
+/// A false sub-condition and the input values it saw
+#[derive(Debug, Clone, PartialEq)]
+pub struct FailedCondition {
+    pub condition: String,
+    pub actual: Vec<(String, Value)>,
+}
+
+impl std::fmt::Display for FailedCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} failed", self.condition)?;
+        match self.actual.as_slice() {
+            [] => Ok(()),
+            [(_, value)] => write!(f, ", actual {}", value),
+            values => {
+                let pairs: Vec<_> = values.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+                write!(f, ", actual {}", pairs.join(", "))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Explanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for step in &self.steps {
+            if !step.matched {
+                let reasons: Vec<_> = step.failed.iter().map(|c| c.to_string()).collect();
+                writeln!(f, "{} skipped: {}", step.rule_id, reasons.join("; "))?;
+            } else if self.winner.as_ref() == Some(&step.rule_id) {
+                writeln!(f, "{} matched (wins)", step.rule_id)?;
+            } else {
+                writeln!(
+                    f,
+                    "{} matched, but {} has precedence",
+                    step.rule_id,
+                    self.winner.as_deref().unwrap_or_default()
+                )?;
+            }
+        }
+        if self.winner.is_none() {
+            if self.output.is_some() {
+                writeln!(f, "No rule matched: default applies")?;
+            } else {
+                writeln!(f, "No rule matched and '{}' has no default", self.spec_id)?;
+            }
+        }
+        if let Some(output) = &self.output {
+            write!(f, "Output: {}", output)?;
+        }
+        Ok(())
+    }
+}
+
+/// The parts of a rule's condition that must all hold
+///
+/// List-form `when` items and structured conditions are parts already; a
+/// single expression is split at its top-level `&&`.
+fn rule_conjuncts(rule: &Rule) -> Vec<String> {
+    match (&rule.when, &rule.conditions) {
+        (Some(WhenClause::Multiple(items)), _) => {
+            items.iter().flat_map(|s| split_conjuncts(s)).collect()
+        }
+        (Some(WhenClause::Single(s)), _) => split_conjuncts(s),
+        (None, Some(conditions)) => conditions.iter().map(|c| c.to_cel()).collect(),
+        (None, None) => Vec::new(),
+    }
+}
+
+/// Split a CEL expression at `&&` outside parentheses, brackets and strings
+fn split_conjuncts(cel: &str) -> Vec<String> {
+    let mut cel = cel.trim();
+    while is_wrapped(cel) {
+        cel = cel[1..cel.len() - 1].trim();
+    }
+
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    let bytes = cel.as_bytes();
+    for (i, c) in cel.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '\'' | '"' => quote = Some(c),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                '&' if depth == 0 && bytes.get(i + 1) == Some(&b'&') && i > start => {
+                    parts.push(cel[start..i].to_string());
+                    start = i + 2;
+                }
+                _ => {}
+            },
+        }
+    }
+    parts.push(cel[start..].to_string());
+
+    parts
+        .into_iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .flat_map(|p| {
+            // A part that is itself a parenthesized conjunction is split too
+            if is_wrapped(&p) {
+                split_conjuncts(&p)
+            } else {
+                vec![p]
+            }
+        })
+        .collect()
+}
+
+/// Whether the outer parentheses enclose the whole expression
+fn is_wrapped(cel: &str) -> bool {
+    if !(cel.starts_with('(') && cel.ends_with(')')) {
+        return false;
+    }
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    for (i, c) in cel.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '\'' | '"' => quote = Some(c),
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 && i < cel.len() - 1 {
+                        return false;
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+    true
+}
+
+/// Convert a JSON input value to a CEL value, checking it against the declared type
+fn json_to_cel(name: &str, value: &Value, typ: &VarType) -> Result<CelValue> {
+    let mismatch = || {
+        Error::Input(format!(
+            "input '{}' is declared {} but got {}",
+            name,
+            type_name(typ),
+            value
+        ))
+    };
+    match typ {
+        VarType::Bool => value.as_bool().map(CelValue::Bool).ok_or_else(mismatch),
+        VarType::Int => value.as_i64().map(CelValue::Int).ok_or_else(mismatch),
+        VarType::Float => value.as_f64().map(CelValue::Float).ok_or_else(mismatch),
+        VarType::String => value.as_str().map(CelValue::from).ok_or_else(mismatch),
+        VarType::Enum(variants) => {
+            let s = value.as_str().ok_or_else(mismatch)?;
+            if variants.iter().any(|v| v == s) {
+                Ok(CelValue::from(s))
+            } else {
+                Err(Error::Input(format!(
+                    "input '{}' must be one of [{}], got \"{}\"",
+                    name,
+                    variants.join(", "),
+                    s
+                )))
+            }
+        }
+        VarType::List(inner) => {
+            let items = value.as_array().ok_or_else(mismatch)?;
+            let items = items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| json_to_cel(&format!("{}[{}]", name, i), item, inner))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(CelValue::from(items))
+        }
+        VarType::Object(fields) => {
+            let object = value.as_object().ok_or_else(mismatch)?;
+            let mut map = HashMap::new();
+            if fields.is_empty() {
+                for (key, field_value) in object {
+                    map.insert(key.clone(), untyped_json_to_cel(field_value));
+                }
+            } else {
+                for (field, field_typ) in fields {
+                    let path = format!("{}.{}", name, field);
+                    let field_value = object
+                        .get(field)
+                        .ok_or_else(|| Error::Input(format!("missing input '{}'", path)))?;
+                    map.insert(field.clone(), json_to_cel(&path, field_value, field_typ)?);
+                }
+            }
+            Ok(CelValue::from(map))
+        }
+    }
+}
+
+/// Convert JSON without a declared type (fields of a bare `object`)
+pub(crate) fn untyped_json_to_cel(value: &Value) -> CelValue {
+    match value {
+        Value::Null => CelValue::Null,
+        Value::Bool(b) => CelValue::Bool(*b),
+        Value::Number(n) => n
+            .as_i64()
+            .map(CelValue::Int)
+            .unwrap_or_else(|| CelValue::Float(n.as_f64().unwrap_or_default())),
+        Value::String(s) => CelValue::from(s.as_str()),
+        Value::Array(items) => {
+            CelValue::from(items.iter().map(untyped_json_to_cel).collect::<Vec<_>>())
+        }
+        Value::Object(object) => CelValue::from(
+            object
+                .iter()
+                .map(|(k, v)| (k.clone(), untyped_json_to_cel(v)))
+                .collect::<HashMap<_, _>>(),
+        ),
+    }
+}
+
+fn type_name(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "bool".into(),
+        VarType::Int => "int".into(),
+        VarType::Float => "float".into(),
+        VarType::String => "string".into(),
+        VarType::Enum(_) => "enum".into(),
+        VarType::List(inner) => format!("list<{}>", type_name(inner)),
+        VarType::Object(_) => "object".into(),
+    }
+}
+
+/// Evaluate output strings that are CEL expressions (e.g. `amount * 2`)
+///
+/// Strings that only look like identifiers (`requires_review`) but name no
+/// input are literal outputs and are returned unchanged.
+fn resolve_output_value(
+    value: &ConditionValue,
+    vars: &HashMap<String, CelValue>,
+) -> Result<ConditionValue> {
+    let references_inputs = |s: &str| {
+        CelCompiler::extract_variables(s)
+            .is_ok_and(|names| names.iter().all(|n| vars.contains_key(n)))
+    };
+    match value {
+        ConditionValue::String(s) if crate::render::is_expression(s) && references_inputs(s) => {
+            let result = CelCompiler::eval(s, vars)?;
+            from_cel_value(&result)
+                .ok_or_else(|| Error::CelEval(format!("{}: unsupported result {:?}", s, result)))
+        }
+        other => Ok(other.clone()),
+    }
+}
+
+/// Convert spec values, such as the sample inputs of semantic
+/// verification, to CEL variables
+pub(crate) fn cel_vars(inputs: &HashMap<String, ConditionValue>) -> HashMap<String, CelValue> {
+    inputs
+        .iter()
+        .map(|(k, v)| (k.clone(), to_cel_value(v)))
+        .collect()
+}
+
+/// Convert a spec value to a CEL value
+fn to_cel_value(value: &ConditionValue) -> CelValue {
+    match value {
+        ConditionValue::Bool(b) => CelValue::Bool(*b),
+        ConditionValue::Int(i) => CelValue::Int(*i),
+        ConditionValue::Float(f) => CelValue::Float(*f),
+        ConditionValue::String(s) => CelValue::from(s.as_str()),
+        ConditionValue::List(items) => {
+            CelValue::from(items.iter().map(to_cel_value).collect::<Vec<_>>())
+        }
+        ConditionValue::Map(map) => CelValue::from(
+            map.iter()
+                .map(|(k, v)| (k.clone(), to_cel_value(v)))
+                .collect::<HashMap<_, _>>(),
+        ),
+        ConditionValue::Null => CelValue::Null,
+    }
+}
+
+/// Convert an evaluated CEL value back to a spec value
+pub(crate) fn from_cel_value(value: &CelValue) -> Option<ConditionValue> {
+    match value {
+        CelValue::Bool(b) => Some(ConditionValue::Bool(*b)),
+        CelValue::Int(i) => Some(ConditionValue::Int(*i)),
+        CelValue::UInt(u) => Some(ConditionValue::Int(*u as i64)),
+        CelValue::Float(f) => Some(ConditionValue::Float(*f)),
+        CelValue::String(s) => Some(ConditionValue::String(s.to_string())),
+        CelValue::List(items) => items
+            .iter()
+            .map(from_cel_value)
+            .collect::<Option<Vec<_>>>()
+            .map(ConditionValue::List),
+        CelValue::Null => Some(ConditionValue::Null),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn login_spec() -> Spec {
+        Spec::from_yaml(
+            r#"
+id: login_check
+inputs:
+  - name: rate_exceeded
+    type: bool
+  - name: attempts
+    type: int
+outputs:
+  - name: status
+    type: int
+rules:
+  - id: R1
+    when: "rate_exceeded"
+    then: 429
+  - id: R2
+    when: "attempts > 3"
+    then: "attempts * 100"
+default: 200
+"#,
+        )
+        .unwrap()
+    }
+
+    fn inputs(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn test_evaluate_first_matching_rule() {
+        let spec = login_spec();
+        let out = spec
+            .evaluate(&inputs(json!({"rate_exceeded": true, "attempts": 5})))
+            .unwrap();
+        assert_eq!(out, Output::Single(ConditionValue::Int(429)));
+
+        let out = spec
+            .evaluate(&inputs(json!({"rate_exceeded": false, "attempts": 5})))
+            .unwrap();
+        assert_eq!(out, Output::Single(ConditionValue::Int(500)));
+    }
+
+    #[test]
+    fn test_evaluate_accumulate_sums_matching_rules() {
+        let spec = Spec::from_yaml(
+            r#"
+id: risk_score
+mode: accumulate
+inputs:
+  - name: new_account
+    type: bool
+  - name: amount
+    type: int
+outputs:
+  - name: score
+    type: int
+rules:
+  - id: R1
+    when: "new_account"
+    then: 30
+  - id: R2
+    when: "amount > 1000"
+    then: 25
+  - id: R3
+    when: "amount > 5000"
+    then: "amount / 1000"
+default: 5
+"#,
+        )
+        .unwrap();
+        assert_eq!(spec.mode, SpecMode::Accumulate);
+
+        let out = spec
+            .evaluate(&inputs(json!({"new_account": true, "amount": 2000})))
+            .unwrap();
+        assert_eq!(out, Output::Single(ConditionValue::Int(60)));
+
+        let out = spec
+            .evaluate(&inputs(json!({"new_account": true, "amount": 8000})))
+            .unwrap();
+        assert_eq!(out, Output::Single(ConditionValue::Int(68)));
+
+        let out = spec
+            .evaluate(&inputs(json!({"new_account": false, "amount": 10})))
+            .unwrap();
+        assert_eq!(out, Output::Single(ConditionValue::Int(5)));
+    }
+
+    #[test]
+    fn test_evaluate_rejects_violated_postcondition() {
+        let spec = Spec::from_yaml(
+            r#"
+id: refund
+inputs:
+  - name: amount
+    type: int
+  - name: disputed
+    type: bool
+outputs:
+  - name: result
+    type: int
+ensures:
+  - "result >= 0"
+rules:
+  - id: R1
+    when: "disputed"
+    then: "0 - amount"
+  - id: R2
+    when: "!disputed"
+    then: amount
+"#,
+        )
+        .unwrap();
+        assert!(spec.validate().iter().all(|e| !e.contains("ensures")));
+
+        let out = spec
+            .evaluate(&inputs(json!({"amount": 40, "disputed": false})))
+            .unwrap();
+        assert_eq!(out, Output::Single(ConditionValue::Int(40)));
+
+        let err = spec
+            .evaluate(&inputs(json!({"amount": 40, "disputed": true})))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("result >= 0"), "{}", err);
+        assert!(err.contains("-40"), "{}", err);
+    }
+
+    #[test]
+    fn test_evaluate_default_fallback() {
+        let out = login_spec()
+            .evaluate(&inputs(json!({"rate_exceeded": false, "attempts": 1})))
+            .unwrap();
+        assert_eq!(out, Output::Single(ConditionValue::Int(200)));
+    }
+
+    #[test]
+    fn test_evaluate_computed_default() {
+        let spec = Spec::from_yaml(
+            r#"
+id: shipping_fee
+inputs:
+  - name: express
+    type: bool
+  - name: base_fee
+    type: int
+  - name: surcharge
+    type: int
+outputs:
+  - name: fee
+    type: int
+rules:
+  - id: R1
+    when: "express"
+    then: 20
+default: "base_fee + surcharge"
+"#,
+        )
+        .unwrap();
+
+        let out = spec
+            .evaluate(&inputs(
+                json!({"express": false, "base_fee": 5, "surcharge": 3}),
+            ))
+            .unwrap();
+        assert_eq!(out, Output::Single(ConditionValue::Int(8)));
+    }
+
+    #[test]
+    fn test_evaluate_priority_order() {
+        // Lower priority is tried first; equal priorities keep their order
+        let mut spec = login_spec();
+        let both = inputs(json!({"rate_exceeded": true, "attempts": 5}));
+        assert_eq!(
+            spec.evaluate(&both).unwrap(),
+            Output::Single(ConditionValue::Int(429))
+        );
+
+        spec.rules[0].priority = 1;
+        assert_eq!(
+            spec.evaluate(&both).unwrap(),
+            Output::Single(ConditionValue::Int(500))
+        );
+        assert_eq!(spec.matching_rule(&both).unwrap().unwrap().id, "R2");
+        let explanation = spec.explain(&both).unwrap();
+        assert_eq!(explanation.steps[0].rule_id, "R2");
+        assert_eq!(explanation.winner.as_deref(), Some("R2"));
+    }
+
+    #[test]
+    fn test_evaluate_missing_input() {
+        let err = login_spec()
+            .evaluate(&inputs(json!({"rate_exceeded": true})))
+            .unwrap_err();
+        assert!(matches!(err, Error::Input(ref msg) if msg.contains("attempts")));
+    }
+
+    #[test]
+    fn test_evaluate_optional_input() {
+        let spec = Spec::from_yaml(
+            r#"
+id: checkout
+inputs:
+  - name: coupon_code
+    type: string?
+outputs:
+  - name: discount
+    type: int
+rules:
+  - id: R1
+    when: 'coupon_code.startsWith("SAVE")'
+    then: 10
+default: 0
+"#,
+        )
+        .unwrap();
+        assert!(spec.inputs[0].optional);
+
+        let out = spec
+            .evaluate(&inputs(json!({"coupon_code": "SAVE5"})))
+            .unwrap();
+        assert_eq!(out, Output::Single(ConditionValue::Int(10)));
+        for absent in [json!({}), json!({"coupon_code": null})] {
+            let out = spec.evaluate(&inputs(absent)).unwrap();
+            assert_eq!(out, Output::Single(ConditionValue::Int(0)));
+        }
+    }
+
+    #[test]
+    fn test_evaluate_type_mismatch() {
+        let err = login_spec()
+            .evaluate(&inputs(json!({"rate_exceeded": true, "attempts": "five"})))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Input error: input 'attempts' is declared int but got \"five\""
+        );
+    }
+
+    #[test]
+    fn test_explain_names_failing_sub_condition() {
+        let spec = Spec::from_yaml(
+            r#"
+id: unlock
+inputs:
+  - name: role
+    type: string
+  - name: locked
+    type: bool
+outputs:
+  - name: allowed
+    type: bool
+rules:
+  - id: R1
+    when: "role == 'admin'"
+    then: true
+  - id: R2
+    when: "role == 'owner' && locked == true"
+    then: true
+default: false
+"#,
+        )
+        .unwrap();
+
+        let explanation = spec
+            .explain(&inputs(json!({"role": "owner", "locked": false})))
+            .unwrap();
+        assert_eq!(explanation.winner, None);
+        assert_eq!(
+            explanation.output,
+            Some(Output::Single(ConditionValue::Bool(false)))
+        );
+
+        let r2 = &explanation.steps[1];
+        assert!(!r2.matched);
+        assert_eq!(r2.failed.len(), 1);
+        assert_eq!(r2.failed[0].condition, "locked == true");
+        assert!(explanation
+            .to_string()
+            .contains("R2 skipped: locked == true failed, actual false"));
+    }
+
+    #[test]
+    fn test_explain_reports_winner_and_shadowed_rules() {
+        let explanation = login_spec()
+            .explain(&inputs(json!({"rate_exceeded": true, "attempts": 5})))
+            .unwrap();
+        assert_eq!(explanation.winner.as_deref(), Some("R1"));
+        assert!(explanation.steps.iter().all(|s| s.matched));
+        assert!(explanation
+            .to_string()
+            .contains("R2 matched, but R1 has precedence"));
+    }
+}
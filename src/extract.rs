@@ -4,18 +4,24 @@
 //! Useful for documenting/specifying existing systems.
 
 use crate::ast::*;
+use crate::cel::CelCompiler;
 use crate::spec::*;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Extract spec from code AST
 pub fn extract(code: &CodeAst) -> ExtractedSpec {
     Extractor::new().extract(code)
 }
 
+/// Extract one spec per function in the code AST
+pub fn extract_all(code: &CodeAst) -> Vec<ExtractedSpec> {
+    Extractor::new().extract_all(code)
+}
+
 /// Spec extractor
 pub struct Extractor {
-    #[allow(dead_code)]
     config: ExtractorConfig,
 }
 
@@ -24,12 +30,21 @@ pub struct Extractor {
 pub struct ExtractorConfig {
     /// Minimum confidence to include a rule
     pub min_confidence: f32,
+    /// Call names that look like I/O; specs should be pure
+    ///
+    /// Matched case-insensitively against each segment of a call path, so
+    /// `query` flags `db.query(..)` and `db.` flags any call on `db`.
+    pub impure_calls: Vec<String>,
 }
 
 impl Default for ExtractorConfig {
     fn default() -> Self {
         Self {
             min_confidence: 0.5,
+            impure_calls: ["fetch", "query", "read", "write", "http", "db."]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
         }
     }
 }
@@ -75,58 +90,103 @@ impl Extractor {
     }
 
     /// Extract spec from code
+    ///
+    /// Convenience wrapper over [`Extractor::extract_all`] that returns the
+    /// spec with the most rules (the first one on ties).
     pub fn extract(&self, code: &CodeAst) -> ExtractedSpec {
-        if code.functions.is_empty() {
-            return ExtractedSpec {
-                spec: Spec {
-                    id: "unknown".into(),
-                    name: None,
-                    description: None,
-                    inputs: vec![],
-                    outputs: vec![],
-                    rules: vec![],
-                    default: None,
-                    meta: SpecMeta::default(),
-                    scoping: None,
-                },
-                confidence: Confidence {
-                    overall: 0.0,
-                    rules: vec![],
-                },
-                questions: vec!["No functions found in code".into()],
-                warnings: vec![],
-            };
-        }
-
-        // Use first function
-        let func = &code.functions[0];
-        self.extract_from_function(func)
-    }
-
-    fn extract_from_function(&self, func: &Function) -> ExtractedSpec {
+        self.extract_all(code)
+            .into_iter()
+            .reduce(|best, next| {
+                if next.spec.rules.len() > best.spec.rules.len() {
+                    next
+                } else {
+                    best
+                }
+            })
+            .unwrap_or_else(|| self.empty_extraction())
+    }
+
+    /// Extract one spec per function, in source order
+    pub fn extract_all(&self, code: &CodeAst) -> Vec<ExtractedSpec> {
+        code.functions
+            .iter()
+            .map(|func| self.extract_from_function(func, &code.types))
+            .collect()
+    }
+
+    fn empty_extraction(&self) -> ExtractedSpec {
+        ExtractedSpec {
+            spec: Spec {
+                id: "unknown".into(),
+                name: None,
+                description: None,
+                inputs: vec![],
+                outputs: vec![],
+                rules: vec![],
+                default: None,
+                mode: Default::default(),
+                on_no_match: None,
+                ensures: Vec::new(),
+                allow_bitwise: false,
+                schema_version: None,
+                meta: SpecMeta::default(),
+                scoping: None,
+            },
+            confidence: Confidence {
+                overall: 0.0,
+                rules: vec![],
+            },
+            questions: vec!["No functions found in code".into()],
+            warnings: vec![],
+        }
+    }
+
+    fn extract_from_function(&self, func: &Function, types: &[TypeDef]) -> ExtractedSpec {
         let mut rules = Vec::new();
         let mut questions = Vec::new();
         let mut warnings = Vec::new();
         let mut rule_confidences = Vec::new();
 
         // Extract inputs from parameters
-        let inputs: Vec<Variable> = func
+        let mut inputs: Vec<Variable> = func
             .params
             .iter()
             .map(|p| Variable {
                 name: p.name.clone(),
-                typ: self.infer_type(&p.typ),
+                typ: self.resolve_type(&p.typ, types, &mut Vec::new()),
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             })
             .collect();
 
+        // Inputs matched against enum variants become closed enums
+        let mut domains = BTreeMap::new();
+        self.collect_enum_domains(&func.body, &inputs, &mut domains);
+        for input in &mut inputs {
+            if let Some(domain) = domains.get(&input.name) {
+                if domain.variants.is_empty() {
+                    continue;
+                }
+                input.typ = VarType::Enum(domain.variants.clone());
+                input.values = Some(domain.variants.clone());
+                if domain.has_wildcard {
+                    questions.push(format!(
+                        "'{}' has a `_` arm (default case) - are there variants besides {}?",
+                        input.name,
+                        domain.variants.join(", ")
+                    ));
+                }
+            }
+        }
+
         // Extract rules from body
         let mut rule_counter = 0;
         self.extract_rules(
             &func.body,
             &inputs,
-            &mut vec![],
+            &mut Guard::default(),
             &mut rules,
             &mut rule_counter,
             &mut rule_confidences,
@@ -140,6 +200,8 @@ impl Extractor {
             typ: output_type,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }];
 
         // Generate questions
@@ -151,6 +213,18 @@ impl Extractor {
             questions.push("Many rules detected - is this the right granularity?".into());
         }
 
+        // Specs should be pure; I/O belongs in an orchestrator
+        let mut calls = Vec::new();
+        collect_calls(&func.body, &mut calls);
+        for (callee, span) in calls {
+            if let Some(name) = self.impure_name(callee) {
+                warnings.push(format!(
+                    "Impure call `{}` found at line {}:{} (`{}(..)`) - belongs in an Orchestrator",
+                    name, span.start_line, span.start_col, callee
+                ));
+            }
+        }
+
         // Check for missing default
         if !self.has_catch_all(&func.body) {
             warnings.push("No default/catch-all case found".into());
@@ -164,15 +238,27 @@ impl Extractor {
                 / rule_confidences.len() as f32
         };
 
+        // Masked comparisons (`flags & 4 != 0`) are only valid with the
+        // bitwise extension
+        let allow_bitwise = rules
+            .iter()
+            .filter_map(Rule::as_cel)
+            .any(|cel| CelCompiler::uses_bitwise(&cel));
+
         ExtractedSpec {
             spec: Spec {
-                id: func.name.clone(),
-                name: Some(humanize(&func.name)),
+                id: func.qualified_name(),
+                name: Some(humanize(&func.qualified_name())),
                 description: None,
                 inputs,
                 outputs,
                 rules,
                 default: None,
+                mode: Default::default(),
+                on_no_match: None,
+                ensures: Vec::new(),
+                allow_bitwise,
+                schema_version: None,
                 meta: SpecMeta::default(),
                 scoping: None,
             },
@@ -190,32 +276,37 @@ impl Extractor {
         &self,
         node: &AstNode,
         inputs: &[Variable],
-        current_conditions: &mut Vec<Condition>,
+        current: &mut Guard,
         rules: &mut Vec<Rule>,
         counter: &mut usize,
         confidences: &mut Vec<RuleConfidence>,
         _warnings: &mut Vec<String>,
     ) {
         match node {
-            AstNode::Match { arms, .. } => {
+            AstNode::Match {
+                scrutinee, arms, ..
+            } => {
+                let targets = self.scrutinee_vars(scrutinee, inputs);
                 for arm in arms {
-                    let mut arm_conditions = current_conditions.clone();
+                    let mut arm_guard = current.clone();
+                    arm_guard.nested = true;
+                    let mut conditions = Vec::new();
                     let conf =
-                        self.extract_pattern_conditions(&arm.pattern, inputs, &mut arm_conditions);
+                        self.extract_pattern_conditions(&arm.pattern, &targets, &mut conditions);
+                    for condition in conditions {
+                        arm_guard.push(condition);
+                    }
 
                     if arm.pattern.is_catch_all() {
                         // Default case
                         if let Some(output) = self.extract_output(&arm.body) {
                             *counter += 1;
                             let rule_id = format!("R{}", counter);
+                            let (when, conditions) = arm_guard.into_rule_guard();
                             rules.push(Rule {
                                 id: rule_id.clone(),
-                                when: None,
-                                conditions: if arm_conditions.is_empty() {
-                                    None
-                                } else {
-                                    Some(arm_conditions)
-                                },
+                                when,
+                                conditions,
                                 then: Output::Single(output),
                                 priority: *counter as i32,
                                 description: Some("Default case".into()),
@@ -229,14 +320,11 @@ impl Extractor {
                     } else if let Some(output) = self.extract_output(&arm.body) {
                         *counter += 1;
                         let rule_id = format!("R{}", counter);
+                        let (when, conditions) = arm_guard.into_rule_guard();
                         rules.push(Rule {
                             id: rule_id.clone(),
-                            when: None,
-                            conditions: if arm_conditions.is_empty() {
-                                None
-                            } else {
-                                Some(arm_conditions)
-                            },
+                            when,
+                            conditions,
                             then: Output::Single(output),
                             priority: *counter as i32,
                             description: None,
@@ -257,20 +345,18 @@ impl Extractor {
                 ..
             } => {
                 // Then branch
-                let mut then_conditions = current_conditions.clone();
-                let conf = self.extract_expr_conditions(condition, &mut then_conditions, false);
+                let mut then_guard = current.clone();
+                then_guard.nested = true;
+                let conf = self.extract_expr_conditions(condition, &mut then_guard, false);
 
                 if let Some(output) = self.extract_output(then_branch) {
                     *counter += 1;
                     let rule_id = format!("R{}", counter);
+                    let (when, conditions) = then_guard.into_rule_guard();
                     rules.push(Rule {
                         id: rule_id.clone(),
-                        when: None,
-                        conditions: if then_conditions.is_empty() {
-                            None
-                        } else {
-                            Some(then_conditions)
-                        },
+                        when,
+                        conditions,
                         then: Output::Single(output),
                         priority: *counter as i32,
                         description: None,
@@ -282,12 +368,10 @@ impl Extractor {
                     });
                 } else {
                     // Recurse into then branch
-                    let mut then_conds = current_conditions.clone();
-                    self.extract_expr_conditions(condition, &mut then_conds, false);
                     self.extract_rules(
                         then_branch,
                         inputs,
-                        &mut then_conds,
+                        &mut then_guard,
                         rules,
                         counter,
                         confidences,
@@ -297,12 +381,12 @@ impl Extractor {
 
                 // Else branch
                 if let Some(else_node) = else_branch {
-                    let mut else_conditions = current_conditions.clone();
-                    self.extract_expr_conditions(condition, &mut else_conditions, true);
+                    let mut else_guard = current.clone();
+                    self.extract_expr_conditions(condition, &mut else_guard, true);
                     self.extract_rules(
                         else_node,
                         inputs,
-                        &mut else_conditions,
+                        &mut else_guard,
                         rules,
                         counter,
                         confidences,
@@ -318,7 +402,7 @@ impl Extractor {
                 self.extract_rules(
                     inner,
                     inputs,
-                    current_conditions,
+                    current,
                     rules,
                     counter,
                     confidences,
@@ -326,17 +410,41 @@ impl Extractor {
                 );
             }
 
-            AstNode::Return {
-                value: Some(inner), ..
-            } => {
-                if let Some(output) = self.extract_output(inner) {
-                    if !current_conditions.is_empty() {
+            // The final `else` of the top-level chain: whatever every
+            // earlier branch left over, so the catch-all
+            AstNode::Return { value: Some(_), .. } | AstNode::Literal { .. }
+                if !current.is_empty() && !current.nested =>
+            {
+                if let Some(output) = self.extract_output(node) {
+                    *counter += 1;
+                    let rule_id = format!("R{}", counter);
+                    rules.push(Rule {
+                        id: rule_id.clone(),
+                        when: None,
+                        conditions: None,
+                        then: Output::Single(output),
+                        priority: *counter as i32,
+                        description: Some("Default case".into()),
+                    });
+                    confidences.push(RuleConfidence {
+                        rule_id,
+                        confidence: 0.8,
+                        reason: "Final else".into(),
+                    });
+                }
+            }
+
+            // An early return, or the literal an `else` branch yields
+            AstNode::Return { value: Some(_), .. } | AstNode::Literal { .. } => {
+                if let Some(output) = self.extract_output(node) {
+                    if !current.is_empty() {
                         *counter += 1;
                         let rule_id = format!("R{}", counter);
+                        let (when, conditions) = current.clone().into_rule_guard();
                         rules.push(Rule {
                             id: rule_id.clone(),
-                            when: None,
-                            conditions: Some(current_conditions.clone()),
+                            when,
+                            conditions,
                             then: Output::Single(output),
                             priority: *counter as i32,
                             description: None,
@@ -344,7 +452,11 @@ impl Extractor {
                         confidences.push(RuleConfidence {
                             rule_id,
                             confidence: 0.7,
-                            reason: "Early return".into(),
+                            reason: if matches!(node, AstNode::Return { .. }) {
+                                "Early return".into()
+                            } else {
+                                "Else branch".into()
+                            },
                         });
                     }
                 }
@@ -354,33 +466,112 @@ impl Extractor {
         }
     }
 
+    /// Input names a match scrutinee binds its patterns to, positionally
+    ///
+    /// `match status` targets `status`; `match (a, b)` targets `a` and `b`.
+    /// Anything else falls back to the parameters in declaration order.
+    fn scrutinee_vars(&self, scrutinee: &AstNode, inputs: &[Variable]) -> Vec<String> {
+        let input_name = |node: &AstNode| match node {
+            AstNode::Var { name, .. } if inputs.iter().any(|i| &i.name == name) => {
+                Some(name.clone())
+            }
+            _ => None,
+        };
+        let positional = || inputs.iter().map(|i| i.name.clone()).collect::<Vec<_>>();
+
+        match scrutinee {
+            AstNode::Tuple { elements, .. } => {
+                let fallback = positional();
+                elements
+                    .iter()
+                    .enumerate()
+                    .map(|(i, e)| {
+                        input_name(e)
+                            .or_else(|| fallback.get(i).cloned())
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            }
+            other => input_name(other)
+                .map(|name| vec![name])
+                .unwrap_or_else(positional),
+        }
+    }
+
+    /// Collect enum variants each input is matched against
+    fn collect_enum_domains(
+        &self,
+        node: &AstNode,
+        inputs: &[Variable],
+        domains: &mut BTreeMap<String, EnumDomain>,
+    ) {
+        match node {
+            AstNode::Match {
+                scrutinee, arms, ..
+            } => {
+                let targets = self.scrutinee_vars(scrutinee, inputs);
+                for arm in arms {
+                    match &arm.pattern {
+                        Pattern::Tuple(elements) => {
+                            for (target, elem) in targets.iter().zip(elements) {
+                                collect_variants(target, elem, domains);
+                            }
+                        }
+                        pattern => {
+                            if let Some(target) = targets.first() {
+                                collect_variants(target, pattern, domains);
+                            }
+                        }
+                    }
+                    self.collect_enum_domains(&arm.body, inputs, domains);
+                }
+            }
+            AstNode::If {
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                self.collect_enum_domains(then_branch, inputs, domains);
+                if let Some(else_node) = else_branch {
+                    self.collect_enum_domains(else_node, inputs, domains);
+                }
+            }
+            AstNode::Block {
+                statements, result, ..
+            } => {
+                for stmt in statements {
+                    self.collect_enum_domains(stmt, inputs, domains);
+                }
+                if let Some(inner) = result {
+                    self.collect_enum_domains(inner, inputs, domains);
+                }
+            }
+            AstNode::Return {
+                value: Some(inner), ..
+            } => self.collect_enum_domains(inner, inputs, domains),
+            _ => {}
+        }
+    }
+
     fn extract_pattern_conditions(
         &self,
         pattern: &Pattern,
-        inputs: &[Variable],
+        targets: &[String],
         conditions: &mut Vec<Condition>,
     ) -> f32 {
         match pattern {
             Pattern::Tuple(elements) => {
                 let mut conf = 1.0;
-                for (i, elem) in elements.iter().enumerate() {
-                    if let Some(input) = inputs.get(i) {
-                        conf *= self.extract_single_pattern(&input.name, elem, conditions);
-                    }
+                for (target, elem) in targets.iter().zip(elements) {
+                    conf *= self.extract_single_pattern(target, elem, conditions);
                 }
                 conf
             }
 
-            Pattern::Literal(lit) => {
-                if let Some(input) = inputs.first() {
-                    conditions.push(Condition {
-                        var: input.name.clone(),
-                        op: ConditionOp::Eq,
-                        value: self.literal_to_value(lit),
-                    });
-                }
-                1.0
-            }
+            Pattern::Literal(_) | Pattern::Constructor { .. } => match targets.first() {
+                Some(target) => self.extract_single_pattern(target, pattern, conditions),
+                None => 1.0,
+            },
 
             Pattern::Wildcard | Pattern::Binding(_) => 0.9,
 
@@ -403,17 +594,25 @@ impl Extractor {
                 });
                 1.0
             }
+            Pattern::Constructor { name, fields } => {
+                conditions.push(Condition {
+                    var: var_name.to_string(),
+                    op: ConditionOp::Eq,
+                    value: ConditionValue::String(variant_name(name).to_string()),
+                });
+                // Payload fields are not modelled
+                if fields.iter().all(Pattern::is_catch_all) {
+                    1.0
+                } else {
+                    0.7
+                }
+            }
             Pattern::Wildcard | Pattern::Binding(_) => 1.0,
             _ => 0.5,
         }
     }
 
-    fn extract_expr_conditions(
-        &self,
-        expr: &AstNode,
-        conditions: &mut Vec<Condition>,
-        negated: bool,
-    ) -> f32 {
+    fn extract_expr_conditions(&self, expr: &AstNode, guard: &mut Guard, negated: bool) -> f32 {
         match expr {
             AstNode::Binary {
                 op: BinaryOp::And,
@@ -421,44 +620,95 @@ impl Extractor {
                 right,
                 ..
             } if !negated => {
-                let c1 = self.extract_expr_conditions(left, conditions, false);
-                let c2 = self.extract_expr_conditions(right, conditions, false);
+                let c1 = self.extract_expr_conditions(left, guard, false);
+                let c2 = self.extract_expr_conditions(right, guard, false);
+                (c1 + c2) / 2.0
+            }
+
+            // !(a || b) is !a && !b
+            AstNode::Binary {
+                op: BinaryOp::Or,
+                left,
+                right,
+                ..
+            } if negated => {
+                let c1 = self.extract_expr_conditions(left, guard, true);
+                let c2 = self.extract_expr_conditions(right, guard, true);
                 (c1 + c2) / 2.0
             }
 
             AstNode::Binary {
                 op, left, right, ..
             } => {
-                if let AstNode::Var { name, .. } = left.as_ref() {
+                let compares = comparison_symbol(*op, false).is_some();
+                if let Some(name) = self.condition_operand(left).filter(|_| compares) {
                     if let Some(value) = self.node_to_value(right) {
                         let cond_op = if negated {
                             self.negate_op(self.binary_to_op(*op))
                         } else {
                             self.binary_to_op(*op)
                         };
-                        conditions.push(Condition {
-                            var: name.clone(),
+                        guard.push(Condition {
+                            var: name,
                             op: cond_op,
                             value,
                         });
                         return 1.0;
                     }
+                    // Input against input (`min_age <= user_age`): no
+                    // literal to hold, so keep the comparison as CEL
+                    if let (Some(symbol), Some(other)) = (
+                        comparison_symbol(*op, negated),
+                        self.condition_operand(right),
+                    ) {
+                        guard.push_cel(format!("{} {} {}", name, symbol, other));
+                        return 1.0;
+                    }
+                }
+                // A masked input (`flags & 4 != 0`) has no structured
+                // form either
+                if let (Some(masked), Some(symbol), Some(value)) = (
+                    self.masked_operand(left),
+                    comparison_symbol(*op, negated),
+                    self.expr_cel(right),
+                ) {
+                    guard.push_cel(format!("{} {} {}", masked, symbol, value));
+                    return 1.0;
+                }
+                // `a && b` in an else branch, `a || b`, arithmetic: keep
+                // the whole expression so later branches stay exclusive
+                match self.expr_cel(expr) {
+                    Some(cel) if negated => {
+                        guard.push_cel(format!("!({})", cel));
+                        0.8
+                    }
+                    Some(cel) => {
+                        guard.push_cel(cel);
+                        0.8
+                    }
+                    None => 0.5,
                 }
-                0.5
             }
 
             AstNode::Unary {
                 op: UnaryOp::Not,
                 operand,
                 ..
-            } => self.extract_expr_conditions(operand, conditions, !negated),
+            } => self.extract_expr_conditions(operand, guard, !negated),
 
-            AstNode::Var { name, .. } => {
-                conditions.push(Condition {
-                    var: name.clone(),
-                    op: ConditionOp::Eq,
-                    value: ConditionValue::Bool(!negated),
-                });
+            AstNode::Var { .. } | AstNode::Field { .. } => {
+                let Some(name) = self.condition_operand(expr) else {
+                    return 0.5;
+                };
+                if negated {
+                    guard.push_cel(format!("!{}", name));
+                } else {
+                    guard.push(Condition {
+                        var: name,
+                        op: ConditionOp::Eq,
+                        value: ConditionValue::Bool(true),
+                    });
+                }
                 1.0
             }
 
@@ -466,6 +716,43 @@ impl Extractor {
         }
     }
 
+    /// CEL for a condition the structured form can't hold, if every part
+    /// of it is an input, literal or operator
+    fn expr_cel(&self, node: &AstNode) -> Option<String> {
+        let operand = |node: &AstNode| {
+            let cel = self.expr_cel(node)?;
+            Some(if matches!(node, AstNode::Binary { .. }) {
+                format!("({})", cel)
+            } else {
+                cel
+            })
+        };
+        match node {
+            AstNode::Var { .. } | AstNode::Field { .. } => self.condition_operand(node),
+            AstNode::Literal { value, .. } => match self.literal_to_value(value) {
+                ConditionValue::Bool(b) => Some(b.to_string()),
+                ConditionValue::Int(i) => Some(i.to_string()),
+                ConditionValue::Float(f) => Some(format!("{:?}", f)),
+                ConditionValue::String(s) => Some(format!("{:?}", s)),
+                _ => None,
+            },
+            AstNode::Binary {
+                op, left, right, ..
+            } => Some(format!("{} {} {}", operand(left)?, op, operand(right)?)),
+            AstNode::Unary {
+                op: UnaryOp::Not,
+                operand: inner,
+                ..
+            } => Some(format!("!{}", operand(inner)?)),
+            AstNode::Unary {
+                op: UnaryOp::Neg,
+                operand: inner,
+                ..
+            } => Some(format!("-{}", operand(inner)?)),
+            _ => None,
+        }
+    }
+
     fn extract_output(&self, node: &AstNode) -> Option<ConditionValue> {
         match node {
             AstNode::Literal { value, .. } => Some(self.literal_to_value(value)),
@@ -480,6 +767,20 @@ impl Extractor {
         }
     }
 
+    /// The configured I/O-looking name a call path matches, if any
+    fn impure_name(&self, callee: &str) -> Option<&str> {
+        let segments: Vec<String> = callee
+            .split(['.', ':'])
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_lowercase())
+            .collect();
+        self.config
+            .impure_calls
+            .iter()
+            .map(|name| name.trim_end_matches('.'))
+            .find(|name| segments.iter().any(|s| s == &name.to_lowercase()))
+    }
+
     fn has_catch_all(&self, node: &AstNode) -> bool {
         match node {
             AstNode::Match { arms, .. } => arms.iter().any(|a| a.pattern.is_catch_all()),
@@ -508,7 +809,21 @@ impl Extractor {
     }
 
     fn infer_type(&self, type_str: &str) -> VarType {
-        match type_str.to_lowercase().as_str() {
+        let lower = type_str.trim().to_lowercase();
+        let bare = lower.trim_start_matches('&');
+
+        // Vec<T>, &[T], T[], list[T], List<T>, Array<T>
+        let inner = ["vec<", "list<", "array<"]
+            .iter()
+            .find_map(|p| bare.strip_prefix(p).and_then(|r| r.strip_suffix('>')))
+            .or_else(|| bare.strip_prefix("list[").and_then(|r| r.strip_suffix(']')))
+            .or_else(|| bare.strip_prefix('[').and_then(|r| r.strip_suffix(']')))
+            .or_else(|| bare.strip_suffix("[]"));
+        if let Some(inner) = inner {
+            return VarType::List(Box::new(self.infer_type(inner)));
+        }
+
+        match lower.as_str() {
             "bool" => VarType::Bool,
             "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
             | "u128" | "usize" | "int" | "number" => VarType::Int,
@@ -518,6 +833,32 @@ impl Extractor {
         }
     }
 
+    /// Like [`Self::infer_type`], but a type declared in the same source
+    /// becomes an object of its fields' types; `seen` stops recursive types
+    fn resolve_type<'a>(
+        &self,
+        type_str: &str,
+        types: &'a [TypeDef],
+        seen: &mut Vec<&'a str>,
+    ) -> VarType {
+        let bare = type_str.trim().trim_start_matches('&').trim();
+        let Some(def) = types
+            .iter()
+            .find(|t| t.name == bare && !seen.contains(&t.name.as_str()))
+        else {
+            return self.infer_type(type_str);
+        };
+
+        seen.push(&def.name);
+        let fields = def
+            .fields
+            .iter()
+            .map(|f| (f.name.clone(), self.resolve_type(&f.typ, types, seen)))
+            .collect();
+        seen.pop();
+        VarType::Object(fields)
+    }
+
     fn infer_output_type(&self, rules: &[Rule]) -> VarType {
         for rule in rules {
             match &rule.then {
@@ -542,6 +883,45 @@ impl Extractor {
         }
     }
 
+    /// Left-hand side of a comparison: an input or a field of one
+    /// (`user.age`)
+    fn condition_operand(&self, node: &AstNode) -> Option<String> {
+        match node {
+            AstNode::Var { name, .. } => Some(name.clone()),
+            AstNode::Field { object, field, .. } => match object.as_ref() {
+                AstNode::Var { .. } | AstNode::Field { .. } => self
+                    .condition_operand(object)
+                    .map(|path| format!("{}.{}", path, field)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// CEL for an input masked or shifted by a literal (`flags & 4`),
+    /// which needs `allow_bitwise`
+    fn masked_operand(&self, node: &AstNode) -> Option<String> {
+        match node {
+            AstNode::Binary {
+                op:
+                    op @ (BinaryOp::BitAnd
+                    | BinaryOp::BitOr
+                    | BinaryOp::BitXor
+                    | BinaryOp::Shl
+                    | BinaryOp::Shr),
+                left,
+                right,
+                ..
+            } => match (self.condition_operand(left), self.node_to_value(right)) {
+                (Some(name), Some(ConditionValue::Int(operand))) => {
+                    Some(format!("{} {} {}", name, op, operand))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
     fn node_to_value(&self, node: &AstNode) -> Option<ConditionValue> {
         match node {
             AstNode::Literal { value, .. } => Some(self.literal_to_value(value)),
@@ -580,6 +960,235 @@ impl Default for Extractor {
     }
 }
 
+/// Conditions on the path to a rule, in the order they were met
+#[derive(Debug, Clone, Default)]
+struct Guard {
+    terms: Vec<GuardTerm>,
+    /// Inside a `then` branch or match arm rather than only the `else`
+    /// branches of the top-level chain, so a final `else` is not the
+    /// function's catch-all
+    nested: bool,
+}
+
+#[derive(Debug, Clone)]
+enum GuardTerm {
+    /// Comparison of an input against a literal
+    Condition(Condition),
+    /// Anything a [`Condition`] can't hold (two inputs compared, a masked
+    /// input, a negated flag or compound condition), as CEL
+    Cel(String),
+}
+
+impl Guard {
+    fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    fn push(&mut self, condition: Condition) {
+        self.terms.push(GuardTerm::Condition(condition));
+    }
+
+    fn push_cel(&mut self, cel: String) {
+        self.terms.push(GuardTerm::Cel(cel));
+    }
+
+    /// `when` and `conditions` for a rule guarded by this
+    ///
+    /// Structured conditions can only hold literals, so once a term needs
+    /// CEL the whole guard is written as a CEL `when` instead.
+    fn into_rule_guard(self) -> (Option<WhenClause>, Option<Vec<Condition>>) {
+        if self
+            .terms
+            .iter()
+            .all(|t| matches!(t, GuardTerm::Condition(_)))
+        {
+            let conditions: Vec<Condition> = self
+                .terms
+                .into_iter()
+                .filter_map(|t| match t {
+                    GuardTerm::Condition(c) => Some(c),
+                    GuardTerm::Cel(_) => None,
+                })
+                .collect();
+            return (None, (!conditions.is_empty()).then_some(conditions));
+        }
+        let cel: Vec<String> = self
+            .terms
+            .into_iter()
+            .map(|t| match t {
+                GuardTerm::Condition(Condition {
+                    var,
+                    op: ConditionOp::Eq,
+                    value: ConditionValue::Bool(flag),
+                }) => {
+                    if flag {
+                        var
+                    } else {
+                        format!("!{}", var)
+                    }
+                }
+                GuardTerm::Condition(c) => c.to_cel(),
+                GuardTerm::Cel(cel) if cel.contains("||") => format!("({})", cel),
+                GuardTerm::Cel(cel) => cel,
+            })
+            .collect();
+        (Some(WhenClause::Single(cel.join(" && "))), None)
+    }
+}
+
+/// CEL operator for a comparison, flipped when the branch is negated
+fn comparison_symbol(op: BinaryOp, negated: bool) -> Option<&'static str> {
+    let (plain, flipped) = match op {
+        BinaryOp::Eq => ("==", "!="),
+        BinaryOp::Ne => ("!=", "=="),
+        BinaryOp::Lt => ("<", ">="),
+        BinaryOp::Le => ("<=", ">"),
+        BinaryOp::Gt => (">", "<="),
+        BinaryOp::Ge => (">=", "<"),
+        _ => return None,
+    };
+    Some(if negated { flipped } else { plain })
+}
+
+/// Variants an input is matched against, in first-seen order
+#[derive(Debug, Default)]
+struct EnumDomain {
+    variants: Vec<String>,
+    /// A `_`/binding arm covers the remaining variants (the default case)
+    has_wildcard: bool,
+}
+
+fn collect_variants(target: &str, pattern: &Pattern, domains: &mut BTreeMap<String, EnumDomain>) {
+    match pattern {
+        Pattern::Constructor { name, .. } => {
+            let domain = domains.entry(target.to_string()).or_default();
+            let variant = variant_name(name).to_string();
+            if !domain.variants.contains(&variant) {
+                domain.variants.push(variant);
+            }
+        }
+        Pattern::Or(alternatives) => {
+            for alt in alternatives {
+                collect_variants(target, alt, domains);
+            }
+        }
+        Pattern::Wildcard | Pattern::Binding(_) => {
+            domains.entry(target.to_string()).or_default().has_wildcard = true;
+        }
+        _ => {}
+    }
+}
+
+/// Every call in a function body with its callee path, in source order
+fn collect_calls<'a>(node: &'a AstNode, calls: &mut Vec<(&'a str, Span)>) {
+    match node {
+        AstNode::Call {
+            function,
+            args,
+            span,
+        } => {
+            calls.push((function.as_str(), *span));
+            args.iter().for_each(|n| collect_calls(n, calls));
+        }
+        AstNode::Binary { left, right, .. } => {
+            collect_calls(left, calls);
+            collect_calls(right, calls);
+        }
+        AstNode::Unary { operand, .. } => collect_calls(operand, calls),
+        AstNode::If {
+            condition,
+            then_branch,
+            else_branch,
+            ..
+        } => {
+            collect_calls(condition, calls);
+            collect_calls(then_branch, calls);
+            if let Some(else_branch) = else_branch {
+                collect_calls(else_branch, calls);
+            }
+        }
+        AstNode::Match {
+            scrutinee, arms, ..
+        } => {
+            collect_calls(scrutinee, calls);
+            for arm in arms {
+                if let Some(guard) = &arm.guard {
+                    collect_calls(guard, calls);
+                }
+                collect_calls(&arm.body, calls);
+            }
+        }
+        AstNode::Block {
+            statements, result, ..
+        } => {
+            statements.iter().for_each(|n| collect_calls(n, calls));
+            if let Some(result) = result {
+                collect_calls(result, calls);
+            }
+        }
+        AstNode::Return { value, .. } => {
+            if let Some(value) = value {
+                collect_calls(value, calls);
+            }
+        }
+        AstNode::Let { value, .. } | AstNode::Await { expr: value, .. } => {
+            collect_calls(value, calls)
+        }
+        AstNode::Field { object, .. } => collect_calls(object, calls),
+        AstNode::Index { object, index, .. } => {
+            collect_calls(object, calls);
+            collect_calls(index, calls);
+        }
+        AstNode::Tuple { elements, .. } | AstNode::Array { elements, .. } => {
+            elements.iter().for_each(|n| collect_calls(n, calls));
+        }
+        AstNode::For {
+            start, end, body, ..
+        } => {
+            collect_calls(start, calls);
+            collect_calls(end, calls);
+            collect_calls(body, calls);
+        }
+        AstNode::ForEach {
+            collection, body, ..
+        } => {
+            collect_calls(collection, calls);
+            collect_calls(body, calls);
+        }
+        AstNode::While {
+            condition, body, ..
+        } => {
+            collect_calls(condition, calls);
+            collect_calls(body, calls);
+        }
+        AstNode::Try {
+            try_block,
+            catch_block,
+            finally_block,
+            ..
+        } => {
+            collect_calls(try_block, calls);
+            for block in [catch_block, finally_block].into_iter().flatten() {
+                collect_calls(block, calls);
+            }
+        }
+        AstNode::Assign { target, value, .. } => {
+            collect_calls(target, calls);
+            collect_calls(value, calls);
+        }
+        AstNode::Closure { body, .. } => collect_calls(body, calls),
+        AstNode::Literal { .. }
+        | AstNode::Var { .. }
+        | AstNode::Unknown { .. }
+        | AstNode::SyntaxError { .. } => {}
+    }
+}
+
+/// `Status::Active` -> `Active`
+fn variant_name(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
 fn humanize(s: &str) -> String {
     s.replace('_', " ")
         .split_whitespace()
@@ -624,6 +1233,15 @@ impl ExtractedSpec {
 
         out
     }
+
+    /// Format several extractions as a multi-document YAML stream
+    pub fn to_yaml_stream(specs: &[ExtractedSpec]) -> String {
+        specs
+            .iter()
+            .map(|s| s.to_yaml())
+            .collect::<Vec<_>>()
+            .join("---\n")
+    }
 }
 
 #[cfg(test)]
@@ -666,4 +1284,259 @@ fn check(a: bool, b: bool) -> i32 {
         assert_eq!(extracted.spec.inputs.len(), 2);
         assert_eq!(extracted.spec.rules.len(), 3);
     }
+
+    #[test]
+    fn test_extract_all_multiple_functions() {
+        let code = r#"
+fn shipping(express: bool) -> i32 {
+    match express {
+        true => 10,
+        false => 5,
+    }
+}
+
+fn tier(a: bool, b: bool) -> i32 {
+    match (a, b) {
+        (true, true) => 1,
+        (true, false) => 2,
+        (false, _) => 3,
+    }
+}
+"#;
+        let ast = parse_rust(code).unwrap();
+        let all = extract_all(&ast);
+
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].spec.id, "shipping");
+        assert_eq!(all[0].spec.rules.len(), 2);
+        assert_eq!(all[1].spec.id, "tier");
+        assert_eq!(all[1].spec.rules.len(), 3);
+        assert!(all.iter().all(|e| e.confidence.overall > 0.0));
+
+        // Single-spec convenience picks the function with the most rules
+        assert_eq!(extract(&ast).spec.id, "tier");
+
+        let stream = ExtractedSpec::to_yaml_stream(&all);
+        assert_eq!(stream.matches("---\n").count(), 1);
+        assert!(stream.contains("id: shipping") && stream.contains("id: tier"));
+    }
+
+    #[test]
+    fn test_infer_list_types() {
+        let extractor = Extractor::new();
+        let strings = VarType::List(Box::new(VarType::String));
+
+        assert_eq!(extractor.infer_type("Vec<String>"), strings);
+        assert_eq!(extractor.infer_type("&[String]"), strings);
+        assert_eq!(extractor.infer_type("string[]"), strings);
+        assert_eq!(extractor.infer_type("list[str]"), strings);
+        assert_eq!(
+            extractor.infer_type("Vec<Vec<i64>>"),
+            VarType::List(Box::new(VarType::List(Box::new(VarType::Int))))
+        );
+
+        let code = r#"
+fn flagged(items: Vec<String>, strict: bool) -> bool {
+    match strict {
+        true => true,
+        false => false,
+    }
+}
+"#;
+        let extracted = extract(&parse_rust(code).unwrap());
+        assert_eq!(extracted.spec.inputs[0].typ, strings);
+    }
+
+    #[test]
+    fn test_extract_enum_from_match_patterns() {
+        let code = r#"
+fn priority(urgent: bool, status: Status) -> i32 {
+    match status {
+        Status::Active => 1,
+        Status::Pending => 2,
+        Status::Closed => 3,
+        _ => 0,
+    }
+}
+"#;
+        let extracted = extract(&parse_rust(code).unwrap());
+        let status = &extracted.spec.inputs[1];
+        let variants = vec![
+            "Active".to_string(),
+            "Pending".to_string(),
+            "Closed".to_string(),
+        ];
+
+        assert_eq!(status.typ, VarType::Enum(variants.clone()));
+        assert_eq!(status.values, Some(variants));
+        assert_eq!(extracted.spec.inputs[0].typ, VarType::Bool);
+
+        // Variant arms condition on the scrutinee; `_` is the default case
+        let rules = &extracted.spec.rules;
+        assert_eq!(rules.len(), 4);
+        let first = &rules[0].conditions.as_ref().unwrap()[0];
+        assert_eq!(first.var, "status");
+        assert_eq!(first.value, ConditionValue::String("Active".into()));
+        assert_eq!(rules[3].description.as_deref(), Some("Default case"));
+        assert!(extracted.questions.iter().any(|q| q.contains("`_` arm")));
+    }
+
+    #[test]
+    fn test_impure_call_warning() {
+        let code = r#"
+fn risk(db: Db, id: i64) -> i32 {
+    if db.query(id) > 10 {
+        1
+    } else {
+        0
+    }
+}
+"#;
+        let extracted = extract(&parse_rust(code).unwrap());
+        let warning = extracted
+            .warnings
+            .iter()
+            .find(|w| w.starts_with("Impure call"))
+            .expect("impurity warning");
+        assert!(warning.contains("`query`"));
+        assert!(warning.contains("line 3:"));
+        assert!(warning.contains("`db.query(..)`"));
+        assert!(warning.contains("belongs in an Orchestrator"));
+
+        // The name list is configurable
+        let lenient = Extractor::with_config(ExtractorConfig {
+            impure_calls: vec![],
+            ..ExtractorConfig::default()
+        });
+        let extracted = lenient.extract(&parse_rust(code).unwrap());
+        assert!(!extracted.warnings.iter().any(|w| w.starts_with("Impure")));
+    }
+
+    #[test]
+    fn test_extract_bitwise_mask() {
+        let code = r#"
+fn can_edit(flags: i64) -> bool {
+    if flags & 4 != 0 {
+        true
+    } else {
+        false
+    }
+}
+"#;
+        let extracted = extract(&parse_rust(code).unwrap());
+        let rules = &extracted.spec.rules;
+        // The mask is not an input, so it stays out of structured conditions
+        assert!(rules.iter().all(|r| r.conditions.is_none()));
+        assert_eq!(rules[0].as_cel().as_deref(), Some("flags & 4 != 0"));
+        assert_eq!(rules[1].description.as_deref(), Some("Default case"));
+        assert!(extracted.spec.allow_bitwise);
+        assert!(extracted.spec.typecheck().is_empty());
+    }
+
+    #[test]
+    fn test_extract_input_comparison() {
+        let code = r#"
+fn old_enough(min_age: i64, user_age: i64, banned: bool) -> bool {
+    if banned {
+        false
+    } else if min_age <= user_age {
+        true
+    } else {
+        false
+    }
+}
+"#;
+        let extracted = extract(&parse_rust(code).unwrap());
+        let rules = &extracted.spec.rules;
+        assert_eq!(rules[0].as_cel().as_deref(), Some("banned == true"));
+
+        // Comparing two inputs keeps the whole guard as CEL
+        assert_eq!(
+            rules[1].as_cel().as_deref(),
+            Some("!banned && min_age <= user_age")
+        );
+        assert!(rules[1].conditions.is_none());
+        assert_eq!(rules[2].as_cel(), None);
+        assert!(extracted.spec.typecheck().is_empty());
+    }
+
+    #[test]
+    fn test_extract_struct_field_types() {
+        let code = r#"
+struct User {
+    tier: String,
+    age: u32,
+}
+
+fn adult(user: User) -> bool {
+    if user.age >= 18 {
+        true
+    } else {
+        false
+    }
+}
+"#;
+        let ast = parse_rust(code).unwrap();
+        assert_eq!(ast.get_type("User").map(|t| t.fields.len()), Some(2));
+
+        let extracted = extract(&ast);
+        assert_eq!(
+            extracted.spec.inputs[0].typ,
+            VarType::Object(vec![
+                ("tier".into(), VarType::String),
+                ("age".into(), VarType::Int),
+            ])
+        );
+        let rules = &extracted.spec.rules;
+        assert_eq!(rules[0].as_cel().as_deref(), Some("user.age >= 18"));
+        assert_eq!(rules[1].description.as_deref(), Some("Default case"));
+        assert!(extracted.spec.typecheck().is_empty());
+    }
+
+    #[test]
+    fn test_extract_else_if_chain() {
+        let code = r#"
+fn pick(a: bool, b: bool) -> i32 {
+    if a {
+        1
+    } else if b {
+        2
+    } else {
+        3
+    }
+}
+"#;
+        let extracted = extract(&parse_rust(code).unwrap());
+        let rules = &extracted.spec.rules;
+        assert_eq!(rules.len(), 3);
+        assert_eq!(rules[0].as_cel().as_deref(), Some("a == true"));
+
+        // Each branch excludes the ones before it
+        let r2 = rules[1].as_cel().unwrap();
+        assert!(r2.contains("!a"), "R2 was {}", r2);
+        assert_eq!(r2, "!a && b");
+
+        // The final else catches everything left over
+        assert_eq!(rules[2].as_cel(), None);
+        assert_eq!(rules[2].description.as_deref(), Some("Default case"));
+        assert_eq!(rules[2].then, Output::Single(ConditionValue::Int(3)));
+
+        // A compound condition is negated as a whole, not dropped
+        let code = r#"
+fn ship(express: bool, heavy: bool, remote: bool) -> i32 {
+    if express && heavy {
+        30
+    } else if remote {
+        20
+    } else {
+        10
+    }
+}
+"#;
+        let extracted = extract(&parse_rust(code).unwrap());
+        assert_eq!(
+            extracted.spec.rules[1].as_cel().as_deref(),
+            Some("!(express && heavy) && remote")
+        );
+    }
 }
@@ -6,6 +6,11 @@
 //! - TypeScript (via prettier - external tool)
 //! - Python (via black or ruff - external tool)
 //! - Go (via gofmt - external tool)
+//! - Kotlin (via ktlint - external tool)
+//! - Swift (via swift-format - external tool)
+//! - PHP (via php-cs-fixer - external tool)
+//! - Ruby (via rubocop - external tool)
+//! - Elixir (via mix format - external tool)
 //! - Java, C# (passthrough - no formatter yet)
 
 use crate::cel::Target;
@@ -21,6 +26,11 @@ pub fn format_code(code: &str, target: Target) -> Result<String, FormatError> {
         Target::Go => format_go(code),
         Target::Java => Ok(basic_format_java(code)),
         Target::CSharp => Ok(basic_format_csharp(code)),
+        Target::Kotlin => format_kotlin(code),
+        Target::Swift => format_swift(code),
+        Target::Php => format_php(code),
+        Target::Ruby => format_ruby(code),
+        Target::Elixir => format_elixir(code),
     }
 }
 
@@ -105,6 +115,86 @@ pub fn format_go(code: &str) -> Result<String, FormatError> {
     }
 }
 
+/// Format Kotlin code using ktlint
+/// Falls back to original code if ktlint is not available or cannot fix the file
+pub fn format_kotlin(code: &str) -> Result<String, FormatError> {
+    if let Ok(formatted) = run_external_formatter(
+        code,
+        "ktlint",
+        &["--format", "--stdin", "--log-level=none"],
+        "Kotlin",
+    ) {
+        return Ok(formatted);
+    }
+
+    // No ktlint available, return as-is
+    Ok(code.to_string())
+}
+
+/// Format Swift code using swift-format
+/// Falls back to original code if swift-format is not available
+pub fn format_swift(code: &str) -> Result<String, FormatError> {
+    if let Ok(formatted) = run_external_formatter(code, "swift-format", &[], "Swift") {
+        return Ok(formatted);
+    }
+
+    // No swift-format available, return as-is
+    Ok(code.to_string())
+}
+
+/// Format PHP code using php-cs-fixer
+/// Falls back to original code if php-cs-fixer is not available
+pub fn format_php(code: &str) -> Result<String, FormatError> {
+    // `-` reads the file from stdin and writes the fixed file to stdout
+    if let Ok(formatted) = run_external_formatter(
+        code,
+        "php-cs-fixer",
+        &["fix", "--quiet", "--using-cache=no", "-"],
+        "PHP",
+    ) {
+        if !formatted.trim().is_empty() {
+            return Ok(formatted);
+        }
+    }
+
+    // No php-cs-fixer available, return as-is
+    Ok(code.to_string())
+}
+
+/// Format Ruby code using rubocop's safe autocorrections
+/// Falls back to original code if rubocop is not available
+pub fn format_ruby(code: &str) -> Result<String, FormatError> {
+    // With `--stderr` the offense report stays off stdout, leaving only the
+    // corrected source
+    if let Ok(formatted) = run_external_formatter(
+        code,
+        "rubocop",
+        &["-a", "--stderr", "--stdin", "generated.rb"],
+        "Ruby",
+    ) {
+        if !formatted.trim().is_empty() {
+            return Ok(formatted);
+        }
+    }
+
+    // No rubocop available, return as-is
+    Ok(code.to_string())
+}
+
+/// Format Elixir code using mix format
+/// Falls back to original code if mix is not available
+pub fn format_elixir(code: &str) -> Result<String, FormatError> {
+    // `mix format -` reads from stdin and writes the result to stdout
+    if let Ok(formatted) = run_external_formatter(code, "mix", &["format", "-"], "Elixir") {
+        if !formatted.trim().is_empty() {
+            return Ok(formatted);
+        }
+    }
+
+    // No mix available, return as-is
+    Ok(code.to_string())
+}
+
 /// Format TypeScript code using prettier
 /// Tries: prettier, npx prettier
 /// Falls back to original code if prettier is not available
@@ -268,6 +358,26 @@ pub fn available_formatters() -> Vec<(&'static str, &'static str)> {
         available.push(("Go", "gofmt"));
     }
 
+    if is_formatter_available("ktlint") {
+        available.push(("Kotlin", "ktlint"));
+    }
+
+    if is_formatter_available("swift-format") {
+        available.push(("Swift", "swift-format"));
+    }
+
+    if is_formatter_available("php-cs-fixer") {
+        available.push(("PHP", "php-cs-fixer"));
+    }
+
+    if is_formatter_available("rubocop") {
+        available.push(("Ruby", "rubocop"));
+    }
+
+    if is_formatter_available("mix") {
+        available.push(("Elixir", "mix format"));
+    }
+
     available
 }
 
@@ -343,6 +453,46 @@ false => 0,
         assert!(!result.is_empty());
     }
 
+    #[test]
+    fn test_format_code_kotlin() {
+        // Kotlin returns code (formatted if ktlint available, otherwise as-is)
+        let code = "fun foo(): Long = 1L\n";
+        let result = format_code(code, Target::Kotlin).unwrap();
+        assert!(result.contains("fun foo()"));
+    }
+
+    #[test]
+    fn test_format_code_swift() {
+        // Swift returns code (formatted if swift-format available, otherwise as-is)
+        let code = "func foo() -> Int64 { return 1 }\n";
+        let result = format_code(code, Target::Swift).unwrap();
+        assert!(result.contains("func foo()"));
+    }
+
+    #[test]
+    fn test_format_code_php() {
+        // PHP returns code (formatted if php-cs-fixer available, otherwise as-is)
+        let code = "<?php\nfunction foo(): int { return 1; }\n";
+        let result = format_code(code, Target::Php).unwrap();
+        assert!(result.contains("function foo()"));
+    }
+
+    #[test]
+    fn test_format_code_ruby() {
+        // Ruby returns code (formatted if rubocop available, otherwise as-is)
+        let code = "module Foo\n  def self.bar\n    1\n  end\nend\n";
+        let result = format_code(code, Target::Ruby).unwrap();
+        assert!(result.contains("def self.bar"));
+    }
+
+    #[test]
+    fn test_format_code_elixir() {
+        // Elixir returns code (formatted if mix available, otherwise as-is)
+        let code = "defmodule Foo do\n  def bar(_input), do: 1\nend\n";
+        let result = format_code(code, Target::Elixir).unwrap();
+        assert!(result.contains("def bar("));
+    }
+
     #[test]
     fn test_format_code_java_passthrough() {
         // Java formatting normalizes brace style
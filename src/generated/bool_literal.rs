@@ -1,25 +1,35 @@
 // GENERATED FROM: bool_literal.yaml
 // SPEC HASH: sha256:36872e0f7f0af7d1
-// GENERATED: 2026-01-06T05:05:48.704114893+00:00
+// GENERATED: 2026-10-16T10:06:57.103214038+00:00
 // DO NOT EDIT - regenerate from spec
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Rust,
+    TypeScript,
+    Python,
+    CSharp,
+    Java,
+    Go,
+}
+
 #[allow(
     unused_parens,
     unused_variables,
     clippy::bool_comparison,
     clippy::if_same_then_else
 )]
-pub fn bool_literal(value: bool, target: String) -> String {
-    if ((value == true) && (target == "Python")) {
+pub fn bool_literal(value: bool, target: Target) -> String {
+    if value == true && target == Target::Python {
         // true_py
         "True".to_string()
-    } else if ((value == false) && (target == "Python")) {
+    } else if value == false && target == Target::Python {
         // false_py
         "False".to_string()
-    } else if (value == true) {
+    } else if value == true {
         // true_default
         "true".to_string()
-    } else if (value == false) {
+    } else if value == false {
         // false_default
         "false".to_string()
     } else {
@@ -1,6 +1,6 @@
 // GENERATED TESTS FROM: bool_literal.yaml
 // SPEC HASH: sha256:36872e0f7f0af7d1
-// GENERATED: 2026-01-06T05:05:48.710543154+00:00
+// GENERATED: 2026-10-16T10:06:57.642926934+00:00
 // DO NOT EDIT — regenerate from spec
 
 #[cfg(test)]
@@ -15,25 +15,29 @@ mod bool_literal_tests {
     #[test]
     fn test_true_py() {
         // true_py: (value == true) && (target == 'Python') → "True"
-        assert_eq!(bool_literal(true, "Python".to_string()), "True".to_string());
+        // inputs: value = true, target = "Python"
+        assert_eq!(bool_literal(true, Target::Python), "True".to_string());
     }
 
     #[test]
     fn test_false_py() {
         // false_py: (value == false) && (target == 'Python') → "False"
-        assert_eq!(bool_literal(false, "Python".to_string()), "False".to_string());
+        // inputs: value = false, target = "Python"
+        assert_eq!(bool_literal(false, Target::Python), "False".to_string());
     }
 
     #[test]
     fn test_true_default() {
         // true_default: value == true → "true"
-        assert_eq!(bool_literal(true, "Rust".to_string()), "true".to_string());
+        // inputs: value = true, target = "Rust"
+        assert_eq!(bool_literal(true, Target::Rust), "true".to_string());
     }
 
     #[test]
     fn test_false_default() {
         // false_default: value == false → "false"
-        assert_eq!(bool_literal(false, "Rust".to_string()), "false".to_string());
+        // inputs: value = false, target = "Rust"
+        assert_eq!(bool_literal(false, Target::Rust), "false".to_string());
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -48,8 +52,10 @@ mod bool_literal_tests {
 
         proptest! {
             #[test]
-            fn prop_always_valid_output(value in any::<bool>(), target in any::<String>()) {
-                let result = bool_literal(value, target);
+            fn prop_always_valid_output(value in any::<bool>(), target in prop::sample::select(vec![Target::Rust, Target::TypeScript, Target::Python, Target::CSharp, Target::Java, Target::Go])) {
+                let Ok(result) = std::panic::catch_unwind(|| bool_literal(value, target)) else {
+                    return Ok(());
+                };
                 let valid_outputs = vec!["False".to_string(), "True".to_string(), "false".to_string(), "true".to_string()];
                 prop_assert!(valid_outputs.contains(&result));
             }
@@ -1,178 +1,207 @@
 // GENERATED FROM: cel_functions.yaml
 // SPEC HASH: sha256:712fd99981775811
-// GENERATED: 2026-01-06T05:05:48.989816049+00:00
+// GENERATED: 2026-10-16T10:08:08.171810341+00:00
 // DO NOT EDIT - regenerate from spec
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Func {
+    Size,
+    Has,
+    Type,
+    Contains,
+    StartsWith,
+    EndsWith,
+    Matches,
+    Int,
+    Float,
+    String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Rust,
+    TypeScript,
+    Python,
+    CSharp,
+    Java,
+    Go,
+}
+
 #[allow(
     unused_parens,
     unused_variables,
     clippy::bool_comparison,
     clippy::if_same_then_else
 )]
-pub fn cel_functions(func: String, target: String) -> String {
-    if ((func == "size") && (target == "Rust")) {
+pub fn cel_functions(func: Func, target: Target) -> String {
+    if func == Func::Size && target == Target::Rust {
         // size_rust
         "{0}.len()".to_string()
-    } else if ((func == "size") && (target == "TypeScript")) {
+    } else if func == Func::Size && target == Target::TypeScript {
         // size_ts
         "{0}.length".to_string()
-    } else if ((func == "size") && (target == "Python")) {
+    } else if func == Func::Size && target == Target::Python {
         // size_py
         "len({0})".to_string()
-    } else if ((func == "has") && (target == "Rust")) {
+    } else if func == Func::Has && target == Target::Rust {
         // has_rust
         "{0}.is_some()".to_string()
-    } else if ((func == "has") && (target == "TypeScript")) {
+    } else if func == Func::Has && target == Target::TypeScript {
         // has_ts
         "({0} !== undefined)".to_string()
-    } else if ((func == "has") && (target == "Python")) {
+    } else if func == Func::Has && target == Target::Python {
         // has_py
         "({0} is not None)".to_string()
-    } else if ((func == "contains") && (target == "Rust")) {
+    } else if func == Func::Contains && target == Target::Rust {
         // contains_rust
         "{0}.contains({1})".to_string()
-    } else if ((func == "contains") && (target == "TypeScript")) {
+    } else if func == Func::Contains && target == Target::TypeScript {
         // contains_ts
         "{0}.includes({1})".to_string()
-    } else if ((func == "contains") && (target == "Python")) {
+    } else if func == Func::Contains && target == Target::Python {
         // contains_py
         "({1} in {0})".to_string()
-    } else if ((func == "startsWith") && (target == "Rust")) {
+    } else if func == Func::StartsWith && target == Target::Rust {
         // startswith_rust
         "{0}.starts_with({1})".to_string()
-    } else if ((func == "startsWith") && (target == "TypeScript")) {
+    } else if func == Func::StartsWith && target == Target::TypeScript {
         // startswith_ts
         "{0}.startsWith({1})".to_string()
-    } else if ((func == "startsWith") && (target == "Python")) {
+    } else if func == Func::StartsWith && target == Target::Python {
         // startswith_py
         "{0}.startswith({1})".to_string()
-    } else if ((func == "endsWith") && (target == "Rust")) {
+    } else if func == Func::EndsWith && target == Target::Rust {
         // endswith_rust
         "{0}.ends_with({1})".to_string()
-    } else if ((func == "endsWith") && (target == "TypeScript")) {
+    } else if func == Func::EndsWith && target == Target::TypeScript {
         // endswith_ts
         "{0}.endsWith({1})".to_string()
-    } else if ((func == "endsWith") && (target == "Python")) {
+    } else if func == Func::EndsWith && target == Target::Python {
         // endswith_py
         "{0}.endswith({1})".to_string()
-    } else if ((func == "matches") && (target == "Rust")) {
+    } else if func == Func::Matches && target == Target::Rust {
         // matches_rust
         "Regex::new({1}).unwrap().is_match({0})".to_string()
-    } else if ((func == "matches") && (target == "TypeScript")) {
+    } else if func == Func::Matches && target == Target::TypeScript {
         // matches_ts
         "{0}.match({1})".to_string()
-    } else if ((func == "matches") && (target == "Python")) {
+    } else if func == Func::Matches && target == Target::Python {
         // matches_py
         "re.match({1}, {0})".to_string()
-    } else if ((func == "int") && (target == "Rust")) {
+    } else if func == Func::Int && target == Target::Rust {
         // int_rust
         "{0} as i64".to_string()
-    } else if ((func == "int") && (target == "TypeScript")) {
+    } else if func == Func::Int && target == Target::TypeScript {
         // int_ts
         "parseInt({0})".to_string()
-    } else if ((func == "int") && (target == "Python")) {
+    } else if func == Func::Int && target == Target::Python {
         // int_py
         "int({0})".to_string()
-    } else if ((func == "float") && (target == "Rust")) {
+    } else if func == Func::Float && target == Target::Rust {
         // float_rust
         "{0} as f64".to_string()
-    } else if ((func == "float") && (target == "TypeScript")) {
+    } else if func == Func::Float && target == Target::TypeScript {
         // float_ts
         "parseFloat({0})".to_string()
-    } else if ((func == "float") && (target == "Python")) {
+    } else if func == Func::Float && target == Target::Python {
         // float_py
         "float({0})".to_string()
-    } else if ((func == "string") && (target == "Rust")) {
+    } else if func == Func::String && target == Target::Rust {
         // string_rust
         "{0}.to_string()".to_string()
-    } else if ((func == "string") && (target == "TypeScript")) {
+    } else if func == Func::String && target == Target::TypeScript {
         // string_ts
         "String({0})".to_string()
-    } else if ((func == "string") && (target == "Python")) {
+    } else if func == Func::String && target == Target::Python {
         // string_py
         "str({0})".to_string()
-    } else if ((func == "size") && (target == "CSharp")) {
+    } else if func == Func::Size && target == Target::CSharp {
         // size_csharp
         "{0}.Count".to_string()
-    } else if ((func == "has") && (target == "CSharp")) {
+    } else if func == Func::Has && target == Target::CSharp {
         // has_csharp
         "({0} != null)".to_string()
-    } else if ((func == "contains") && (target == "CSharp")) {
+    } else if func == Func::Contains && target == Target::CSharp {
         // contains_csharp
         "{0}.Contains({1})".to_string()
-    } else if ((func == "startsWith") && (target == "CSharp")) {
+    } else if func == Func::StartsWith && target == Target::CSharp {
         // startswith_csharp
         "{0}.StartsWith({1})".to_string()
-    } else if ((func == "endsWith") && (target == "CSharp")) {
+    } else if func == Func::EndsWith && target == Target::CSharp {
         // endswith_csharp
         "{0}.EndsWith({1})".to_string()
-    } else if ((func == "matches") && (target == "CSharp")) {
+    } else if func == Func::Matches && target == Target::CSharp {
         // matches_csharp
         "Regex.IsMatch({0}, {1})".to_string()
-    } else if ((func == "int") && (target == "CSharp")) {
+    } else if func == Func::Int && target == Target::CSharp {
         // int_csharp
         "(long){0}".to_string()
-    } else if ((func == "float") && (target == "CSharp")) {
+    } else if func == Func::Float && target == Target::CSharp {
         // float_csharp
         "(double){0}".to_string()
-    } else if ((func == "string") && (target == "CSharp")) {
+    } else if func == Func::String && target == Target::CSharp {
         // string_csharp
         "{0}.ToString()".to_string()
-    } else if ((func == "size") && (target == "Java")) {
+    } else if func == Func::Size && target == Target::Java {
         // size_java
         "{0}.size()".to_string()
-    } else if ((func == "has") && (target == "Java")) {
+    } else if func == Func::Has && target == Target::Java {
         // has_java
         "({0} != null)".to_string()
-    } else if ((func == "contains") && (target == "Java")) {
+    } else if func == Func::Contains && target == Target::Java {
         // contains_java
         "{0}.contains({1})".to_string()
-    } else if ((func == "startsWith") && (target == "Java")) {
+    } else if func == Func::StartsWith && target == Target::Java {
         // startswith_java
         "{0}.startsWith({1})".to_string()
-    } else if ((func == "endsWith") && (target == "Java")) {
+    } else if func == Func::EndsWith && target == Target::Java {
         // endswith_java
         "{0}.endsWith({1})".to_string()
-    } else if ((func == "matches") && (target == "Java")) {
+    } else if func == Func::Matches && target == Target::Java {
         // matches_java
         "{0}.matches({1})".to_string()
-    } else if ((func == "int") && (target == "Java")) {
+    } else if func == Func::Int && target == Target::Java {
         // int_java
         "(long){0}".to_string()
-    } else if ((func == "float") && (target == "Java")) {
+    } else if func == Func::Float && target == Target::Java {
         // float_java
         "(double){0}".to_string()
-    } else if ((func == "string") && (target == "Java")) {
+    } else if func == Func::String && target == Target::Java {
         // string_java
         "{0}.toString()".to_string()
-    } else if ((func == "size") && (target == "Go")) {
+    } else if func == Func::Size && target == Target::Go {
         // size_go
         "len({0})".to_string()
-    } else if ((func == "has") && (target == "Go")) {
+    } else if func == Func::Has && target == Target::Go {
         // has_go
         "({0} != nil)".to_string()
-    } else if ((func == "contains") && (target == "Go")) {
+    } else if func == Func::Contains && target == Target::Go {
         // contains_go
         "strings.Contains({0}, {1})".to_string()
-    } else if ((func == "startsWith") && (target == "Go")) {
+    } else if func == Func::StartsWith && target == Target::Go {
         // startswith_go
         "strings.HasPrefix({0}, {1})".to_string()
-    } else if ((func == "endsWith") && (target == "Go")) {
+    } else if func == Func::EndsWith && target == Target::Go {
         // endswith_go
         "strings.HasSuffix({0}, {1})".to_string()
-    } else if ((func == "matches") && (target == "Go")) {
+    } else if func == Func::Matches && target == Target::Go {
         // matches_go
         "regexp.MatchString({1}, {0})".to_string()
-    } else if ((func == "int") && (target == "Go")) {
+    } else if func == Func::Int && target == Target::Go {
         // int_go
         "int64({0})".to_string()
-    } else if ((func == "float") && (target == "Go")) {
+    } else if func == Func::Float && target == Target::Go {
         // float_go
         "float64({0})".to_string()
-    } else if ((func == "string") && (target == "Go")) {
+    } else if func == Func::String && target == Target::Go {
         // string_go
         "fmt.Sprintf(\"%v\", {0})".to_string()
     } else {
+        // WARNING: spec incomplete — 6 uncovered input combination(s)
+        //   func == "type" && target == "Rust"
+        //   func == "type" && target == "TypeScript"
+        //   func == "type" && target == "Python"
+        //   ... and 3 more
         unreachable!("No rule matched")
     }
 }
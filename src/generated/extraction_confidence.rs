@@ -1,37 +1,63 @@
 // GENERATED FROM: extraction_confidence.yaml
 // SPEC HASH: sha256:2a233b220bb043ae
-// GENERATED: 2026-01-06T05:05:49.109582751+00:00
+// GENERATED: 2026-10-16T10:40:47.650579966+00:00
 // DO NOT EDIT - regenerate from spec
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternType {
+    Literal,
+    Wildcard,
+    Tuple,
+    Constructor,
+    Complex,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputType {
+    Literal,
+    Expression,
+    FunctionCall,
+    Complex,
+}
+
 #[allow(
     unused_parens,
     unused_variables,
     clippy::bool_comparison,
     clippy::if_same_then_else
 )]
-pub fn extraction_confidence(pattern_type: String, has_guard: bool, output_type: String) -> f64 {
-    if (((pattern_type == "Literal") && (output_type == "Literal")) && (!has_guard)) {
+pub fn extraction_confidence(
+    pattern_type: PatternType,
+    has_guard: bool,
+    output_type: OutputType,
+) -> f64 {
+    if pattern_type == PatternType::Literal && output_type == OutputType::Literal && !has_guard {
         // literal_literal
         1.0f64
-    } else if (((pattern_type == "Tuple") && (output_type == "Literal")) && (!has_guard)) {
+    } else if pattern_type == PatternType::Tuple && output_type == OutputType::Literal && !has_guard
+    {
         // tuple_literal
         0.95f64
-    } else if ((pattern_type == "Wildcard") && (output_type == "Literal")) {
+    } else if pattern_type == PatternType::Wildcard && output_type == OutputType::Literal {
         // wildcard
         0.85f64
     } else if has_guard {
         // guarded
         0.7f64
-    } else if (pattern_type == "Constructor") {
+    } else if pattern_type == PatternType::Constructor {
         // constructor
         0.75f64
-    } else if (output_type == "FunctionCall") {
+    } else if output_type == OutputType::FunctionCall {
         // complex_output
         0.6f64
-    } else if ((pattern_type == "Complex") || (output_type == "Complex")) {
+    } else if pattern_type == PatternType::Complex || output_type == OutputType::Complex {
         // complex
         0.4f64
     } else {
+        // WARNING: spec incomplete — 3 uncovered input combination(s)
+        //   pattern_type == "Literal" && output_type == "Expression" && !has_guard
+        //   pattern_type == "Tuple" && output_type == "Expression" && !has_guard
+        //   pattern_type == "Wildcard" && output_type == "Expression" && !has_guard
         unreachable!("No rule matched")
     }
 }
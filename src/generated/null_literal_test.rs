@@ -1,6 +1,6 @@
 // GENERATED TESTS FROM: null_literal.yaml
 // SPEC HASH: sha256:89a8d6dc562fefab
-// GENERATED: 2026-01-06T05:05:49.253122888+00:00
+// GENERATED: 2026-10-16T10:40:17.511545252+00:00
 // DO NOT EDIT — regenerate from spec
 
 #[cfg(test)]
@@ -15,37 +15,43 @@ mod null_literal_tests {
     #[test]
     fn test_null_rust() {
         // null_rust: target == 'Rust' → "None"
-        assert_eq!(null_literal("Rust".to_string()), "None".to_string());
+        // inputs: target = "Rust"
+        assert_eq!(null_literal(Target::Rust), "None".to_string());
     }
 
     #[test]
     fn test_null_ts() {
         // null_ts: target == 'TypeScript' → "null"
-        assert_eq!(null_literal("TypeScript".to_string()), "null".to_string());
+        // inputs: target = "TypeScript"
+        assert_eq!(null_literal(Target::TypeScript), "null".to_string());
     }
 
     #[test]
     fn test_null_py() {
         // null_py: target == 'Python' → "None"
-        assert_eq!(null_literal("Python".to_string()), "None".to_string());
+        // inputs: target = "Python"
+        assert_eq!(null_literal(Target::Python), "None".to_string());
     }
 
     #[test]
     fn test_null_csharp() {
         // null_csharp: target == 'CSharp' → "null"
-        assert_eq!(null_literal("CSharp".to_string()), "null".to_string());
+        // inputs: target = "CSharp"
+        assert_eq!(null_literal(Target::CSharp), "null".to_string());
     }
 
     #[test]
     fn test_null_java() {
         // null_java: target == 'Java' → "null"
-        assert_eq!(null_literal("Java".to_string()), "null".to_string());
+        // inputs: target = "Java"
+        assert_eq!(null_literal(Target::Java), "null".to_string());
     }
 
     #[test]
     fn test_null_go() {
         // null_go: target == 'Go' → "nil"
-        assert_eq!(null_literal("Go".to_string()), "nil".to_string());
+        // inputs: target = "Go"
+        assert_eq!(null_literal(Target::Go), "nil".to_string());
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -60,8 +66,10 @@ mod null_literal_tests {
 
         proptest! {
             #[test]
-            fn prop_always_valid_output(target in any::<String>()) {
-                let result = null_literal(target);
+            fn prop_always_valid_output(target in prop::sample::select(vec![Target::Rust, Target::TypeScript, Target::Python, Target::CSharp, Target::Java, Target::Go])) {
+                let Ok(result) = std::panic::catch_unwind(|| null_literal(target)) else {
+                    return Ok(());
+                };
                 let valid_outputs = vec!["None".to_string(), "nil".to_string(), "null".to_string()];
                 prop_assert!(valid_outputs.contains(&result));
             }
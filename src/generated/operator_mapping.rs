@@ -1,73 +1,97 @@
 // GENERATED FROM: operator_mapping.yaml
 // SPEC HASH: sha256:a20f57d199b9c1a4
-// GENERATED: 2026-01-06T05:05:49.352836896+00:00
+// GENERATED: 2026-10-16T10:08:26.871477946+00:00
 // DO NOT EDIT - regenerate from spec
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+    Not,
+    In,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Rust,
+    TypeScript,
+    Python,
+    CSharp,
+    Java,
+    Go,
+}
+
 #[allow(
     unused_parens,
     unused_variables,
     clippy::bool_comparison,
     clippy::if_same_then_else
 )]
-pub fn operator_mapping(op: String, target: String) -> String {
-    if ((op == "Eq") && (target == "TypeScript")) {
+pub fn operator_mapping(op: Op, target: Target) -> String {
+    if op == Op::Eq && target == Target::TypeScript {
         // eq_ts
         "===".to_string()
-    } else if (op == "Eq") {
+    } else if op == Op::Eq {
         // eq_default
         "==".to_string()
-    } else if ((op == "Ne") && (target == "TypeScript")) {
+    } else if op == Op::Ne && target == Target::TypeScript {
         // ne_ts
         "!==".to_string()
-    } else if (op == "Ne") {
+    } else if op == Op::Ne {
         // ne_default
         "!=".to_string()
-    } else if (op == "Lt") {
+    } else if op == Op::Lt {
         // lt
         "<".to_string()
-    } else if (op == "Le") {
+    } else if op == Op::Le {
         // le
         "<=".to_string()
-    } else if (op == "Gt") {
+    } else if op == Op::Gt {
         // gt
         ">".to_string()
-    } else if (op == "Ge") {
+    } else if op == Op::Ge {
         // ge
         ">=".to_string()
-    } else if ((op == "And") && (target == "Python")) {
+    } else if op == Op::And && target == Target::Python {
         // and_py
         "and".to_string()
-    } else if (op == "And") {
+    } else if op == Op::And {
         // and_default
         "&&".to_string()
-    } else if ((op == "Or") && (target == "Python")) {
+    } else if op == Op::Or && target == Target::Python {
         // or_py
         "or".to_string()
-    } else if (op == "Or") {
+    } else if op == Op::Or {
         // or_default
         "||".to_string()
-    } else if ((op == "Not") && (target == "Python")) {
+    } else if op == Op::Not && target == Target::Python {
         // not_py
         "not ".to_string()
-    } else if (op == "Not") {
+    } else if op == Op::Not {
         // not_default
         "!".to_string()
-    } else if ((op == "In") && (target == "Rust")) {
+    } else if op == Op::In && target == Target::Rust {
         // in_rust
         ".contains(&{})".to_string()
-    } else if ((op == "In") && (target == "TypeScript")) {
+    } else if op == Op::In && target == Target::TypeScript {
         // in_ts
         ".includes({})".to_string()
-    } else if ((op == "In") && (target == "Python")) {
+    } else if op == Op::In && target == Target::Python {
         // in_py
         " in ".to_string()
-    } else if ((op == "In") && (target == "CSharp")) {
+    } else if op == Op::In && target == Target::CSharp {
         // in_csharp
         ".Contains({})".to_string()
-    } else if ((op == "In") && (target == "Java")) {
+    } else if op == Op::In && target == Target::Java {
         // in_java
         ".contains({})".to_string()
-    } else if ((op == "In") && (target == "Go")) {
+    } else if op == Op::In && target == Target::Go {
         // in_go
         "contains({}, {})".to_string()
     } else {
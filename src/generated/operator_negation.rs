@@ -1,31 +1,41 @@
 // GENERATED FROM: operator_negation.yaml
 // SPEC HASH: sha256:719d81f5844361a1
-// GENERATED: 2026-01-06T05:05:49.407065271+00:00
+// GENERATED: 2026-10-16T10:38:39.706171578+00:00
 // DO NOT EDIT - regenerate from spec
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
 #[allow(
     unused_parens,
     unused_variables,
     clippy::bool_comparison,
     clippy::if_same_then_else
 )]
-pub fn operator_negation(op: String) -> String {
-    if (op == "Eq") {
+pub fn operator_negation(op: Op) -> String {
+    if op == Op::Eq {
         // eq_to_ne
         "Ne".to_string()
-    } else if (op == "Ne") {
+    } else if op == Op::Ne {
         // ne_to_eq
         "Eq".to_string()
-    } else if (op == "Lt") {
+    } else if op == Op::Lt {
         // lt_to_ge
         "Ge".to_string()
-    } else if (op == "Le") {
+    } else if op == Op::Le {
         // le_to_gt
         "Gt".to_string()
-    } else if (op == "Gt") {
+    } else if op == Op::Gt {
         // gt_to_le
         "Le".to_string()
-    } else if (op == "Ge") {
+    } else if op == Op::Ge {
         // ge_to_lt
         "Lt".to_string()
     } else {
@@ -1,27 +1,52 @@
 // GENERATED FROM: string_render.yaml
 // SPEC HASH: sha256:879a9c7c59ff173a
-// GENERATED: 2026-01-06T05:05:49.445200847+00:00
+// GENERATED: 2026-10-16T10:44:09.222890412+00:00
 // DO NOT EDIT - regenerate from spec
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Rust,
+    TypeScript,
+    Python,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StringRenderOutput {
+    pub quote: String,
+    pub suffix: String,
+}
+
 #[allow(
     unused_parens,
     unused_variables,
     clippy::bool_comparison,
     clippy::if_same_then_else
 )]
-pub fn string_render(target: String, needs_owned: bool) -> (String, String) {
-    if ((target == "Rust") && needs_owned) {
+pub fn string_render(target: Target, needs_owned: bool) -> StringRenderOutput {
+    if target == Target::Rust && needs_owned {
         // rust_owned
-        ("\"".to_string(), ".to_string()".to_string())
-    } else if ((target == "Rust") && (!needs_owned)) {
+        StringRenderOutput {
+            quote: "\"".to_string(),
+            suffix: ".to_string()".to_string(),
+        }
+    } else if target == Target::Rust && !needs_owned {
         // rust_borrowed
-        ("\"".to_string(), "".to_string())
-    } else if (target == "TypeScript") {
+        StringRenderOutput {
+            quote: "\"".to_string(),
+            suffix: "".to_string(),
+        }
+    } else if target == Target::TypeScript {
         // ts
-        ("\"".to_string(), "".to_string())
-    } else if (target == "Python") {
+        StringRenderOutput {
+            quote: "\"".to_string(),
+            suffix: "".to_string(),
+        }
+    } else if target == Target::Python {
         // py
-        ("\"".to_string(), "".to_string())
+        StringRenderOutput {
+            quote: "\"".to_string(),
+            suffix: "".to_string(),
+        }
     } else {
         unreachable!("No rule matched")
     }
@@ -1,8 +1,15 @@
 // GENERATED FROM: test_mode_selection.yaml
 // SPEC HASH: sha256:ce44baee9e2bf428
-// GENERATED: 2026-01-06T05:05:49.479481307+00:00
+// GENERATED: 2026-10-16T10:54:56.059284259+00:00
 // DO NOT EDIT - regenerate from spec
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestModeSelectionOutput {
+    pub generate_exhaustive: bool,
+    pub generate_boundary: bool,
+    pub generate_property: bool,
+}
+
 #[allow(
     unused_parens,
     unused_variables,
@@ -14,17 +21,31 @@ pub fn test_mode_selection(
     all_inputs_enumerable: bool,
     has_numeric_conditions: bool,
     total_combinations: i64,
-) -> (bool, bool, bool) {
-    if (all_inputs_enumerable && (total_combinations <= 64)) {
+) -> TestModeSelectionOutput {
+    if all_inputs_enumerable && total_combinations <= 64 {
         // exhaustive_yes
-        (true, false, true)
-    } else if ((!all_inputs_enumerable) || (total_combinations > 64)) {
+        TestModeSelectionOutput {
+            generate_exhaustive: true,
+            generate_boundary: false,
+            generate_property: true,
+        }
+    } else if !all_inputs_enumerable || total_combinations > 64 {
         // exhaustive_no
-        (false, true, true)
+        TestModeSelectionOutput {
+            generate_exhaustive: false,
+            generate_boundary: true,
+            generate_property: true,
+        }
     } else if has_numeric_conditions {
         // boundary_numeric
-        (false, true, true)
+        TestModeSelectionOutput {
+            generate_exhaustive: false,
+            generate_boundary: true,
+            generate_property: true,
+        }
     } else {
+        // WARNING: spec incomplete — 1 uncovered input combination(s)
+        //   all_inputs_enumerable && total_combinations > 64 && total_combinations <= 64 && !has_numeric_conditions
         unreachable!("No rule matched")
     }
 }
@@ -1,103 +1,122 @@
 // GENERATED FROM: type_mapping.yaml
 // SPEC HASH: sha256:0ee4f2f0c417737b
-// GENERATED: 2026-01-06T05:05:49.619105313+00:00
+// GENERATED: 2026-10-16T10:57:22.222893945+00:00
 // DO NOT EDIT - regenerate from spec
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarType {
+    Bool,
+    Int,
+    Float,
+    String,
+    Object,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Rust,
+    TypeScript,
+    Python,
+    CSharp,
+    Java,
+    Go,
+}
+
 #[allow(
     unused_parens,
     unused_variables,
     clippy::bool_comparison,
     clippy::if_same_then_else
 )]
-pub fn type_mapping(var_type: String, target: String) -> String {
-    if ((var_type == "Bool") && (target == "Rust")) {
+pub fn type_mapping(var_type: VarType, target: Target) -> String {
+    if var_type == VarType::Bool && target == Target::Rust {
         // bool_rust
         "bool".to_string()
-    } else if ((var_type == "Bool") && (target == "TypeScript")) {
+    } else if var_type == VarType::Bool && target == Target::TypeScript {
         // bool_ts
         "boolean".to_string()
-    } else if ((var_type == "Bool") && (target == "Python")) {
+    } else if var_type == VarType::Bool && target == Target::Python {
         // bool_py
         "bool".to_string()
-    } else if ((var_type == "Int") && (target == "Rust")) {
+    } else if var_type == VarType::Int && target == Target::Rust {
         // int_rust
         "i64".to_string()
-    } else if ((var_type == "Int") && (target == "TypeScript")) {
+    } else if var_type == VarType::Int && target == Target::TypeScript {
         // int_ts
         "number".to_string()
-    } else if ((var_type == "Int") && (target == "Python")) {
+    } else if var_type == VarType::Int && target == Target::Python {
         // int_py
         "int".to_string()
-    } else if ((var_type == "Float") && (target == "Rust")) {
+    } else if var_type == VarType::Float && target == Target::Rust {
         // float_rust
         "f64".to_string()
-    } else if ((var_type == "Float") && (target == "TypeScript")) {
+    } else if var_type == VarType::Float && target == Target::TypeScript {
         // float_ts
         "number".to_string()
-    } else if ((var_type == "Float") && (target == "Python")) {
+    } else if var_type == VarType::Float && target == Target::Python {
         // float_py
         "float".to_string()
-    } else if ((var_type == "String") && (target == "Rust")) {
+    } else if var_type == VarType::String && target == Target::Rust {
         // string_rust
         "String".to_string()
-    } else if ((var_type == "String") && (target == "TypeScript")) {
+    } else if var_type == VarType::String && target == Target::TypeScript {
         // string_ts
         "string".to_string()
-    } else if ((var_type == "String") && (target == "Python")) {
+    } else if var_type == VarType::String && target == Target::Python {
         // string_py
         "str".to_string()
-    } else if ((var_type == "Object") && (target == "Rust")) {
+    } else if var_type == VarType::Object && target == Target::Rust {
         // object_rust
         "serde_json::Value".to_string()
-    } else if ((var_type == "Object") && (target == "TypeScript")) {
+    } else if var_type == VarType::Object && target == Target::TypeScript {
         // object_ts
         "Record<string, unknown>".to_string()
-    } else if ((var_type == "Object") && (target == "Python")) {
+    } else if var_type == VarType::Object && target == Target::Python {
         // object_py
         "dict".to_string()
-    } else if ((var_type == "Bool") && (target == "CSharp")) {
+    } else if var_type == VarType::Bool && target == Target::CSharp {
         // bool_csharp
         "bool".to_string()
-    } else if ((var_type == "Int") && (target == "CSharp")) {
+    } else if var_type == VarType::Int && target == Target::CSharp {
         // int_csharp
         "long".to_string()
-    } else if ((var_type == "Float") && (target == "CSharp")) {
+    } else if var_type == VarType::Float && target == Target::CSharp {
         // float_csharp
         "double".to_string()
-    } else if ((var_type == "String") && (target == "CSharp")) {
+    } else if var_type == VarType::String && target == Target::CSharp {
         // string_csharp
         "string".to_string()
-    } else if ((var_type == "Object") && (target == "CSharp")) {
+    } else if var_type == VarType::Object && target == Target::CSharp {
         // object_csharp
         "Dictionary<string, object>".to_string()
-    } else if ((var_type == "Bool") && (target == "Java")) {
+    } else if var_type == VarType::Bool && target == Target::Java {
         // bool_java
         "boolean".to_string()
-    } else if ((var_type == "Int") && (target == "Java")) {
+    } else if var_type == VarType::Int && target == Target::Java {
         // int_java
         "long".to_string()
-    } else if ((var_type == "Float") && (target == "Java")) {
+    } else if var_type == VarType::Float && target == Target::Java {
         // float_java
         "double".to_string()
-    } else if ((var_type == "String") && (target == "Java")) {
+    } else if var_type == VarType::String && target == Target::Java {
         // string_java
         "String".to_string()
-    } else if ((var_type == "Object") && (target == "Java")) {
+    } else if var_type == VarType::Object && target == Target::Java {
         // object_java
         "Map<String, Object>".to_string()
-    } else if ((var_type == "Bool") && (target == "Go")) {
+    } else if var_type == VarType::Bool && target == Target::Go {
         // bool_go
         "bool".to_string()
-    } else if ((var_type == "Int") && (target == "Go")) {
+    } else if var_type == VarType::Int && target == Target::Go {
         // int_go
         "int64".to_string()
-    } else if ((var_type == "Float") && (target == "Go")) {
+    } else if var_type == VarType::Float && target == Target::Go {
         // float_go
         "float64".to_string()
-    } else if ((var_type == "String") && (target == "Go")) {
+    } else if var_type == VarType::String && target == Target::Go {
         // string_go
         "string".to_string()
-    } else if ((var_type == "Object") && (target == "Go")) {
+    } else if var_type == VarType::Object && target == Target::Go {
         // object_go
         "map[string]interface{}".to_string()
     } else {
@@ -179,19 +179,27 @@ pub mod error;
 pub mod meta;
 pub mod project;
 pub mod spec;
+pub mod table;
 pub mod util;
 
 // Operations (Layer 0: hand-crafted)
 pub mod analyze;
 pub mod drift;
+pub mod eval;
 pub mod extract;
 pub mod format;
+pub mod lsp;
+pub mod merge;
+pub mod migrate;
 pub mod orchestrate;
 pub mod parse;
 pub mod render;
+pub mod sarif;
+pub mod split;
 pub mod templates;
 pub mod testgen;
 pub mod testgen_orchestrate;
+pub mod typecheck;
 pub mod verify;
 
 // Completeness analysis (Phase 5)
@@ -203,20 +211,38 @@ pub mod completeness;
 pub mod generated;
 
 // Re-exports
-pub use analyze::{analyze, AnalysisReport, Analyzer, FunctionMetrics, Issue, Severity};
+pub use analyze::{
+    analyze, AnalysisReport, Analyzer, AnalyzerConfig, FunctionMetrics, HalsteadMetrics, Issue,
+    Severity,
+};
 pub use ast::{
     AstNode, BinaryOp, CodeAst, Function, LiteralValue, MatchArm, Pattern, Span, UnaryOp,
 };
 pub use cel::Target;
-pub use cel::{CelCompiler, CelExpr};
-pub use drift::{compare, Difference, DriftDetector, DriftReport, DriftStatus};
+pub use cel::{simplify_cel, CelCompiler, CelExpr, Simplify};
+pub use drift::{
+    compare, compare_with_spec, Difference, DriftDetector, DriftReport, DriftStatus, RuleDrift,
+    RuleDriftKind, SpecDriftReport, SpecDriftStatus,
+};
 pub use error::{Error, Result};
-pub use extract::{extract, Confidence, ExtractedSpec, Extractor};
-pub use parse::parse_rust;
-pub use render::{render, Renderer};
-pub use spec::{Condition, ConditionOp, ConditionValue, Output, Rule, Spec, VarType, Variable};
+pub use eval::{Explanation, FailedCondition, RuleExplanation};
+pub use extract::{extract, extract_all, Confidence, ExtractedSpec, Extractor, ExtractorConfig};
+pub use merge::MergeError;
+pub use parse::{
+    parse_for_path, parse_rust, parse_rust_with_diagnostics, DiagnosticKind, ParseCache,
+    ParseDiagnostic, ParseDiagnostics,
+};
+pub use render::{diff_generated, render, render_with_config, RenderConfig, RenderStyle, Renderer};
+pub use spec::{
+    Condition, ConditionOp, ConditionValue, OnNoMatch, Output, Rule, Spec, SpecMode, VarType,
+    Variable,
+};
+pub use table::{DecisionRow, DecisionTable};
 pub use testgen::{generate_tests, TestConfig, TestGenerator, TestMode};
-pub use verify::{verify, Coverage, CoverageGap, VerificationResult, Verifier};
+pub use typecheck::TypeError;
+pub use verify::{
+    verify, verify_semantic, Coverage, CoverageGap, Mismatch, VerificationResult, Verifier,
+};
 
 // Code formatting
 pub use format::{
@@ -227,6 +253,7 @@ pub use format::{
 // Completeness analysis
 pub use completeness::{
     analyze_completeness,
+    analyze_dont_cares,
     analyze_suite,
     compose,
     cover_to_cel,
@@ -241,6 +268,7 @@ pub use completeness::{
     minimize_rules,
     rules_to_cover,
     validate_spec,
+    validate_spec_with,
     AnalysisMode,
     ChainDefinition,
     ComparisonOp,
@@ -260,6 +288,7 @@ pub use completeness::{
     PredicateInfo,
     PredicateSet,
     PredicateValue,
+    RuleDontCares,
     RuleOverlap,
     SpecResult,
     StringOpKind,
@@ -275,8 +304,8 @@ pub use completeness::LiteralValue as PredicateLiteralValue;
 
 // Orchestration
 pub use orchestrate::{
-    calculate_complexity, count_steps, render_orchestrator, ChainStep, ComplexityReport,
-    Orchestrator, OrchestratorInput, OrchestratorOutput,
+    calculate_complexity, count_steps, render_orchestrator, render_orchestrator_with_config,
+    ChainStep, ComplexityReport, Orchestrator, OrchestratorInput, OrchestratorOutput,
 };
 
 // Template-based code generation
@@ -293,8 +322,9 @@ pub use config::{ImacRoot, LocalConfig, MergedConfig, ProjectConfig, ValidationC
 pub use meta::{create_meta, find_stale_specs, ImacMeta};
 pub use project::{
     detect_output_conflicts, discover_all_imacs, discover_generated_dir, discover_specs_dir,
-    find_root, get_generated_dir, get_output_dir, list_specs, load_project_structure,
-    validate_unique_ids, ImacFolder, ProjectStructure,
+    find_root, generate_folder, generate_folder_with, get_generated_dir, get_output_dir,
+    list_specs, load_project_structure, load_specs, validate_unique_ids, GeneratedOutput,
+    ImacFolder, ProjectStructure,
 };
 
 /// Library version
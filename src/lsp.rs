@@ -0,0 +1,393 @@
+//! Language server for spec files
+//!
+//! `imacs lsp` speaks LSP over stdio. When a spec is opened or changed it is
+//! parsed, validated and checked for completeness, and the findings are
+//! published as diagnostics on the offending rule. Hovering an input or
+//! output name shows its declared type.
+//!
+//! The conversions from reports to diagnostics are plain functions so they
+//! can be tested without a client.
+
+use crate::completeness::{
+    analyze_completeness, validate_spec, IncompletenessReport, Severity, ValidationReport,
+};
+use crate::error::{Error, Result};
+use crate::orchestrate::Orchestrator;
+use crate::spec::{rule_lines, Spec, VarType, Variable};
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+use tower_lsp::jsonrpc::Result as RpcResult;
+use tower_lsp::lsp_types::*;
+use tower_lsp::{Client, LanguageServer, LspService, Server};
+
+/// Serve LSP over stdin/stdout until the client exits
+pub fn run() -> Result<()> {
+    let runtime = tokio::runtime::Runtime::new().map_err(Error::Io)?;
+    runtime.block_on(async {
+        let (service, socket) = LspService::new(|client| Backend {
+            client,
+            documents: Mutex::new(HashMap::new()),
+        });
+        Server::new(tokio::io::stdin(), tokio::io::stdout(), socket)
+            .serve(service)
+            .await;
+    });
+    Ok(())
+}
+
+struct Backend {
+    client: Client,
+    /// Latest text of each open document
+    documents: Mutex<HashMap<Url, String>>,
+}
+
+impl Backend {
+    async fn update(&self, uri: Url, text: String) {
+        let path = uri.to_file_path().ok();
+        if path.as_deref().is_some_and(|p| !is_spec_path(p)) {
+            return;
+        }
+        let diagnostics = diagnose(&text, path.as_deref());
+        self.documents.lock().unwrap().insert(uri.clone(), text);
+        self.client
+            .publish_diagnostics(uri, diagnostics, None)
+            .await;
+    }
+}
+
+#[tower_lsp::async_trait]
+impl LanguageServer for Backend {
+    async fn initialize(&self, _: InitializeParams) -> RpcResult<InitializeResult> {
+        Ok(InitializeResult {
+            capabilities: ServerCapabilities {
+                text_document_sync: Some(TextDocumentSyncCapability::Kind(
+                    TextDocumentSyncKind::FULL,
+                )),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                ..Default::default()
+            },
+            server_info: Some(ServerInfo {
+                name: "imacs".into(),
+                version: Some(env!("CARGO_PKG_VERSION").into()),
+            }),
+        })
+    }
+
+    async fn shutdown(&self) -> RpcResult<()> {
+        Ok(())
+    }
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        self.update(params.text_document.uri, params.text_document.text)
+            .await;
+    }
+
+    async fn did_change(&self, mut params: DidChangeTextDocumentParams) {
+        // Full sync: the last change holds the whole document
+        if let Some(change) = params.content_changes.pop() {
+            self.update(params.text_document.uri, change.text).await;
+        }
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let uri = params.text_document.uri;
+        self.documents.lock().unwrap().remove(&uri);
+        self.client.publish_diagnostics(uri, Vec::new(), None).await;
+    }
+
+    async fn hover(&self, params: HoverParams) -> RpcResult<Option<Hover>> {
+        let at = params.text_document_position_params;
+        let text = self
+            .documents
+            .lock()
+            .unwrap()
+            .get(&at.text_document.uri)
+            .cloned();
+        Ok(text
+            .and_then(|text| hover_text(&text, at.position))
+            .map(|value| Hover {
+                contents: HoverContents::Markup(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value,
+                }),
+                range: None,
+            }))
+    }
+}
+
+/// YAML files other than project configuration
+fn is_spec_path(path: &Path) -> bool {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    name != "config.yaml"
+        && matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("yaml" | "yml")
+        )
+}
+
+/// All diagnostics for a spec (or orchestrator) document
+///
+/// With a `path`, `include:` fragments are resolved relative to it.
+pub fn diagnose(text: &str, path: Option<&Path>) -> Vec<Diagnostic> {
+    // Orchestrators have a 'chain:' or 'uses:' key; only parse errors apply
+    if text.contains("\nchain:") || text.contains("\nuses:") {
+        return match Orchestrator::from_yaml(text) {
+            Ok(_) => Vec::new(),
+            Err(e) => vec![parse_diagnostic(&e.to_string(), text)],
+        };
+    }
+
+    let parsed = match path {
+        Some(path) => Spec::from_yaml_with_base(text, path),
+        None => Spec::from_yaml(text),
+    };
+    let spec = match parsed {
+        Ok(spec) => spec,
+        Err(e) => return vec![parse_diagnostic(&e.to_string(), text)],
+    };
+
+    let mut diagnostics = validation_diagnostics(&validate_spec(&spec, false), text);
+    if spec.default.is_none() {
+        diagnostics.extend(completeness_diagnostics(&analyze_completeness(&spec), text));
+    }
+    diagnostics
+}
+
+/// One diagnostic per validation issue, placed on its first affected rule
+pub fn validation_diagnostics(report: &ValidationReport, text: &str) -> Vec<Diagnostic> {
+    let rules = rule_ranges(text);
+    report
+        .issues
+        .iter()
+        .map(|issue| Diagnostic {
+            range: issue
+                .affected_rules
+                .iter()
+                .find_map(|id| rules.get(id))
+                .copied()
+                .unwrap_or_default(),
+            severity: Some(match issue.severity {
+                Severity::Error => DiagnosticSeverity::ERROR,
+                Severity::Warning => DiagnosticSeverity::WARNING,
+            }),
+            code: Some(NumberOrString::String(issue.code.clone())),
+            source: Some("imacs".into()),
+            message: match &issue.suggestion {
+                Some(suggestion) => format!("{}\n{}", issue.message, suggestion),
+                None => issue.message.clone(),
+            },
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// A note on the `rules:` key when some inputs match no rule
+pub fn completeness_diagnostics(report: &IncompletenessReport, text: &str) -> Vec<Diagnostic> {
+    let Some(example) = report.missing_cases.first() else {
+        return Vec::new();
+    };
+    let range = text
+        .lines()
+        .position(|line| line.trim_end() == "rules:")
+        .map(|line| line_range(text, line))
+        .unwrap_or_default();
+    vec![Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::INFORMATION),
+        code: Some(NumberOrString::String("INCOMPLETE".into())),
+        source: Some("imacs".into()),
+        message: format!(
+            "{} input combination(s) match no rule and there is no default, e.g. {}",
+            report.missing_cases.len(),
+            example.cel_conditions.join(" && ")
+        ),
+        ..Default::default()
+    }]
+}
+
+/// Parse error placed at the line/column the YAML parser reported
+fn parse_diagnostic(message: &str, text: &str) -> Diagnostic {
+    let location = Regex::new(r"line (\d+) column (\d+)").expect("valid regex");
+    let range = location
+        .captures(message)
+        .and_then(|c| c[1].parse::<usize>().ok())
+        .map(|line| line_range(text, line.saturating_sub(1)))
+        .unwrap_or_default();
+    Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("imacs".into()),
+        message: message.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Range of each rule's `id:` line
+fn rule_ranges(text: &str) -> HashMap<String, Range> {
+    rule_lines(text)
+        .into_iter()
+        .map(|(id, line)| (id, line_range(text, line)))
+        .collect()
+}
+
+/// A line's text, without leading indentation
+fn line_range(text: &str, line_no: usize) -> Range {
+    let line = text.lines().nth(line_no).unwrap_or("");
+    let indent = line.len() - line.trim_start().len();
+    Range::new(
+        Position::new(line_no as u32, indent as u32),
+        Position::new(
+            line_no as u32,
+            line.trim_end().encode_utf16().count() as u32,
+        ),
+    )
+}
+
+/// Markdown describing the input or output named under the cursor
+pub fn hover_text(text: &str, position: Position) -> Option<String> {
+    let line = text.lines().nth(position.line as usize)?;
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let chars: Vec<char> = line.chars().collect();
+    let at = (position.character as usize).min(chars.len());
+    let start = chars[..at]
+        .iter()
+        .rposition(|c| !is_word(*c))
+        .map_or(0, |i| i + 1);
+    let end = chars[at..]
+        .iter()
+        .position(|c| !is_word(*c))
+        .map_or(chars.len(), |i| at + i);
+    let word: String = chars[start..end].iter().collect();
+    if word.is_empty() {
+        return None;
+    }
+
+    let spec = Spec::from_yaml(text).ok()?;
+    let describe = |kind: &str, var: &Variable| {
+        let optional = if var.optional { " (optional)" } else { "" };
+        let mut out = format!(
+            "{} `{}`: `{}`{}",
+            kind,
+            var.name,
+            type_label(&var.typ),
+            optional
+        );
+        if let Some(description) = &var.description {
+            out.push_str("\n\n");
+            out.push_str(description);
+        }
+        out
+    };
+    spec.inputs
+        .iter()
+        .find(|v| v.name == word)
+        .map(|v| describe("input", v))
+        .or_else(|| {
+            spec.outputs
+                .iter()
+                .find(|v| v.name == word)
+                .map(|v| describe("output", v))
+        })
+}
+
+fn type_label(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "bool".into(),
+        VarType::Int => "int".into(),
+        VarType::Float => "float".into(),
+        VarType::String => "string".into(),
+        VarType::Enum(values) => format!("enum [{}]", values.join(", ")),
+        VarType::List(inner) => format!("list<{}>", type_label(inner)),
+        VarType::Object(fields) if fields.is_empty() => "object".into(),
+        VarType::Object(fields) => {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|(name, typ)| format!("{}: {}", name, type_label(typ)))
+                .collect();
+            format!("object {{ {} }}", fields.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::completeness::{IssueType, ValidationIssue};
+
+    const SPEC: &str = r#"id: access
+inputs:
+  - name: role
+    type: string
+    description: Caller's role
+outputs:
+  - name: allowed
+    type: bool
+rules:
+  - id: R1
+    when: role == "admin"
+    then: true
+  - id: R2
+    when: role == "admin"
+    then: false
+default: false
+"#;
+
+    fn issue(code: &str, severity: Severity, rule: &str) -> ValidationIssue {
+        ValidationIssue {
+            code: code.into(),
+            severity,
+            issue_type: IssueType::ContradictoryRules,
+            message: format!("{} problem", code),
+            affected_rules: vec![rule.into()],
+            explanation: None,
+            suggestion: None,
+            fix_example: None,
+            context: None,
+        }
+    }
+
+    #[test]
+    fn test_validation_report_to_diagnostics() {
+        let report = ValidationReport {
+            is_valid: false,
+            issues: vec![
+                issue("V001", Severity::Error, "R2"),
+                issue("V002", Severity::Warning, "R1"),
+            ],
+            fixes: vec![],
+            error_count: 1,
+            warning_count: 1,
+        };
+
+        let diagnostics = validation_diagnostics(&report, SPEC);
+        assert_eq!(diagnostics.len(), 2);
+
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(
+            diagnostics[0].code,
+            Some(NumberOrString::String("V001".into()))
+        );
+        assert_eq!(diagnostics[0].range.start, Position::new(12, 2));
+
+        assert_eq!(diagnostics[1].severity, Some(DiagnosticSeverity::WARNING));
+        assert_eq!(diagnostics[1].range.start, Position::new(9, 2));
+        assert_eq!(diagnostics[1].range.end, Position::new(9, 10));
+    }
+
+    #[test]
+    fn test_parse_error_diagnostic() {
+        let diagnostics = diagnose("id: broken\nrules:\n  - id: R1\n    when: [\n", None);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+    }
+
+    #[test]
+    fn test_hover_shows_input_type() {
+        let hover = hover_text(SPEC, Position::new(10, 12)).unwrap();
+        assert!(hover.contains("input `role`: `string`"));
+        assert!(hover.contains("Caller's role"));
+        assert_eq!(hover_text(SPEC, Position::new(10, 23)), None);
+    }
+}
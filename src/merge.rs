@@ -0,0 +1,198 @@
+//! Spec merging — combine two decision tables that share inputs
+//!
+//! Useful when refactoring: two specs that grew up separately over the same
+//! inputs can be folded into one table. Inputs and outputs are unioned by
+//! name, rules are concatenated (the receiver's rules first) and exact
+//! duplicates are dropped.
+
+use crate::spec::{Output, Rule, Spec, VarType, Variable};
+use thiserror::Error;
+
+/// Why two specs could not be merged
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum MergeError {
+    /// The same variable is declared with different types
+    #[error(
+        "variable '{name}' is {} in one spec but {} in the other",
+        type_label(.left),
+        type_label(.right)
+    )]
+    TypeConflict {
+        name: String,
+        left: VarType,
+        right: VarType,
+    },
+
+    /// Both specs declare a default, and they differ
+    #[error("specs have different defaults: {left} vs {right}")]
+    DefaultConflict { left: Output, right: Output },
+}
+
+impl Spec {
+    /// Merge `other` into a copy of this spec
+    ///
+    /// Inputs and outputs are unioned by name; a variable declared with two
+    /// different types is a [`MergeError::TypeConflict`], and one optional
+    /// on either side stays optional. Rules keep their effective order with
+    /// this spec's rules first, and priorities are reset so that declaration
+    /// order decides. Rules of `other` with the same condition and output as
+    /// an earlier rule are dropped; other rule ids that collide are prefixed
+    /// with `other`'s id. Id, name, metadata and scoping come from `self`.
+    pub fn merge(&self, other: &Spec) -> Result<Spec, MergeError> {
+        let inputs = union_variables(&self.inputs, &other.inputs)?;
+        let outputs = union_variables(&self.outputs, &other.outputs)?;
+
+        let default = match (&self.default, &other.default) {
+            (Some(left), Some(right)) if left != right => {
+                return Err(MergeError::DefaultConflict {
+                    left: left.clone(),
+                    right: right.clone(),
+                })
+            }
+            (left, right) => left.clone().or_else(|| right.clone()),
+        };
+
+        let mut rules: Vec<Rule> = Vec::new();
+        for rule in self
+            .rules_in_order()
+            .into_iter()
+            .chain(other.rules_in_order())
+        {
+            if rules.iter().any(|r| same_rule(r, rule)) {
+                continue;
+            }
+            let mut rule = rule.clone();
+            if rules.iter().any(|r| r.id == rule.id) {
+                rule.id = format!("{}_{}", other.id, rule.id);
+            }
+            rule.priority = 0;
+            rules.push(rule);
+        }
+
+        Ok(Spec {
+            inputs,
+            outputs,
+            rules,
+            default,
+            ..self.clone()
+        })
+    }
+}
+
+/// Same condition and same output
+fn same_rule(a: &Rule, b: &Rule) -> bool {
+    a.as_cel() == b.as_cel() && a.then == b.then
+}
+
+fn union_variables(left: &[Variable], right: &[Variable]) -> Result<Vec<Variable>, MergeError> {
+    let mut merged = left.to_vec();
+    for var in right {
+        match merged.iter_mut().find(|v| v.name == var.name) {
+            Some(existing) if existing.typ != var.typ => {
+                return Err(MergeError::TypeConflict {
+                    name: var.name.clone(),
+                    left: existing.typ.clone(),
+                    right: var.typ.clone(),
+                });
+            }
+            Some(existing) => {
+                existing.optional |= var.optional;
+                if existing.description.is_none() {
+                    existing.description = var.description.clone();
+                }
+            }
+            None => merged.push(var.clone()),
+        }
+    }
+    Ok(merged)
+}
+
+/// A type as written in spec YAML
+fn type_label(typ: &VarType) -> String {
+    serde_norway::to_string(typ)
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|_| format!("{:?}", typ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(yaml: &str) -> Spec {
+        Spec::from_yaml(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_merge_unions_inputs_and_dedups_rules() {
+        let access = spec(
+            r#"
+id: access
+inputs:
+  - name: role
+    type: string
+outputs:
+  - name: allowed
+    type: bool
+rules:
+  - id: R1
+    when: "role == 'admin'"
+    then: true
+default: false
+"#,
+        );
+        let suspended = spec(
+            r#"
+id: suspended
+inputs:
+  - name: role
+    type: string
+  - name: suspended
+    type: bool
+outputs:
+  - name: allowed
+    type: bool
+rules:
+  - id: R1
+    when: "suspended"
+    then: false
+    priority: 5
+  - id: R2
+    when: "role == 'admin'"
+    then: true
+  - id: R3
+    when: "role == 'guest'"
+    then: false
+default: false
+"#,
+        );
+
+        let merged = access.merge(&suspended).unwrap();
+        assert_eq!(merged.id, "access");
+        let inputs: Vec<&str> = merged.inputs.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(inputs, ["role", "suspended"]);
+        assert_eq!(merged.outputs.len(), 1);
+
+        // R2 duplicates access's R1; suspended's R1 is tried last and renamed
+        let ids: Vec<&str> = merged.rules.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["R1", "R3", "suspended_R1"]);
+        assert!(merged.rules.iter().all(|r| r.priority == 0));
+        assert!(merged.validate().is_empty());
+    }
+
+    #[test]
+    fn test_merge_type_conflict() {
+        let a = spec("id: a\ninputs:\n  - name: amount\n    type: int\nrules:\n  - id: R1\n    when: amount > 1\n    then: true\n");
+        let b = spec("id: b\ninputs:\n  - name: amount\n    type: float\nrules:\n  - id: R1\n    when: amount > 1.5\n    then: true\n");
+
+        let err = a.merge(&b).unwrap_err();
+        assert_eq!(
+            err,
+            MergeError::TypeConflict {
+                name: "amount".into(),
+                left: VarType::Int,
+                right: VarType::Float,
+            }
+        );
+        assert!(err.to_string().contains("'amount' is int"));
+    }
+}
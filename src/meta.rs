@@ -181,10 +181,15 @@ impl ImacMeta {
 /// Compute SHA256 hash of a file
 fn compute_file_hash(path: &Path) -> Result<String> {
     let content = std::fs::read(path).map_err(Error::Io)?;
+    Ok(compute_content_hash(&content))
+}
+
+/// Compute SHA256 hash of in-memory content (hex encoded)
+pub fn compute_content_hash(content: &[u8]) -> String {
     let mut hasher = Sha256::new();
-    hasher.update(&content);
+    hasher.update(content);
     let hash = hasher.finalize();
-    Ok(hex::encode(hash))
+    hex::encode(hash)
 }
 
 /// Create new metadata with current timestamp
@@ -237,6 +242,102 @@ pub fn find_stale_specs(imacs_dir: &Path, generated_dir: &Path) -> Result<Vec<Pa
     Ok(stale)
 }
 
+/// Outcome of checking one spec's generated code in [`selfcheck`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelfcheckStatus {
+    /// Spec hash matches the one recorded at generation; not re-rendered
+    Unchanged,
+    /// Re-rendered and identical to the generated file
+    Matches,
+    /// Re-rendered and different from the generated file
+    Mismatch,
+    /// No generated file for the spec
+    Missing,
+}
+
+/// Check the generated Rust code (`{id}.rs`) and tests (`{id}_test.rs`) of
+/// every spec in `imacs_dir`
+///
+/// Specs whose hash matches `.imacs_meta.yaml` are skipped unless `force`
+/// is set; the rest are re-rendered and compared with [`same_rust`].
+/// Results are `(spec id, status)`.
+pub fn selfcheck(
+    imacs_dir: &Path,
+    generated_dir: &Path,
+    force: bool,
+) -> Result<Vec<(String, SelfcheckStatus)>> {
+    let meta = if force {
+        None
+    } else {
+        ImacMeta::load_from_dir(generated_dir)?
+    };
+
+    let mut results = Vec::new();
+    for spec_path in crate::project::list_specs(imacs_dir)? {
+        let spec_content = std::fs::read_to_string(&spec_path).map_err(Error::Io)?;
+        let spec = crate::Spec::from_yaml(&spec_content)?;
+
+        let generated_path = generated_dir.join(format!("{}.rs", spec.id));
+        if !generated_path.exists() {
+            results.push((spec.id, SelfcheckStatus::Missing));
+            continue;
+        }
+        if meta
+            .as_ref()
+            .is_some_and(|meta| !meta.is_stale(&spec_path, imacs_dir))
+        {
+            results.push((spec.id, SelfcheckStatus::Unchanged));
+            continue;
+        }
+
+        let code = std::fs::read_to_string(&generated_path).map_err(Error::Io)?;
+        // Regen writes no test file for a spec without tests
+        let tests = std::fs::read_to_string(generated_dir.join(format!("{}_test.rs", spec.id)))
+            .unwrap_or_default();
+        let (expected_code, expected_tests) = generated_rust(&spec);
+
+        let status = if same_rust(&expected_code, &code) && same_rust(&expected_tests, &tests) {
+            SelfcheckStatus::Matches
+        } else {
+            SelfcheckStatus::Mismatch
+        };
+        results.push((spec.id, status));
+    }
+    Ok(results)
+}
+
+/// Rust code and tests for an internal spec, as `imacs regen` writes them
+/// to `{id}.rs` and `{id}_test.rs`
+pub fn generated_rust(spec: &crate::Spec) -> (String, String) {
+    (
+        crate::render(spec, crate::Target::Rust),
+        crate::generate_tests(spec, crate::Target::Rust),
+    )
+}
+
+/// Whether generated Rust matches what is on disk
+///
+/// Both sides are normalized with prettyplease, so rustfmt layout and
+/// comments (including the `GENERATED:` and `SPEC HASH:` headers) don't
+/// count. Code that doesn't parse is compared line by line, ignoring
+/// those two headers.
+fn same_rust(expected: &str, actual: &str) -> bool {
+    match (
+        crate::format::format_rust(expected),
+        crate::format::format_rust(actual),
+    ) {
+        (Ok(expected), Ok(actual)) => expected == actual,
+        _ => {
+            let filter_metadata =
+                |l: &&str| !l.starts_with("// GENERATED:") && !l.starts_with("// SPEC HASH:");
+            expected
+                .lines()
+                .filter(filter_metadata)
+                .eq(actual.lines().filter(filter_metadata))
+        }
+    }
+}
+
 /// Collect all spec files in a directory
 fn collect_all_specs(dir: &Path) -> Result<Vec<PathBuf>> {
     let mut specs = Vec::new();
@@ -315,4 +416,87 @@ mod tests {
         let stale = find_stale_specs(&imacs_dir, &generated_dir).unwrap();
         assert_eq!(stale.len(), 1);
     }
+
+    #[test]
+    fn test_selfcheck_skips_unchanged_specs() {
+        let temp = TempDir::new().unwrap();
+        let imacs_dir = temp.path().join("imacs");
+        let generated_dir = temp.path().join("generated");
+        fs::create_dir_all(&imacs_dir).unwrap();
+        fs::create_dir_all(&generated_dir).unwrap();
+
+        let spec_yaml = |id: &str| {
+            format!(
+                "id: {}\ninputs:\n  - name: a\n    type: bool\noutputs:\n  - name: out\n    type: int\nrules:\n  - id: R1\n    when: a\n    then: 1\n  - id: R2\n    when: '!a'\n    then: 0\n",
+                id
+            )
+        };
+
+        // What `imacs regen` leaves behind: generated code plus recorded hashes
+        let mut meta = create_meta();
+        for id in ["first", "second"] {
+            let path = imacs_dir.join(format!("{}.yaml", id));
+            fs::write(&path, spec_yaml(id)).unwrap();
+            let spec = crate::Spec::from_yaml(&spec_yaml(id)).unwrap();
+            let (code, tests) = generated_rust(&spec);
+            fs::write(generated_dir.join(format!("{}.rs", id)), code).unwrap();
+            fs::write(generated_dir.join(format!("{}_test.rs", id)), tests).unwrap();
+            meta.update_hash(&path, &imacs_dir).unwrap();
+        }
+        meta.save_to_dir(&generated_dir).unwrap();
+
+        let results = selfcheck(&imacs_dir, &generated_dir, false).unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .iter()
+            .all(|(_, status)| *status == SelfcheckStatus::Unchanged));
+
+        // Editing one spec's bytes re-checks only that spec
+        let edited = format!("# tweaked\n{}", spec_yaml("second"));
+        fs::write(imacs_dir.join("second.yaml"), edited).unwrap();
+        let results = selfcheck(&imacs_dir, &generated_dir, false).unwrap();
+        let status = |id: &str| results.iter().find(|(s, _)| s == id).unwrap().1;
+        assert_eq!(status("first"), SelfcheckStatus::Unchanged);
+        assert_eq!(status("second"), SelfcheckStatus::Matches);
+
+        // --force checks everything
+        let results = selfcheck(&imacs_dir, &generated_dir, true).unwrap();
+        assert!(results
+            .iter()
+            .all(|(_, status)| *status == SelfcheckStatus::Matches));
+    }
+
+    #[test]
+    fn test_selfcheck_ignores_layout_and_comments() {
+        let temp = TempDir::new().unwrap();
+        let imacs_dir = temp.path().join("imacs");
+        let generated_dir = temp.path().join("generated");
+        fs::create_dir_all(&imacs_dir).unwrap();
+        fs::create_dir_all(&generated_dir).unwrap();
+
+        let yaml = "id: check\ninputs:\n  - name: a\n    type: bool\noutputs:\n  - name: out\n    type: int\nrules:\n  - id: R1\n    when: a\n    then: 1\n  - id: R2\n    when: '!a'\n    then: 0\n";
+        fs::write(imacs_dir.join("check.yaml"), yaml).unwrap();
+        let spec = crate::Spec::from_yaml(yaml).unwrap();
+        let (code, tests) = generated_rust(&spec);
+        fs::write(generated_dir.join("check_test.rs"), tests).unwrap();
+
+        // Reformatted, with a comment of its own: still the same code
+        let reformatted = format!("// hand-formatted\n{}", code.replace("    ", "\t"));
+        fs::write(generated_dir.join("check.rs"), reformatted).unwrap();
+        let results = selfcheck(&imacs_dir, &generated_dir, true).unwrap();
+        assert_eq!(
+            results,
+            vec![("check".to_string(), SelfcheckStatus::Matches)]
+        );
+
+        // A changed output is a mismatch
+        let edited = code.replace("1i64", "2i64");
+        assert_ne!(edited, code);
+        fs::write(generated_dir.join("check.rs"), edited).unwrap();
+        let results = selfcheck(&imacs_dir, &generated_dir, true).unwrap();
+        assert_eq!(
+            results,
+            vec![("check".to_string(), SelfcheckStatus::Mismatch)]
+        );
+    }
 }
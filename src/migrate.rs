@@ -0,0 +1,168 @@
+//! Spec schema versions and migrations
+//!
+//! A spec records the schema it was written against in `schema_version`;
+//! specs written before versioning have none and count as version 1. Each
+//! migration rewrites the YAML document from one version to the next, so
+//! older specs keep loading after keys are renamed.
+
+use crate::error::{Error, Result};
+use crate::spec::Spec;
+use serde_norway::{Mapping, Value};
+
+/// Schema version of the spec model in this build
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// Keys renamed going from schema version `from` to `from + 1`
+struct Migration {
+    from: u32,
+    /// `(section, old, new)`: `section` names a top-level list whose
+    /// entries have the key renamed, or is empty for the spec itself
+    renames: &'static [(&'static str, &'static str, &'static str)],
+}
+
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: 1,
+    // Variables declared their type with `kind:`
+    renames: &[("inputs", "kind", "type"), ("outputs", "kind", "type")],
+}];
+
+impl Spec {
+    /// Parse a spec written against any supported schema version and bring
+    /// it up to [`CURRENT_SCHEMA_VERSION`]
+    ///
+    /// Unlike [`Spec::from_yaml`], which also applies the migrations, the
+    /// result is stamped with the current `schema_version`, so writing it
+    /// back out gives the upgraded form.
+    pub fn migrate(yaml: &str) -> Result<Spec> {
+        let mut spec = Spec::from_yaml(yaml)?;
+        spec.schema_version = Some(CURRENT_SCHEMA_VERSION);
+        Ok(spec)
+    }
+}
+
+/// Apply every migration from the document's `schema_version` onwards
+///
+/// Returns whether the document changed. An explicit older
+/// `schema_version` is bumped to the current one; a version newer than
+/// this build knows is an error.
+pub fn migrate_document(doc: &mut Value) -> Result<bool> {
+    let Some(spec) = doc.as_mapping_mut() else {
+        return Ok(false);
+    };
+    let version = match spec.get("schema_version") {
+        None => 1,
+        Some(value) => value
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .filter(|v| *v >= 1)
+            .ok_or_else(|| {
+                Error::SpecParse(format!(
+                    "schema_version must be a positive integer, got {}",
+                    serde_norway::to_string(value)
+                        .unwrap_or_default()
+                        .trim_end()
+                ))
+            })?,
+    };
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(Error::SpecParse(format!(
+            "schema_version {} is newer than this version of imacs supports ({}); upgrade imacs",
+            version, CURRENT_SCHEMA_VERSION
+        )));
+    }
+
+    let mut changed = false;
+    for migration in MIGRATIONS.iter().filter(|m| m.from >= version) {
+        for (section, old, new) in migration.renames {
+            if section.is_empty() {
+                changed |= rename_key(spec, old, new)?;
+            } else if let Some(Value::Sequence(entries)) = spec.get_mut(*section) {
+                for entry in entries.iter_mut().filter_map(Value::as_mapping_mut) {
+                    changed |= rename_key(entry, old, new)?;
+                }
+            }
+        }
+    }
+    if spec.contains_key("schema_version") && version < CURRENT_SCHEMA_VERSION {
+        spec.insert("schema_version".into(), CURRENT_SCHEMA_VERSION.into());
+        changed = true;
+    }
+    Ok(changed)
+}
+
+/// Move `old`'s value to `new`; both present is ambiguous
+fn rename_key(mapping: &mut Mapping, old: &str, new: &str) -> Result<bool> {
+    let Some(value) = mapping.remove(old) else {
+        return Ok(false);
+    };
+    if mapping.contains_key(new) {
+        return Err(Error::SpecParse(format!(
+            "both '{}' and its replacement '{}' are set",
+            old, new
+        )));
+    }
+    mapping.insert(new.into(), value);
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::VarType;
+
+    const V1_SPEC: &str = r#"
+schema_version: 1
+id: check_status
+inputs:
+  - name: rate_exceeded
+    kind: bool
+  - name: tier
+    kind: !enum [free, pro]
+outputs:
+  - name: status
+    kind: int
+rules:
+  - id: R1
+    when: rate_exceeded
+    then: 429
+default: 200
+"#;
+
+    #[test]
+    fn test_migrate_v1_renamed_type_key() {
+        let spec = Spec::migrate(V1_SPEC).unwrap();
+        assert_eq!(spec.schema_version, Some(CURRENT_SCHEMA_VERSION));
+        assert_eq!(spec.inputs[0].typ, VarType::Bool);
+        assert_eq!(
+            spec.inputs[1].typ,
+            VarType::Enum(vec!["free".into(), "pro".into()])
+        );
+        assert_eq!(spec.outputs[0].typ, VarType::Int);
+
+        // Loading applies the same migrations, and the upgraded form
+        // round-trips
+        assert_eq!(
+            Spec::from_yaml(V1_SPEC).unwrap().inputs[0].typ,
+            VarType::Bool
+        );
+        let upgraded = spec.to_yaml().unwrap();
+        assert!(upgraded.contains("schema_version: 2"));
+        assert!(!upgraded.contains("kind:"));
+        let reloaded = Spec::from_yaml(&upgraded).unwrap();
+        assert_eq!(reloaded.inputs[1].typ, spec.inputs[1].typ);
+    }
+
+    #[test]
+    fn test_future_schema_version_errors() {
+        let yaml = V1_SPEC.replace("schema_version: 1", "schema_version: 99");
+        let err = Spec::from_yaml(&yaml).unwrap_err().to_string();
+        assert!(err.contains("schema_version 99 is newer"), "{}", err);
+    }
+
+    #[test]
+    fn test_both_old_and_new_key_errors() {
+        let yaml = V1_SPEC.replace("    kind: int", "    kind: int\n    type: int");
+        let err = Spec::from_yaml(&yaml).unwrap_err().to_string();
+        assert!(err.contains("'kind'"), "{}", err);
+    }
+}
@@ -0,0 +1,428 @@
+//! Flow diagrams for orchestrators
+//!
+//! Builds a node/edge graph from an orchestrator chain and renders it as a
+//! Mermaid `flowchart` or a Graphviz `digraph` for design reviews.
+
+use super::{ChainStep, Orchestrator};
+
+/// Node shape in the rendered diagram
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shape {
+    Box,
+    Diamond,
+    Hexagon,
+    Rounded,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    id: String,
+    lines: Vec<String>,
+    shape: Shape,
+}
+
+#[derive(Debug, Clone)]
+struct Edge {
+    from: String,
+    to: String,
+    label: Option<String>,
+}
+
+/// Pending edge out of an already-placed node, waiting for its successor
+type Exit = (String, Option<String>);
+
+#[derive(Debug, Default)]
+struct FlowGraph {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+    anonymous: usize,
+}
+
+impl FlowGraph {
+    fn build(orch: &Orchestrator) -> Self {
+        let mut graph = FlowGraph::default();
+        graph.chain(&orch.chain, Vec::new());
+        graph
+    }
+
+    fn node(&mut self, id: String, lines: Vec<String>, shape: Shape) -> String {
+        self.nodes.push(Node {
+            id: id.clone(),
+            lines,
+            shape,
+        });
+        id
+    }
+
+    /// Node ID for steps that have no `id` of their own
+    fn anonymous_id(&mut self, kind: &str) -> String {
+        self.anonymous += 1;
+        format!("{}_{}", kind, self.anonymous)
+    }
+
+    fn connect(&mut self, exits: Vec<Exit>, to: &str) {
+        for (from, label) in exits {
+            self.edges.push(Edge {
+                from,
+                to: to.to_string(),
+                label,
+            });
+        }
+    }
+
+    /// Place a sequence of steps after `entry`, returning the dangling exits
+    fn chain(&mut self, steps: &[ChainStep], entry: Vec<Exit>) -> Vec<Exit> {
+        let mut exits = entry;
+        for step in steps {
+            exits = self.step(step, exits);
+        }
+        exits
+    }
+
+    fn step(&mut self, step: &ChainStep, entry: Vec<Exit>) -> Vec<Exit> {
+        match step {
+            ChainStep::Call(s) => {
+                let id = self.node(
+                    s.id.clone(),
+                    vec![s.id.clone(), format!("call {}", s.spec)],
+                    Shape::Box,
+                );
+                self.connect(entry, &id);
+                vec![(id, None)]
+            }
+            ChainStep::Gate(s) => {
+                let id = self.node(
+                    s.id.clone(),
+                    vec![s.id.clone(), s.condition.clone()],
+                    Shape::Diamond,
+                );
+                self.connect(entry, &id);
+                vec![(id, Some("pass".to_string()))]
+            }
+            ChainStep::Compute(s) => {
+                let id = self.node(
+                    s.id.clone(),
+                    vec![s.id.clone(), format!("{} = {}", s.name, s.expr)],
+                    Shape::Box,
+                );
+                self.connect(entry, &id);
+                vec![(id, None)]
+            }
+            ChainStep::Dynamic(s) => {
+                let id = self.node(
+                    s.id.clone(),
+                    vec![s.id.clone(), format!("dispatch {}", s.spec)],
+                    Shape::Box,
+                );
+                self.connect(entry, &id);
+                vec![(id, None)]
+            }
+            ChainStep::Await(s) => {
+                let id = self.node(
+                    s.id.clone(),
+                    vec![s.id.clone(), format!("await {}", s.expr)],
+                    Shape::Box,
+                );
+                self.connect(entry, &id);
+                vec![(id, None)]
+            }
+            ChainStep::Set(s) => {
+                let id = self.anonymous_id("set");
+                let id = self.node(
+                    id,
+                    vec![format!("set {} = {}", s.name, s.value)],
+                    Shape::Box,
+                );
+                self.connect(entry, &id);
+                vec![(id, None)]
+            }
+            ChainStep::Emit(s) => {
+                let id = self.anonymous_id("emit");
+                let id = self.node(id, vec![format!("emit {}", s.event)], Shape::Box);
+                self.connect(entry, &id);
+                vec![(id, None)]
+            }
+            ChainStep::Return(s) => {
+                let id = self.anonymous_id("return");
+                let mut lines = vec![format!("return {}", s.value)];
+                if let Some(cond) = &s.condition {
+                    lines.push(format!("if {}", cond));
+                }
+                let id = self.node(id, lines, Shape::Rounded);
+                self.connect(entry, &id);
+                // Only a conditional return lets control continue
+                match s.condition {
+                    Some(_) => vec![(id, Some("otherwise".to_string()))],
+                    None => Vec::new(),
+                }
+            }
+            ChainStep::Branch(s) => {
+                let id = self.node(
+                    s.id.clone(),
+                    vec![s.id.clone(), format!("on {}", s.on)],
+                    Shape::Hexagon,
+                );
+                self.connect(entry, &id);
+                let mut cases: Vec<_> = s.cases.iter().collect();
+                cases.sort_by(|a, b| a.0.cmp(b.0));
+                let mut exits = Vec::new();
+                for (case, steps) in cases {
+                    exits.extend(self.chain(steps, vec![(id.clone(), Some(case.clone()))]));
+                }
+                let default_entry = vec![(id.clone(), Some("default".to_string()))];
+                match &s.default {
+                    Some(steps) => exits.extend(self.chain(steps, default_entry)),
+                    None => exits.extend(default_entry),
+                }
+                exits
+            }
+            ChainStep::Loop(s) => {
+                let mut lines = vec![s.id.clone(), format!("loop max {}", s.max_iterations)];
+                if let Some(until) = &s.until {
+                    lines.push(format!("until {}", until));
+                }
+                let id = self.node(s.id.clone(), lines, Shape::Hexagon);
+                self.connect(entry, &id);
+                let body = self.chain(&s.steps, vec![(id.clone(), Some("repeat".to_string()))]);
+                self.connect(body, &id);
+                vec![(id, Some("done".to_string()))]
+            }
+            ChainStep::ForEach(s) => {
+                let id = self.node(
+                    s.id.clone(),
+                    vec![s.id.clone(), format!("for {} in {}", s.item, s.collection)],
+                    Shape::Hexagon,
+                );
+                self.connect(entry, &id);
+                let body = self.chain(&s.steps, vec![(id.clone(), Some("each".to_string()))]);
+                self.connect(body, &id);
+                vec![(id, Some("done".to_string()))]
+            }
+            ChainStep::Parallel(s) => {
+                let id = self.node(
+                    s.id.clone(),
+                    vec![s.id.clone(), "parallel".to_string()],
+                    Shape::Box,
+                );
+                self.connect(entry, &id);
+                let mut exits = Vec::new();
+                for inner in &s.steps {
+                    exits.extend(self.step(inner, vec![(id.clone(), None)]));
+                }
+                exits
+            }
+            ChainStep::Try(s) => {
+                let id = self.node(
+                    s.id.clone(),
+                    vec![s.id.clone(), "try".to_string()],
+                    Shape::Box,
+                );
+                self.connect(entry, &id);
+                let mut exits = self.chain(&s.try_steps, vec![(id.clone(), None)]);
+                if let Some(catch) = &s.catch {
+                    exits.extend(
+                        self.chain(&catch.steps, vec![(id.clone(), Some("catch".to_string()))]),
+                    );
+                }
+                match &s.finally {
+                    Some(finally) => self.chain(finally, exits),
+                    None => exits,
+                }
+            }
+        }
+    }
+}
+
+impl Orchestrator {
+    /// Render the chain as a Mermaid `flowchart`
+    ///
+    /// Gates are diamonds, branches and loops are hexagons, and branch
+    /// cases and try/catch paths appear as labeled edges.
+    pub fn to_mermaid(&self) -> String {
+        let graph = FlowGraph::build(self);
+        let mut out = String::from("flowchart TD\n");
+        for node in &graph.nodes {
+            let label = node
+                .lines
+                .iter()
+                .map(|l| mermaid_escape(l))
+                .collect::<Vec<_>>()
+                .join("<br/>");
+            let (open, close) = match node.shape {
+                Shape::Box => ("[", "]"),
+                Shape::Diamond => ("{", "}"),
+                Shape::Hexagon => ("{{", "}}"),
+                Shape::Rounded => ("([", "])"),
+            };
+            out.push_str(&format!(
+                "    {}{}\"{}\"{}\n",
+                mermaid_id(&node.id),
+                open,
+                label,
+                close
+            ));
+        }
+        for edge in &graph.edges {
+            match &edge.label {
+                Some(label) => out.push_str(&format!(
+                    "    {} -->|\"{}\"| {}\n",
+                    mermaid_id(&edge.from),
+                    mermaid_escape(label),
+                    mermaid_id(&edge.to)
+                )),
+                None => out.push_str(&format!(
+                    "    {} --> {}\n",
+                    mermaid_id(&edge.from),
+                    mermaid_id(&edge.to)
+                )),
+            }
+        }
+        out
+    }
+
+    /// Render the chain as a Graphviz DOT `digraph`
+    pub fn to_dot(&self) -> String {
+        let graph = FlowGraph::build(self);
+        let mut out = format!("digraph \"{}\" {{\n", dot_escape(&self.id));
+        out.push_str("    rankdir=TB;\n");
+        out.push_str("    node [shape=box];\n");
+        for node in &graph.nodes {
+            let label = node
+                .lines
+                .iter()
+                .map(|l| dot_escape(l))
+                .collect::<Vec<_>>()
+                .join("\\n");
+            let shape = match node.shape {
+                Shape::Box => "",
+                Shape::Diamond => ", shape=diamond",
+                Shape::Hexagon => ", shape=hexagon",
+                Shape::Rounded => ", shape=box, style=rounded",
+            };
+            out.push_str(&format!(
+                "    \"{}\" [label=\"{}\"{}];\n",
+                dot_escape(&node.id),
+                label,
+                shape
+            ));
+        }
+        for edge in &graph.edges {
+            let label = edge
+                .label
+                .as_ref()
+                .map(|l| format!(" [label=\"{}\"]", dot_escape(l)))
+                .unwrap_or_default();
+            out.push_str(&format!(
+                "    \"{}\" -> \"{}\"{};\n",
+                dot_escape(&edge.from),
+                dot_escape(&edge.to),
+                label
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Mermaid node IDs must be plain identifiers and must not be the `end` keyword
+fn mermaid_id(id: &str) -> String {
+    let id: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if id.eq_ignore_ascii_case("end") {
+        format!("{}_", id)
+    } else {
+        id
+    }
+}
+
+fn mermaid_escape(s: &str) -> String {
+    s.replace('"', "#quot;")
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestrate::collect_step_ids;
+
+    const FLOW: &str = r#"
+id: order_flow
+chain:
+  - step: call
+    id: check_access
+    spec: access_level
+    inputs: {}
+  - step: gate
+    id: require_access
+    condition: "check_access.level >= 50"
+  - step: branch
+    id: by_zone
+    on: "zone"
+    cases:
+      domestic:
+        - step: call
+          id: domestic_rate
+          spec: shipping_rate
+          inputs: {}
+      intl:
+        - step: try
+          id: intl_quote
+          try:
+            - step: call
+              id: carrier_quote
+              spec: carrier
+              inputs: {}
+          catch:
+            steps:
+              - step: call
+                id: fallback_quote
+                spec: shipping_rate
+                inputs: {}
+  - step: foreach
+    id: each_item
+    collection: "items"
+    steps:
+      - step: call
+        id: price_item
+        spec: pricing
+        inputs: {}
+"#;
+
+    #[test]
+    fn test_mermaid_has_node_per_step() {
+        let orch = Orchestrator::from_yaml(FLOW).unwrap();
+        let mermaid = orch.to_mermaid();
+        assert!(mermaid.starts_with("flowchart TD\n"));
+
+        let declared: Vec<&str> = mermaid
+            .lines()
+            .filter(|l| !l.contains("-->"))
+            .filter_map(|l| l.trim().split(['[', '{', '(']).next())
+            .filter(|id| !id.is_empty() && *id != "flowchart TD")
+            .collect();
+        let mut ids = collect_step_ids(&orch.chain);
+        ids.sort();
+        let mut declared: Vec<String> = declared.into_iter().map(String::from).collect();
+        declared.sort();
+        assert_eq!(declared, ids);
+
+        assert!(mermaid.contains("    require_access{"));
+        assert!(mermaid.contains("by_zone -->|\"domestic\"| domestic_rate"));
+        assert!(mermaid.contains("intl_quote -->|\"catch\"| fallback_quote"));
+    }
+
+    #[test]
+    fn test_dot_output() {
+        let orch = Orchestrator::from_yaml(FLOW).unwrap();
+        let dot = orch.to_dot();
+        assert!(dot.starts_with("digraph \"order_flow\" {"));
+        assert!(dot.contains("\"require_access\" [label=\"require_access\\ncheck_access.level >= 50\", shape=diamond];"));
+        assert!(dot.contains("\"by_zone\" -> \"domestic_rate\" [label=\"domestic\"];"));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+}
@@ -9,13 +9,20 @@
 //! - Error handling (try/catch/finally)
 //!
 //! Code generation uses MiniJinja templates for properly formatted output.
+//! [`Orchestrator::simulate`] dry-runs a chain without generating code.
+
+mod graph;
+mod simulate;
 
 use crate::cel::Target;
+use crate::render::RenderConfig;
 use crate::spec::{Spec, VarType};
 use crate::templates;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+pub use simulate::{SimulationTrace, StepTrace};
+
 /// Render an orchestrator to target language using templates
 ///
 /// This function uses MiniJinja templates for code generation,
@@ -29,6 +36,17 @@ pub fn render_orchestrator(
         .unwrap_or_else(|e| panic!("Template rendering failed: {}", e))
 }
 
+/// Render orchestrator to target language code, with render options
+pub fn render_orchestrator_with_config(
+    orch: &Orchestrator,
+    specs: &HashMap<String, Spec>,
+    target: Target,
+    config: &RenderConfig,
+) -> String {
+    templates::render_orchestrator_with(orch, specs, target, config)
+        .unwrap_or_else(|e| panic!("Template rendering failed: {}", e))
+}
+
 /// An orchestrator composes multiple specs into a workflow
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Orchestrator {
@@ -119,6 +137,9 @@ impl Orchestrator {
             }
         }
 
+        // Check names don't shadow one another in generated code
+        errors.extend(self.validate_names());
+
         // Check all referenced specs exist
         for spec_id in self.referenced_specs() {
             if !specs.contains_key(&spec_id) {
@@ -129,6 +150,65 @@ impl Orchestrator {
         // Check inputs are provided for all call steps
         self.validate_chain(&self.chain, specs, &mut errors);
 
+        // Check step references respect execution order
+        errors.extend(self.validate_dataflow(specs));
+
+        errors
+    }
+
+    /// Validate data flow between steps
+    ///
+    /// Walks the chain in execution order and checks every `step_id.field`
+    /// reference against the steps that have already run. Flags forward
+    /// references, references to unknown step IDs, unknown outputs of
+    /// known specs, and steps that follow an unconditional return.
+    pub fn validate_dataflow(&self, specs: &HashMap<String, Spec>) -> Vec<String> {
+        let mut flow = DataflowState {
+            specs,
+            all_ids: collect_step_ids(&self.chain).into_iter().collect(),
+            locals: self.inputs.iter().map(|i| i.name.clone()).collect(),
+            done: HashMap::new(),
+            errors: Vec::new(),
+        };
+        flow.all_ids.extend(collect_compute_names(&self.chain));
+        flow.walk(&self.chain);
+        flow.errors
+    }
+
+    /// Check for names defined twice
+    ///
+    /// Inputs, step IDs, compute and set names and foreach item/index
+    /// variables all become variables in generated code, so two of them
+    /// with one name shadow each other. Each collision names both
+    /// definitions. Duplicate step IDs are reported by `validate` on their
+    /// own, a set step may reassign an earlier set, and foreach variables
+    /// only collide with those of an enclosing foreach.
+    pub fn validate_names(&self) -> Vec<String> {
+        let mut defs: Vec<NameDef> = self
+            .inputs
+            .iter()
+            .map(|input| NameDef {
+                name: input.name.clone(),
+                label: format!("input '{}'", input.name),
+                kind: NameKind::Input,
+                owner: None,
+                loops: Vec::new(),
+            })
+            .collect();
+        collect_name_defs(&self.chain, &[], &mut defs);
+
+        let mut errors = Vec::new();
+        for (i, later) in defs.iter().enumerate() {
+            let clash = defs[..i]
+                .iter()
+                .find(|earlier| earlier.name == later.name && earlier.collides_with(later));
+            if let Some(earlier) = clash {
+                errors.push(format!(
+                    "Name '{}' is defined twice: {} and {}",
+                    later.name, earlier.label, later.label
+                ));
+            }
+        }
         errors
     }
 
@@ -178,6 +258,399 @@ impl Orchestrator {
     }
 }
 
+/// Tracks which steps have run while walking a chain for [`Orchestrator::validate_dataflow`]
+struct DataflowState<'a> {
+    specs: &'a HashMap<String, Spec>,
+    /// Every step ID and compute name declared anywhere in the chain
+    all_ids: std::collections::HashSet<String>,
+    /// Names that are not step references (inputs, loop variables, error bindings)
+    locals: std::collections::HashSet<String>,
+    /// Steps that have run so far, with the call they made (if any)
+    done: HashMap<String, Option<&'a CallStep>>,
+    errors: Vec<String>,
+}
+
+impl<'a> DataflowState<'a> {
+    fn walk(&mut self, steps: &'a [ChainStep]) {
+        let mut returned = false;
+        for step in steps {
+            if returned {
+                self.errors.push(format!(
+                    "{} is unreachable after an unconditional return",
+                    step_label(step)
+                ));
+                continue;
+            }
+            self.check_step(step);
+            if let ChainStep::Return(ret) = step {
+                returned = ret.condition.is_none();
+            }
+        }
+    }
+
+    /// Walk a nested block with extra local names in scope
+    fn walk_scoped(&mut self, steps: &'a [ChainStep], locals: &[&String]) {
+        let added: Vec<String> = locals
+            .iter()
+            .filter(|name| self.locals.insert((*name).clone()))
+            .map(|name| (*name).clone())
+            .collect();
+        self.walk(steps);
+        for name in added {
+            self.locals.remove(&name);
+        }
+    }
+
+    fn check_step(&mut self, step: &'a ChainStep) {
+        let label = step_label(step);
+        match step {
+            ChainStep::Call(call) => {
+                let mut inputs: Vec<_> = call.inputs.iter().collect();
+                inputs.sort();
+                for (_, expr) in inputs {
+                    self.check_expr(&label, expr);
+                }
+                if let Some(cond) = &call.condition {
+                    self.check_expr(&label, cond);
+                }
+                self.done.insert(call.id.clone(), Some(call));
+            }
+            ChainStep::Compute(compute) => {
+                self.check_expr(&label, &compute.expr);
+                self.done.insert(compute.id.clone(), None);
+                self.done.insert(compute.name.clone(), None);
+            }
+            ChainStep::Gate(gate) => {
+                self.check_expr(&label, &gate.condition);
+                self.done.insert(gate.id.clone(), None);
+            }
+            ChainStep::Return(ret) => {
+                self.check_expr(&label, &ret.value);
+                if let Some(cond) = &ret.condition {
+                    self.check_expr(&label, cond);
+                }
+            }
+            ChainStep::Set(set) => {
+                self.check_expr(&label, &set.value);
+                self.locals.insert(set.name.clone());
+            }
+            ChainStep::Emit(emit) => self.check_expr(&label, &emit.data),
+            ChainStep::Await(await_) => {
+                self.check_expr(&label, &await_.expr);
+                self.done.insert(await_.id.clone(), None);
+            }
+            ChainStep::Dynamic(dyn_) => {
+                self.check_expr(&label, &dyn_.spec);
+                let mut inputs: Vec<_> = dyn_.inputs.iter().collect();
+                inputs.sort();
+                for (_, expr) in inputs {
+                    self.check_expr(&label, expr);
+                }
+                self.done.insert(dyn_.id.clone(), None);
+            }
+            ChainStep::Parallel(par) => {
+                // Parallel steps only see what ran before the block
+                let before = self.done.clone();
+                let mut after = before.clone();
+                for inner in &par.steps {
+                    self.done = before.clone();
+                    self.walk(std::slice::from_ref(inner));
+                    after.extend(self.done.drain());
+                }
+                self.done = after;
+                self.done.insert(par.id.clone(), None);
+            }
+            ChainStep::Branch(branch) => {
+                self.check_expr(&label, &branch.on);
+                let before = self.done.clone();
+                let mut after = before.clone();
+                let arms = branch
+                    .sorted_cases()
+                    .into_iter()
+                    .map(|(_, steps)| steps)
+                    .chain(branch.default.as_ref());
+                for steps in arms {
+                    self.done = before.clone();
+                    self.walk(steps);
+                    after.extend(self.done.drain());
+                }
+                self.done = after;
+                self.done.insert(branch.id.clone(), None);
+            }
+            ChainStep::Loop(loop_) => {
+                self.walk_scoped(&loop_.steps, &[&loop_.counter]);
+                if let Some(until) = &loop_.until {
+                    self.check_expr(&label, until);
+                }
+                self.done.insert(loop_.id.clone(), None);
+            }
+            ChainStep::ForEach(foreach) => {
+                self.check_expr(&label, &foreach.collection);
+                self.walk_scoped(&foreach.steps, &[&foreach.item, &foreach.index]);
+                self.done.insert(foreach.id.clone(), None);
+            }
+            ChainStep::Try(try_) => {
+                self.walk(&try_.try_steps);
+                if let Some(catch) = &try_.catch {
+                    self.walk_scoped(&catch.steps, &[&catch.error]);
+                }
+                if let Some(finally) = &try_.finally {
+                    self.walk(finally);
+                }
+                self.done.insert(try_.id.clone(), None);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, label: &str, expr: &str) {
+        for (head, field) in step_references(expr) {
+            if self.locals.contains(&head) {
+                continue;
+            }
+            match self.done.get(&head) {
+                Some(Some(call)) => {
+                    if let Some(spec) = self.specs.get(&call.spec) {
+                        let known = call.outputs.contains_key(&field)
+                            || spec.outputs.iter().any(|o| o.name == field);
+                        if !known {
+                            self.errors.push(format!(
+                                "{} references unknown output '{}' of step '{}' (spec '{}')",
+                                label, field, head, call.spec
+                            ));
+                        }
+                    }
+                }
+                Some(None) => {}
+                None if self.all_ids.contains(&head) => self.errors.push(format!(
+                    "{} references step '{}' before it runs",
+                    label, head
+                )),
+                None => self
+                    .errors
+                    .push(format!("{} references unknown step '{}'", label, head)),
+            }
+        }
+    }
+}
+
+/// A name an orchestrator defines, for [`Orchestrator::validate_names`]
+struct NameDef {
+    name: String,
+    /// How the definition reads in an error (`set step 'limit'`)
+    label: String,
+    kind: NameKind,
+    /// Step the name belongs to (step IDs, compute names, foreach variables)
+    owner: Option<String>,
+    /// IDs of the foreach steps the name is scoped to, outermost first
+    loops: Vec<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum NameKind {
+    Input,
+    Step,
+    Compute,
+    Set,
+    LoopVar,
+}
+
+impl NameDef {
+    fn collides_with(&self, other: &NameDef) -> bool {
+        use NameKind::*;
+        match (self.kind, other.kind) {
+            // Already reported as duplicate step IDs
+            (Step, Step) => false,
+            // Reassignment
+            (Set, Set) => false,
+            // A compute step named after its own ID
+            (Step, Compute) | (Compute, Step) => self.owner != other.owner,
+            // Sibling loops may reuse `item`; nested ones shadow it
+            (LoopVar, LoopVar) => {
+                self.loops.starts_with(&other.loops) || other.loops.starts_with(&self.loops)
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Every step ID, compute/set name and foreach variable of a chain, in order
+fn collect_name_defs(steps: &[ChainStep], loops: &[String], defs: &mut Vec<NameDef>) {
+    let def = |name: &str, label: String, kind: NameKind, owner: Option<&str>| NameDef {
+        name: name.to_string(),
+        label,
+        kind,
+        owner: owner.map(str::to_string),
+        loops: loops.to_vec(),
+    };
+    let step_def = |id: &str| def(id, format!("step '{}'", id), NameKind::Step, Some(id));
+
+    for step in steps {
+        match step {
+            ChainStep::Call(c) => defs.push(step_def(&c.id)),
+            ChainStep::Parallel(p) => {
+                defs.push(step_def(&p.id));
+                collect_name_defs(&p.steps, loops, defs);
+            }
+            ChainStep::Branch(b) => {
+                defs.push(step_def(&b.id));
+                for (_, steps) in b.sorted_cases() {
+                    collect_name_defs(steps, loops, defs);
+                }
+                if let Some(d) = &b.default {
+                    collect_name_defs(d, loops, defs);
+                }
+            }
+            ChainStep::Loop(l) => {
+                defs.push(step_def(&l.id));
+                collect_name_defs(&l.steps, loops, defs);
+            }
+            ChainStep::ForEach(f) => {
+                defs.push(step_def(&f.id));
+                let mut inner = loops.to_vec();
+                inner.push(f.id.clone());
+                for (name, what) in [(&f.item, "item"), (&f.index, "index")] {
+                    defs.push(NameDef {
+                        name: name.clone(),
+                        label: format!("foreach {} '{}' of step '{}'", what, name, f.id),
+                        kind: NameKind::LoopVar,
+                        owner: Some(f.id.clone()),
+                        loops: inner.clone(),
+                    });
+                }
+                collect_name_defs(&f.steps, &inner, defs);
+            }
+            ChainStep::Gate(g) => defs.push(step_def(&g.id)),
+            ChainStep::Compute(c) => {
+                defs.push(step_def(&c.id));
+                defs.push(def(
+                    &c.name,
+                    format!("compute '{}' of step '{}'", c.name, c.id),
+                    NameKind::Compute,
+                    Some(&c.id),
+                ));
+            }
+            ChainStep::Set(s) => defs.push(def(
+                &s.name,
+                format!("set step '{}'", s.name),
+                NameKind::Set,
+                None,
+            )),
+            ChainStep::Try(t) => {
+                defs.push(step_def(&t.id));
+                collect_name_defs(&t.try_steps, loops, defs);
+                if let Some(c) = &t.catch {
+                    collect_name_defs(&c.steps, loops, defs);
+                }
+                if let Some(f) = &t.finally {
+                    collect_name_defs(f, loops, defs);
+                }
+            }
+            ChainStep::Dynamic(d) => defs.push(step_def(&d.id)),
+            ChainStep::Await(a) => defs.push(step_def(&a.id)),
+            ChainStep::Return(_) | ChainStep::Emit(_) => {}
+        }
+    }
+}
+
+/// Human-readable label for a step in validation messages
+fn step_label(step: &ChainStep) -> String {
+    match step {
+        ChainStep::Call(s) => format!("Step '{}'", s.id),
+        ChainStep::Parallel(s) => format!("Step '{}'", s.id),
+        ChainStep::Branch(s) => format!("Step '{}'", s.id),
+        ChainStep::Loop(s) => format!("Step '{}'", s.id),
+        ChainStep::ForEach(s) => format!("Step '{}'", s.id),
+        ChainStep::Gate(s) => format!("Step '{}'", s.id),
+        ChainStep::Compute(s) => format!("Step '{}'", s.id),
+        ChainStep::Try(s) => format!("Step '{}'", s.id),
+        ChainStep::Dynamic(s) => format!("Step '{}'", s.id),
+        ChainStep::Await(s) => format!("Step '{}'", s.id),
+        ChainStep::Return(_) => "Return step".to_string(),
+        ChainStep::Set(s) => format!("Set step '{}'", s.name),
+        ChainStep::Emit(s) => format!("Emit step '{}'", s.event),
+    }
+}
+
+/// Extract `step_id.field` references from an orchestrator expression
+///
+/// Only the first segment of a dotted path is treated as a step reference;
+/// string literals and numeric literals are skipped.
+fn step_references(expr: &str) -> Vec<(String, String)> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut refs = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '"' || c == '\'' {
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                i += 1;
+            }
+        } else if c.is_alphabetic() || c == '_' {
+            let after_dot = i > 0 && chars[i - 1] == '.';
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let head: String = chars[start..i].iter().collect();
+            if after_dot || i >= chars.len() || chars[i] != '.' {
+                continue;
+            }
+            let field_start = i + 1;
+            let mut end = field_start;
+            while end < chars.len() && (chars[end].is_alphanumeric() || chars[end] == '_') {
+                end += 1;
+            }
+            if end > field_start {
+                refs.push((head, chars[field_start..end].iter().collect()));
+            }
+        } else {
+            i += 1;
+        }
+    }
+    refs
+}
+
+/// Collect the variable names bound by compute steps anywhere in a chain
+fn collect_compute_names(steps: &[ChainStep]) -> Vec<String> {
+    let mut names = Vec::new();
+    for step in steps {
+        match step {
+            ChainStep::Compute(c) => names.push(c.name.clone()),
+            ChainStep::Parallel(p) => names.extend(collect_compute_names(&p.steps)),
+            ChainStep::Branch(b) => {
+                for steps in b.cases.values() {
+                    names.extend(collect_compute_names(steps));
+                }
+                if let Some(d) = &b.default {
+                    names.extend(collect_compute_names(d));
+                }
+            }
+            ChainStep::Loop(l) => names.extend(collect_compute_names(&l.steps)),
+            ChainStep::ForEach(f) => names.extend(collect_compute_names(&f.steps)),
+            ChainStep::Try(t) => {
+                names.extend(collect_compute_names(&t.try_steps));
+                if let Some(c) = &t.catch {
+                    names.extend(collect_compute_names(&c.steps));
+                }
+                if let Some(f) = &t.finally {
+                    names.extend(collect_compute_names(f));
+                }
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
 /// Input to an orchestrator
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrchestratorInput {
@@ -290,6 +763,15 @@ pub struct BranchStep {
     pub default: Option<Vec<ChainStep>>,
 }
 
+impl BranchStep {
+    /// Cases ordered by value, so code generated from them is stable
+    pub fn sorted_cases(&self) -> Vec<(&String, &Vec<ChainStep>)> {
+        let mut cases: Vec<_> = self.cases.iter().collect();
+        cases.sort_by(|a, b| a.0.cmp(b.0));
+        cases
+    }
+}
+
 /// Loop with counter
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoopStep {
@@ -1012,4 +1494,112 @@ chain:
         // Should NOT warn about this branch since it has a spec call
         assert!(!report.warnings.iter().any(|w| w.contains("my_branch")));
     }
+
+    #[test]
+    fn test_validate_dataflow_forward_reference() {
+        let yaml = r#"
+id: forward_refs
+inputs:
+  - name: amount
+    type: int
+chain:
+  - step: call
+    id: first
+    spec: spec_a
+    inputs:
+      x: "second.total"
+  - step: call
+    id: second
+    spec: spec_b
+    inputs:
+      x: "amount"
+  - step: compute
+    id: third
+    name: doubled
+    expr: "second.total * 2 + missing.value"
+"#;
+        let orch = Orchestrator::from_yaml(yaml).unwrap();
+        let errors = orch.validate_dataflow(&HashMap::new());
+        assert_eq!(
+            errors,
+            vec![
+                "Step 'first' references step 'second' before it runs".to_string(),
+                "Step 'third' references unknown step 'missing'".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_name_collisions() {
+        let yaml = r#"
+id: limits
+inputs:
+  - name: limit
+    type: int
+  - name: orders
+    type: object
+chain:
+  - step: set
+    name: limit
+    value: "100"
+  - step: compute
+    id: total
+    name: total
+    expr: "limit * 2"
+  - step: foreach
+    id: each_order
+    collection: "orders"
+    steps:
+      - step: foreach
+        id: each_line
+        collection: "item.lines"
+        steps: []
+  - step: foreach
+    id: each_again
+    collection: "orders"
+    steps: []
+"#;
+        let orch = Orchestrator::from_yaml(yaml).unwrap();
+        let errors = orch.validate_names();
+        assert_eq!(
+            errors,
+            vec![
+                "Name 'limit' is defined twice: input 'limit' and set step 'limit'".to_string(),
+                "Name 'item' is defined twice: foreach item 'item' of step 'each_order' and foreach item 'item' of step 'each_line'".to_string(),
+                "Name 'idx' is defined twice: foreach index 'idx' of step 'each_order' and foreach index 'idx' of step 'each_line'".to_string(),
+            ]
+        );
+        assert!(orch
+            .validate(&HashMap::new())
+            .iter()
+            .any(|e| e.contains("input 'limit' and set step 'limit'")));
+    }
+
+    #[test]
+    fn test_validate_dataflow_dead_step_after_return() {
+        let yaml = r#"
+id: early_exit
+chain:
+  - step: call
+    id: lookup
+    spec: spec_a
+    inputs: {}
+  - step: return
+    value: "lookup.result"
+    condition: "lookup.done"
+  - step: return
+    value: "lookup.result"
+  - step: call
+    id: never
+    spec: spec_b
+    inputs:
+      x: "lookup.result"
+"#;
+        let orch = Orchestrator::from_yaml(yaml).unwrap();
+        let errors = orch.validate(&HashMap::new());
+        assert!(errors
+            .iter()
+            .any(|e| e == "Step 'never' is unreachable after an unconditional return"));
+        assert!(!errors.iter().any(|e| e.contains("before it runs")));
+    }
 }
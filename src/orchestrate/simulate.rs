@@ -0,0 +1,505 @@
+//! Dry-run interpreter for orchestrators
+//!
+//! Walks a chain against concrete inputs without generating code: calls are
+//! evaluated with [`Spec::evaluate`], conditions and expressions with the CEL
+//! interpreter, and every step is recorded in a [`SimulationTrace`].
+
+use super::{ChainStep, Orchestrator};
+use crate::cel::CelCompiler;
+use crate::error::{Error, Result};
+use crate::eval::{from_cel_value, untyped_json_to_cel};
+use crate::spec::{Output, Spec};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// What an orchestrator did for one set of inputs
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SimulationTrace {
+    /// Steps in the order they ran
+    pub steps: Vec<StepTrace>,
+    /// Declared orchestrator outputs found in the final context
+    pub outputs: Map<String, Value>,
+    /// Value of the `return` step that ended the run, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub returned: Option<Value>,
+    /// Error that ended the run (a failed gate or an evaluation error)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// One executed step
+#[derive(Debug, Clone, Serialize)]
+pub struct StepTrace {
+    /// Step id (the variable name for `set`, the event for `emit`)
+    pub id: String,
+    /// Step kind as written in YAML (`call`, `gate`, `branch`, ...)
+    pub kind: String,
+    /// Values the step consumed (spec inputs for a call)
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub inputs: Map<String, Value>,
+    /// Values the step produced
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub outputs: Map<String, Value>,
+    /// Case a `branch` took, or `skipped` for a call whose condition was false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+}
+
+impl StepTrace {
+    fn new(id: &str, kind: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            kind: kind.to_string(),
+            inputs: Map::new(),
+            outputs: Map::new(),
+            branch: None,
+        }
+    }
+}
+
+impl Orchestrator {
+    /// Trace what the orchestrator would do for `inputs` (a JSON object)
+    ///
+    /// Parallel steps run one after another in declaration order. The run
+    /// stops at the first `return`, failed gate or evaluation error; the
+    /// latter two are recorded in [`SimulationTrace::error`].
+    pub fn simulate(&self, specs: &HashMap<String, Spec>, inputs: &Value) -> SimulationTrace {
+        let mut sim = Simulator {
+            specs,
+            context: Map::new(),
+            trace: SimulationTrace::default(),
+        };
+
+        match inputs.as_object() {
+            Some(values) => {
+                sim.context = values.clone();
+                if let Some(missing) = self.inputs.iter().find(|i| !values.contains_key(&i.name)) {
+                    sim.trace.error = Some(format!("missing input '{}'", missing.name));
+                }
+            }
+            None => sim.trace.error = Some("inputs must be a JSON object".into()),
+        }
+
+        if sim.trace.error.is_none() {
+            sim.run(&self.chain);
+        }
+
+        for output in &self.outputs {
+            if let Some(value) = sim.context.get(&output.name) {
+                sim.trace.outputs.insert(output.name.clone(), value.clone());
+            }
+        }
+        sim.trace
+    }
+}
+
+/// Whether the chain keeps going after a step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flow {
+    Continue,
+    Stop,
+}
+
+struct Simulator<'a> {
+    specs: &'a HashMap<String, Spec>,
+    /// Orchestrator inputs, step results (by step id) and computed values
+    context: Map<String, Value>,
+    trace: SimulationTrace,
+}
+
+impl Simulator<'_> {
+    fn run(&mut self, steps: &[ChainStep]) -> Flow {
+        for step in steps {
+            let flow = match self.step(step) {
+                Ok(flow) => flow,
+                Err(e) => {
+                    self.trace.error = Some(format!("{}: {}", step_label(step), e));
+                    Flow::Stop
+                }
+            };
+            if flow == Flow::Stop {
+                return Flow::Stop;
+            }
+        }
+        Flow::Continue
+    }
+
+    fn step(&mut self, step: &ChainStep) -> Result<Flow> {
+        match step {
+            ChainStep::Call(call) => {
+                if let Some(condition) = &call.condition {
+                    if !self.eval_bool(condition)? {
+                        let mut trace = StepTrace::new(&call.id, "call");
+                        trace.branch = Some("skipped".into());
+                        self.trace.steps.push(trace);
+                        return Ok(Flow::Continue);
+                    }
+                }
+                let outputs = self.call(&call.id, "call", &call.spec, &call.inputs)?;
+                for (local, spec_output) in &call.outputs {
+                    if let Some(value) = outputs.get(spec_output) {
+                        self.context.insert(local.clone(), value.clone());
+                    }
+                }
+                Ok(Flow::Continue)
+            }
+            ChainStep::Gate(gate) => {
+                let passed = self.eval_bool(&gate.condition)?;
+                let mut trace = StepTrace::new(&gate.id, "gate");
+                trace.outputs.insert("passed".into(), Value::Bool(passed));
+                self.trace.steps.push(trace);
+                if passed {
+                    Ok(Flow::Continue)
+                } else {
+                    self.trace.error = Some(
+                        gate.error
+                            .clone()
+                            .unwrap_or_else(|| format!("gate '{}' failed", gate.id)),
+                    );
+                    Ok(Flow::Stop)
+                }
+            }
+            ChainStep::Branch(branch) => {
+                let key = match self.eval(&branch.on)? {
+                    Value::String(s) => s,
+                    other => other.to_string(),
+                };
+                let (taken, steps) = match branch.cases.get(&key) {
+                    Some(steps) => (key, Some(steps)),
+                    None => ("default".to_string(), branch.default.as_ref()),
+                };
+                let mut trace = StepTrace::new(&branch.id, "branch");
+                trace.branch = Some(if steps.is_some() {
+                    taken
+                } else {
+                    "none".into()
+                });
+                self.trace.steps.push(trace);
+                Ok(steps.map_or(Flow::Continue, |steps| self.run(steps)))
+            }
+            ChainStep::Return(ret) => {
+                if let Some(condition) = &ret.condition {
+                    if !self.eval_bool(condition)? {
+                        return Ok(Flow::Continue);
+                    }
+                }
+                let value = self.eval(&ret.value)?;
+                let mut trace = StepTrace::new("return", "return");
+                trace.outputs.insert("value".into(), value.clone());
+                self.trace.steps.push(trace);
+                self.trace.returned = Some(value);
+                Ok(Flow::Stop)
+            }
+            ChainStep::Compute(compute) => {
+                let value = self.eval(&compute.expr)?;
+                self.assign(&compute.id, "compute", &compute.name, value);
+                Ok(Flow::Continue)
+            }
+            ChainStep::Set(set) => {
+                let value = self.eval(&set.value)?;
+                self.assign(&set.name, "set", &set.name, value);
+                Ok(Flow::Continue)
+            }
+            ChainStep::Parallel(par) => {
+                self.trace.steps.push(StepTrace::new(&par.id, "parallel"));
+                Ok(self.run(&par.steps))
+            }
+            ChainStep::Loop(loop_) => {
+                self.trace.steps.push(StepTrace::new(&loop_.id, "loop"));
+                for i in 0..loop_.max_iterations {
+                    self.context.insert(loop_.counter.clone(), Value::from(i));
+                    if self.run(&loop_.steps) == Flow::Stop {
+                        return Ok(Flow::Stop);
+                    }
+                    if let Some(until) = &loop_.until {
+                        if self.eval_bool(until)? {
+                            break;
+                        }
+                    }
+                }
+                Ok(Flow::Continue)
+            }
+            ChainStep::ForEach(foreach) => {
+                let items = match self.eval(&foreach.collection)? {
+                    Value::Array(items) => items,
+                    other => {
+                        return Err(Error::CelEval(format!(
+                            "{} is not a list: {}",
+                            foreach.collection, other
+                        )))
+                    }
+                };
+                self.trace
+                    .steps
+                    .push(StepTrace::new(&foreach.id, "foreach"));
+                for (i, item) in items.into_iter().enumerate() {
+                    self.context.insert(foreach.item.clone(), item);
+                    self.context.insert(foreach.index.clone(), Value::from(i));
+                    if self.run(&foreach.steps) == Flow::Stop {
+                        return Ok(Flow::Stop);
+                    }
+                }
+                Ok(Flow::Continue)
+            }
+            ChainStep::Try(try_) => {
+                self.trace.steps.push(StepTrace::new(&try_.id, "try"));
+                let mut flow = self.run(&try_.try_steps);
+                if let (Some(catch), Some(error)) = (&try_.catch, self.trace.error.take()) {
+                    self.context
+                        .insert(catch.error.clone(), Value::String(error));
+                    flow = self.run(&catch.steps);
+                } else if flow == Flow::Stop && self.trace.returned.is_none() {
+                    // Uncaught error: `finally` still runs, then the run ends
+                    if let Some(finally) = &try_.finally {
+                        let error = self.trace.error.take();
+                        self.run(finally);
+                        self.trace.error = self.trace.error.take().or(error);
+                    }
+                    return Ok(Flow::Stop);
+                }
+                if let Some(finally) = &try_.finally {
+                    if self.run(finally) == Flow::Stop {
+                        return Ok(Flow::Stop);
+                    }
+                }
+                Ok(flow)
+            }
+            ChainStep::Dynamic(dyn_) => {
+                let spec = match self.eval(&dyn_.spec)? {
+                    Value::String(s) => s,
+                    other => other.to_string(),
+                };
+                if !dyn_.allowed.is_empty() && !dyn_.allowed.contains(&spec) {
+                    return Err(Error::Other(format!("spec '{}' is not allowed", spec)));
+                }
+                self.call(&dyn_.id, "dynamic", &spec, &dyn_.inputs)?;
+                Ok(Flow::Continue)
+            }
+            ChainStep::Await(await_) => {
+                let value = self.eval(&await_.expr)?;
+                self.assign(&await_.id, "await", &await_.id, value);
+                Ok(Flow::Continue)
+            }
+            ChainStep::Emit(emit) => {
+                let data = self.eval(&emit.data)?;
+                let mut trace = StepTrace::new(&emit.event, "emit");
+                trace.outputs.insert("data".into(), data);
+                self.trace.steps.push(trace);
+                Ok(Flow::Continue)
+            }
+        }
+    }
+
+    /// Evaluate `spec` with mapped inputs and store its outputs under `id`
+    ///
+    /// Spec inputs without a mapping take the context value of the same name.
+    fn call(
+        &mut self,
+        id: &str,
+        kind: &str,
+        spec_id: &str,
+        mappings: &HashMap<String, String>,
+    ) -> Result<Map<String, Value>> {
+        let spec = self
+            .specs
+            .get(spec_id)
+            .ok_or_else(|| Error::Other(format!("spec '{}' not loaded", spec_id)))?;
+
+        let mut inputs = Map::new();
+        for input in &spec.inputs {
+            let value = match mappings.get(&input.name) {
+                Some(expr) => Some(self.eval(expr)?),
+                None => self.context.get(&input.name).cloned(),
+            };
+            if let Some(value) = value {
+                inputs.insert(input.name.clone(), value);
+            }
+        }
+
+        let outputs: Map<String, Value> = match spec.evaluate(&inputs)? {
+            Output::Named(map) => map
+                .into_iter()
+                .map(|(k, v)| Ok((k, serde_json::to_value(v)?)))
+                .collect::<Result<_>>()?,
+            Output::Single(value) => {
+                let name = spec.outputs.first().map_or("result", |o| o.name.as_str());
+                Map::from_iter([(name.to_string(), serde_json::to_value(value)?)])
+            }
+        };
+
+        self.context
+            .insert(id.to_string(), Value::Object(outputs.clone()));
+        let mut trace = StepTrace::new(id, kind);
+        trace.inputs = inputs;
+        trace.outputs = outputs.clone();
+        self.trace.steps.push(trace);
+        Ok(outputs)
+    }
+
+    fn assign(&mut self, id: &str, kind: &str, name: &str, value: Value) {
+        self.context.insert(name.to_string(), value.clone());
+        let mut trace = StepTrace::new(id, kind);
+        trace.outputs.insert(name.to_string(), value);
+        self.trace.steps.push(trace);
+    }
+
+    fn eval(&self, expr: &str) -> Result<Value> {
+        let vars = self
+            .context
+            .iter()
+            .map(|(k, v)| (k.clone(), untyped_json_to_cel(v)))
+            .collect();
+        let result = CelCompiler::eval(expr, &vars)?;
+        let value = from_cel_value(&result)
+            .ok_or_else(|| Error::CelEval(format!("{}: unsupported result {:?}", expr, result)))?;
+        Ok(serde_json::to_value(value)?)
+    }
+
+    fn eval_bool(&self, expr: &str) -> Result<bool> {
+        match self.eval(expr)? {
+            Value::Bool(b) => Ok(b),
+            other => Err(Error::CelEval(format!(
+                "{}: expected bool, got {}",
+                expr, other
+            ))),
+        }
+    }
+}
+
+/// How a step is named in error messages
+fn step_label(step: &ChainStep) -> String {
+    match step {
+        ChainStep::Call(s) => s.id.clone(),
+        ChainStep::Parallel(s) => s.id.clone(),
+        ChainStep::Branch(s) => s.id.clone(),
+        ChainStep::Loop(s) => s.id.clone(),
+        ChainStep::ForEach(s) => s.id.clone(),
+        ChainStep::Gate(s) => s.id.clone(),
+        ChainStep::Return(_) => "return".into(),
+        ChainStep::Compute(s) => s.id.clone(),
+        ChainStep::Set(s) => s.name.clone(),
+        ChainStep::Try(s) => s.id.clone(),
+        ChainStep::Dynamic(s) => s.id.clone(),
+        ChainStep::Await(s) => s.id.clone(),
+        ChainStep::Emit(s) => s.event.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn order_flow() -> (Orchestrator, HashMap<String, Spec>) {
+        let orch = Orchestrator::from_yaml(
+            r#"
+id: order_flow
+inputs:
+  - name: user_id
+    type: string
+  - name: cart_items
+    type: list<float>
+outputs:
+  - name: total
+    type: int
+uses:
+  - validate_cart
+  - calculate_total
+chain:
+  - step: call
+    id: validate
+    spec: validate_cart
+    inputs:
+      items: "cart_items"
+  - step: gate
+    id: check_valid
+    condition: "validate.is_valid"
+    error: "Cart validation failed"
+  - step: call
+    id: totals
+    spec: calculate_total
+    inputs:
+      items: "cart_items"
+    outputs:
+      total: total
+"#,
+        )
+        .unwrap();
+
+        let validate = Spec::from_yaml(
+            r#"
+id: validate_cart
+inputs:
+  - name: items
+    type: list<float>
+outputs:
+  - name: is_valid
+    type: bool
+rules:
+  - id: R1
+    when: "size(items) > 0"
+    then: true
+default: false
+"#,
+        )
+        .unwrap();
+        let total = Spec::from_yaml(
+            r#"
+id: calculate_total
+inputs:
+  - name: items
+    type: list<float>
+outputs:
+  - name: total
+    type: int
+rules:
+  - id: R1
+    when: "size(items) > 0"
+    then: "size(items) * 10"
+default: 0
+"#,
+        )
+        .unwrap();
+
+        let specs = [validate, total]
+            .into_iter()
+            .map(|s| (s.id.clone(), s))
+            .collect();
+        (orch, specs)
+    }
+
+    #[test]
+    fn test_simulate_order_flow() {
+        let (orch, specs) = order_flow();
+        let trace = orch.simulate(&specs, &json!({"user_id": "u1", "cart_items": [12.5, 3.0]}));
+
+        assert_eq!(trace.error, None);
+        let steps: Vec<(&str, &str)> = trace
+            .steps
+            .iter()
+            .map(|s| (s.kind.as_str(), s.id.as_str()))
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                ("call", "validate"),
+                ("gate", "check_valid"),
+                ("call", "totals")
+            ]
+        );
+        assert_eq!(trace.steps[0].outputs["is_valid"], true);
+        assert_eq!(trace.steps[2].inputs["items"], json!([12.5, 3.0]));
+        assert_eq!(trace.outputs["total"], 20);
+    }
+
+    #[test]
+    fn test_simulate_gate_failure_ends_run() {
+        let (orch, specs) = order_flow();
+        let trace = orch.simulate(&specs, &json!({"user_id": "u1", "cart_items": []}));
+
+        assert_eq!(trace.error.as_deref(), Some("Cart validation failed"));
+        assert_eq!(trace.steps.len(), 2);
+        assert_eq!(trace.steps[1].outputs["passed"], false);
+    }
+}
@@ -5,7 +5,13 @@
 
 use crate::ast::*;
 use crate::error::{Error, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
 use tree_sitter::{Node, Parser};
 
 // Re-export language enum
@@ -24,14 +30,20 @@ pub fn parse_rust(source: &str) -> Result<CodeAst> {
 
     let root = tree.root_node();
     let mut functions = Vec::new();
+    let mut types = Vec::new();
 
-    // Walk top-level items looking for functions
+    // Walk top-level items looking for functions, impl methods and structs
     let mut cursor = root.walk();
     for child in root.children(&mut cursor) {
-        if child.kind() == "function_item" {
-            if let Some(func) = parse_rust_function(child, source) {
-                functions.push(func);
+        match child.kind() {
+            "function_item" => {
+                if let Some(func) = parse_rust_function(child, source) {
+                    functions.push(func);
+                }
             }
+            "impl_item" => functions.extend(parse_rust_impl(child, source)),
+            "struct_item" => types.extend(parse_rust_struct(child, source)),
+            _ => {}
         }
     }
 
@@ -43,10 +55,72 @@ pub fn parse_rust(source: &str) -> Result<CodeAst> {
     Ok(CodeAst {
         language: Language::Rust,
         functions,
+        types,
         source_hash,
     })
 }
 
+/// Named fields of a `struct`; tuple and unit structs have none
+fn parse_rust_struct(node: Node, source: &str) -> Option<TypeDef> {
+    let name = node
+        .child_by_field_name("name")?
+        .utf8_text(source.as_bytes())
+        .ok()?
+        .to_string();
+
+    let mut fields = Vec::new();
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut cursor = body.walk();
+        for child in body.children(&mut cursor) {
+            if child.kind() != "field_declaration" {
+                continue;
+            }
+            let name = child
+                .child_by_field_name("name")
+                .and_then(|n| n.utf8_text(source.as_bytes()).ok());
+            let typ = child
+                .child_by_field_name("type")
+                .and_then(|n| n.utf8_text(source.as_bytes()).ok());
+            if let (Some(name), Some(typ)) = (name, typ) {
+                fields.push(Parameter {
+                    name: name.to_string(),
+                    typ: typ.to_string(),
+                });
+            }
+        }
+    }
+
+    Some(TypeDef {
+        name,
+        fields,
+        span: node_span(node),
+    })
+}
+
+/// Methods of an `impl` block, tagged with the implementing type
+///
+/// The `self` receiver is not a `parameter` node, so it never shows up in
+/// the method's params.
+fn parse_rust_impl(node: Node, source: &str) -> Vec<Function> {
+    let owner = node
+        .child_by_field_name("type")
+        .and_then(|t| t.utf8_text(source.as_bytes()).ok())
+        .map(|t| t.split('<').next().unwrap_or(t).trim().to_string());
+    let Some(body) = node.child_by_field_name("body") else {
+        return Vec::new();
+    };
+
+    let mut cursor = body.walk();
+    body.children(&mut cursor)
+        .filter(|child| child.kind() == "function_item")
+        .filter_map(|child| parse_rust_function(child, source))
+        .map(|func| Function {
+            owner: owner.clone(),
+            ..func
+        })
+        .collect()
+}
+
 fn parse_rust_function(node: Node, source: &str) -> Option<Function> {
     let mut name = String::new();
     let mut params = Vec::new();
@@ -101,6 +175,7 @@ fn parse_rust_function(node: Node, source: &str) -> Option<Function> {
             span: node_span(node),
         }),
         span: node_span(node),
+        owner: None,
     })
 }
 
@@ -433,6 +508,7 @@ fn parse_rust_expr(node: Node, source: &str) -> AstNode {
 
         "closure_expression" => parse_rust_closure(node, source),
 
+        _ if node.is_error() => syntax_error(node, source),
         _ => AstNode::Unknown {
             kind: node.kind().to_string(),
             span: node_span(node),
@@ -738,6 +814,12 @@ fn parse_rust_pattern(node: Node, source: &str) -> Pattern {
             Pattern::Constructor { name, fields }
         }
 
+        // Unit enum variant, e.g. `Status::Active`
+        "scoped_identifier" => Pattern::Constructor {
+            name: node.utf8_text(source.as_bytes()).unwrap_or("").to_string(),
+            fields: vec![],
+        },
+
         "rest_pattern" | ".." => Pattern::Rest,
 
         _ => {
@@ -977,6 +1059,14 @@ fn parse_rust_closure(node: Node, source: &str) -> AstNode {
     }
 }
 
+/// Placeholder for an `ERROR` region tree-sitter recovered from
+fn syntax_error(node: Node, source: &str) -> AstNode {
+    AstNode::SyntaxError {
+        text: node.utf8_text(source.as_bytes()).unwrap_or("").to_string(),
+        span: node_span(node),
+    }
+}
+
 fn node_span(node: Node) -> Span {
     Span {
         start_line: node.start_position().row + 1,
@@ -1003,8 +1093,9 @@ pub fn parse_typescript(source: &str) -> Result<CodeAst> {
 
     let root = tree.root_node();
     let mut functions = Vec::new();
+    let mut types = Vec::new();
 
-    // Walk top-level items looking for functions
+    // Walk top-level items looking for functions, classes and interfaces
     let mut cursor = root.walk();
     for child in root.children(&mut cursor) {
         match child.kind() {
@@ -1012,7 +1103,14 @@ pub fn parse_typescript(source: &str) -> Result<CodeAst> {
                 if let Some(func) = parse_ts_function(child, source) {
                     functions.push(func);
                 }
+                functions.extend(parse_ts_class(child, source));
+                types.extend(parse_ts_type(child, source));
             }
+            "class_declaration" | "abstract_class_declaration" => {
+                functions.extend(parse_ts_class(child, source));
+                types.extend(parse_ts_type(child, source));
+            }
+            "interface_declaration" => types.extend(parse_ts_type(child, source)),
             _ => {}
         }
     }
@@ -1024,10 +1122,108 @@ pub fn parse_typescript(source: &str) -> Result<CodeAst> {
     Ok(CodeAst {
         language: Language::TypeScript,
         functions,
+        types,
         source_hash,
     })
 }
 
+/// Fields of an interface or class declaration (possibly exported)
+fn parse_ts_type(node: Node, source: &str) -> Option<TypeDef> {
+    if node.kind() == "export_statement" {
+        let mut cursor = node.walk();
+        let decl = node.children(&mut cursor).find(|child| {
+            matches!(
+                child.kind(),
+                "interface_declaration" | "class_declaration" | "abstract_class_declaration"
+            )
+        });
+        return decl.and_then(|decl| parse_ts_type(decl, source));
+    }
+    if !matches!(
+        node.kind(),
+        "interface_declaration" | "class_declaration" | "abstract_class_declaration"
+    ) {
+        return None;
+    }
+
+    let name = node
+        .child_by_field_name("name")?
+        .utf8_text(source.as_bytes())
+        .ok()?
+        .to_string();
+    let body = node.child_by_field_name("body")?;
+
+    let mut fields = Vec::new();
+    let mut cursor = body.walk();
+    for child in body.children(&mut cursor) {
+        if !matches!(
+            child.kind(),
+            "property_signature" | "public_field_definition"
+        ) {
+            continue;
+        }
+        let name = child
+            .child_by_field_name("name")
+            .and_then(|n| n.utf8_text(source.as_bytes()).ok());
+        let typ = child
+            .child_by_field_name("type")
+            .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+            .map(|t| t.trim_start_matches(':').trim());
+        if let (Some(name), Some(typ)) = (name, typ) {
+            fields.push(Parameter {
+                name: name.to_string(),
+                typ: typ.to_string(),
+            });
+        }
+    }
+
+    Some(TypeDef {
+        name,
+        fields,
+        span: node_span(node),
+    })
+}
+
+/// Methods of a class declaration (possibly exported), tagged with the class
+fn parse_ts_class(node: Node, source: &str) -> Vec<Function> {
+    if node.kind() == "export_statement" {
+        let mut cursor = node.walk();
+        let class = node.children(&mut cursor).find(|child| {
+            matches!(
+                child.kind(),
+                "class_declaration" | "abstract_class_declaration"
+            )
+        });
+        return class
+            .map(|class| parse_ts_class(class, source))
+            .unwrap_or_default();
+    }
+    if !matches!(
+        node.kind(),
+        "class_declaration" | "abstract_class_declaration"
+    ) {
+        return Vec::new();
+    }
+
+    let owner = node
+        .child_by_field_name("name")
+        .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+        .map(str::to_string);
+    let Some(body) = node.child_by_field_name("body") else {
+        return Vec::new();
+    };
+
+    let mut cursor = body.walk();
+    body.children(&mut cursor)
+        .filter(|child| child.kind() == "method_definition")
+        .filter_map(|child| parse_ts_function(child, source))
+        .map(|func| Function {
+            owner: owner.clone(),
+            ..func
+        })
+        .collect()
+}
+
 fn parse_ts_function(node: Node, source: &str) -> Option<Function> {
     // Handle export_statement wrapper
     if node.kind() == "export_statement" {
@@ -1048,7 +1244,7 @@ fn parse_ts_function(node: Node, source: &str) -> Option<Function> {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "identifier" => {
+            "identifier" | "property_identifier" => {
                 name = child.utf8_text(source.as_bytes()).unwrap_or("").to_string();
             }
             "formal_parameters" => {
@@ -1084,6 +1280,7 @@ fn parse_ts_function(node: Node, source: &str) -> Option<Function> {
             span: node_span(node),
         }),
         span: node_span(node),
+        owner: None,
     })
 }
 
@@ -1599,6 +1796,7 @@ fn parse_ts_expr(node: Node, source: &str) -> AstNode {
                 span: node_span(node),
             }
         }
+        _ if node.is_error() => syntax_error(node, source),
         _ => AstNode::Unknown {
             kind: node.kind().to_string(),
             span: node_span(node),
@@ -1703,10 +1901,14 @@ pub fn parse_python(source: &str) -> Result<CodeAst> {
 
     let mut cursor = root.walk();
     for child in root.children(&mut cursor) {
-        if child.kind() == "function_definition" {
-            if let Some(func) = parse_py_function(child, source) {
-                functions.push(func);
+        match child.kind() {
+            "function_definition" => {
+                if let Some(func) = parse_py_function(child, source) {
+                    functions.push(func);
+                }
             }
+            "class_definition" => functions.extend(parse_py_class(child, source)),
+            _ => {}
         }
     }
 
@@ -1717,10 +1919,45 @@ pub fn parse_python(source: &str) -> Result<CodeAst> {
     Ok(CodeAst {
         language: Language::Python,
         functions,
+        types: Vec::new(),
         source_hash,
     })
 }
 
+/// Methods of a class, tagged with the class, without the `self`/`cls`
+/// receiver
+fn parse_py_class(node: Node, source: &str) -> Vec<Function> {
+    let owner = node
+        .child_by_field_name("name")
+        .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+        .map(str::to_string);
+    let Some(body) = node.child_by_field_name("body") else {
+        return Vec::new();
+    };
+
+    let mut cursor = body.walk();
+    body.children(&mut cursor)
+        .filter_map(|child| match child.kind() {
+            "function_definition" => Some(child),
+            "decorated_definition" => child.child_by_field_name("definition"),
+            _ => None,
+        })
+        .filter(|def| def.kind() == "function_definition")
+        .filter_map(|def| parse_py_function(def, source))
+        .map(|mut func| {
+            if func
+                .params
+                .first()
+                .is_some_and(|p| p.name == "self" || p.name == "cls")
+            {
+                func.params.remove(0);
+            }
+            func.owner = owner.clone();
+            func
+        })
+        .collect()
+}
+
 fn parse_py_function(node: Node, source: &str) -> Option<Function> {
     let mut name = String::new();
     let mut params = Vec::new();
@@ -1760,6 +1997,7 @@ fn parse_py_function(node: Node, source: &str) -> Option<Function> {
             span: node_span(node),
         }),
         span: node_span(node),
+        owner: None,
     })
 }
 
@@ -2215,6 +2453,7 @@ fn parse_py_expr(node: Node, source: &str) -> AstNode {
                 span: node_span(node),
             }
         }
+        _ if node.is_error() => syntax_error(node, source),
         _ => AstNode::Unknown {
             kind: node.kind().to_string(),
             span: node_span(node),
@@ -2256,6 +2495,7 @@ pub fn parse_go(source: &str) -> Result<CodeAst> {
     Ok(CodeAst {
         language: Language::Go,
         functions,
+        types: Vec::new(),
         source_hash,
     })
 }
@@ -2299,6 +2539,7 @@ fn parse_go_function(node: Node, source: &str) -> Option<Function> {
             span: node_span(node),
         }),
         span: node_span(node),
+        owner: None,
     })
 }
 
@@ -2673,6 +2914,7 @@ fn parse_go_expr(node: Node, source: &str) -> AstNode {
                 span: node_span(node),
             }
         }
+        _ if node.is_error() => syntax_error(node, source),
         _ => AstNode::Unknown {
             kind: node.kind().to_string(),
             span: node_span(node),
@@ -2698,20 +2940,38 @@ pub fn parse_csharp(source: &str) -> Result<CodeAst> {
     let root = tree.root_node();
     let mut functions = Vec::new();
 
-    // Recursively find method declarations
-    fn find_methods(node: Node, source: &str, functions: &mut Vec<Function>) {
+    // Recursively find method declarations, tagged with the enclosing type
+    fn find_methods<'a>(
+        node: Node,
+        source: &'a str,
+        owner: Option<&'a str>,
+        functions: &mut Vec<Function>,
+    ) {
         if node.kind() == "method_declaration" {
             if let Some(func) = parse_cs_method(node, source) {
-                functions.push(func);
+                functions.push(Function {
+                    owner: owner.map(str::to_string),
+                    ..func
+                });
             }
         }
+        let owner = match node.kind() {
+            "class_declaration"
+            | "interface_declaration"
+            | "record_declaration"
+            | "struct_declaration" => node
+                .child_by_field_name("name")
+                .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+                .or(owner),
+            _ => owner,
+        };
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
-            find_methods(child, source, functions);
+            find_methods(child, source, owner, functions);
         }
     }
 
-    find_methods(root, source, &mut functions);
+    find_methods(root, source, None, &mut functions);
 
     let mut hasher = Sha256::new();
     hasher.update(source.as_bytes());
@@ -2720,6 +2980,7 @@ pub fn parse_csharp(source: &str) -> Result<CodeAst> {
     Ok(CodeAst {
         language: Language::CSharp,
         functions,
+        types: Vec::new(),
         source_hash,
     })
 }
@@ -2777,6 +3038,7 @@ fn parse_cs_method(node: Node, source: &str) -> Option<Function> {
             span: node_span(node),
         }),
         span: node_span(node),
+        owner: None,
     })
 }
 
@@ -3086,6 +3348,7 @@ fn parse_cs_expr(node: Node, source: &str) -> AstNode {
                 }
             }
         }
+        _ if node.is_error() => syntax_error(node, source),
         _ => AstNode::Unknown {
             kind: node.kind().to_string(),
             span: node_span(node),
@@ -3111,20 +3374,38 @@ pub fn parse_java(source: &str) -> Result<CodeAst> {
     let root = tree.root_node();
     let mut functions = Vec::new();
 
-    // Recursively find method declarations
-    fn find_methods(node: Node, source: &str, functions: &mut Vec<Function>) {
+    // Recursively find method declarations, tagged with the enclosing type
+    fn find_methods<'a>(
+        node: Node,
+        source: &'a str,
+        owner: Option<&'a str>,
+        functions: &mut Vec<Function>,
+    ) {
         if node.kind() == "method_declaration" {
             if let Some(func) = parse_java_method(node, source) {
-                functions.push(func);
+                functions.push(Function {
+                    owner: owner.map(str::to_string),
+                    ..func
+                });
             }
         }
+        let owner = match node.kind() {
+            "class_declaration"
+            | "interface_declaration"
+            | "record_declaration"
+            | "enum_declaration" => node
+                .child_by_field_name("name")
+                .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+                .or(owner),
+            _ => owner,
+        };
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
-            find_methods(child, source, functions);
+            find_methods(child, source, owner, functions);
         }
     }
 
-    find_methods(root, source, &mut functions);
+    find_methods(root, source, None, &mut functions);
 
     let mut hasher = Sha256::new();
     hasher.update(source.as_bytes());
@@ -3133,6 +3414,7 @@ pub fn parse_java(source: &str) -> Result<CodeAst> {
     Ok(CodeAst {
         language: Language::Java,
         functions,
+        types: Vec::new(),
         source_hash,
     })
 }
@@ -3180,6 +3462,7 @@ fn parse_java_method(node: Node, source: &str) -> Option<Function> {
             span: node_span(node),
         }),
         span: node_span(node),
+        owner: None,
     })
 }
 
@@ -3479,6 +3762,7 @@ fn parse_java_expr(node: Node, source: &str) -> AstNode {
                 }
             }
         }
+        _ if node.is_error() => syntax_error(node, source),
         _ => AstNode::Unknown {
             kind: node.kind().to_string(),
             span: node_span(node),
@@ -3511,6 +3795,281 @@ pub fn parse_auto(source: &str) -> Result<CodeAst> {
     }
 }
 
+/// Parse using the parser for the file's extension, falling back to
+/// [`parse_auto`] for unknown extensions
+pub fn parse_for_path(path: &Path, source: &str) -> Result<CodeAst> {
+    match language_for_path(path) {
+        Language::Rust => parse_rust(source),
+        Language::TypeScript => parse_typescript(source),
+        Language::Python => parse_python(source),
+        Language::Go => parse_go(source),
+        Language::CSharp => parse_csharp(source),
+        Language::Java => parse_java(source),
+        Language::Unknown => parse_auto(source),
+    }
+}
+
+/// Language implied by a file's extension; `Unknown` means auto-detect
+fn language_for_path(path: &Path) -> Language {
+    match path.extension().and_then(|e| e.to_str()).unwrap_or("") {
+        "rs" => Language::Rust,
+        "ts" | "tsx" | "js" | "jsx" | "mjs" | "cjs" => Language::TypeScript,
+        "py" => Language::Python,
+        "go" => Language::Go,
+        "cs" => Language::CSharp,
+        "java" => Language::Java,
+        _ => Language::Unknown,
+    }
+}
+
+// ============================================================================
+// Parse cache
+// ============================================================================
+
+/// Parsed files, so a run that looks at the same file twice parses it once
+///
+/// Entries are keyed by path, modification time and language: editing a
+/// file changes its mtime, so the next lookup misses and parses it afresh.
+#[derive(Debug, Default)]
+pub struct ParseCache {
+    entries: HashMap<PathBuf, CacheEntry>,
+    hits: usize,
+    misses: usize,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    modified: SystemTime,
+    language: Language,
+    ast: Arc<CodeAst>,
+}
+
+impl ParseCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read and parse `path` as [`parse_for_path`] would, reusing the
+    /// previous result if the file has not changed since
+    pub fn parse(&mut self, path: &Path) -> Result<Arc<CodeAst>> {
+        let modified = std::fs::metadata(path)
+            .and_then(|m| m.modified())
+            .map_err(Error::Io)?;
+        let language = language_for_path(path);
+
+        if let Some(entry) = self.entries.get(path) {
+            if entry.modified == modified && entry.language == language {
+                self.hits += 1;
+                return Ok(Arc::clone(&entry.ast));
+            }
+        }
+
+        self.misses += 1;
+        let source = std::fs::read_to_string(path).map_err(Error::Io)?;
+        let ast = Arc::new(parse_for_path(path, &source)?);
+        self.entries.insert(
+            path.to_path_buf(),
+            CacheEntry {
+                modified,
+                language,
+                ast: Arc::clone(&ast),
+            },
+        );
+        Ok(ast)
+    }
+
+    /// Lookups answered from the cache
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Lookups that had to parse
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+// ============================================================================
+// Diagnostics
+// ============================================================================
+
+/// Problems found while parsing, in source order
+///
+/// Tree-sitter recovers from syntax errors, so broken source still yields
+/// a [`CodeAst`]; these say which parts of it not to trust.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ParseDiagnostics {
+    pub diagnostics: Vec<ParseDiagnostic>,
+}
+
+/// One parse problem and where it is
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ParseDiagnostic {
+    pub kind: DiagnosticKind,
+    /// Human-readable description
+    pub message: String,
+    pub span: Span,
+}
+
+/// What went wrong
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticKind {
+    /// Source the grammar could not parse; the AST has a
+    /// [`AstNode::SyntaxError`] there
+    SyntaxError,
+    /// A token the parser had to assume, such as a missing `;`
+    Missing,
+    /// Valid syntax the AST has no node for ([`AstNode::Unknown`])
+    UnknownNode,
+}
+
+impl ParseDiagnostics {
+    /// Whether the source had syntax errors (unknown nodes don't count)
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|d| d.kind != DiagnosticKind::UnknownNode)
+    }
+}
+
+/// Parse Rust, reporting syntax errors and unsupported constructs with
+/// their locations instead of failing
+pub fn parse_rust_with_diagnostics(source: &str) -> (CodeAst, ParseDiagnostics) {
+    with_diagnostics(
+        source,
+        tree_sitter_rust::LANGUAGE.into(),
+        Language::Rust,
+        parse_rust,
+    )
+}
+
+/// TypeScript counterpart of [`parse_rust_with_diagnostics`]
+pub fn parse_typescript_with_diagnostics(source: &str) -> (CodeAst, ParseDiagnostics) {
+    with_diagnostics(
+        source,
+        tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+        Language::TypeScript,
+        parse_typescript,
+    )
+}
+
+/// Python counterpart of [`parse_rust_with_diagnostics`]
+pub fn parse_python_with_diagnostics(source: &str) -> (CodeAst, ParseDiagnostics) {
+    with_diagnostics(
+        source,
+        tree_sitter_python::LANGUAGE.into(),
+        Language::Python,
+        parse_python,
+    )
+}
+
+/// Go counterpart of [`parse_rust_with_diagnostics`]
+pub fn parse_go_with_diagnostics(source: &str) -> (CodeAst, ParseDiagnostics) {
+    with_diagnostics(
+        source,
+        tree_sitter_go::LANGUAGE.into(),
+        Language::Go,
+        parse_go,
+    )
+}
+
+/// C# counterpart of [`parse_rust_with_diagnostics`]
+pub fn parse_csharp_with_diagnostics(source: &str) -> (CodeAst, ParseDiagnostics) {
+    with_diagnostics(
+        source,
+        tree_sitter_c_sharp::LANGUAGE.into(),
+        Language::CSharp,
+        parse_csharp,
+    )
+}
+
+/// Java counterpart of [`parse_rust_with_diagnostics`]
+pub fn parse_java_with_diagnostics(source: &str) -> (CodeAst, ParseDiagnostics) {
+    with_diagnostics(
+        source,
+        tree_sitter_java::LANGUAGE.into(),
+        Language::Java,
+        parse_java,
+    )
+}
+
+/// Run `parse` for the AST, then walk the raw syntax tree for error and
+/// missing nodes and the AST for nodes it could not represent
+fn with_diagnostics(
+    source: &str,
+    grammar: tree_sitter::Language,
+    language: Language,
+    parse: fn(&str) -> Result<CodeAst>,
+) -> (CodeAst, ParseDiagnostics) {
+    let mut diagnostics = Vec::new();
+
+    let mut parser = Parser::new();
+    let tree = parser
+        .set_language(&grammar)
+        .ok()
+        .and_then(|_| parser.parse(source, None));
+    match &tree {
+        Some(tree) => collect_syntax_errors(tree.root_node(), source, &mut diagnostics),
+        None => diagnostics.push(ParseDiagnostic {
+            kind: DiagnosticKind::SyntaxError,
+            message: "Failed to parse source".into(),
+            span: Span::default(),
+        }),
+    }
+
+    let ast = parse(source).unwrap_or_else(|_| CodeAst {
+        language,
+        functions: Vec::new(),
+        types: Vec::new(),
+        source_hash: String::new(),
+    });
+    for func in &ast.functions {
+        collect_unknown_nodes(&func.body, &mut diagnostics);
+    }
+
+    diagnostics.sort_by_key(|d| (d.span.start_line, d.span.start_col));
+    (ast, ParseDiagnostics { diagnostics })
+}
+
+fn collect_syntax_errors(node: Node, source: &str, out: &mut Vec<ParseDiagnostic>) {
+    if node.is_missing() {
+        out.push(ParseDiagnostic {
+            kind: DiagnosticKind::Missing,
+            message: format!("Missing `{}`", node.kind()),
+            span: node_span(node),
+        });
+    } else if node.is_error() {
+        let text = node.utf8_text(source.as_bytes()).unwrap_or("");
+        out.push(ParseDiagnostic {
+            kind: DiagnosticKind::SyntaxError,
+            message: format!(
+                "Syntax error at `{}`",
+                text.lines().next().unwrap_or("").trim()
+            ),
+            span: node_span(node),
+        });
+    } else if node.has_error() {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            collect_syntax_errors(child, source, out);
+        }
+    }
+}
+
+fn collect_unknown_nodes(node: &AstNode, out: &mut Vec<ParseDiagnostic>) {
+    if let AstNode::Unknown { kind, span } = node {
+        out.push(ParseDiagnostic {
+            kind: DiagnosticKind::UnknownNode,
+            message: format!("Unsupported `{}`", kind),
+            span: *span,
+        });
+    }
+    for child in node.children() {
+        collect_unknown_nodes(child, out);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3590,4 +4149,117 @@ fn check(x: i32) -> bool {
             assert!(matches!(result.as_deref(), Some(AstNode::If { .. })));
         }
     }
+
+    #[test]
+    fn test_parse_impl_methods() {
+        let code = r#"
+struct OrderValidator;
+
+impl OrderValidator {
+    fn validate(&self, amount: i64, vip: bool) -> bool {
+        amount > 0 || vip
+    }
+
+    pub fn tier(&self, amount: i64) -> i32 {
+        if amount > 1000 { 2 } else { 1 }
+    }
+}
+
+fn free(x: bool) -> bool {
+    x
+}
+"#;
+        let ast = parse_rust(code).unwrap();
+        assert_eq!(ast.function_names(), ["validate", "tier", "free"]);
+
+        let validate = ast.get_function("validate").unwrap();
+        let params: Vec<&str> = validate.params.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(params, ["amount", "vip"]);
+        assert_eq!(validate.owner.as_deref(), Some("OrderValidator"));
+        assert_eq!(validate.qualified_name(), "order_validator_validate");
+
+        let tier = ast.get_function("tier").unwrap();
+        assert_eq!(tier.params.len(), 1);
+        assert_eq!(ast.get_function("free").unwrap().owner, None);
+    }
+
+    #[test]
+    fn test_parse_class_methods() {
+        let py = parse_python(
+            "class Pricing:\n    def fee(self, amount: int) -> int:\n        return amount\n",
+        )
+        .unwrap();
+        assert_eq!(py.functions[0].name, "fee");
+        assert_eq!(py.functions[0].params.len(), 1);
+        assert_eq!(py.functions[0].owner.as_deref(), Some("Pricing"));
+
+        let ts = parse_typescript(
+            "export class Pricing {\n    fee(amount: number): number {\n        return amount;\n    }\n}\n",
+        )
+        .unwrap();
+        assert_eq!(ts.functions[0].name, "fee");
+        assert_eq!(ts.functions[0].owner.as_deref(), Some("Pricing"));
+
+        let java = parse_java(
+            "class Pricing {\n    int fee(int amount) {\n        return amount;\n    }\n}\n",
+        )
+        .unwrap();
+        assert_eq!(java.functions[0].qualified_name(), "pricing_fee");
+    }
+
+    #[test]
+    fn test_parse_diagnostics_locate_syntax_errors() {
+        let code = r#"
+fn good(a: bool) -> i32 { if a { 1 } else { 0 } }
+fn broken(x: i32) -> i32 {
+    let y = x + ;
+    y
+}
+fn also_good(b: bool) -> bool { !b }
+"#;
+        let (ast, diags) = parse_rust_with_diagnostics(code);
+        assert!(diags.has_errors());
+        assert!(diags.diagnostics.iter().any(|d| {
+            matches!(
+                d.kind,
+                DiagnosticKind::SyntaxError | DiagnosticKind::Missing
+            ) && d.span.start_line == 4
+        }));
+
+        let names = ast.function_names();
+        assert!(names.contains(&"good"));
+        assert!(names.contains(&"also_good"));
+
+        let (_, clean) = parse_rust_with_diagnostics("fn ok(a: bool) -> bool { a }");
+        assert!(!clean.has_errors());
+    }
+
+    #[test]
+    fn test_parse_cache_hits_unchanged_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("check.rs");
+        std::fs::write(&path, "fn check(a: bool) -> i32 { if a { 1 } else { 0 } }").unwrap();
+
+        let mut cache = ParseCache::new();
+        let first = cache.parse(&path).unwrap();
+        let second = cache.parse(&path).unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(first.language, Language::Rust);
+        assert_eq!(first.source_hash, second.source_hash);
+
+        // A new mtime invalidates the entry
+        std::fs::write(&path, "fn check(a: bool) -> i32 { if a { 2 } else { 0 } }").unwrap();
+        let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+        let later = modified + std::time::Duration::from_secs(5);
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        let third = cache.parse(&path).unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (1, 2));
+        assert_ne!(third.source_hash, first.source_hash);
+    }
 }
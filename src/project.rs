@@ -3,9 +3,13 @@
 //! Finds `imacs/` folders, validates structure, and enforces safeguards
 //! from FMECA analysis.
 
+use crate::cel::Target;
 use crate::config::{ImacRoot, LocalConfig, MergedConfig};
 use crate::error::{Error, Result};
+use crate::orchestrate::{render_orchestrator_with_config, Orchestrator};
+use crate::render::RenderConfig;
 use crate::spec::Spec;
+use rayon::prelude::*;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
@@ -349,7 +353,16 @@ pub fn get_output_dir(
 ) -> PathBuf {
     let base = imacs_dir.parent().unwrap_or(imacs_dir);
 
-    // 1. Check per-language override
+    // 1. Check per-target override
+    if let Some(path) = config
+        .per_target
+        .get(&target)
+        .and_then(|t| t.output_dir.as_ref())
+    {
+        return base.join(path);
+    }
+
+    // 2. Check per-language output override
     let lang_override = match target {
         crate::cel::Target::Rust => &config.output.rust,
         crate::cel::Target::TypeScript => &config.output.typescript,
@@ -357,18 +370,23 @@ pub fn get_output_dir(
         crate::cel::Target::Go => &config.output.go,
         crate::cel::Target::Java => &config.output.java,
         crate::cel::Target::CSharp => &config.output.csharp,
+        crate::cel::Target::Kotlin => &config.output.kotlin,
+        crate::cel::Target::Swift => &config.output.swift,
+        crate::cel::Target::Php => &config.output.php,
+        crate::cel::Target::Ruby => &config.output.ruby,
+        crate::cel::Target::Elixir => &config.output.elixir,
     };
 
     if let Some(path) = lang_override {
         return base.join(path);
     }
 
-    // 2. Check default override
+    // 3. Check default override
     if let Some(default) = &config.output.default {
         return base.join(default);
     }
 
-    // 3. Fallback to convention
+    // 4. Fallback to convention
     base.join("generated")
 }
 
@@ -413,6 +431,124 @@ pub fn list_specs(imacs_dir: &Path) -> Result<Vec<PathBuf>> {
     Ok(specs)
 }
 
+/// Parse every spec of a directory, sorted by id
+///
+/// Orchestrators (files with a `chain:` or `uses:` key) are skipped.
+pub fn load_specs(dir: &Path) -> Result<Vec<Spec>> {
+    let mut specs = Vec::new();
+    for path in list_specs(dir)? {
+        let content = std::fs::read_to_string(&path).map_err(Error::Io)?;
+        if content.contains("\nchain:") || content.contains("\nuses:") {
+            continue;
+        }
+        specs.push(Spec::from_yaml_with_base(&content, &path)?);
+    }
+    specs.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(specs)
+}
+
+/// Generated code and tests for one spec and target, not yet written to disk
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedOutput {
+    pub spec_path: PathBuf,
+    pub spec_id: String,
+    pub target: Target,
+    pub output_dir: PathBuf,
+    pub code_filename: String,
+    pub test_filename: String,
+    pub code: String,
+    pub tests: String,
+}
+
+impl GeneratedOutput {
+    pub fn code_path(&self) -> PathBuf {
+        self.output_dir.join(&self.code_filename)
+    }
+
+    pub fn test_path(&self) -> PathBuf {
+        self.output_dir.join(&self.test_filename)
+    }
+}
+
+/// Render code and tests for every spec and configured target of a folder
+///
+/// Rendering runs on `jobs` threads (0 = one per CPU). Nothing is written;
+/// results are sorted by code path so output does not depend on scheduling.
+pub fn generate_folder(
+    folder: &ImacFolder,
+    spec_paths: &[PathBuf],
+    jobs: usize,
+) -> Result<Vec<GeneratedOutput>> {
+    generate_folder_with(folder, spec_paths, jobs, &RenderConfig::default())
+}
+
+/// `generate_folder` with render options, e.g. a `template_dir` override
+pub fn generate_folder_with(
+    folder: &ImacFolder,
+    spec_paths: &[PathBuf],
+    jobs: usize,
+    config: &RenderConfig,
+) -> Result<Vec<GeneratedOutput>> {
+    let work: Vec<(&PathBuf, Target)> = spec_paths
+        .iter()
+        .flat_map(|path| folder.config.targets.iter().map(move |t| (path, *t)))
+        .collect();
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(|e| Error::Other(format!("Failed to start worker threads: {}", e)))?;
+
+    let mut outputs = pool.install(|| {
+        work.par_iter()
+            .map(|(path, target)| generate_one(folder, path, *target, config))
+            .collect::<Result<Vec<_>>>()
+    })?;
+    outputs.sort_by_key(|output| output.code_path());
+    Ok(outputs)
+}
+
+fn generate_one(
+    folder: &ImacFolder,
+    spec_path: &Path,
+    target: Target,
+    config: &RenderConfig,
+) -> Result<GeneratedOutput> {
+    let spec_content = std::fs::read_to_string(spec_path).map_err(Error::Io)?;
+
+    // Orchestrators have a 'chain:' or 'uses:' key
+    let is_orchestrator = spec_content.contains("\nchain:") || spec_content.contains("\nuses:");
+
+    let (id, code, tests) = if is_orchestrator {
+        let orch = Orchestrator::from_yaml(&spec_content)?;
+        let specs_map = HashMap::new();
+        (
+            orch.id.clone(),
+            render_orchestrator_with_config(&orch, &specs_map, target, config),
+            crate::testgen::orchestrator::generate_orchestrator_tests(&orch, target),
+        )
+    } else {
+        let spec = Spec::from_yaml_with_base(&spec_content, spec_path)?;
+        (
+            spec.id.clone(),
+            crate::render::render_with_config(&spec, target, config),
+            crate::testgen::generate_tests(&spec, target),
+        )
+    };
+    let spec_id = format!("{}{}", folder.config.spec_id_prefix, id);
+
+    Ok(GeneratedOutput {
+        spec_path: spec_path.to_path_buf(),
+        code_filename: folder.config.apply_naming(&spec_id, &target, false),
+        test_filename: folder.config.apply_naming(&spec_id, &target, true),
+        output_dir: get_output_dir(&folder.path, &folder.config, target),
+        spec_id,
+        target,
+        code,
+        tests,
+    })
+}
+
 /// Discover specs directory (imacs/ or .imacs/)
 ///
 /// Checks current directory and parents for imacs/ or .imacs/ folder
@@ -496,6 +632,7 @@ mod tests {
             validation: ValidationConfig::default(),
             spec_id_prefix: "".to_string(),
             output: OutputConfig::default(),
+            per_target: HashMap::new(),
         };
 
         let output_dir = get_output_dir(&imacs_dir, &config, Target::Rust);
@@ -525,6 +662,7 @@ mod tests {
             validation: ValidationConfig::default(),
             spec_id_prefix: "".to_string(),
             output,
+            per_target: HashMap::new(),
         };
 
         let rust_dir = get_output_dir(&imacs_dir, &config, Target::Rust);
@@ -558,10 +696,104 @@ mod tests {
             validation: ValidationConfig::default(),
             spec_id_prefix: "".to_string(),
             output,
+            per_target: HashMap::new(),
         };
 
         let output_dir = get_output_dir(&imacs_dir, &config, Target::Rust);
         let expected = temp.path().join("custom_output");
         assert_eq!(output_dir, expected);
     }
+
+    #[test]
+    fn test_generate_folder_same_with_any_job_count() {
+        use crate::config::{NamingConfig, OutputConfig, ValidationConfig};
+
+        let temp = TempDir::new().unwrap();
+        let imacs_dir = temp.path().join("imacs");
+        fs::create_dir_all(&imacs_dir).unwrap();
+        for i in 0..6 {
+            fs::write(
+                imacs_dir.join(format!("spec_{}.yaml", i)),
+                format!(
+                    "id: spec_{i}\ninputs:\n  - name: n\n    type: int\noutputs:\n  - name: r\n    type: int\nrules:\n  - id: R1\n    when: n > {i}\n    then: {i}\ndefault: 0\n"
+                ),
+            )
+            .unwrap();
+        }
+
+        let folder = ImacFolder {
+            path: imacs_dir.clone(),
+            config: MergedConfig {
+                targets: vec![Target::Rust, Target::TypeScript, Target::Python],
+                auto_format: false,
+                naming: NamingConfig::default(),
+                validation: ValidationConfig::default(),
+                spec_id_prefix: "".to_string(),
+                output: OutputConfig::default(),
+                per_target: HashMap::new(),
+            },
+            is_root: true,
+        };
+        let specs = list_specs(&imacs_dir).unwrap();
+
+        // Timestamps differ between runs; everything else must match
+        let strip = |outputs: Vec<GeneratedOutput>| {
+            outputs
+                .into_iter()
+                .map(|mut o| {
+                    for text in [&mut o.code, &mut o.tests] {
+                        *text = text
+                            .lines()
+                            .filter(|l| !l.contains("GENERATED:"))
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                    }
+                    o
+                })
+                .collect::<Vec<_>>()
+        };
+        let serial = strip(generate_folder(&folder, &specs, 1).unwrap());
+        let parallel = strip(generate_folder(&folder, &specs, 4).unwrap());
+
+        assert_eq!(serial.len(), 18);
+        assert_eq!(serial, parallel);
+    }
+
+    #[test]
+    fn test_per_target_output_dir_from_root_config() {
+        let temp = TempDir::new().unwrap();
+        let imacs_dir = temp.path().join("imacs");
+        fs::create_dir_all(&imacs_dir).unwrap();
+        fs::write(
+            imacs_dir.join(".imacs_root"),
+            "version: 1\nproject:\n  name: test\ndefaults:\n  targets: [rust, typescript]\n  per_target:\n    typescript:\n      output_dir: frontend/src/gen\n",
+        )
+        .unwrap();
+        fs::write(
+            imacs_dir.join("flag.yaml"),
+            "id: flag\ninputs:\n  - name: enabled\n    type: bool\noutputs:\n  - name: r\n    type: int\nrules:\n  - id: R1\n    when: enabled\n    then: 1\ndefault: 0\n",
+        )
+        .unwrap();
+
+        let base = temp.path().canonicalize().unwrap();
+        let structure = load_project_structure(temp.path()).unwrap();
+        let root = structure.root.unwrap();
+        let outputs = generate_folder(&root, &list_specs(&imacs_dir).unwrap(), 1).unwrap();
+
+        let path_for = |target: Target| {
+            outputs
+                .iter()
+                .find(|o| o.target == target)
+                .map(|o| o.code_path())
+                .unwrap()
+        };
+        assert_eq!(
+            path_for(Target::TypeScript),
+            base.join("frontend/src/gen").join("flag.ts")
+        );
+        assert_eq!(
+            path_for(Target::Rust),
+            base.join("generated").join("flag.rs")
+        );
+    }
 }
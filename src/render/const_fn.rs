@@ -0,0 +1,147 @@
+//! Rust `const fn` eligibility
+//!
+//! Embedded and `no_std` users can evaluate a decision at compile time when
+//! the generated function is a `const fn`. That only compiles when every
+//! input and output is a bool or an int, every output is a literal and the
+//! conditions stick to operators usable in const contexts.
+
+use crate::cel::CelCompiler;
+use crate::spec::{ConditionValue, Output, Spec, VarType, Variable};
+use cel_parser::ast::{operators, Expr};
+use cel_parser::reference::Val;
+
+/// Why `spec` cannot be rendered as a Rust `const fn`, or `None` when it can
+pub fn const_fn_blocker(spec: &Spec) -> Option<String> {
+    if spec.outputs.is_empty() {
+        return Some("it declares no outputs".into());
+    }
+    let vars = spec
+        .inputs
+        .iter()
+        .map(|v| ("input", v))
+        .chain(spec.outputs.iter().map(|v| ("output", v)));
+    for (kind, var) in vars {
+        if let Some(reason) = variable_blocker(var) {
+            return Some(format!("{} '{}' {}", kind, var.name, reason));
+        }
+    }
+
+    for rule in &spec.rules {
+        if let Some(value) = non_literal_output(&rule.then) {
+            return Some(format!(
+                "rule {} outputs {}, not an int or bool literal",
+                rule.id, value
+            ));
+        }
+        let Some(cel) = rule.as_cel() else { continue };
+        let ast = match CelCompiler::parse(&cel) {
+            Ok(ast) => ast,
+            Err(e) => return Some(format!("rule {}: {}", rule.id, e)),
+        };
+        if let Some(what) = non_const_expr(&ast) {
+            return Some(format!(
+                "rule {} uses {}, which is not const-evaluable",
+                rule.id, what
+            ));
+        }
+    }
+
+    if let Some(value) = spec.default.as_ref().and_then(non_literal_output) {
+        return Some(format!(
+            "the default is {}, not an int or bool literal",
+            value
+        ));
+    }
+    None
+}
+
+fn variable_blocker(var: &Variable) -> Option<&'static str> {
+    if var.optional {
+        return Some("is optional");
+    }
+    match var.typ {
+        VarType::Bool | VarType::Int => None,
+        VarType::Float => Some("is a float"),
+        VarType::String => Some("is a string"),
+        VarType::Enum(_) => Some("is an enum"),
+        VarType::List(_) => Some("is a list"),
+        VarType::Object(_) => Some("is an object"),
+    }
+}
+
+/// The first value of an output that is not an int or bool literal
+fn non_literal_output(output: &Output) -> Option<&ConditionValue> {
+    let is_literal =
+        |v: &&ConditionValue| matches!(v, ConditionValue::Int(_) | ConditionValue::Bool(_));
+    match output {
+        Output::Single(ConditionValue::Map(map)) | Output::Named(map) => {
+            map.values().find(|v| !is_literal(v))
+        }
+        Output::Single(v) => Some(v).filter(|v| !is_literal(v)),
+    }
+}
+
+/// The first part of a condition that has no const Rust lowering
+fn non_const_expr(expr: &cel_parser::Expression) -> Option<String> {
+    const CONST_OPERATORS: [&str; 16] = [
+        operators::LOGICAL_AND,
+        operators::LOGICAL_OR,
+        operators::LOGICAL_NOT,
+        operators::EQUALS,
+        operators::NOT_EQUALS,
+        operators::LESS,
+        operators::LESS_EQUALS,
+        operators::GREATER,
+        operators::GREATER_EQUALS,
+        operators::ADD,
+        operators::SUBSTRACT,
+        operators::MULTIPLY,
+        operators::DIVIDE,
+        operators::MODULO,
+        operators::NEGATE,
+        operators::CONDITIONAL,
+    ];
+
+    match &expr.expr {
+        Expr::Ident(_) | Expr::Literal(Val::Int(_) | Val::UInt(_) | Val::Boolean(_)) => None,
+        Expr::Call(call)
+            if call.target.is_none()
+                && (CONST_OPERATORS.contains(&call.func_name.as_str())
+                    || crate::cel::bitwise_symbol(&call.func_name).is_some()) =>
+        {
+            call.args.iter().find_map(non_const_expr)
+        }
+        Expr::Call(call) if call.func_name == operators::IN => Some("`in`".into()),
+        Expr::Call(call) => Some(format!("`{}()`", call.func_name)),
+        Expr::Literal(_) => Some("a non-integer literal".into()),
+        _ => Some("a member, list or map expression".into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_membership_blocks_const_fn() {
+        let spec = Spec::from_yaml(
+            r#"
+id: tier_limit
+inputs:
+  - name: level
+    type: int
+outputs:
+  - name: limit
+    type: int
+rules:
+  - id: R1
+    when: "level in [1, 2]"
+    then: 10
+default: 100
+"#,
+        )
+        .unwrap();
+        let reason = const_fn_blocker(&spec).unwrap();
+        assert!(reason.contains("rule R1 uses `in`"), "{}", reason);
+    }
+}
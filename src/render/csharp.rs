@@ -40,7 +40,7 @@ impl<'a> CSharpRenderer<'a> {
             }
         });
 
-        let inner_classes = self.render_classes(spec, &class_name, return_type);
+        let inner_classes = self.render_classes(spec, &class_name, &return_type);
 
         if let Some(ns) = namespace {
             quote! {
@@ -72,12 +72,7 @@ impl<'a> CSharpRenderer<'a> {
         }
     }
 
-    fn render_classes(
-        &self,
-        spec: &Spec,
-        class_name: &str,
-        return_type: &'static str,
-    ) -> csharp::Tokens {
+    fn render_classes(&self, spec: &Spec, class_name: &str, return_type: &str) -> csharp::Tokens {
         quote! {
             public class $(class_name)Input
             {
@@ -115,7 +110,7 @@ impl<'a> CSharpRenderer<'a> {
     fn render_body(&self, spec: &Spec) -> csharp::Tokens {
         let mut tokens = csharp::Tokens::new();
 
-        for (i, rule) in spec.rules.iter().enumerate() {
+        for (i, rule) in spec.rules_in_order().into_iter().enumerate() {
             let condition = rule
                 .as_cel()
                 .map(|cel| {
@@ -164,16 +159,16 @@ impl<'a> CSharpRenderer<'a> {
         tokens
     }
 
-    fn render_type(&self, typ: Option<&VarType>) -> &'static str {
+    fn render_type(&self, typ: Option<&VarType>) -> String {
         match typ {
-            Some(VarType::Bool) => "bool",
-            Some(VarType::Int) => "long",
-            Some(VarType::Float) => "double",
-            Some(VarType::String) => "string",
-            Some(VarType::Object) => "Dictionary<string, object>",
-            Some(VarType::List(_)) => "List<object>",
-            Some(VarType::Enum(_)) => "string",
-            None => "void",
+            Some(VarType::Bool) => "bool".into(),
+            Some(VarType::Int) => "long".into(),
+            Some(VarType::Float) => "double".into(),
+            Some(VarType::String) => "string".into(),
+            Some(VarType::Object(_)) => "Dictionary<string, object>".into(),
+            Some(VarType::List(inner)) => format!("List<{}>", self.render_type(Some(inner))),
+            Some(VarType::Enum(_)) => "string".into(),
+            None => "void".into(),
         }
     }
 
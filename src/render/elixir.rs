@@ -0,0 +1,388 @@
+//! Elixir code generation
+//!
+//! A decision table maps onto a multi-clause function: each rule becomes a
+//! `def evaluate(...)` clause whose guard is the rule's condition, and the
+//! clauses are tried in rule order. When a condition uses something guards
+//! cannot (string functions, regexes, comprehensions, ...) the function is a
+//! single clause with a `cond do` instead.
+
+use crate::cel::{CelCompiler, Target};
+use crate::spec::*;
+use cel_parser::ast::{operators, Expr};
+use cel_parser::reference::Val;
+use chrono::Utc;
+
+use super::{is_expression, to_pascal_case, RenderConfig};
+
+/// Render spec to Elixir code
+pub fn render(spec: &Spec, config: &RenderConfig) -> String {
+    ElixirRenderer { config }.render(spec)
+}
+
+struct ElixirRenderer<'a> {
+    config: &'a RenderConfig,
+}
+
+impl<'a> ElixirRenderer<'a> {
+    fn render(&self, spec: &Spec) -> String {
+        let mut out = String::new();
+        let indent = &self.config.indent;
+
+        if self.config.provenance {
+            out.push_str(&format!("# GENERATED FROM: {}.yaml\n", spec.id));
+            out.push_str(&format!("# SPEC HASH: {}\n", spec.hash()));
+            out.push_str(&format!("# GENERATED: {}\n", Utc::now().to_rfc3339()));
+            out.push_str("# DO NOT EDIT — regenerate from spec\n\n");
+        }
+
+        out.push_str(&format!("defmodule {} do\n", to_pascal_case(&spec.id)));
+
+        let rules: Vec<(&Rule, String, String)> = spec
+            .rules_in_order()
+            .into_iter()
+            .map(|rule| {
+                let condition = rule
+                    .as_cel()
+                    .map(|cel| {
+                        let guarded = guard_optionals(&cel, &spec.inputs);
+                        let code = CelCompiler::compile(&guarded, Target::Elixir)
+                            .unwrap_or_else(|_| "true".into());
+                        enum_atoms(&code, &spec.inputs)
+                    })
+                    .unwrap_or_else(|| "true".into());
+                let output = self.render_output(&rule.then, &spec.outputs);
+                (rule, condition, output)
+            })
+            .collect();
+        let fallback = match &spec.default {
+            Some(default) => self.render_output(default, &spec.outputs),
+            None => "raise \"No rule matched\"".into(),
+        };
+
+        let accumulate = spec.mode == SpecMode::Accumulate;
+        if !accumulate && spec.rules.iter().all(|r| guard_safe(r.as_cel().as_deref())) {
+            for (rule, condition, output) in &rules {
+                if self.config.comments {
+                    out.push_str(&format!("{}# {}\n", indent, rule.id));
+                }
+                let pattern = input_pattern(&spec.inputs, [condition.as_str(), output.as_str()]);
+                let guard = if condition == "true" {
+                    String::new()
+                } else {
+                    format!(" when {}", condition)
+                };
+                out.push_str(&format!(
+                    "{}def evaluate({}){}, do: {}\n",
+                    indent, pattern, guard, output
+                ));
+            }
+            out.push_str(&format!(
+                "{}def evaluate({}), do: {}\n",
+                indent,
+                input_pattern(&spec.inputs, [fallback.as_str()]),
+                fallback
+            ));
+            out.push_str("end\n");
+            return out;
+        }
+
+        let used: Vec<&str> = rules
+            .iter()
+            .flat_map(|(_, condition, output)| [condition.as_str(), output.as_str()])
+            .chain([fallback.as_str()])
+            .collect();
+        out.push_str(&format!(
+            "{}def evaluate({}) do\n",
+            indent,
+            input_pattern(&spec.inputs, used)
+        ));
+        let body = indent.repeat(2);
+        if accumulate {
+            out.push_str(&format!(
+                "{}total = {}\n",
+                body,
+                spec.default
+                    .as_ref()
+                    .map(|d| self.render_output(d, &spec.outputs))
+                    .unwrap_or_else(|| "0".into())
+            ));
+            for (rule, condition, output) in &rules {
+                if self.config.comments {
+                    out.push_str(&format!("{}# {}\n", body, rule.id));
+                }
+                out.push_str(&format!(
+                    "{}total = if {}, do: total + {}, else: total\n",
+                    body, condition, output
+                ));
+            }
+            out.push_str(&format!("{}total\n", body));
+        } else {
+            let arm = indent.repeat(3);
+            let arm_body = indent.repeat(4);
+            out.push_str(&format!("{}cond do\n", body));
+            for (rule, condition, output) in &rules {
+                out.push_str(&format!("{}{} ->\n", arm, condition));
+                if self.config.comments {
+                    out.push_str(&format!("{}# {}\n", arm_body, rule.id));
+                }
+                out.push_str(&format!("{}{}\n\n", arm_body, output));
+            }
+            out.push_str(&format!("{}true ->\n{}{}\n", arm, arm_body, fallback));
+            out.push_str(&format!("{}end\n", body));
+        }
+        out.push_str(&format!("{}end\n", indent));
+        out.push_str("end\n");
+
+        out
+    }
+
+    /// Multi-output specs return a map keyed by output name
+    fn render_output(&self, output: &Output, outputs: &[Variable]) -> String {
+        match output {
+            Output::Single(v) => self.render_value(v),
+            Output::Named(map) => {
+                let fields: Vec<_> = outputs
+                    .iter()
+                    .filter_map(|o| {
+                        map.get(&o.name)
+                            .map(|v| format!("{}: {}", o.name, self.render_value(v)))
+                    })
+                    .collect();
+                format!("%{{{}}}", fields.join(", "))
+            }
+        }
+    }
+
+    fn render_value(&self, v: &ConditionValue) -> String {
+        match v {
+            ConditionValue::Bool(b) => b.to_string(),
+            ConditionValue::Int(i) => i.to_string(),
+            ConditionValue::Float(f) => format!("{:?}", f),
+            ConditionValue::String(s) => {
+                // Check if this is a CEL expression or a literal string
+                if is_expression(s) {
+                    CelCompiler::compile(s, Target::Elixir).unwrap_or_else(|_| elixir_string(s))
+                } else {
+                    elixir_string(s)
+                }
+            }
+            ConditionValue::Null => "nil".into(),
+            _ => "nil".into(),
+        }
+    }
+}
+
+/// Whether a rule's condition can be written as a function guard
+///
+/// Guards allow comparisons, boolean and arithmetic operators, `in` over a
+/// literal list, field access and a handful of Kernel functions; anything
+/// else needs a `cond` in the function body. A rule without a condition
+/// needs no guard.
+pub(crate) fn guard_safe(cel: Option<&str>) -> bool {
+    let Some(cel) = cel else {
+        return true;
+    };
+    CelCompiler::parse(cel).is_ok_and(|ast| guard_expr(&ast))
+}
+
+fn guard_expr(expr: &cel_parser::Expression) -> bool {
+    const GUARD_OPERATORS: [&str; 15] = [
+        operators::LOGICAL_AND,
+        operators::LOGICAL_OR,
+        operators::LOGICAL_NOT,
+        operators::EQUALS,
+        operators::NOT_EQUALS,
+        operators::LESS,
+        operators::LESS_EQUALS,
+        operators::GREATER,
+        operators::GREATER_EQUALS,
+        operators::ADD,
+        operators::SUBSTRACT,
+        operators::MULTIPLY,
+        operators::DIVIDE,
+        operators::MODULO,
+        operators::NEGATE,
+    ];
+    // `length/1`, `is_nil/1`, `trunc/1` and `:erlang.float/1`
+    const GUARD_FUNCTIONS: [&str; 5] = ["size", "has", "int", "double", "float"];
+
+    match &expr.expr {
+        Expr::Ident(_) => true,
+        Expr::Literal(val) => !matches!(val, Val::Bytes(_)),
+        Expr::Select(select) => guard_expr(&select.operand),
+        Expr::List(list) => list.elements.iter().all(guard_expr),
+        Expr::Call(call) if call.target.is_some() => false,
+        Expr::Call(call) if call.func_name == operators::IN => {
+            matches!(call.args.get(1).map(|a| &a.expr), Some(Expr::List(_)))
+                && call.args.iter().all(guard_expr)
+        }
+        Expr::Call(call)
+            if GUARD_OPERATORS.contains(&call.func_name.as_str())
+                || GUARD_FUNCTIONS.contains(&call.func_name.as_str())
+                || crate::cel::bitwise_symbol(&call.func_name).is_some() =>
+        {
+            call.args.iter().all(guard_expr)
+        }
+        _ => false,
+    }
+}
+
+/// Map pattern binding the inputs that `code` refers to, e.g.
+/// `%{tier: tier}`; `_input` when it uses none
+///
+/// Binding only what a clause uses keeps the compiler from warning about
+/// unused variables.
+pub(crate) fn input_pattern<'c>(
+    inputs: &[Variable],
+    code: impl IntoIterator<Item = &'c str>,
+) -> String {
+    let code: Vec<&str> = code.into_iter().collect();
+    let used: Vec<String> = inputs
+        .iter()
+        .filter(|input| code.iter().any(|c| uses_name(c, &input.name)))
+        .map(|input| format!("{}: {}", input.name, input.name))
+        .collect();
+    if used.is_empty() {
+        "_input".into()
+    } else {
+        format!("%{{{}}}", used.join(", "))
+    }
+}
+
+/// Whether `name` appears in `code` as a variable, rather than inside a
+/// string, as an atom (`:name`), a field (`x.name`) or a map key (`name:`)
+fn uses_name(code: &str, name: &str) -> bool {
+    let word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in code.char_indices() {
+        if in_string {
+            in_string = !(c == '"' && !escaped);
+            escaped = c == '\\' && !escaped;
+            continue;
+        }
+        if c == '"' {
+            in_string = true;
+            continue;
+        }
+        if !code[i..].starts_with(name) {
+            continue;
+        }
+        let before = code[..i].chars().next_back();
+        let after = code[i + name.len()..].chars().next();
+        if before.is_some_and(|b| word(b) || b == '.' || b == ':')
+            || after.is_some_and(|a| word(a) || a == ':' || a == '?' || a == '!')
+        {
+            continue;
+        }
+        return true;
+    }
+    false
+}
+
+/// Rewrite string comparisons on `enum` inputs to compare atoms
+///
+/// Works on compiled code, whose comparisons are always parenthesised:
+/// `(tier == "gold")` becomes `(tier == :gold)` and `(tier in ["a", "b"])`
+/// becomes `(tier in [:a, :b])`.
+pub(crate) fn enum_atoms(code: &str, inputs: &[Variable]) -> String {
+    let mut code = code.to_string();
+    for input in inputs {
+        let VarType::Enum(values) = &input.typ else {
+            continue;
+        };
+        for value in values {
+            let literal = elixir_string(value);
+            let atom = atom(value);
+            for op in ["==", "!="] {
+                code = code
+                    .replace(
+                        &format!("({} {} {})", input.name, op, literal),
+                        &format!("({} {} {})", input.name, op, atom),
+                    )
+                    .replace(
+                        &format!("({} {} {})", literal, op, input.name),
+                        &format!("({} {} {})", atom, op, input.name),
+                    );
+            }
+        }
+
+        let membership = format!("({} in [", input.name);
+        let mut rewritten = String::new();
+        let mut rest = code.as_str();
+        while let Some(start) = rest.find(&membership) {
+            let list_start = start + membership.len();
+            let Some(len) = rest[list_start..].find(']') else {
+                break;
+            };
+            let mut list = rest[list_start..list_start + len].to_string();
+            for value in values {
+                list = list.replace(&elixir_string(value), &atom(value));
+            }
+            rewritten.push_str(&rest[..list_start]);
+            rewritten.push_str(&list);
+            rest = &rest[list_start + len..];
+        }
+        rewritten.push_str(rest);
+        code = rewritten;
+    }
+    code
+}
+
+/// Atom literal for an enum value: `:gold`, or `:"gold plus"` when the
+/// value is not a plain identifier
+pub(crate) fn atom(value: &str) -> String {
+    let plain = value
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && value
+            .trim_end_matches(['?', '!'])
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        format!(":{}", value)
+    } else {
+        format!(":{}", elixir_string(value))
+    }
+}
+
+pub(crate) fn elixir_string(s: &str) -> String {
+    format!(
+        "\"{}\"",
+        s.escape_default().to_string().replace("#{", "\\#{")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guard_safety() {
+        assert!(guard_safe(Some(
+            "amount > 100 && tier in ['gold', 'silver']"
+        )));
+        assert!(guard_safe(Some("size(items) == 0 || !has(user.email)")));
+        assert!(guard_safe(None));
+        assert!(!guard_safe(Some("name.startsWith('a')")));
+        assert!(!guard_safe(Some("items.all(i, i > 0)")));
+        assert!(!guard_safe(Some("tier in allowed")));
+    }
+
+    #[test]
+    fn test_enum_atoms() {
+        let inputs = vec![Variable {
+            name: "tier".into(),
+            typ: VarType::Enum(vec!["gold".into(), "gold plus".into()]),
+            description: None,
+            values: None,
+            optional: false,
+            range: None,
+        }];
+        assert_eq!(
+            enum_atoms("((tier == \"gold\") or (tier in [\"gold plus\"]))", &inputs),
+            "((tier == :gold) or (tier in [:\"gold plus\"]))"
+        );
+    }
+}
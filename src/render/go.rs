@@ -95,7 +95,7 @@ impl<'a> GoRenderer<'a> {
     fn render_body(&self, spec: &Spec) -> go::Tokens {
         let mut tokens = go::Tokens::new();
 
-        for (i, rule) in spec.rules.iter().enumerate() {
+        for (i, rule) in spec.rules_in_order().into_iter().enumerate() {
             let condition = rule
                 .as_cel()
                 .map(|cel| {
@@ -105,7 +105,7 @@ impl<'a> GoRenderer<'a> {
                 })
                 .unwrap_or_else(|| "true".into());
 
-            let output = self.render_output(&rule.then);
+            let output = self.render_output(&rule.then, spec);
             let comment = if self.config.comments {
                 Some(format!("// {}", rule.id))
             } else {
@@ -130,7 +130,7 @@ impl<'a> GoRenderer<'a> {
         }
 
         if let Some(default) = &spec.default {
-            let output = self.render_output(default);
+            let output = self.render_output(default, spec);
             tokens.append(quote! {
                 else {
                     return $output
@@ -141,33 +141,43 @@ impl<'a> GoRenderer<'a> {
         tokens
     }
 
-    fn render_type(&self, typ: Option<&VarType>) -> &'static str {
+    fn render_type(&self, typ: Option<&VarType>) -> String {
         match typ {
-            Some(VarType::Bool) => "bool",
-            Some(VarType::Int) => "int64",
-            Some(VarType::Float) => "float64",
-            Some(VarType::String) => "string",
-            Some(VarType::Object) => "interface{}",
-            Some(VarType::List(_)) => "[]interface{}",
-            Some(VarType::Enum(_)) => "string",
-            None => "",
+            Some(VarType::Bool) => "bool".into(),
+            Some(VarType::Int) => "int64".into(),
+            Some(VarType::Float) => "float64".into(),
+            Some(VarType::String) => "string".into(),
+            Some(VarType::Object(_)) => "interface{}".into(),
+            Some(VarType::List(inner)) => format!("[]{}", self.render_type(Some(inner))),
+            Some(VarType::Enum(_)) => "string".into(),
+            None => String::new(),
         }
     }
 
-    fn render_output(&self, output: &Output) -> go::Tokens {
+    fn render_output(&self, output: &Output, spec: &Spec) -> go::Tokens {
         match output {
-            Output::Single(v) => self.render_value(v),
+            Output::Single(v) => {
+                self.render_value(v, &self.render_type(spec.outputs.first().map(|o| &o.typ)))
+            }
             Output::Named(map) => {
                 let fields: Vec<_> = map
                     .iter()
-                    .map(|(k, v)| format!("\"{}\": {}", k, self.render_value_string(v)))
+                    .map(|(k, v)| {
+                        let typ = match spec.outputs.iter().find(|o| &o.name == k) {
+                            Some(o) => self.render_type(Some(&o.typ)),
+                            None => "interface{}".into(),
+                        };
+                        let value = self.render_value_string(v, &typ);
+                        format!("\"{}\": {}", k, value)
+                    })
                     .collect();
                 quote!(map[string]interface{}{$(fields.join(", "))})
             }
         }
     }
 
-    fn render_value(&self, v: &ConditionValue) -> go::Tokens {
+    /// `typ` is the Go type the value must have, for lowering a ternary
+    fn render_value(&self, v: &ConditionValue, typ: &str) -> go::Tokens {
         match v {
             ConditionValue::Bool(b) => quote!($(b.to_string())),
             ConditionValue::Int(i) => quote!($(i.to_string())),
@@ -176,7 +186,7 @@ impl<'a> GoRenderer<'a> {
                 // Check if this is a CEL expression or a literal string
                 if is_expression(s) {
                     // Compile as CEL expression and translate variable names
-                    let compiled = CelCompiler::compile(s, Target::Go)
+                    let compiled = CelCompiler::compile_go_as(s, typ)
                         .map(|c| translate_vars(&c, &self.input_names, VarTranslation::InputPascal))
                         .unwrap_or_else(|_| format!("\"{}\"", s));
                     quote!($compiled)
@@ -189,7 +199,7 @@ impl<'a> GoRenderer<'a> {
         }
     }
 
-    fn render_value_string(&self, v: &ConditionValue) -> String {
+    fn render_value_string(&self, v: &ConditionValue, typ: &str) -> String {
         match v {
             ConditionValue::Bool(b) => b.to_string(),
             ConditionValue::Int(i) => i.to_string(),
@@ -198,7 +208,7 @@ impl<'a> GoRenderer<'a> {
                 // Check if this is a CEL expression or a literal string
                 if is_expression(s) {
                     // Compile as CEL expression and translate variable names
-                    CelCompiler::compile(s, Target::Go)
+                    CelCompiler::compile_go_as(s, typ)
                         .map(|c| translate_vars(&c, &self.input_names, VarTranslation::InputPascal))
                         .unwrap_or_else(|_| format!("\"{}\"", s))
                 } else {
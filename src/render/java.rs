@@ -77,7 +77,7 @@ impl<'a> JavaRenderer<'a> {
     fn render_body(&self, spec: &Spec) -> java::Tokens {
         let mut tokens = java::Tokens::new();
 
-        for (i, rule) in spec.rules.iter().enumerate() {
+        for (i, rule) in spec.rules_in_order().into_iter().enumerate() {
             let condition = rule
                 .as_cel()
                 .map(|cel| {
@@ -123,16 +123,26 @@ impl<'a> JavaRenderer<'a> {
         tokens
     }
 
-    fn render_type(&self, typ: Option<&VarType>) -> &'static str {
+    fn render_type(&self, typ: Option<&VarType>) -> String {
         match typ {
-            Some(VarType::Bool) => "boolean",
-            Some(VarType::Int) => "long",
-            Some(VarType::Float) => "double",
-            Some(VarType::String) => "String",
-            Some(VarType::Object) => "Object",
-            Some(VarType::List(_)) => "List<Object>",
-            Some(VarType::Enum(_)) => "String",
-            None => "void",
+            Some(VarType::Bool) => "boolean".into(),
+            Some(VarType::Int) => "long".into(),
+            Some(VarType::Float) => "double".into(),
+            Some(VarType::String) => "String".into(),
+            Some(VarType::Object(_)) => "Object".into(),
+            Some(VarType::List(inner)) => format!("List<{}>", self.render_boxed_type(inner)),
+            Some(VarType::Enum(_)) => "String".into(),
+            None => "void".into(),
+        }
+    }
+
+    /// Type usable as a generic argument (primitives boxed)
+    fn render_boxed_type(&self, typ: &VarType) -> String {
+        match typ {
+            VarType::Bool => "Boolean".into(),
+            VarType::Int => "Long".into(),
+            VarType::Float => "Double".into(),
+            other => self.render_type(Some(other)),
         }
     }
 
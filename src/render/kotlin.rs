@@ -0,0 +1,194 @@
+//! Kotlin code generation
+//!
+//! genco has no Kotlin backend, so this renderer builds the source directly.
+
+use crate::cel::{CelCompiler, Target};
+use crate::spec::*;
+use chrono::Utc;
+
+use super::scoping::ResolvedNamespace;
+use super::{
+    is_expression, native_enum, native_enum_literals, to_camel_case, to_pascal_case, RenderConfig,
+};
+use super::{translate_vars, VarTranslation};
+
+/// Render spec to Kotlin code
+pub fn render(spec: &Spec, config: &RenderConfig) -> String {
+    let input_names: Vec<String> = spec.inputs.iter().map(|i| i.name.clone()).collect();
+    KotlinRenderer {
+        config,
+        input_names,
+    }
+    .render(spec)
+}
+
+struct KotlinRenderer<'a> {
+    config: &'a RenderConfig,
+    input_names: Vec<String>,
+}
+
+impl<'a> KotlinRenderer<'a> {
+    fn render(&self, spec: &Spec) -> String {
+        let mut out = String::new();
+        let type_name = to_pascal_case(&spec.id);
+        let indent = &self.config.indent;
+
+        if let Some(ResolvedNamespace::Java(pkg)) = &self.config.namespace {
+            out.push_str(&format!("package {}\n\n", pkg.render()));
+        }
+
+        if self.config.provenance {
+            out.push_str(&format!("// GENERATED FROM: {}.yaml\n", spec.id));
+            out.push_str(&format!("// SPEC HASH: {}\n", spec.hash()));
+            out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
+            out.push_str("// DO NOT EDIT — regenerate from spec\n\n");
+        }
+
+        // Enum inputs are sealed classes, so a `when` over one is exhaustive
+        for native in spec
+            .inputs
+            .iter()
+            .filter_map(|i| native_enum(&i.name, &i.typ))
+        {
+            out.push_str(&format!("sealed class {} {{\n", native.name));
+            for v in &native.variants {
+                out.push_str(&format!(
+                    "{}data object {} : {}()\n",
+                    indent, v.ident, native.name
+                ));
+            }
+            out.push_str("}\n\n");
+        }
+
+        out.push_str(&format!("data class {}Input(\n", type_name));
+        for input in &spec.inputs {
+            let typ = match native_enum(&input.name, &input.typ) {
+                Some(native) => native.name,
+                None => self.render_type(Some(&input.typ)),
+            };
+            out.push_str(&format!(
+                "{}val {}: {},\n",
+                indent,
+                to_camel_case(&input.name),
+                typ
+            ));
+        }
+        out.push_str(")\n\n");
+
+        let return_type = self.render_type(spec.outputs.first().map(|v| &v.typ));
+        out.push_str(&format!(
+            "fun {}(input: {}Input): {} {{\n",
+            to_camel_case(&spec.id),
+            type_name,
+            return_type
+        ));
+        out.push_str(&format!("{}return when {{\n", indent));
+        out.push_str(&self.render_branches(spec));
+        out.push_str(&format!("{}}}\n", indent));
+        out.push_str("}\n");
+
+        out
+    }
+
+    fn render_branches(&self, spec: &Spec) -> String {
+        let mut out = String::new();
+        let branch_indent = self.config.indent.repeat(2);
+
+        for rule in spec.rules_in_order() {
+            let condition = rule
+                .as_cel()
+                .map(|cel| {
+                    let compiled =
+                        CelCompiler::compile(&cel, Target::Kotlin).unwrap_or_else(|_| cel.clone());
+                    let translated =
+                        translate_vars(&compiled, &self.input_names, VarTranslation::InputCamel);
+                    native_enum_literals(
+                        &translated,
+                        &spec.inputs,
+                        |n| format!("input.{}", to_camel_case(n)),
+                        |e, v| format!("{}.{}", e.name, v.ident),
+                    )
+                })
+                .unwrap_or_else(|| "true".into());
+
+            if self.config.comments {
+                out.push_str(&format!("{}// {}\n", branch_indent, rule.id));
+            }
+            out.push_str(&format!(
+                "{}{} -> {}\n",
+                branch_indent,
+                condition,
+                self.render_output(&rule.then)
+            ));
+        }
+
+        match &spec.default {
+            Some(default) => out.push_str(&format!(
+                "{}else -> {}\n",
+                branch_indent,
+                self.render_output(default)
+            )),
+            None => out.push_str(&format!(
+                "{}else -> throw IllegalStateException(\"No rule matched\")\n",
+                branch_indent
+            )),
+        }
+
+        out
+    }
+
+    fn render_type(&self, typ: Option<&VarType>) -> String {
+        match typ {
+            Some(VarType::Bool) => "Boolean".into(),
+            Some(VarType::Int) => "Long".into(),
+            Some(VarType::Float) => "Double".into(),
+            Some(VarType::String) => "String".into(),
+            Some(VarType::Object(_)) => "Map<String, Any?>".into(),
+            Some(VarType::List(inner)) => format!("List<{}>", self.render_type(Some(inner))),
+            Some(VarType::Enum(_)) => "String".into(),
+            None => "Unit".into(),
+        }
+    }
+
+    fn render_output(&self, output: &Output) -> String {
+        match output {
+            Output::Single(v) => self.render_value(v),
+            Output::Named(map) => {
+                let fields: Vec<_> = map
+                    .iter()
+                    .map(|(k, v)| format!("\"{}\" to {}", k, self.render_value(v)))
+                    .collect();
+                format!("mapOf({})", fields.join(", "))
+            }
+        }
+    }
+
+    fn render_value(&self, v: &ConditionValue) -> String {
+        match v {
+            ConditionValue::Bool(b) => b.to_string(),
+            ConditionValue::Int(i) => format!("{}L", i),
+            ConditionValue::Float(f) => format!("{:?}", f),
+            ConditionValue::String(s) => {
+                // Check if this is a CEL expression or a literal string
+                if is_expression(s) {
+                    CelCompiler::compile(s, Target::Kotlin)
+                        .map(|c| translate_vars(&c, &self.input_names, VarTranslation::InputCamel))
+                        .unwrap_or_else(|_| kotlin_string(s))
+                } else {
+                    kotlin_string(s)
+                }
+            }
+            ConditionValue::Null => "null".into(),
+            _ => "null".into(),
+        }
+    }
+}
+
+fn kotlin_string(s: &str) -> String {
+    format!(
+        "\"{}\"",
+        s.replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('$', "\\$")
+    )
+}
@@ -8,20 +8,35 @@
 //! - `bool_literal` for boolean literals
 //! - `null_literal` for null/none literals
 
+mod const_fn;
 mod csharp;
+pub(crate) mod elixir;
 mod go;
 mod java;
+mod kotlin;
+mod no_alloc;
+mod openapi;
+mod parens;
+mod php;
+mod proto;
 mod python;
+mod ruby;
 mod rust;
 pub mod scoping;
+mod swift;
 mod typescript;
 
+pub use const_fn::const_fn_blocker;
+pub use no_alloc::no_alloc_blocker;
+pub use openapi::spec_to_openapi;
+pub use parens::minimize_parens;
+pub use proto::spec_to_proto;
 pub use scoping::{
     CSharpNamespace, GoPackage, GoPackageName, JavaPackage, LanguageScopingTyped, NamespaceError,
     PythonModule, ResolvedNamespace, RustModule, RustVisibility, ScopingConfig, TypeScriptModule,
 };
 
-use crate::cel::Target;
+use crate::cel::{CelCompiler, Target};
 use crate::format::format_code;
 use crate::spec::*;
 
@@ -29,18 +44,70 @@ pub use crate::cel::Target as RenderTarget;
 
 /// Render spec to target language using templates
 pub fn render(spec: &Spec, target: Target) -> String {
+    render_with_config(spec, target, &RenderConfig::default())
+}
+
+/// Render spec to target language using templates, with render options
+pub fn render_with_config(spec: &Spec, target: Target, config: &RenderConfig) -> String {
     // Try template-based rendering first
-    match crate::templates::render_spec(spec, target, true) {
+    match crate::templates::render_spec_with(spec, target, config) {
         Ok(code) => code,
         Err(_) => {
             // Fall back to legacy genco renderers if templates fail
-            let code = Renderer::new(target).render(spec);
+            let code = Renderer::with_config(target, config.clone()).render(spec);
             // Apply formatting (silently fall back to unformatted if formatter fails)
             format_code(&code, target).unwrap_or(code)
         }
     }
 }
 
+/// Copy of `spec` with every `when` clause simplified
+///
+/// Conditions that fail to parse are kept as written so the renderer reports
+/// them the usual way.
+pub(crate) fn simplify_rule_conditions(spec: &Spec) -> Spec {
+    let mut simplified = spec.clone();
+    for rule in &mut simplified.rules {
+        if let Some(when) = &rule.when {
+            let cel = when.to_cel();
+            if let Ok(simple) = crate::cel::simplify_cel(&cel) {
+                rule.when = Some(WhenClause::Single(simple));
+            }
+        }
+    }
+    simplified
+}
+
+/// Unified diff from previously generated code to a fresh render of `spec`
+///
+/// The `GENERATED:` and `SPEC HASH:` provenance comments change on every
+/// render, so they are ignored. Returns `None` when the code is up to date.
+pub fn diff_generated(spec: &Spec, target: Target, generated: &str, path: &str) -> Option<String> {
+    let is_provenance = |line: &str| {
+        let comment = line.trim_start_matches(['/', '#']).trim_start();
+        comment.starts_with("GENERATED:") || comment.starts_with("SPEC HASH:")
+    };
+    let strip = |code: &str| -> String {
+        code.lines()
+            .filter(|l| !is_provenance(l))
+            .map(|l| format!("{}\n", l))
+            .collect()
+    };
+
+    let expected = strip(&render(spec, target));
+    let actual = strip(generated);
+    if expected == actual {
+        return None;
+    }
+
+    Some(
+        similar::TextDiff::from_lines(&actual, &expected)
+            .unified_diff()
+            .header(path, &format!("{} (from spec {})", path, spec.id))
+            .to_string(),
+    )
+}
+
 /// Code renderer
 pub struct Renderer {
     target: Target,
@@ -58,6 +125,61 @@ pub struct RenderConfig {
     pub indent: String,
     /// Resolved namespace for the target language
     pub namespace: Option<ResolvedNamespace>,
+    /// Treat the fallback branch as unreachable (Rust: `unreachable!()`)
+    /// when completeness analysis proves every input matches a rule;
+    /// incomplete specs keep their default either way
+    pub assume_complete: bool,
+    /// Wrap each orchestrator step in a tracing span named after the step
+    /// (Rust: `tracing`, TypeScript/Python: OpenTelemetry)
+    pub telemetry: bool,
+    /// Shape of the generated decision function
+    pub style: RenderStyle,
+    /// Simplify each rule's `when` (De Morgan, double negation, constant
+    /// folding) before lowering it to code
+    pub simplify_conditions: bool,
+    /// Override the spec's `on_no_match` (what happens when no rule
+    /// matches); template-rendered targets only
+    pub on_no_match: Option<OnNoMatch>,
+    /// Emit a helper for building the input with zero-valued defaults:
+    /// a builder (Rust), a factory (TypeScript) or dataclass defaults
+    /// (Python)
+    pub builders: bool,
+    /// Flag the cases completeness analysis finds uncovered with a
+    /// `WARNING: spec incomplete` comment above the fallback branch;
+    /// template-rendered targets only
+    pub warn_incomplete: bool,
+    /// Drop parentheses around rule conditions that the target's operator
+    /// precedence makes redundant (`x > 10 && y < 20` rather than
+    /// `((x > 10) && (y < 20))`); template-rendered targets only
+    pub minimal_parens: bool,
+    /// Directory whose `specs/<lang>.jinja` and `orchestrators/<lang>.jinja`
+    /// replace the embedded templates (`--template-dir`)
+    pub template_dir: Option<std::path::PathBuf>,
+    /// Declare the Rust function `pub const fn` so it can run at compile
+    /// time; `templates::render_spec_with` errors when the spec is not
+    /// const-evaluable (see [`const_fn_blocker`]). Rust only
+    pub const_fn: bool,
+    /// Keep the Rust function free of heap allocation and `std`: only
+    /// `Copy` types, with a `Copy` result struct; `templates::render_spec_with`
+    /// errors when the spec needs strings, lists or maps (see
+    /// [`no_alloc_blocker`]). Rust only
+    pub no_alloc: bool,
+    /// Check the spec's `ensures` postconditions on every result: the
+    /// decision function becomes `<id>_unchecked` and a wrapper under the
+    /// original name asserts them (Rust: `debug_assert!`, TypeScript: a
+    /// thrown `Error`, Python: `assert`). Rust, TypeScript and Python only
+    pub check_ensures: bool,
+}
+
+/// How a spec's rules are laid out in generated code
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RenderStyle {
+    /// One if/else (or match) branch per rule
+    #[default]
+    Imperative,
+    /// A table of `(rule id, condition, output)` entries in rule order,
+    /// walked by a single loop (implemented for Rust and Python)
+    DataTable,
 }
 
 impl Default for RenderConfig {
@@ -67,6 +189,18 @@ impl Default for RenderConfig {
             provenance: true,
             indent: "    ".into(),
             namespace: None,
+            assume_complete: false,
+            telemetry: false,
+            style: RenderStyle::Imperative,
+            simplify_conditions: false,
+            on_no_match: None,
+            builders: false,
+            warn_incomplete: true,
+            minimal_parens: true,
+            template_dir: None,
+            const_fn: false,
+            no_alloc: false,
+            check_ensures: false,
         }
     }
 }
@@ -95,6 +229,11 @@ impl Renderer {
             Target::CSharp => csharp::render(spec, &config),
             Target::Java => java::render(spec, &config),
             Target::Go => go::render(spec, &config),
+            Target::Kotlin => kotlin::render(spec, &config),
+            Target::Swift => swift::render(spec, &config),
+            Target::Php => php::render(spec, &config),
+            Target::Ruby => ruby::render(spec, &config),
+            Target::Elixir => elixir::render(spec, &config),
         }
     }
 
@@ -107,10 +246,43 @@ impl Renderer {
             config.namespace = scoping.for_target(self.target);
         }
 
+        config.assume_complete = config.assume_complete && is_proven_complete(spec);
+
         config
     }
 }
 
+/// Whether completeness analysis shows every input matches some rule
+pub(crate) fn is_proven_complete(spec: &Spec) -> bool {
+    crate::completeness::analyze_completeness(spec).is_complete
+}
+
+/// Lines of the comment warning that some inputs match no rule, listing up
+/// to three of the uncovered combinations in CEL; empty for complete specs
+pub(crate) fn incomplete_warning(spec: &Spec) -> Vec<String> {
+    const LISTED: usize = 3;
+
+    let report = crate::completeness::analyze_completeness(spec);
+    if report.is_complete {
+        return Vec::new();
+    }
+    let missing = &report.missing_cases;
+    let mut lines = vec![format!(
+        "WARNING: spec incomplete — {} uncovered input combination(s)",
+        missing.len()
+    )];
+    lines.extend(
+        missing
+            .iter()
+            .take(LISTED)
+            .map(|case| format!("  {}", case.cel_conditions.join(" && "))),
+    );
+    if missing.len() > LISTED {
+        lines.push(format!("  ... and {} more", missing.len() - LISTED));
+    }
+    lines
+}
+
 // Re-export from shared util module
 pub(crate) use crate::util::{to_camel_case, to_pascal_case};
 
@@ -121,8 +293,10 @@ pub(crate) enum VarTranslation {
     CamelCase,
     /// Convert snake_case to input.PascalCase (Go)
     InputPascal,
-    /// Convert snake_case to input.camelCase (Java)
+    /// Convert snake_case to input.camelCase (Java, Kotlin, Swift)
     InputCamel,
+    /// Convert $name to $this->name (PHP)
+    ThisProperty,
 }
 
 /// Translate variable names in an expression according to the target language convention
@@ -144,6 +318,10 @@ pub(crate) fn translate_vars(expr: &str, input_names: &[String], mode: VarTransl
             VarTranslation::InputCamel => {
                 format!("input.{}", to_camel_case(name))
             }
+            VarTranslation::ThisProperty => {
+                result = result.replace(&format!("${}", name), &format!("$this->{}", name));
+                continue;
+            }
         };
         result = result.replace(name.as_str(), &replacement);
     }
@@ -169,7 +347,8 @@ pub(crate) fn is_expression(s: &str) -> bool {
         || s.contains(" || ")
         || s.contains(" ? ");
 
-    if has_operator {
+    // Ternaries written without spaces (`a>1?b:c`) still need to be compiled
+    if has_operator || CelCompiler::is_conditional(s) {
         return true;
     }
 
@@ -185,6 +364,112 @@ pub(crate) fn is_expression(s: &str) -> bool {
     false
 }
 
+/// Native enum generated for an `enum` input
+pub(crate) struct NativeEnum {
+    /// Type name, the input name in PascalCase (`member_tier` -> `MemberTier`)
+    pub name: String,
+    pub variants: Vec<NativeVariant>,
+}
+
+/// One declared value of a [`NativeEnum`]
+pub(crate) struct NativeVariant {
+    /// Value as written in the spec (`gold`)
+    pub value: String,
+    /// PascalCase identifier (`Gold`), for Rust
+    pub ident: String,
+    /// UPPER_SNAKE identifier (`GOLD`), for Python
+    pub ident_upper: String,
+}
+
+impl NativeEnum {
+    /// Variant declared with the given spec value
+    pub fn variant(&self, value: &str) -> Option<&NativeVariant> {
+        self.variants.iter().find(|v| v.value == value)
+    }
+}
+
+/// The native enum for an input, if it is an `enum` whose values all make
+/// distinct identifiers; other enums stay strings
+pub(crate) fn native_enum(name: &str, typ: &VarType) -> Option<NativeEnum> {
+    let VarType::Enum(values) = typ else {
+        return None;
+    };
+    let mut variants: Vec<NativeVariant> = Vec::new();
+    for value in values {
+        let words: Vec<&str> = value
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .collect();
+        // `GOLD` and `gold` both become `Gold`; `goldPlus` keeps its humps
+        let ident: String = words
+            .iter()
+            .map(|w| {
+                if w.chars().any(|c| c.is_ascii_lowercase()) {
+                    to_pascal_case(w)
+                } else {
+                    to_pascal_case(&w.to_lowercase())
+                }
+            })
+            .collect();
+        if !ident.starts_with(|c: char| c.is_ascii_alphabetic())
+            || ident == "Self"
+            || variants.iter().any(|v| v.ident == ident)
+        {
+            return None;
+        }
+        variants.push(NativeVariant {
+            value: value.clone(),
+            ident,
+            ident_upper: words.join("_").to_uppercase(),
+        });
+    }
+    if variants.is_empty() {
+        return None;
+    }
+    Some(NativeEnum {
+        name: to_pascal_case(name),
+        variants,
+    })
+}
+
+/// Rewrite `input == "value"` comparisons on enum inputs so the value is
+/// the input's native variant, rendered by `variant`
+///
+/// Works on compiled code, whose comparisons are always parenthesised and
+/// whose string literals are double-quoted in the targets with native
+/// enums. `subject` spells an input the way the compiled code reads it
+/// (`tier` in Rust, `input.tier` in Kotlin).
+pub(crate) fn native_enum_literals(
+    code: &str,
+    inputs: &[Variable],
+    subject: impl Fn(&str) -> String,
+    variant: impl Fn(&NativeEnum, &NativeVariant) -> String,
+) -> String {
+    let mut code = code.to_string();
+    for input in inputs {
+        let Some(native) = native_enum(&input.name, &input.typ) else {
+            continue;
+        };
+        let subject = subject(&input.name);
+        for v in &native.variants {
+            let literal = format!("\"{}\"", v.value.escape_default());
+            let path = variant(&native, v);
+            for op in ["==", "!="] {
+                code = code
+                    .replace(
+                        &format!("({} {} {})", subject, op, literal),
+                        &format!("({} {} {})", subject, op, path),
+                    )
+                    .replace(
+                        &format!("({} {} {})", literal, op, subject),
+                        &format!("({} {} {})", path, op, subject),
+                    );
+            }
+        }
+    }
+    code
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -238,6 +523,260 @@ rules:
         assert!(code.contains("200"));
     }
 
+    fn gate_spec(rules: &str) -> Spec {
+        Spec::from_yaml(&format!(
+            "id: gate\ninputs:\n  - name: a\n    type: bool\n  - name: b\n    type: bool\noutputs:\n  - name: level\n    type: int\nrules:\n{}default: 0\n",
+            rules
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_assume_complete_uses_unreachable() {
+        let spec = gate_spec(
+            "  - id: R1\n    when: \"a\"\n    then: 1\n  - id: R2\n    when: \"!a\"\n    then: 2\n",
+        );
+        let config = RenderConfig {
+            assume_complete: true,
+            ..Default::default()
+        };
+
+        let code = render_with_config(&spec, Target::Rust, &config);
+        assert!(code.contains("// COMPLETE: every input matches a rule"));
+        assert!(code.contains("unreachable!(\"gate is complete: every input matches a rule\")"));
+
+        let legacy = Renderer::with_config(Target::Rust, config).render(&spec);
+        assert!(legacy.contains("unreachable!(\"gate is complete"));
+
+        // Off by default
+        assert!(!render(&spec, Target::Rust).contains("unreachable!"));
+    }
+
+    #[test]
+    fn test_assume_complete_keeps_default_when_incomplete() {
+        let spec = gate_spec("  - id: R1\n    when: \"a && b\"\n    then: 1\n");
+        let config = RenderConfig {
+            assume_complete: true,
+            ..Default::default()
+        };
+
+        let code = render_with_config(&spec, Target::Rust, &config);
+        assert!(!code.contains("unreachable!"));
+        assert!(!code.contains("// COMPLETE"));
+        assert!(code.contains("} else {\n        0\n    }"));
+
+        let legacy = Renderer::with_config(Target::Rust, config).render(&spec);
+        assert!(!legacy.contains("unreachable!"));
+    }
+
+    #[test]
+    fn test_data_table_style() {
+        let spec = gate_spec(
+            "  - id: R1\n    when: \"a && b\"\n    then: 1\n  - id: R2\n    when: \"a\"\n    then: 2\n",
+        );
+        let config = RenderConfig {
+            style: RenderStyle::DataTable,
+            ..Default::default()
+        };
+
+        let code = render_with_config(&spec, Target::Rust, &config);
+        assert!(code.contains("const GATE_RULES: [GateRule; 2] = ["));
+        let r1 = code.find("(\"R1\", |").expect("R1 entry");
+        let r2 = code.find("(\"R2\", |").expect("R2 entry");
+        assert!(r1 < r2, "rule order preserved");
+        assert!(code.contains("for (_rule_id, when, then) in GATE_RULES"));
+        assert!(
+            syn::parse_file(&code).is_ok(),
+            "generated Rust parses:\n{code}"
+        );
+
+        let py = render_with_config(&spec, Target::Python, &config);
+        assert!(py.contains("GATE_RULES = ["));
+        assert!(py.contains("for _rule_id, when, then in GATE_RULES:"));
+
+        // Imperative stays the default
+        assert!(!render(&spec, Target::Rust).contains("GATE_RULES"));
+    }
+
+    #[test]
+    fn test_accumulate_mode_sums_rules() {
+        let spec = Spec::from_yaml(
+            "id: score\nmode: accumulate\ninputs:\n  - name: a\n    type: bool\n  - name: b\n    type: bool\noutputs:\n  - name: points\n    type: int\nrules:\n  - id: R1\n    when: \"a\"\n    then: 10\n  - id: R2\n    when: \"b\"\n    then: 5\n",
+        )
+        .unwrap();
+
+        let rust = render(&spec, Target::Rust);
+        assert!(rust.contains("let mut total: i64 = Default::default();"));
+        assert!(rust.contains("total += 10;"));
+        assert!(rust.contains("total += 5;"));
+        assert!(syn::parse_file(&rust).is_ok());
+
+        assert!(render(&spec, Target::TypeScript).contains("total += 5;"));
+        assert!(render(&spec, Target::Python).contains("total += 5"));
+    }
+
+    #[test]
+    fn test_diff_generated() {
+        let spec = sample_spec();
+        let fresh = render(&spec, Target::Rust);
+        assert!(diff_generated(&spec, Target::Rust, &fresh, "check_status.rs").is_none());
+
+        // Provenance lines differ on every render and are ignored
+        let restamped = fresh
+            .lines()
+            .map(|l| {
+                if l.starts_with("// GENERATED:") {
+                    "// GENERATED: 1970-01-01T00:00:00Z"
+                } else {
+                    l
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(diff_generated(&spec, Target::Rust, &restamped, "check_status.rs").is_none());
+
+        let edited = fresh.replace("429", "503");
+        let diff = diff_generated(&spec, Target::Rust, &edited, "check_status.rs").unwrap();
+        assert!(
+            diff.starts_with("--- check_status.rs\n+++ check_status.rs (from spec check_status)\n")
+        );
+        assert!(diff
+            .lines()
+            .any(|l| l.starts_with('-') && l.contains("503")));
+        assert!(diff
+            .lines()
+            .any(|l| l.starts_with('+') && l.contains("429")));
+    }
+
+    #[test]
+    fn test_simplify_conditions() {
+        let spec = gate_spec(
+            "  - id: R1\n    when: \"!(!a)\"\n    then: 1\n  - id: R2\n    when: \"!(a || !b)\"\n    then: 2\n  - id: R3\n    when: \"true && b\"\n    then: 3\n",
+        );
+
+        // Simplified conditions agree with the originals on every input
+        let simplified = simplify_rule_conditions(&spec);
+        for (rule, simple) in spec.rules.iter().zip(&simplified.rules) {
+            let original = rule.as_cel().unwrap();
+            let simple = simple.as_cel().unwrap();
+            for bits in 0..4 {
+                let vars = std::collections::HashMap::from([
+                    ("a".to_string(), crate::cel::CelValue::Bool(bits & 1 != 0)),
+                    ("b".to_string(), crate::cel::CelValue::Bool(bits & 2 != 0)),
+                ]);
+                assert_eq!(
+                    CelCompiler::eval_bool(&original, &vars).unwrap(),
+                    CelCompiler::eval_bool(&simple, &vars).unwrap(),
+                    "{} vs {}",
+                    original,
+                    simple
+                );
+            }
+        }
+        assert_eq!(simplified.rules[1].as_cel().unwrap(), "!a && b");
+
+        let config = RenderConfig {
+            simplify_conditions: true,
+            ..Default::default()
+        };
+        let code = render_with_config(&spec, Target::Rust, &config);
+        assert!(code.contains("if a {"));
+        assert!(!code.contains("!(!a)"));
+        assert!(code.contains(&format!("// SPEC HASH: {}", spec.hash())));
+
+        // Off by default
+        assert!(render(&spec, Target::Rust).contains("if !(!a) {"));
+    }
+
+    #[test]
+    fn test_minimal_parens() {
+        let spec = Spec::from_yaml(
+            r#"
+id: in_range
+inputs:
+  - name: x
+    type: int
+  - name: y
+    type: int
+outputs:
+  - name: result
+    type: int
+rules:
+  - id: R1
+    when: "x > 10 && y < 20"
+    then: 1
+default: 0
+"#,
+        )
+        .unwrap();
+
+        let code = render(&spec, Target::Rust);
+        assert!(code.contains("if x > 10 && y < 20 {"), "{}", code);
+        assert!(syn::parse_file(&code).is_ok());
+
+        let config = RenderConfig {
+            minimal_parens: false,
+            ..Default::default()
+        };
+        let verbose = render_with_config(&spec, Target::Rust, &config);
+        assert!(
+            verbose.contains("if ((x > 10) && (y < 20)) {"),
+            "{}",
+            verbose
+        );
+
+        // Both spellings parse to the same decision logic
+        let rules = |code: &str| -> Vec<Option<String>> {
+            let ast = crate::parse::parse_rust(code).unwrap();
+            crate::extract::extract(&ast)
+                .spec
+                .rules
+                .iter()
+                .map(Rule::as_cel)
+                .collect()
+        };
+        assert_eq!(rules(&code), rules(&verbose));
+        assert!(rules(&code).contains(&Some("x > 10 && y < 20".into())));
+    }
+
+    #[test]
+    fn test_on_no_match_tails() {
+        let spec = gate_spec("  - id: R1\n    when: \"a && b\"\n    then: 1\n");
+        let tail = |on_no_match: Option<OnNoMatch>| {
+            let config = RenderConfig {
+                on_no_match,
+                ..Default::default()
+            };
+            let code = render_with_config(&spec, Target::Rust, &config);
+            assert!(
+                syn::parse_file(&code).is_ok(),
+                "generated code should parse:\n{}",
+                code
+            );
+            code
+        };
+
+        for on_no_match in [None, Some(OnNoMatch::ReturnDefault)] {
+            let code = tail(on_no_match);
+            assert!(code.contains("-> i64 {"));
+            assert!(code.contains("} else {\n        0\n    }"));
+        }
+
+        let code = tail(Some(OnNoMatch::Panic));
+        assert!(code.contains("-> i64 {"));
+        assert!(code.contains("} else {\n        unreachable!(\"No rule matched\")\n    }"));
+
+        let code = tail(Some(OnNoMatch::Error));
+        assert!(code.contains("-> Result<i64, &'static str> {"));
+        assert!(code.contains("        Ok(1)\n"));
+        assert!(code.contains("} else {\n        Err(\"No rule matched\")\n    }"));
+
+        // The spec can ask for it too
+        let mut fallible = spec.clone();
+        fallible.on_no_match = Some(OnNoMatch::Error);
+        assert!(render(&fallible, Target::Rust).contains("Err(\"No rule matched\")"));
+    }
+
     #[test]
     fn test_render_typescript() {
         let spec = sample_spec();
@@ -481,6 +1020,699 @@ rules:
         }
     }
 
+    // =========================================================================
+    // Behavioral tests for ternary outputs
+    // =========================================================================
+
+    fn ternary_spec() -> Spec {
+        Spec::from_yaml(
+            r#"
+id: apply_discount
+inputs:
+  - name: amount
+    type: float
+  - name: member
+    type: bool
+outputs:
+  - name: total
+    type: float
+rules:
+  - id: R1
+    when: "member"
+    then: "amount > 100.0 ? amount * 0.9 : amount"
+  - id: R2
+    when: "!member"
+    then: "amount>500.0?amount*0.95:amount"
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn rust_ternary_renders_as_if_expression() {
+        use crate::parse::parse_rust;
+
+        let spec = ternary_spec();
+        let code = render(&spec, Target::Rust);
+
+        assert!(
+            code.contains("(if (amount > 100.0) { (amount * 0.9) } else { amount })"),
+            "Rust should lower ternary to if/else. Got:\n{}",
+            code
+        );
+        assert!(
+            code.contains("(if (amount > 500.0) { (amount * 0.95) } else { amount })"),
+            "Rust should lower ternary written without spaces. Got:\n{}",
+            code
+        );
+        assert!(
+            !code.contains("\"amount"),
+            "Rust should NOT wrap ternary in quotes. Got:\n{}",
+            code
+        );
+        assert!(
+            parse_rust(&code).is_ok(),
+            "Ternary Rust code should parse. Code:\n{}",
+            code
+        );
+    }
+
+    #[test]
+    fn python_ternary_renders_as_conditional_expression() {
+        use crate::parse::parse_python;
+
+        let spec = ternary_spec();
+        let code = render(&spec, Target::Python);
+
+        assert!(
+            code.contains("((amount * 0.9) if (amount > 100.0) else amount)"),
+            "Python should lower ternary to `a if cond else b`. Got:\n{}",
+            code
+        );
+        assert!(
+            !code.contains("\"amount"),
+            "Python should NOT wrap ternary in quotes. Got:\n{}",
+            code
+        );
+        assert!(
+            parse_python(&code).is_ok(),
+            "Ternary Python code should parse. Code:\n{}",
+            code
+        );
+    }
+
+    #[test]
+    fn c_family_ternary_keeps_conditional_operator() {
+        let spec = ternary_spec();
+        for (target, var) in [
+            (Target::TypeScript, "amount"),
+            (Target::CSharp, "amount"),
+            (Target::Java, "input.amount"),
+        ] {
+            let code = render(&spec, target);
+            let expected = format!("(({v} > 100.0) ? ({v} * 0.9) : {v})", v = var);
+            assert!(
+                code.contains(&expected),
+                "{:?} should render `cond ? a : b`. Got:\n{}",
+                target,
+                code
+            );
+            assert!(
+                !code.contains("\"amount"),
+                "{:?} should NOT wrap ternary in quotes. Got:\n{}",
+                target,
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn go_ternary_lowers_to_closure() {
+        use crate::parse::parse_go_with_diagnostics;
+
+        // Go has no conditional operator
+        let spec = ternary_spec();
+        let code = render(&spec, Target::Go);
+
+        assert!(
+            code.contains(
+                "func() float64 { if (input.Amount > 100.0) { return (input.Amount * 0.9) }; \
+                 return input.Amount }()"
+            ),
+            "Go should lower ternary to a closure. Got:\n{}",
+            code
+        );
+        assert!(
+            !code.contains(" ? "),
+            "Go should not use `?:`. Got:\n{}",
+            code
+        );
+        let (_, diagnostics) = parse_go_with_diagnostics(&code);
+        assert!(
+            !diagnostics.has_errors(),
+            "Ternary Go code should parse cleanly. Diagnostics: {:?}\nCode:\n{}",
+            diagnostics,
+            code
+        );
+
+        // Bare variables say nothing about the type; the output does
+        let spec = Spec::from_yaml(
+            r#"
+id: pick_rate
+inputs:
+  - name: vip
+    type: bool
+  - name: gold_rate
+    type: float
+  - name: base_rate
+    type: float
+outputs:
+  - name: rate
+    type: float
+rules:
+  - id: R1
+    when: "true"
+    then: "vip ? gold_rate : base_rate"
+"#,
+        )
+        .unwrap();
+        let code = render(&spec, Target::Go);
+        assert!(
+            code.contains(
+                "func() float64 { if input.Vip { return input.GoldRate }; return input.BaseRate }()"
+            ),
+            "Go should type the closure from the output. Got:\n{}",
+            code
+        );
+    }
+
+    // =========================================================================
+    // Behavioral tests for regex conditions
+    // =========================================================================
+
+    fn regex_spec() -> Spec {
+        Spec::from_yaml(
+            r#"
+id: check_email
+inputs:
+  - name: email
+    type: string
+outputs:
+  - name: valid
+    type: bool
+rules:
+  - id: R1
+    when: "email.matches('.+@.+')"
+    then: true
+  - id: R2
+    when: "!matches(email, '.+@.+') && email.matches('^admin')"
+    then: true
+default: false
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn regex_conditions_use_hoisted_constants() {
+        use crate::parse::{
+            parse_csharp, parse_go, parse_java, parse_python, parse_rust, parse_typescript,
+        };
+
+        let spec = regex_spec();
+        let cases: [(
+            Target,
+            &str,
+            &str,
+            fn(&str) -> crate::Result<crate::ast::CodeAst>,
+        ); 6] = [
+            (
+                Target::Rust,
+                "static RE_0: std::sync::LazyLock<regex::Regex>",
+                "RE_0.is_match(&email)",
+                parse_rust,
+            ),
+            (
+                Target::TypeScript,
+                "const RE_0 = new RegExp(\".+@.+\");",
+                "RE_0.test(email)",
+                parse_typescript,
+            ),
+            (
+                Target::Python,
+                "RE_0 = re.compile(\".+@.+\")",
+                "RE_0.search(email) is not None",
+                parse_python,
+            ),
+            (
+                Target::Go,
+                "var re0 = regexp.MustCompile(\".+@.+\")",
+                "re0.MatchString(input.Email)",
+                parse_go,
+            ),
+            (
+                Target::Java,
+                "private static final java.util.regex.Pattern RE_0",
+                "RE_0.matcher(input.email).find()",
+                parse_java,
+            ),
+            (
+                Target::CSharp,
+                "private static readonly System.Text.RegularExpressions.Regex Re0",
+                "Re0.IsMatch(email)",
+                parse_csharp,
+            ),
+        ];
+
+        // The incompleteness warning quotes the uncovered patterns; keep it
+        // out so the constant count below only sees the generated code
+        let config = RenderConfig {
+            warn_incomplete: false,
+            ..Default::default()
+        };
+        for (target, declaration, call, parse) in cases {
+            let code = render_with_config(&spec, target, &config);
+            assert!(
+                code.contains(declaration),
+                "{:?} should hoist the regex. Got:\n{}",
+                target,
+                code
+            );
+            assert!(
+                code.contains(call),
+                "{:?} should match against the hoisted regex. Got:\n{}",
+                target,
+                code
+            );
+            // Both rules share the first pattern; the second gets its own constant
+            assert_eq!(code.matches(".+@.+").count(), 1, "{:?}:\n{}", target, code);
+            assert!(
+                parse(&code).is_ok(),
+                "{:?} regex code should parse. Code:\n{}",
+                target,
+                code
+            );
+        }
+
+        let kotlin = render(&spec, Target::Kotlin);
+        assert!(kotlin.contains("private val RE_0 = Regex(\".+@.+\")"));
+        assert!(kotlin.contains("RE_0.containsMatchIn(input.email)"));
+    }
+
+    // =========================================================================
+    // Behavioral tests for string method conditions
+    // =========================================================================
+
+    fn string_method_spec() -> Spec {
+        Spec::from_yaml(
+            r#"
+id: classify_status
+inputs:
+  - name: status_line
+    type: string
+outputs:
+  - name: kind
+    type: string
+rules:
+  - id: R1
+    when: "status_line.startsWith('HTTP')"
+    then: "http"
+  - id: R2
+    when: "status_line.endsWith('OK')"
+    then: "ok"
+  - id: R3
+    when: "status_line.contains('error')"
+    then: "error"
+default: "other"
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn string_methods_render_idiomatically() {
+        let spec = string_method_spec();
+        let cases = [
+            (
+                Target::Rust,
+                [
+                    "status_line.starts_with(\"HTTP\")",
+                    "status_line.ends_with(\"OK\")",
+                    "status_line.contains(\"error\")",
+                ],
+            ),
+            (
+                Target::TypeScript,
+                [
+                    "statusLine.startsWith(\"HTTP\")",
+                    "statusLine.endsWith(\"OK\")",
+                    "statusLine.includes(\"error\")",
+                ],
+            ),
+            (
+                Target::Python,
+                [
+                    "status_line.startswith(\"HTTP\")",
+                    "status_line.endswith(\"OK\")",
+                    "(\"error\" in status_line)",
+                ],
+            ),
+            (
+                Target::Go,
+                [
+                    "strings.HasPrefix(input.StatusLine, \"HTTP\")",
+                    "strings.HasSuffix(input.StatusLine, \"OK\")",
+                    "strings.Contains(input.StatusLine, \"error\")",
+                ],
+            ),
+            (
+                Target::Java,
+                [
+                    "input.statusLine.startsWith(\"HTTP\")",
+                    "input.statusLine.endsWith(\"OK\")",
+                    "input.statusLine.contains(\"error\")",
+                ],
+            ),
+            (
+                Target::CSharp,
+                [
+                    "statusLine.StartsWith(\"HTTP\")",
+                    "statusLine.EndsWith(\"OK\")",
+                    "statusLine.Contains(\"error\")",
+                ],
+            ),
+        ];
+
+        for (target, expected) in cases {
+            // Template path and legacy renderer (which goes through translate_vars)
+            for code in [render(&spec, target), Renderer::new(target).render(&spec)] {
+                for snippet in expected {
+                    assert!(
+                        code.contains(snippet),
+                        "{:?} should contain `{}`. Got:\n{}",
+                        target,
+                        snippet,
+                        code
+                    );
+                }
+            }
+        }
+
+        let go = render(&spec, Target::Go);
+        assert!(
+            go.contains("import \"strings\""),
+            "Go needs strings import:\n{}",
+            go
+        );
+    }
+
+    // =========================================================================
+    // Behavioral tests for bitwise conditions
+    // =========================================================================
+
+    #[test]
+    fn incomplete_spec_warns_above_fallback() {
+        // if-without-else: nothing handles the `!a` inputs
+        let spec = Spec::from_yaml(
+            r#"
+id: gate
+inputs:
+  - name: a
+    type: bool
+  - name: b
+    type: bool
+outputs:
+  - name: open
+    type: bool
+rules:
+  - id: R1
+    when: "a && b"
+    then: true
+  - id: R2
+    when: "a && !b"
+    then: false
+"#,
+        )
+        .unwrap();
+
+        let code = render(&spec, Target::Rust);
+        let warning = code
+            .lines()
+            .find_map(|l| l.trim().strip_prefix("// WARNING: spec incomplete — "))
+            .unwrap_or_else(|| panic!("missing warning:\n{}", code));
+        let count: usize = warning.split_whitespace().next().unwrap().parse().unwrap();
+        assert!(count > 0, "{}", warning);
+        assert!(
+            code.contains("//   !a"),
+            "should list a missing case:\n{}",
+            code
+        );
+
+        for target in [Target::Python, Target::Go, Target::Kotlin] {
+            assert!(render(&spec, target).contains("WARNING: spec incomplete"));
+        }
+
+        let quiet = RenderConfig {
+            warn_incomplete: false,
+            ..Default::default()
+        };
+        assert!(!render_with_config(&spec, Target::Rust, &quiet).contains("WARNING"));
+    }
+
+    fn multi_output_spec() -> Spec {
+        Spec::from_yaml(
+            r#"
+id: login_result
+inputs:
+  - name: locked
+    type: bool
+  - name: attempts
+    type: int
+outputs:
+  - name: status
+    type: int
+  - name: message
+    type: string
+rules:
+  - id: R1
+    when: "locked"
+    then:
+      status: 423
+      message: "Account locked"
+  - id: R2
+    when: "attempts > 3"
+    then:
+      status: 429
+      message: "Too many attempts"
+default:
+  status: 200
+  message: "OK"
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn enum_inputs_render_as_native_enums() {
+        let spec = Spec::from_yaml(
+            r#"
+id: discount
+inputs:
+  - name: member_tier
+    type: !enum [gold, silver, bronze]
+outputs:
+  - name: percent
+    type: int
+rules:
+  - id: R1
+    when: "member_tier == 'gold'"
+    then: 20
+  - id: R2
+    when: "member_tier != 'bronze'"
+    then: 10
+default: 0
+"#,
+        )
+        .unwrap();
+
+        let rust = render(&spec, Target::Rust);
+        let flat = rust.split_whitespace().collect::<Vec<_>>().join(" ");
+        assert!(
+            flat.contains("pub enum MemberTier { Gold, Silver, Bronze, }"),
+            "{}",
+            rust
+        );
+        assert!(flat.contains("pub fn discount(member_tier: MemberTier)"));
+        assert!(rust.contains("member_tier == MemberTier::Gold"));
+        assert!(rust.contains("member_tier != MemberTier::Bronze"));
+        assert!(!rust.contains("\"gold\""));
+
+        let ts = render(&spec, Target::TypeScript);
+        assert!(ts.contains("export type MemberTier = \"gold\" | \"silver\" | \"bronze\";"));
+        assert!(ts.contains("memberTier: MemberTier;"));
+
+        let py = render(&spec, Target::Python);
+        assert!(py.contains("from enum import Enum"));
+        assert!(py.contains("class MemberTier(str, Enum):"));
+        assert!(py.contains("    GOLD = \"gold\""));
+        assert!(py.contains("member_tier: MemberTier"));
+        assert!(py.contains("member_tier == MemberTier.GOLD"));
+    }
+
+    #[test]
+    fn rules_render_in_priority_order() {
+        let spec = Spec::from_yaml(
+            r#"
+id: route
+inputs:
+  - name: a
+    type: bool
+  - name: b
+    type: bool
+outputs:
+  - name: lane
+    type: int
+rules:
+  - id: R1
+    when: "a"
+    then: 1
+    priority: 1
+  - id: R2
+    when: "b"
+    then: 2
+default: 0
+"#,
+        )
+        .unwrap();
+
+        // R2 has the lower priority, so it is tried first everywhere
+        for target in [Target::Rust, Target::Python, Target::Go] {
+            for code in [render(&spec, target), Renderer::new(target).render(&spec)] {
+                let r1 = code.find("R1").unwrap();
+                let r2 = code.find("R2").unwrap();
+                assert!(r2 < r1, "{:?} renders R1 first:\n{}", target, code);
+            }
+        }
+        let both = serde_json::json!({"a": true, "b": true});
+        assert_eq!(
+            spec.evaluate(both.as_object().unwrap()).unwrap(),
+            Output::Single(ConditionValue::Int(2))
+        );
+    }
+
+    #[test]
+    fn kotlin_enum_inputs_render_as_sealed_classes() {
+        let spec = Spec::from_yaml(
+            r#"
+id: discount
+inputs:
+  - name: member_tier
+    type: !enum [gold, silver, bronze]
+outputs:
+  - name: percent
+    type: int
+rules:
+  - id: R1
+    when: "member_tier == 'gold'"
+    then: 20
+default: 0
+"#,
+        )
+        .unwrap();
+
+        let template = render(&spec, Target::Kotlin);
+        let legacy = Renderer::new(Target::Kotlin).render(&spec);
+        for code in [&template, &legacy] {
+            assert!(code.contains("sealed class MemberTier {"), "{}", code);
+            assert!(code.contains("    data object Gold : MemberTier()"));
+            assert!(code.contains("val memberTier: MemberTier,"));
+            assert!(code.contains("fun discount(input: DiscountInput): Long"));
+            assert!(code.contains("input.memberTier == MemberTier.Gold"));
+            assert!(!code.contains("\"gold\""));
+        }
+
+        let tests = crate::testgen::generate_tests(&spec, Target::Kotlin);
+        assert!(tests.contains("DiscountInput(memberTier = MemberTier.Gold)"));
+    }
+
+    #[test]
+    fn multiple_outputs_return_result_struct() {
+        let spec = multi_output_spec();
+        let cases = [
+            (
+                Target::Rust,
+                "pub struct LoginResultOutput {",
+                [
+                    "LoginResultOutput { status: 423i64, message: \"Account locked\".to_string() }",
+                    "LoginResultOutput { status: 429i64, message: \"Too many attempts\".to_string() }",
+                    "LoginResultOutput { status: 200i64, message: \"OK\".to_string() }",
+                ],
+            ),
+            (
+                Target::TypeScript,
+                "export interface LoginResultOutput {",
+                [
+                    "return { status: 423, message: \"Account locked\" };",
+                    "return { status: 429, message: \"Too many attempts\" };",
+                    "return { status: 200, message: \"OK\" };",
+                ],
+            ),
+            (
+                Target::Python,
+                "class LoginResultOutput:",
+                [
+                    "return LoginResultOutput(status=423, message=\"Account locked\")",
+                    "return LoginResultOutput(status=429, message=\"Too many attempts\")",
+                    "return LoginResultOutput(status=200, message=\"OK\")",
+                ],
+            ),
+        ];
+
+        for (target, declaration, returns) in cases {
+            let code = render(&spec, target);
+            assert!(code.contains(declaration), "{:?}:\n{}", target, code);
+            for expected in returns {
+                assert!(
+                    code.contains(expected),
+                    "{:?} should return `{}`. Got:\n{}",
+                    target,
+                    expected,
+                    code
+                );
+            }
+        }
+
+        let code = render(&spec, Target::Rust);
+        assert!(code.contains("-> LoginResultOutput {"), "{}", code);
+        assert!(crate::parse::parse_rust(&code).is_ok());
+    }
+
+    fn bitwise_spec() -> Spec {
+        Spec::from_yaml(
+            r#"
+id: can_edit
+allow_bitwise: true
+inputs:
+  - name: flags
+    type: int
+outputs:
+  - name: allowed
+    type: bool
+rules:
+  - id: R1
+    when: "flags & 4 != 0"
+    then: true
+default: false
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn bitwise_conditions_render_native_operators() {
+        let spec = bitwise_spec();
+        let cases = [
+            (Target::Rust, "if flags & 4 != 0 {"),
+            (Target::TypeScript, "((flags & 4) !== 0)"),
+            (Target::Python, "if flags & 4 != 0:"),
+            (Target::Go, "if input.Flags & 4 != 0 {"),
+            (Target::Java, "((input.flags & 4) != 0)"),
+            (Target::CSharp, "((flags & 4) != 0)"),
+            (Target::Kotlin, "((input.flags and 4) != 0)"),
+        ];
+
+        for (target, expected) in cases {
+            let code = render(&spec, target);
+            assert!(
+                code.contains(expected),
+                "{:?} should contain `{}`. Got:\n{}",
+                target,
+                expected,
+                code
+            );
+        }
+    }
+
     // =========================================================================
     // Behavioral tests for literal vs computed outputs
     // =========================================================================
@@ -723,6 +1955,92 @@ rules:
         assert!(!ast.functions.is_empty(), "Should have at least one method");
     }
 
+    #[test]
+    fn kotlin_roundtrip_has_function_and_input() {
+        let spec = computed_spec();
+        let code = render(&spec, Target::Kotlin);
+
+        assert!(
+            code.contains("fun shippingRate(input: ShippingRateInput): Double"),
+            "Kotlin should render a top-level function. Code:\n{}",
+            code
+        );
+        assert!(
+            code.contains("data class ShippingRateInput("),
+            "Kotlin should render an input data class. Code:\n{}",
+            code
+        );
+        assert!(
+            code.contains("when {"),
+            "Kotlin should use when-style control flow. Code:\n{}",
+            code
+        );
+        assert!(
+            code.contains("input.weightKg * 5.0") && code.contains("+ 7.0"),
+            "Kotlin should use camelCase property access. Code:\n{}",
+            code
+        );
+    }
+
+    #[test]
+    fn kotlin_legacy_renderer_matches_template_shape() {
+        let spec = computed_spec();
+        let code = Renderer::new(Target::Kotlin).render(&spec);
+
+        assert!(code.contains("fun shippingRate(input: ShippingRateInput): Double"));
+        assert!(code.contains("data class ShippingRateInput("));
+        assert!(code.contains("return when {"));
+        assert!(code.contains("input.zone == \"domestic\""));
+        assert!(code.contains("else -> throw IllegalStateException"));
+    }
+
+    #[test]
+    fn swift_renders_function_and_input_struct() {
+        let spec = Spec::from_yaml(
+            r#"
+id: check_status
+inputs:
+  - name: error_count
+    type: int
+  - name: retry_after
+    type: int?
+outputs:
+  - name: status
+    type: string
+rules:
+  - id: R1
+    when: "error_count > 10"
+    then: "down"
+  - id: R2
+    when: "retry_after > 0"
+    then: "degraded"
+default: "up"
+"#,
+        )
+        .unwrap();
+
+        let code = render(&spec, Target::Swift);
+        assert!(
+            code.contains("func checkStatus(_ input: CheckStatusInput) -> String {"),
+            "Swift should render a top-level function. Code:\n{}",
+            code
+        );
+        assert!(
+            code.contains("struct CheckStatusInput {"),
+            "Swift should render an input struct. Code:\n{}",
+            code
+        );
+        assert!(code.contains("let errorCount: Int64"));
+        assert!(code.contains("let retryAfter: Int64?"));
+        assert!(code.contains("if input.errorCount > 10 {"));
+        assert!(code.contains("input.retryAfter != nil && input.retryAfter! > 0"));
+        assert!(code.contains("return \"up\""));
+
+        let legacy = Renderer::new(Target::Swift).render(&spec);
+        assert!(legacy.contains("func checkStatus(_ input: CheckStatusInput) -> String {"));
+        assert!(legacy.contains("struct CheckStatusInput {"));
+    }
+
     // =========================================================================
     // Verify AST structure for all languages
     // =========================================================================
@@ -782,4 +2100,57 @@ rules:
             );
         }
     }
+
+    #[test]
+    fn list_inputs_render_typed_collections() {
+        let spec = Spec::from_yaml(
+            r#"
+id: cart_flagged
+inputs:
+  - name: item_flags
+    type: list<string>
+outputs:
+  - name: flagged
+    type: bool
+rules:
+  - id: R1
+    when: "item_flags.exists(f, f == 'fraud')"
+    then: true
+default: false
+"#,
+        )
+        .unwrap();
+
+        let cases = [
+            (Target::Rust, "Vec<String>"),
+            (Target::TypeScript, "string[]"),
+            (Target::Python, "list[str]"),
+            (Target::Go, "[]string"),
+            (Target::Java, "List<String>"),
+            (Target::CSharp, "List<string>"),
+            (Target::Kotlin, "List<String>"),
+            (Target::Swift, "[String]"),
+            (Target::Php, "array $item_flags"),
+        ];
+        for (target, expected) in cases {
+            for code in [render(&spec, target), Renderer::new(target).render(&spec)] {
+                assert!(
+                    code.contains(expected),
+                    "{:?} should type the list input as {}. Code:\n{}",
+                    target,
+                    expected,
+                    code
+                );
+                assert!(
+                    !code.contains("unsupported"),
+                    "{:?} should lower exists(). Code:\n{}",
+                    target,
+                    code
+                );
+            }
+        }
+
+        let rust = render(&spec, Target::Rust);
+        assert!(rust.contains(".iter().any(|f|"), "Code:\n{}", rust);
+    }
 }
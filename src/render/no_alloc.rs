@@ -0,0 +1,176 @@
+//! Rust `no_alloc` eligibility
+//!
+//! Edge and WASM deployments want a decision function that needs neither
+//! `std` nor a heap. That holds when every input and output is a `Copy`
+//! type (bool, int, float or a native enum), outputs are values or
+//! arithmetic on inputs, and conditions only use operators that lower to
+//! `core`.
+
+use crate::cel::CelCompiler;
+use crate::render::native_enum;
+use crate::spec::{ConditionValue, Output, Spec, VarType, Variable};
+use cel_parser::ast::{operators, Expr};
+
+/// Why `spec` cannot be rendered as an allocation-free Rust function, or
+/// `None` when it can
+pub fn no_alloc_blocker(spec: &Spec) -> Option<String> {
+    if spec.outputs.is_empty() {
+        return Some("it declares no outputs, so rules return a HashMap".into());
+    }
+    let vars = spec
+        .inputs
+        .iter()
+        .map(|v| ("input", v))
+        .chain(spec.outputs.iter().map(|v| ("output", v)));
+    for (kind, var) in vars {
+        if let Some(reason) = variable_blocker(var) {
+            return Some(format!("{} '{}' {}", kind, var.name, reason));
+        }
+    }
+
+    for rule in &spec.rules {
+        if let Some(what) = allocating_output(spec, &rule.then) {
+            return Some(format!("rule {} outputs {}", rule.id, what));
+        }
+        let Some(cel) = rule.as_cel() else { continue };
+        let ast = match CelCompiler::parse(&cel) {
+            Ok(ast) => ast,
+            Err(e) => return Some(format!("rule {}: {}", rule.id, e)),
+        };
+        if let Some(what) = allocating_expr(&ast) {
+            return Some(format!("rule {} uses {}", rule.id, what));
+        }
+    }
+
+    if let Some(what) = spec
+        .default
+        .as_ref()
+        .and_then(|d| allocating_output(spec, d))
+    {
+        return Some(format!("the default is {}", what));
+    }
+    None
+}
+
+fn variable_blocker(var: &Variable) -> Option<&'static str> {
+    match &var.typ {
+        VarType::Bool | VarType::Int | VarType::Float => None,
+        VarType::Enum(_) if native_enum(&var.name, &var.typ).is_some() => None,
+        VarType::Enum(_) => Some("is an enum whose values are not identifiers (a String)"),
+        VarType::String => Some("is a string"),
+        VarType::List(_) => Some("is a list"),
+        VarType::Object(_) => Some("is an object"),
+    }
+}
+
+/// What makes an output value allocate, if anything
+fn allocating_output(spec: &Spec, output: &Output) -> Option<String> {
+    let values: Vec<(&str, &ConditionValue)> = match output {
+        Output::Single(ConditionValue::Map(map)) | Output::Named(map) => {
+            map.iter().map(|(k, v)| (k.as_str(), v)).collect()
+        }
+        Output::Single(v) => vec![(spec.outputs[0].name.as_str(), v)],
+    };
+    for (name, value) in values {
+        match value {
+            ConditionValue::Bool(_)
+            | ConditionValue::Int(_)
+            | ConditionValue::Float(_)
+            | ConditionValue::Null => {}
+            ConditionValue::String(s) => {
+                // Enum outputs name a variant
+                let is_enum = spec
+                    .outputs
+                    .iter()
+                    .any(|o| o.name == name && native_enum(&o.name, &o.typ).is_some());
+                if is_enum {
+                    continue;
+                }
+                if !crate::templates::context::is_expression(s) {
+                    return Some(format!("the string '{}'", s));
+                }
+                match CelCompiler::parse(s) {
+                    Ok(ast) => {
+                        if let Some(what) = allocating_expr(&ast) {
+                            return Some(format!("'{}', which uses {}", s, what));
+                        }
+                    }
+                    Err(_) => return Some(format!("the string '{}'", s)),
+                }
+            }
+            ConditionValue::List(_) => return Some("a list".into()),
+            ConditionValue::Map(_) => return Some("a map".into()),
+        }
+    }
+    None
+}
+
+/// The first part of an expression whose Rust lowering allocates or needs `std`
+fn allocating_expr(expr: &cel_parser::Expression) -> Option<String> {
+    const CORE_OPERATORS: [&str; 17] = [
+        operators::LOGICAL_AND,
+        operators::LOGICAL_OR,
+        operators::LOGICAL_NOT,
+        operators::EQUALS,
+        operators::NOT_EQUALS,
+        operators::LESS,
+        operators::LESS_EQUALS,
+        operators::GREATER,
+        operators::GREATER_EQUALS,
+        operators::ADD,
+        operators::SUBSTRACT,
+        operators::MULTIPLY,
+        operators::DIVIDE,
+        operators::MODULO,
+        operators::NEGATE,
+        operators::CONDITIONAL,
+        operators::IN,
+    ];
+    // Numeric conversions lower to `as` casts
+    const CASTS: [&str; 4] = ["int", "uint", "double", "float"];
+
+    match &expr.expr {
+        // String literals only reach here compared with native enum inputs
+        Expr::Ident(_) | Expr::Literal(_) => None,
+        Expr::List(list) => list.elements.iter().find_map(allocating_expr),
+        Expr::Call(call)
+            if call.target.is_none()
+                && (CORE_OPERATORS.contains(&call.func_name.as_str())
+                    || CASTS.contains(&call.func_name.as_str())
+                    || crate::cel::bitwise_symbol(&call.func_name).is_some()) =>
+        {
+            call.args.iter().find_map(allocating_expr)
+        }
+        Expr::Call(call) => Some(format!("`{}()`", call.func_name)),
+        Expr::Map(_) => Some("a map literal".into()),
+        _ => Some("a member or comprehension expression".into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_function_blocks_no_alloc() {
+        let spec = Spec::from_yaml(
+            r#"
+id: label
+inputs:
+  - name: level
+    type: int
+outputs:
+  - name: code
+    type: int
+rules:
+  - id: R1
+    when: "size(string(level)) > 2"
+    then: 1
+default: 0
+"#,
+        )
+        .unwrap();
+        let reason = no_alloc_blocker(&spec).unwrap();
+        assert!(reason.contains("rule R1 uses `size()`"), "{}", reason);
+    }
+}
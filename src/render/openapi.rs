@@ -0,0 +1,171 @@
+//! OpenAPI generation
+//!
+//! Describes a spec as an HTTP operation: the request body carries the
+//! inputs, the 200 response carries the outputs, and every rule contributes
+//! a worked example.
+
+use crate::spec::*;
+use crate::testgen::extract_test_values;
+use serde_json::{json, Map, Value};
+
+/// Render a spec as an OpenAPI 3.1 document with a single `POST` operation at `path`
+///
+/// Request and response schemas come from [`Spec::input_json_schema`] and
+/// [`Spec::output_json_schema`]. Each rule adds a request example whose
+/// inputs satisfy its `when`, with the matching response example.
+pub fn spec_to_openapi(spec: &Spec, path: &str) -> serde_norway::Value {
+    let mut request_examples = Map::new();
+    let mut response_examples = Map::new();
+
+    for rule in &spec.rules {
+        let inputs = rule_example(spec, rule);
+        let summary = match rule.as_cel() {
+            Some(cel) => format!("{}: {}", rule.id, cel),
+            None => rule.id.clone(),
+        };
+
+        let output = spec.evaluate(&inputs).unwrap_or_else(|_| rule.then.clone());
+        request_examples.insert(
+            rule.id.clone(),
+            json!({"summary": summary, "value": inputs}),
+        );
+        response_examples.insert(
+            rule.id.clone(),
+            json!({"summary": summary, "value": output_example(spec, &output)}),
+        );
+    }
+
+    let mut operation = json!({
+        "operationId": spec.id,
+        "summary": spec.name.clone().unwrap_or_else(|| spec.id.clone()),
+        "requestBody": {
+            "required": true,
+            "content": {
+                "application/json": {
+                    "schema": operation_schema(spec.input_json_schema()),
+                    "examples": request_examples,
+                }
+            }
+        },
+        "responses": {
+            "200": {
+                "description": "Decision result",
+                "content": {
+                    "application/json": {
+                        "schema": operation_schema(spec.output_json_schema()),
+                        "examples": response_examples,
+                    }
+                }
+            }
+        }
+    });
+    if let Some(description) = &spec.description {
+        operation["description"] = json!(description);
+    }
+
+    let document = json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": spec.name.clone().unwrap_or_else(|| spec.id.clone()),
+            "version": spec.meta.version.clone().unwrap_or_else(|| "0.0.0".to_string()),
+        },
+        "paths": {
+            path: {"post": operation}
+        }
+    });
+
+    serde_norway::to_value(&document).unwrap_or(serde_norway::Value::Null)
+}
+
+/// Request body for a rule, built from the values test generation picks for it
+fn rule_example(spec: &Spec, rule: &Rule) -> Map<String, Value> {
+    let values = extract_test_values(rule, &spec.inputs);
+    spec.inputs
+        .iter()
+        .map(|input| {
+            let literal = values
+                .get(&input.name)
+                .map(String::as_str)
+                .unwrap_or("null");
+            let value = serde_json::from_str(literal)
+                .unwrap_or_else(|_| Value::String(literal.to_string()));
+            (input.name.clone(), coerce(value, &input.typ))
+        })
+        .collect()
+}
+
+/// Make a test literal fit the declared type (e.g. `5` for a float input becomes `5.0`)
+fn coerce(value: Value, typ: &VarType) -> Value {
+    match (typ, &value) {
+        (VarType::Float, Value::Number(n)) => n.as_f64().map(|f| json!(f)).unwrap_or(value),
+        (VarType::List(_), Value::Null) => json!([]),
+        (VarType::Object(_), Value::Null) => json!({}),
+        _ => value,
+    }
+}
+
+/// Response body for an evaluated output, keyed like the output schema
+fn output_example(spec: &Spec, output: &Output) -> Value {
+    match output {
+        Output::Named(map) => json!(map),
+        Output::Single(value) => match spec.outputs.first() {
+            Some(var) => json!({ var.name.clone(): value }),
+            None => json!(value),
+        },
+    }
+}
+
+/// Schemas embedded in an operation drop the standalone `$schema` marker
+fn operation_schema(mut schema: Value) -> Value {
+    if let Some(object) = schema.as_object_mut() {
+        object.remove("$schema");
+    }
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_example_per_rule() {
+        let spec = Spec::from_yaml(
+            r#"
+id: shipping_rate
+inputs:
+  - name: weight
+    type: float
+  - name: zone
+    type: !enum [domestic, intl]
+outputs:
+  - name: rate
+    type: float
+rules:
+  - id: R1
+    when: 'zone == "intl"'
+    then: 25.0
+  - id: R2
+    when: 'zone == "domestic" && weight > 10.0'
+    then: 12.5
+  - id: R3
+    when: 'zone == "domestic" && weight <= 10.0'
+    then: 5.0
+"#,
+        )
+        .unwrap();
+
+        let doc = spec_to_openapi(&spec, "/shipping-rate");
+        let doc: Value = serde_json::to_value(&doc).unwrap();
+        let operation = &doc["paths"]["/shipping-rate"]["post"];
+        assert_eq!(doc["openapi"], "3.1.0");
+
+        let body = &operation["requestBody"]["content"]["application/json"];
+        let examples = body["examples"].as_object().unwrap();
+        assert_eq!(examples.len(), spec.rules.len());
+        assert_eq!(examples["R1"]["value"]["zone"], "intl");
+        assert_eq!(body["schema"]["properties"]["zone"]["enum"][1], "intl");
+
+        let response = &operation["responses"]["200"]["content"]["application/json"];
+        assert_eq!(response["examples"]["R1"]["value"]["rate"], 25.0);
+    }
+}
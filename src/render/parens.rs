@@ -0,0 +1,627 @@
+//! Redundant parenthesis removal for rendered conditions
+//!
+//! CEL lowering parenthesizes every operator (`((x > 10) && (y < 20))`),
+//! which is always correct but noisy. This pass drops a pair of
+//! parentheses only when the target's operator precedence guarantees the
+//! expression groups the same way without them, giving `x > 10 && y < 20`.
+//!
+//! Anything the pass does not model (ternaries, casts, closures, Kotlin
+//! infix functions, ...) keeps its parentheses.
+
+use crate::cel::Target;
+use std::collections::HashMap;
+
+/// Binding strength of member access, calls and indexing
+const POSTFIX: u8 = 200;
+/// Identifiers, literals and parenthesized groups
+const ATOM: u8 = 255;
+
+/// How operators of one precedence level chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Assoc {
+    /// `a - b - c` is `(a - b) - c`
+    Left,
+    /// Regrouping either side is harmless (`&&`, `||`)
+    Full,
+    /// Comparisons: `a < b < c` is an error, or chains in Python
+    None,
+}
+
+/// What a token does in the expression around it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    /// Identifier or literal
+    Operand,
+    /// Infix operator
+    Binary(u8, Assoc),
+    /// Prefix operator
+    Prefix(u8),
+    /// `.`, `::`, `?.`, `->` or postfix `!` (Swift/TypeScript unwrap)
+    Postfix,
+    /// `(` opening a parenthesized expression
+    Group,
+    /// `(`/`[` of a call, index or list literal, or `{`
+    Open,
+    /// Any closing bracket
+    Close,
+    /// `,`
+    Comma,
+    /// Something this pass does not model
+    Opaque,
+}
+
+struct Token<'a> {
+    text: &'a str,
+    start: usize,
+    role: Role,
+}
+
+/// Drop the parentheses in rendered `code` that `target`'s precedence
+/// rules make redundant
+///
+/// One pass over the tokens decides each pair innermost first. A dropped
+/// pair's contents count as part of the group around it, so a decision
+/// never relies on parentheses that have since disappeared.
+pub fn minimize_parens(code: &str, target: Target) -> String {
+    let tokens = classify(tokenize(code), target);
+    let Some(closing) = matching_brackets(&tokens) else {
+        return code.to_string();
+    };
+
+    // Groups close innermost first
+    let mut groups: Vec<usize> = (0..tokens.len())
+        .filter(|&i| tokens[i].role == Role::Group)
+        .collect();
+    groups.sort_by_key(|&i| closing[i]);
+
+    let mut inlined = vec![None; tokens.len()];
+    let mut dropped = Dropped::new();
+    for open in groups {
+        if let Some(summary) = removable(code, &tokens, &closing, &inlined, &dropped, open) {
+            let (open_at, close_at) = (tokens[open].start, tokens[closing[open]].start);
+            let shown = (dropped.after(code, open_at), dropped.before(code, close_at));
+            dropped.0.insert(open_at, shown.0);
+            dropped.0.insert(close_at, shown.1);
+            inlined[open] = Some(summary);
+        }
+    }
+
+    code.char_indices()
+        .filter(|(i, _)| !dropped.0.contains_key(i))
+        .map(|(_, c)| c)
+        .collect()
+}
+
+/// Byte offsets of the brackets dropped so far, each with the character
+/// now seen through it: the one after an opening bracket, the one before
+/// a closing bracket
+struct Dropped(HashMap<usize, Option<char>>);
+
+impl Dropped {
+    fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Character that now comes just before `code[at]`
+    fn before(&self, code: &str, at: usize) -> Option<char> {
+        let (i, c) = code[..at].char_indices().next_back()?;
+        self.0.get(&i).copied().unwrap_or(Some(c))
+    }
+
+    /// Character that now comes just after `code[at]`
+    fn after(&self, code: &str, at: usize) -> Option<char> {
+        let (i, c) = code[at + 1..].char_indices().next()?;
+        self.0.get(&(at + 1 + i)).copied().unwrap_or(Some(c))
+    }
+}
+
+/// What [`summarize`] finds inside a group: its loosest binding strength
+/// and the distinct infix operators at that strength
+type Summary<'a> = (u8, Vec<&'a str>);
+
+/// Summary of the group opened at `tokens[open]` if its parentheses can go
+fn removable<'a>(
+    code: &str,
+    tokens: &[Token<'a>],
+    closing: &[usize],
+    inlined: &[Option<Summary<'a>>],
+    dropped: &Dropped,
+    open: usize,
+) -> Option<Summary<'a>> {
+    let close = closing[open];
+    let (inner, ops) = summarize(tokens, closing, inlined, open + 1, close)?;
+
+    let left_ok = match open.checked_sub(1).map(|i| &tokens[i]) {
+        None => true,
+        Some(t) => match t.role {
+            Role::Group | Role::Comma => true,
+            Role::Open => t.text != "{",
+            Role::Binary(p, assoc) => {
+                (inner > p
+                    || (inner == p && assoc == Assoc::Full && ops.iter().all(|op| *op == t.text)))
+                    && !mixes_bits_and_arithmetic(&ops, t.text)
+            }
+            Role::Prefix(p) => inner > p,
+            _ => false,
+        },
+    };
+    let right_ok = match tokens.get(close + 1) {
+        None => true,
+        Some(t) => match t.role {
+            Role::Close | Role::Comma => true,
+            Role::Binary(p, assoc) => {
+                (inner > p || (inner == p && assoc != Assoc::None))
+                    && !mixes_bits_and_arithmetic(&ops, t.text)
+            }
+            Role::Postfix | Role::Open => t.text != "{" && inner >= POSTFIX,
+            _ => false,
+        },
+    };
+
+    let keeps_apart =
+        !glues(code, dropped, tokens[open].start) && !glues(code, dropped, tokens[close].start);
+    (left_ok && right_ok && keeps_apart).then_some((inner, ops))
+}
+
+/// Bitwise operators next to arithmetic keep their parentheses, as
+/// clippy's `precedence` lint asks (`(a + b) << 2`)
+fn mixes_bits_and_arithmetic(ops: &[&str], neighbour: &str) -> bool {
+    let bits = |op: &str| matches!(op, "&" | "|" | "^" | "<<" | ">>" | ">>>" | "&^");
+    let arithmetic = |op: &str| matches!(op, "+" | "-" | "*" | "/" | "%" | "//");
+    ops.iter()
+        .any(|op| (bits(op) && arithmetic(neighbour)) || (arithmetic(op) && bits(neighbour)))
+}
+
+/// Whether deleting the bracket at `at` would fuse its neighbours into one
+/// token (`not(x)` into `notx`, `-(-x)` into `--x`), looking past
+/// brackets already dropped
+fn glues(code: &str, dropped: &Dropped, at: usize) -> bool {
+    let before = dropped.before(code, at);
+    let after = dropped.after(code, at);
+    let word = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    let op = |c: char| "+-*/%<>=!&|^~.?:".contains(c);
+    match (before, after) {
+        (Some(b), Some(a)) => (word(b) && word(a)) || (op(b) && op(a)),
+        _ => false,
+    }
+}
+
+/// Loosest binding strength among the operators directly inside
+/// `tokens[from..to]`, with the infix operators at that strength
+///
+/// The contents of a dropped group count as directly inside. `None` if
+/// anything there is opaque.
+fn summarize<'a>(
+    tokens: &[Token<'a>],
+    closing: &[usize],
+    inlined: &[Option<Summary<'a>>],
+    from: usize,
+    to: usize,
+) -> Option<Summary<'a>> {
+    let mut loosest = ATOM;
+    let mut ops = Vec::new();
+    let mut i = from;
+    while i < to {
+        let token = &tokens[i];
+        match token.role {
+            Role::Group => {
+                if let Some((p, group_ops)) = &inlined[i] {
+                    if *p < loosest {
+                        loosest = *p;
+                        ops.clear();
+                    }
+                    if *p == loosest {
+                        for op in group_ops {
+                            if !ops.contains(op) {
+                                ops.push(*op);
+                            }
+                        }
+                    }
+                }
+            }
+            Role::Binary(p, _) => {
+                if p < loosest {
+                    loosest = p;
+                    ops.clear();
+                }
+                if p == loosest && !ops.contains(&token.text) {
+                    ops.push(token.text);
+                }
+            }
+            Role::Prefix(p) if p < loosest => {
+                loosest = p;
+                ops.clear();
+            }
+            Role::Postfix => loosest = loosest.min(POSTFIX),
+            Role::Open if token.text == "{" => return None,
+            Role::Open => loosest = loosest.min(POSTFIX),
+            Role::Comma | Role::Opaque => return None,
+            _ => {}
+        }
+        i = match token.role {
+            Role::Group | Role::Open => closing[i] + 1,
+            _ => i + 1,
+        };
+    }
+    Some((loosest, ops))
+}
+
+/// Index of each opening bracket's closing bracket; `None` if unbalanced
+fn matching_brackets(tokens: &[Token]) -> Option<Vec<usize>> {
+    let mut closing = vec![0; tokens.len()];
+    let mut stack = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        match token.role {
+            Role::Group | Role::Open => stack.push(i),
+            Role::Close => closing[stack.pop()?] = i,
+            _ => {}
+        }
+    }
+    stack.is_empty().then_some(closing)
+}
+
+/// Split code into string literals, words, numbers, operators and
+/// brackets, dropping whitespace
+fn tokenize(code: &str) -> Vec<(&str, usize)> {
+    const OPERATORS: [&str; 20] = [
+        "===", "!==", ">>>", "==", "!=", "<=", ">=", "&&", "||", "<<", ">>", "::", "?.", "??",
+        "?:", "->", "=>", "&^", "**", "//",
+    ];
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while let Some(c) = code[pos..].chars().next() {
+        if c.is_whitespace() {
+            pos += c.len_utf8();
+            continue;
+        }
+        let rest = &code[pos..];
+        let len = if c == '"' || c == '\'' {
+            let mut escaped = false;
+            rest.char_indices()
+                .skip(1)
+                .find(|&(_, ch)| {
+                    let closes = ch == c && !escaped;
+                    escaped = ch == '\\' && !escaped;
+                    closes
+                })
+                .map_or(rest.len(), |(i, _)| i + 1)
+        } else if c.is_ascii_digit() {
+            let mut end = 0;
+            for (i, ch) in rest.char_indices() {
+                let fraction = ch == '.'
+                    && rest[i + 1..]
+                        .chars()
+                        .next()
+                        .is_some_and(|n| n.is_ascii_digit());
+                if !(ch.is_alphanumeric() || ch == '_' || fraction) {
+                    break;
+                }
+                end = i + ch.len_utf8();
+            }
+            end
+        } else if c.is_alphanumeric() || c == '_' || c == '$' {
+            rest.find(|ch: char| !(ch.is_alphanumeric() || ch == '_' || ch == '$'))
+                .unwrap_or(rest.len())
+        } else if let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(**op)) {
+            op.len()
+        } else {
+            c.len_utf8()
+        };
+        tokens.push((&rest[..len], pos));
+        pos += len;
+    }
+    tokens
+}
+
+/// Give each token its role, tracking whether an operand or an operator
+/// comes next
+fn classify(raw: Vec<(&str, usize)>, target: Target) -> Vec<Token<'_>> {
+    let mut tokens: Vec<Token> = Vec::with_capacity(raw.len());
+    let mut expect_operand = true;
+    let mut i = 0;
+    while i < raw.len() {
+        let (text, start) = raw[i];
+        let next = raw.get(i + 1).map(|(t, _)| *t);
+        let first = text.chars().next().unwrap_or(' ');
+        let is_word = first.is_alphabetic() || first == '_' || first == '$';
+
+        // Python's two-word comparisons
+        let pair = match (target, text, next) {
+            (Target::Python, "not", Some("in")) if !expect_operand => Some("not in"),
+            (Target::Python, "is", Some("not")) if !expect_operand => Some("is not"),
+            _ => None,
+        };
+        if let Some(op) = pair {
+            let role = binary_role(op, target).unwrap_or(Role::Opaque);
+            for &(text, start) in &raw[i..i + 2] {
+                tokens.push(Token { text, start, role });
+            }
+            expect_operand = true;
+            i += 2;
+            continue;
+        }
+
+        // Elixir atoms (`:gold`, `:"gold plus"`)
+        let atom = target == Target::Elixir
+            && text == ":"
+            && expect_operand
+            && raw.get(i + 1).is_some_and(|&(next, next_start)| {
+                next_start == start + 1
+                    && next.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == '"')
+            });
+        if atom {
+            for &(text, start) in &raw[i..i + 2] {
+                tokens.push(Token {
+                    text,
+                    start,
+                    role: Role::Operand,
+                });
+            }
+            expect_operand = false;
+            i += 2;
+            continue;
+        }
+
+        let role = match text {
+            "(" if expect_operand => Role::Group,
+            "(" | "[" | "{" => Role::Open,
+            ")" | "]" | "}" => Role::Close,
+            "," => Role::Comma,
+            _ if expect_operand => match prefix_role(text, target) {
+                Some(role) => role,
+                None if is_word || first.is_ascii_digit() || first == '"' || first == '\'' => {
+                    Role::Operand
+                }
+                None => Role::Opaque,
+            },
+            "." | "::" | "?." if target != Target::Php => Role::Postfix,
+            "->" if target == Target::Php => Role::Postfix,
+            // Postfix only when written against its operand (`x!`)
+            "!" if tokens
+                .last()
+                .is_some_and(|prev| prev.start + prev.text.len() == start && next != Some("=")) =>
+            {
+                Role::Postfix
+            }
+            _ => binary_role(text, target).unwrap_or(Role::Opaque),
+        };
+        expect_operand = match role {
+            Role::Operand | Role::Close => false,
+            Role::Postfix => text != "!",
+            _ => true,
+        };
+        tokens.push(Token { text, start, role });
+        i += 1;
+    }
+    tokens
+}
+
+fn prefix_role(op: &str, target: Target) -> Option<Role> {
+    let unary = match target {
+        Target::Rust => 100,
+        Target::Python if op == "not" => return Some(Role::Prefix(25)),
+        Target::Python => 110,
+        Target::Go => 60,
+        Target::Swift => 70,
+        Target::Kotlin => 80,
+        Target::TypeScript | Target::Java | Target::CSharp | Target::Php => 110,
+        Target::Ruby => 100,
+        // `not` binds as tightly as `!` in Elixir
+        Target::Elixir => 100,
+    };
+    let known = match op {
+        "not" => target == Target::Elixir,
+        "!" => target != Target::Python,
+        "-" | "+" => true,
+        "~" => matches!(
+            target,
+            Target::Python | Target::TypeScript | Target::Java | Target::CSharp | Target::Ruby
+        ),
+        "&" | "*" => matches!(target, Target::Rust | Target::Go),
+        "^" => target == Target::Go,
+        _ => false,
+    };
+    known.then_some(Role::Prefix(unary))
+}
+
+/// Precedence of an infix operator; higher binds tighter
+fn binary_role(op: &str, target: Target) -> Option<Role> {
+    let level = match target {
+        Target::Rust => match op {
+            "||" => 10,
+            "&&" => 20,
+            "==" | "!=" | "<" | "<=" | ">" | ">=" => 30,
+            "|" => 40,
+            "^" => 50,
+            "&" => 60,
+            "<<" | ">>" => 70,
+            "+" | "-" => 80,
+            "*" | "/" | "%" => 90,
+            _ => return None,
+        },
+        Target::Python => match op {
+            "or" => 10,
+            "and" => 20,
+            "==" | "!=" | "<" | "<=" | ">" | ">=" | "in" | "not in" | "is" | "is not" => 30,
+            "|" => 40,
+            "^" => 50,
+            "&" => 60,
+            "<<" | ">>" => 70,
+            "+" | "-" => 80,
+            "*" | "/" | "//" | "%" => 90,
+            _ => return None,
+        },
+        Target::TypeScript | Target::Java | Target::CSharp | Target::Php => match op {
+            "||" => 10,
+            "&&" => 20,
+            "|" => 30,
+            "^" => 40,
+            "&" => 50,
+            "==" | "!=" | "===" | "!==" => 60,
+            "<" | "<=" | ">" | ">=" => 70,
+            "." if target == Target::Php => 75,
+            "<<" | ">>" | ">>>" => 80,
+            "+" | "-" => 90,
+            "*" | "/" | "%" => 100,
+            _ => return None,
+        },
+        Target::Go => match op {
+            "||" => 10,
+            "&&" => 20,
+            "==" | "!=" | "<" | "<=" | ">" | ">=" => 30,
+            "+" | "-" | "|" | "^" => 40,
+            "*" | "/" | "%" | "<<" | ">>" | "&" | "&^" => 50,
+            _ => return None,
+        },
+        Target::Swift => match op {
+            "||" => 10,
+            "&&" => 20,
+            "==" | "!=" | "<" | "<=" | ">" | ">=" => 30,
+            "+" | "-" | "|" | "^" => 40,
+            "*" | "/" | "%" | "&" => 50,
+            "<<" | ">>" => 60,
+            _ => return None,
+        },
+        Target::Kotlin => match op {
+            "||" => 10,
+            "&&" => 20,
+            "==" | "!=" | "===" | "!==" => 30,
+            "<" | "<=" | ">" | ">=" => 40,
+            "in" | "is" => 50,
+            "+" | "-" => 60,
+            "*" | "/" | "%" => 70,
+            _ => return None,
+        },
+        // Equality binds looser than ordering in Ruby
+        Target::Ruby => match op {
+            "||" => 10,
+            "&&" => 20,
+            "==" | "!=" => 30,
+            "<" | "<=" | ">" | ">=" => 40,
+            "|" | "^" => 50,
+            "&" => 60,
+            "<<" | ">>" => 70,
+            "+" | "-" => 80,
+            "*" | "/" | "%" => 90,
+            _ => return None,
+        },
+        Target::Elixir => match op {
+            "or" => 10,
+            "and" => 20,
+            "==" | "!=" => 30,
+            "<" | "<=" | ">" | ">=" => 40,
+            "in" => 50,
+            "+" | "-" => 60,
+            "*" | "/" => 70,
+            _ => return None,
+        },
+    };
+    let assoc = match op {
+        "||" | "&&" | "or" | "and" => Assoc::Full,
+        "==" | "!=" | "===" | "!==" | "<" | "<=" | ">" | ">=" | "in" | "not in" | "is"
+        | "is not" => Assoc::None,
+        _ => Assoc::Left,
+    };
+    Some(Role::Binary(level, assoc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drops_parens_precedence_allows() {
+        let cases = [
+            (Target::Rust, "((x > 10) && (y < 20))", "x > 10 && y < 20"),
+            (Target::Rust, "((a - b) - c)", "a - b - c"),
+            (Target::Rust, "(a || (b && c))", "a || b && c"),
+            (Target::Rust, "((flags & 4) != 0)", "flags & 4 != 0"),
+            (Target::Python, "(not (a == b))", "not a == b"),
+            (
+                Target::Python,
+                "(RE_0.search(x) is not None)",
+                "RE_0.search(x) is not None",
+            ),
+            (
+                Target::Go,
+                "input.A != nil && ((strings.HasPrefix((*input.A), \"x\") && (input.B > 1)))",
+                "input.A != nil && strings.HasPrefix(*input.A, \"x\") && input.B > 1",
+            ),
+            (
+                Target::Swift,
+                "input.a != nil && ((input.a! > 0))",
+                "input.a != nil && input.a! > 0",
+            ),
+            (
+                Target::Elixir,
+                "((not locked) and (tier == :free))",
+                "not locked and tier == :free",
+            ),
+        ];
+        for (target, code, expected) in cases {
+            assert_eq!(minimize_parens(code, target), expected, "{:?}", target);
+        }
+    }
+
+    #[test]
+    fn test_keeps_parens_precedence_needs() {
+        let cases = [
+            (Target::Rust, "((a || b) && c)", "(a || b) && c"),
+            (Target::Rust, "(a - (b - c))", "a - (b - c)"),
+            (Target::Rust, "((a + b) << 2)", "(a + b) << 2"),
+            (Target::Rust, "(!(!a))", "!(!a)"),
+            (Target::Rust, "((x as i64) < y)", "(x as i64) < y"),
+            // `&` binds looser than `===` in C-like languages
+            (
+                Target::TypeScript,
+                "((flags & 4) !== 0)",
+                "(flags & 4) !== 0",
+            ),
+            // Python would chain `a < b == c`
+            (Target::Python, "((a < b) == c)", "(a < b) == c"),
+            (
+                Target::Kotlin,
+                "((input.flags and 4) != 0)",
+                "(input.flags and 4) != 0",
+            ),
+            (Target::Python, "not(x)", "not(x)"),
+            // Elixir's `not` binds tighter than `==`
+            (Target::Elixir, "(not (a == b))", "not (a == b)"),
+        ];
+        for (target, code, expected) in cases {
+            assert_eq!(minimize_parens(code, target), expected, "{:?}", target);
+        }
+    }
+
+    #[test]
+    fn test_long_chains_in_one_pass() {
+        // `((((c0 && c1) && c2) && c3) ...)`, as the CEL compiler nests them
+        let terms: Vec<String> = (0..2000).map(|i| format!("(x{} > {})", i, i)).collect();
+        let nested = terms[1..]
+            .iter()
+            .fold(terms[0].clone(), |acc, t| format!("({} && {})", acc, t));
+        let flat: Vec<String> = (0..2000).map(|i| format!("x{} > {}", i, i)).collect();
+        assert_eq!(minimize_parens(&nested, Target::Rust), flat.join(" && "));
+    }
+
+    #[test]
+    fn test_dropped_inner_group_counts_in_outer() {
+        let cases = [
+            (Target::Rust, "(x * ((a + b)))", "x * (a + b)"),
+            (Target::Rust, "(((a || b)) && c)", "(a || b) && c"),
+            (Target::Python, "(not ((x)))", "not x"),
+            (Target::Python, "not((x))", "not(x)"),
+        ];
+        for (target, code, expected) in cases {
+            assert_eq!(minimize_parens(code, target), expected, "{:?}", target);
+        }
+    }
+
+    #[test]
+    fn test_string_literals_untouched() {
+        assert_eq!(
+            minimize_parens("(name == \"(a) && (b)\")", Target::Rust),
+            "name == \"(a) && (b)\""
+        );
+    }
+}
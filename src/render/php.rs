@@ -0,0 +1,177 @@
+//! PHP code generation
+//!
+//! genco has no PHP backend, so this renderer builds the source directly.
+
+use crate::cel::{CelCompiler, Target};
+use crate::spec::*;
+use chrono::Utc;
+
+use super::{is_expression, to_pascal_case, RenderConfig};
+use super::{translate_vars, VarTranslation};
+
+/// Render spec to PHP code
+pub fn render(spec: &Spec, config: &RenderConfig) -> String {
+    let input_names: Vec<String> = spec.inputs.iter().map(|i| i.name.clone()).collect();
+    PhpRenderer {
+        config,
+        input_names,
+    }
+    .render(spec)
+}
+
+struct PhpRenderer<'a> {
+    config: &'a RenderConfig,
+    input_names: Vec<String>,
+}
+
+impl<'a> PhpRenderer<'a> {
+    fn render(&self, spec: &Spec) -> String {
+        let mut out = String::from("<?php\n\n");
+        let indent = &self.config.indent;
+
+        if self.config.provenance {
+            out.push_str(&format!("// GENERATED FROM: {}.yaml\n", spec.id));
+            out.push_str(&format!("// SPEC HASH: {}\n", spec.hash()));
+            out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
+            out.push_str("// DO NOT EDIT — regenerate from spec\n\n");
+        }
+
+        out.push_str("declare(strict_types=1);\n\n");
+        out.push_str(&format!("final class {}\n{{\n", to_pascal_case(&spec.id)));
+        for input in &spec.inputs {
+            let optional = if input.optional { "?" } else { "" };
+            out.push_str(&format!(
+                "{}private {}{} ${};\n",
+                indent,
+                optional,
+                self.render_type(Some(&input.typ)),
+                input.name
+            ));
+        }
+        out.push('\n');
+
+        let return_type = if spec.outputs.len() > 1 {
+            "array".to_string()
+        } else {
+            self.render_type(spec.outputs.first().map(|v| &v.typ))
+        };
+        out.push_str(&format!(
+            "{}public function evaluate(array $input): {}\n{}{{\n",
+            indent, return_type, indent
+        ));
+        for input in &spec.inputs {
+            let fallback = if input.optional { " ?? null" } else { "" };
+            out.push_str(&format!(
+                "{}$this->{} = $input['{}']{};\n",
+                indent.repeat(2),
+                input.name,
+                input.name,
+                fallback
+            ));
+        }
+        out.push_str(&self.render_branches(spec));
+        out.push_str(&format!("{}}}\n", indent));
+        out.push_str("}\n");
+
+        out
+    }
+
+    fn render_branches(&self, spec: &Spec) -> String {
+        let mut out = String::new();
+        let body_indent = self.config.indent.repeat(2);
+        let branch_indent = self.config.indent.repeat(3);
+
+        for rule in spec.rules_in_order() {
+            let condition = rule
+                .as_cel()
+                .map(|cel| {
+                    let guarded = guard_optionals(&cel, &spec.inputs);
+                    let compiled =
+                        CelCompiler::compile(&guarded, Target::Php).unwrap_or_else(|_| cel.clone());
+                    translate_vars(&compiled, &self.input_names, VarTranslation::ThisProperty)
+                })
+                .unwrap_or_else(|| "true".into());
+
+            out.push('\n');
+            if self.config.comments {
+                out.push_str(&format!("{}// {}\n", body_indent, rule.id));
+            }
+            out.push_str(&format!("{}if ({}) {{\n", body_indent, condition));
+            out.push_str(&format!(
+                "{}return {};\n",
+                branch_indent,
+                self.render_output(&rule.then, &spec.outputs)
+            ));
+            out.push_str(&format!("{}}}\n", body_indent));
+        }
+
+        out.push('\n');
+        match &spec.default {
+            Some(default) => out.push_str(&format!(
+                "{}return {};\n",
+                body_indent,
+                self.render_output(default, &spec.outputs)
+            )),
+            None => out.push_str(&format!(
+                "{}throw new \\LogicException('No rule matched');\n",
+                body_indent
+            )),
+        }
+
+        out
+    }
+
+    fn render_type(&self, typ: Option<&VarType>) -> String {
+        match typ {
+            Some(VarType::Bool) => "bool".into(),
+            Some(VarType::Int) => "int".into(),
+            Some(VarType::Float) => "float".into(),
+            Some(VarType::String) | Some(VarType::Enum(_)) => "string".into(),
+            Some(VarType::Object(_)) | Some(VarType::List(_)) => "array".into(),
+            None => "void".into(),
+        }
+    }
+
+    /// Multi-output specs return an array keyed in output order
+    fn render_output(&self, output: &Output, outputs: &[Variable]) -> String {
+        match output {
+            Output::Single(v) => self.render_value(v),
+            Output::Named(map) => {
+                let fields: Vec<_> = outputs
+                    .iter()
+                    .filter_map(|o| {
+                        map.get(&o.name)
+                            .map(|v| format!("'{}' => {}", o.name, self.render_value(v)))
+                    })
+                    .collect();
+                format!("[{}]", fields.join(", "))
+            }
+        }
+    }
+
+    fn render_value(&self, v: &ConditionValue) -> String {
+        match v {
+            ConditionValue::Bool(b) => b.to_string(),
+            ConditionValue::Int(i) => i.to_string(),
+            ConditionValue::Float(f) => format!("{:?}", f),
+            ConditionValue::String(s) => {
+                // Check if this is a CEL expression or a literal string
+                if is_expression(s) {
+                    CelCompiler::compile(s, Target::Php)
+                        .map(|c| {
+                            translate_vars(&c, &self.input_names, VarTranslation::ThisProperty)
+                        })
+                        .unwrap_or_else(|_| php_string(s))
+                } else {
+                    php_string(s)
+                }
+            }
+            ConditionValue::Null => "null".into(),
+            _ => "null".into(),
+        }
+    }
+}
+
+fn php_string(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
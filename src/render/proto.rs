@@ -0,0 +1,278 @@
+//! Protocol Buffers generation
+//!
+//! Describes a spec as a gRPC service: the request message carries the
+//! inputs, the response message carries the outputs, and a single
+//! `Evaluate` RPC maps one to the other.
+
+use super::native_enum;
+use crate::spec::*;
+use crate::util::{to_pascal_case, to_upper_snake_case};
+
+/// Render a spec as a proto3 file with `{Id}Request`, `{Id}Response` and a
+/// `{Id}Service` exposing `rpc Evaluate`
+///
+/// Enum inputs and outputs become proto `enum`s (with the zero value
+/// reserved for `UNSPECIFIED`), lists become `repeated` fields, objects
+/// become their own messages and optional inputs are marked `optional`.
+/// An output enum named like an input enum with other values is declared
+/// separately with an `Output` suffix (`TierOutput`).
+pub fn spec_to_proto(spec: &Spec) -> String {
+    let id_pascal = to_pascal_case(&spec.id);
+    let mut defs = Definitions {
+        side: "Input",
+        ..Default::default()
+    };
+
+    let request = defs.message(&format!("{}Request", id_pascal), &spec.inputs, "");
+    defs.side = "Output";
+    let response = defs.message(&format!("{}Response", id_pascal), &spec.outputs, "");
+
+    let mut out = String::new();
+    out.push_str("// GENERATED FROM: ");
+    out.push_str(&spec.id);
+    out.push_str(".yaml\n// DO NOT EDIT - regenerate from spec\n\n");
+    out.push_str("syntax = \"proto3\";\n\n");
+    out.push_str(&format!("package {};\n\n", spec.id));
+    if defs.uses_struct {
+        out.push_str("import \"google/protobuf/struct.proto\";\n\n");
+    }
+    if let Some(description) = spec.description.as_ref().or(spec.name.as_ref()) {
+        for line in description.lines() {
+            out.push_str(&format!("// {}\n", line.trim_end()));
+        }
+    }
+    out.push_str(&format!("service {}Service {{\n", id_pascal));
+    out.push_str(&format!(
+        "  rpc Evaluate({}Request) returns ({}Response);\n}}\n",
+        id_pascal, id_pascal
+    ));
+    out.push('\n');
+    out.push_str(&request);
+    out.push('\n');
+    out.push_str(&response);
+    for def in &defs.blocks {
+        out.push('\n');
+        out.push_str(def);
+    }
+    out
+}
+
+/// Enum and nested message declarations, in the order first referenced
+#[derive(Default)]
+struct Definitions {
+    names: Vec<String>,
+    blocks: Vec<String>,
+    /// Variant values of each enum declared so far, by name
+    enums: Vec<(String, Vec<String>)>,
+    /// `Input` or `Output`, for telling apart same-named enums
+    side: &'static str,
+    /// An untyped object needs `google/protobuf/struct.proto`
+    uses_struct: bool,
+}
+
+impl Definitions {
+    /// A message with one field per variable, numbered in declaration order
+    fn message(&mut self, name: &str, vars: &[Variable], owner: &str) -> String {
+        let mut out = format!("message {} {{\n", name);
+        for (i, var) in vars.iter().enumerate() {
+            if let Some(description) = &var.description {
+                out.push_str(&format!("  // {}\n", description.trim()));
+            }
+            let (repeated, typ) = self.field_type(owner, &var.name, &var.typ);
+            let label = if repeated {
+                "repeated "
+            } else if var.optional {
+                "optional "
+            } else {
+                ""
+            };
+            out.push_str(&format!("  {}{} {} = {};\n", label, typ, var.name, i + 1));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Proto type for a field, and whether it is `repeated`
+    fn field_type(&mut self, owner: &str, name: &str, typ: &VarType) -> (bool, String) {
+        match typ {
+            VarType::List(inner) => {
+                let (nested, element) = self.field_type(owner, name, inner);
+                if !nested {
+                    return (true, element);
+                }
+                // `repeated repeated` is not a thing; wrap the inner list
+                let wrapper = format!("{}{}List", owner, to_pascal_case(name));
+                if !self.names.contains(&wrapper) {
+                    self.names.push(wrapper.clone());
+                    self.blocks.push(format!(
+                        "message {} {{\n  repeated {} values = 1;\n}}\n",
+                        wrapper, element
+                    ));
+                }
+                (true, wrapper)
+            }
+            _ => (false, self.scalar_type(owner, name, typ)),
+        }
+    }
+
+    /// Name for an enum with these values: `base` if it is free or already
+    /// names the same enum, else `base` with the side appended (`TierOutput`)
+    fn enum_name(&self, base: String, values: &[String]) -> String {
+        let fits = |name: &str| {
+            !self.names.iter().any(|n| n == name)
+                || self.enums.iter().any(|(n, v)| n == name && v == values)
+        };
+        if fits(&base) {
+            return base;
+        }
+        let sided = format!("{}{}", base, self.side);
+        if fits(&sided) {
+            return sided;
+        }
+        (2..)
+            .map(|i| format!("{}{}", sided, i))
+            .find(|name| fits(name))
+            .expect("unbounded range")
+    }
+
+    fn scalar_type(&mut self, owner: &str, name: &str, typ: &VarType) -> String {
+        match typ {
+            VarType::Bool => "bool".into(),
+            VarType::Int => "int64".into(),
+            VarType::Float => "double".into(),
+            VarType::String | VarType::List(_) => "string".into(),
+            VarType::Enum(_) => match native_enum(name, typ) {
+                Some(native) => {
+                    let values: Vec<String> =
+                        native.variants.iter().map(|v| v.value.clone()).collect();
+                    let enum_name = self.enum_name(format!("{}{}", owner, native.name), &values);
+                    if !self.names.contains(&enum_name) {
+                        let prefix = to_upper_snake_case(&enum_name);
+                        let mut block =
+                            format!("enum {} {{\n  {}_UNSPECIFIED = 0;\n", enum_name, prefix);
+                        for (i, v) in native.variants.iter().enumerate() {
+                            block.push_str(&format!(
+                                "  {}_{} = {};\n",
+                                prefix,
+                                v.ident_upper,
+                                i + 1
+                            ));
+                        }
+                        block.push_str("}\n");
+                        self.names.push(enum_name.clone());
+                        self.blocks.push(block);
+                        self.enums.push((enum_name.clone(), values));
+                    }
+                    enum_name
+                }
+                // Values that don't make identifiers stay strings
+                None => "string".into(),
+            },
+            VarType::Object(fields) if fields.is_empty() => {
+                self.uses_struct = true;
+                "google.protobuf.Struct".into()
+            }
+            VarType::Object(fields) => {
+                let message_name = format!("{}{}", owner, to_pascal_case(name));
+                if !self.names.contains(&message_name) {
+                    self.names.push(message_name.clone());
+                    let vars: Vec<Variable> = fields
+                        .iter()
+                        .map(|(field, field_typ)| Variable {
+                            name: field.clone(),
+                            typ: field_typ.clone(),
+                            description: None,
+                            values: None,
+                            optional: false,
+                            range: None,
+                        })
+                        .collect();
+                    let block = self.message(&message_name, &vars, &message_name);
+                    self.blocks.push(block);
+                }
+                message_name
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proto_service() {
+        let spec = Spec::from_yaml(
+            r#"
+id: check_status
+inputs:
+  - name: rate_exceeded
+    type: bool
+  - name: tier
+    type: !enum [free, pro]
+  - name: tags
+    type: list<string>
+outputs:
+  - name: status
+    type: int
+rules:
+  - id: R1
+    when: rate_exceeded
+    then: 429
+  - id: R2
+    when: '!rate_exceeded'
+    then: 200
+"#,
+        )
+        .unwrap();
+
+        let proto = spec_to_proto(&spec);
+        assert!(proto.contains("syntax = \"proto3\";"));
+        assert!(proto.contains("message CheckStatusRequest {"));
+        assert!(proto.contains("message CheckStatusResponse {"));
+        assert!(proto.contains("service CheckStatusService {"));
+        assert!(proto.contains("rpc Evaluate(CheckStatusRequest) returns (CheckStatusResponse);"));
+        assert!(proto.contains("  bool rate_exceeded = 1;"));
+        assert!(proto.contains("  Tier tier = 2;"));
+        assert!(proto.contains("  repeated string tags = 3;"));
+        assert!(proto.contains("  int64 status = 1;"));
+        assert!(proto.contains("enum Tier {\n  TIER_UNSPECIFIED = 0;\n  TIER_FREE = 1;"));
+    }
+
+    #[test]
+    fn test_proto_enum_name_shared_by_input_and_output() {
+        let yaml = r#"
+id: upgrade
+inputs:
+  - name: tier
+    type: !enum [free, pro]
+outputs:
+  - name: tier
+    type: !enum [pro, enterprise]
+rules:
+  - id: R1
+    when: tier == 'free'
+    then: pro
+  - id: R2
+    when: tier == 'pro'
+    then: enterprise
+"#;
+
+        let proto = spec_to_proto(&Spec::from_yaml(yaml).unwrap());
+        assert!(proto.contains("  Tier tier = 1;"));
+        assert!(proto.contains(
+            "enum Tier {\n  TIER_UNSPECIFIED = 0;\n  TIER_FREE = 1;\n  TIER_PRO = 2;\n}"
+        ));
+        // The output's variants differ, so it gets its own enum
+        assert!(proto.contains("  TierOutput tier = 1;"));
+        assert!(proto.contains(
+            "enum TierOutput {\n  TIER_OUTPUT_UNSPECIFIED = 0;\n  TIER_OUTPUT_PRO = 1;\n  TIER_OUTPUT_ENTERPRISE = 2;\n}"
+        ));
+
+        // Identical variants share one enum
+        let same = yaml.replace("[pro, enterprise]", "[free, pro]");
+        let proto = spec_to_proto(&Spec::from_yaml(&same).unwrap());
+        assert_eq!(proto.matches("  Tier tier = 1;").count(), 2);
+        assert_eq!(proto.matches("enum ").count(), 1);
+    }
+}
@@ -81,7 +81,7 @@ impl<'a> PythonRenderer<'a> {
     fn render_if_else(&self, spec: &Spec, out: &mut String) {
         let ind = &self.config.indent;
 
-        for (i, rule) in spec.rules.iter().enumerate() {
+        for (i, rule) in spec.rules_in_order().into_iter().enumerate() {
             let condition = rule
                 .as_cel()
                 .map(|cel| {
@@ -135,7 +135,7 @@ impl<'a> PythonRenderer<'a> {
             out.push_str(&format!("{}match ({}):\n", ind, tuple.join(", ")));
         }
 
-        for rule in &spec.rules {
+        for rule in spec.rules_in_order() {
             let pattern = self.render_pattern(spec, rule);
             out.push_str(&format!("{}{}case {}:\n", ind, ind, pattern));
 
@@ -166,16 +166,16 @@ impl<'a> PythonRenderer<'a> {
 
     /// DOGFOODING: Use generated type_mapping for Python types
     fn render_type(&self, typ: &VarType) -> String {
-        use crate::generated::type_mapping::type_mapping;
+        use crate::generated::type_mapping::{self as mapping, type_mapping};
 
         match typ {
-            VarType::Bool => type_mapping("Bool".into(), "Python".into()),
-            VarType::Int => type_mapping("Int".into(), "Python".into()),
-            VarType::Float => type_mapping("Float".into(), "Python".into()),
-            VarType::String => type_mapping("String".into(), "Python".into()),
+            VarType::Bool => type_mapping(mapping::VarType::Bool, mapping::Target::Python),
+            VarType::Int => type_mapping(mapping::VarType::Int, mapping::Target::Python),
+            VarType::Float => type_mapping(mapping::VarType::Float, mapping::Target::Python),
+            VarType::String => type_mapping(mapping::VarType::String, mapping::Target::Python),
             VarType::Enum(_) => "str".into(), // Enums render as str
             VarType::List(inner) => format!("list[{}]", self.render_type(inner)),
-            VarType::Object => type_mapping("Object".into(), "Python".into()),
+            VarType::Object(_) => type_mapping(mapping::VarType::Object, mapping::Target::Python),
         }
     }
 
@@ -237,13 +237,13 @@ impl<'a> PythonRenderer<'a> {
 
     /// DOGFOODING: Use generated bool_literal for Python-specific booleans (True/False)
     fn render_bool(&self, value: bool) -> String {
-        use crate::generated::bool_literal::bool_literal;
-        bool_literal(value, "Python".into())
+        use crate::generated::bool_literal::{bool_literal, Target};
+        bool_literal(value, Target::Python)
     }
 
     /// DOGFOODING: Use generated null_literal for Python null (None)
     fn render_null(&self) -> String {
-        use crate::generated::null_literal::null_literal;
-        null_literal("Python".into())
+        use crate::generated::null_literal::{null_literal, Target};
+        null_literal(Target::Python)
     }
 }
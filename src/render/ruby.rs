@@ -0,0 +1,132 @@
+//! Ruby code generation
+//!
+//! genco has no Ruby backend, so this renderer builds the source directly.
+
+use crate::cel::{CelCompiler, Target};
+use crate::spec::*;
+use chrono::Utc;
+
+use super::{is_expression, to_pascal_case, RenderConfig};
+
+/// Render spec to Ruby code
+pub fn render(spec: &Spec, config: &RenderConfig) -> String {
+    RubyRenderer { config }.render(spec)
+}
+
+struct RubyRenderer<'a> {
+    config: &'a RenderConfig,
+}
+
+impl<'a> RubyRenderer<'a> {
+    fn render(&self, spec: &Spec) -> String {
+        let mut out = String::from("# frozen_string_literal: true\n\n");
+        let indent = &self.config.indent;
+
+        if self.config.provenance {
+            out.push_str(&format!("# GENERATED FROM: {}.yaml\n", spec.id));
+            out.push_str(&format!("# SPEC HASH: {}\n", spec.hash()));
+            out.push_str(&format!("# GENERATED: {}\n", Utc::now().to_rfc3339()));
+            out.push_str("# DO NOT EDIT — regenerate from spec\n\n");
+        }
+
+        out.push_str(&format!("module {}\n", to_pascal_case(&spec.id)));
+        out.push_str(&format!("{}module_function\n\n", indent));
+        out.push_str(&format!("{}def evaluate(input)\n", indent));
+        for input in &spec.inputs {
+            out.push_str(&format!(
+                "{}{} = input[:{}]\n",
+                indent.repeat(2),
+                input.name,
+                input.name
+            ));
+        }
+        if !spec.inputs.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&self.render_branches(spec));
+        out.push_str(&format!("{}end\n", indent));
+        out.push_str("end\n");
+
+        out
+    }
+
+    fn render_branches(&self, spec: &Spec) -> String {
+        let mut out = String::new();
+        let body_indent = self.config.indent.repeat(2);
+        let branch_indent = self.config.indent.repeat(3);
+
+        for (i, rule) in spec.rules_in_order().into_iter().enumerate() {
+            let condition = rule
+                .as_cel()
+                .map(|cel| {
+                    let guarded = guard_optionals(&cel, &spec.inputs);
+                    CelCompiler::compile(&guarded, Target::Ruby).unwrap_or_else(|_| cel.clone())
+                })
+                .unwrap_or_else(|| "true".into());
+
+            let keyword = if i == 0 { "if" } else { "elsif" };
+            out.push_str(&format!("{}{} {}\n", body_indent, keyword, condition));
+            if self.config.comments {
+                out.push_str(&format!("{}# {}\n", branch_indent, rule.id));
+            }
+            out.push_str(&format!(
+                "{}{}\n",
+                branch_indent,
+                self.render_output(&rule.then, &spec.outputs)
+            ));
+        }
+
+        let fallback = match &spec.default {
+            Some(default) => self.render_output(default, &spec.outputs),
+            None => "raise 'No rule matched'".into(),
+        };
+        if spec.rules.is_empty() {
+            out.push_str(&format!("{}{}\n", body_indent, fallback));
+        } else {
+            out.push_str(&format!("{}else\n", body_indent));
+            out.push_str(&format!("{}{}\n", branch_indent, fallback));
+            out.push_str(&format!("{}end\n", body_indent));
+        }
+
+        out
+    }
+
+    /// Multi-output specs return a hash keyed by output name
+    fn render_output(&self, output: &Output, outputs: &[Variable]) -> String {
+        match output {
+            Output::Single(v) => self.render_value(v),
+            Output::Named(map) => {
+                let fields: Vec<_> = outputs
+                    .iter()
+                    .filter_map(|o| {
+                        map.get(&o.name)
+                            .map(|v| format!("{}: {}", o.name, self.render_value(v)))
+                    })
+                    .collect();
+                format!("{{ {} }}", fields.join(", "))
+            }
+        }
+    }
+
+    fn render_value(&self, v: &ConditionValue) -> String {
+        match v {
+            ConditionValue::Bool(b) => b.to_string(),
+            ConditionValue::Int(i) => i.to_string(),
+            ConditionValue::Float(f) => format!("{:?}", f),
+            ConditionValue::String(s) => {
+                // Check if this is a CEL expression or a literal string
+                if is_expression(s) {
+                    CelCompiler::compile(s, Target::Ruby).unwrap_or_else(|_| ruby_string(s))
+                } else {
+                    ruby_string(s)
+                }
+            }
+            ConditionValue::Null => "nil".into(),
+            _ => "nil".into(),
+        }
+    }
+}
+
+fn ruby_string(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
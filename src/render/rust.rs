@@ -52,13 +52,19 @@ impl<'a> RustRenderer<'a> {
             .collect();
 
         let return_type = if spec.outputs.len() > 1 {
-            // Multiple outputs -> tuple
-            let types: Vec<_> = spec
-                .outputs
-                .iter()
-                .map(|v| self.render_type(&v.typ))
-                .collect();
-            format!("({})", types.join(", "))
+            // Multiple outputs -> result struct
+            let name = output_struct_name(spec);
+            out.push_str("#[derive(Debug, Clone, PartialEq)]\n");
+            out.push_str(&format!("pub struct {} {{\n", name));
+            for output in &spec.outputs {
+                out.push_str(&format!(
+                    "    pub {}: {},\n",
+                    output.name,
+                    self.render_type(&output.typ)
+                ));
+            }
+            out.push_str("}\n\n");
+            name
         } else {
             spec.outputs
                 .first()
@@ -66,6 +72,9 @@ impl<'a> RustRenderer<'a> {
                 .unwrap_or_else(|| "()".into())
         };
 
+        if self.config.assume_complete {
+            out.push_str("// COMPLETE: every input matches a rule (imacs completeness analysis)\n");
+        }
         out.push_str(&format!(
             "pub fn {}({}) -> {} {{\n",
             spec.id,
@@ -87,16 +96,16 @@ impl<'a> RustRenderer<'a> {
     /// DOGFOODING: Use generated type_mapping for type conversions
     fn render_type(&self, typ: &VarType) -> String {
         // Use the generated type_mapping function
-        use crate::generated::type_mapping::type_mapping;
+        use crate::generated::type_mapping::{self as mapping, type_mapping};
 
         match typ {
-            VarType::Bool => type_mapping("Bool".into(), "Rust".into()),
-            VarType::Int => type_mapping("Int".into(), "Rust".into()),
-            VarType::Float => type_mapping("Float".into(), "Rust".into()),
-            VarType::String => type_mapping("String".into(), "Rust".into()),
+            VarType::Bool => type_mapping(mapping::VarType::Bool, mapping::Target::Rust),
+            VarType::Int => type_mapping(mapping::VarType::Int, mapping::Target::Rust),
+            VarType::Float => type_mapping(mapping::VarType::Float, mapping::Target::Rust),
+            VarType::String => type_mapping(mapping::VarType::String, mapping::Target::Rust),
             VarType::Enum(_) => "String".into(), // Enums render as strings
             VarType::List(inner) => format!("Vec<{}>", self.render_type(inner)),
-            VarType::Object => type_mapping("Object".into(), "Rust".into()),
+            VarType::Object(_) => type_mapping(mapping::VarType::Object, mapping::Target::Rust),
         }
     }
 
@@ -122,7 +131,7 @@ impl<'a> RustRenderer<'a> {
         }
 
         // Match arms
-        for rule in &spec.rules {
+        for rule in spec.rules_in_order() {
             if self.config.comments {
                 out.push_str(&format!("{}{}// {}\n", ind, ind, rule.id));
             }
@@ -133,7 +142,14 @@ impl<'a> RustRenderer<'a> {
         }
 
         // Default if specified
-        if let Some(default) = &spec.default {
+        if self.config.assume_complete {
+            out.push_str(&format!(
+                "{}{}_ => {},\n",
+                ind,
+                ind,
+                self.unreachable_complete(spec)
+            ));
+        } else if let Some(default) = &spec.default {
             out.push_str(&format!(
                 "{}{}_ => {},\n",
                 ind,
@@ -148,7 +164,7 @@ impl<'a> RustRenderer<'a> {
     fn render_if_else(&self, spec: &Spec, out: &mut String) {
         let ind = &self.config.indent;
 
-        for (i, rule) in spec.rules.iter().enumerate() {
+        for (i, rule) in spec.rules_in_order().into_iter().enumerate() {
             let condition = rule
                 .as_cel()
                 .map(|cel| CelCompiler::compile(&cel, Target::Rust).unwrap_or_else(|_| cel.clone()))
@@ -174,7 +190,14 @@ impl<'a> RustRenderer<'a> {
 
         // Default/else - always add one
         out.push_str(&format!("{}}} else {{\n", ind));
-        if let Some(default) = &spec.default {
+        if self.config.assume_complete {
+            out.push_str(&format!(
+                "{}{}{}\n",
+                ind,
+                ind,
+                self.unreachable_complete(spec)
+            ));
+        } else if let Some(default) = &spec.default {
             out.push_str(&format!(
                 "{}{}{}\n",
                 ind,
@@ -191,6 +214,14 @@ impl<'a> RustRenderer<'a> {
         out.push_str(&format!("{}}}\n", ind));
     }
 
+    /// Fallback for a spec completeness analysis has proven complete
+    fn unreachable_complete(&self, spec: &Spec) -> String {
+        format!(
+            "unreachable!(\"{} is complete: every input matches a rule\")",
+            spec.id
+        )
+    }
+
     fn render_pattern(&self, spec: &Spec, rule: &Rule) -> String {
         let conditions = rule.conditions.as_ref();
 
@@ -227,14 +258,14 @@ impl<'a> RustRenderer<'a> {
 
     /// DOGFOODING: Use generated bool_literal for boolean rendering
     fn render_bool(&self, value: bool) -> String {
-        use crate::generated::bool_literal::bool_literal;
-        bool_literal(value, "Rust".into())
+        use crate::generated::bool_literal::{bool_literal, Target};
+        bool_literal(value, Target::Rust)
     }
 
     /// DOGFOODING: Use generated null_literal for null rendering
     fn render_null(&self) -> String {
-        use crate::generated::null_literal::null_literal;
-        null_literal("Rust".into())
+        use crate::generated::null_literal::{null_literal, Target};
+        null_literal(Target::Rust)
     }
 
     fn render_output(&self, output: &Output) -> String {
@@ -275,7 +306,7 @@ impl<'a> RustRenderer<'a> {
         }
     }
 
-    /// Render output for a spec, handling named outputs as tuples
+    /// Render output for a spec, handling named outputs as result structs
     fn render_output_for_spec(&self, output: &Output, spec: &Spec) -> String {
         // Handle both Output::Named and Output::Single(ConditionValue::Map)
         // The latter occurs due to serde untagged parsing order
@@ -287,17 +318,19 @@ impl<'a> RustRenderer<'a> {
 
         let map = map.unwrap();
         if spec.outputs.len() > 1 {
-            // Multiple outputs -> tuple in spec output order
-            let values: Vec<_> = spec
+            // Multiple outputs -> result struct in spec output order
+            let fields: Vec<_> = spec
                 .outputs
                 .iter()
                 .map(|out_var| {
-                    map.get(&out_var.name)
+                    let value = map
+                        .get(&out_var.name)
                         .map(|v| self.render_condition_value(v))
-                        .unwrap_or_else(|| "Default::default()".into())
+                        .unwrap_or_else(|| "Default::default()".into());
+                    format!("{}: {}", out_var.name, value)
                 })
                 .collect();
-            format!("({})", values.join(", "))
+            format!("{} {{ {} }}", output_struct_name(spec), fields.join(", "))
         } else if let Some(first_output) = spec.outputs.first() {
             // Single output - get its value from the map
             map.get(&first_output.name)
@@ -308,3 +341,8 @@ impl<'a> RustRenderer<'a> {
         }
     }
 }
+
+/// Name of the struct returned by specs with several outputs
+fn output_struct_name(spec: &Spec) -> String {
+    format!("{}Output", crate::util::to_pascal_case(&spec.id))
+}
@@ -1006,7 +1006,8 @@ impl ScopingConfig {
                 .csharp
                 .as_ref()
                 .map(|ns| ResolvedNamespace::CSharp(ns.clone())),
-            Target::Java => self
+            // Kotlin packages use the same syntax, so they share the Java config
+            Target::Java | Target::Kotlin => self
                 .languages
                 .java
                 .as_ref()
@@ -1031,6 +1032,14 @@ impl ScopingConfig {
                 .typescript
                 .as_ref()
                 .map(|m| ResolvedNamespace::TypeScript(m.clone())),
+            // Swift modules are build targets, not source declarations
+            Target::Swift => None,
+            // PHP has no scoping configuration
+            Target::Php => None,
+            // Ruby has no scoping configuration
+            Target::Ruby => None,
+            // Elixir has no scoping configuration
+            Target::Elixir => None,
         }
     }
 
@@ -0,0 +1,154 @@
+//! Swift code generation
+//!
+//! genco has no Swift backend, so this renderer builds the source directly.
+
+use crate::cel::{CelCompiler, Target};
+use crate::spec::*;
+use chrono::Utc;
+
+use super::{is_expression, to_camel_case, to_pascal_case, RenderConfig};
+use super::{translate_vars, VarTranslation};
+
+/// Render spec to Swift code
+pub fn render(spec: &Spec, config: &RenderConfig) -> String {
+    let input_names: Vec<String> = spec.inputs.iter().map(|i| i.name.clone()).collect();
+    SwiftRenderer {
+        config,
+        input_names,
+    }
+    .render(spec)
+}
+
+struct SwiftRenderer<'a> {
+    config: &'a RenderConfig,
+    input_names: Vec<String>,
+}
+
+impl<'a> SwiftRenderer<'a> {
+    fn render(&self, spec: &Spec) -> String {
+        let mut out = String::new();
+        let type_name = to_pascal_case(&spec.id);
+        let indent = &self.config.indent;
+
+        if self.config.provenance {
+            out.push_str(&format!("// GENERATED FROM: {}.yaml\n", spec.id));
+            out.push_str(&format!("// SPEC HASH: {}\n", spec.hash()));
+            out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
+            out.push_str("// DO NOT EDIT — regenerate from spec\n\n");
+        }
+
+        out.push_str(&format!("struct {}Input {{\n", type_name));
+        for input in &spec.inputs {
+            let optional = if input.optional { "?" } else { "" };
+            out.push_str(&format!(
+                "{}let {}: {}{}\n",
+                indent,
+                to_camel_case(&input.name),
+                self.render_type(Some(&input.typ)),
+                optional
+            ));
+        }
+        out.push_str("}\n\n");
+
+        let return_type = self.render_type(spec.outputs.first().map(|v| &v.typ));
+        out.push_str(&format!(
+            "func {}(_ input: {}Input) -> {} {{\n",
+            to_camel_case(&spec.id),
+            type_name,
+            return_type
+        ));
+        out.push_str(&self.render_branches(spec));
+        out.push_str("}\n");
+
+        out
+    }
+
+    fn render_branches(&self, spec: &Spec) -> String {
+        let mut out = String::new();
+        let indent = &self.config.indent;
+
+        for rule in spec.rules_in_order() {
+            let condition = rule
+                .as_cel()
+                .map(|cel| {
+                    let compiled =
+                        CelCompiler::compile(&cel, Target::Swift).unwrap_or_else(|_| cel.clone());
+                    translate_vars(&compiled, &self.input_names, VarTranslation::InputCamel)
+                })
+                .unwrap_or_else(|| "true".into());
+
+            if self.config.comments {
+                out.push_str(&format!("{}// {}\n", indent, rule.id));
+            }
+            out.push_str(&format!("{}if {} {{\n", indent, condition));
+            out.push_str(&format!(
+                "{}{}return {}\n",
+                indent,
+                indent,
+                self.render_output(&rule.then)
+            ));
+            out.push_str(&format!("{}}}\n", indent));
+        }
+
+        match &spec.default {
+            Some(default) => out.push_str(&format!(
+                "{}return {}\n",
+                indent,
+                self.render_output(default)
+            )),
+            None => out.push_str(&format!("{}fatalError(\"No rule matched\")\n", indent)),
+        }
+
+        out
+    }
+
+    fn render_type(&self, typ: Option<&VarType>) -> String {
+        match typ {
+            Some(VarType::Bool) => "Bool".into(),
+            Some(VarType::Int) => "Int64".into(),
+            Some(VarType::Float) => "Double".into(),
+            Some(VarType::String) => "String".into(),
+            Some(VarType::Object(_)) => "[String: Any]".into(),
+            Some(VarType::List(inner)) => format!("[{}]", self.render_type(Some(inner))),
+            Some(VarType::Enum(_)) => "String".into(),
+            None => "Void".into(),
+        }
+    }
+
+    fn render_output(&self, output: &Output) -> String {
+        match output {
+            Output::Single(v) => self.render_value(v),
+            Output::Named(map) => {
+                let fields: Vec<_> = map
+                    .iter()
+                    .map(|(k, v)| format!("\"{}\": {}", k, self.render_value(v)))
+                    .collect();
+                format!("[{}]", fields.join(", "))
+            }
+        }
+    }
+
+    fn render_value(&self, v: &ConditionValue) -> String {
+        match v {
+            ConditionValue::Bool(b) => b.to_string(),
+            ConditionValue::Int(i) => i.to_string(),
+            ConditionValue::Float(f) => format!("{:?}", f),
+            ConditionValue::String(s) => {
+                // Check if this is a CEL expression or a literal string
+                if is_expression(s) {
+                    CelCompiler::compile(s, Target::Swift)
+                        .map(|c| translate_vars(&c, &self.input_names, VarTranslation::InputCamel))
+                        .unwrap_or_else(|_| swift_string(s))
+                } else {
+                    swift_string(s)
+                }
+            }
+            ConditionValue::Null => "nil".into(),
+            _ => "nil".into(),
+        }
+    }
+}
+
+fn swift_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
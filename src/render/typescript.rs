@@ -87,7 +87,7 @@ impl<'a> TypeScriptRenderer<'a> {
         ));
 
         // If-else chain
-        for (i, rule) in spec.rules.iter().enumerate() {
+        for (i, rule) in spec.rules_in_order().into_iter().enumerate() {
             let condition = rule
                 .as_cel()
                 .map(|cel| {
@@ -133,19 +133,24 @@ impl<'a> TypeScriptRenderer<'a> {
 
     /// DOGFOODING: Use generated type_mapping for TypeScript types
     fn render_type(&self, typ: &VarType) -> String {
-        use crate::generated::type_mapping::type_mapping;
+        use crate::generated::type_mapping::{self as mapping, type_mapping};
 
         match typ {
-            VarType::Bool => type_mapping("Bool".into(), "TypeScript".into()),
-            VarType::Int | VarType::Float => type_mapping("Int".into(), "TypeScript".into()), // Both are "number"
-            VarType::String => type_mapping("String".into(), "TypeScript".into()),
+            VarType::Bool => type_mapping(mapping::VarType::Bool, mapping::Target::TypeScript),
+            // Both are "number"
+            VarType::Int | VarType::Float => {
+                type_mapping(mapping::VarType::Int, mapping::Target::TypeScript)
+            }
+            VarType::String => type_mapping(mapping::VarType::String, mapping::Target::TypeScript),
             VarType::Enum(variants) => variants
                 .iter()
                 .map(|v| format!("\"{}\"", v))
                 .collect::<Vec<_>>()
                 .join(" | "),
             VarType::List(inner) => format!("{}[]", self.render_type(inner)),
-            VarType::Object => type_mapping("Object".into(), "TypeScript".into()),
+            VarType::Object(_) => {
+                type_mapping(mapping::VarType::Object, mapping::Target::TypeScript)
+            }
         }
     }
 
@@ -188,13 +193,13 @@ impl<'a> TypeScriptRenderer<'a> {
 
     /// DOGFOODING: Use generated bool_literal for boolean rendering
     fn render_bool(&self, value: bool) -> String {
-        use crate::generated::bool_literal::bool_literal;
-        bool_literal(value, "TypeScript".into())
+        use crate::generated::bool_literal::{bool_literal, Target};
+        bool_literal(value, Target::TypeScript)
     }
 
     /// DOGFOODING: Use generated null_literal for null rendering
     fn render_null(&self) -> String {
-        use crate::generated::null_literal::null_literal;
-        null_literal("TypeScript".into())
+        use crate::generated::null_literal::{null_literal, Target};
+        null_literal(Target::TypeScript)
     }
 }
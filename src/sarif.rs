@@ -0,0 +1,228 @@
+//! SARIF 2.1.0 output for CI
+//!
+//! Verification gaps, validation issues and completeness findings become
+//! SARIF results that code-scanning tools (e.g. GitHub Actions) can ingest.
+//! Findings about a rule point at the rule's `id:` line in the spec; code
+//! mismatches point at the code file.
+
+use crate::completeness::{IncompletenessReport, Severity, ValidationReport};
+use crate::spec::rule_lines;
+use crate::verify::VerificationResult;
+use serde_json::{json, Value};
+use std::collections::{BTreeSet, HashMap};
+
+/// Files the findings refer to
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SarifSource<'a> {
+    /// Spec path as it should appear in the report
+    pub spec_path: &'a str,
+    /// Spec YAML, used to find rule lines
+    pub spec_text: &'a str,
+    /// Code path, for verification results
+    pub code_path: Option<&'a str>,
+}
+
+/// A report that can be written as SARIF results
+pub trait SarifReport {
+    /// One SARIF `result` object per finding
+    fn sarif_results(&self, source: &SarifSource) -> Vec<Value>;
+}
+
+/// Wrap a report's findings in a single-run SARIF 2.1.0 log
+pub fn to_sarif(report: &impl SarifReport, source: &SarifSource) -> Value {
+    let results = report.sarif_results(source);
+    let rule_ids: BTreeSet<&str> = results
+        .iter()
+        .filter_map(|r| r["ruleId"].as_str())
+        .collect();
+    let rules: Vec<Value> = rule_ids.iter().map(|id| json!({ "id": id })).collect();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "imacs",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/outboundlabs/imacs",
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
+/// A SARIF result; `line` is zero-based and omitted when unknown
+fn result(rule_id: &str, level: &str, message: String, uri: &str, line: Option<usize>) -> Value {
+    let mut location = json!({ "artifactLocation": { "uri": uri } });
+    if let Some(line) = line {
+        location["region"] = json!({ "startLine": line + 1 });
+    }
+    json!({
+        "ruleId": rule_id,
+        "level": level,
+        "message": { "text": message },
+        "locations": [{ "physicalLocation": location }],
+    })
+}
+
+/// Line of the first rule in `ids` that can be found
+fn first_rule_line(lines: &HashMap<String, usize>, ids: &[String]) -> Option<usize> {
+    ids.iter().find_map(|id| lines.get(id)).copied()
+}
+
+impl SarifReport for ValidationReport {
+    fn sarif_results(&self, source: &SarifSource) -> Vec<Value> {
+        let lines = rule_lines(source.spec_text);
+        self.issues
+            .iter()
+            .map(|issue| {
+                let level = match issue.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                };
+                result(
+                    &issue.code,
+                    level,
+                    issue.message.clone(),
+                    source.spec_path,
+                    first_rule_line(&lines, &issue.affected_rules),
+                )
+            })
+            .collect()
+    }
+}
+
+impl SarifReport for IncompletenessReport {
+    fn sarif_results(&self, source: &SarifSource) -> Vec<Value> {
+        let lines = rule_lines(source.spec_text);
+        let rules_line = source
+            .spec_text
+            .lines()
+            .position(|line| line.trim_end() == "rules:");
+
+        let missing = self.missing_cases.iter().map(|case| {
+            result(
+                "IMACS_INCOMPLETE",
+                "warning",
+                format!("No rule covers: {}", case.cel_conditions.join(" && ")),
+                source.spec_path,
+                rules_line,
+            )
+        });
+        let overlaps = self.overlaps.iter().map(|overlap| {
+            result(
+                "IMACS_OVERLAP",
+                "note",
+                format!(
+                    "Rules {} all match when {}",
+                    overlap.rule_ids.join(", "),
+                    overlap.cel_conditions.join(" && ")
+                ),
+                source.spec_path,
+                first_rule_line(&lines, &overlap.rule_ids),
+            )
+        });
+        missing.chain(overlaps).collect()
+    }
+}
+
+impl SarifReport for VerificationResult {
+    fn sarif_results(&self, source: &SarifSource) -> Vec<Value> {
+        let lines = rule_lines(source.spec_text);
+        let code_path = source.code_path.unwrap_or(source.spec_path);
+
+        let gaps = self.gaps.iter().map(|gap| {
+            result(
+                "IMACS_GAP",
+                "error",
+                format!(
+                    "Rule {} ({:?}): expected {} => {}. {}",
+                    gap.rule_id,
+                    gap.reason,
+                    gap.expected_condition,
+                    gap.expected_output,
+                    gap.suggestion
+                ),
+                source.spec_path,
+                lines.get(&gap.rule_id).copied(),
+            )
+        });
+        let mismatches = self.mismatches.iter().map(|mismatch| {
+            let inputs: Vec<String> = mismatch
+                .inputs
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect();
+            result(
+                "IMACS_MISMATCH",
+                "error",
+                format!(
+                    "For {} the spec returns {} but the code returns {}",
+                    inputs.join(", "),
+                    mismatch.spec_output,
+                    mismatch.code_output
+                ),
+                code_path,
+                None,
+            )
+        });
+        gaps.chain(mismatches).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::completeness::{IssueType, ValidationIssue};
+
+    fn issue(code: &str, rule: &str) -> ValidationIssue {
+        ValidationIssue {
+            code: code.into(),
+            severity: Severity::Error,
+            issue_type: IssueType::ContradictoryRules,
+            message: format!("{} problem", code),
+            affected_rules: vec![rule.into()],
+            explanation: None,
+            suggestion: None,
+            fix_example: None,
+            context: None,
+        }
+    }
+
+    #[test]
+    fn test_validation_report_to_sarif() {
+        let spec_text = "id: access\nrules:\n  - id: R1\n    when: a\n    then: true\n  - id: R2\n    when: a\n    then: false\n";
+        let report = ValidationReport {
+            is_valid: false,
+            issues: vec![issue("C001", "R1"), issue("C002", "R2")],
+            fixes: vec![],
+            error_count: 2,
+            warning_count: 0,
+        };
+        let source = SarifSource {
+            spec_path: "specs/access.yaml",
+            spec_text,
+            code_path: None,
+        };
+
+        let sarif = to_sarif(&report, &source);
+        assert_eq!(sarif["version"], "2.1.0");
+        let results = sarif["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "C001");
+        assert_eq!(results[1]["ruleId"], "C002");
+        assert!(results.iter().all(|r| r["level"] == "error"));
+
+        let location = &results[1]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "specs/access.yaml");
+        assert_eq!(location["region"]["startLine"], 6);
+
+        let rules = sarif["runs"][0]["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap();
+        assert_eq!(rules.len(), 2);
+    }
+}
@@ -35,11 +35,13 @@
 //!     then: 200
 //! ```
 
+use crate::cel::CelCompiler;
 use crate::error::{Error, Result};
 use crate::render::ScopingConfig;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// A complete specification
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
@@ -72,6 +74,40 @@ pub struct Spec {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default: Option<Output>,
 
+    /// How matching rules combine into the output
+    #[serde(default, skip_serializing_if = "SpecMode::is_first_match")]
+    pub mode: SpecMode,
+
+    /// What generated code does when no rule matches (defaults to returning
+    /// `default` when there is one, else panicking)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_no_match: Option<OnNoMatch>,
+
+    /// Postconditions the output must satisfy, as CEL over `result`
+    ///
+    /// `result` is the output value, or for several outputs an object with
+    /// one field per output (`result.fee >= 0`). [`Spec::evaluate`] fails
+    /// when one does not hold; rendering with `check_ensures` adds the
+    /// same checks to generated code.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ensures: Vec<String>,
+
+    /// Accept the non-standard bitwise operators `&`, `|`, `^`, `<<` and `>>`
+    /// in conditions, e.g. `flags & 4 != 0` for permission bitmasks
+    ///
+    /// They bind tighter than comparisons, as in Rust and Go, and render to
+    /// each target's native operators. Off by default so specs stay portable
+    /// CEL.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_bitwise: bool,
+
+    /// Schema version the spec was written against (1 when absent)
+    ///
+    /// Specs from older versions are migrated as they load; see
+    /// [`crate::migrate`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<u32>,
+
     /// Metadata
     #[serde(default, skip_serializing_if = "SpecMeta::is_empty")]
     pub meta: SpecMeta,
@@ -82,14 +118,52 @@ pub struct Spec {
     pub scoping: Option<ScopingConfig>,
 }
 
+/// How a spec's matching rules produce its output
+///
+/// With `accumulate`, the numeric `then` values of every matching rule are
+/// summed, starting from `default` (0 when absent), e.g. a risk score where
+/// several rules each add points.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SpecMode {
+    /// The first matching rule, in priority order, wins
+    #[default]
+    FirstMatch,
+    /// Every matching rule contributes to a sum
+    Accumulate,
+}
+
+impl SpecMode {
+    pub fn is_first_match(&self) -> bool {
+        *self == SpecMode::FirstMatch
+    }
+}
+
+/// Behaviour of generated code for inputs no rule matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OnNoMatch {
+    /// Abort (`unreachable!`, `panic`, `fatalError`, thrown exception)
+    Panic,
+    /// Return the spec's `default` output
+    ReturnDefault,
+    /// Return a language-idiomatic error: `Result::Err` in Rust,
+    /// `(zero, error)` in Go, `throws` in Swift, a thrown exception elsewhere
+    Error,
+}
+
 /// A variable (input or output)
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+///
+/// An input that may be absent is marked `optional: true`, or written with
+/// the shorthand `type: string?`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct Variable {
     /// Variable name
     pub name: String,
 
     /// Variable type
     #[serde(rename = "type")]
+    #[schemars(with = "TypeDecl")]
     pub typ: VarType,
 
     /// Description
@@ -99,22 +173,446 @@ pub struct Variable {
     /// For enums: valid values
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
+
+    /// Input may be null/absent
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+
+    /// For ints: inclusive bounds `[min, max]` of the values the input takes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<(i64, i64)>,
+}
+
+impl Variable {
+    /// Every value a bounded int input can take, when it declares a `range`
+    pub fn range_values(&self) -> Option<std::ops::RangeInclusive<i64>> {
+        match (&self.typ, self.range) {
+            (VarType::Int, Some((min, max))) => Some(min..=max),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Variable {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct VariableDef {
+            name: String,
+            #[serde(rename = "type")]
+            typ: TypeDecl,
+            #[serde(default)]
+            description: Option<String>,
+            #[serde(default)]
+            values: Option<Vec<String>>,
+            #[serde(default)]
+            optional: bool,
+            #[serde(default)]
+            range: Option<(i64, i64)>,
+        }
+
+        let def = VariableDef::deserialize(deserializer)?;
+        if let Some((min, max)) = def.range {
+            if def.typ.typ != VarType::Int {
+                return Err(serde::de::Error::custom(format!(
+                    "input '{}': range is only allowed on int inputs",
+                    def.name
+                )));
+            }
+            if min > max {
+                return Err(serde::de::Error::custom(format!(
+                    "input '{}': range [{}, {}] is empty",
+                    def.name, min, max
+                )));
+            }
+        }
+        Ok(Variable {
+            name: def.name,
+            typ: def.typ.typ,
+            description: def.description,
+            values: def.values,
+            optional: def.optional || def.typ.optional,
+            range: def.range,
+        })
+    }
+}
+
+/// A declared type, which may carry the `?` optional shorthand (`string?`)
+struct TypeDecl {
+    typ: VarType,
+    optional: bool,
+}
+
+// A type, or a type name with the `?` shorthand for an optional input
+impl JsonSchema for TypeDecl {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "TypeDecl".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "oneOf": [
+                generator.subschema_for::<VarType>(),
+                {
+                    "description": "Optional input, e.g. string? or list<int>?",
+                    "type": "string",
+                    "pattern": "^(bool|int|float|string|object|list<.+>)\\?$"
+                }
+            ]
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for TypeDecl {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Visitor;
+
+        struct TypeDeclVisitor;
+
+        impl<'de> Visitor<'de> for TypeDeclVisitor {
+            type Value = TypeDecl;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                VarTypeVisitor.expecting(f)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> std::result::Result<TypeDecl, E> {
+                let (name, optional) = match v.trim().strip_suffix('?') {
+                    Some(name) => (name, true),
+                    None => (v, false),
+                };
+                Ok(TypeDecl {
+                    typ: VarTypeVisitor.visit_str(name)?,
+                    optional,
+                })
+            }
+
+            fn visit_enum<A>(self, data: A) -> std::result::Result<TypeDecl, A::Error>
+            where
+                A: serde::de::EnumAccess<'de>,
+            {
+                Ok(TypeDecl {
+                    typ: VarTypeVisitor.visit_enum(data)?,
+                    optional: false,
+                })
+            }
+
+            fn visit_map<A>(self, map: A) -> std::result::Result<TypeDecl, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                Ok(TypeDecl {
+                    typ: VarTypeVisitor.visit_map(map)?,
+                    optional: false,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(TypeDeclVisitor)
+    }
+}
+
+/// Optional inputs that `cel` reads without checking for null itself
+///
+/// A condition on such an input can only hold when the input is present.
+pub fn unguarded_optionals<'a>(cel: &str, inputs: &'a [Variable]) -> Vec<&'a Variable> {
+    let referenced = CelCompiler::extract_variables(cel).unwrap_or_default();
+    let checked = CelCompiler::null_checked_variables(cel).unwrap_or_default();
+    inputs
+        .iter()
+        .filter(|input| input.optional && referenced.contains(&input.name))
+        .filter(|input| !checked.contains(&input.name))
+        .collect()
+}
+
+/// Prefix `cel` with a `name != null` guard for each of its [`unguarded_optionals`]
+pub fn guard_optionals(cel: &str, inputs: &[Variable]) -> String {
+    unguarded_optionals(cel, inputs)
+        .iter()
+        .rev()
+        .fold(cel.to_string(), |cel, input| {
+            format!("{} != null && ({})", input.name, cel)
+        })
 }
 
 /// Variable types
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
-#[serde(rename_all = "lowercase")]
-#[derive(Default)]
+///
+/// In YAML a type is a name (`int`), `list<T>` (e.g. `list<string>`), or a
+/// tagged form such as `!enum [a, b]` / `!list string`. Objects with typed
+/// fields use `!object {tier: string, total: float}`; a bare `object` has no
+/// declared fields.
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum VarType {
     Bool,
     Int,
     Float,
     #[default]
     String,
-    #[serde(rename = "enum")]
     Enum(Vec<String>),
     List(Box<VarType>),
-    Object,
+    /// Object with named, ordered fields (empty for an untyped object)
+    Object(Vec<(String, VarType)>),
+}
+
+impl VarType {
+    /// Declared fields of an object type (empty for everything else)
+    pub fn object_fields(&self) -> &[(String, VarType)] {
+        match self {
+            VarType::Object(fields) => fields,
+            _ => &[],
+        }
+    }
+
+    /// JSON Schema (draft-07) fragment describing values of this type
+    pub fn json_schema(&self) -> serde_json::Value {
+        use serde_json::json;
+        match self {
+            VarType::Bool => json!({"type": "boolean"}),
+            VarType::Int => json!({"type": "integer"}),
+            VarType::Float => json!({"type": "number"}),
+            VarType::String => json!({"type": "string"}),
+            VarType::Enum(variants) => json!({"type": "string", "enum": variants}),
+            VarType::List(inner) => json!({"type": "array", "items": inner.json_schema()}),
+            VarType::Object(fields) if fields.is_empty() => json!({"type": "object"}),
+            VarType::Object(fields) => {
+                let properties: serde_json::Map<_, _> = fields
+                    .iter()
+                    .map(|(name, typ)| (name.clone(), typ.json_schema()))
+                    .collect();
+                let required: Vec<_> = fields.iter().map(|(name, _)| name.clone()).collect();
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": required,
+                    "additionalProperties": false,
+                })
+            }
+        }
+    }
+}
+
+impl Serialize for VarType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            VarType::Bool => serializer.serialize_unit_variant("VarType", 0, "bool"),
+            VarType::Int => serializer.serialize_unit_variant("VarType", 1, "int"),
+            VarType::Float => serializer.serialize_unit_variant("VarType", 2, "float"),
+            VarType::String => serializer.serialize_unit_variant("VarType", 3, "string"),
+            VarType::Enum(values) => {
+                serializer.serialize_newtype_variant("VarType", 4, "enum", values)
+            }
+            VarType::List(inner) => {
+                serializer.serialize_newtype_variant("VarType", 5, "list", inner)
+            }
+            // A field-less object stays `object` so existing spec hashes are unchanged
+            VarType::Object(fields) if fields.is_empty() => {
+                serializer.serialize_unit_variant("VarType", 6, "object")
+            }
+            VarType::Object(fields) => {
+                serializer.serialize_newtype_variant("VarType", 6, "object", &FieldsRef(fields))
+            }
+        }
+    }
+}
+
+/// Ordered `name: type` mapping for object fields
+struct ObjectFields(Vec<(String, VarType)>);
+
+/// Borrowed form of [`ObjectFields`] for serialization
+struct FieldsRef<'a>(&'a [(String, VarType)]);
+
+impl Serialize for FieldsRef<'_> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, typ) in &self.0 {
+            map.serialize_entry(name, typ)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct FieldsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FieldsVisitor {
+            type Value = ObjectFields;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a mapping of field names to types")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> std::result::Result<ObjectFields, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut fields = Vec::new();
+                while let Some((name, typ)) = map.next_entry::<String, VarType>()? {
+                    fields.push((name, typ));
+                }
+                Ok(ObjectFields(fields))
+            }
+        }
+
+        deserializer.deserialize_map(FieldsVisitor)
+    }
+}
+
+impl std::str::FromStr for VarType {
+    type Err = String;
+
+    /// Parse a type name such as `int` or `list<string>`
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(inner) = s.strip_prefix("list<").and_then(|r| r.strip_suffix('>')) {
+            return Ok(VarType::List(Box::new(inner.parse()?)));
+        }
+        match s {
+            "bool" => Ok(VarType::Bool),
+            "int" => Ok(VarType::Int),
+            "float" => Ok(VarType::Float),
+            "string" => Ok(VarType::String),
+            "object" => Ok(VarType::Object(vec![])),
+            _ => Err(format!(
+                "unknown type '{}' (expected bool, int, float, string, object, list<T>, or !enum [...])",
+                s
+            )),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for VarType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(VarTypeVisitor)
+    }
+}
+
+// Hand-written to match the serde format above rather than the enum's shape
+impl JsonSchema for VarType {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "VarType".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        let var_type = generator.subschema_for::<VarType>();
+        schemars::json_schema!({
+            "description": "Variable types",
+            "oneOf": [
+                {
+                    "type": "string",
+                    "enum": ["bool", "int", "float", "string", "object"]
+                },
+                {
+                    "description": "List with element type, e.g. list<string>",
+                    "type": "string",
+                    "pattern": "^list<.+>$"
+                },
+                {
+                    "type": "object",
+                    "required": ["enum"],
+                    "properties": {
+                        "enum": {"type": "array", "items": {"type": "string"}}
+                    },
+                    "additionalProperties": false
+                },
+                {
+                    "type": "object",
+                    "required": ["list"],
+                    "properties": {"list": var_type},
+                    "additionalProperties": false
+                },
+                {
+                    "description": "Object with typed fields, e.g. {\"object\": {\"tier\": \"string\"}}",
+                    "type": "object",
+                    "required": ["object"],
+                    "properties": {
+                        "object": {"type": "object", "additionalProperties": var_type}
+                    },
+                    "additionalProperties": false
+                }
+            ]
+        })
+    }
+}
+
+struct VarTypeVisitor;
+
+impl<'de> serde::de::Visitor<'de> for VarTypeVisitor {
+    type Value = VarType;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a type name, list<T>, or a tagged enum/list/object type")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> std::result::Result<VarType, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    // YAML tags: `!enum [a, b]`, `!list string`, `!object {tier: string}`
+    fn visit_enum<A>(self, data: A) -> std::result::Result<VarType, A::Error>
+    where
+        A: serde::de::EnumAccess<'de>,
+    {
+        use serde::de::{Error as _, VariantAccess};
+
+        let (tag, variant): (String, _) = data.variant()?;
+        match tag.as_str() {
+            "enum" => variant.newtype_variant().map(VarType::Enum),
+            "list" => variant
+                .newtype_variant()
+                .map(|inner| VarType::List(Box::new(inner))),
+            "object" => variant
+                .newtype_variant()
+                .map(|ObjectFields(fields)| VarType::Object(fields)),
+            other => {
+                variant.unit_variant()?;
+                other.parse().map_err(A::Error::custom)
+            }
+        }
+    }
+
+    // JSON: `{"enum": [...]}`, `{"list": "string"}`, `{"object": {...}}`
+    fn visit_map<A>(self, mut map: A) -> std::result::Result<VarType, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error as _;
+
+        let key: String = map
+            .next_key()?
+            .ok_or_else(|| A::Error::custom("empty type mapping"))?;
+        match key.as_str() {
+            "enum" => map.next_value().map(VarType::Enum),
+            "list" => map.next_value().map(|inner| VarType::List(Box::new(inner))),
+            "object" => map
+                .next_value()
+                .map(|ObjectFields(fields)| VarType::Object(fields)),
+            other => Err(A::Error::unknown_field(other, &["enum", "list", "object"])),
+        }
+    }
 }
 
 /// Condition clause - can be a single CEL expression or an array (AND'd together)
@@ -176,7 +674,12 @@ impl From<String> for WhenClause {
 }
 
 /// A decision rule
+///
+/// Besides `when`, a rule may list its conditions under `when_any` (at
+/// least one holds, `a || b`) or `when_all` (every one holds, like a `when`
+/// array); both fold into `when` as the rule is read.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(try_from = "RawRule")]
 pub struct Rule {
     /// Rule identifier
     pub id: String,
@@ -193,7 +696,8 @@ pub struct Rule {
     #[serde(rename = "then")]
     pub then: Output,
 
-    /// Priority (lower = higher priority)
+    /// Priority (lower = higher priority): rules are tried from the lowest
+    /// priority up, in declaration order among equals
     #[serde(default)]
     pub priority: i32,
 
@@ -202,6 +706,84 @@ pub struct Rule {
     pub description: Option<String>,
 }
 
+/// A rule as written, before `when_any`/`when_all` fold into `when`
+#[derive(Deserialize, JsonSchema)]
+struct RawRule {
+    /// Rule identifier
+    id: String,
+    /// CEL condition expression(s) - can be a single string or array of strings
+    when: Option<WhenClause>,
+    /// CEL conditions of which at least one must hold (OR'd together)
+    when_any: Option<Vec<String>>,
+    /// CEL conditions that must all hold (AND'd together)
+    when_all: Option<Vec<String>>,
+    /// Structured conditions (alternative to CEL)
+    conditions: Option<Vec<Condition>>,
+    /// Output value(s)
+    then: Output,
+    /// Priority (lower = higher priority): rules are tried from the lowest
+    /// priority up, in declaration order among equals
+    #[serde(default)]
+    priority: i32,
+    /// Description
+    description: Option<String>,
+}
+
+impl TryFrom<RawRule> for Rule {
+    type Error = String;
+
+    fn try_from(raw: RawRule) -> std::result::Result<Self, String> {
+        let given: Vec<&str> = [
+            ("when", raw.when.is_some()),
+            ("when_any", raw.when_any.is_some()),
+            ("when_all", raw.when_all.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(key, set)| set.then_some(key))
+        .collect();
+        if given.len() > 1 {
+            return Err(format!(
+                "rule '{}' has both {} and {}; use only one",
+                raw.id, given[0], given[1]
+            ));
+        }
+
+        let when = match (raw.when, raw.when_any, raw.when_all) {
+            (Some(when), _, _) => Some(when),
+            (_, Some(any), _) if any.is_empty() => {
+                return Err(format!(
+                    "rule '{}' has an empty when_any, which can never match",
+                    raw.id
+                ))
+            }
+            // `||` binds looser than everything but `?:`
+            (_, Some(any), _) => Some(WhenClause::Single(
+                any.iter()
+                    .map(|c| {
+                        if any.len() > 1 && c.contains('?') {
+                            format!("({})", c)
+                        } else {
+                            c.clone()
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" || "),
+            )),
+            (_, _, Some(all)) => Some(WhenClause::Multiple(all)),
+            (None, None, None) => None,
+        };
+
+        Ok(Rule {
+            id: raw.id,
+            when,
+            conditions: raw.conditions,
+            then: raw.then,
+            priority: raw.priority,
+            description: raw.description,
+        })
+    }
+}
+
 /// A structured condition
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct Condition {
@@ -342,10 +924,38 @@ impl SpecMeta {
 
 impl Spec {
     /// Parse spec from YAML string
+    ///
+    /// Specs written against an older `schema_version` are migrated first.
     pub fn from_yaml(yaml: &str) -> Result<Self> {
+        let mut doc: serde_norway::Value =
+            serde_norway::from_str(yaml).map_err(|e| Error::SpecParse(e.to_string()))?;
+        if crate::migrate::migrate_document(&mut doc)? {
+            return serde_norway::from_value(doc).map_err(|e| Error::SpecParse(e.to_string()));
+        }
+        // Unchanged specs parse from the text so errors keep their location
         serde_norway::from_str(yaml).map_err(|e| Error::SpecParse(e.to_string()))
     }
 
+    /// Parse spec from YAML, resolving `include:` fragments
+    ///
+    /// `include:` names one file or a list of files, relative to the
+    /// directory of `path` (the spec's own file). Each fragment contributes
+    /// its `inputs` and `outputs`; later fragments, and then the spec's own
+    /// definitions, override earlier ones with the same name. Fragments may
+    /// include further fragments.
+    pub fn from_yaml_with_base(yaml: &str, path: &Path) -> Result<Self> {
+        let mut doc: serde_norway::Value =
+            serde_norway::from_str(yaml).map_err(|e| Error::SpecParse(e.to_string()))?;
+        let mut chain = vec![path.canonicalize().unwrap_or_else(|_| path.to_path_buf())];
+        resolve_includes(
+            &mut doc,
+            path.parent().unwrap_or(Path::new(".")),
+            &mut chain,
+        )?;
+        crate::migrate::migrate_document(&mut doc)?;
+        serde_norway::from_value(doc).map_err(|e| Error::SpecParse(e.to_string()))
+    }
+
     /// Serialize spec to YAML string
     pub fn to_yaml(&self) -> Result<String> {
         serde_norway::to_string(self).map_err(|e| Error::SpecParse(e.to_string()))
@@ -366,6 +976,16 @@ impl Spec {
         self.rules.iter().find(|r| r.id == id)
     }
 
+    /// Rules in the order they are tried, lowest priority first
+    ///
+    /// The interpreter, the renderers and verification all walk rules in
+    /// this order, so they agree on which rule fires.
+    pub fn rules_in_order(&self) -> Vec<&Rule> {
+        let mut rules: Vec<&Rule> = self.rules.iter().collect();
+        rules.sort_by(|a, b| Rule::evaluation_order(a, b));
+        rules
+    }
+
     /// Compute hash of spec for change detection
     pub fn hash(&self) -> String {
         use sha2::{Digest, Sha256};
@@ -375,6 +995,16 @@ impl Spec {
         format!("sha256:{}", hex::encode(&hasher.finalize()[..8]))
     }
 
+    /// JSON Schema (draft-07) for an object holding the spec's inputs
+    pub fn input_json_schema(&self) -> serde_json::Value {
+        variables_json_schema(&format!("{} input", self.id), &self.inputs)
+    }
+
+    /// JSON Schema (draft-07) for an object holding the spec's outputs
+    pub fn output_json_schema(&self) -> serde_json::Value {
+        variables_json_schema(&format!("{} output", self.id), &self.outputs)
+    }
+
     /// Validate spec for completeness
     pub fn validate(&self) -> Vec<String> {
         let mut errors = Vec::new();
@@ -416,8 +1046,38 @@ impl Spec {
             }
         }
 
+        for ensure in &self.ensures {
+            match crate::cel::CelCompiler::extract_variables(ensure) {
+                Ok(names) => {
+                    if let Some(name) = names.iter().find(|n| *n != "result") {
+                        errors.push(format!(
+                            "ensures '{}' references '{}'; only `result` is in scope",
+                            ensure, name
+                        ));
+                    }
+                }
+                Err(e) => errors.push(format!("ensures '{}' is not valid CEL: {}", ensure, e)),
+            }
+        }
+
+        if self.mode == SpecMode::Accumulate
+            && !matches!(
+                self.outputs.as_slice(),
+                [Variable {
+                    typ: VarType::Int | VarType::Float,
+                    ..
+                }]
+            )
+        {
+            errors.push("Accumulate mode requires a single int or float output".into());
+        }
+
+        if self.on_no_match == Some(OnNoMatch::ReturnDefault) && self.default.is_none() {
+            errors.push("on_no_match: return_default requires a default output".into());
+        }
+
         // PY-2: Warn if no default rule (exhaustiveness not guaranteed)
-        if self.default.is_none() && !self.rules.is_empty() {
+        if self.default.is_none() && !self.rules.is_empty() && self.mode == SpecMode::FirstMatch {
             errors.push("Warning: No default rule - exhaustiveness not guaranteed".into());
         }
 
@@ -425,7 +1085,158 @@ impl Spec {
     }
 }
 
+/// Sections an `include:` fragment contributes, merged by variable name
+const INCLUDED_SECTIONS: [&str; 2] = ["inputs", "outputs"];
+
+/// Replace `doc`'s `include:` key with the fragments' inputs and outputs
+///
+/// `chain` holds the files currently being resolved, innermost last, so a
+/// fragment that includes itself (directly or not) is reported as a cycle.
+fn resolve_includes(
+    doc: &mut serde_norway::Value,
+    base: &Path,
+    chain: &mut Vec<PathBuf>,
+) -> Result<()> {
+    use serde_norway::Value;
+
+    let Some(mapping) = doc.as_mapping_mut() else {
+        return Ok(());
+    };
+    let files: Vec<String> = match mapping.remove("include") {
+        None => return Ok(()),
+        Some(Value::String(file)) => vec![file],
+        Some(list) => serde_norway::from_value(list)
+            .map_err(|e| Error::SpecParse(format!("include: {}", e)))?,
+    };
+
+    let mut merged: [Vec<Value>; 2] = Default::default();
+    for file in &files {
+        let path = base.join(file);
+        let canonical = path
+            .canonicalize()
+            .map_err(|e| Error::SpecParse(format!("include '{}': {}", path.display(), e)))?;
+        if chain.contains(&canonical) {
+            let cycle: Vec<String> = chain
+                .iter()
+                .skip_while(|p| **p != canonical)
+                .chain(std::iter::once(&canonical))
+                .map(|p| p.display().to_string())
+                .collect();
+            return Err(Error::SpecParse(format!(
+                "include cycle: {}",
+                cycle.join(" -> ")
+            )));
+        }
+
+        let content = std::fs::read_to_string(&canonical).map_err(Error::Io)?;
+        let mut fragment: Value = serde_norway::from_str(&content)
+            .map_err(|e| Error::SpecParse(format!("{}: {}", canonical.display(), e)))?;
+        chain.push(canonical.clone());
+        resolve_includes(
+            &mut fragment,
+            canonical.parent().unwrap_or(Path::new(".")),
+            chain,
+        )?;
+        chain.pop();
+
+        for (section, vars) in INCLUDED_SECTIONS.iter().zip(merged.iter_mut()) {
+            if let Some(Value::Sequence(items)) = fragment.get(*section) {
+                merge_by_name(vars, items);
+            }
+        }
+    }
+
+    for (section, mut vars) in INCLUDED_SECTIONS.into_iter().zip(merged) {
+        if let Some(Value::Sequence(local)) = mapping.get(section) {
+            merge_by_name(&mut vars, local);
+        }
+        if !vars.is_empty() {
+            mapping.insert(Value::from(section), Value::Sequence(vars));
+        }
+    }
+    Ok(())
+}
+
+/// Append `items` to `vars`, replacing any entry with the same `name` in place
+fn merge_by_name(vars: &mut Vec<serde_norway::Value>, items: &[serde_norway::Value]) {
+    for item in items {
+        let existing = item.get("name").and_then(|name| {
+            vars.iter()
+                .position(|v| v.get("name").is_some_and(|n| n == name))
+        });
+        match existing {
+            Some(i) => vars[i] = item.clone(),
+            None => vars.push(item.clone()),
+        }
+    }
+}
+
+/// Zero-based line of each `id:` under the top-level `rules:` key
+///
+/// A line scan rather than a YAML parse, so it also works on specs that
+/// no longer parse. Used to point diagnostics at the offending rule.
+pub(crate) fn rule_lines(yaml: &str) -> HashMap<String, usize> {
+    let mut lines = HashMap::new();
+    let mut in_rules = false;
+    for (line_no, line) in yaml.lines().enumerate() {
+        let top_level = !line.trim().is_empty() && !line.starts_with([' ', '-', '#']);
+        if top_level {
+            in_rules = line.trim_end() == "rules:";
+            continue;
+        }
+        if !in_rules {
+            continue;
+        }
+        let entry = line.trim_start().trim_start_matches('-').trim_start();
+        if let Some(id) = entry.strip_prefix("id:") {
+            let id = id.trim().trim_matches(['"', '\'']);
+            lines.entry(id.to_string()).or_insert(line_no);
+        }
+    }
+    lines
+}
+
+/// Object schema with a property per variable, required unless optional
+fn variables_json_schema(title: &str, vars: &[Variable]) -> serde_json::Value {
+    let properties: serde_json::Map<_, _> = vars
+        .iter()
+        .map(|var| {
+            let mut schema = var.typ.json_schema();
+            if let (VarType::String, Some(values)) = (&var.typ, &var.values) {
+                schema["enum"] = serde_json::json!(values);
+            }
+            if var.optional {
+                schema = serde_json::json!({"anyOf": [schema, {"type": "null"}]});
+            }
+            if let Some(description) = &var.description {
+                schema["description"] = serde_json::json!(description);
+            }
+            (var.name.clone(), schema)
+        })
+        .collect();
+    let required: Vec<_> = vars
+        .iter()
+        .filter(|v| !v.optional)
+        .map(|v| v.name.clone())
+        .collect();
+    serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": title,
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
 impl Rule {
+    /// Which of two rules is tried first: the lower `priority`
+    ///
+    /// Stable sorts with this keep equal priorities in declaration order.
+    pub fn evaluation_order(a: &Rule, b: &Rule) -> std::cmp::Ordering {
+        a.priority.cmp(&b.priority)
+    }
+
     /// Get condition as CEL expression
     pub fn as_cel(&self) -> Option<String> {
         if let Some(when_clause) = &self.when {
@@ -521,6 +1332,11 @@ rules:
             outputs: vec![],
             rules: vec![],
             default: None,
+            mode: Default::default(),
+            on_no_match: None,
+            ensures: Vec::new(),
+            allow_bitwise: false,
+            schema_version: None,
             meta: SpecMeta::default(),
             scoping: None,
         };
@@ -575,6 +1391,62 @@ rules:
         assert_eq!(clause.to_cel(), "true");
     }
 
+    #[test]
+    fn test_when_any_and_when_all() {
+        let spec_with = |rule: &str| {
+            Spec::from_yaml(&format!(
+                r#"
+id: access
+inputs:
+  - name: a
+    type: bool
+  - name: b
+    type: bool
+outputs:
+  - name: allowed
+    type: bool
+rules:
+  - id: R1
+{}
+    then: true
+default: false
+"#,
+                rule
+            ))
+        };
+        let any = spec_with("    when_any: [\"a\", \"b\"]").unwrap();
+        let cel = spec_with("    when: \"a || b\"").unwrap();
+        assert_eq!(any.rules[0].as_cel().unwrap(), "a || b");
+
+        // Same generated code and the same answers as the CEL it stands for
+        let rust = crate::templates::render_spec(&any, crate::cel::Target::Rust, false).unwrap();
+        assert!(rust.contains("a || b"), "{}", rust);
+        assert_eq!(
+            rust,
+            crate::templates::render_spec(&cel, crate::cel::Target::Rust, false).unwrap()
+        );
+        for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+            let json = serde_json::json!({"a": a, "b": b});
+            let inputs = json.as_object().unwrap();
+            assert_eq!(any.evaluate(inputs).unwrap(), cel.evaluate(inputs).unwrap());
+        }
+        assert_eq!(
+            crate::completeness::analyze_completeness(&any).is_complete,
+            crate::completeness::analyze_completeness(&cel).is_complete
+        );
+
+        let all = spec_with("    when_all: [\"a\", \"b\"]").unwrap();
+        assert_eq!(all.rules[0].as_cel().unwrap(), "(a) && (b)");
+
+        let err = spec_with("    when: a\n    when_any: [b]").unwrap_err();
+        assert!(
+            err.to_string().contains("both when and when_any"),
+            "{}",
+            err
+        );
+        assert!(spec_with("    when_any: []").is_err());
+    }
+
     #[test]
     fn test_parse_yaml_with_array_when() {
         let yaml = r#"
@@ -631,4 +1503,264 @@ rules:
         let spec = Spec::from_yaml(yaml).unwrap();
         assert_eq!(spec.rules[0].as_cel(), Some("x && y".into()));
     }
+
+    #[test]
+    fn test_parse_list_type() {
+        let yaml = r#"
+id: cart_check
+inputs:
+  - name: item_flags
+    type: list<string>
+  - name: tier
+    type: !enum [gold, silver]
+  - name: matrix
+    type: list<list<int>>
+outputs:
+  - name: flagged
+    type: bool
+rules:
+  - id: R1
+    when: "item_flags.exists(f, f == 'fraud')"
+    then: true
+"#;
+        let spec = Spec::from_yaml(yaml).unwrap();
+        assert_eq!(spec.inputs[0].typ, VarType::List(Box::new(VarType::String)));
+        assert_eq!(
+            spec.inputs[1].typ,
+            VarType::Enum(vec!["gold".into(), "silver".into()])
+        );
+        assert_eq!(
+            spec.inputs[2].typ,
+            VarType::List(Box::new(VarType::List(Box::new(VarType::Int))))
+        );
+
+        // Serialized form reads back unchanged
+        let reparsed = Spec::from_yaml(&spec.to_yaml().unwrap()).unwrap();
+        assert_eq!(reparsed.inputs[0].typ, spec.inputs[0].typ);
+
+        assert!("list<nope>".parse::<VarType>().is_err());
+    }
+
+    #[test]
+    fn test_parse_object_fields() {
+        let yaml = r#"
+id: checkout
+inputs:
+  - name: user
+    type: !object
+      tier: string
+      address: !object { country: string }
+  - name: extra
+    type: object
+outputs:
+  - name: discount
+    type: float
+rules:
+  - id: R1
+    when: "user.tier == 'gold' && user.address.country == 'US'"
+    then: 0.2
+default: 0.0
+"#;
+        let spec = Spec::from_yaml(yaml).unwrap();
+        assert_eq!(
+            spec.inputs[0].typ,
+            VarType::Object(vec![
+                ("tier".into(), VarType::String),
+                (
+                    "address".into(),
+                    VarType::Object(vec![("country".into(), VarType::String)])
+                ),
+            ])
+        );
+        assert_eq!(spec.inputs[1].typ, VarType::Object(vec![]));
+
+        // Field order survives a round trip; a bare object stays `object`
+        let yaml_out = spec.to_yaml().unwrap();
+        assert!(yaml_out.contains("type: object"));
+        let reparsed = Spec::from_yaml(&yaml_out).unwrap();
+        assert_eq!(reparsed.inputs[0].typ, spec.inputs[0].typ);
+
+        let json = serde_json::to_string(&spec.inputs[0].typ).unwrap();
+        let from_json: VarType = serde_json::from_str(&json).unwrap();
+        assert_eq!(from_json, spec.inputs[0].typ);
+    }
+
+    #[test]
+    fn test_input_json_schema_validates_inputs() {
+        let spec = Spec::from_yaml(
+            r#"
+id: shipping
+inputs:
+  - name: weight
+    type: float
+  - name: items
+    type: int
+  - name: tier
+    type: !enum [gold, silver, bronze]
+  - name: tags
+    type: list<string>
+outputs:
+  - name: cost
+    type: float
+rules:
+  - id: R1
+    when: "weight > 10.0"
+    then: 5.0
+"#,
+        )
+        .unwrap();
+
+        let schema = spec.input_json_schema();
+        assert_eq!(schema["$schema"], "http://json-schema.org/draft-07/schema#");
+        assert_eq!(
+            schema["properties"]["tier"]["enum"],
+            serde_json::json!(["gold", "silver", "bronze"])
+        );
+        assert_eq!(
+            schema["required"],
+            serde_json::json!(["weight", "items", "tier", "tags"])
+        );
+
+        let valid = serde_json::json!({
+            "weight": 2.5,
+            "items": 3,
+            "tier": "gold",
+            "tags": ["fragile"],
+        });
+        assert!(jsonschema::is_valid(&schema, &valid));
+
+        let wrong_type = serde_json::json!({
+            "weight": 2.5,
+            "items": "three",
+            "tier": "gold",
+            "tags": [],
+        });
+        assert!(!jsonschema::is_valid(&schema, &wrong_type));
+
+        let bad_variant = serde_json::json!({
+            "weight": 2.5,
+            "items": 3,
+            "tier": "platinum",
+            "tags": [],
+        });
+        assert!(!jsonschema::is_valid(&schema, &bad_variant));
+
+        let output = spec.output_json_schema();
+        assert_eq!(output["properties"]["cost"]["type"], "number");
+    }
+
+    #[test]
+    fn test_include_shared_inputs() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("common.yaml"),
+            r#"
+inputs:
+  - name: tier
+    type: !enum [gold, silver]
+  - name: region
+    type: string
+  - name: total
+    type: int
+"#,
+        )
+        .unwrap();
+        let spec_path = dir.path().join("discount.yaml");
+        let yaml = r#"
+id: discount
+include: common.yaml
+inputs:
+  - name: total
+    type: float
+  - name: coupon
+    type: string
+outputs:
+  - name: rate
+    type: float
+rules:
+  - id: R1
+    when: "tier == 'gold'"
+    then: 0.1
+"#;
+
+        let spec = Spec::from_yaml_with_base(yaml, &spec_path).unwrap();
+        let names: Vec<&str> = spec.inputs.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, ["tier", "region", "total", "coupon"]);
+        // The spec's own definition overrides the included one
+        assert_eq!(spec.inputs[2].typ, VarType::Float);
+        assert_eq!(spec.outputs.len(), 1);
+    }
+
+    #[test]
+    fn test_include_cycle_is_an_error() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a.yaml"), "include: b.yaml\n").unwrap();
+        std::fs::write(dir.path().join("b.yaml"), "include: [a.yaml]\n").unwrap();
+        let yaml = "id: cyclic\ninclude: a.yaml\n";
+
+        let err = Spec::from_yaml_with_base(yaml, &dir.path().join("spec.yaml")).unwrap_err();
+        assert!(matches!(err, Error::SpecParse(ref msg) if msg.starts_with("include cycle:")));
+    }
+
+    #[test]
+    fn test_var_type_schema_follows_serde_format() {
+        let schema = serde_json::to_value(schemars::schema_for!(Spec)).unwrap();
+        let var_type = &schema["$defs"]["VarType"]["oneOf"];
+        let names = &var_type[0]["enum"];
+        assert_eq!(
+            names,
+            &serde_json::json!(["bool", "int", "float", "string", "object"])
+        );
+        assert_eq!(var_type[1]["pattern"], "^list<.+>$");
+        // Object fields are a name-to-type mapping, not the enum's tuple list
+        let fields = &var_type[4]["properties"]["object"];
+        assert_eq!(fields["type"], "object");
+        assert_eq!(fields["additionalProperties"]["$ref"], "#/$defs/VarType");
+    }
+
+    #[test]
+    fn test_variable_schema_accepts_optional_shorthand() {
+        let schema = serde_json::to_value(schemars::schema_for!(Spec)).unwrap();
+        let typ = &schema["$defs"]["Variable"]["properties"]["type"]["oneOf"];
+        assert_eq!(typ[0]["$ref"], "#/$defs/VarType");
+        let pattern = regex::Regex::new(typ[1]["pattern"].as_str().unwrap()).unwrap();
+        for name in ["string?", "int?", "list<string>?"] {
+            assert!(pattern.is_match(name), "{}", name);
+        }
+        for name in ["string", "list<string>", "strin?"] {
+            assert!(!pattern.is_match(name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_unguarded_optionals_reads_the_ast() {
+        let optional = |name: &str| Variable {
+            name: name.into(),
+            typ: VarType::String,
+            description: None,
+            values: None,
+            optional: true,
+            range: None,
+        };
+        let inputs = [optional("coupon"), optional("a_coupon"), optional("label")];
+        let unguarded = |cel: &str| -> Vec<String> {
+            unguarded_optionals(cel, &inputs)
+                .iter()
+                .map(|v| v.name.clone())
+                .collect()
+        };
+
+        assert!(unguarded("coupon!=null && coupon == 'X'").is_empty());
+        assert!(unguarded("null == coupon || coupon == 'X'").is_empty());
+        // Another input's check, or one quoted in a string, guards nothing
+        assert_eq!(unguarded("a_coupon != null && coupon == 'X'"), ["coupon"]);
+        assert_eq!(
+            unguarded("label == \"coupon != null\" && coupon == 'X'"),
+            ["coupon", "label"]
+        );
+        assert_eq!(
+            guard_optionals("coupon == 'X'", &inputs),
+            "coupon != null && (coupon == 'X')"
+        );
+    }
 }
@@ -0,0 +1,211 @@
+//! Spec splitting — break a large decision table into one spec per output
+//!
+//! The inverse of [`Spec::merge`](crate::merge): when one spec conflates
+//! unrelated decisions, partitioning its rules by what they return usually
+//! separates them, and each piece then needs only a few of the inputs.
+
+use crate::cel::CelCompiler;
+use crate::spec::{ConditionValue, Output, Rule, Spec};
+
+impl Spec {
+    /// Partition rules by their `then` value, one sub-spec per distinct output
+    ///
+    /// Sub-specs are returned in order of each output's first rule and are
+    /// named `{id}_{output}`. Rules whose output is computed from inputs
+    /// (e.g. `amount * 2`) go together into a catch-all `{id}_computed`
+    /// spec, which comes last. Each sub-spec keeps only the inputs its rules
+    /// reference, along with the original outputs, default and priorities.
+    pub fn split_by_output(&self) -> Vec<Spec> {
+        let mut groups: Vec<(Option<&Output>, Vec<&Rule>)> = Vec::new();
+        let mut computed: Vec<&Rule> = Vec::new();
+
+        for rule in &self.rules {
+            if self.is_computed(&rule.then) {
+                computed.push(rule);
+            } else if let Some((_, rules)) = groups.iter_mut().find(|(o, _)| *o == Some(&rule.then))
+            {
+                rules.push(rule);
+            } else {
+                groups.push((Some(&rule.then), vec![rule]));
+            }
+        }
+        if !computed.is_empty() {
+            groups.push((None, computed));
+        }
+
+        let mut ids: Vec<String> = Vec::new();
+        groups
+            .into_iter()
+            .enumerate()
+            .map(|(i, (output, rules))| {
+                let suffix = match output {
+                    Some(output) => output_slug(output),
+                    None => "computed".to_string(),
+                };
+                let mut id = format!("{}_{}", self.id, suffix);
+                if suffix.is_empty() || ids.contains(&id) {
+                    id = format!("{}_{}", self.id, i + 1);
+                }
+                ids.push(id.clone());
+                self.sub_spec(id, output, rules)
+            })
+            .collect()
+    }
+
+    fn sub_spec(&self, id: String, output: Option<&Output>, rules: Vec<&Rule>) -> Spec {
+        let mut referenced: Vec<String> = Vec::new();
+        for rule in &rules {
+            if let Some(cel) = rule.as_cel() {
+                referenced.extend(CelCompiler::extract_variables(&cel).unwrap_or_default());
+            }
+            for value in output_values(&rule.then) {
+                if let ConditionValue::String(s) = value {
+                    if crate::render::is_expression(s) {
+                        referenced.extend(CelCompiler::extract_variables(s).unwrap_or_default());
+                    }
+                }
+            }
+        }
+
+        let description = match output {
+            Some(output) => format!("Rules of {} that return {}", self.id, output),
+            None => format!("Rules of {} with computed outputs", self.id),
+        };
+
+        Spec {
+            id,
+            description: Some(description),
+            inputs: self
+                .inputs
+                .iter()
+                .filter(|input| referenced.contains(&input.name))
+                .cloned()
+                .collect(),
+            rules: rules.into_iter().cloned().collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Whether an output is an expression over this spec's inputs
+    fn is_computed(&self, output: &Output) -> bool {
+        output_values(output).into_iter().any(|value| match value {
+            ConditionValue::String(s) if crate::render::is_expression(s) => {
+                CelCompiler::extract_variables(s).is_ok_and(|names| {
+                    names
+                        .iter()
+                        .any(|n| self.inputs.iter().any(|i| &i.name == n))
+                })
+            }
+            _ => false,
+        })
+    }
+}
+
+fn output_values(output: &Output) -> Vec<&ConditionValue> {
+    match output {
+        Output::Single(value) => vec![value],
+        Output::Named(fields) => fields.values().collect(),
+    }
+}
+
+/// Identifier-safe form of an output value, empty when there is none
+fn output_slug(output: &Output) -> String {
+    let raw = match output {
+        Output::Single(ConditionValue::String(s)) => s.clone(),
+        Output::Single(value @ (ConditionValue::Bool(_) | ConditionValue::Int(_))) => {
+            value.to_string()
+        }
+        _ => return String::new(),
+    };
+    let slug: String = raw
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    slug.trim_matches('_').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_by_output_keeps_minimal_inputs() {
+        let spec = Spec::from_yaml(
+            r#"
+id: triage
+inputs:
+  - name: fraud_score
+    type: int
+  - name: amount
+    type: int
+  - name: vip
+    type: bool
+  - name: country
+    type: string
+outputs:
+  - name: action
+    type: string
+rules:
+  - id: R1
+    when: "fraud_score > 80"
+    then: block
+  - id: R2
+    when: "amount > 10000 && !vip"
+    then: review
+  - id: R3
+    when: "country == 'XX'"
+    then: block
+  - id: R4
+    when: "vip"
+    then: approve
+default: approve
+"#,
+        )
+        .unwrap();
+
+        let parts = spec.split_by_output();
+        let ids: Vec<&str> = parts.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["triage_block", "triage_review", "triage_approve"]);
+
+        let inputs =
+            |s: &Spec| -> Vec<String> { s.inputs.iter().map(|i| i.name.clone()).collect() };
+        assert_eq!(inputs(&parts[0]), ["fraud_score", "country"]);
+        assert_eq!(inputs(&parts[1]), ["amount", "vip"]);
+        assert_eq!(inputs(&parts[2]), ["vip"]);
+
+        let rules: Vec<&str> = parts[0].rules.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(rules, ["R1", "R3"]);
+    }
+
+    #[test]
+    fn test_split_puts_computed_outputs_last() {
+        let spec = Spec::from_yaml(
+            r#"
+id: fee
+inputs:
+  - name: amount
+    type: int
+  - name: waived
+    type: bool
+outputs:
+  - name: fee
+    type: int
+rules:
+  - id: R1
+    when: "waived"
+    then: 0
+  - id: R2
+    when: "amount > 100"
+    then: "amount / 10"
+"#,
+        )
+        .unwrap();
+
+        let parts = spec.split_by_output();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[1].id, "fee_computed");
+        assert_eq!(parts[1].inputs.len(), 1);
+        assert_eq!(parts[1].inputs[0].name, "amount");
+    }
+}
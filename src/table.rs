@@ -0,0 +1,495 @@
+//! Decision tables — specs as spreadsheet-style grids
+//!
+//! A decision table has one column per input followed by the output
+//! column(s). Each row is a rule; a blank or `*` cell means the rule does not
+//! care about that input. Specs are imported from CSV and exported to CSV or
+//! Markdown for review.
+
+use crate::cel::CelCompiler;
+use crate::error::{Error, Result};
+use crate::spec::{Condition, ConditionOp, ConditionValue, Output, Rule, Spec, VarType, Variable};
+use cel_parser::ast::{operators, Expr};
+use cel_parser::reference::Val;
+use cel_parser::Expression as CelExpr;
+use serde::Serialize;
+use std::io::Read;
+
+/// A spec laid out as a grid
+#[derive(Debug, Clone, Serialize)]
+pub struct DecisionTable {
+    /// Input column names
+    pub inputs: Vec<String>,
+    /// Output column names
+    pub outputs: Vec<String>,
+    /// One row per rule, then the default (if any)
+    pub rows: Vec<DecisionRow>,
+}
+
+/// One rule of a [`DecisionTable`]
+#[derive(Debug, Clone, Serialize)]
+pub struct DecisionRow {
+    /// Rule id (`default` for the fallback row)
+    pub id: String,
+    /// Condition value per input, `*` for don't-care
+    pub cells: Vec<String>,
+    /// Value per output
+    pub outputs: Vec<String>,
+    /// Set when the condition could not be flattened into the grid and the
+    /// raw CEL was kept in a cell instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Cell that leaves an input unconstrained
+fn is_dont_care(cell: &str) -> bool {
+    cell.is_empty() || cell == "*"
+}
+
+impl Spec {
+    /// Build a spec from a decision-table CSV
+    ///
+    /// The header names the inputs and, in the last column, the output.
+    /// Column types are inferred from the cells (bool, then int, then float,
+    /// falling back to string). The spec id is `decision_table`; callers
+    /// usually replace it with the file name.
+    pub fn from_csv<R: Read>(reader: R) -> Result<Spec> {
+        let mut csv = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+
+        let header: Vec<String> = csv
+            .headers()
+            .map_err(|e| Error::SpecParse(e.to_string()))?
+            .iter()
+            .map(str::to_string)
+            .collect();
+        if header.len() < 2 {
+            return Err(Error::SpecParse(
+                "decision table needs at least one input column and an output column".into(),
+            ));
+        }
+
+        let rows: Vec<Vec<String>> = csv
+            .records()
+            .map(|record| {
+                record
+                    .map(|r| r.iter().map(str::to_string).collect())
+                    .map_err(|e| Error::SpecParse(e.to_string()))
+            })
+            .collect::<Result<_>>()?;
+
+        let types: Vec<VarType> = (0..header.len())
+            .map(|col| infer_type(rows.iter().map(|row| row[col].as_str())))
+            .collect();
+        let (output_col, input_cols) = header.split_last().expect("checked above");
+        let output_type = types.last().expect("checked above");
+
+        let variable = |name: &str, typ: &VarType| Variable {
+            name: name.to_string(),
+            typ: typ.clone(),
+            description: None,
+            values: None,
+            optional: false,
+            range: None,
+        };
+
+        let mut rules = Vec::with_capacity(rows.len());
+        for (i, row) in rows.iter().enumerate() {
+            let (output, cells) = row.split_last().expect("row width matches header");
+            if is_dont_care(output) {
+                return Err(Error::SpecParse(format!(
+                    "row {}: missing value for output '{}'",
+                    i + 1,
+                    output_col
+                )));
+            }
+            let conditions: Vec<Condition> = cells
+                .iter()
+                .zip(input_cols.iter().zip(&types))
+                .filter(|(cell, _)| !is_dont_care(cell))
+                .map(|(cell, (name, typ))| Condition {
+                    var: name.clone(),
+                    op: ConditionOp::Eq,
+                    value: parse_cell(cell, typ),
+                })
+                .collect();
+            rules.push(Rule {
+                id: format!("R{}", i + 1),
+                when: conditions.is_empty().then(|| "true".into()),
+                conditions: (!conditions.is_empty()).then_some(conditions),
+                then: Output::Single(parse_cell(output, output_type)),
+                priority: 0,
+                description: None,
+            });
+        }
+
+        Ok(Spec {
+            id: "decision_table".into(),
+            name: None,
+            description: None,
+            inputs: input_cols
+                .iter()
+                .zip(&types)
+                .map(|(name, typ)| variable(name, typ))
+                .collect(),
+            outputs: vec![variable(output_col, output_type)],
+            rules,
+            default: None,
+            mode: Default::default(),
+            on_no_match: None,
+            ensures: Vec::new(),
+            allow_bitwise: false,
+            schema_version: None,
+            meta: Default::default(),
+            scoping: None,
+        })
+    }
+}
+
+impl Spec {
+    /// Lay the spec out as a decision table
+    ///
+    /// Conditions that are conjunctions of `input == literal`, `flag` and
+    /// `!flag` become grid cells. Anything else is kept as raw CEL in the
+    /// cell of the first input it mentions, and the row carries a note. The
+    /// default, if present, is a final all-`*` row.
+    pub fn to_decision_table(&self) -> DecisionTable {
+        let inputs: Vec<String> = self.inputs.iter().map(|v| v.name.clone()).collect();
+        let outputs: Vec<String> = if self.outputs.is_empty() {
+            vec!["result".into()]
+        } else {
+            self.outputs.iter().map(|v| v.name.clone()).collect()
+        };
+        let dont_care = vec!["*".to_string(); inputs.len()];
+
+        let mut rows: Vec<DecisionRow> = self
+            .rules
+            .iter()
+            .map(|rule| {
+                let cel = rule.as_cel().unwrap_or_else(|| "true".into());
+                let (cells, note) = match flatten(&cel, &inputs) {
+                    Some(cells) => (cells, None),
+                    None => {
+                        let mentioned = CelCompiler::extract_variables(&cel).unwrap_or_default();
+                        let col = inputs
+                            .iter()
+                            .position(|name| mentioned.contains(name))
+                            .unwrap_or(0);
+                        let mut cells = dont_care.clone();
+                        if let Some(cell) = cells.get_mut(col) {
+                            *cell = cel;
+                        }
+                        (cells, Some("condition kept as CEL".into()))
+                    }
+                };
+                DecisionRow {
+                    id: rule.id.clone(),
+                    cells,
+                    outputs: output_cells(&rule.then, &outputs),
+                    note,
+                }
+            })
+            .collect();
+
+        if let Some(default) = &self.default {
+            rows.push(DecisionRow {
+                id: "default".into(),
+                cells: dont_care,
+                outputs: output_cells(default, &outputs),
+                note: None,
+            });
+        }
+
+        DecisionTable {
+            inputs,
+            outputs,
+            rows,
+        }
+    }
+}
+
+impl DecisionTable {
+    /// CSV with inputs then outputs as columns, readable by [`Spec::from_csv`]
+    ///
+    /// Rule ids and notes are dropped; a default row comes back from
+    /// `from_csv` as a catch-all rule.
+    pub fn to_csv(&self) -> String {
+        let mut csv = csv::Writer::from_writer(Vec::new());
+        let write = |csv: &mut csv::Writer<Vec<u8>>, record: Vec<&String>| {
+            csv.write_record(record).expect("writing CSV to memory");
+        };
+        write(&mut csv, self.inputs.iter().chain(&self.outputs).collect());
+        for row in &self.rows {
+            write(&mut csv, row.cells.iter().chain(&row.outputs).collect());
+        }
+        let bytes = csv.into_inner().expect("writing CSV to memory");
+        String::from_utf8(bytes).expect("CSV from UTF-8 cells")
+    }
+
+    /// GitHub-flavored Markdown table with a leading rule column
+    ///
+    /// Rows with a note are listed below the table.
+    pub fn to_markdown_table(&self) -> String {
+        let line = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+        let escape = |cell: &str| cell.replace('|', "\\|");
+
+        let header: Vec<String> = std::iter::once("Rule".to_string())
+            .chain(self.inputs.iter().cloned())
+            .chain(self.outputs.iter().cloned())
+            .collect();
+        let mut out = line(header.clone());
+        out.push_str(&line(header.iter().map(|_| "---".to_string()).collect()));
+
+        for row in &self.rows {
+            let cells = std::iter::once(row.id.clone())
+                .chain(row.cells.iter().map(|cell| {
+                    if row.note.is_some() && cell != "*" {
+                        format!("`{}`", escape(cell))
+                    } else {
+                        escape(cell)
+                    }
+                }))
+                .chain(row.outputs.iter().map(|cell| escape(cell)))
+                .collect();
+            out.push_str(&line(cells));
+        }
+
+        let notes: Vec<String> = self
+            .rows
+            .iter()
+            .filter_map(|row| Some(format!("- {}: {}\n", row.id, row.note.as_ref()?)))
+            .collect();
+        if !notes.is_empty() {
+            out.push('\n');
+            out.extend(notes);
+        }
+        out
+    }
+}
+
+/// Grid cells for a conjunction of simple equalities, or `None`
+fn flatten(cel: &str, inputs: &[String]) -> Option<Vec<String>> {
+    let ast = CelCompiler::parse(cel).ok()?;
+    let mut cells = vec!["*".to_string(); inputs.len()];
+    let mut pending = vec![&ast];
+    while let Some(expr) = pending.pop() {
+        if let Expr::Call(call) = &expr.expr {
+            if call.func_name == operators::LOGICAL_AND && call.args.len() == 2 {
+                pending.extend(&call.args);
+                continue;
+            }
+        }
+        if is_true(expr) {
+            continue;
+        }
+        let (var, value) = simple_equality(expr)?;
+        let col = inputs.iter().position(|name| *name == var)?;
+        if cells[col] != "*" {
+            return None;
+        }
+        cells[col] = value;
+    }
+    Some(cells)
+}
+
+fn is_true(expr: &CelExpr) -> bool {
+    match &expr.expr {
+        Expr::Literal(Val::Boolean(b)) => *b,
+        Expr::Ident(name) => name.as_str() == "true",
+        _ => false,
+    }
+}
+
+/// `x == lit`, `lit == x`, `flag` or `!flag` as (input, cell)
+fn simple_equality(expr: &CelExpr) -> Option<(String, String)> {
+    match &expr.expr {
+        Expr::Ident(name) if name.as_str() != "false" => Some((name.to_string(), "true".into())),
+        Expr::Call(call) if call.func_name == operators::LOGICAL_NOT && call.args.len() == 1 => {
+            match &call.args[0].expr {
+                Expr::Ident(name) => Some((name.to_string(), "false".into())),
+                _ => None,
+            }
+        }
+        Expr::Call(call) if call.func_name == operators::EQUALS && call.args.len() == 2 => {
+            match (&call.args[0].expr, &call.args[1].expr) {
+                (Expr::Ident(name), Expr::Literal(val))
+                | (Expr::Literal(val), Expr::Ident(name)) => {
+                    Some((name.to_string(), literal_cell(val)?))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn literal_cell(val: &Val) -> Option<String> {
+    match val {
+        Val::Boolean(b) => Some(b.to_string()),
+        Val::Int(i) => Some(i.to_string()),
+        Val::UInt(u) => Some(u.to_string()),
+        Val::Double(f) => Some(f.to_string()),
+        Val::String(s) => Some(s.to_string()),
+        _ => None,
+    }
+}
+
+fn output_cells(output: &Output, columns: &[String]) -> Vec<String> {
+    match output {
+        Output::Single(value) => vec![value_cell(value)],
+        Output::Named(values) => columns
+            .iter()
+            .map(|name| values.get(name).map(value_cell).unwrap_or_default())
+            .collect(),
+    }
+}
+
+fn value_cell(value: &ConditionValue) -> String {
+    match value {
+        ConditionValue::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Narrowest type that fits every non-blank cell of a column
+fn infer_type<'a>(cells: impl Iterator<Item = &'a str>) -> VarType {
+    let mut values = cells.filter(|c| !is_dont_care(c)).peekable();
+    if values.peek().is_none() {
+        return VarType::String;
+    }
+    let values: Vec<&str> = values.collect();
+    if values.iter().all(|c| c.parse::<bool>().is_ok()) {
+        VarType::Bool
+    } else if values.iter().all(|c| c.parse::<i64>().is_ok()) {
+        VarType::Int
+    } else if values.iter().all(|c| c.parse::<f64>().is_ok()) {
+        VarType::Float
+    } else {
+        VarType::String
+    }
+}
+
+fn parse_cell(cell: &str, typ: &VarType) -> ConditionValue {
+    match typ {
+        VarType::Bool => ConditionValue::Bool(cell == "true"),
+        VarType::Int => ConditionValue::Int(cell.parse().unwrap_or_default()),
+        VarType::Float => ConditionValue::Float(cell.parse().unwrap_or_default()),
+        _ => ConditionValue::String(cell.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_csv() {
+        let csv = "\
+tier,age,verified,discount
+gold,,true,20
+gold,*,false,10
+silver,30,,5
+,,,0
+";
+        let spec = Spec::from_csv(csv.as_bytes()).unwrap();
+
+        let inputs: Vec<(&str, &VarType)> = spec
+            .inputs
+            .iter()
+            .map(|v| (v.name.as_str(), &v.typ))
+            .collect();
+        assert_eq!(
+            inputs,
+            [
+                ("tier", &VarType::String),
+                ("age", &VarType::Int),
+                ("verified", &VarType::Bool)
+            ]
+        );
+        assert_eq!(spec.outputs[0].name, "discount");
+        assert_eq!(spec.outputs[0].typ, VarType::Int);
+
+        assert_eq!(spec.rules.len(), 4);
+        assert_eq!(
+            spec.rules[0].as_cel().unwrap(),
+            "tier == \"gold\" && verified == true"
+        );
+        assert_eq!(
+            spec.rules[2].as_cel().unwrap(),
+            "tier == \"silver\" && age == 30"
+        );
+        assert_eq!(spec.rules[3].as_cel().unwrap(), "true");
+        assert_eq!(spec.rules[1].then, Output::Single(ConditionValue::Int(10)));
+    }
+
+    #[test]
+    fn test_bool_spec_round_trips_through_csv() {
+        let spec = Spec::from_yaml(
+            r#"
+id: access
+inputs:
+  - name: admin
+    type: bool
+  - name: locked
+    type: bool
+outputs:
+  - name: allowed
+    type: bool
+rules:
+  - id: R1
+    when: "admin && !locked"
+    then: true
+  - id: R2
+    when: "locked"
+    then: false
+  - id: R3
+    when: "!admin"
+    then: false
+"#,
+        )
+        .unwrap();
+
+        let table = spec.to_decision_table();
+        assert_eq!(table.rows[0].cells, ["true", "false"]);
+        assert_eq!(table.rows[2].cells, ["false", "*"]);
+        assert!(table.rows.iter().all(|r| r.note.is_none()));
+
+        let back = Spec::from_csv(table.to_csv().as_bytes()).unwrap();
+        assert_eq!(back.rules.len(), spec.rules.len());
+        assert_eq!(back.rules[1].as_cel().unwrap(), "locked == true");
+    }
+
+    #[test]
+    fn test_unflattenable_condition_kept_as_cel() {
+        let spec = Spec::from_yaml(
+            r#"
+id: risk
+inputs:
+  - name: amount
+    type: int
+  - name: region
+    type: string
+outputs:
+  - name: review
+    type: bool
+rules:
+  - id: R1
+    when: "amount > 1000 || region == \"EU\""
+    then: true
+default: false
+"#,
+        )
+        .unwrap();
+
+        let table = spec.to_decision_table();
+        assert_eq!(table.rows[0].cells[0], "amount > 1000 || region == \"EU\"");
+        assert_eq!(table.rows[0].cells[1], "*");
+        assert!(table.rows[0].note.is_some());
+        assert_eq!(table.rows[1].id, "default");
+
+        let md = table.to_markdown_table();
+        assert!(md.starts_with("| Rule | amount | region | review |\n| --- |"));
+        assert!(md.contains("\\|\\|"));
+        assert!(md.contains("- R1: condition kept as CEL"));
+    }
+}
@@ -3,7 +3,11 @@
 //! Converts Spec and Orchestrator into template-friendly data structures.
 
 use crate::cel::{CelCompiler, Target};
-use crate::spec::{ConditionOp, ConditionValue, Output, Rule, Spec, VarType, Variable};
+use crate::render::{minimize_parens, native_enum, native_enum_literals};
+use crate::spec::{
+    guard_optionals, unguarded_optionals, ConditionOp, ConditionValue, OnNoMatch, Output, Rule,
+    Spec, VarType, Variable,
+};
 use chrono::Utc;
 use serde::Serialize;
 use std::collections::HashMap;
@@ -25,14 +29,64 @@ pub struct SpecContext {
     pub generated_at: String,
     /// Input variables
     pub inputs: Vec<InputView>,
+    /// Nested types for object inputs with declared fields (innermost first)
+    pub object_types: Vec<ObjectTypeView>,
+    /// Native enums for `enum` inputs (Rust `enum`, TypeScript union type,
+    /// Python `Enum`)
+    pub enum_types: Vec<EnumTypeView>,
     /// Output variables
     pub outputs: Vec<OutputView>,
     /// Rules
     pub rules: Vec<RuleView>,
+    /// Literal regex patterns hoisted to module-level constants
+    pub regexes: Vec<RegexView>,
+    /// Whether any condition uses `matches` (regex import needed)
+    pub uses_regex: bool,
+    /// Whether any input is optional (Python `Optional` import needed)
+    pub uses_optional: bool,
+    /// Standard library packages referenced by the Go code (e.g., `strings`)
+    pub go_imports: Vec<String>,
     /// Default output (if specified)
     pub default: Option<OutputValueView>,
+    /// Every input provably matches a rule, so the fallback is unreachable
+    /// (set only when rendering with `assume_complete`)
+    pub complete: bool,
+    /// Comment lines flagging inputs no rule covers, shown above the
+    /// fallback branch (set only when rendering with `warn_incomplete`)
+    pub incomplete_warning: Vec<String>,
+    /// Render rules as a data table walked by a loop
+    /// (set only when rendering with `RenderStyle::DataTable`)
+    pub data_table: bool,
+    /// Emit input builders/factories with zero-valued defaults
+    /// (set only when rendering with `builders`)
+    pub builders: bool,
+    /// Declare the Rust function `const fn` (set only when rendering with
+    /// `const_fn` and the spec is const-evaluable)
+    pub const_fn: bool,
+    /// Rust output avoids the heap and `std` (set only when rendering with
+    /// `no_alloc` and the spec allows it)
+    pub no_alloc: bool,
+    /// Postconditions asserted by a wrapper around the decision function
+    /// (set only when rendering with `check_ensures`)
+    pub ensures: Vec<EnsureView>,
+    /// Whether any input (or object field) defaults to a mutable container,
+    /// which Python dataclasses declare through `field(default_factory=...)`
+    pub uses_default_factory: bool,
+    /// Sum the outputs of every matching rule (`mode: accumulate`)
+    pub accumulate: bool,
+    /// What the fallback branch does: `default`, `panic` or `error`
+    /// (`error` makes the function fallible)
+    pub on_no_match: String,
     /// Whether to use match/switch vs if-else
     pub use_match: bool,
+    /// Every rule condition can be an Elixir guard, so each rule gets its
+    /// own `def` clause instead of a `cond` arm
+    pub elixir_guards: bool,
+    /// Map pattern binding the inputs the Elixir function uses, for the
+    /// single-clause (`cond` or accumulate) form
+    pub elixir_pattern: String,
+    /// Map pattern for the Elixir fallback clause
+    pub elixir_default_pattern: String,
     /// Whether HashMap import is needed (for Rust)
     pub needs_hashmap: bool,
     /// Whether outputs are named (Output::Named) - affects return type
@@ -41,6 +95,8 @@ pub struct SpecContext {
     pub target: String,
     /// Description (if any)
     pub description: Option<String>,
+    /// Description split into lines for doc comments
+    pub description_lines: Vec<String>,
     // Namespace fields for scoping
     /// C# namespace (e.g., "Company.Rules.Auth")
     pub namespace: Option<String>,
@@ -75,6 +131,125 @@ pub struct InputView {
     pub java_type: String,
     /// C# type
     pub csharp_type: String,
+    /// Kotlin type
+    pub kotlin_type: String,
+    /// Swift type
+    pub swift_type: String,
+    /// PHP type
+    pub php_type: String,
+    /// Zero value in Rust (builder defaults)
+    pub rust_zero: String,
+    /// Zero value in TypeScript (factory defaults)
+    pub ts_zero: String,
+    /// Zero value in Python (dataclass defaults)
+    pub py_zero: String,
+}
+
+/// View of a generated type for an object input's fields
+#[derive(Debug, Clone, Serialize)]
+pub struct ObjectTypeView {
+    /// Type name (e.g., `CheckoutUser` for input `user` of spec `checkout`)
+    pub name: String,
+    /// Fields, with nested objects already mapped to their own type names
+    pub fields: Vec<InputView>,
+}
+
+/// View of a native enum generated for an `enum` input
+#[derive(Debug, Clone, Serialize)]
+pub struct EnumTypeView {
+    /// Type name (e.g., `MemberTier` for input `member_tier`)
+    pub name: String,
+    pub variants: Vec<EnumVariantView>,
+}
+
+/// View of one enum variant
+#[derive(Debug, Clone, Serialize)]
+pub struct EnumVariantView {
+    /// Value as written in the spec (e.g., `gold`)
+    pub value: String,
+    /// Rust variant (e.g., `Gold`)
+    pub ident: String,
+    /// Python member (e.g., `GOLD`)
+    pub ident_upper: String,
+}
+
+/// View of an `ensures` postcondition, checked against `result`
+#[derive(Debug, Clone, Serialize)]
+pub struct EnsureView {
+    /// Postcondition as written in the spec
+    pub cel: String,
+    /// Failure message as a double-quoted string literal
+    pub message: String,
+    /// Rust `debug_assert!` message (braces escaped for `format!`)
+    pub message_rust: String,
+    /// Condition for Rust
+    pub rust: String,
+    /// Condition for TypeScript (output fields in camelCase)
+    pub ts: String,
+    /// Condition for Python
+    pub py: String,
+}
+
+impl EnsureView {
+    pub fn from_cel(cel: &str, spec: &Spec) -> Self {
+        let message = format!("{:?}", format!("{}: ensures {}", spec.id, cel));
+        let compile = |target| CelCompiler::compile(cel, target).unwrap_or_else(|_| "true".into());
+        // `result.base_fee` reads the TypeScript output field `baseFee`
+        let result = Variable {
+            name: "result".into(),
+            typ: VarType::Object(
+                spec.outputs
+                    .iter()
+                    .map(|o| (o.name.clone(), o.typ.clone()))
+                    .collect(),
+            ),
+            description: None,
+            values: None,
+            optional: false,
+            range: None,
+        };
+        let ts = translate_field_paths(
+            &compile(Target::TypeScript),
+            std::slice::from_ref(&result),
+            |n| n.to_string(),
+            to_camel_case,
+        );
+        Self {
+            cel: cel.to_string(),
+            message_rust: message.replace('{', "{{").replace('}', "}}"),
+            message,
+            rust: compile(Target::Rust),
+            ts,
+            py: compile(Target::Python),
+        }
+    }
+}
+
+/// View of a hoisted regex constant
+#[derive(Debug, Clone, Serialize)]
+pub struct RegexView {
+    /// Constant name (e.g., `RE_0`)
+    pub name: String,
+    /// Go variable name (unexported, e.g., `re0`)
+    pub name_go: String,
+    /// C# field name (e.g., `Re0`)
+    pub name_csharp: String,
+    /// Constructor expression for Rust
+    pub rust: String,
+    /// Constructor expression for TypeScript
+    pub ts: String,
+    /// Constructor expression for Python
+    pub py: String,
+    /// Constructor expression for Go
+    pub go: String,
+    /// Constructor expression for Java
+    pub java: String,
+    /// Constructor expression for C#
+    pub csharp: String,
+    /// Constructor expression for Kotlin
+    pub kotlin: String,
+    /// Constructor expression for Swift
+    pub swift: String,
 }
 
 /// View of an output variable
@@ -100,6 +275,12 @@ pub struct OutputView {
     pub java_type: String,
     /// C# type
     pub csharp_type: String,
+    /// Kotlin type
+    pub kotlin_type: String,
+    /// Swift type
+    pub swift_type: String,
+    /// PHP type
+    pub php_type: String,
 }
 
 /// View of a rule
@@ -119,16 +300,34 @@ pub struct RuleView {
     pub condition_java: String,
     /// Condition as C# code
     pub condition_csharp: String,
+    /// Condition as Kotlin code
+    pub condition_kotlin: String,
+    /// Condition as Swift code
+    pub condition_swift: String,
+    /// Condition as PHP code
+    pub condition_php: String,
+    /// Condition as Ruby code
+    pub condition_ruby: String,
+    /// Condition as Elixir code (enum inputs compared as atoms)
+    pub condition_elixir: String,
     /// Pattern for match statements (Rust)
     pub pattern_rust: String,
     /// Pattern for match statements (Python)
     pub pattern_py: String,
+    /// Pattern for switch statements (Swift)
+    pub pattern_swift: String,
+    /// Pattern for `case`/`in` statements (Ruby)
+    pub pattern_ruby: String,
+    /// Map pattern binding the inputs this rule's clause uses (Elixir)
+    pub pattern_elixir: String,
     /// Output value
     pub output: OutputValueView,
     /// Whether this rule uses CEL (vs simple conditions)
     pub is_cel: bool,
     /// Raw CEL expression (if any)
     pub cel_expr: Option<String>,
+    /// Rule description split into lines, commented above the branch
+    pub description_lines: Vec<String>,
 }
 
 /// View of an output value
@@ -148,6 +347,16 @@ pub struct OutputValueView {
     pub java: String,
     /// Single value rendered for C#
     pub csharp: String,
+    /// Single value rendered for Kotlin
+    pub kotlin: String,
+    /// Single value rendered for Swift
+    pub swift: String,
+    /// Single value rendered for PHP
+    pub php: String,
+    /// Single value rendered for Ruby
+    pub ruby: String,
+    /// Single value rendered for Elixir
+    pub elixir: String,
     /// Named values (if Output::Named)
     pub named: Option<HashMap<String, NamedValueView>>,
 }
@@ -161,40 +370,137 @@ pub struct NamedValueView {
     pub go: String,
     pub java: String,
     pub csharp: String,
+    pub kotlin: String,
+    pub swift: String,
+    pub php: String,
+    pub ruby: String,
+    pub elixir: String,
 }
 
 impl SpecContext {
     /// Create a SpecContext from a Spec
     pub fn from_spec(spec: &Spec, target: Target, provenance: bool) -> Self {
-        let inputs: Vec<InputView> = spec.inputs.iter().map(InputView::from_var).collect();
+        let id_pascal = to_pascal_case(&spec.id);
+        let mut object_types = Vec::new();
+        let mut enum_types = Vec::new();
+        let inputs: Vec<InputView> = spec
+            .inputs
+            .iter()
+            .map(|var| {
+                let mut view =
+                    InputView::from_typed(&var.name, &var.typ, &id_pascal, &mut object_types);
+                if let Some(native) = native_enum(&var.name, &var.typ) {
+                    let enum_type = EnumTypeView::from_native(native);
+                    view = view.with_enum(&enum_type);
+                    enum_types.push(enum_type);
+                }
+                if var.optional {
+                    view.nullable()
+                } else {
+                    view
+                }
+            })
+            .collect();
+        let uses_optional = spec.inputs.iter().any(|var| var.optional);
+        let uses_default_factory = inputs
+            .iter()
+            .chain(object_types.iter().flat_map(|obj| &obj.fields))
+            .any(|view| view.py_zero.starts_with("field("));
         let input_names: Vec<String> = inputs.iter().map(|i| i.name.clone()).collect();
 
         let outputs: Vec<OutputView> = spec.outputs.iter().map(OutputView::from_var).collect();
 
+        // Literal `matches` patterns are compiled once per module
+        let mut uses_regex = false;
+        let mut regex_patterns: Vec<String> = Vec::new();
+        for rule in &spec.rules {
+            if let Some(patterns) = rule.as_cel().and_then(|c| CelCompiler::regex_patterns(&c)) {
+                uses_regex = true;
+                for pattern in patterns {
+                    if !regex_patterns.contains(&pattern) {
+                        regex_patterns.push(pattern);
+                    }
+                }
+            }
+        }
+
+        // Rules render in the order they are tried
         let rules: Vec<RuleView> = spec
-            .rules
+            .rules_in_order()
+            .into_iter()
+            .map(|r| {
+                RuleView::from_rule(
+                    r,
+                    &input_names,
+                    &spec.inputs,
+                    &spec.outputs,
+                    &regex_patterns,
+                )
+            })
+            .collect();
+
+        let regexes: Vec<RegexView> = regex_patterns
             .iter()
-            .map(|r| RuleView::from_rule(r, &input_names, &spec.inputs))
+            .enumerate()
+            .map(|(i, pattern)| RegexView {
+                name: regex_const_name(i, Target::Rust),
+                name_go: regex_const_name(i, Target::Go),
+                name_csharp: regex_const_name(i, Target::CSharp),
+                rust: CelCompiler::regex_literal_constructor(pattern, Target::Rust),
+                ts: CelCompiler::regex_literal_constructor(pattern, Target::TypeScript),
+                py: CelCompiler::regex_literal_constructor(pattern, Target::Python),
+                go: CelCompiler::regex_literal_constructor(pattern, Target::Go),
+                java: CelCompiler::regex_literal_constructor(pattern, Target::Java),
+                csharp: CelCompiler::regex_literal_constructor(pattern, Target::CSharp),
+                kotlin: CelCompiler::regex_literal_constructor(pattern, Target::Kotlin),
+                swift: CelCompiler::regex_literal_constructor(pattern, Target::Swift),
+            })
             .collect();
 
         let default = spec
             .default
             .as_ref()
-            .map(|d| OutputValueView::from_output(d, &input_names));
+            .map(|d| OutputValueView::from_output(d, &input_names, &spec.outputs));
+
+        let accumulate = spec.mode == crate::spec::SpecMode::Accumulate;
+        let on_no_match = no_match_behaviour(spec.on_no_match, default.is_some(), accumulate);
+        let mut go_imports = collect_go_imports(&rules, default.as_ref(), &regexes);
+        if on_no_match == "error" {
+            require_go_import(&mut go_imports, "errors");
+        }
 
         // Check if return type should be HashMap (only when no outputs are defined in spec)
-        // When spec.outputs is defined, we always use tuple/single return type
+        // When spec.outputs is defined, we use the result struct or single return type
         let has_named_outputs = spec.outputs.is_empty()
             && (rules.iter().any(|r| r.output.named.is_some())
                 || default.as_ref().is_some_and(|d| d.named.is_some()));
 
         // Determine if we should use match (all rules have simple equality conditions)
-        let use_match = spec.rules.iter().all(|r| {
-            r.conditions
-                .as_ref()
-                .map(|c| c.iter().all(|cond| cond.op == ConditionOp::Eq))
-                .unwrap_or(false)
-        });
+        let use_match = !uses_optional
+            && spec.rules.iter().all(|r| {
+                r.conditions
+                    .as_ref()
+                    .map(|c| c.iter().all(|cond| cond.op == ConditionOp::Eq))
+                    .unwrap_or(false)
+            });
+
+        // Elixir: one `def` clause per rule when every condition is a valid guard
+        let elixir_guards = !accumulate
+            && spec
+                .rules
+                .iter()
+                .all(|r| crate::render::elixir::guard_safe(r.as_cel().as_deref()));
+        let elixir_pattern = crate::render::elixir::input_pattern(
+            &spec.inputs,
+            rules
+                .iter()
+                .flat_map(|r| elixir_code(&r.condition_elixir, &r.output))
+                .chain(default.iter().flat_map(|d| elixir_code("", d))),
+        );
+        let elixir_default_pattern = crate::render::elixir::input_pattern(
+            &spec.inputs,
+            default.iter().flat_map(|d| elixir_code("", d)),
+        );
 
         // Check if HashMap is needed (for Rust) - only when outputs are dynamic (not defined in spec)
         let needs_hashmap = has_named_outputs;
@@ -204,26 +510,97 @@ impl SpecContext {
 
         Self {
             id: spec.id.clone(),
-            id_pascal: to_pascal_case(&spec.id),
+            id_pascal,
             id_camel: to_camel_case(&spec.id),
             spec_hash: spec.hash(),
             provenance,
             generated_at: Utc::now().to_rfc3339(),
             inputs,
+            object_types,
+            enum_types,
             outputs,
             rules,
+            regexes,
+            uses_regex,
+            uses_optional,
+            go_imports,
             default,
+            complete: false,
+            incomplete_warning: Vec::new(),
+            data_table: false,
+            builders: false,
+            const_fn: false,
+            no_alloc: false,
+            ensures: Vec::new(),
+            uses_default_factory,
+            accumulate,
+            on_no_match: on_no_match.into(),
             use_match,
+            elixir_guards,
+            elixir_pattern,
+            elixir_default_pattern,
             needs_hashmap,
             has_named_outputs,
             target: format!("{:?}", target),
             description: spec.description.clone(),
+            description_lines: comment_lines(spec.description.as_deref()),
             namespace,
             package,
             module_path,
             module,
         }
     }
+
+    /// Override the spec's own `on_no_match` (e.g. from `RenderConfig`)
+    pub fn set_on_no_match(&mut self, mode: OnNoMatch) {
+        let behaviour = no_match_behaviour(Some(mode), self.default.is_some(), self.accumulate);
+        if behaviour == "error" {
+            require_go_import(&mut self.go_imports, "errors");
+        }
+        self.on_no_match = behaviour.into();
+    }
+
+    /// Drop redundant parentheses from every rule condition
+    ///
+    /// Runs on the finished conditions, after the rewrites above that
+    /// expect the compiler's fully parenthesized output.
+    pub fn minimize_parens(&mut self) {
+        for rule in &mut self.rules {
+            for (condition, target) in [
+                (&mut rule.condition_rust, Target::Rust),
+                (&mut rule.condition_ts, Target::TypeScript),
+                (&mut rule.condition_py, Target::Python),
+                (&mut rule.condition_go, Target::Go),
+                (&mut rule.condition_java, Target::Java),
+                (&mut rule.condition_csharp, Target::CSharp),
+                (&mut rule.condition_kotlin, Target::Kotlin),
+                (&mut rule.condition_swift, Target::Swift),
+                (&mut rule.condition_php, Target::Php),
+                (&mut rule.condition_ruby, Target::Ruby),
+                (&mut rule.condition_elixir, Target::Elixir),
+            ] {
+                *condition = minimize_parens(condition, target);
+            }
+        }
+    }
+}
+
+/// Resolve what the fallback branch does. Without an explicit choice a
+/// declared default is returned and anything else panics; `return_default`
+/// without a default also panics. Accumulate specs always fall back to
+/// their running total.
+fn no_match_behaviour(
+    mode: Option<OnNoMatch>,
+    has_default: bool,
+    accumulate: bool,
+) -> &'static str {
+    match mode {
+        _ if accumulate => "default",
+        Some(OnNoMatch::Error) => "error",
+        Some(OnNoMatch::Panic) => "panic",
+        Some(OnNoMatch::ReturnDefault) | None if has_default => "default",
+        Some(OnNoMatch::ReturnDefault) | None => "panic",
+    }
 }
 
 /// Extract namespace fields from spec scoping config based on target language
@@ -246,7 +623,8 @@ fn extract_namespace_fields(
             let ns = scoping.languages.csharp.as_ref().map(|n| n.render());
             (ns, None, None, None)
         }
-        Target::Java => {
+        // Kotlin shares Java's package syntax and configuration
+        Target::Java | Target::Kotlin => {
             let pkg = scoping.languages.java.as_ref().map(|p| p.render());
             (None, pkg, None, None)
         }
@@ -271,23 +649,127 @@ fn extract_namespace_fields(
             let module = scoping.languages.typescript.as_ref().map(|t| t.render());
             (None, None, None, module)
         }
+        // Swift modules are build targets, not source declarations
+        Target::Swift => (None, None, None, None),
+        // PHP has no scoping configuration
+        Target::Php => (None, None, None, None),
+        // Ruby has no scoping configuration
+        Target::Ruby => (None, None, None, None),
+        // Elixir has no scoping configuration
+        Target::Elixir => (None, None, None, None),
     }
 }
 
 impl InputView {
-    fn from_var(var: &Variable) -> Self {
-        let var_type = format_var_type(&var.typ);
+    /// Build the view for an input (or object field) named `name`.
+    ///
+    /// Objects with declared fields get a generated type named
+    /// `{owner}{Name}`, which is pushed onto `object_types` after any
+    /// nested types it depends on.
+    fn from_typed(
+        name: &str,
+        typ: &VarType,
+        owner: &str,
+        object_types: &mut Vec<ObjectTypeView>,
+    ) -> Self {
+        let mut view = Self {
+            name: name.to_string(),
+            name_pascal: to_pascal_case(name),
+            name_camel: to_camel_case(name),
+            var_type: format_var_type(typ),
+            rust_type: map_type_rust(typ),
+            ts_type: map_type_ts(typ),
+            py_type: map_type_python(typ),
+            go_type: map_type_go(typ),
+            java_type: map_type_java(typ),
+            csharp_type: map_type_csharp(typ),
+            kotlin_type: map_type_kotlin(typ),
+            swift_type: map_type_swift(typ),
+            php_type: map_type_php(typ),
+            rust_zero: zero_value_rust(typ),
+            ts_zero: zero_value_ts(typ),
+            py_zero: zero_value_python(typ),
+        };
+
+        let fields = typ.object_fields();
+        if !fields.is_empty() {
+            let type_name = format!("{}{}", owner, view.name_pascal);
+            let field_views = fields
+                .iter()
+                .map(|(field, field_typ)| {
+                    Self::from_typed(field, field_typ, &type_name, object_types)
+                })
+                .collect();
+            object_types.push(ObjectTypeView {
+                name: type_name.clone(),
+                fields: field_views,
+            });
+
+            view.rust_type = type_name.clone();
+            view.ts_type = type_name.clone();
+            view.py_type = type_name.clone();
+            view.go_type = type_name.clone();
+            view.java_type = type_name.clone();
+            view.csharp_type = type_name.clone();
+            view.kotlin_type = type_name.clone();
+            view.ts_zero = format!("{{}} as {}", type_name);
+            view.py_zero = format!("field(default_factory={})", type_name);
+            view.swift_type = type_name;
+        }
+
+        view
+    }
+
+    /// Type the input with its native enum in Rust, TypeScript, Python and
+    /// Kotlin
+    fn with_enum(mut self, enum_type: &EnumTypeView) -> Self {
+        let first = &enum_type.variants[0];
+        self.rust_type = enum_type.name.clone();
+        self.ts_type = enum_type.name.clone();
+        self.py_type = enum_type.name.clone();
+        self.kotlin_type = enum_type.name.clone();
+        self.rust_zero = format!("{}::{}", enum_type.name, first.ident);
+        self.py_zero = format!("{}.{}", enum_type.name, first.ident_upper);
+        self
+    }
+
+    /// Switch every target type to its nullable form, for an optional input
+    fn nullable(mut self) -> Self {
+        self.rust_type = format!("Option<{}>", self.rust_type);
+        self.ts_type = format!("{} | null", self.ts_type);
+        self.py_type = format!("Optional[{}]", self.py_type);
+        self.go_type = format!("*{}", self.go_type);
+        // Java reference types are already nullable; primitives are boxed
+        self.java_type = match self.java_type.as_str() {
+            "boolean" => "Boolean".to_string(),
+            "long" => "Long".to_string(),
+            "double" => "Double".to_string(),
+            _ => self.java_type,
+        };
+        self.csharp_type = format!("{}?", self.csharp_type);
+        self.kotlin_type = format!("{}?", self.kotlin_type);
+        self.swift_type = format!("{}?", self.swift_type);
+        self.php_type = format!("?{}", self.php_type);
+        self.rust_zero = "None".to_string();
+        self.ts_zero = "null".to_string();
+        self.py_zero = "None".to_string();
+        self
+    }
+}
+
+impl EnumTypeView {
+    fn from_native(native: crate::render::NativeEnum) -> Self {
         Self {
-            name: var.name.clone(),
-            name_pascal: to_pascal_case(&var.name),
-            name_camel: to_camel_case(&var.name),
-            var_type: var_type.clone(),
-            rust_type: map_type_rust(&var.typ),
-            ts_type: map_type_ts(&var.typ),
-            py_type: map_type_python(&var.typ),
-            go_type: map_type_go(&var.typ),
-            java_type: map_type_java(&var.typ),
-            csharp_type: map_type_csharp(&var.typ),
+            name: native.name,
+            variants: native
+                .variants
+                .into_iter()
+                .map(|v| EnumVariantView {
+                    value: v.value,
+                    ident: v.ident,
+                    ident_upper: v.ident_upper,
+                })
+                .collect(),
         }
     }
 }
@@ -306,12 +788,21 @@ impl OutputView {
             go_type: map_type_go(&var.typ),
             java_type: map_type_java(&var.typ),
             csharp_type: map_type_csharp(&var.typ),
+            kotlin_type: map_type_kotlin(&var.typ),
+            swift_type: map_type_swift(&var.typ),
+            php_type: map_type_php(&var.typ),
         }
     }
 }
 
 impl RuleView {
-    fn from_rule(rule: &Rule, input_names: &[String], inputs: &[Variable]) -> Self {
+    fn from_rule(
+        rule: &Rule,
+        input_names: &[String],
+        inputs: &[Variable],
+        outputs: &[Variable],
+        regex_patterns: &[String],
+    ) -> Self {
         let cel_expr = rule.as_cel();
         let is_cel = cel_expr.is_some();
 
@@ -323,31 +814,116 @@ impl RuleView {
             condition_go,
             condition_java,
             condition_csharp,
+            condition_kotlin,
+            condition_swift,
+            condition_php,
+            condition_ruby,
+            condition_elixir,
         ) = if let Some(cel) = &cel_expr {
+            // Rust and Go unwrap optionals themselves once the rest is compiled
+            let guarded = guard_optionals(cel, inputs);
             (
                 CelCompiler::compile(cel, Target::Rust).unwrap_or_else(|_| "true".into()),
-                compile_ts_condition(cel, input_names),
-                CelCompiler::compile(cel, Target::Python).unwrap_or_else(|_| "True".into()),
+                compile_ts_condition(&guarded, input_names),
+                CelCompiler::compile(&guarded, Target::Python).unwrap_or_else(|_| "True".into()),
                 compile_go_condition(cel, input_names),
-                compile_java_condition(cel, input_names),
-                compile_csharp_condition(cel, input_names),
+                compile_java_condition(&guarded, input_names),
+                compile_csharp_condition(&guarded, input_names),
+                compile_kotlin_condition(&guarded, input_names),
+                compile_swift_condition(cel, input_names),
+                compile_php_condition(&guarded, input_names),
+                CelCompiler::compile(&guarded, Target::Ruby).unwrap_or_else(|_| "true".into()),
+                CelCompiler::compile(&guarded, Target::Elixir).unwrap_or_else(|_| "true".into()),
             )
         } else {
             (
+                "true".into(),
+                "true".into(),
                 "true".into(),
                 "true".into(),
                 "True".into(),
                 "true".into(),
                 "true".into(),
                 "true".into(),
+                "true".into(),
+                "true".into(),
+                "true".into(),
             )
         };
 
+        // Object field access follows each target's property casing
+        let condition_ts = translate_field_paths(&condition_ts, inputs, ts_local, to_camel_case);
+        let condition_go = translate_field_paths(
+            &condition_go,
+            inputs,
+            |n| format!("input.{}", to_pascal_case(n)),
+            to_pascal_case,
+        );
+        let condition_java = translate_field_paths(
+            &condition_java,
+            inputs,
+            |n| format!("input.{}", to_camel_case(n)),
+            to_camel_case,
+        );
+        let condition_csharp =
+            translate_field_paths(&condition_csharp, inputs, ts_local, to_pascal_case);
+        let condition_kotlin = translate_field_paths(
+            &condition_kotlin,
+            inputs,
+            |n| format!("input.{}", to_camel_case(n)),
+            to_camel_case,
+        );
+        let condition_swift = translate_field_paths(
+            &condition_swift,
+            inputs,
+            |n| format!("input.{}", to_camel_case(n)),
+            to_camel_case,
+        );
+
+        // Conditions on optional inputs only hold when the input is present
+        let optionals = cel_expr
+            .as_deref()
+            .map(|cel| unguarded_optionals(cel, inputs))
+            .unwrap_or_default();
+        // Enum inputs compare against their native variants
+        let condition_rust =
+            native_enum_literals(&condition_rust, inputs, str::to_string, |e, v| {
+                format!("{}::{}", e.name, v.ident)
+            });
+        let condition_py = native_enum_literals(&condition_py, inputs, str::to_string, |e, v| {
+            format!("{}.{}", e.name, v.ident_upper)
+        });
+        let condition_kotlin = native_enum_literals(
+            &condition_kotlin,
+            inputs,
+            |n| format!("input.{}", to_camel_case(n)),
+            |e, v| format!("{}.{}", e.name, v.ident),
+        );
+        let condition_elixir = crate::render::elixir::enum_atoms(&condition_elixir, inputs);
+
+        let condition_rust = guard_rust_optionals(condition_rust, &optionals);
+        let condition_go = guard_go_optionals(condition_go, &optionals);
+        let condition_swift = guard_swift_optionals(condition_swift, &optionals);
+
+        // Reference hoisted regex constants instead of compiling per call
+        let condition_rust = hoist_regexes(&condition_rust, Target::Rust, regex_patterns);
+        let condition_ts = hoist_regexes(&condition_ts, Target::TypeScript, regex_patterns);
+        let condition_py = hoist_regexes(&condition_py, Target::Python, regex_patterns);
+        let condition_go = hoist_regexes(&condition_go, Target::Go, regex_patterns);
+        let condition_java = hoist_regexes(&condition_java, Target::Java, regex_patterns);
+        let condition_csharp = hoist_regexes(&condition_csharp, Target::CSharp, regex_patterns);
+        let condition_kotlin = hoist_regexes(&condition_kotlin, Target::Kotlin, regex_patterns);
+        let condition_swift = hoist_regexes(&condition_swift, Target::Swift, regex_patterns);
+
         // Generate match patterns
         let pattern_rust = generate_rust_pattern(rule, inputs);
         let pattern_py = generate_python_pattern(rule, inputs);
+        let pattern_swift = generate_swift_pattern(rule, inputs);
+        let pattern_ruby = generate_ruby_pattern(rule, inputs);
 
-        let output = OutputValueView::from_output(&rule.then, input_names);
+        let output = OutputValueView::from_output(&rule.then, input_names, outputs);
+        let pattern_elixir =
+            crate::render::elixir::input_pattern(inputs, elixir_code(&condition_elixir, &output));
 
         Self {
             id: rule.id.clone(),
@@ -357,17 +933,47 @@ impl RuleView {
             condition_go,
             condition_java,
             condition_csharp,
+            condition_kotlin,
+            condition_swift,
+            condition_php,
+            condition_ruby,
+            condition_elixir,
             pattern_rust,
             pattern_py,
+            pattern_swift,
+            pattern_ruby,
+            pattern_elixir,
             output,
             is_cel,
             cel_expr,
+            description_lines: comment_lines(rule.description.as_deref()),
         }
     }
 }
 
+/// Elixir code a clause evaluates: its condition and every output value
+fn elixir_code<'a>(condition: &'a str, output: &'a OutputValueView) -> Vec<&'a str> {
+    let mut code = vec![condition, output.elixir.as_str()];
+    if let Some(named) = &output.named {
+        code.extend(named.values().map(|v| v.elixir.as_str()));
+    }
+    code
+}
+
+/// Lines of a description, safe to put in a line or block comment
+fn comment_lines(text: Option<&str>) -> Vec<String> {
+    text.map(|text| {
+        text.trim()
+            .lines()
+            .map(|line| line.trim_end().replace("*/", "* /"))
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
 impl OutputValueView {
-    fn from_output(output: &Output, input_names: &[String]) -> Self {
+    /// `outputs` give Go the result type of a top-level ternary
+    fn from_output(output: &Output, input_names: &[String], outputs: &[Variable]) -> Self {
         // Helper to build named output view from a map
         let build_named = |map: &HashMap<String, ConditionValue>| -> Self {
             let named: HashMap<String, NamedValueView> = map
@@ -379,9 +985,18 @@ impl OutputValueView {
                             rust: render_value_rust(v, input_names),
                             ts: render_value_ts(v, input_names),
                             py: render_value_python(v, input_names),
-                            go: render_value_go(v, input_names),
+                            go: render_typed_value_go(
+                                v,
+                                input_names,
+                                outputs.iter().find(|o| &o.name == k).map(|o| &o.typ),
+                            ),
                             java: render_value_java(v, input_names),
                             csharp: render_value_csharp(v, input_names),
+                            kotlin: render_value_kotlin(v, input_names),
+                            swift: render_value_swift(v, input_names),
+                            php: render_value_php(v, input_names),
+                            ruby: render_value_ruby(v),
+                            elixir: render_value_elixir(v),
                         },
                     )
                 })
@@ -394,6 +1009,11 @@ impl OutputValueView {
                 go: String::new(),
                 java: String::new(),
                 csharp: String::new(),
+                kotlin: String::new(),
+                swift: String::new(),
+                php: String::new(),
+                ruby: String::new(),
+                elixir: String::new(),
                 named: Some(named),
             }
         };
@@ -406,9 +1026,14 @@ impl OutputValueView {
                 rust: render_value_rust(val, input_names),
                 ts: render_value_ts(val, input_names),
                 py: render_value_python(val, input_names),
-                go: render_value_go(val, input_names),
+                go: render_typed_value_go(val, input_names, outputs.first().map(|o| &o.typ)),
                 java: render_value_java(val, input_names),
                 csharp: render_value_csharp(val, input_names),
+                kotlin: render_value_kotlin(val, input_names),
+                swift: render_value_swift(val, input_names),
+                php: render_value_php(val, input_names),
+                ruby: render_value_ruby(val),
+                elixir: render_value_elixir(val),
                 named: None,
             },
             Output::Named(map) => build_named(map),
@@ -429,7 +1054,7 @@ fn format_var_type(typ: &VarType) -> String {
         VarType::Int => "int".to_string(),
         VarType::Float => "float".to_string(),
         VarType::String => "string".to_string(),
-        VarType::Object => "object".to_string(),
+        VarType::Object(_) => "object".to_string(),
         VarType::Enum(variants) => format!("enum({})", variants.join("|")),
         VarType::List(inner) => format!("List<{}>", format_var_type(inner)),
     }
@@ -441,7 +1066,7 @@ fn map_type_rust(typ: &VarType) -> String {
         VarType::Int => "i64".to_string(),
         VarType::Float => "f64".to_string(),
         VarType::String => "String".to_string(),
-        VarType::Object => "serde_json::Value".to_string(),
+        VarType::Object(_) => "serde_json::Value".to_string(),
         VarType::Enum(_) => "String".to_string(),
         VarType::List(inner) => format!("Vec<{}>", map_type_rust(inner)),
     }
@@ -452,7 +1077,7 @@ fn map_type_ts(typ: &VarType) -> String {
         VarType::Bool => "boolean".to_string(),
         VarType::Int | VarType::Float => "number".to_string(),
         VarType::String => "string".to_string(),
-        VarType::Object => "Record<string, unknown>".to_string(),
+        VarType::Object(_) => "Record<string, unknown>".to_string(),
         VarType::Enum(variants) => {
             let quoted: Vec<_> = variants.iter().map(|v| format!("\"{}\"", v)).collect();
             quoted.join(" | ")
@@ -467,7 +1092,7 @@ fn map_type_python(typ: &VarType) -> String {
         VarType::Int => "int".to_string(),
         VarType::Float => "float".to_string(),
         VarType::String => "str".to_string(),
-        VarType::Object => "dict[str, Any]".to_string(),
+        VarType::Object(_) => "dict[str, Any]".to_string(),
         VarType::Enum(_) => "str".to_string(),
         VarType::List(inner) => format!("list[{}]", map_type_python(inner)),
     }
@@ -479,7 +1104,7 @@ fn map_type_go(typ: &VarType) -> String {
         VarType::Int => "int64".to_string(),
         VarType::Float => "float64".to_string(),
         VarType::String => "string".to_string(),
-        VarType::Object => "map[string]interface{}".to_string(),
+        VarType::Object(_) => "map[string]interface{}".to_string(),
         VarType::Enum(_) => "string".to_string(),
         VarType::List(inner) => format!("[]{}", map_type_go(inner)),
     }
@@ -491,7 +1116,7 @@ fn map_type_java(typ: &VarType) -> String {
         VarType::Int => "long".to_string(),
         VarType::Float => "double".to_string(),
         VarType::String => "String".to_string(),
-        VarType::Object => "Map<String, Object>".to_string(),
+        VarType::Object(_) => "Map<String, Object>".to_string(),
         VarType::Enum(_) => "String".to_string(),
         VarType::List(inner) => format!("List<{}>", map_type_java_boxed(inner)),
     }
@@ -512,12 +1137,101 @@ fn map_type_csharp(typ: &VarType) -> String {
         VarType::Int => "long".to_string(),
         VarType::Float => "double".to_string(),
         VarType::String => "string".to_string(),
-        VarType::Object => "Dictionary<string, object>".to_string(),
+        VarType::Object(_) => "Dictionary<string, object>".to_string(),
         VarType::Enum(_) => "string".to_string(),
         VarType::List(inner) => format!("List<{}>", map_type_csharp(inner)),
     }
 }
 
+fn map_type_kotlin(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "Boolean".to_string(),
+        VarType::Int => "Long".to_string(),
+        VarType::Float => "Double".to_string(),
+        VarType::String => "String".to_string(),
+        VarType::Object(_) => "Map<String, Any?>".to_string(),
+        VarType::Enum(_) => "String".to_string(),
+        VarType::List(inner) => format!("List<{}>", map_type_kotlin(inner)),
+    }
+}
+
+/// PHP property types cannot express element or field types, so lists and
+/// objects are plain arrays
+fn map_type_php(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "bool".to_string(),
+        VarType::Int => "int".to_string(),
+        VarType::Float => "float".to_string(),
+        VarType::String | VarType::Enum(_) => "string".to_string(),
+        VarType::List(_) | VarType::Object(_) => "array".to_string(),
+    }
+}
+
+fn map_type_swift(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "Bool".to_string(),
+        VarType::Int => "Int64".to_string(),
+        VarType::Float => "Double".to_string(),
+        VarType::String => "String".to_string(),
+        VarType::Object(_) => "[String: Any]".to_string(),
+        VarType::Enum(_) => "String".to_string(),
+        VarType::List(inner) => format!("[{}]", map_type_swift(inner)),
+    }
+}
+
+// ============================================================================
+// Zero values (builder defaults)
+// ============================================================================
+
+/// Enums default to their first variant rather than an empty string
+fn first_variant(variants: &[String]) -> Option<&str> {
+    variants.first().map(String::as_str)
+}
+
+fn zero_value_rust(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "false".to_string(),
+        VarType::Int => "0".to_string(),
+        VarType::Float => "0.0".to_string(),
+        VarType::String => "String::new()".to_string(),
+        VarType::Enum(variants) => match first_variant(variants) {
+            Some(v) => format!("\"{}\".to_string()", escape_string(v)),
+            None => "String::new()".to_string(),
+        },
+        VarType::List(_) => "Vec::new()".to_string(),
+        VarType::Object(_) => "Default::default()".to_string(),
+    }
+}
+
+fn zero_value_ts(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "false".to_string(),
+        VarType::Int | VarType::Float => "0".to_string(),
+        VarType::String => "\"\"".to_string(),
+        VarType::Enum(variants) => format!(
+            "\"{}\"",
+            escape_string(first_variant(variants).unwrap_or_default())
+        ),
+        VarType::List(_) => "[]".to_string(),
+        VarType::Object(_) => "{}".to_string(),
+    }
+}
+
+fn zero_value_python(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "False".to_string(),
+        VarType::Int => "0".to_string(),
+        VarType::Float => "0.0".to_string(),
+        VarType::String => "\"\"".to_string(),
+        VarType::Enum(variants) => format!(
+            "\"{}\"",
+            escape_string(first_variant(variants).unwrap_or_default())
+        ),
+        VarType::List(_) => "field(default_factory=list)".to_string(),
+        VarType::Object(_) => "field(default_factory=dict)".to_string(),
+    }
+}
+
 // ============================================================================
 // Value rendering helpers
 // ============================================================================
@@ -617,6 +1331,23 @@ fn render_value_python(val: &ConditionValue, input_names: &[String]) -> String {
     }
 }
 
+/// [`render_value_go`] for a value of type `typ`, which a ternary needs to
+/// become a typed closure
+fn render_typed_value_go(
+    val: &ConditionValue,
+    input_names: &[String],
+    typ: Option<&VarType>,
+) -> String {
+    match (val, typ) {
+        (ConditionValue::String(s), Some(typ)) if is_expression(s) => {
+            let compiled =
+                CelCompiler::compile_go_as(s, &map_type_go(typ)).unwrap_or_else(|_| s.to_string());
+            go_input_fields(compiled, input_names)
+        }
+        _ => render_value_go(val, input_names),
+    }
+}
+
 fn render_value_go(val: &ConditionValue, input_names: &[String]) -> String {
     match val {
         ConditionValue::Bool(b) => b.to_string(),
@@ -707,11 +1438,163 @@ fn render_value_csharp(val: &ConditionValue, input_names: &[String]) -> String {
     }
 }
 
+fn render_value_kotlin(val: &ConditionValue, input_names: &[String]) -> String {
+    match val {
+        ConditionValue::Bool(b) => b.to_string(),
+        ConditionValue::Int(i) => format!("{}L", i),
+        ConditionValue::Float(f) => format!("{:?}", f),
+        ConditionValue::String(s) => {
+            if is_expression(s) {
+                compile_kotlin_expression(s, input_names)
+            } else {
+                format!("\"{}\"", escape_kotlin_string(s))
+            }
+        }
+        ConditionValue::Null => "null".to_string(),
+        ConditionValue::List(items) => {
+            let rendered: Vec<_> = items
+                .iter()
+                .map(|i| render_value_kotlin(i, input_names))
+                .collect();
+            format!("listOf({})", rendered.join(", "))
+        }
+        ConditionValue::Map(map) => {
+            let pairs: Vec<_> = map
+                .iter()
+                .map(|(k, v)| format!("\"{}\" to {}", k, render_value_kotlin(v, input_names)))
+                .collect();
+            format!("mapOf({})", pairs.join(", "))
+        }
+    }
+}
+
+fn render_value_swift(val: &ConditionValue, input_names: &[String]) -> String {
+    match val {
+        ConditionValue::Bool(b) => b.to_string(),
+        ConditionValue::Int(i) => i.to_string(),
+        ConditionValue::Float(f) => format!("{:?}", f),
+        ConditionValue::String(s) => {
+            if is_expression(s) {
+                compile_swift_expression(s, input_names)
+            } else {
+                format!("\"{}\"", escape_string(s))
+            }
+        }
+        ConditionValue::Null => "nil".to_string(),
+        ConditionValue::List(items) => {
+            let rendered: Vec<_> = items
+                .iter()
+                .map(|i| render_value_swift(i, input_names))
+                .collect();
+            format!("[{}]", rendered.join(", "))
+        }
+        ConditionValue::Map(map) if map.is_empty() => "[:]".to_string(),
+        ConditionValue::Map(map) => {
+            let pairs: Vec<_> = map
+                .iter()
+                .map(|(k, v)| format!("\"{}\": {}", k, render_value_swift(v, input_names)))
+                .collect();
+            format!("[{}]", pairs.join(", "))
+        }
+    }
+}
+
+fn render_value_php(val: &ConditionValue, input_names: &[String]) -> String {
+    match val {
+        ConditionValue::Bool(b) => b.to_string(),
+        ConditionValue::Int(i) => i.to_string(),
+        ConditionValue::Float(f) => format!("{:?}", f),
+        ConditionValue::String(s) => {
+            if is_expression(s) {
+                compile_php_expression(s, input_names)
+            } else {
+                format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+            }
+        }
+        ConditionValue::Null => "null".to_string(),
+        ConditionValue::List(items) => {
+            let rendered: Vec<_> = items
+                .iter()
+                .map(|i| render_value_php(i, input_names))
+                .collect();
+            format!("[{}]", rendered.join(", "))
+        }
+        ConditionValue::Map(map) => {
+            let pairs: Vec<_> = map
+                .iter()
+                .map(|(k, v)| format!("'{}' => {}", k, render_value_php(v, input_names)))
+                .collect();
+            format!("[{}]", pairs.join(", "))
+        }
+    }
+}
+
+/// Inputs are locals in the generated method, so expressions need no
+/// renaming
+fn render_value_ruby(val: &ConditionValue) -> String {
+    match val {
+        ConditionValue::Bool(b) => b.to_string(),
+        ConditionValue::Int(i) => i.to_string(),
+        ConditionValue::Float(f) => format!("{:?}", f),
+        ConditionValue::String(s) => {
+            if is_expression(s) {
+                CelCompiler::compile(s, Target::Ruby).unwrap_or_else(|_| s.to_string())
+            } else {
+                format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+            }
+        }
+        ConditionValue::Null => "nil".to_string(),
+        ConditionValue::List(items) => {
+            let rendered: Vec<_> = items.iter().map(render_value_ruby).collect();
+            format!("[{}]", rendered.join(", "))
+        }
+        ConditionValue::Map(map) => {
+            let pairs: Vec<_> = map
+                .iter()
+                .map(|(k, v)| format!("{}: {}", k, render_value_ruby(v)))
+                .collect();
+            format!("{{ {} }}", pairs.join(", "))
+        }
+    }
+}
+
+/// Inputs are bound by the clause's map pattern, so expressions need no
+/// renaming
+fn render_value_elixir(val: &ConditionValue) -> String {
+    match val {
+        ConditionValue::Bool(b) => b.to_string(),
+        ConditionValue::Int(i) => i.to_string(),
+        ConditionValue::Float(f) => format!("{:?}", f),
+        ConditionValue::String(s) => {
+            if is_expression(s) {
+                CelCompiler::compile(s, Target::Elixir).unwrap_or_else(|_| s.to_string())
+            } else {
+                format!(
+                    "\"{}\"",
+                    s.escape_default().to_string().replace("#{", "\\#{")
+                )
+            }
+        }
+        ConditionValue::Null => "nil".to_string(),
+        ConditionValue::List(items) => {
+            let rendered: Vec<_> = items.iter().map(render_value_elixir).collect();
+            format!("[{}]", rendered.join(", "))
+        }
+        ConditionValue::Map(map) => {
+            let pairs: Vec<_> = map
+                .iter()
+                .map(|(k, v)| format!("{}: {}", k, render_value_elixir(v)))
+                .collect();
+            format!("%{{{}}}", pairs.join(", "))
+        }
+    }
+}
+
 // ============================================================================
 // Expression and pattern helpers
 // ============================================================================
 
-fn is_expression(s: &str) -> bool {
+pub(crate) fn is_expression(s: &str) -> bool {
     let has_operator = s.contains(" + ")
         || s.contains(" - ")
         || s.contains(" * ")
@@ -727,7 +1610,8 @@ fn is_expression(s: &str) -> bool {
         || s.contains(" || ")
         || s.contains(" ? ");
 
-    if has_operator {
+    // Ternaries written without spaces (`a>1?b:c`) still need to be compiled
+    if has_operator || CelCompiler::is_conditional(s) {
         return true;
     }
 
@@ -751,6 +1635,11 @@ fn escape_string(s: &str) -> String {
         .replace('\t', "\\t")
 }
 
+/// Kotlin string literals additionally treat `$` as a template marker
+fn escape_kotlin_string(s: &str) -> String {
+    escape_string(s).replace('$', "\\$")
+}
+
 fn compile_ts_condition(cel: &str, input_names: &[String]) -> String {
     let mut result =
         CelCompiler::compile(cel, Target::TypeScript).unwrap_or_else(|_| "true".into());
@@ -797,6 +1686,181 @@ fn compile_java_condition(cel: &str, input_names: &[String]) -> String {
     result
 }
 
+fn compile_kotlin_condition(cel: &str, input_names: &[String]) -> String {
+    let mut result = CelCompiler::compile(cel, Target::Kotlin).unwrap_or_else(|_| "true".into());
+    // Kotlin uses input.propertyName on the data class, like Java
+    for name in input_names {
+        let camel = to_camel_case(name);
+        result = replace_var_name(&result, name, &format!("input.{}", camel));
+    }
+    result
+}
+
+fn compile_swift_condition(cel: &str, input_names: &[String]) -> String {
+    let mut result = CelCompiler::compile(cel, Target::Swift).unwrap_or_else(|_| "true".into());
+    // Swift reads properties off the input struct
+    for name in input_names {
+        let camel = to_camel_case(name);
+        result = replace_var_name(&result, name, &format!("input.{}", camel));
+    }
+    result
+}
+
+fn compile_php_condition(cel: &str, input_names: &[String]) -> String {
+    let result = CelCompiler::compile(cel, Target::Php).unwrap_or_else(|_| "true".into());
+    php_properties(result, input_names)
+}
+
+/// Point `$input` variables at the typed properties `evaluate` fills in
+fn php_properties(code: String, input_names: &[String]) -> String {
+    input_names.iter().fold(code, |code, name| {
+        replace_var_name(&code, &format!("${}", name), &format!("$this->{}", name))
+    })
+}
+
+/// Bind each optional input a Rust condition reads, so the condition is
+/// false when the input is `None`
+fn guard_rust_optionals(code: String, optionals: &[&Variable]) -> String {
+    optionals.iter().rev().fold(code, |code, input| {
+        let borrow = match input.typ {
+            VarType::Bool | VarType::Int | VarType::Float => "",
+            // Native enums are `Copy`
+            VarType::Enum(_) if native_enum(&input.name, &input.typ).is_some() => "",
+            VarType::String | VarType::Enum(_) => ".as_deref()",
+            VarType::List(_) | VarType::Object(_) => ".as_ref()",
+        };
+        format!(
+            "{name}{borrow}.is_some_and(|{name}| {code})",
+            name = input.name
+        )
+    })
+}
+
+/// Dereference each optional (pointer) input a Go condition reads behind a
+/// `nil` check
+fn guard_go_optionals(code: String, optionals: &[&Variable]) -> String {
+    optionals.iter().rev().fold(code, |code, input| {
+        let field = format!("input.{}", to_pascal_case(&input.name));
+        let deref = replace_var_name(&code, &field, &format!("(*{})", field));
+        format!("{} != nil && ({})", field, deref)
+    })
+}
+
+/// Force-unwrap each optional input a Swift condition reads behind a `nil`
+/// check
+fn guard_swift_optionals(code: String, optionals: &[&Variable]) -> String {
+    optionals.iter().rev().fold(code, |code, input| {
+        let property = format!("input.{}", to_camel_case(&input.name));
+        let unwrapped = replace_var_name(&code, &property, &format!("{}!", property));
+        format!("{} != nil && ({})", property, unwrapped)
+    })
+}
+
+/// Go packages the generated code needs to import
+fn collect_go_imports(
+    rules: &[RuleView],
+    default: Option<&OutputValueView>,
+    regexes: &[RegexView],
+) -> Vec<String> {
+    let code: Vec<&str> = rules
+        .iter()
+        .flat_map(|r| [r.condition_go.as_str(), r.output.go.as_str()])
+        .chain(default.map(|d| d.go.as_str()))
+        .chain(regexes.iter().map(|r| r.go.as_str()))
+        .collect();
+
+    ["regexp", "strings"]
+        .into_iter()
+        .filter(|pkg| {
+            let qualifier = format!("{}.", pkg);
+            code.iter().any(|c| {
+                c.match_indices(&qualifier).any(|(pos, _)| {
+                    pos == 0 || {
+                        let before = c.as_bytes()[pos - 1];
+                        !before.is_ascii_alphanumeric() && before != b'_' && before != b'.'
+                    }
+                })
+            })
+        })
+        .map(String::from)
+        .collect()
+}
+
+/// Add a Go standard library import, keeping the list sorted
+fn require_go_import(imports: &mut Vec<String>, pkg: &str) {
+    if !imports.iter().any(|p| p == pkg) {
+        imports.push(pkg.to_string());
+        imports.sort();
+    }
+}
+
+/// Name of the hoisted constant for the `index`-th regex pattern
+fn regex_const_name(index: usize, target: Target) -> String {
+    match target {
+        Target::Go => format!("re{}", index),
+        Target::CSharp => format!("Re{}", index),
+        _ => format!("RE_{}", index),
+    }
+}
+
+/// Replace inline regex construction with the hoisted constants
+fn hoist_regexes(code: &str, target: Target, patterns: &[String]) -> String {
+    patterns
+        .iter()
+        .enumerate()
+        .fold(code.to_string(), |code, (i, pattern)| {
+            code.replace(
+                &CelCompiler::regex_literal_constructor(pattern, target),
+                &regex_const_name(i, target),
+            )
+        })
+}
+
+/// Local variable name used by the TypeScript and C# templates
+///
+/// Mirrors `compile_ts_condition`, which only renames inputs containing `_`.
+fn ts_local(name: &str) -> String {
+    if name.contains('_') {
+        to_camel_case(name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Rewrite `input.field` paths on object inputs to the target's field casing
+///
+/// `root` renders an input the way the target's compiled condition already
+/// refers to it (e.g. `input.User` for Go); `field` renders each field name.
+/// Paths are rewritten parent-first so nested fields see the renamed prefix.
+fn translate_field_paths(
+    code: &str,
+    inputs: &[Variable],
+    root: impl Fn(&str) -> String,
+    field: fn(&str) -> String,
+) -> String {
+    fn walk(
+        code: String,
+        prefix: &str,
+        fields: &[(String, VarType)],
+        field: fn(&str) -> String,
+    ) -> String {
+        let mut code = code;
+        for (name, typ) in fields {
+            let from = format!("{}.{}", prefix, name);
+            let to = format!("{}.{}", prefix, field(name));
+            if from != to {
+                code = replace_var_name(&code, &from, &to);
+            }
+            code = walk(code, &to, typ.object_fields(), field);
+        }
+        code
+    }
+
+    inputs.iter().fold(code.to_string(), |code, input| {
+        walk(code, &root(&input.name), input.typ.object_fields(), field)
+    })
+}
+
 /// Replace variable name with word boundary awareness
 /// This prevents replacing "member_tier" inside "non_member_tier"
 fn replace_var_name(source: &str, from: &str, to: &str) -> String {
@@ -849,12 +1913,17 @@ fn compile_ts_expression(expr: &str, input_names: &[String]) -> String {
 }
 
 fn compile_go_expression(expr: &str, input_names: &[String]) -> String {
-    let mut result = CelCompiler::compile(expr, Target::Go).unwrap_or_else(|_| expr.to_string());
+    let result = CelCompiler::compile(expr, Target::Go).unwrap_or_else(|_| expr.to_string());
+    go_input_fields(result, input_names)
+}
+
+/// Point each input name in compiled Go at its `input` struct field
+fn go_input_fields(mut code: String, input_names: &[String]) -> String {
     for name in input_names {
         let pascal = to_pascal_case(name);
-        result = replace_var_name(&result, name, &format!("input.{}", pascal));
+        code = replace_var_name(&code, name, &format!("input.{}", pascal));
     }
-    result
+    code
 }
 
 fn compile_java_expression(expr: &str, input_names: &[String]) -> String {
@@ -866,6 +1935,30 @@ fn compile_java_expression(expr: &str, input_names: &[String]) -> String {
     result
 }
 
+fn compile_kotlin_expression(expr: &str, input_names: &[String]) -> String {
+    let mut result =
+        CelCompiler::compile(expr, Target::Kotlin).unwrap_or_else(|_| expr.to_string());
+    for name in input_names {
+        let camel = to_camel_case(name);
+        result = replace_var_name(&result, name, &format!("input.{}", camel));
+    }
+    result
+}
+
+fn compile_php_expression(expr: &str, input_names: &[String]) -> String {
+    let result = CelCompiler::compile(expr, Target::Php).unwrap_or_else(|_| expr.to_string());
+    php_properties(result, input_names)
+}
+
+fn compile_swift_expression(expr: &str, input_names: &[String]) -> String {
+    let mut result = CelCompiler::compile(expr, Target::Swift).unwrap_or_else(|_| expr.to_string());
+    for name in input_names {
+        let camel = to_camel_case(name);
+        result = replace_var_name(&result, name, &format!("input.{}", camel));
+    }
+    result
+}
+
 fn compile_csharp_expression(expr: &str, input_names: &[String]) -> String {
     let mut result =
         CelCompiler::compile(expr, Target::CSharp).unwrap_or_else(|_| expr.to_string());
@@ -885,7 +1978,7 @@ fn generate_rust_pattern(rule: &Rule, inputs: &[Variable]) -> String {
     if inputs.len() == 1 {
         conditions
             .and_then(|c| c.first())
-            .map(|c| render_pattern_value_rust(&c.value))
+            .map(|c| rust_pattern(&inputs[0], &c.value))
             .unwrap_or_else(|| "_".into())
     } else {
         let patterns: Vec<String> = inputs
@@ -893,7 +1986,7 @@ fn generate_rust_pattern(rule: &Rule, inputs: &[Variable]) -> String {
             .map(|input| {
                 conditions
                     .and_then(|c| c.iter().find(|cond| cond.var == input.name))
-                    .map(|c| render_pattern_value_rust(&c.value))
+                    .map(|c| rust_pattern(input, &c.value))
                     .unwrap_or_else(|| "_".into())
             })
             .collect();
@@ -923,6 +2016,57 @@ fn generate_python_pattern(rule: &Rule, inputs: &[Variable]) -> String {
     }
 }
 
+/// Array pattern over the inputs, matched against `case [a, b]`; a single
+/// input is matched directly
+fn generate_ruby_pattern(rule: &Rule, inputs: &[Variable]) -> String {
+    let conditions = rule.conditions.as_ref();
+    let patterns: Vec<String> = inputs
+        .iter()
+        .map(|input| {
+            conditions
+                .and_then(|c| c.iter().find(|cond| cond.var == input.name))
+                .map(|c| render_pattern_value_ruby(&c.value))
+                .unwrap_or_else(|| "_".into())
+        })
+        .collect();
+
+    if patterns.len() == 1 {
+        patterns[0].clone()
+    } else {
+        format!("[{}]", patterns.join(", "))
+    }
+}
+
+fn generate_swift_pattern(rule: &Rule, inputs: &[Variable]) -> String {
+    let conditions = rule.conditions.as_ref();
+    let patterns: Vec<String> = inputs
+        .iter()
+        .map(|input| {
+            conditions
+                .and_then(|c| c.iter().find(|cond| cond.var == input.name))
+                .map(|c| render_pattern_value_swift(&c.value))
+                .unwrap_or_else(|| "_".into())
+        })
+        .collect();
+
+    if patterns.len() == 1 {
+        patterns[0].clone()
+    } else {
+        format!("({})", patterns.join(", "))
+    }
+}
+
+/// Match pattern for a value of `input`, naming the variant of a native enum
+fn rust_pattern(input: &Variable, val: &ConditionValue) -> String {
+    let variant = match (native_enum(&input.name, &input.typ), val) {
+        (Some(native), ConditionValue::String(s)) => native
+            .variant(s)
+            .map(|v| format!("{}::{}", native.name, v.ident)),
+        _ => None,
+    };
+    variant.unwrap_or_else(|| render_pattern_value_rust(val))
+}
+
 fn render_pattern_value_rust(val: &ConditionValue) -> String {
     match val {
         ConditionValue::Bool(b) => b.to_string(),
@@ -945,6 +2089,29 @@ fn render_pattern_value_python(val: &ConditionValue) -> String {
     }
 }
 
+fn render_pattern_value_ruby(val: &ConditionValue) -> String {
+    match val {
+        ConditionValue::Bool(b) => b.to_string(),
+        ConditionValue::Int(i) => i.to_string(),
+        ConditionValue::Float(f) => format!("{:?}", f),
+        ConditionValue::String(s) => {
+            format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+        }
+        ConditionValue::Null => "nil".to_string(),
+        _ => "_".to_string(),
+    }
+}
+
+fn render_pattern_value_swift(val: &ConditionValue) -> String {
+    match val {
+        ConditionValue::Bool(b) => b.to_string(),
+        ConditionValue::Int(i) => i.to_string(),
+        ConditionValue::Float(f) => format!("{:?}", f),
+        ConditionValue::String(s) => format!("\"{}\"", escape_string(s)),
+        _ => "_".to_string(),
+    }
+}
+
 // ============================================================================
 // Orchestrator context
 // ============================================================================
@@ -968,6 +2135,10 @@ pub struct OrchestratorContext {
     pub outputs: Vec<OutputView>,
     /// Steps
     pub steps: Vec<StepView>,
+    /// Whether any step runs branches in parallel (generated code is async)
+    pub has_parallel: bool,
+    /// Wrap each step in a tracing span
+    pub telemetry: bool,
     /// Target language
     pub target: String,
     // Namespace fields for scoping
@@ -981,6 +2152,33 @@ pub struct OrchestratorContext {
     pub module: Option<String>,
 }
 
+/// Retry policy of a call step
+#[derive(Debug, Clone, Serialize)]
+pub struct RetryView {
+    pub max_attempts: u32,
+    pub delay_ms: u64,
+    /// Double the delay after each failed attempt
+    pub exponential: bool,
+}
+
+impl From<&crate::orchestrate::RetryConfig> for RetryView {
+    fn from(retry: &crate::orchestrate::RetryConfig) -> Self {
+        Self {
+            max_attempts: retry.max_attempts.max(1),
+            delay_ms: retry.delay_ms,
+            exponential: retry.exponential,
+        }
+    }
+}
+
+/// Branches of a parallel step and how to wait for them
+#[derive(Debug, Clone, Serialize)]
+pub struct ParallelView {
+    /// `all`, `any` or `first_success`
+    pub wait: String,
+    pub steps: Vec<StepView>,
+}
+
 /// View of an orchestrator step
 #[derive(Debug, Clone, Serialize)]
 pub struct StepView {
@@ -1009,6 +2207,13 @@ pub struct StepView {
     pub condition_go: Option<String>,
     pub condition_java: Option<String>,
     pub condition_csharp: Option<String>,
+    pub condition_swift: Option<String>,
+    /// Gate failure message (`error:` on the gate step)
+    pub error: Option<String>,
+    /// Retry policy for a call step
+    pub retry: Option<RetryView>,
+    /// Branches of a parallel step
+    pub parallel: Option<ParallelView>,
     /// Input mappings for Call steps: spec_input_name -> compiled expression
     pub input_mappings: Vec<InputMapping>,
     /// Output mappings for Call steps: local_name -> spec_output_name
@@ -1032,6 +2237,8 @@ pub struct InputMapping {
     pub expr_java: String,
     /// Compiled expression in C#
     pub expr_csharp: String,
+    /// Compiled expression in Swift
+    pub expr_swift: String,
 }
 
 /// Output mapping for a Call step
@@ -1050,8 +2257,6 @@ impl OrchestratorContext {
         target: Target,
         provenance: bool,
     ) -> Self {
-        use crate::orchestrate::ChainStep;
-
         let inputs: Vec<InputView> = orch.inputs.iter().map(InputView::from_orch_var).collect();
         let outputs: Vec<OutputView> = orch.outputs.iter().map(OutputView::from_orch_var).collect();
         let input_names: Vec<String> = inputs.iter().map(|i| i.name.clone()).collect();
@@ -1059,362 +2264,9 @@ impl OrchestratorContext {
         let steps: Vec<StepView> = orch
             .chain
             .iter()
-            .map(|s| {
-                match s {
-                    ChainStep::Call(call) => {
-                        // Compile input mappings
-                        let input_mappings: Vec<InputMapping> = call
-                            .inputs
-                            .iter()
-                            .map(|(spec_input, expr)| {
-                                let _spec_input_pascal = to_pascal_case(spec_input);
-                                let _spec_input_camel = to_camel_case(spec_input);
-                                InputMapping {
-                                    spec_input_name: spec_input.clone(),
-                                    expr_rust: compile_orch_expr_rust(expr, &input_names),
-                                    expr_ts: compile_orch_expr_ts(expr, &input_names),
-                                    expr_py: compile_orch_expr_py(expr, &input_names),
-                                    expr_go: compile_orch_expr_go(expr, &input_names),
-                                    expr_java: compile_orch_expr_java(expr, &input_names),
-                                    expr_csharp: compile_orch_expr_csharp(expr, &input_names),
-                                }
-                            })
-                            .collect();
-
-                        // Compile output mappings
-                        let output_mappings: Vec<OutputMapping> = call
-                            .outputs
-                            .iter()
-                            .map(|(local_name, spec_output)| OutputMapping {
-                                local_name: local_name.clone(),
-                                spec_output_name: spec_output.clone(),
-                            })
-                            .collect();
-
-                        // Compile condition if present
-                        let (
-                            condition_rust,
-                            condition_ts,
-                            condition_py,
-                            condition_go,
-                            condition_java,
-                            condition_csharp,
-                        ) = if let Some(cond) = &call.condition {
-                            (
-                                Some(compile_orch_expr_rust(cond, &input_names)),
-                                Some(compile_orch_expr_ts(cond, &input_names)),
-                                Some(compile_orch_expr_py(cond, &input_names)),
-                                Some(compile_orch_expr_go(cond, &input_names)),
-                                Some(compile_orch_expr_java(cond, &input_names)),
-                                Some(compile_orch_expr_csharp(cond, &input_names)),
-                            )
-                        } else {
-                            (None, None, None, None, None, None)
-                        };
-
-                        StepView {
-                            id: call.id.clone(),
-                            step_type: "Call".to_string(),
-                            spec_id: Some(call.spec.clone()),
-                            is_gate: false,
-                            is_call: true,
-                            is_compute: false,
-                            is_branch: false,
-                            is_loop: false,
-                            condition: call.condition.clone(),
-                            condition_rust,
-                            condition_ts,
-                            condition_py,
-                            condition_go,
-                            condition_java,
-                            condition_csharp,
-                            input_mappings,
-                            output_mappings,
-                        }
-                    }
-                    ChainStep::Gate(gate) => {
-                        let cond = gate.condition.clone();
-                        StepView {
-                            id: gate.id.clone(),
-                            step_type: "Gate".to_string(),
-                            spec_id: None,
-                            is_gate: true,
-                            is_call: false,
-                            is_compute: false,
-                            is_branch: false,
-                            is_loop: false,
-                            condition: Some(cond.clone()),
-                            condition_rust: Some(compile_orch_expr_rust(&cond, &input_names)),
-                            condition_ts: Some(compile_orch_expr_ts(&cond, &input_names)),
-                            condition_py: Some(compile_orch_expr_py(&cond, &input_names)),
-                            condition_go: Some(compile_orch_expr_go(&cond, &input_names)),
-                            condition_java: Some(compile_orch_expr_java(&cond, &input_names)),
-                            condition_csharp: Some(compile_orch_expr_csharp(&cond, &input_names)),
-                            input_mappings: Vec::new(),
-                            output_mappings: Vec::new(),
-                        }
-                    }
-                    ChainStep::Compute(compute) => StepView {
-                        id: compute.id.clone(),
-                        step_type: "Compute".to_string(),
-                        spec_id: None,
-                        is_gate: false,
-                        is_call: false,
-                        is_compute: true,
-                        is_branch: false,
-                        is_loop: false,
-                        condition: None,
-                        condition_rust: None,
-                        condition_ts: None,
-                        condition_py: None,
-                        condition_go: None,
-                        condition_java: None,
-                        condition_csharp: None,
-                        input_mappings: Vec::new(),
-                        output_mappings: Vec::new(),
-                    },
-                    ChainStep::Branch(branch) => {
-                        let cond = branch.on.clone();
-                        StepView {
-                            id: branch.id.clone(),
-                            step_type: "Branch".to_string(),
-                            spec_id: None,
-                            is_gate: false,
-                            is_call: false,
-                            is_compute: false,
-                            is_branch: true,
-                            is_loop: false,
-                            condition: Some(cond.clone()),
-                            condition_rust: Some(compile_orch_expr_rust(&cond, &input_names)),
-                            condition_ts: Some(compile_orch_expr_ts(&cond, &input_names)),
-                            condition_py: Some(compile_orch_expr_py(&cond, &input_names)),
-                            condition_go: Some(compile_orch_expr_go(&cond, &input_names)),
-                            condition_java: Some(compile_orch_expr_java(&cond, &input_names)),
-                            condition_csharp: Some(compile_orch_expr_csharp(&cond, &input_names)),
-                            input_mappings: Vec::new(),
-                            output_mappings: Vec::new(),
-                        }
-                    }
-                    ChainStep::Loop(loop_step) => {
-                        let cond = loop_step.until.clone();
-                        StepView {
-                            id: loop_step.id.clone(),
-                            step_type: "Loop".to_string(),
-                            spec_id: None,
-                            is_gate: false,
-                            is_call: false,
-                            is_compute: false,
-                            is_branch: false,
-                            is_loop: true,
-                            condition: cond.clone(),
-                            condition_rust: cond
-                                .as_ref()
-                                .map(|c| compile_orch_expr_rust(c, &input_names)),
-                            condition_ts: cond
-                                .as_ref()
-                                .map(|c| compile_orch_expr_ts(c, &input_names)),
-                            condition_py: cond
-                                .as_ref()
-                                .map(|c| compile_orch_expr_py(c, &input_names)),
-                            condition_go: cond
-                                .as_ref()
-                                .map(|c| compile_orch_expr_go(c, &input_names)),
-                            condition_java: cond
-                                .as_ref()
-                                .map(|c| compile_orch_expr_java(c, &input_names)),
-                            condition_csharp: cond
-                                .as_ref()
-                                .map(|c| compile_orch_expr_csharp(c, &input_names)),
-                            input_mappings: Vec::new(),
-                            output_mappings: Vec::new(),
-                        }
-                    }
-                    ChainStep::ForEach(foreach) => StepView {
-                        id: foreach.id.clone(),
-                        step_type: "ForEach".to_string(),
-                        spec_id: None,
-                        is_gate: false,
-                        is_call: false,
-                        is_compute: false,
-                        is_branch: false,
-                        is_loop: true,
-                        condition: None,
-                        condition_rust: None,
-                        condition_ts: None,
-                        condition_py: None,
-                        condition_go: None,
-                        condition_java: None,
-                        condition_csharp: None,
-                        input_mappings: Vec::new(),
-                        output_mappings: Vec::new(),
-                    },
-                    ChainStep::Parallel(par) => StepView {
-                        id: par.id.clone(),
-                        step_type: "Parallel".to_string(),
-                        spec_id: None,
-                        is_gate: false,
-                        is_call: false,
-                        is_compute: false,
-                        is_branch: false,
-                        is_loop: false,
-                        condition: None,
-                        condition_rust: None,
-                        condition_ts: None,
-                        condition_py: None,
-                        condition_go: None,
-                        condition_java: None,
-                        condition_csharp: None,
-                        input_mappings: Vec::new(),
-                        output_mappings: Vec::new(),
-                    },
-                    ChainStep::Return(ret) => {
-                        let cond = ret.condition.clone();
-                        StepView {
-                            id: "return".to_string(),
-                            step_type: "Return".to_string(),
-                            spec_id: None,
-                            is_gate: false,
-                            is_call: false,
-                            is_compute: false,
-                            is_branch: false,
-                            is_loop: false,
-                            condition: cond.clone(),
-                            condition_rust: cond
-                                .as_ref()
-                                .map(|c| compile_orch_expr_rust(c, &input_names)),
-                            condition_ts: cond
-                                .as_ref()
-                                .map(|c| compile_orch_expr_ts(c, &input_names)),
-                            condition_py: cond
-                                .as_ref()
-                                .map(|c| compile_orch_expr_py(c, &input_names)),
-                            condition_go: cond
-                                .as_ref()
-                                .map(|c| compile_orch_expr_go(c, &input_names)),
-                            condition_java: cond
-                                .as_ref()
-                                .map(|c| compile_orch_expr_java(c, &input_names)),
-                            condition_csharp: cond
-                                .as_ref()
-                                .map(|c| compile_orch_expr_csharp(c, &input_names)),
-                            input_mappings: Vec::new(),
-                            output_mappings: Vec::new(),
-                        }
-                    }
-                    ChainStep::Set(set) => StepView {
-                        id: format!("set_{}", set.name),
-                        step_type: "Set".to_string(),
-                        spec_id: None,
-                        is_gate: false,
-                        is_call: false,
-                        is_compute: true,
-                        is_branch: false,
-                        is_loop: false,
-                        condition: None,
-                        condition_rust: None,
-                        condition_ts: None,
-                        condition_py: None,
-                        condition_go: None,
-                        condition_java: None,
-                        condition_csharp: None,
-                        input_mappings: Vec::new(),
-                        output_mappings: Vec::new(),
-                    },
-                    ChainStep::Try(try_step) => StepView {
-                        id: try_step.id.clone(),
-                        step_type: "Try".to_string(),
-                        spec_id: None,
-                        is_gate: false,
-                        is_call: false,
-                        is_compute: false,
-                        is_branch: false,
-                        is_loop: false,
-                        condition: None,
-                        condition_rust: None,
-                        condition_ts: None,
-                        condition_py: None,
-                        condition_go: None,
-                        condition_java: None,
-                        condition_csharp: None,
-                        input_mappings: Vec::new(),
-                        output_mappings: Vec::new(),
-                    },
-                    ChainStep::Dynamic(dyn_step) => {
-                        // Similar to Call step but with dynamic spec selection
-                        let input_mappings: Vec<InputMapping> = dyn_step
-                            .inputs
-                            .iter()
-                            .map(|(spec_input, expr)| InputMapping {
-                                spec_input_name: spec_input.clone(),
-                                expr_rust: compile_orch_expr_rust(expr, &input_names),
-                                expr_ts: compile_orch_expr_ts(expr, &input_names),
-                                expr_py: compile_orch_expr_py(expr, &input_names),
-                                expr_go: compile_orch_expr_go(expr, &input_names),
-                                expr_java: compile_orch_expr_java(expr, &input_names),
-                                expr_csharp: compile_orch_expr_csharp(expr, &input_names),
-                            })
-                            .collect();
-                        StepView {
-                            id: dyn_step.id.clone(),
-                            step_type: "Dynamic".to_string(),
-                            spec_id: Some(dyn_step.spec.clone()),
-                            is_gate: false,
-                            is_call: true,
-                            is_compute: false,
-                            is_branch: false,
-                            is_loop: false,
-                            condition: None,
-                            condition_rust: None,
-                            condition_ts: None,
-                            condition_py: None,
-                            condition_go: None,
-                            condition_java: None,
-                            condition_csharp: None,
-                            input_mappings,
-                            output_mappings: Vec::new(),
-                        }
-                    }
-                    ChainStep::Await(await_step) => StepView {
-                        id: await_step.id.clone(),
-                        step_type: "Await".to_string(),
-                        spec_id: None,
-                        is_gate: false,
-                        is_call: false,
-                        is_compute: false,
-                        is_branch: false,
-                        is_loop: false,
-                        condition: None,
-                        condition_rust: None,
-                        condition_ts: None,
-                        condition_py: None,
-                        condition_go: None,
-                        condition_java: None,
-                        condition_csharp: None,
-                        input_mappings: Vec::new(),
-                        output_mappings: Vec::new(),
-                    },
-                    ChainStep::Emit(emit) => StepView {
-                        id: format!("emit_{}", emit.event),
-                        step_type: "Emit".to_string(),
-                        spec_id: None,
-                        is_gate: false,
-                        is_call: false,
-                        is_compute: false,
-                        is_branch: false,
-                        is_loop: false,
-                        condition: None,
-                        condition_rust: None,
-                        condition_ts: None,
-                        condition_py: None,
-                        condition_go: None,
-                        condition_java: None,
-                        condition_csharp: None,
-                        input_mappings: Vec::new(),
-                        output_mappings: Vec::new(),
-                    },
-                }
-            })
+            .map(|s| step_view(s, &input_names))
             .collect();
+        let has_parallel = steps.iter().any(|s| s.parallel.is_some());
 
         // Extract namespace from orchestrator's scoping config if present
         let (namespace, package, module_path, module) = extract_orch_namespace_fields(orch, target);
@@ -1428,6 +2280,8 @@ impl OrchestratorContext {
             inputs,
             outputs,
             steps,
+            has_parallel,
+            telemetry: false,
             target: format!("{:?}", target),
             namespace,
             package,
@@ -1437,6 +2291,413 @@ impl OrchestratorContext {
     }
 }
 
+/// Compile a step's input mappings, ordered by spec input name
+fn input_mappings(inputs: &HashMap<String, String>, input_names: &[String]) -> Vec<InputMapping> {
+    let mut inputs: Vec<_> = inputs.iter().collect();
+    inputs.sort();
+    inputs
+        .into_iter()
+        .map(|(spec_input, expr)| InputMapping {
+            spec_input_name: spec_input.clone(),
+            expr_rust: compile_orch_expr_rust(expr, input_names),
+            expr_ts: compile_orch_expr_ts(expr, input_names),
+            expr_py: compile_orch_expr_py(expr, input_names),
+            expr_go: compile_orch_expr_go(expr, input_names),
+            expr_java: compile_orch_expr_java(expr, input_names),
+            expr_csharp: compile_orch_expr_csharp(expr, input_names),
+            expr_swift: compile_orch_expr_swift(expr, input_names),
+        })
+        .collect()
+}
+
+/// Build the template view of one chain step
+fn step_view(s: &crate::orchestrate::ChainStep, input_names: &[String]) -> StepView {
+    use crate::orchestrate::{ChainStep, WaitStrategy};
+
+    match s {
+        ChainStep::Call(call) => {
+            // Sorted by name so regeneration is byte-stable
+            let input_mappings = input_mappings(&call.inputs, input_names);
+            let mut outputs: Vec<_> = call.outputs.iter().collect();
+            outputs.sort();
+            let output_mappings: Vec<OutputMapping> = outputs
+                .into_iter()
+                .map(|(local_name, spec_output)| OutputMapping {
+                    local_name: local_name.clone(),
+                    spec_output_name: spec_output.clone(),
+                })
+                .collect();
+
+            // Compile condition if present
+            let (
+                condition_rust,
+                condition_ts,
+                condition_py,
+                condition_go,
+                condition_java,
+                condition_csharp,
+                condition_swift,
+            ) = if let Some(cond) = &call.condition {
+                (
+                    Some(compile_orch_expr_rust(cond, input_names)),
+                    Some(compile_orch_expr_ts(cond, input_names)),
+                    Some(compile_orch_expr_py(cond, input_names)),
+                    Some(compile_orch_expr_go(cond, input_names)),
+                    Some(compile_orch_expr_java(cond, input_names)),
+                    Some(compile_orch_expr_csharp(cond, input_names)),
+                    Some(compile_orch_expr_swift(cond, input_names)),
+                )
+            } else {
+                (None, None, None, None, None, None, None)
+            };
+
+            StepView {
+                id: call.id.clone(),
+                step_type: "Call".to_string(),
+                spec_id: Some(call.spec.clone()),
+                is_gate: false,
+                is_call: true,
+                is_compute: false,
+                is_branch: false,
+                is_loop: false,
+                condition: call.condition.clone(),
+                condition_rust,
+                condition_ts,
+                condition_py,
+                condition_go,
+                condition_java,
+                condition_csharp,
+                condition_swift,
+                error: None,
+                retry: call.retry.as_ref().map(RetryView::from),
+                parallel: None,
+                input_mappings,
+                output_mappings,
+            }
+        }
+        ChainStep::Gate(gate) => {
+            let cond = gate.condition.clone();
+            StepView {
+                id: gate.id.clone(),
+                step_type: "Gate".to_string(),
+                spec_id: None,
+                is_gate: true,
+                is_call: false,
+                is_compute: false,
+                is_branch: false,
+                is_loop: false,
+                condition: Some(cond.clone()),
+                condition_rust: Some(compile_orch_expr_rust(&cond, input_names)),
+                condition_ts: Some(compile_orch_expr_ts(&cond, input_names)),
+                condition_py: Some(compile_orch_expr_py(&cond, input_names)),
+                condition_go: Some(compile_orch_expr_go(&cond, input_names)),
+                condition_java: Some(compile_orch_expr_java(&cond, input_names)),
+                condition_csharp: Some(compile_orch_expr_csharp(&cond, input_names)),
+                condition_swift: Some(compile_orch_expr_swift(&cond, input_names)),
+                error: gate.error.clone(),
+                retry: None,
+                parallel: None,
+                input_mappings: Vec::new(),
+                output_mappings: Vec::new(),
+            }
+        }
+        ChainStep::Compute(compute) => StepView {
+            id: compute.id.clone(),
+            step_type: "Compute".to_string(),
+            spec_id: None,
+            is_gate: false,
+            is_call: false,
+            is_compute: true,
+            is_branch: false,
+            is_loop: false,
+            condition: None,
+            condition_rust: None,
+            condition_ts: None,
+            condition_py: None,
+            condition_go: None,
+            condition_java: None,
+            condition_csharp: None,
+            condition_swift: None,
+            error: None,
+            retry: None,
+            parallel: None,
+            input_mappings: Vec::new(),
+            output_mappings: Vec::new(),
+        },
+        ChainStep::Branch(branch) => {
+            let cond = branch.on.clone();
+            StepView {
+                id: branch.id.clone(),
+                step_type: "Branch".to_string(),
+                spec_id: None,
+                is_gate: false,
+                is_call: false,
+                is_compute: false,
+                is_branch: true,
+                is_loop: false,
+                condition: Some(cond.clone()),
+                condition_rust: Some(compile_orch_expr_rust(&cond, input_names)),
+                condition_ts: Some(compile_orch_expr_ts(&cond, input_names)),
+                condition_py: Some(compile_orch_expr_py(&cond, input_names)),
+                condition_go: Some(compile_orch_expr_go(&cond, input_names)),
+                condition_java: Some(compile_orch_expr_java(&cond, input_names)),
+                condition_csharp: Some(compile_orch_expr_csharp(&cond, input_names)),
+                condition_swift: Some(compile_orch_expr_swift(&cond, input_names)),
+                error: None,
+                retry: None,
+                parallel: None,
+                input_mappings: Vec::new(),
+                output_mappings: Vec::new(),
+            }
+        }
+        ChainStep::Loop(loop_step) => {
+            let cond = loop_step.until.clone();
+            StepView {
+                id: loop_step.id.clone(),
+                step_type: "Loop".to_string(),
+                spec_id: None,
+                is_gate: false,
+                is_call: false,
+                is_compute: false,
+                is_branch: false,
+                is_loop: true,
+                condition: cond.clone(),
+                condition_rust: cond
+                    .as_ref()
+                    .map(|c| compile_orch_expr_rust(c, input_names)),
+                condition_ts: cond.as_ref().map(|c| compile_orch_expr_ts(c, input_names)),
+                condition_py: cond.as_ref().map(|c| compile_orch_expr_py(c, input_names)),
+                condition_go: cond.as_ref().map(|c| compile_orch_expr_go(c, input_names)),
+                condition_java: cond
+                    .as_ref()
+                    .map(|c| compile_orch_expr_java(c, input_names)),
+                condition_csharp: cond
+                    .as_ref()
+                    .map(|c| compile_orch_expr_csharp(c, input_names)),
+                condition_swift: cond
+                    .as_ref()
+                    .map(|c| compile_orch_expr_swift(c, input_names)),
+                error: None,
+                retry: None,
+                parallel: None,
+                input_mappings: Vec::new(),
+                output_mappings: Vec::new(),
+            }
+        }
+        ChainStep::ForEach(foreach) => StepView {
+            id: foreach.id.clone(),
+            step_type: "ForEach".to_string(),
+            spec_id: None,
+            is_gate: false,
+            is_call: false,
+            is_compute: false,
+            is_branch: false,
+            is_loop: true,
+            condition: None,
+            condition_rust: None,
+            condition_ts: None,
+            condition_py: None,
+            condition_go: None,
+            condition_java: None,
+            condition_csharp: None,
+            condition_swift: None,
+            error: None,
+            retry: None,
+            parallel: None,
+            input_mappings: Vec::new(),
+            output_mappings: Vec::new(),
+        },
+        ChainStep::Parallel(par) => StepView {
+            id: par.id.clone(),
+            step_type: "Parallel".to_string(),
+            spec_id: None,
+            is_gate: false,
+            is_call: false,
+            is_compute: false,
+            is_branch: false,
+            is_loop: false,
+            condition: None,
+            condition_rust: None,
+            condition_ts: None,
+            condition_py: None,
+            condition_go: None,
+            condition_java: None,
+            condition_csharp: None,
+            condition_swift: None,
+            error: None,
+            retry: None,
+            parallel: Some(ParallelView {
+                wait: match par.wait {
+                    WaitStrategy::All => "all",
+                    WaitStrategy::Any => "any",
+                    WaitStrategy::FirstSuccess => "first_success",
+                }
+                .to_string(),
+                steps: par
+                    .steps
+                    .iter()
+                    .map(|s| step_view(s, input_names))
+                    .collect(),
+            }),
+            input_mappings: Vec::new(),
+            output_mappings: Vec::new(),
+        },
+        ChainStep::Return(ret) => {
+            let cond = ret.condition.clone();
+            StepView {
+                id: "return".to_string(),
+                step_type: "Return".to_string(),
+                spec_id: None,
+                is_gate: false,
+                is_call: false,
+                is_compute: false,
+                is_branch: false,
+                is_loop: false,
+                condition: cond.clone(),
+                condition_rust: cond
+                    .as_ref()
+                    .map(|c| compile_orch_expr_rust(c, input_names)),
+                condition_ts: cond.as_ref().map(|c| compile_orch_expr_ts(c, input_names)),
+                condition_py: cond.as_ref().map(|c| compile_orch_expr_py(c, input_names)),
+                condition_go: cond.as_ref().map(|c| compile_orch_expr_go(c, input_names)),
+                condition_java: cond
+                    .as_ref()
+                    .map(|c| compile_orch_expr_java(c, input_names)),
+                condition_csharp: cond
+                    .as_ref()
+                    .map(|c| compile_orch_expr_csharp(c, input_names)),
+                condition_swift: cond
+                    .as_ref()
+                    .map(|c| compile_orch_expr_swift(c, input_names)),
+                error: None,
+                retry: None,
+                parallel: None,
+                input_mappings: Vec::new(),
+                output_mappings: Vec::new(),
+            }
+        }
+        ChainStep::Set(set) => StepView {
+            id: format!("set_{}", set.name),
+            step_type: "Set".to_string(),
+            spec_id: None,
+            is_gate: false,
+            is_call: false,
+            is_compute: true,
+            is_branch: false,
+            is_loop: false,
+            condition: None,
+            condition_rust: None,
+            condition_ts: None,
+            condition_py: None,
+            condition_go: None,
+            condition_java: None,
+            condition_csharp: None,
+            condition_swift: None,
+            error: None,
+            retry: None,
+            parallel: None,
+            input_mappings: Vec::new(),
+            output_mappings: Vec::new(),
+        },
+        ChainStep::Try(try_step) => StepView {
+            id: try_step.id.clone(),
+            step_type: "Try".to_string(),
+            spec_id: None,
+            is_gate: false,
+            is_call: false,
+            is_compute: false,
+            is_branch: false,
+            is_loop: false,
+            condition: None,
+            condition_rust: None,
+            condition_ts: None,
+            condition_py: None,
+            condition_go: None,
+            condition_java: None,
+            condition_csharp: None,
+            condition_swift: None,
+            error: None,
+            retry: None,
+            parallel: None,
+            input_mappings: Vec::new(),
+            output_mappings: Vec::new(),
+        },
+        ChainStep::Dynamic(dyn_step) => {
+            // Similar to Call step but with dynamic spec selection
+            let input_mappings = input_mappings(&dyn_step.inputs, input_names);
+            StepView {
+                id: dyn_step.id.clone(),
+                step_type: "Dynamic".to_string(),
+                spec_id: Some(dyn_step.spec.clone()),
+                is_gate: false,
+                is_call: true,
+                is_compute: false,
+                is_branch: false,
+                is_loop: false,
+                condition: None,
+                condition_rust: None,
+                condition_ts: None,
+                condition_py: None,
+                condition_go: None,
+                condition_java: None,
+                condition_csharp: None,
+                condition_swift: None,
+                error: None,
+                retry: None,
+                parallel: None,
+                input_mappings,
+                output_mappings: Vec::new(),
+            }
+        }
+        ChainStep::Await(await_step) => StepView {
+            id: await_step.id.clone(),
+            step_type: "Await".to_string(),
+            spec_id: None,
+            is_gate: false,
+            is_call: false,
+            is_compute: false,
+            is_branch: false,
+            is_loop: false,
+            condition: None,
+            condition_rust: None,
+            condition_ts: None,
+            condition_py: None,
+            condition_go: None,
+            condition_java: None,
+            condition_csharp: None,
+            condition_swift: None,
+            error: None,
+            retry: None,
+            parallel: None,
+            input_mappings: Vec::new(),
+            output_mappings: Vec::new(),
+        },
+        ChainStep::Emit(emit) => StepView {
+            id: format!("emit_{}", emit.event),
+            step_type: "Emit".to_string(),
+            spec_id: None,
+            is_gate: false,
+            is_call: false,
+            is_compute: false,
+            is_branch: false,
+            is_loop: false,
+            condition: None,
+            condition_rust: None,
+            condition_ts: None,
+            condition_py: None,
+            condition_go: None,
+            condition_java: None,
+            condition_csharp: None,
+            condition_swift: None,
+            error: None,
+            retry: None,
+            parallel: None,
+            input_mappings: Vec::new(),
+            output_mappings: Vec::new(),
+        },
+    }
+}
+
 /// Extract namespace fields from orchestrator scoping config based on target language
 fn extract_orch_namespace_fields(
     orch: &crate::orchestrate::Orchestrator,
@@ -1457,7 +2718,8 @@ fn extract_orch_namespace_fields(
             let ns = scoping.languages.csharp.as_ref().map(|n| n.render());
             (ns, None, None, None)
         }
-        Target::Java => {
+        // Kotlin shares Java's package syntax and configuration
+        Target::Java | Target::Kotlin => {
             let pkg = scoping.languages.java.as_ref().map(|p| p.render());
             (None, pkg, None, None)
         }
@@ -1482,6 +2744,14 @@ fn extract_orch_namespace_fields(
             let module = scoping.languages.typescript.as_ref().map(|t| t.render());
             (None, None, None, module)
         }
+        // Swift modules are build targets, not source declarations
+        Target::Swift => (None, None, None, None),
+        // PHP has no scoping configuration
+        Target::Php => (None, None, None, None),
+        // Ruby has no scoping configuration
+        Target::Ruby => (None, None, None, None),
+        // Elixir has no scoping configuration
+        Target::Elixir => (None, None, None, None),
     }
 }
 
@@ -1499,6 +2769,12 @@ impl InputView {
             go_type: map_type_go(&var.var_type),
             java_type: map_type_java(&var.var_type),
             csharp_type: map_type_csharp(&var.var_type),
+            kotlin_type: map_type_kotlin(&var.var_type),
+            swift_type: map_type_swift(&var.var_type),
+            php_type: map_type_php(&var.var_type),
+            rust_zero: zero_value_rust(&var.var_type),
+            ts_zero: zero_value_ts(&var.var_type),
+            py_zero: zero_value_python(&var.var_type),
         }
     }
 }
@@ -1517,6 +2793,9 @@ impl OutputView {
             go_type: map_type_go(&var.var_type),
             java_type: map_type_java(&var.var_type),
             csharp_type: map_type_csharp(&var.var_type),
+            kotlin_type: map_type_kotlin(&var.var_type),
+            swift_type: map_type_swift(&var.var_type),
+            php_type: map_type_php(&var.var_type),
         }
     }
 }
@@ -1630,6 +2909,23 @@ fn compile_orch_expr_csharp(expr: &str, input_names: &[String]) -> String {
     }
 }
 
+/// Compile an orchestrator expression to Swift syntax
+fn compile_orch_expr_swift(expr: &str, input_names: &[String]) -> String {
+    if expr.contains('.') {
+        // Context reference: "check_access.level" -> "ctx.checkAccess?[\"level\"]"
+        let parts: Vec<&str> = expr.split('.').collect();
+        let mut result = format!("ctx.{}", to_camel_case(parts[0]));
+        for part in &parts[1..] {
+            result = format!("{}?[\"{}\"]", result, part);
+        }
+        result
+    } else if input_names.contains(&expr.to_string()) {
+        format!("input.{}", to_camel_case(expr))
+    } else {
+        expr.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
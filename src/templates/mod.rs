@@ -9,8 +9,9 @@ pub mod context;
 pub mod filters;
 
 use minijinja::Environment;
-use std::path::Path;
-use std::sync::OnceLock;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
 
 use crate::cel::Target;
 
@@ -23,6 +24,11 @@ mod embedded {
     pub const GO_SPEC: &str = include_str!("../../templates/specs/go.jinja");
     pub const JAVA_SPEC: &str = include_str!("../../templates/specs/java.jinja");
     pub const CSHARP_SPEC: &str = include_str!("../../templates/specs/csharp.jinja");
+    pub const KOTLIN_SPEC: &str = include_str!("../../templates/specs/kotlin.jinja");
+    pub const SWIFT_SPEC: &str = include_str!("../../templates/specs/swift.jinja");
+    pub const PHP_SPEC: &str = include_str!("../../templates/specs/php.jinja");
+    pub const RUBY_SPEC: &str = include_str!("../../templates/specs/ruby.jinja");
+    pub const ELIXIR_SPEC: &str = include_str!("../../templates/specs/elixir.jinja");
 
     // Orchestrator templates
     pub const RUST_ORCH: &str = include_str!("../../templates/orchestrators/rust.jinja");
@@ -32,6 +38,8 @@ mod embedded {
     pub const GO_ORCH: &str = include_str!("../../templates/orchestrators/go.jinja");
     pub const JAVA_ORCH: &str = include_str!("../../templates/orchestrators/java.jinja");
     pub const CSHARP_ORCH: &str = include_str!("../../templates/orchestrators/csharp.jinja");
+    pub const KOTLIN_ORCH: &str = include_str!("../../templates/orchestrators/kotlin.jinja");
+    pub const SWIFT_ORCH: &str = include_str!("../../templates/orchestrators/swift.jinja");
 }
 
 /// Template engine singleton
@@ -57,6 +65,16 @@ fn init_engine() -> Environment<'static> {
         .expect("Failed to load java spec template");
     env.add_template("specs/csharp.jinja", embedded::CSHARP_SPEC)
         .expect("Failed to load csharp spec template");
+    env.add_template("specs/kotlin.jinja", embedded::KOTLIN_SPEC)
+        .expect("Failed to load kotlin spec template");
+    env.add_template("specs/swift.jinja", embedded::SWIFT_SPEC)
+        .expect("Failed to load swift spec template");
+    env.add_template("specs/php.jinja", embedded::PHP_SPEC)
+        .expect("Failed to load php spec template");
+    env.add_template("specs/ruby.jinja", embedded::RUBY_SPEC)
+        .expect("Failed to load ruby spec template");
+    env.add_template("specs/elixir.jinja", embedded::ELIXIR_SPEC)
+        .expect("Failed to load elixir spec template");
 
     // Load embedded orchestrator templates
     env.add_template("orchestrators/rust.jinja", embedded::RUST_ORCH)
@@ -71,6 +89,10 @@ fn init_engine() -> Environment<'static> {
         .expect("Failed to load java orchestrator template");
     env.add_template("orchestrators/csharp.jinja", embedded::CSHARP_ORCH)
         .expect("Failed to load csharp orchestrator template");
+    env.add_template("orchestrators/kotlin.jinja", embedded::KOTLIN_ORCH)
+        .expect("Failed to load kotlin orchestrator template");
+    env.add_template("orchestrators/swift.jinja", embedded::SWIFT_ORCH)
+        .expect("Failed to load swift orchestrator template");
 
     env
 }
@@ -80,28 +102,159 @@ pub fn engine() -> &'static Environment<'static> {
     ENGINE.get_or_init(init_engine)
 }
 
+/// Engines for `--template-dir` overrides, built once per directory
+static OVERRIDE_ENGINES: OnceLock<Mutex<HashMap<PathBuf, &'static Environment<'static>>>> =
+    OnceLock::new();
+
 /// Create a new template engine with custom template directory
 /// Templates in custom_dir override embedded templates
+///
+/// Each custom template must render a sample spec (or orchestrator), so a
+/// broken template is reported here rather than when generating code.
 pub fn engine_with_override(custom_dir: &Path) -> Result<Environment<'static>, TemplateError> {
     let mut env = init_engine();
 
     // Load custom templates, overriding embedded ones
-    load_custom_templates(&mut env, custom_dir)?;
+    let loaded = load_custom_templates(&mut env, custom_dir)?;
+    for name in loaded {
+        check_custom_template(&env, name, custom_dir)?;
+    }
+
+    Ok(env)
+}
 
+/// The engine for a custom template directory, built on first use and then
+/// shared by every render that names the same directory
+pub fn engine_for_dir(custom_dir: &Path) -> Result<&'static Environment<'static>, TemplateError> {
+    let engines = OVERRIDE_ENGINES.get_or_init(Default::default);
+    let mut engines = engines.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(env) = engines.get(custom_dir) {
+        return Ok(env);
+    }
+    let env: &'static Environment<'static> = Box::leak(Box::new(engine_with_override(custom_dir)?));
+    engines.insert(custom_dir.to_path_buf(), env);
     Ok(env)
 }
 
-/// Load custom templates from a directory
-fn load_custom_templates(env: &mut Environment<'static>, dir: &Path) -> Result<(), TemplateError> {
+/// The engine a render should use: the `template_dir` override if set,
+/// otherwise the embedded templates
+fn engine_for(
+    config: &crate::render::RenderConfig,
+) -> Result<&'static Environment<'static>, TemplateError> {
+    match &config.template_dir {
+        Some(dir) => engine_for_dir(dir),
+        None => Ok(engine()),
+    }
+}
+
+/// Sample spec every custom spec template must render
+const SAMPLE_SPEC: &str = r#"
+id: sample_check
+inputs:
+  - name: enabled
+    type: bool
+  - name: tier
+    type: !enum [free, pro]
+  - name: amount
+    type: int
+outputs:
+  - name: status
+    type: int
+rules:
+  - id: R1
+    when: "enabled && tier == 'pro'"
+    then: 1
+    description: "Pro accounts"
+  - id: R2
+    when: "enabled && amount > 10"
+    then: 2
+default: 0
+"#;
+
+/// Sample orchestrator every custom orchestrator template must render
+const SAMPLE_ORCHESTRATOR: &str = r#"
+id: sample_flow
+inputs:
+  - name: user_id
+    type: string
+outputs:
+  - name: status
+    type: int
+chain:
+  - step: gate
+    id: has_user
+    condition: "user_id != ''"
+  - step: call
+    id: check
+    spec: sample_check
+    inputs:
+      enabled: "true"
+"#;
+
+/// Render the sample spec or orchestrator with a freshly loaded custom
+/// template, naming the template file if it fails
+fn check_custom_template(
+    env: &Environment<'static>,
+    name: &str,
+    custom_dir: &Path,
+) -> Result<(), TemplateError> {
+    let broken = |e: String| {
+        TemplateError::RenderError(format!(
+            "custom template {} fails to render a sample: {}",
+            custom_dir.join(name).display(),
+            e
+        ))
+    };
+    let template = env.get_template(name).map_err(|e| broken(e.to_string()))?;
+    let target = TEMPLATE_TARGETS
+        .iter()
+        .find(|(_, filename)| name.ends_with(filename))
+        .map_or(Target::Rust, |(target, _)| *target);
+    let rendered = if name.starts_with("orchestrators/") {
+        let orch = crate::orchestrate::Orchestrator::from_yaml(SAMPLE_ORCHESTRATOR)
+            .map_err(|e| broken(e.to_string()))?;
+        let ctx =
+            context::OrchestratorContext::from_orchestrator(&orch, &HashMap::new(), target, false);
+        template.render(&ctx)
+    } else {
+        let spec = crate::spec::Spec::from_yaml(SAMPLE_SPEC).map_err(|e| broken(e.to_string()))?;
+        template.render(context::SpecContext::from_spec(&spec, target, false))
+    };
+    rendered.map(|_| ()).map_err(|e| broken(e.to_string()))
+}
+
+/// Template file name for each target, as found under `specs/` and
+/// `orchestrators/`
+const TEMPLATE_TARGETS: [(Target, &str); 11] = [
+    (Target::Rust, "rust.jinja"),
+    (Target::TypeScript, "typescript.jinja"),
+    (Target::Python, "python.jinja"),
+    (Target::Go, "go.jinja"),
+    (Target::Java, "java.jinja"),
+    (Target::CSharp, "csharp.jinja"),
+    (Target::Kotlin, "kotlin.jinja"),
+    (Target::Swift, "swift.jinja"),
+    (Target::Php, "php.jinja"),
+    (Target::Ruby, "ruby.jinja"),
+    (Target::Elixir, "elixir.jinja"),
+];
+
+/// Load custom templates from a directory, returning the names of the
+/// templates it replaced
+fn load_custom_templates(
+    env: &mut Environment<'static>,
+    dir: &Path,
+) -> Result<Vec<&'static str>, TemplateError> {
+    if !dir.is_dir() {
+        return Err(TemplateError::IoError(format!(
+            "Template directory {} does not exist",
+            dir.display()
+        )));
+    }
+    let mut loaded = Vec::new();
+
     // Load spec templates if they exist
-    for (target, filename) in [
-        ("rust", "rust.jinja"),
-        ("typescript", "typescript.jinja"),
-        ("python", "python.jinja"),
-        ("go", "go.jinja"),
-        ("java", "java.jinja"),
-        ("csharp", "csharp.jinja"),
-    ] {
+    for (_, filename) in TEMPLATE_TARGETS {
         let spec_path = dir.join("specs").join(filename);
         if spec_path.exists() {
             let content = std::fs::read_to_string(&spec_path).map_err(|e| {
@@ -111,8 +264,10 @@ fn load_custom_templates(env: &mut Environment<'static>, dir: &Path) -> Result<(
             // Note: This leaks the strings, but it's acceptable for config-time loading
             let leaked_name: &'static str = Box::leak(template_name.into_boxed_str());
             let leaked_content: &'static str = Box::leak(content.into_boxed_str());
-            env.add_template(leaked_name, leaked_content)
-                .map_err(|e| TemplateError::ParseError(target.into(), e.to_string()))?;
+            env.add_template(leaked_name, leaked_content).map_err(|e| {
+                TemplateError::ParseError(spec_path.display().to_string(), e.to_string())
+            })?;
+            loaded.push(leaked_name);
         }
 
         let orch_path = dir.join("orchestrators").join(filename);
@@ -123,12 +278,14 @@ fn load_custom_templates(env: &mut Environment<'static>, dir: &Path) -> Result<(
             let template_name = format!("orchestrators/{}", filename);
             let leaked_name: &'static str = Box::leak(template_name.into_boxed_str());
             let leaked_content: &'static str = Box::leak(content.into_boxed_str());
-            env.add_template(leaked_name, leaked_content)
-                .map_err(|e| TemplateError::ParseError(target.into(), e.to_string()))?;
+            env.add_template(leaked_name, leaked_content).map_err(|e| {
+                TemplateError::ParseError(orch_path.display().to_string(), e.to_string())
+            })?;
+            loaded.push(leaked_name);
         }
     }
 
-    Ok(())
+    Ok(loaded)
 }
 
 /// Get the template name for a target language (specs)
@@ -140,6 +297,11 @@ pub fn spec_template_name(target: Target) -> &'static str {
         Target::Go => "specs/go.jinja",
         Target::Java => "specs/java.jinja",
         Target::CSharp => "specs/csharp.jinja",
+        Target::Kotlin => "specs/kotlin.jinja",
+        Target::Swift => "specs/swift.jinja",
+        Target::Php => "specs/php.jinja",
+        Target::Ruby => "specs/ruby.jinja",
+        Target::Elixir => "specs/elixir.jinja",
     }
 }
 
@@ -152,6 +314,14 @@ pub fn orchestrator_template_name(target: Target) -> &'static str {
         Target::Go => "orchestrators/go.jinja",
         Target::Java => "orchestrators/java.jinja",
         Target::CSharp => "orchestrators/csharp.jinja",
+        Target::Kotlin => "orchestrators/kotlin.jinja",
+        Target::Swift => "orchestrators/swift.jinja",
+        // No embedded PHP orchestrator; one can be supplied via --template-dir
+        Target::Php => "orchestrators/php.jinja",
+        // No embedded Ruby orchestrator; one can be supplied via --template-dir
+        Target::Ruby => "orchestrators/ruby.jinja",
+        // No embedded Elixir orchestrator; one can be supplied via --template-dir
+        Target::Elixir => "orchestrators/elixir.jinja",
     }
 }
 
@@ -161,12 +331,79 @@ pub fn render_spec(
     target: Target,
     provenance: bool,
 ) -> Result<String, TemplateError> {
-    let env = engine();
+    let config = crate::render::RenderConfig {
+        provenance,
+        ..Default::default()
+    };
+    render_spec_with(spec, target, &config)
+}
+
+/// Render a spec using templates, honouring the render options templates
+/// support (`provenance`, `assume_complete`, `style`, `simplify_conditions`,
+/// `on_no_match`, `builders`, `warn_incomplete`, `minimal_parens`,
+/// `const_fn`, `no_alloc`, `check_ensures`)
+pub fn render_spec_with(
+    spec: &crate::spec::Spec,
+    target: Target,
+    config: &crate::render::RenderConfig,
+) -> Result<String, TemplateError> {
+    let env = engine_for(config)?;
     let template = env
         .get_template(spec_template_name(target))
         .map_err(|e| TemplateError::TemplateNotFound(e.to_string()))?;
 
-    let ctx = context::SpecContext::from_spec(spec, target, provenance);
+    let mut ctx = if config.simplify_conditions {
+        let simplified = crate::render::simplify_rule_conditions(spec);
+        let mut ctx = context::SpecContext::from_spec(&simplified, target, config.provenance);
+        // Provenance still refers to the spec as written
+        ctx.spec_hash = spec.hash();
+        ctx
+    } else {
+        context::SpecContext::from_spec(spec, target, config.provenance)
+    };
+    ctx.complete =
+        config.assume_complete && !ctx.accumulate && crate::render::is_proven_complete(spec);
+    ctx.data_table = config.style == crate::render::RenderStyle::DataTable && !ctx.accumulate;
+    ctx.builders = config.builders;
+    if config.warn_incomplete && !ctx.accumulate && !ctx.complete {
+        ctx.incomplete_warning = crate::render::incomplete_warning(spec);
+    }
+    if let Some(mode) = config.on_no_match {
+        ctx.set_on_no_match(mode);
+    }
+    if config.minimal_parens {
+        ctx.minimize_parens();
+    }
+    if config.const_fn && target == Target::Rust {
+        let blocker = crate::render::const_fn_blocker(spec).or_else(|| {
+            ctx.data_table
+                .then(|| "the data-table style walks its rules in a loop".to_string())
+        });
+        if let Some(reason) = blocker {
+            return Err(TemplateError::RenderError(format!(
+                "cannot render '{}' as a const fn: {}",
+                spec.id, reason
+            )));
+        }
+        ctx.const_fn = true;
+    }
+    if config.no_alloc && target == Target::Rust {
+        if let Some(reason) = crate::render::no_alloc_blocker(spec) {
+            return Err(TemplateError::RenderError(format!(
+                "cannot render '{}' without allocation: {}",
+                spec.id, reason
+            )));
+        }
+        ctx.no_alloc = true;
+    }
+    if config.check_ensures && matches!(target, Target::Rust | Target::TypeScript | Target::Python)
+    {
+        ctx.ensures = spec
+            .ensures
+            .iter()
+            .map(|cel| context::EnsureView::from_cel(cel, spec))
+            .collect();
+    }
     template
         .render(&ctx)
         .map_err(|e| TemplateError::RenderError(e.to_string()))
@@ -179,12 +416,29 @@ pub fn render_orchestrator(
     target: Target,
     provenance: bool,
 ) -> Result<String, TemplateError> {
-    let env = engine();
+    let config = crate::render::RenderConfig {
+        provenance,
+        ..Default::default()
+    };
+    render_orchestrator_with(orch, specs, target, &config)
+}
+
+/// Render an orchestrator using templates, honouring the render options
+/// orchestrator templates support (`provenance`, `telemetry`)
+pub fn render_orchestrator_with(
+    orch: &crate::orchestrate::Orchestrator,
+    specs: &std::collections::HashMap<String, crate::spec::Spec>,
+    target: Target,
+    config: &crate::render::RenderConfig,
+) -> Result<String, TemplateError> {
+    let env = engine_for(config)?;
     let template = env
         .get_template(orchestrator_template_name(target))
         .map_err(|e| TemplateError::TemplateNotFound(e.to_string()))?;
 
-    let ctx = context::OrchestratorContext::from_orchestrator(orch, specs, target, provenance);
+    let mut ctx =
+        context::OrchestratorContext::from_orchestrator(orch, specs, target, config.provenance);
+    ctx.telemetry = config.telemetry;
     template
         .render(&ctx)
         .map_err(|e| TemplateError::RenderError(e.to_string()))
@@ -260,6 +514,11 @@ rules:
         assert!(env.get_template("specs/go.jinja").is_ok());
         assert!(env.get_template("specs/java.jinja").is_ok());
         assert!(env.get_template("specs/csharp.jinja").is_ok());
+        assert!(env.get_template("specs/kotlin.jinja").is_ok());
+        assert!(env.get_template("specs/php.jinja").is_ok());
+        assert!(env.get_template("specs/ruby.jinja").is_ok());
+        assert!(env.get_template("specs/elixir.jinja").is_ok());
+        assert!(env.get_template("specs/swift.jinja").is_ok());
     }
 
     #[test]
@@ -286,6 +545,146 @@ rules:
         assert!(code.contains("429"), "Missing rule R1 output");
     }
 
+    #[test]
+    fn test_render_computed_default_rust() {
+        let spec = Spec::from_yaml(
+            r#"
+id: shipping_fee
+inputs:
+  - name: express
+    type: bool
+  - name: base_fee
+    type: int
+  - name: surcharge
+    type: int
+outputs:
+  - name: fee
+    type: int
+rules:
+  - id: R1
+    when: "express"
+    then: 20
+default: "base_fee + surcharge"
+"#,
+        )
+        .unwrap();
+
+        let rust = render_spec(&spec, Target::Rust, false).unwrap();
+        assert!(rust.contains("base_fee + surcharge"), "{}", rust);
+        assert!(!rust.contains("\"base_fee + surcharge\""), "{}", rust);
+    }
+
+    #[test]
+    fn test_render_check_ensures() {
+        let mut spec = sample_spec();
+        spec.ensures = vec!["result >= 200".into()];
+        let config = crate::render::RenderConfig {
+            check_ensures: true,
+            ..Default::default()
+        };
+
+        let rust = render_spec_with(&spec, Target::Rust, &config).unwrap();
+        assert!(
+            rust.contains("fn check_status_unchecked(rate_exceeded: bool, locked: bool) -> i64"),
+            "{}",
+            rust
+        );
+        assert!(!rust.contains("pub fn check_status_unchecked"), "{}", rust);
+        assert!(rust.contains("pub fn check_status(rate_exceeded: bool, locked: bool) -> i64"));
+        assert!(rust.contains("let result = check_status_unchecked(rate_exceeded, locked);"));
+        assert!(rust.contains("debug_assert!("), "{}", rust);
+        assert!(rust.contains("check_status: ensures result >= 200"));
+
+        let ts = render_spec_with(&spec, Target::TypeScript, &config).unwrap();
+        assert!(ts.contains("function checkStatusUnchecked(input: CheckStatusInput)"));
+        assert!(ts.contains("const result = checkStatusUnchecked(input);"));
+        assert!(ts.contains("throw new Error(\"check_status: ensures result >= 200\");"));
+
+        let py = render_spec_with(&spec, Target::Python, &config).unwrap();
+        assert!(py.contains("def check_status_unchecked(input: CheckStatusInput)"));
+        assert!(py.contains("assert "), "{}", py);
+
+        // Without the flag the spec's postconditions leave the code alone
+        let plain = render_spec(&spec, Target::Rust, false).unwrap();
+        assert!(!plain.contains("_unchecked"));
+    }
+
+    #[test]
+    fn test_render_const_fn_rust() {
+        let config = crate::render::RenderConfig {
+            const_fn: true,
+            ..Default::default()
+        };
+        let rust = render_spec_with(&sample_spec(), Target::Rust, &config).unwrap();
+        assert!(
+            rust.contains("pub const fn check_status(rate_exceeded: bool, locked: bool) -> i64"),
+            "{}",
+            rust
+        );
+
+        let mut spec = sample_spec();
+        spec.outputs[0].typ = crate::spec::VarType::String;
+        spec.rules[0].then =
+            crate::spec::Output::Single(crate::spec::ConditionValue::String("rate limited".into()));
+        let err = render_spec_with(&spec, Target::Rust, &config)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("output 'status' is a string"), "{}", err);
+    }
+
+    #[test]
+    fn test_render_no_alloc_rust() {
+        let config = crate::render::RenderConfig {
+            no_alloc: true,
+            ..Default::default()
+        };
+        let mut spec = sample_spec();
+        spec.outputs.push(crate::spec::Variable {
+            name: "retry_after".into(),
+            typ: crate::spec::VarType::Float,
+            description: None,
+            values: None,
+            optional: false,
+            range: None,
+        });
+        for rule in &mut spec.rules {
+            let status = rule.then.clone();
+            let crate::spec::Output::Single(status) = status else {
+                unreachable!()
+            };
+            rule.then = crate::spec::Output::Named(
+                [
+                    ("status".to_string(), status),
+                    (
+                        "retry_after".to_string(),
+                        crate::spec::ConditionValue::Float(1.5),
+                    ),
+                ]
+                .into(),
+            );
+        }
+        let rust = render_spec_with(&spec, Target::Rust, &config).unwrap();
+        assert!(!rust.contains("String"), "{}", rust);
+        assert!(!rust.contains("to_string"), "{}", rust);
+        assert!(!rust.contains("HashMap"), "{}", rust);
+        assert!(
+            rust.contains("#[derive(Debug, Clone, Copy, PartialEq)]\npub struct CheckStatusOutput")
+        );
+        assert!(rust.contains(
+            "pub fn check_status(rate_exceeded: bool, locked: bool) -> CheckStatusOutput"
+        ));
+
+        let mut spec = sample_spec();
+        spec.outputs[0].typ = crate::spec::VarType::String;
+        spec.rules[0].then =
+            crate::spec::Output::Single(crate::spec::ConditionValue::String("rate limited".into()));
+        let err = render_spec_with(&spec, Target::Rust, &config)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("without allocation"), "{}", err);
+        assert!(err.contains("output 'status' is a string"), "{}", err);
+    }
+
     #[test]
     fn test_render_typescript_spec() {
         let spec = sample_spec();
@@ -355,6 +754,151 @@ rules:
         assert!(code.contains("429"), "Missing rule R1 output");
     }
 
+    #[test]
+    fn test_render_kotlin_spec() {
+        let spec = sample_spec();
+        let result = render_spec(&spec, Target::Kotlin, true);
+        assert!(result.is_ok(), "Kotlin render failed: {:?}", result.err());
+
+        let code = result.unwrap();
+        assert!(
+            code.contains("data class CheckStatusInput("),
+            "Missing input data class"
+        );
+        assert!(
+            code.contains("fun checkStatus(input: CheckStatusInput): Long"),
+            "Missing function signature"
+        );
+        assert!(code.contains("return when {"), "Should use when expression");
+        assert!(
+            code.contains("input.rateExceeded"),
+            "Missing property access"
+        );
+        assert!(code.contains("429L"), "Missing rule R1 output");
+    }
+
+    #[test]
+    fn test_render_php_spec() {
+        let spec = sample_spec();
+        let result = render_spec(&spec, Target::Php, true);
+        assert!(result.is_ok(), "PHP render failed: {:?}", result.err());
+
+        let code = result.unwrap();
+        assert!(code.starts_with("<?php"), "Missing open tag");
+        assert!(code.contains("final class CheckStatus"), "Missing class");
+        assert!(
+            code.contains("public function evaluate(array $input): int"),
+            "Missing evaluate method"
+        );
+        assert!(
+            code.contains("private bool $rate_exceeded;"),
+            "Missing typed property"
+        );
+        assert!(
+            code.contains("$this->rate_exceeded"),
+            "Missing property access"
+        );
+        for output in ["429", "423", "200"] {
+            assert!(code.contains(output), "Missing rule output {}", output);
+        }
+    }
+
+    #[test]
+    fn test_render_ruby_spec() {
+        let spec = sample_spec();
+        let result = render_spec(&spec, Target::Ruby, true);
+        assert!(result.is_ok(), "Ruby render failed: {:?}", result.err());
+
+        let code = result.unwrap();
+        assert!(code.contains("module CheckStatus"), "Missing module");
+        assert!(code.contains("def evaluate(input)"), "Missing evaluate");
+        assert!(
+            code.contains("rate_exceeded = input[:rate_exceeded]"),
+            "Missing symbol key access"
+        );
+        assert!(code.contains("elsif "), "Missing elsif branch");
+        assert!(code.contains("raise 'No rule matched'"), "Missing fallback");
+        for output in ["429", "423", "200"] {
+            assert!(code.contains(output), "Missing rule output {}", output);
+        }
+    }
+
+    #[test]
+    fn test_render_elixir_spec() {
+        let spec = Spec::from_yaml(
+            r#"
+id: shipping_rate
+inputs:
+  - name: tier
+    type: !enum [standard, express]
+  - name: weight
+    type: int
+outputs:
+  - name: rate
+    type: int
+rules:
+  - id: R1
+    when: "tier == 'express'"
+    then: 25
+  - id: R2
+    when: "weight > 10"
+    then: 15
+default: 5
+"#,
+        )
+        .unwrap();
+        let result = render_spec(&spec, Target::Elixir, true);
+        assert!(result.is_ok(), "Elixir render failed: {:?}", result.err());
+
+        let code = result.unwrap();
+        assert!(code.contains("defmodule ShippingRate do"), "Missing module");
+        let guarded: Vec<&str> = code
+            .lines()
+            .filter(|l| l.contains("def evaluate(") && l.contains(" when "))
+            .collect();
+        assert_eq!(guarded.len(), 2, "Expected one guarded clause per rule");
+        assert!(
+            guarded[0].contains("%{tier: tier}") && guarded[0].contains(":express"),
+            "Enum input should match an atom: {}",
+            guarded[0]
+        );
+        assert!(guarded[1].contains("%{weight: weight}"));
+        assert!(
+            code.contains("def evaluate(_input), do: 5"),
+            "Missing fallback"
+        );
+        assert!(!code.contains("cond do"));
+    }
+
+    #[test]
+    fn test_render_input_builders() {
+        let spec = sample_spec();
+        let config = crate::render::RenderConfig {
+            provenance: false,
+            builders: true,
+            ..Default::default()
+        };
+
+        let rust = render_spec_with(&spec, Target::Rust, &config).unwrap();
+        assert!(rust.contains("pub fn builder() -> CheckStatusInputBuilder {"));
+        assert!(rust.contains("pub fn rate_exceeded(mut self, rate_exceeded: bool) -> Self {"));
+        assert!(rust.contains("rate_exceeded: false,"));
+        assert!(rust.contains("check_status(self.rate_exceeded, self.locked)"));
+
+        let ts = render_spec_with(&spec, Target::TypeScript, &config).unwrap();
+        assert!(ts.contains(
+            "export function checkStatusInput(fields: Partial<CheckStatusInput> = {}): CheckStatusInput {"
+        ));
+        assert!(ts.contains("rateExceeded: false,"));
+
+        let py = render_spec_with(&spec, Target::Python, &config).unwrap();
+        assert!(py.contains("rate_exceeded: bool = False"));
+
+        // Off by default
+        let plain = render_spec(&spec, Target::Rust, false).unwrap();
+        assert!(!plain.contains("builder()"));
+    }
+
     // Integration test: verify template output is properly formatted
     #[test]
     fn test_template_output_formatting() {
@@ -387,6 +931,148 @@ rules:
         );
     }
 
+    #[test]
+    fn test_render_object_input_fields() {
+        let spec = Spec::from_yaml(
+            r#"
+id: checkout
+inputs:
+  - name: user
+    type: !object
+      tier: string
+      home_address: !object { country_code: string }
+outputs:
+  - name: discount
+    type: float
+rules:
+  - id: R1
+    when: "user.tier == 'gold' && user.home_address.country_code == 'US'"
+    then: 0.2
+default: 0.0
+"#,
+        )
+        .unwrap();
+
+        let go = render_spec(&spec, Target::Go, false).unwrap();
+        assert!(go.contains("type CheckoutUserHomeAddress struct"));
+        assert!(go.contains("type CheckoutUser struct"));
+        assert!(go.contains("User CheckoutUser"));
+        assert!(go.contains("input.User.Tier"));
+        assert!(go.contains("input.User.HomeAddress.CountryCode"));
+        // Nested types are declared before the types that use them
+        assert!(
+            go.find("type CheckoutUserHomeAddress").unwrap()
+                < go.find("type CheckoutUser struct").unwrap()
+        );
+
+        let java = render_spec(&spec, Target::Java, false).unwrap();
+        assert!(java.contains("public static class CheckoutUser {"));
+        assert!(java.contains("input.user.homeAddress.countryCode"));
+
+        let kotlin = render_spec(&spec, Target::Kotlin, false).unwrap();
+        assert!(kotlin.contains("data class CheckoutUser("));
+        assert!(kotlin.contains("val user: CheckoutUser,"));
+        assert!(kotlin.contains("input.user.homeAddress.countryCode"));
+
+        let cs = render_spec(&spec, Target::CSharp, false).unwrap();
+        assert!(cs.contains("public class CheckoutUser"));
+        assert!(cs.contains("user.HomeAddress.CountryCode"));
+
+        let ts = render_spec(&spec, Target::TypeScript, false).unwrap();
+        assert!(ts.contains("export interface CheckoutUser {"));
+        assert!(ts.contains("user.homeAddress.countryCode"));
+
+        let py = render_spec(&spec, Target::Python, false).unwrap();
+        assert!(py.contains("class CheckoutUser:"));
+        assert!(py.contains("user: CheckoutUser"));
+        assert!(py.contains("user.home_address.country_code"));
+
+        let rust = render_spec(&spec, Target::Rust, false).unwrap();
+        assert!(rust.contains("pub struct CheckoutUser {"));
+        assert!(rust.contains("user: CheckoutUser"));
+        assert!(rust.contains("user.home_address.country_code"));
+    }
+
+    fn optional_input_spec() -> Spec {
+        Spec::from_yaml(
+            r#"
+id: checkout
+inputs:
+  - name: coupon_code
+    type: string?
+  - name: total
+    type: float
+outputs:
+  - name: discount
+    type: float
+rules:
+  - id: R1
+    when: "coupon_code.startsWith('SAVE') && total > 50.0"
+    then: 0.1
+default: 0.0
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_render_optional_input_rust() {
+        let rust = render_spec(&optional_input_spec(), Target::Rust, false).unwrap();
+        assert!(rust.contains("coupon_code: Option<String>"));
+        assert!(rust.contains("total: f64"));
+        assert!(rust.contains("coupon_code.as_deref().is_some_and(|coupon_code| "));
+    }
+
+    #[test]
+    fn test_render_optional_input_typescript() {
+        let ts = render_spec(&optional_input_spec(), Target::TypeScript, false).unwrap();
+        assert!(ts.contains("couponCode: string | null;"));
+        assert!(ts.contains("couponCode !== null"));
+    }
+
+    #[test]
+    fn test_render_optional_input_python() {
+        let py = render_spec(&optional_input_spec(), Target::Python, false).unwrap();
+        assert!(py.contains("from typing import Any, Optional"));
+        assert!(py.contains("coupon_code: Optional[str]"));
+        assert!(py.contains("coupon_code is not None"));
+    }
+
+    #[test]
+    fn test_render_optional_input_go() {
+        let go = render_spec(&optional_input_spec(), Target::Go, false).unwrap();
+        assert!(go.contains("CouponCode *string"));
+        assert!(go.contains("input.CouponCode != nil && strings.HasPrefix(*input.CouponCode, "));
+    }
+
+    #[test]
+    fn test_render_optional_input_java() {
+        let java = render_spec(&optional_input_spec(), Target::Java, false).unwrap();
+        assert!(java.contains("public String couponCode;"));
+        assert!(java.contains("input.couponCode != null"));
+    }
+
+    #[test]
+    fn test_render_optional_input_csharp() {
+        let cs = render_spec(&optional_input_spec(), Target::CSharp, false).unwrap();
+        assert!(cs.contains("public string? CouponCode { get; set; }"));
+        assert!(cs.contains("couponCode != null"));
+    }
+
+    #[test]
+    fn test_render_optional_input_kotlin() {
+        let kotlin = render_spec(&optional_input_spec(), Target::Kotlin, false).unwrap();
+        assert!(kotlin.contains("val couponCode: String?,"));
+        assert!(kotlin.contains("input.couponCode != null"));
+    }
+
+    #[test]
+    fn test_render_optional_input_swift() {
+        let swift = render_spec(&optional_input_spec(), Target::Swift, false).unwrap();
+        assert!(swift.contains("let couponCode: String?"));
+        assert!(swift.contains("input.couponCode != nil && input.couponCode!.hasPrefix("));
+    }
+
     // Orchestrator template tests
     fn sample_orchestrator() -> crate::orchestrate::Orchestrator {
         crate::orchestrate::Orchestrator::from_yaml(
@@ -469,4 +1155,358 @@ chain:
         assert!(code.contains("async function"), "Missing async function");
         assert!(code.lines().count() > 10, "Should have multiple lines");
     }
+
+    #[test]
+    fn test_render_orchestrator_kotlin() {
+        let orch = sample_orchestrator();
+        let specs = std::collections::HashMap::new();
+        let result = render_orchestrator(&orch, &specs, Target::Kotlin, true);
+        assert!(
+            result.is_ok(),
+            "Kotlin orchestrator render failed: {:?}",
+            result.err()
+        );
+
+        let code = result.unwrap();
+        assert!(code.contains("data class TestFlowOrchestratorInput("));
+        assert!(code.contains("fun testFlow(input: TestFlowOrchestratorInput)"));
+        assert!(code.contains("throw TestFlowException(\"check_input\""));
+    }
+
+    #[test]
+    fn test_render_orchestrator_swift() {
+        let orch = sample_orchestrator();
+        let specs = std::collections::HashMap::new();
+        let result = render_orchestrator(&orch, &specs, Target::Swift, true);
+        assert!(
+            result.is_ok(),
+            "Swift orchestrator render failed: {:?}",
+            result.err()
+        );
+
+        let code = result.unwrap();
+        assert!(code.contains("struct TestFlowOrchestratorInput {"));
+        assert!(code.contains("func testFlow(_ input: TestFlowOrchestratorInput) throws"));
+        assert!(code.contains("throw TestFlowError(step: \"check_input\""));
+    }
+
+    #[test]
+    fn test_render_orchestrator_go_gate_returns_error() {
+        let orch = crate::orchestrate::Orchestrator::from_yaml(include_str!(
+            "../../examples/.imacs/order_flow.yaml"
+        ))
+        .unwrap();
+        let specs = std::collections::HashMap::new();
+        let code = render_orchestrator(&orch, &specs, Target::Go, false).unwrap();
+
+        assert!(code.contains("func OrderFlow(input OrderFlowInput) (OrderFlowOutput, error) {"));
+        assert!(code.contains(
+            "return OrderFlowOutput{}, fmt.Errorf(\"require_access: %w\", OrderFlowError{"
+        ));
+        assert!(code.contains("Message: \"gate condition failed: check_access.level >= 50\","));
+        assert!(code.contains("}, nil"));
+    }
+
+    fn retrying_orchestrator() -> crate::orchestrate::Orchestrator {
+        crate::orchestrate::Orchestrator::from_yaml(
+            r#"
+id: pay_flow
+inputs:
+  - name: amount
+    type: float
+outputs:
+  - name: charged
+    type: bool
+chain:
+  - step: call
+    id: charge
+    spec: charge_card
+    inputs:
+      amount: "amount"
+    retry:
+      max_attempts: 4
+      delay_ms: 250
+      exponential: true
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_render_orchestrator_rust_retry() {
+        let specs = std::collections::HashMap::new();
+        let code =
+            render_orchestrator(&retrying_orchestrator(), &specs, Target::Rust, false).unwrap();
+
+        assert!(code.contains("for attempt in 0..4u32 {"));
+        assert!(code.contains(
+            "std::thread::sleep(std::time::Duration::from_millis(250 << (attempt - 1)));"
+        ));
+        assert!(code.contains("std::panic::catch_unwind(move || charge_card(attempt_input))"));
+        assert!(code.contains("message: \"failed after 4 attempts\".into(),"));
+    }
+
+    #[test]
+    fn test_descriptions_become_comments() {
+        let mut spec = sample_spec();
+        spec.description = Some("Pick the HTTP status for a login attempt".into());
+        spec.rules[0].description = Some("rate limited".into());
+
+        // The description sits directly above the branch that returns 429
+        let comment_above = |code: &str, comment: &str, branch: &str| {
+            let lines: Vec<&str> = code.lines().filter(|l| !l.trim().is_empty()).collect();
+            lines
+                .windows(2)
+                .any(|w| w[0].trim() == comment && w[1].trim_start().starts_with(branch))
+        };
+
+        let rust = render_spec(&spec, Target::Rust, false).unwrap();
+        assert!(comment_above(
+            &rust,
+            "// rate limited",
+            "if rate_exceeded {"
+        ));
+        assert!(rust.contains("/// Pick the HTTP status for a login attempt\n"));
+        let fn_line = rust.lines().position(|l| l.starts_with("pub fn")).unwrap();
+        let doc_line = rust.lines().position(|l| l.starts_with("///")).unwrap();
+        assert!(doc_line < fn_line);
+
+        let py = render_spec(&spec, Target::Python, false).unwrap();
+        assert!(comment_above(&py, "# rate limited", "if rate_exceeded:"));
+        assert!(
+            py.contains("    \"\"\"\n    Pick the HTTP status for a login attempt\n    \"\"\"\n")
+        );
+    }
+
+    #[test]
+    fn test_render_orchestrator_typescript_retry() {
+        let specs = std::collections::HashMap::new();
+        let code = render_orchestrator(&retrying_orchestrator(), &specs, Target::TypeScript, false)
+            .unwrap();
+
+        assert!(code.contains("for (let attempt = 0; attempt < 4; attempt++) {"));
+        assert!(code.contains(
+            "await new Promise((resolve) => setTimeout(resolve, 250 * 2 ** (attempt - 1)));"
+        ));
+        assert!(code.contains("charge_result = await charge_card({"));
+        assert!(code.contains("} catch (err) {"));
+    }
+
+    #[test]
+    fn test_render_orchestrator_without_retry_has_no_loop() {
+        let specs = std::collections::HashMap::new();
+        let code =
+            render_orchestrator(&sample_orchestrator(), &specs, Target::Rust, false).unwrap();
+        assert!(!code.contains("attempt"));
+    }
+
+    #[test]
+    fn test_render_orchestrator_telemetry_spans() {
+        let orch = crate::orchestrate::Orchestrator::from_yaml(
+            r#"
+id: checkout
+inputs:
+  - name: amount
+    type: float
+outputs:
+  - name: charged
+    type: bool
+chain:
+  - step: gate
+    id: validate
+    condition: "amount > 0.0"
+  - step: call
+    id: charge
+    spec: charge_card
+    inputs:
+      amount: "amount"
+"#,
+        )
+        .unwrap();
+        let specs = std::collections::HashMap::new();
+        let config = crate::render::RenderConfig {
+            telemetry: true,
+            ..Default::default()
+        };
+
+        let rust = render_orchestrator_with(&orch, &specs, Target::Rust, &config).unwrap();
+        assert!(rust.contains("let validate_span = tracing::info_span!(\"validate\").entered();"));
+        assert!(rust.contains("tracing::info_span!(\"charge\", spec = \"charge_card\")"));
+        assert!(rust.contains("drop(charge_span);"));
+
+        let ts = render_orchestrator_with(&orch, &specs, Target::TypeScript, &config).unwrap();
+        assert!(ts.contains("import { trace } from \"@opentelemetry/api\";"));
+        assert!(ts.contains("charge_span.end();"));
+
+        let py = render_orchestrator_with(&orch, &specs, Target::Python, &config).unwrap();
+        assert!(py.contains("with tracer.start_as_current_span(\"validate\") as validate_span:"));
+
+        // Off by default
+        let plain = render_orchestrator(&orch, &specs, Target::Rust, false).unwrap();
+        assert!(!plain.contains("tracing::"));
+    }
+
+    fn parallel_orchestrator(wait: &str) -> crate::orchestrate::Orchestrator {
+        crate::orchestrate::Orchestrator::from_yaml(&format!(
+            r#"
+id: quote_flow
+inputs:
+  - name: weight
+    type: float
+outputs:
+  - name: price
+    type: float
+chain:
+  - step: parallel
+    id: quotes
+    wait: {wait}
+    steps:
+      - step: call
+        id: ground
+        spec: ground_rate
+        inputs:
+          weight: "weight"
+      - step: call
+        id: air
+        spec: air_rate
+        inputs:
+          weight: "weight"
+"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_render_parallel_all() {
+        let specs = std::collections::HashMap::new();
+        let orch = parallel_orchestrator("all");
+
+        let ts = render_orchestrator(&orch, &specs, Target::TypeScript, false).unwrap();
+        assert!(ts.contains("await Promise.all(quotes_branches)"));
+        assert!(ts.contains("Promise.resolve(ground_rate({"));
+        assert!(ts.contains("air?: unknown;"));
+
+        let rust = render_orchestrator(&orch, &specs, Target::Rust, false).unwrap();
+        assert!(rust.contains("pub async fn quote_flow("));
+        assert!(rust.contains("tokio::join!(ground_branch, air_branch)"));
+        assert!(rust.contains("tokio::task::spawn_blocking(move || air_rate(air_input))"));
+        assert!(rust.contains("requires tokio"));
+    }
+
+    #[test]
+    fn test_render_parallel_first_success() {
+        let specs = std::collections::HashMap::new();
+        let orch = parallel_orchestrator("first_success");
+
+        let ts = render_orchestrator(&orch, &specs, Target::TypeScript, false).unwrap();
+        assert!(ts.contains("await Promise.any(quotes_branches)"));
+
+        let rust = render_orchestrator(&orch, &specs, Target::Rust, false).unwrap();
+        assert!(rust.contains("futures::future::select_all(quotes_pending).await"));
+        assert!(rust.contains("if let Ok(value) = result {"));
+
+        let py = render_orchestrator(&orch, &specs, Target::Python, false).unwrap();
+        assert!(py.contains("async def quote_flow("));
+        assert!(py.contains("return_when=asyncio.FIRST_COMPLETED"));
+    }
+
+    #[test]
+    fn test_render_orchestrator_is_deterministic() {
+        let yaml = r#"
+id: score_flow
+inputs:
+  - name: user_id
+    type: string
+  - name: amount
+    type: float
+  - name: region
+    type: string
+outputs:
+  - name: score
+    type: int
+chain:
+  - step: call
+    id: score
+    spec: score_user
+    inputs:
+      user: "user_id"
+      amount: "amount"
+      region: "region"
+      weight: "amount * 2.0"
+      label: "'standard'"
+    outputs:
+      score: "score"
+      band: "band"
+      reason: "reason"
+      flagged: "flagged"
+"#;
+        let specs = std::collections::HashMap::new();
+        for target in [Target::Rust, Target::TypeScript, Target::Python, Target::Go] {
+            // Each parse builds fresh HashMaps with their own iteration order
+            let render = || {
+                let orch = crate::orchestrate::Orchestrator::from_yaml(yaml).unwrap();
+                render_orchestrator(&orch, &specs, target, false).unwrap()
+            };
+            let first = render();
+            for _ in 0..10 {
+                assert_eq!(render(), first, "{:?} output changed between runs", target);
+            }
+        }
+    }
+
+    #[test]
+    fn test_template_dir_overrides_spec_template() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("specs")).unwrap();
+        std::fs::write(
+            dir.path().join("specs/rust.jinja"),
+            "// custom template for {{ id }}\npub fn {{ id }}() {}\n",
+        )
+        .unwrap();
+
+        let spec = crate::spec::Spec::from_yaml(
+            r#"
+id: check_status
+inputs:
+  - name: locked
+    type: bool
+outputs:
+  - name: status
+    type: int
+rules:
+  - id: R1
+    when: locked
+    then: 423
+default: 200
+"#,
+        )
+        .unwrap();
+        let config = crate::render::RenderConfig {
+            template_dir: Some(dir.path().to_path_buf()),
+            ..Default::default()
+        };
+        let rust = render_spec_with(&spec, Target::Rust, &config).unwrap();
+        assert_eq!(
+            rust,
+            "// custom template for check_status\npub fn check_status() {}"
+        );
+
+        // Targets without a custom template keep the built-in one
+        let py = render_spec_with(&spec, Target::Python, &config).unwrap();
+        assert!(py.contains("def check_status("));
+    }
+
+    #[test]
+    fn test_broken_custom_template_is_reported() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("specs")).unwrap();
+        std::fs::write(
+            dir.path().join("specs/go.jinja"),
+            "{{ id | no_such_filter }}\n",
+        )
+        .unwrap();
+
+        let err = engine_with_override(dir.path()).unwrap_err().to_string();
+        assert!(err.contains("specs/go.jinja"), "{}", err);
+    }
 }
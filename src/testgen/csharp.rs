@@ -4,8 +4,9 @@ use crate::spec::*;
 use chrono::Utc;
 
 use super::{
-    can_enumerate, extract_test_values, generate_combinations, to_camel_case, to_pascal_case,
-    TestConfig,
+    boundary_cases, can_enumerate, extract_test_values, generate_combinations,
+    has_numeric_conditions, rule_test_comment, table_cases, to_camel_case, to_pascal_case,
+    TestConfig, TestMode,
 };
 
 pub fn generate(spec: &Spec, config: &TestConfig) -> String {
@@ -29,6 +30,21 @@ impl<'a> CSharpTestGen<'a> {
         out.push_str("using Xunit;\n\n");
         out.push_str(&format!("public class {}Tests\n{{\n", class_name));
 
+        if self.config.mode == TestMode::TableDriven {
+            out.push_str(&self.generate_table_test(spec, &class_name));
+        } else {
+            self.push_rule_and_exhaustive_tests(spec, &class_name, &mut out);
+        }
+
+        if self.config.boundary && has_numeric_conditions(spec) {
+            out.push_str(&self.generate_boundary_tests(spec, &class_name));
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    fn push_rule_and_exhaustive_tests(&self, spec: &Spec, class_name: &str, out: &mut String) {
         for rule in &spec.rules {
             let test_name = format!("Test_{}", to_pascal_case(&rule.id));
             let inputs = self.generate_input_object(spec, rule);
@@ -36,12 +52,9 @@ impl<'a> CSharpTestGen<'a> {
 
             out.push_str("    [Fact]\n");
             out.push_str(&format!("    public void {}()\n    {{\n", test_name));
-            out.push_str(&format!(
-                "        // {}: {} → {}\n",
-                rule.id,
-                rule.as_cel().unwrap_or_default(),
-                rule.then
-            ));
+            for line in rule_test_comment(rule, &spec.inputs) {
+                out.push_str(&format!("        // {}\n", line));
+            }
             out.push_str(&format!(
                 "        Assert.Equal({}, {}.Evaluate({}));\n",
                 expected, class_name, inputs
@@ -93,8 +106,80 @@ impl<'a> CSharpTestGen<'a> {
                 class_name
             ));
         }
+    }
 
-        out.push_str("}\n");
+    /// Rule and exhaustive cases as rows of one `TestTable`
+    fn generate_table_test(&self, spec: &Spec, class_name: &str) -> String {
+        let mut out = String::new();
+        out.push_str("    [Fact]\n");
+        out.push_str("    public void TestTable()\n    {\n");
+        out.push_str(&format!(
+            "        var cases = new (string Rule, {}Input Input, object Expected)[]\n        {{\n",
+            class_name
+        ));
+        for case in table_cases(spec) {
+            let fields: Vec<String> = spec
+                .inputs
+                .iter()
+                .zip(&case.inputs)
+                .map(|(input, value)| format!("{} = {}", to_pascal_case(&input.name), value))
+                .collect();
+            out.push_str(&format!(
+                "            (\"{}\", new {}Input {{ {} }}, {}),\n",
+                case.label,
+                class_name,
+                fields.join(", "),
+                self.csharp_value(&case.expected)
+            ));
+        }
+        out.push_str("        };\n");
+        out.push_str("        for (var i = 0; i < cases.Length; i++)\n        {\n");
+        out.push_str(&format!(
+            "            Assert.True(Equals(cases[i].Expected, {}.Evaluate(cases[i].Input)), $\"case {{i}} ({{cases[i].Rule}})\");\n",
+            class_name
+        ));
+        out.push_str("        }\n");
+        out.push_str("    }\n");
+        out
+    }
+
+    /// One `[Fact]` per numeric threshold, checking threshold - 1, threshold and + 1
+    fn generate_boundary_tests(&self, spec: &Spec, class_name: &str) -> String {
+        let mut out = String::new();
+        for case in boundary_cases(spec) {
+            out.push_str("\n    [Fact]\n");
+            out.push_str(&format!(
+                "    public void TestBoundary_{}()\n    {{\n",
+                to_pascal_case(&case.name)
+            ));
+            out.push_str(&format!(
+                "        // {} around {}\n",
+                case.input, case.threshold
+            ));
+            for point in &case.points {
+                let fields: Vec<String> = spec
+                    .inputs
+                    .iter()
+                    .zip(&point.inputs)
+                    .map(|(input, value)| {
+                        format!(
+                            "{} = {}",
+                            to_pascal_case(&input.name),
+                            self.to_csharp_value(value)
+                        )
+                    })
+                    .collect();
+                out.push_str(&format!(
+                    "        Assert.Equal({}, {}.Evaluate(new {}Input {{ {} }})); // {}\n",
+                    self.csharp_value(&point.expected),
+                    class_name,
+                    class_name,
+                    fields.join(", "),
+                    point.label
+                ));
+            }
+            out.push_str("    }\n");
+        }
         out
     }
 
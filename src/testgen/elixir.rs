@@ -0,0 +1,150 @@
+//! Elixir test generation (ExUnit)
+
+use crate::render::elixir::{atom, elixir_string};
+use crate::spec::*;
+use chrono::Utc;
+
+use super::{
+    boundary_cases, extract_test_values, has_numeric_conditions, rule_test_comment, to_pascal_case,
+    TestConfig,
+};
+
+pub fn generate(spec: &Spec, config: &TestConfig) -> String {
+    let mut out = String::new();
+    let module_name = to_pascal_case(&spec.id);
+
+    out.push_str(&format!("# GENERATED TESTS FROM: {}.yaml\n", spec.id));
+    out.push_str(&format!("# SPEC HASH: {}\n", spec.hash()));
+    out.push_str(&format!("# GENERATED: {}\n", Utc::now().to_rfc3339()));
+    out.push_str("# DO NOT EDIT — regenerate from spec\n\n");
+
+    out.push_str(&format!("defmodule {}Test do\n", module_name));
+    out.push_str("  use ExUnit.Case, async: true\n");
+
+    for rule in &spec.rules {
+        out.push('\n');
+        out.push_str(&format!("  test {} do\n", elixir_string(&rule.id)));
+        for line in rule_test_comment(rule, &spec.inputs) {
+            out.push_str(&format!("    # {}\n", line));
+        }
+        out.push_str(&format!(
+            "    assert {}.evaluate({}) == {}\n",
+            module_name,
+            generate_elixir_input(spec, rule),
+            elixir_value(&rule.then, &spec.outputs)
+        ));
+        out.push_str("  end\n");
+    }
+
+    if config.boundary && has_numeric_conditions(spec) {
+        out.push_str(&generate_boundary_tests(spec, &module_name));
+    }
+
+    out.push_str("end\n");
+    out
+}
+
+/// One test per numeric threshold, checking threshold - 1, threshold and + 1
+fn generate_boundary_tests(spec: &Spec, module_name: &str) -> String {
+    let mut out = String::new();
+    for case in boundary_cases(spec) {
+        out.push('\n');
+        out.push_str(&format!(
+            "  test {} do\n",
+            elixir_string(&format!(
+                "boundary {} around {}",
+                case.input, case.threshold
+            ))
+        ));
+        for point in &case.points {
+            let entries: Vec<String> = spec
+                .inputs
+                .iter()
+                .zip(&point.inputs)
+                .map(|(input, value)| {
+                    format!("{}: {}", input.name, elixir_test_value(value, input))
+                })
+                .collect();
+            out.push_str(&format!(
+                "    # {}\n    assert {}.evaluate(%{{{}}}) == {}\n",
+                point.label,
+                module_name,
+                entries.join(", "),
+                elixir_value(&point.expected, &spec.outputs)
+            ));
+        }
+        out.push_str("  end\n");
+    }
+    out
+}
+
+fn generate_elixir_input(spec: &Spec, rule: &Rule) -> String {
+    let values = extract_test_values(rule, &spec.inputs);
+    let entries: Vec<String> = spec
+        .inputs
+        .iter()
+        .map(|input| {
+            let value = values
+                .get(&input.name)
+                .map(|v| elixir_test_value(v, input))
+                .unwrap_or_else(|| default_elixir_value(&input.typ));
+            format!("{}: {}", input.name, value)
+        })
+        .collect();
+    format!("%{{{}}}", entries.join(", "))
+}
+
+/// Adapt a shared test value string to Elixir literal syntax; enum inputs
+/// are passed as atoms, matching the generated module
+fn elixir_test_value(value: &str, input: &Variable) -> String {
+    let quoted = value.starts_with('"') && value.ends_with('"') && value.len() >= 2;
+    match &input.typ {
+        _ if value == "null" => "nil".into(),
+        VarType::Enum(_) if quoted => atom(&value[1..value.len() - 1].replace("\\\"", "\"")),
+        VarType::Float if value.parse::<f64>().is_ok() && !value.contains('.') => {
+            format!("{}.0", value)
+        }
+        // Double-quoted Elixir strings would interpolate `#{}`
+        _ if quoted => elixir_string(&value[1..value.len() - 1].replace("\\\"", "\"")),
+        _ => value.to_string(),
+    }
+}
+
+fn default_elixir_value(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "false".into(),
+        VarType::Int => "0".into(),
+        VarType::Float => "0.0".into(),
+        VarType::String => "\"\"".into(),
+        VarType::Enum(variants) => variants.first().map(|v| atom(v)).unwrap_or("nil".into()),
+        VarType::List(_) => "[]".into(),
+        VarType::Object(_) => "%{}".into(),
+    }
+}
+
+/// Expected value; multi-output specs return a map keyed by output name
+fn elixir_value(output: &Output, outputs: &[Variable]) -> String {
+    match output {
+        Output::Single(v) => elixir_condition_value(v),
+        Output::Named(map) => {
+            let entries: Vec<String> = outputs
+                .iter()
+                .filter_map(|o| {
+                    map.get(&o.name)
+                        .map(|v| format!("{}: {}", o.name, elixir_condition_value(v)))
+                })
+                .collect();
+            format!("%{{{}}}", entries.join(", "))
+        }
+    }
+}
+
+fn elixir_condition_value(v: &ConditionValue) -> String {
+    match v {
+        ConditionValue::Bool(b) => b.to_string(),
+        ConditionValue::Int(i) => i.to_string(),
+        ConditionValue::Float(f) => format!("{:?}", f),
+        ConditionValue::String(s) => elixir_string(s),
+        _ => "nil".into(),
+    }
+}
@@ -3,9 +3,12 @@
 use crate::spec::*;
 use chrono::Utc;
 
-use super::{extract_test_values, to_pascal_case, TestConfig};
+use super::{
+    boundary_cases, extract_test_values, has_numeric_conditions, rule_test_comment, table_cases,
+    to_pascal_case, TableCase, TestConfig, TestMode,
+};
 
-pub fn generate(spec: &Spec, _config: &TestConfig) -> String {
+pub fn generate(spec: &Spec, config: &TestConfig) -> String {
     let mut out = String::new();
     let func_name = to_pascal_case(&spec.id);
     let struct_name = format!("{}Input", func_name);
@@ -18,18 +21,38 @@ pub fn generate(spec: &Spec, _config: &TestConfig) -> String {
     out.push_str("package main\n\n");
     out.push_str("import \"testing\"\n\n");
 
+    if config.mode == TestMode::TableDriven {
+        out.push_str(&generate_table_test(spec, &func_name, &struct_name));
+    } else {
+        push_rule_tests(spec, &func_name, &struct_name, &mut out);
+    }
+
+    if config.boundary && has_numeric_conditions(spec) {
+        for case in boundary_cases(spec) {
+            out.push_str(&format!("\n// {} around {}\n", case.input, case.threshold));
+            out.push_str(&case_table_test(
+                spec,
+                &format!("Test{}_Boundary_{}", func_name, to_pascal_case(&case.name)),
+                &func_name,
+                &struct_name,
+                &case.points,
+            ));
+        }
+    }
+
+    out
+}
+
+fn push_rule_tests(spec: &Spec, func_name: &str, struct_name: &str, out: &mut String) {
     for rule in &spec.rules {
         let test_name = format!("Test{}_{}", func_name, to_pascal_case(&rule.id));
         let expected = go_value(&rule.then);
-        let inputs = generate_go_input(spec, rule, &struct_name);
+        let inputs = generate_go_input(spec, rule, struct_name);
 
         out.push_str(&format!("func {}(t *testing.T) {{\n", test_name));
-        out.push_str(&format!(
-            "\t// {}: {} → {}\n",
-            rule.id,
-            rule.as_cel().unwrap_or_default(),
-            rule.then
-        ));
+        for line in rule_test_comment(rule, &spec.inputs) {
+            out.push_str(&format!("\t// {}\n", line));
+        }
         out.push_str(&format!("\tinput := {}\n", inputs));
         out.push_str(&format!("\tresult := {}(input)\n", func_name));
         out.push_str(&format!("\tif result != {} {{\n", expected));
@@ -40,7 +63,64 @@ pub fn generate(spec: &Spec, _config: &TestConfig) -> String {
         out.push_str("\t}\n");
         out.push_str("}\n\n");
     }
+}
 
+/// Rule and exhaustive cases as rows of one `Test<Name>Table`
+fn generate_table_test(spec: &Spec, func_name: &str, struct_name: &str) -> String {
+    case_table_test(
+        spec,
+        &format!("Test{}Table", func_name),
+        func_name,
+        struct_name,
+        &table_cases(spec),
+    )
+}
+
+/// A table-driven test function over `cases`
+fn case_table_test(
+    spec: &Spec,
+    test_name: &str,
+    func_name: &str,
+    struct_name: &str,
+    cases: &[TableCase],
+) -> String {
+    let expected_type = match spec.outputs.as_slice() {
+        [output] => go_type(&output.typ),
+        _ => "interface{}".into(),
+    };
+
+    let mut out = String::new();
+    out.push_str(&format!("func {}(t *testing.T) {{\n", test_name));
+    out.push_str("\tcases := []struct {\n");
+    out.push_str("\t\trule     string\n");
+    out.push_str(&format!("\t\tinput    {}\n", struct_name));
+    out.push_str(&format!("\t\texpected {}\n", expected_type));
+    out.push_str("\t}{\n");
+    for case in cases {
+        let fields: Vec<String> = spec
+            .inputs
+            .iter()
+            .zip(&case.inputs)
+            .map(|(input, value)| format!("{}: {}", to_pascal_case(&input.name), value))
+            .collect();
+        out.push_str(&format!(
+            "\t\t{{\"{}\", {}{{{}}}, {}}},\n",
+            case.label,
+            struct_name,
+            fields.join(", "),
+            go_value(&case.expected)
+        ));
+    }
+    out.push_str("\t}\n\n");
+    out.push_str("\tfor i, tc := range cases {\n");
+    out.push_str(&format!("\t\tresult := {}(tc.input)\n", func_name));
+    out.push_str("\t\tif result != tc.expected {\n");
+    out.push_str(
+        "\t\t\tt.Errorf(\"case %d (%s): expected %v, got %v\", i, tc.rule, tc.expected, result)\n",
+    );
+    out.push_str("\t\t}\n");
+    out.push_str("\t}\n");
+    out.push_str("}\n");
     out
 }
 
@@ -60,6 +140,16 @@ fn generate_go_input(spec: &Spec, rule: &Rule, struct_name: &str) -> String {
     format!("{}{{{}}}", struct_name, fields.join(", "))
 }
 
+fn go_type(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "bool".into(),
+        VarType::Int => "int64".into(),
+        VarType::Float => "float64".into(),
+        VarType::String | VarType::Enum(_) => "string".into(),
+        _ => "interface{}".into(),
+    }
+}
+
 fn go_value(output: &Output) -> String {
     match output {
         Output::Single(v) => go_condition_value(v),
@@ -3,9 +3,12 @@
 use crate::spec::*;
 use chrono::Utc;
 
-use super::{extract_test_values, to_pascal_case, TestConfig};
+use super::{
+    boundary_cases, extract_test_values, has_numeric_conditions, rule_test_comment, table_cases,
+    to_pascal_case, TestConfig, TestMode,
+};
 
-pub fn generate(spec: &Spec, _config: &TestConfig) -> String {
+pub fn generate(spec: &Spec, config: &TestConfig) -> String {
     let mut out = String::new();
     let class_name = to_pascal_case(&spec.id);
 
@@ -19,19 +22,31 @@ pub fn generate(spec: &Spec, _config: &TestConfig) -> String {
 
     out.push_str(&format!("public class {}Test {{\n", class_name));
 
+    if config.mode == TestMode::TableDriven {
+        out.push_str(&generate_table_test(spec, &class_name));
+    } else {
+        push_rule_tests(spec, &class_name, &mut out);
+    }
+
+    if config.boundary && has_numeric_conditions(spec) {
+        out.push_str(&generate_boundary_tests(spec, &class_name));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn push_rule_tests(spec: &Spec, class_name: &str, out: &mut String) {
     for rule in &spec.rules {
         let test_name = format!("test{}", to_pascal_case(&rule.id));
         let expected = java_value(&rule.then);
-        let inputs = generate_java_input(spec, rule, &class_name);
+        let inputs = generate_java_input(spec, rule, class_name);
 
         out.push_str("    @Test\n");
         out.push_str(&format!("    public void {}() {{\n", test_name));
-        out.push_str(&format!(
-            "        // {}: {} → {}\n",
-            rule.id,
-            rule.as_cel().unwrap_or_default(),
-            rule.then
-        ));
+        for line in rule_test_comment(rule, &spec.inputs) {
+            out.push_str(&format!("        // {}\n", line));
+        }
         out.push_str(&format!("        var input = {};\n", inputs));
         out.push_str(&format!(
             "        assertEquals({}, {}.evaluate(input));\n",
@@ -39,8 +54,63 @@ pub fn generate(spec: &Spec, _config: &TestConfig) -> String {
         ));
         out.push_str("    }\n\n");
     }
+}
 
-    out.push_str("}\n");
+/// One `@Test` per numeric threshold, checking threshold - 1, threshold and + 1
+fn generate_boundary_tests(spec: &Spec, class_name: &str) -> String {
+    let mut out = String::new();
+    for case in boundary_cases(spec) {
+        out.push_str("\n    @Test\n");
+        out.push_str(&format!(
+            "    public void testBoundary{}() {{\n",
+            to_pascal_case(&case.name)
+        ));
+        out.push_str(&format!(
+            "        // {} around {}\n",
+            case.input, case.threshold
+        ));
+        for point in &case.points {
+            out.push_str(&format!(
+                "        assertEquals({}, {}.evaluate(new {}.Input({}))); // {}\n",
+                java_value(&point.expected),
+                class_name,
+                class_name,
+                point.inputs.join(", "),
+                point.label
+            ));
+        }
+        out.push_str("    }\n");
+    }
+    out
+}
+
+/// Rule and exhaustive cases as rows of one `testTable`
+fn generate_table_test(spec: &Spec, class_name: &str) -> String {
+    let mut out = String::new();
+    out.push_str("    @Test\n");
+    out.push_str("    public void testTable() {\n");
+    out.push_str("        Object[][] cases = {\n");
+    for case in table_cases(spec) {
+        out.push_str(&format!(
+            "            {{\"{}\", new {}.Input({}), {}}},\n",
+            case.label,
+            class_name,
+            case.inputs.join(", "),
+            java_value(&case.expected)
+        ));
+    }
+    out.push_str("        };\n");
+    out.push_str("        for (int i = 0; i < cases.length; i++) {\n");
+    out.push_str(&format!(
+        "            var input = ({}.Input) cases[i][1];\n",
+        class_name
+    ));
+    out.push_str(&format!(
+        "            assertEquals(cases[i][2], {}.evaluate(input), \"case \" + i + \" (\" + cases[i][0] + \")\");\n",
+        class_name
+    ));
+    out.push_str("        }\n");
+    out.push_str("    }\n");
     out
 }
 
@@ -0,0 +1,166 @@
+//! Kotlin test generation (JUnit 5)
+
+use crate::render::native_enum;
+use crate::spec::*;
+use chrono::Utc;
+
+use super::{
+    boundary_cases, extract_test_values, has_numeric_conditions, rule_test_comment, to_camel_case,
+    to_pascal_case, TestConfig,
+};
+
+pub fn generate(spec: &Spec, config: &TestConfig) -> String {
+    let mut out = String::new();
+    let type_name = to_pascal_case(&spec.id);
+    let func_name = to_camel_case(&spec.id);
+
+    out.push_str(&format!("// GENERATED TESTS FROM: {}.yaml\n", spec.id));
+    out.push_str(&format!("// SPEC HASH: {}\n", spec.hash()));
+    out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
+    out.push_str("// DO NOT EDIT — regenerate from spec\n\n");
+
+    out.push_str("import org.junit.jupiter.api.Assertions.assertEquals\n");
+    out.push_str("import org.junit.jupiter.api.Test\n\n");
+
+    out.push_str(&format!("class {}Test {{\n", type_name));
+
+    for rule in &spec.rules {
+        let test_name = format!("test{}", to_pascal_case(&rule.id));
+        let expected = kotlin_value(&rule.then);
+        let inputs = generate_kotlin_input(spec, rule, &type_name);
+
+        out.push_str("    @Test\n");
+        out.push_str(&format!("    fun {}() {{\n", test_name));
+        for line in rule_test_comment(rule, &spec.inputs) {
+            out.push_str(&format!("        // {}\n", line));
+        }
+        out.push_str(&format!("        val input = {}\n", inputs));
+        out.push_str(&format!(
+            "        assertEquals({}, {}(input))\n",
+            expected, func_name
+        ));
+        out.push_str("    }\n\n");
+    }
+
+    if config.boundary && has_numeric_conditions(spec) {
+        out.push_str(&generate_boundary_tests(spec, &type_name, &func_name));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// One `@Test` per numeric threshold, checking threshold - 1, threshold and + 1
+fn generate_boundary_tests(spec: &Spec, type_name: &str, func_name: &str) -> String {
+    let mut out = String::new();
+    for case in boundary_cases(spec) {
+        out.push_str("    @Test\n");
+        out.push_str(&format!(
+            "    fun testBoundary{}() {{\n",
+            to_pascal_case(&case.name)
+        ));
+        out.push_str(&format!(
+            "        // {} around {}\n",
+            case.input, case.threshold
+        ));
+        for point in &case.points {
+            let fields: Vec<String> = spec
+                .inputs
+                .iter()
+                .zip(&point.inputs)
+                .map(|(input, value)| {
+                    format!(
+                        "{} = {}",
+                        to_camel_case(&input.name),
+                        kotlin_test_value(value, input)
+                    )
+                })
+                .collect();
+            out.push_str(&format!(
+                "        assertEquals({}, {}({}Input({}))) // {}\n",
+                kotlin_value(&point.expected),
+                func_name,
+                type_name,
+                fields.join(", "),
+                point.label
+            ));
+        }
+        out.push_str("    }\n\n");
+    }
+    out
+}
+
+fn generate_kotlin_input(spec: &Spec, rule: &Rule, type_name: &str) -> String {
+    let values = extract_test_values(rule, &spec.inputs);
+    let fields: Vec<String> = spec
+        .inputs
+        .iter()
+        .map(|input| {
+            let value = values
+                .get(&input.name)
+                .cloned()
+                .map(|v| kotlin_test_value(&v, input))
+                .unwrap_or_else(|| default_kotlin_value(input));
+            format!("{} = {}", to_camel_case(&input.name), value)
+        })
+        .collect();
+    format!("{}Input({})", type_name, fields.join(", "))
+}
+
+/// Adapt a shared test value string to Kotlin literal syntax
+fn kotlin_test_value(value: &str, input: &Variable) -> String {
+    // Enum inputs are sealed classes; pass the variant object
+    let variant = native_enum(&input.name, &input.typ).and_then(|native| {
+        let value: String = serde_json::from_str(value).ok()?;
+        let ident = &native.variant(&value)?.ident;
+        Some(format!("{}.{}", native.name, ident))
+    });
+    if let Some(variant) = variant {
+        return variant;
+    }
+    match &input.typ {
+        // Generated input properties are non-nullable
+        _ if value == "null" => default_kotlin_value(input),
+        VarType::Int if value.parse::<i64>().is_ok() => format!("{}L", value),
+        VarType::Float if value.parse::<f64>().is_ok() && !value.contains('.') => {
+            format!("{}.0", value)
+        }
+        _ => value.to_string(),
+    }
+}
+
+fn default_kotlin_value(input: &Variable) -> String {
+    if let Some(native) = native_enum(&input.name, &input.typ) {
+        return format!("{}.{}", native.name, native.variants[0].ident);
+    }
+    match &input.typ {
+        VarType::Bool => "false".into(),
+        VarType::Int => "0L".into(),
+        VarType::Float => "0.0".into(),
+        VarType::String => "\"\"".into(),
+        VarType::Enum(variants) => variants
+            .first()
+            .map(|v| format!("\"{}\"", v))
+            .unwrap_or("\"\"".into()),
+        VarType::List(_) => "emptyList()".into(),
+        VarType::Object(_) => "emptyMap()".into(),
+    }
+}
+
+fn kotlin_value(output: &Output) -> String {
+    match output {
+        Output::Single(v) => kotlin_condition_value(v),
+        Output::Named(_) => "null".into(),
+    }
+}
+
+fn kotlin_condition_value(v: &ConditionValue) -> String {
+    match v {
+        ConditionValue::Bool(b) => b.to_string(),
+        ConditionValue::Int(i) => format!("{}L", i),
+        ConditionValue::Float(f) => format!("{:?}", f),
+        ConditionValue::String(s) => format!("\"{}\"", s.replace('$', "\\$")),
+        ConditionValue::Null => "null".into(),
+        _ => "null".into(),
+    }
+}
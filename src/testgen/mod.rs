@@ -2,9 +2,15 @@
 //!
 //! Generates comprehensive test suites from decision tables:
 //! - One test per rule (basic coverage)
+//! - Table-driven tests (every case in one test; `TestMode::TableDriven`)
 //! - Exhaustive tests (all input combinations)
 //! - Boundary tests (edge cases for numeric conditions)
-//! - Property tests (fuzzing)
+//! - Property tests (fuzzing; on by default, see `TestConfig::property`)
+//! - cargo-fuzz harness (Rust; opt-in via `TestConfig::fuzz`)
+//! - Benchmarks (Criterion, vitest bench, pytest-benchmark; opt-in via
+//!   `TestConfig::bench`)
+//! - Aggregate runner (Rust; every spec's table in one module via
+//!   `TestGenerator::generate_aggregate`)
 //!
 //! For orchestrators:
 //! - Happy path tests (all gates pass)
@@ -12,22 +18,30 @@
 //! - Step execution tests
 
 mod csharp;
+mod elixir;
 mod go;
 mod java;
+mod kotlin;
 pub mod orchestrator;
+mod php;
 mod python;
+mod ruby;
 mod rust;
+mod swift;
 mod typescript;
 
 use crate::cel::Target;
+use crate::error::{Error, Result};
 use crate::spec::*;
 
 // Re-export language modules
 pub use csharp::generate as generate_csharp;
 pub use go::generate as generate_go;
 pub use java::generate as generate_java;
+pub use kotlin::generate as generate_kotlin;
 pub use python::generate as generate_python;
 pub use rust::generate as generate_rust;
+pub use swift::generate as generate_swift;
 pub use typescript::generate as generate_typescript;
 
 /// Generate tests from spec
@@ -52,18 +66,25 @@ pub struct TestConfig {
     pub exhaustive: bool,
     /// Include boundary tests
     pub boundary: bool,
-    /// Include property tests
+    /// Include property tests (proptest / fast-check / hypothesis)
     pub property: bool,
+    /// Emit a `cargo-fuzz` target instead of unit tests (Rust only)
+    pub fuzz: bool,
+    /// Emit a benchmark instead of unit tests (Rust, TypeScript, Python)
+    pub bench: bool,
     /// Test framework
     pub framework: TestFramework,
 }
 
 /// Test generation mode
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TestMode {
     /// One test per rule
     PerRule,
     /// All tests in one function (table-driven)
+    ///
+    /// Rule and exhaustive cases become rows of a single test; boundary and
+    /// property tests are still emitted separately when enabled.
     TableDriven,
 }
 
@@ -80,10 +101,18 @@ pub enum TestFramework {
     Pytest,
     /// C#: xUnit
     XUnit,
-    /// Java: JUnit
+    /// Java, Kotlin: JUnit
     JUnit,
     /// Go: testing
     GoTest,
+    /// Swift: XCTest
+    XCTest,
+    /// PHP: PHPUnit
+    PHPUnit,
+    /// Ruby: RSpec
+    RSpec,
+    /// Elixir: ExUnit
+    ExUnit,
 }
 
 impl Default for TestConfig {
@@ -94,6 +123,8 @@ impl Default for TestConfig {
             exhaustive: true,
             boundary: true,
             property: true,
+            fuzz: false,
+            bench: false,
             framework: TestFramework::RustBuiltin,
         }
     }
@@ -106,8 +137,12 @@ impl TestGenerator {
             Target::TypeScript => TestFramework::Vitest,
             Target::Python => TestFramework::Pytest,
             Target::CSharp => TestFramework::XUnit,
-            Target::Java => TestFramework::JUnit,
+            Target::Java | Target::Kotlin => TestFramework::JUnit,
             Target::Go => TestFramework::GoTest,
+            Target::Swift => TestFramework::XCTest,
+            Target::Php => TestFramework::PHPUnit,
+            Target::Ruby => TestFramework::RSpec,
+            Target::Elixir => TestFramework::ExUnit,
         };
 
         Self {
@@ -120,6 +155,30 @@ impl TestGenerator {
         }
     }
 
+    /// Enable or disable property-based tests
+    pub fn with_property(mut self, property: bool) -> Self {
+        self.config.property = property;
+        self
+    }
+
+    /// Emit a `cargo-fuzz` harness instead of unit tests (Rust only)
+    pub fn with_fuzz(mut self, fuzz: bool) -> Self {
+        self.config.fuzz = fuzz;
+        self
+    }
+
+    /// Emit a benchmark instead of unit tests (Rust, TypeScript, Python)
+    pub fn with_bench(mut self, bench: bool) -> Self {
+        self.config.bench = bench;
+        self
+    }
+
+    /// Emit one test per rule, or a single table-driven test
+    pub fn with_mode(mut self, mode: TestMode) -> Self {
+        self.config.mode = mode;
+        self
+    }
+
     pub fn with_config(config: TestConfig) -> Self {
         Self {
             target: config.target,
@@ -136,6 +195,25 @@ impl TestGenerator {
             Target::CSharp => csharp::generate(spec, &self.config),
             Target::Java => java::generate(spec, &self.config),
             Target::Go => go::generate(spec, &self.config),
+            Target::Kotlin => kotlin::generate(spec, &self.config),
+            Target::Swift => swift::generate(spec, &self.config),
+            Target::Php => php::generate(spec, &self.config),
+            Target::Ruby => ruby::generate(spec, &self.config),
+            Target::Elixir => elixir::generate(spec, &self.config),
+        }
+    }
+
+    /// Generate one test module covering several specs
+    ///
+    /// Each spec gets a table-driven test named by its id (`test_{id}_table`),
+    /// so a single `cargo test` runs every spec of a folder. Rust only.
+    pub fn generate_aggregate(&self, specs: &[Spec]) -> Result<String> {
+        match self.target {
+            Target::Rust => Ok(rust::generate_aggregate(specs, &self.config)),
+            other => Err(Error::Other(format!(
+                "Aggregate tests are only generated for Rust, not {:?}",
+                other
+            ))),
         }
     }
 }
@@ -144,6 +222,31 @@ impl TestGenerator {
 // Common utilities
 // ============================================================================
 
+/// Comment lines for a rule's test: the rule's condition and output, its
+/// description, and the value each input is given to make the rule fire
+///
+/// Languages prefix each line with their own comment marker.
+pub(crate) fn rule_test_comment(rule: &Rule, inputs: &[Variable]) -> Vec<String> {
+    let mut lines = vec![format!(
+        "{}: {} → {}",
+        rule.id,
+        rule.as_cel().unwrap_or_default(),
+        rule.then
+    )];
+    if let Some(description) = &rule.description {
+        lines.extend(description.trim().lines().map(|l| l.trim_end().to_string()));
+    }
+    let values = extract_test_values(rule, inputs);
+    let given: Vec<String> = inputs
+        .iter()
+        .filter_map(|i| values.get(&i.name).map(|v| format!("{} = {}", i.name, v)))
+        .collect();
+    if !given.is_empty() {
+        lines.push(format!("inputs: {}", given.join(", ")));
+    }
+    lines
+}
+
 /// Extract test input values from a rule (handles both `conditions` and `when` CEL)
 ///
 /// Returns a map of variable name -> value string
@@ -326,27 +429,169 @@ pub(crate) fn can_enumerate(spec: &Spec) -> bool {
         .map(|i| match &i.typ {
             VarType::Bool => 2,
             VarType::Enum(v) => v.len(),
-            _ => 100,
+            _ => match i.range_values() {
+                Some(range) => usize::try_from(range.end().abs_diff(*range.start()))
+                    .map_or(usize::MAX, |span| span.saturating_add(1)),
+                // Strings, numbers, and lists have unbounded domains
+                None => 100,
+            },
         })
-        .product();
+        .fold(1, usize::saturating_mul);
     total <= 64
 }
 
 /// Check if spec has numeric conditions (for boundary tests)
 pub(crate) fn has_numeric_conditions(spec: &Spec) -> bool {
-    spec.rules.iter().any(|r| {
-        r.conditions
-            .as_ref()
-            .map(|c| {
-                c.iter().any(|cond| {
-                    matches!(
-                        cond.op,
-                        ConditionOp::Lt | ConditionOp::Le | ConditionOp::Gt | ConditionOp::Ge
-                    )
+    !numeric_thresholds(spec).is_empty()
+}
+
+/// Numeric thresholds the rules compare inputs against
+///
+/// Taken from structured `<`/`<=`/`>`/`>=` conditions and from comparisons
+/// in the parsed `when` expression. Only int and float inputs count.
+fn numeric_thresholds(spec: &Spec) -> Vec<(&Rule, &Variable, f64)> {
+    use crate::completeness::{extract_predicates, LiteralValue, Predicate};
+
+    let mut found = Vec::new();
+    for rule in &spec.rules {
+        let mut candidates: Vec<(String, f64)> = Vec::new();
+        for cond in rule.conditions.iter().flatten() {
+            if !matches!(
+                cond.op,
+                ConditionOp::Lt | ConditionOp::Le | ConditionOp::Gt | ConditionOp::Ge
+            ) {
+                continue;
+            }
+            match cond.value {
+                ConditionValue::Int(i) => candidates.push((cond.var.clone(), i as f64)),
+                ConditionValue::Float(f) => candidates.push((cond.var.clone(), f)),
+                _ => {}
+            }
+        }
+        if let Some(preds) = rule.as_cel().and_then(|cel| extract_predicates(&cel).ok()) {
+            for pred in preds {
+                if let Predicate::Comparison { var, value, .. } = pred {
+                    match value {
+                        LiteralValue::Int(i) => candidates.push((var, i as f64)),
+                        LiteralValue::Float(f) => candidates.push((var, f)),
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        for (var, threshold) in candidates {
+            let Some(input) = spec.inputs.iter().find(|i| i.name == var) else {
+                continue;
+            };
+            if !matches!(input.typ, VarType::Int | VarType::Float) {
+                continue;
+            }
+            if !found
+                .iter()
+                .any(|(_, i, t): &(&Rule, &Variable, f64)| i.name == var && *t == threshold)
+            {
+                found.push((rule, input, threshold));
+            }
+        }
+    }
+    found
+}
+
+/// Boundary test around one numeric threshold
+pub(crate) struct BoundaryCase {
+    /// `<input>_<threshold>`, usable in a test name
+    pub name: String,
+    /// Input compared against the threshold
+    pub input: String,
+    /// The threshold as written in the rule (`10000`, `2.5`)
+    pub threshold: String,
+    /// Rows at threshold - 1, threshold and threshold + 1; each row is
+    /// labelled with the rule that fires there (or `default`)
+    pub points: Vec<TableCase>,
+}
+
+/// Boundary cases for every numeric threshold in the rules
+///
+/// Each point starts from the rule's own test values (so its other
+/// conditions hold) and moves only the compared input. Expected outputs come
+/// from `Spec::evaluate`, so a point on the far side of the threshold expects
+/// whichever rule or default takes over there. Thresholds where the rule
+/// never fires (e.g. shadowed by a higher-priority rule) are skipped.
+pub(crate) fn boundary_cases(spec: &Spec) -> Vec<BoundaryCase> {
+    let mut cases = Vec::new();
+    for (rule, input, threshold) in numeric_thresholds(spec) {
+        let (text, points): (String, Vec<String>) = match input.typ {
+            VarType::Int => {
+                if threshold.fract() != 0.0 {
+                    continue;
+                }
+                let t = threshold as i64;
+                let points = [t.saturating_sub(1), t, t.saturating_add(1)];
+                (
+                    t.to_string(),
+                    points.iter().map(|p| p.to_string()).collect(),
+                )
+            }
+            _ => {
+                let points = [threshold - 1.0, threshold, threshold + 1.0];
+                (
+                    threshold.to_string(),
+                    points.iter().map(|p| format!("{:?}", p)).collect(),
+                )
+            }
+        };
+
+        let base = extract_test_values(rule, &spec.inputs);
+        let points: Vec<TableCase> = points
+            .into_iter()
+            .filter_map(|point| {
+                let inputs: Vec<String> = spec
+                    .inputs
+                    .iter()
+                    .map(|i| {
+                        if i.name == input.name {
+                            point.clone()
+                        } else {
+                            base.get(&i.name).cloned().unwrap_or_else(|| "null".into())
+                        }
+                    })
+                    .collect();
+                let json: serde_json::Map<String, serde_json::Value> = spec
+                    .inputs
+                    .iter()
+                    .zip(&inputs)
+                    .filter_map(|(i, v)| Some((i.name.clone(), serde_json::from_str(v).ok()?)))
+                    .collect();
+                let expected = spec.evaluate(&json).ok()?;
+                let label = match spec.matching_rule(&json).ok()? {
+                    Some(r) => r.id.clone(),
+                    None => "default".into(),
+                };
+                Some(TableCase {
+                    label,
+                    inputs,
+                    expected,
                 })
             })
-            .unwrap_or(false)
-    })
+            .collect();
+        if !points.iter().any(|p| p.label == rule.id) {
+            continue;
+        }
+
+        let name = format!(
+            "{}_{}",
+            input.name,
+            text.replace('-', "neg").replace('.', "_")
+        );
+        cases.push(BoundaryCase {
+            name,
+            input: input.name.clone(),
+            threshold: text,
+            points,
+        });
+    }
+    cases
 }
 
 /// Generate all input combinations for exhaustive testing
@@ -358,7 +603,10 @@ pub(crate) fn generate_combinations(spec: &Spec) -> Vec<(Vec<String>, String, St
             VarType::Bool => vec!["false".into(), "true".into()],
             VarType::Enum(v) => v.iter().map(|s| format!("\"{}\"", s)).collect(),
             VarType::String => vec!["\"\"".into()],
-            VarType::Int => vec!["0".into()],
+            VarType::Int => match i.range_values() {
+                Some(range) => range.map(|n| n.to_string()).collect(),
+                None => vec!["0".into()],
+            },
             VarType::Float => vec!["0.0".into()],
             _ => vec!["null".into()],
         })
@@ -397,30 +645,314 @@ pub(crate) fn generate_combinations(spec: &Spec) -> Vec<(Vec<String>, String, St
     results
 }
 
+/// Input rows to check the `ensures` postconditions on: every combination
+/// from `generate_combinations` that selects a rule or the default
+///
+/// Empty when the spec has no postconditions or its inputs can't be
+/// enumerated.
+pub(crate) fn ensures_cases(spec: &Spec) -> Vec<Vec<String>> {
+    if spec.ensures.is_empty() || !can_enumerate(spec) {
+        return Vec::new();
+    }
+    generate_combinations(spec)
+        .into_iter()
+        .filter(|(_, rule_id, _)| rule_id != "unknown")
+        .map(|(inputs, _, _)| inputs)
+        .collect()
+}
+
+/// Rule (and its output) that a row of literal inputs selects
+///
+/// Evaluated with `Spec::matching_rule` so CEL `when` clauses, including
+/// comparisons between two inputs, count; rows that cannot be evaluated
+/// fall back to matching structured conditions by equality. Computed
+/// outputs, including a computed default, are evaluated for the row.
 fn find_matching_rule(spec: &Spec, inputs: &[String]) -> (String, String) {
-    for rule in &spec.rules {
-        if let Some(conditions) = &rule.conditions {
-            let matches = conditions.iter().all(|cond| {
-                let idx = spec.inputs.iter().position(|i| i.name == cond.var);
-                if let Some(idx) = idx {
-                    let input_val = &inputs[idx];
-                    let cond_val = condition_value_str(&cond.value);
-                    input_val == &cond_val
-                } else {
-                    false
+    let json: serde_json::Map<String, serde_json::Value> = spec
+        .inputs
+        .iter()
+        .zip(inputs)
+        .filter_map(|(i, v)| Some((i.name.clone(), serde_json::from_str(v).ok()?)))
+        .collect();
+    let matched = match spec.matching_rule(&json) {
+        Ok(rule) => rule,
+        Err(_) => spec.rules_in_order().into_iter().find(|rule| {
+            rule.conditions.as_ref().is_some_and(|conditions| {
+                conditions.iter().all(|cond| {
+                    spec.inputs
+                        .iter()
+                        .position(|i| i.name == cond.var)
+                        .is_some_and(|idx| inputs[idx] == condition_value_str(&cond.value))
+                })
+            })
+        }),
+    };
+    // Computed outputs (`base_fee + surcharge`) are expected as their value
+    let expected = |output: &Output| match spec.evaluate(&json) {
+        Ok(resolved) => output_value_str(&resolved),
+        Err(_) => output_value_str(output),
+    };
+    if let Some(rule) = matched {
+        return (rule.id.clone(), expected(&rule.then));
+    }
+
+    spec.default
+        .as_ref()
+        .map(|d| ("default".into(), expected(d)))
+        .unwrap_or_else(|| ("unknown".into(), "null".into()))
+}
+
+/// One row of a table-driven test
+pub(crate) struct TableCase {
+    /// Rule the row exercises (`exhaustive` for extra input combinations)
+    pub label: String,
+    /// Literal per input, in input order (as from `extract_test_values`)
+    pub inputs: Vec<String>,
+    pub expected: Output,
+}
+
+/// Rows for a table-driven test
+///
+/// One row per rule, then every remaining input combination from
+/// `generate_combinations` when the input space can be enumerated.
+/// Combination rows take their expected output from `Spec::evaluate`.
+pub(crate) fn table_cases(spec: &Spec) -> Vec<TableCase> {
+    let mut cases: Vec<TableCase> = spec
+        .rules
+        .iter()
+        .map(|rule| {
+            let values = extract_test_values(rule, &spec.inputs);
+            TableCase {
+                label: rule.id.clone(),
+                inputs: spec
+                    .inputs
+                    .iter()
+                    .map(|i| {
+                        values
+                            .get(&i.name)
+                            .cloned()
+                            .unwrap_or_else(|| "null".into())
+                    })
+                    .collect(),
+                expected: rule.then.clone(),
+            }
+        })
+        .collect();
+
+    if can_enumerate(spec) {
+        for (inputs, _, _) in generate_combinations(spec) {
+            if cases.iter().any(|c| c.inputs == inputs) {
+                continue;
+            }
+            let json: serde_json::Map<String, serde_json::Value> = spec
+                .inputs
+                .iter()
+                .zip(&inputs)
+                .filter_map(|(i, v)| Some((i.name.clone(), serde_json::from_str(v).ok()?)))
+                .collect();
+            if let Ok(expected) = spec.evaluate(&json) {
+                cases.push(TableCase {
+                    label: "exhaustive".into(),
+                    inputs,
+                    expected,
+                });
+            }
+        }
+    }
+
+    cases
+}
+
+/// Most distinct input rows a benchmark iterates over
+const BENCH_CASES: usize = 16;
+
+/// Representative inputs for a benchmark, as literals in input order
+///
+/// One row per rule, then input combinations from `generate_combinations`
+/// when the input space can be enumerated, without repeats and capped at
+/// `BENCH_CASES`.
+pub(crate) fn bench_cases(spec: &Spec) -> Vec<Vec<String>> {
+    let rule_rows = spec.rules.iter().map(|rule| {
+        let values = extract_test_values(rule, &spec.inputs);
+        spec.inputs
+            .iter()
+            .map(|i| {
+                values
+                    .get(&i.name)
+                    .cloned()
+                    .unwrap_or_else(|| "null".into())
+            })
+            .collect::<Vec<_>>()
+    });
+    let combination_rows = can_enumerate(spec)
+        .then(|| generate_combinations(spec))
+        .into_iter()
+        .flatten()
+        .map(|(inputs, _, _)| inputs);
+
+    let mut cases: Vec<Vec<String>> = Vec::new();
+    for row in rule_rows.chain(combination_rows) {
+        if cases.len() == BENCH_CASES {
+            break;
+        }
+        if !cases.contains(&row) {
+            cases.push(row);
+        }
+    }
+    cases
+}
+
+/// Value domain of one input for property-based tests
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PropertyDomain {
+    Bool,
+    Enum(Vec<String>),
+    /// Inclusive integer range around the rule thresholds
+    Int(i64, i64),
+    /// Float range around the rule thresholds
+    Float(f64, f64),
+    /// String literals compared against in rules; arbitrary strings are mixed in
+    String(Vec<String>),
+}
+
+/// Property-test domain for each input, in input order
+///
+/// Numeric ranges are bounded around the literals the rules compare the input
+/// against, so generated values exercise both sides of every threshold.
+/// Returns `None` when an input (list, object) has no generatable domain.
+pub(crate) fn property_domains(spec: &Spec) -> Option<Vec<PropertyDomain>> {
+    spec.inputs
+        .iter()
+        .map(|input| {
+            let literals = rule_literals(spec, &input.name);
+            let domain = match &input.typ {
+                VarType::Bool => PropertyDomain::Bool,
+                VarType::Enum(variants) => PropertyDomain::Enum(variants.clone()),
+                VarType::Int => {
+                    let ints: Vec<i64> = literals
+                        .iter()
+                        .filter_map(|l| match l {
+                            ConditionValue::Int(n) => Some(*n),
+                            _ => None,
+                        })
+                        .collect();
+                    let lo = ints.iter().min().copied().unwrap_or(0);
+                    let hi = ints.iter().max().copied().unwrap_or(0);
+                    let margin = hi.saturating_sub(lo).max(100);
+                    PropertyDomain::Int(lo.saturating_sub(margin), hi.saturating_add(margin))
+                }
+                VarType::Float => {
+                    let floats: Vec<f64> = literals
+                        .iter()
+                        .filter_map(|l| match l {
+                            ConditionValue::Float(f) => Some(*f),
+                            ConditionValue::Int(n) => Some(*n as f64),
+                            _ => None,
+                        })
+                        .collect();
+                    let lo = floats.iter().copied().fold(f64::INFINITY, f64::min);
+                    let hi = floats.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                    let (lo, hi) = if lo.is_finite() { (lo, hi) } else { (0.0, 0.0) };
+                    let margin = (hi - lo).max(100.0);
+                    PropertyDomain::Float(lo - margin, hi + margin)
+                }
+                VarType::String => {
+                    let mut strings: Vec<String> = Vec::new();
+                    for lit in literals {
+                        if let ConditionValue::String(s) = lit {
+                            if !strings.contains(&s) {
+                                strings.push(s);
+                            }
+                        }
+                    }
+                    PropertyDomain::String(strings)
                 }
-            });
+                VarType::List(_) | VarType::Object(_) => return None,
+            };
+            Some(domain)
+        })
+        .collect()
+}
+
+/// Literal values the rules compare `name` against (structured and CEL)
+fn rule_literals(spec: &Spec, name: &str) -> Vec<ConditionValue> {
+    let mut literals = Vec::new();
+    for rule in &spec.rules {
+        for cond in rule.conditions.iter().flatten().filter(|c| c.var == name) {
+            match &cond.value {
+                ConditionValue::List(items) => literals.extend(items.iter().cloned()),
+                other => literals.push(other.clone()),
+            }
+        }
+        if let Some(ast) = rule
+            .as_cel()
+            .and_then(|cel| crate::cel::CelCompiler::parse(&cel).ok())
+        {
+            collect_cel_literals(&ast, name, &mut literals);
+        }
+    }
+    literals
+}
+
+fn collect_cel_literals(expr: &crate::cel::CelExpr, name: &str, out: &mut Vec<ConditionValue>) {
+    use cel_parser::ast::{operators, Expr};
 
-            if matches {
-                return (rule.id.clone(), output_value_str(&rule.then));
+    let Expr::Call(call) = &expr.expr else {
+        return;
+    };
+    if let [left, right] = call.args.as_slice() {
+        let is_var =
+            |e: &crate::cel::CelExpr| matches!(&e.expr, Expr::Ident(n) if n.as_str() == name);
+        let other = if is_var(left) {
+            Some(right)
+        } else if is_var(right) {
+            Some(left)
+        } else {
+            None
+        };
+        match other.map(|o| &o.expr) {
+            Some(Expr::Literal(val)) => out.extend(literal_condition_value(val)),
+            Some(Expr::List(list)) if call.func_name == operators::IN => {
+                for item in &list.elements {
+                    if let Expr::Literal(val) = &item.expr {
+                        out.extend(literal_condition_value(val));
+                    }
+                }
             }
+            _ => {}
         }
     }
+    for arg in &call.args {
+        collect_cel_literals(arg, name, out);
+    }
+}
 
-    spec.default
-        .as_ref()
-        .map(|d| ("default".into(), output_value_str(d)))
-        .unwrap_or_else(|| ("unknown".into(), "null".into()))
+fn literal_condition_value(val: &cel_parser::reference::Val) -> Option<ConditionValue> {
+    use cel_parser::reference::Val;
+
+    match val {
+        Val::Int(i) => Some(ConditionValue::Int(*i)),
+        Val::UInt(u) => i64::try_from(*u).ok().map(ConditionValue::Int),
+        Val::Double(f) => Some(ConditionValue::Float(*f)),
+        Val::String(s) => Some(ConditionValue::String(s.to_string())),
+        Val::Boolean(b) => Some(ConditionValue::Bool(*b)),
+        _ => None,
+    }
+}
+
+/// Whether every rule output (and the default) is a plain literal value,
+/// so a property test can check membership in the declared outputs
+pub(crate) fn outputs_are_literals(spec: &Spec) -> bool {
+    spec.rules
+        .iter()
+        .map(|r| &r.then)
+        .chain(spec.default.as_ref())
+        .all(|output| match output {
+            Output::Single(ConditionValue::String(s)) => !crate::render::is_expression(s),
+            Output::Single(ConditionValue::Map(_) | ConditionValue::List(_)) => false,
+            Output::Single(_) => true,
+            Output::Named(_) => false,
+        })
 }
 
 fn condition_value_str(v: &ConditionValue) -> String {
@@ -491,6 +1023,22 @@ rules:
         assert!(tests.contains("test_r1"));
     }
 
+    #[test]
+    fn test_rule_test_explains_match() {
+        let spec = sample_spec();
+        let tests = generate_tests(&spec, Target::Rust);
+
+        let r1 = &tests[tests.find("fn test_r1()").unwrap()..];
+        let body = &r1[..r1.find("    }").unwrap()];
+        let cel = spec.rules[0].as_cel().unwrap();
+        assert!(body.contains(&format!("// R1: {} → 429", cel)), "{}", body);
+        assert!(
+            body.contains("// inputs: rate_exceeded = true, locked = false"),
+            "{}",
+            body
+        );
+    }
+
     #[test]
     fn test_generate_typescript() {
         let spec = sample_spec();
@@ -508,4 +1056,447 @@ rules:
         assert!(tests.contains("def test_"));
         assert!(tests.contains("assert"));
     }
+
+    #[test]
+    fn test_generate_swift() {
+        let spec = sample_spec();
+        let tests = generate_tests(&spec, Target::Swift);
+
+        assert!(tests.contains("import XCTest"));
+        assert!(tests.contains("func testR1()"));
+        assert!(tests.contains("XCTAssertEqual("));
+    }
+
+    #[test]
+    fn test_generate_ruby() {
+        let spec = sample_spec();
+        let tests = generate_tests(&spec, Target::Ruby);
+
+        assert!(tests.contains("RSpec.describe"));
+        assert!(tests.contains("  it 'R1' do"));
+        assert!(tests.contains("expect("));
+        assert!(tests.contains(".to eq(429)"));
+    }
+
+    #[test]
+    fn test_generate_elixir() {
+        let spec = sample_spec();
+        let tests = generate_tests(&spec, Target::Elixir);
+
+        assert!(tests.contains("defmodule CheckStatusTest do"));
+        assert!(tests.contains("use ExUnit.Case"));
+        assert!(tests.contains("  test \"R1\" do"));
+        assert!(tests.contains("assert CheckStatus.evaluate(%{"));
+        assert!(tests.contains(") == 429"));
+    }
+
+    #[test]
+    fn test_generate_aggregate_over_directory() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("access.yaml"),
+            r#"
+id: access
+inputs:
+  - name: tier
+    type: !enum [free, pro]
+outputs:
+  - name: allowed
+    type: bool
+rules:
+  - id: R1
+    when: "tier == 'pro'"
+    then: true
+default: false
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("quota.yaml"),
+            r#"
+id: quota
+inputs:
+  - name: tier
+    type: !enum [free, pro]
+  - name: verified
+    type: bool
+outputs:
+  - name: limit
+    type: int
+rules:
+  - id: R1
+    when: "tier == 'pro'"
+    then: 1000
+  - id: R2
+    when: "tier == 'free' && verified"
+    then: 100
+default: 10
+"#,
+        )
+        .unwrap();
+
+        let specs = crate::project::load_specs(dir.path()).unwrap();
+        let tests = TestGenerator::new(Target::Rust)
+            .generate_aggregate(&specs)
+            .unwrap();
+
+        assert_eq!(tests.matches("mod spec_tests {").count(), 1);
+        assert!(tests.contains("fn test_access_table()"), "{}", tests);
+        assert!(tests.contains("fn test_quota_table()"), "{}", tests);
+        assert!(tests.contains("use super::access::*;"));
+        assert!(tests.contains("use super::quota::*;"));
+        // Both specs' rows, each through its own function
+        assert!(tests.contains("(\"R1\", (Tier::Pro,), true),"), "{}", tests);
+        assert!(
+            tests.contains("(\"R2\", (Tier::Free, true), 100),"),
+            "{}",
+            tests
+        );
+        assert!(tests.contains("assert_eq!(access(tier), expected"));
+        assert!(tests.contains("assert_eq!(quota(tier, verified), expected"));
+
+        assert!(TestGenerator::new(Target::Python)
+            .generate_aggregate(&specs)
+            .is_err());
+    }
+
+    #[test]
+    fn test_generate_ensures_tests() {
+        let spec = Spec::from_yaml(
+            r#"
+id: refund
+inputs:
+  - name: disputed
+    type: bool
+  - name: tier
+    type: !enum [basic, gold]
+outputs:
+  - name: amount
+    type: int
+ensures:
+  - "result >= 0"
+rules:
+  - id: R1
+    when: "disputed"
+    then: -5
+  - id: R2
+    when: "tier == 'gold'"
+    then: 20
+default: 10
+"#,
+        )
+        .unwrap();
+
+        // The interpreter catches the rule that breaks the postcondition
+        let json = serde_json::json!({"disputed": true, "tier": "basic"});
+        assert!(spec.evaluate(json.as_object().unwrap()).is_err());
+
+        assert_eq!(ensures_cases(&spec).len(), 4);
+        let rust = generate_tests(&spec, Target::Rust);
+        assert!(rust.contains("fn test_ensures()"), "{}", rust);
+        assert!(rust.contains("refund(true, "), "{}", rust);
+        assert!(rust.contains("assert!(result >= 0, \"refund: ensures result >= 0\");"));
+
+        let ts = generate_tests(&spec, Target::TypeScript);
+        assert!(ts.contains("it('ensures'"), "{}", ts);
+        assert!(ts.contains("const result = refund(input);"));
+
+        let py = generate_tests(&spec, Target::Python);
+        assert!(py.contains("class TestRefundEnsures:"), "{}", py);
+        assert!(py.contains("result = refund(*inputs)"));
+
+        // No postconditions, no test
+        let mut plain = spec.clone();
+        plain.ensures.clear();
+        assert!(!generate_tests(&plain, Target::Rust).contains("test_ensures"));
+    }
+
+    #[test]
+    fn test_table_driven_single_test() {
+        let spec = sample_spec();
+        let markers = [
+            (Target::Rust, "#[test]"),
+            (Target::TypeScript, "it("),
+            (Target::Python, "def test_"),
+            (Target::Go, "func Test"),
+            (Target::Java, "@Test"),
+            (Target::CSharp, "[Fact]"),
+        ];
+
+        for (target, marker) in markers {
+            let tests = TestGenerator::new(target)
+                .with_mode(TestMode::TableDriven)
+                .generate(&spec);
+            assert_eq!(tests.matches(marker).count(), 1, "{:?}:\n{}", target, tests);
+            for output in ["429", "423", "200"] {
+                assert!(tests.contains(output), "{:?} missing {}", target, output);
+            }
+        }
+
+        let rust = TestGenerator::new(Target::Rust)
+            .with_mode(TestMode::TableDriven)
+            .generate(&spec);
+        assert!(rust.contains("(\"R2\", (false, true), 423),"));
+        assert!(rust.contains("for (i, (rule, input, expected)) in cases.into_iter().enumerate()"));
+        assert!(rust.contains("\"case {} ({}) failed\", i, rule"));
+    }
+
+    #[test]
+    fn test_list_inputs_not_enumerable() {
+        let spec = Spec::from_yaml(
+            r#"
+id: cart
+inputs:
+  - name: flags
+    type: list<string>
+outputs:
+  - name: flagged
+    type: bool
+rules:
+  - id: R1
+    when: "flags.exists(f, f == 'fraud')"
+    then: true
+default: false
+"#,
+        )
+        .unwrap();
+
+        assert!(!can_enumerate(&spec));
+    }
+
+    #[test]
+    fn test_int_range_enumerates_its_values() {
+        let spec = Spec::from_yaml(
+            r#"
+id: retry_policy
+inputs:
+  - name: attempts
+    type: int
+    range: [0, 2]
+  - name: idempotent
+    type: bool
+outputs:
+  - name: retry
+    type: bool
+rules:
+  - id: R1
+    when: "attempts < 2 && idempotent"
+    then: true
+default: false
+"#,
+        )
+        .unwrap();
+
+        assert!(can_enumerate(&spec));
+        let rows = generate_combinations(&spec);
+        assert_eq!(rows.len(), 6);
+        let attempts: Vec<&str> = rows
+            .iter()
+            .filter(|(inputs, _, _)| inputs[1] == "true")
+            .map(|(inputs, _, _)| inputs[0].as_str())
+            .collect();
+        assert_eq!(attempts, vec!["0", "1", "2"]);
+        let expected: Vec<&str> = rows
+            .iter()
+            .filter(|(inputs, _, _)| inputs[1] == "true")
+            .map(|(_, _, expected)| expected.as_str())
+            .collect();
+        assert_eq!(expected, vec!["true", "true", "false"]);
+    }
+
+    #[test]
+    fn test_property_domains_follow_thresholds() {
+        let spec = Spec::from_yaml(
+            r#"
+id: shipping
+inputs:
+  - name: weight
+    type: int
+  - name: zone
+    type: !enum [domestic, intl]
+  - name: express
+    type: bool
+  - name: code
+    type: string
+outputs:
+  - name: rate
+    type: int
+rules:
+  - id: R1
+    when: "weight > 50 && zone == 'intl'"
+    then: 40
+  - id: R2
+    when: "weight <= 10 || code == 'FREE'"
+    then: 0
+default: 10
+"#,
+        )
+        .unwrap();
+
+        let domains = property_domains(&spec).unwrap();
+        assert_eq!(domains[0], PropertyDomain::Int(-90, 150));
+        assert_eq!(
+            domains[1],
+            PropertyDomain::Enum(vec!["domestic".into(), "intl".into()])
+        );
+        assert_eq!(domains[2], PropertyDomain::Bool);
+        assert_eq!(domains[3], PropertyDomain::String(vec!["FREE".into()]));
+        assert!(outputs_are_literals(&spec));
+
+        // Property tests are on by default
+        assert!(!TestGenerator::new(Target::Rust)
+            .with_property(false)
+            .generate(&spec)
+            .contains("proptest!"));
+        let rust = generate_tests(&spec, Target::Rust);
+        assert!(rust.contains("weight in -90i64..=150i64"));
+        assert!(rust.contains("zone in prop::sample::select(vec![Zone::Domestic, Zone::Intl])"));
+
+        let ts = TestGenerator::new(Target::TypeScript)
+            .with_property(true)
+            .generate(&spec);
+        assert!(ts.contains("import fc from 'fast-check';"));
+        assert!(ts.contains("fc.integer({ min: -90, max: 150 })"));
+        assert!(ts.contains("fc.constantFrom(\"domestic\", \"intl\")"));
+
+        let py = TestGenerator::new(Target::Python)
+            .with_property(true)
+            .generate(&spec);
+        assert!(py.contains("from hypothesis import given, strategies as st"));
+        assert!(py.contains("weight=st.integers(min_value=-90, max_value=150)"));
+        assert!(py.contains("zone=st.sampled_from([\"domestic\", \"intl\"])"));
+        assert!(py.contains("assert result in [40, 0, 10]"));
+    }
+
+    #[test]
+    fn test_rust_fuzz_harness() {
+        let spec = Spec::from_yaml(
+            r#"
+id: shipping
+inputs:
+  - name: weight
+    type: int
+  - name: zone
+    type: !enum [domestic, intl]
+  - name: express
+    type: bool
+outputs:
+  - name: rate
+    type: int
+rules:
+  - id: R1
+    when: "weight > 50 && zone == 'intl'"
+    then: 40
+  - id: R2
+    when: "express"
+    then: 25
+default: 10
+"#,
+        )
+        .unwrap();
+
+        let fuzz = TestGenerator::new(Target::Rust)
+            .with_fuzz(true)
+            .generate(&spec);
+        assert!(fuzz.contains("#![no_main]"));
+        assert!(fuzz.contains("fuzz_target!(|input: FuzzInput| {"));
+        assert!(fuzz.contains("#[derive(Debug, Arbitrary)]"));
+        assert!(fuzz.contains("use shipping::{shipping, Zone};"));
+        assert!(fuzz.contains("const ZONE_VARIANTS: [Zone; 2] = [Zone::Domestic, Zone::Intl];"));
+        assert!(fuzz.contains("    zone: u8,\n"));
+        assert!(fuzz.contains("    express: bool,\n"));
+        assert!(fuzz.contains(
+            "let result = shipping(input.weight, ZONE_VARIANTS[input.zone as usize % ZONE_VARIANTS.len()], input.express);"
+        ));
+        assert!(fuzz.contains("valid_outputs.contains(&result)"));
+        assert!(!fuzz.contains("#[test]"));
+    }
+
+    #[test]
+    fn test_benchmarks_cover_several_cases() {
+        let spec = sample_spec();
+
+        let rust = TestGenerator::new(Target::Rust)
+            .with_bench(true)
+            .generate(&spec);
+        assert!(rust.contains("criterion_group!(benches, bench_check_status);"));
+        assert!(rust.contains("criterion_main!(benches);"));
+        assert!(rust.contains("black_box(check_status(rate_exceeded, locked));"));
+        // One row per rule
+        assert!(rust.contains("(true, false),"));
+        assert!(rust.contains("(false, true),"));
+        assert!(rust.contains("(false, false),"));
+        assert!(!rust.contains("#[test]"));
+
+        let ts = TestGenerator::new(Target::TypeScript)
+            .with_bench(true)
+            .generate(&spec);
+        assert!(ts.contains("import { bench, describe } from 'vitest';"));
+        assert!(ts.contains("checkStatus(input);"));
+        assert!(ts.matches("rateExceeded:").count() > 1);
+
+        let py = TestGenerator::new(Target::Python)
+            .with_bench(true)
+            .generate(&spec);
+        assert!(py.contains("def test_check_status_benchmark(benchmark):"));
+        assert!(py.contains("check_status(*inputs)"));
+        assert!(py.matches("    (").count() > 1);
+    }
+
+    #[test]
+    fn test_boundary_tests_around_thresholds() {
+        let spec = Spec::from_yaml(
+            r#"
+id: shipping
+inputs:
+  - name: cart_total
+    type: int
+  - name: weight
+    type: float
+outputs:
+  - name: cost
+    type: int
+rules:
+  - id: R1
+    when: "cart_total > 10000"
+    then: 0
+  - id: R2
+    conditions:
+      - var: weight
+        op: "<"
+        value: 2.5
+    then: 5
+default: 15
+"#,
+        )
+        .unwrap();
+
+        let rust = generate_tests(&spec, Target::Rust);
+        assert!(rust.contains("fn test_boundary_cart_total_10000()"));
+        assert!(rust.contains("assert_eq!(shipping(9999, 0.0), 5);  // R2"));
+        assert!(rust.contains("assert_eq!(shipping(10000, 0.0), 5);  // R2"));
+        assert!(rust.contains("assert_eq!(shipping(10001, 0.0), 0);  // R1"));
+
+        assert!(rust.contains("fn test_boundary_weight_2_5()"));
+        assert!(rust.contains("assert_eq!(shipping(0, 1.5), 5);  // R2"));
+        assert!(rust.contains("assert_eq!(shipping(0, 3.5), 15);  // default"));
+
+        for target in [
+            Target::TypeScript,
+            Target::Python,
+            Target::Go,
+            Target::Java,
+            Target::CSharp,
+            Target::Kotlin,
+            Target::Swift,
+            Target::Php,
+            Target::Ruby,
+            Target::Elixir,
+        ] {
+            let tests = generate_tests(&spec, target);
+            for literal in ["9999", "10000", "10001", "1.5", "3.5"] {
+                assert!(tests.contains(literal), "{:?} missing {}", target, literal);
+            }
+        }
+    }
 }
@@ -21,6 +21,23 @@ pub fn generate_orchestrator_tests(orch: &Orchestrator, target: Target) -> Strin
         Target::Python => generate_python(orch),
         Target::Go => generate_go(orch),
         Target::Java => generate_java(orch),
+        Target::Kotlin => generate_kotlin(orch),
+        Target::Swift => generate_swift(orch),
+        // PHP has no orchestrator template yet
+        Target::Php => format!(
+            "<?php\n\n// Orchestrator tests for {} are not generated for PHP\n",
+            orch.id
+        ),
+        // Ruby has no orchestrator template yet
+        Target::Ruby => format!(
+            "# Orchestrator tests for {} are not generated for Ruby\n",
+            orch.id
+        ),
+        // Elixir has no orchestrator template yet
+        Target::Elixir => format!(
+            "# Orchestrator tests for {} are not generated for Elixir\n",
+            orch.id
+        ),
     }
 }
 
@@ -713,6 +730,248 @@ fn java_default_value(typ: &VarType) -> String {
     }
 }
 
+// ============================================================================
+// Kotlin Test Generation (JUnit 5)
+// ============================================================================
+
+fn generate_kotlin(orch: &Orchestrator) -> String {
+    let mut out = String::new();
+    let class_name = to_pascal_case(&orch.id);
+    let func_name = to_camel_case(&orch.id);
+
+    // Header
+    out.push_str(&format!("// GENERATED TESTS FROM: {}.yaml\n", orch.id));
+    out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
+    out.push_str("// DO NOT EDIT — regenerate from spec\n\n");
+
+    out.push_str("import org.junit.jupiter.api.Assertions.assertEquals\n");
+    out.push_str("import org.junit.jupiter.api.Test\n");
+    out.push_str("import org.junit.jupiter.api.assertDoesNotThrow\n");
+    out.push_str("import org.junit.jupiter.api.assertThrows\n\n");
+
+    out.push_str(&format!("class {}Tests {{\n\n", class_name));
+
+    // Happy path
+    out.push_str("    @Test\n");
+    out.push_str("    fun `execute with valid inputs should succeed`() {\n");
+    out.push_str(&format!(
+        "        val input = {}OrchestratorInput(\n",
+        class_name
+    ));
+    let input_values: Vec<String> = orch
+        .inputs
+        .iter()
+        .map(|i| {
+            format!(
+                "{} = {}",
+                to_camel_case(&i.name),
+                kotlin_sample_value(&i.var_type)
+            )
+        })
+        .collect();
+    out.push_str(&format!(
+        "            {}\n",
+        input_values.join(",\n            ")
+    ));
+    out.push_str("        )\n\n");
+    out.push_str(&format!(
+        "        assertDoesNotThrow {{ {}(input) }}\n",
+        func_name
+    ));
+    out.push_str("    }\n\n");
+
+    // Gate failure tests
+    for step in &orch.chain {
+        if let ChainStep::Gate(gate) = step {
+            out.push_str("    @Test\n");
+            out.push_str(&format!(
+                "    fun `execute when gate {} fails should throw`() {{\n",
+                gate.id
+            ));
+            out.push_str(&format!(
+                "        val input = {}OrchestratorInput(\n",
+                class_name
+            ));
+            let default_values: Vec<String> = orch
+                .inputs
+                .iter()
+                .map(|i| {
+                    format!(
+                        "{} = {}",
+                        to_camel_case(&i.name),
+                        kotlin_default_value(&i.var_type)
+                    )
+                })
+                .collect();
+            out.push_str(&format!(
+                "            {}\n",
+                default_values.join(",\n            ")
+            ));
+            out.push_str("        )\n\n");
+            out.push_str(&format!(
+                "        val ex = assertThrows<{}Exception> {{ {}(input) }}\n",
+                class_name, func_name
+            ));
+            out.push_str(&format!("        assertEquals(\"{}\", ex.step)\n", gate.id));
+            out.push_str("        assertEquals(\"gate_failed\", ex.type)\n");
+            out.push_str("    }\n\n");
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn kotlin_sample_value(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "true".into(),
+        VarType::Int => "100L".into(),
+        VarType::Float => "10.0".into(),
+        VarType::String => "\"test\"".into(),
+        VarType::Enum(variants) => variants
+            .first()
+            .map(|v| format!("\"{}\"", v))
+            .unwrap_or("\"\"".into()),
+        VarType::List(_) => "emptyList()".into(),
+        VarType::Object(_) => "emptyMap()".into(),
+    }
+}
+
+fn kotlin_default_value(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "false".into(),
+        VarType::Int => "0L".into(),
+        VarType::Float => "0.0".into(),
+        VarType::String => "\"\"".into(),
+        VarType::Enum(variants) => variants
+            .first()
+            .map(|v| format!("\"{}\"", v))
+            .unwrap_or("\"\"".into()),
+        VarType::List(_) => "emptyList()".into(),
+        VarType::Object(_) => "emptyMap()".into(),
+    }
+}
+
+// ============================================================================
+// Swift Test Generation (XCTest)
+// ============================================================================
+
+fn generate_swift(orch: &Orchestrator) -> String {
+    let mut out = String::new();
+    let class_name = to_pascal_case(&orch.id);
+    let func_name = to_camel_case(&orch.id);
+
+    // Header
+    out.push_str(&format!("// GENERATED TESTS FROM: {}.yaml\n", orch.id));
+    out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
+    out.push_str("// DO NOT EDIT — regenerate from spec\n\n");
+
+    out.push_str("import XCTest\n\n");
+
+    out.push_str(&format!(
+        "final class {}Tests: XCTestCase {{\n\n",
+        class_name
+    ));
+
+    // Happy path
+    out.push_str("    func testExecuteWithValidInputsSucceeds() {\n");
+    let input_values: Vec<String> = orch
+        .inputs
+        .iter()
+        .map(|i| {
+            format!(
+                "{}: {}",
+                to_camel_case(&i.name),
+                swift_sample_value(&i.var_type)
+            )
+        })
+        .collect();
+    out.push_str(&format!(
+        "        let input = {}OrchestratorInput({})\n\n",
+        class_name,
+        input_values.join(", ")
+    ));
+    out.push_str(&format!(
+        "        XCTAssertNoThrow(try {}(input))\n",
+        func_name
+    ));
+    out.push_str("    }\n\n");
+
+    // Gate failure tests
+    for step in &orch.chain {
+        if let ChainStep::Gate(gate) = step {
+            out.push_str(&format!(
+                "    func testExecuteWhenGate{}FailsThrows() {{\n",
+                to_pascal_case(&gate.id)
+            ));
+            let default_values: Vec<String> = orch
+                .inputs
+                .iter()
+                .map(|i| {
+                    format!(
+                        "{}: {}",
+                        to_camel_case(&i.name),
+                        swift_default_value(&i.var_type)
+                    )
+                })
+                .collect();
+            out.push_str(&format!(
+                "        let input = {}OrchestratorInput({})\n\n",
+                class_name,
+                default_values.join(", ")
+            ));
+            out.push_str(&format!(
+                "        XCTAssertThrowsError(try {}(input)) {{ error in\n",
+                func_name
+            ));
+            out.push_str(&format!(
+                "            let failure = error as? {}Error\n",
+                class_name
+            ));
+            out.push_str(&format!(
+                "            XCTAssertEqual(failure?.step, \"{}\")\n",
+                gate.id
+            ));
+            out.push_str("            XCTAssertEqual(failure?.type, \"gate_failed\")\n");
+            out.push_str("        }\n");
+            out.push_str("    }\n\n");
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn swift_sample_value(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "true".into(),
+        VarType::Int => "100".into(),
+        VarType::Float => "10.0".into(),
+        VarType::String => "\"test\"".into(),
+        VarType::Enum(variants) => variants
+            .first()
+            .map(|v| format!("\"{}\"", v))
+            .unwrap_or("\"\"".into()),
+        VarType::List(_) => "[]".into(),
+        VarType::Object(_) => "[:]".into(),
+    }
+}
+
+fn swift_default_value(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "false".into(),
+        VarType::Int => "0".into(),
+        VarType::Float => "0.0".into(),
+        VarType::String => "\"\"".into(),
+        VarType::Enum(variants) => variants
+            .first()
+            .map(|v| format!("\"{}\"", v))
+            .unwrap_or("\"\"".into()),
+        VarType::List(_) => "[]".into(),
+        VarType::Object(_) => "[:]".into(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
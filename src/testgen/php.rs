@@ -0,0 +1,167 @@
+//! PHP test generation (PHPUnit)
+
+use crate::spec::*;
+use chrono::Utc;
+
+use super::{
+    boundary_cases, extract_test_values, has_numeric_conditions, rule_test_comment, to_pascal_case,
+    TestConfig,
+};
+
+pub fn generate(spec: &Spec, config: &TestConfig) -> String {
+    let mut out = String::new();
+    let class_name = to_pascal_case(&spec.id);
+
+    out.push_str("<?php\n\n");
+    out.push_str(&format!("// GENERATED TESTS FROM: {}.yaml\n", spec.id));
+    out.push_str(&format!("// SPEC HASH: {}\n", spec.hash()));
+    out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
+    out.push_str("// DO NOT EDIT — regenerate from spec\n\n");
+
+    out.push_str("declare(strict_types=1);\n\n");
+    out.push_str("use PHPUnit\\Framework\\TestCase;\n\n");
+
+    out.push_str(&format!(
+        "final class {}Test extends TestCase\n{{\n",
+        class_name
+    ));
+
+    for rule in &spec.rules {
+        let test_name = format!("test{}", to_pascal_case(&rule.id));
+        let input = generate_php_input(spec, rule);
+
+        out.push_str(&format!(
+            "    public function {}(): void\n    {{\n",
+            test_name
+        ));
+        for line in rule_test_comment(rule, &spec.inputs) {
+            out.push_str(&format!("        // {}\n", line));
+        }
+        out.push_str(&format!(
+            "        $this->assertSame({}, (new {}())->evaluate({}));\n",
+            php_value(&rule.then, &spec.outputs),
+            class_name,
+            input
+        ));
+        out.push_str("    }\n\n");
+    }
+
+    if config.boundary && has_numeric_conditions(spec) {
+        out.push_str(&generate_boundary_tests(spec, &class_name));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// One test per numeric threshold, checking threshold - 1, threshold and + 1
+fn generate_boundary_tests(spec: &Spec, class_name: &str) -> String {
+    let mut out = String::new();
+    for case in boundary_cases(spec) {
+        out.push_str(&format!(
+            "    public function testBoundary{}(): void\n    {{\n",
+            to_pascal_case(&case.name)
+        ));
+        out.push_str(&format!(
+            "        // {} around {}\n",
+            case.input, case.threshold
+        ));
+        for point in &case.points {
+            let entries: Vec<String> = spec
+                .inputs
+                .iter()
+                .zip(&point.inputs)
+                .map(|(input, value)| {
+                    format!("'{}' => {}", input.name, php_test_value(value, input))
+                })
+                .collect();
+            out.push_str(&format!(
+                "        $this->assertSame({}, (new {}())->evaluate([{}])); // {}\n",
+                php_value(&point.expected, &spec.outputs),
+                class_name,
+                entries.join(", "),
+                point.label
+            ));
+        }
+        out.push_str("    }\n\n");
+    }
+    out
+}
+
+fn generate_php_input(spec: &Spec, rule: &Rule) -> String {
+    let values = extract_test_values(rule, &spec.inputs);
+    let entries: Vec<String> = spec
+        .inputs
+        .iter()
+        .map(|input| {
+            let value = values
+                .get(&input.name)
+                .map(|v| php_test_value(v, input))
+                .unwrap_or_else(|| default_php_value(&input.typ));
+            format!("'{}' => {}", input.name, value)
+        })
+        .collect();
+    format!("[{}]", entries.join(", "))
+}
+
+/// Adapt a shared test value string to PHP literal syntax
+fn php_test_value(value: &str, input: &Variable) -> String {
+    match &input.typ {
+        _ if value == "null" && input.optional => "null".into(),
+        // Typed properties reject null unless the input is optional
+        typ if value == "null" => default_php_value(typ),
+        VarType::Float if value.parse::<f64>().is_ok() && !value.contains('.') => {
+            format!("{}.0", value)
+        }
+        // Double-quoted PHP strings would interpolate `$`
+        _ if value.starts_with('"') && value.ends_with('"') && value.len() >= 2 => {
+            php_string(&value[1..value.len() - 1].replace("\\\"", "\""))
+        }
+        _ => value.to_string(),
+    }
+}
+
+fn default_php_value(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "false".into(),
+        VarType::Int => "0".into(),
+        VarType::Float => "0.0".into(),
+        VarType::String => "''".into(),
+        VarType::Enum(variants) => variants
+            .first()
+            .map(|v| php_string(v))
+            .unwrap_or("''".into()),
+        VarType::List(_) | VarType::Object(_) => "[]".into(),
+    }
+}
+
+/// Expected value; multi-output specs return an array keyed in output order
+fn php_value(output: &Output, outputs: &[Variable]) -> String {
+    match output {
+        Output::Single(v) => php_condition_value(v),
+        Output::Named(map) => {
+            let entries: Vec<String> = outputs
+                .iter()
+                .filter_map(|o| {
+                    map.get(&o.name)
+                        .map(|v| format!("'{}' => {}", o.name, php_condition_value(v)))
+                })
+                .collect();
+            format!("[{}]", entries.join(", "))
+        }
+    }
+}
+
+fn php_condition_value(v: &ConditionValue) -> String {
+    match v {
+        ConditionValue::Bool(b) => b.to_string(),
+        ConditionValue::Int(i) => i.to_string(),
+        ConditionValue::Float(f) => format!("{:?}", f),
+        ConditionValue::String(s) => php_string(s),
+        _ => "null".into(),
+    }
+}
+
+fn php_string(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
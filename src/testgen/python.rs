@@ -1,10 +1,13 @@
 //! Python test generation (pytest)
 
 use crate::spec::*;
+use crate::templates::context::EnsureView;
 use chrono::Utc;
 
 use super::{
-    can_enumerate, extract_test_values, generate_combinations, to_pascal_case, TestConfig,
+    bench_cases, boundary_cases, can_enumerate, ensures_cases, extract_test_values,
+    generate_combinations, has_numeric_conditions, outputs_are_literals, property_domains,
+    rule_test_comment, table_cases, to_pascal_case, PropertyDomain, TestConfig, TestMode,
 };
 
 pub fn generate(spec: &Spec, config: &TestConfig) -> String {
@@ -17,16 +20,104 @@ struct PyTestGen<'a> {
 
 impl<'a> PyTestGen<'a> {
     fn generate(&self, spec: &Spec) -> String {
-        let mut out = String::new();
+        if self.config.bench {
+            return self.generate_bench(spec);
+        }
 
+        let mut out = String::new();
         out.push_str(&format!("# GENERATED TESTS FROM: {}.yaml\n", spec.id));
         out.push_str(&format!("# SPEC HASH: {}\n", spec.hash()));
         out.push_str(&format!("# GENERATED: {}\n", Utc::now().to_rfc3339()));
         out.push_str("# DO NOT EDIT — regenerate from spec\n\n");
 
         out.push_str("import pytest\n");
+        let domains = property_domains(spec).filter(|_| self.config.property);
+        if domains.is_some() {
+            out.push_str("from hypothesis import given, strategies as st\n");
+        }
+        out.push_str(&format!("from {} import {}\n\n", spec.id, spec.id));
+
+        if self.config.mode == TestMode::TableDriven {
+            out.push_str(&self.generate_table_test(spec));
+        } else {
+            self.push_rule_and_exhaustive_tests(spec, &mut out);
+        }
+
+        if self.config.boundary && has_numeric_conditions(spec) {
+            out.push_str(&self.generate_boundary_tests(spec));
+        }
+
+        out.push_str(&self.generate_ensures_test(spec));
+
+        if let Some(domains) = domains {
+            out.push_str(&self.generate_property_tests(spec, &domains));
+        }
+
+        out
+    }
+
+    /// One test checking every `ensures` postcondition on each enumerable input
+    fn generate_ensures_test(&self, spec: &Spec) -> String {
+        let cases = ensures_cases(spec);
+        if cases.is_empty() {
+            return String::new();
+        }
+
+        let mut out = String::new();
+        out.push_str(&format!(
+            "\nclass Test{}Ensures:\n",
+            to_pascal_case(&spec.id)
+        ));
+        out.push_str("    \"\"\"Postconditions hold for every input combination\"\"\"\n\n");
+        out.push_str("    CASES = [\n");
+        for inputs in &cases {
+            let values: Vec<String> = inputs.iter().map(|v| self.to_python_value(v)).collect();
+            // One-element tuple needs a trailing comma
+            let trailing = if values.len() == 1 { "," } else { "" };
+            out.push_str(&format!("        ({}{}),\n", values.join(", "), trailing));
+        }
+        out.push_str("    ]\n\n");
+        out.push_str("    @pytest.mark.parametrize(\"inputs\", CASES)\n");
+        out.push_str("    def test_ensures(self, inputs):\n");
+        out.push_str(&format!("        result = {}(*inputs)\n", spec.id));
+        for ensure in &spec.ensures {
+            let view = EnsureView::from_cel(ensure, spec);
+            out.push_str(&format!("        assert {}, {}\n", view.py, view.message));
+        }
+        out
+    }
+
+    /// pytest-benchmark test calling the function on each representative input
+    fn generate_bench(&self, spec: &Spec) -> String {
+        let mut out = String::new();
+
+        out.push_str(&format!("# GENERATED BENCHMARK FROM: {}.yaml\n", spec.id));
+        out.push_str(&format!("# SPEC HASH: {}\n", spec.hash()));
+        out.push_str(&format!("# GENERATED: {}\n", Utc::now().to_rfc3339()));
+        out.push_str("# DO NOT EDIT — regenerate from spec\n");
+        out.push_str("# Requires pytest-benchmark\n\n");
+
         out.push_str(&format!("from {} import {}\n\n", spec.id, spec.id));
 
+        out.push_str("CASES = [\n");
+        for case in bench_cases(spec) {
+            let values: Vec<String> = case.iter().map(|v| self.to_python_value(v)).collect();
+            // One-element tuple needs a trailing comma
+            let trailing = if values.len() == 1 { "," } else { "" };
+            out.push_str(&format!("    ({}{}),\n", values.join(", "), trailing));
+        }
+        out.push_str("]\n\n\n");
+
+        out.push_str("def run_cases():\n");
+        out.push_str("    for inputs in CASES:\n");
+        out.push_str(&format!("        {}(*inputs)\n\n\n", spec.id));
+
+        out.push_str(&format!("def test_{}_benchmark(benchmark):\n", spec.id));
+        out.push_str("    benchmark(run_cases)\n");
+        out
+    }
+
+    fn push_rule_and_exhaustive_tests(&self, spec: &Spec, out: &mut String) {
         out.push_str(&format!("class Test{}Rules:\n", to_pascal_case(&spec.id)));
         out.push_str("    \"\"\"One test per rule\"\"\"\n\n");
 
@@ -36,12 +127,9 @@ impl<'a> PyTestGen<'a> {
             let expected = self.python_value(&rule.then);
 
             out.push_str(&format!("    def {}(self):\n", test_name));
-            out.push_str(&format!(
-                "        # {}: {} → {}\n",
-                rule.id,
-                rule.as_cel().unwrap_or_default(),
-                rule.then
-            ));
+            for line in rule_test_comment(rule, &spec.inputs) {
+                out.push_str(&format!("        # {}\n", line));
+            }
             out.push_str(&format!(
                 "        assert {}({}) == {}\n\n",
                 spec.id, inputs, expected
@@ -102,10 +190,167 @@ impl<'a> PyTestGen<'a> {
                     .join(", ")
             ));
         }
+    }
 
+    /// Rule and exhaustive cases as rows of one `test_table`
+    fn generate_table_test(&self, spec: &Spec) -> String {
+        let mut out = String::new();
+        out.push_str(&format!("class Test{}Table:\n", to_pascal_case(&spec.id)));
+        out.push_str("    \"\"\"Every case in one table\"\"\"\n\n");
+
+        out.push_str("    CASES = [\n");
+        for case in table_cases(spec) {
+            let mut values: Vec<String> = case
+                .inputs
+                .iter()
+                .map(|v| self.to_python_value(v))
+                .collect();
+            if values.len() == 1 {
+                // One-element tuple needs a trailing comma
+                values[0].push(',');
+            }
+            out.push_str(&format!(
+                "        (\"{}\", ({}), {}),\n",
+                case.label,
+                values.join(", "),
+                self.python_value(&case.expected)
+            ));
+        }
+        out.push_str("    ]\n\n");
+
+        out.push_str("    def test_table(self):\n");
+        out.push_str("        for i, (rule, inputs, expected) in enumerate(self.CASES):\n");
+        out.push_str(&format!(
+            "            assert {}(*inputs) == expected, f\"case {{i}} ({{rule}})\"\n",
+            spec.id
+        ));
         out
     }
 
+    /// One test per numeric threshold, checking threshold - 1, threshold and + 1
+    fn generate_boundary_tests(&self, spec: &Spec) -> String {
+        let cases = boundary_cases(spec);
+        if cases.is_empty() {
+            return String::new();
+        }
+
+        let mut out = String::new();
+        out.push_str(&format!(
+            "\n\nclass Test{}Boundaries:\n",
+            to_pascal_case(&spec.id)
+        ));
+        out.push_str("    \"\"\"Values around numeric thresholds\"\"\"\n");
+
+        for case in cases {
+            out.push_str(&format!("\n    def test_boundary_{}(self):\n", case.name));
+            out.push_str(&format!(
+                "        # {} around {}\n",
+                case.input, case.threshold
+            ));
+            for point in &case.points {
+                let inputs: Vec<String> = point
+                    .inputs
+                    .iter()
+                    .map(|v| self.to_python_value(v))
+                    .collect();
+                out.push_str(&format!(
+                    "        assert {}({}) == {}  # {}\n",
+                    spec.id,
+                    inputs.join(", "),
+                    self.python_value(&point.expected),
+                    point.label
+                ));
+            }
+        }
+        out
+    }
+
+    fn generate_property_tests(&self, spec: &Spec, domains: &[PropertyDomain]) -> String {
+        let mut out = String::new();
+        let names: Vec<&str> = spec.inputs.iter().map(|i| i.name.as_str()).collect();
+
+        out.push_str(&format!(
+            "\n\nclass Test{}Properties:\n",
+            to_pascal_case(&spec.id)
+        ));
+        out.push_str("    \"\"\"Arbitrary inputs within declared domains\"\"\"\n\n");
+
+        let strategies: Vec<String> = names
+            .iter()
+            .zip(domains)
+            .map(|(name, domain)| format!("{}={}", name, self.strategy(domain)))
+            .collect();
+        out.push_str(&format!("    @given({})\n", strategies.join(", ")));
+        let params: Vec<String> = std::iter::once("self".to_string())
+            .chain(names.iter().map(|n| n.to_string()))
+            .collect();
+        out.push_str(&format!(
+            "    def test_always_valid_output({}):\n",
+            params.join(", ")
+        ));
+
+        let call = format!("{}({})", spec.id, names.join(", "));
+        if spec.default.is_some() {
+            out.push_str(&format!("        result = {}\n", call));
+        } else {
+            // Without a default, unmatched inputs raise by design
+            out.push_str("        try:\n");
+            out.push_str(&format!("            result = {}\n", call));
+            out.push_str("        except ValueError:\n");
+            out.push_str("            return\n");
+        }
+
+        if outputs_are_literals(spec) {
+            let mut valid: Vec<String> = Vec::new();
+            for output in spec
+                .rules
+                .iter()
+                .map(|r| &r.then)
+                .chain(spec.default.as_ref())
+            {
+                let value = self.python_value(output);
+                if !valid.contains(&value) {
+                    valid.push(value);
+                }
+            }
+            out.push_str(&format!(
+                "        assert result in [{}]\n",
+                valid.join(", ")
+            ));
+        } else {
+            // Computed outputs have no finite set to check against
+            out.push_str("        assert result is not None\n");
+        }
+
+        out
+    }
+
+    fn strategy(&self, domain: &PropertyDomain) -> String {
+        let quoted = |values: &[String]| -> String {
+            values
+                .iter()
+                .map(|v| format!("\"{}\"", v.replace('\\', "\\\\").replace('"', "\\\"")))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match domain {
+            PropertyDomain::Bool => "st.booleans()".into(),
+            PropertyDomain::Enum(variants) => format!("st.sampled_from([{}])", quoted(variants)),
+            PropertyDomain::Int(lo, hi) => {
+                format!("st.integers(min_value={}, max_value={})", lo, hi)
+            }
+            PropertyDomain::Float(lo, hi) => format!(
+                "st.floats(min_value={:?}, max_value={:?}, allow_nan=False)",
+                lo, hi
+            ),
+            PropertyDomain::String(literals) if literals.is_empty() => "st.text()".into(),
+            PropertyDomain::String(literals) => format!(
+                "st.one_of(st.sampled_from([{}]), st.text())",
+                quoted(literals)
+            ),
+        }
+    }
+
     fn generate_inputs(&self, spec: &Spec, rule: &Rule) -> String {
         let values = extract_test_values(rule, &spec.inputs);
         spec.inputs
@@ -0,0 +1,156 @@
+//! Ruby test generation (RSpec)
+
+use crate::spec::*;
+use chrono::Utc;
+
+use super::{
+    boundary_cases, extract_test_values, has_numeric_conditions, rule_test_comment, to_pascal_case,
+    TestConfig,
+};
+
+pub fn generate(spec: &Spec, config: &TestConfig) -> String {
+    let mut out = String::new();
+    let module_name = to_pascal_case(&spec.id);
+
+    out.push_str("# frozen_string_literal: true\n\n");
+    out.push_str(&format!("# GENERATED TESTS FROM: {}.yaml\n", spec.id));
+    out.push_str(&format!("# SPEC HASH: {}\n", spec.hash()));
+    out.push_str(&format!("# GENERATED: {}\n", Utc::now().to_rfc3339()));
+    out.push_str("# DO NOT EDIT — regenerate from spec\n\n");
+
+    out.push_str(&format!("require_relative '{}'\n\n", spec.id));
+    out.push_str(&format!("RSpec.describe {} do\n", module_name));
+
+    for (i, rule) in spec.rules.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        out.push_str(&format!("  it {} do\n", ruby_string(&rule.id)));
+        for line in rule_test_comment(rule, &spec.inputs) {
+            out.push_str(&format!("    # {}\n", line));
+        }
+        out.push_str(&format!(
+            "    expect({}.evaluate({})).to eq({})\n",
+            module_name,
+            generate_ruby_input(spec, rule),
+            ruby_value(&rule.then, &spec.outputs)
+        ));
+        out.push_str("  end\n");
+    }
+
+    if config.boundary && has_numeric_conditions(spec) {
+        out.push_str(&generate_boundary_tests(spec, &module_name));
+    }
+
+    out.push_str("end\n");
+    out
+}
+
+/// One example per numeric threshold, checking threshold - 1, threshold and + 1
+fn generate_boundary_tests(spec: &Spec, module_name: &str) -> String {
+    let mut out = String::new();
+    for case in boundary_cases(spec) {
+        out.push('\n');
+        out.push_str(&format!(
+            "  it {} do\n",
+            ruby_string(&format!(
+                "boundary {} around {}",
+                case.input, case.threshold
+            ))
+        ));
+        for point in &case.points {
+            let entries: Vec<String> = spec
+                .inputs
+                .iter()
+                .zip(&point.inputs)
+                .map(|(input, value)| format!("{}: {}", input.name, ruby_test_value(value, input)))
+                .collect();
+            out.push_str(&format!(
+                "    expect({}.evaluate({{ {} }})).to eq({}) # {}\n",
+                module_name,
+                entries.join(", "),
+                ruby_value(&point.expected, &spec.outputs),
+                point.label
+            ));
+        }
+        out.push_str("  end\n");
+    }
+    out
+}
+
+fn generate_ruby_input(spec: &Spec, rule: &Rule) -> String {
+    let values = extract_test_values(rule, &spec.inputs);
+    let entries: Vec<String> = spec
+        .inputs
+        .iter()
+        .map(|input| {
+            let value = values
+                .get(&input.name)
+                .map(|v| ruby_test_value(v, input))
+                .unwrap_or_else(|| default_ruby_value(&input.typ));
+            format!("{}: {}", input.name, value)
+        })
+        .collect();
+    format!("{{ {} }}", entries.join(", "))
+}
+
+/// Adapt a shared test value string to Ruby literal syntax
+fn ruby_test_value(value: &str, input: &Variable) -> String {
+    match &input.typ {
+        _ if value == "null" => "nil".into(),
+        VarType::Float if value.parse::<f64>().is_ok() && !value.contains('.') => {
+            format!("{}.0", value)
+        }
+        // Double-quoted Ruby strings would interpolate `#{}`
+        _ if value.starts_with('"') && value.ends_with('"') && value.len() >= 2 => {
+            ruby_string(&value[1..value.len() - 1].replace("\\\"", "\""))
+        }
+        _ => value.to_string(),
+    }
+}
+
+fn default_ruby_value(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "false".into(),
+        VarType::Int => "0".into(),
+        VarType::Float => "0.0".into(),
+        VarType::String => "''".into(),
+        VarType::Enum(variants) => variants
+            .first()
+            .map(|v| ruby_string(v))
+            .unwrap_or("''".into()),
+        VarType::List(_) => "[]".into(),
+        VarType::Object(_) => "{}".into(),
+    }
+}
+
+/// Expected value; multi-output specs return a hash keyed by output name
+fn ruby_value(output: &Output, outputs: &[Variable]) -> String {
+    match output {
+        Output::Single(v) => ruby_condition_value(v),
+        Output::Named(map) => {
+            let entries: Vec<String> = outputs
+                .iter()
+                .filter_map(|o| {
+                    map.get(&o.name)
+                        .map(|v| format!("{}: {}", o.name, ruby_condition_value(v)))
+                })
+                .collect();
+            format!("{{ {} }}", entries.join(", "))
+        }
+    }
+}
+
+fn ruby_condition_value(v: &ConditionValue) -> String {
+    match v {
+        ConditionValue::Bool(b) => b.to_string(),
+        ConditionValue::Int(i) => i.to_string(),
+        ConditionValue::Float(f) => format!("{:?}", f),
+        ConditionValue::String(s) => ruby_string(s),
+        _ => "nil".into(),
+    }
+}
+
+fn ruby_string(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
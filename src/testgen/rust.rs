@@ -1,22 +1,38 @@
 //! Rust test generation
 
+use crate::render::native_enum;
 use crate::spec::*;
+use crate::templates::context::EnsureView;
 use chrono::Utc;
 
 use super::{
-    can_enumerate, extract_test_values, generate_combinations, has_numeric_conditions, TestConfig,
+    bench_cases, boundary_cases, can_enumerate, ensures_cases, extract_test_values,
+    generate_combinations, has_numeric_conditions, outputs_are_literals, property_domains,
+    rule_test_comment, table_cases, PropertyDomain, TableCase, TestConfig, TestMode,
 };
 
 pub fn generate(spec: &Spec, config: &TestConfig) -> String {
     RustTestGen { config }.generate(spec)
 }
 
+/// One test module running the table-driven cases of every spec
+pub fn generate_aggregate(specs: &[Spec], config: &TestConfig) -> String {
+    RustTestGen { config }.generate_aggregate(specs)
+}
+
 struct RustTestGen<'a> {
     config: &'a TestConfig,
 }
 
 impl<'a> RustTestGen<'a> {
     fn generate(&self, spec: &Spec) -> String {
+        if self.config.fuzz {
+            return self.generate_fuzz(spec);
+        }
+        if self.config.bench {
+            return self.generate_bench(spec);
+        }
+
         let mut out = String::new();
 
         // Header
@@ -30,86 +46,79 @@ impl<'a> RustTestGen<'a> {
         out.push_str("    #[allow(unused_imports)]\n");
         out.push_str("    use super::*;\n\n");
 
-        // Rule tests
-        out.push_str("    // ═══════════════════════════════════════════════════════════════\n");
-        out.push_str("    // Rule tests (one per rule)\n");
-        out.push_str("    // ═══════════════════════════════════════════════════════════════\n\n");
-
-        for rule in &spec.rules {
-            let test_name = format!("test_{}", rule.id.to_lowercase());
-            let inputs = self.generate_inputs(spec, rule);
-            let expected = self.rust_value_for_spec(&rule.then, spec);
-
-            out.push_str("    #[test]\n");
-            out.push_str(&format!("    fn {}() {{\n", test_name));
-            out.push_str(&format!(
-                "        // {}: {} → {}\n",
-                rule.id,
-                rule.as_cel().unwrap_or_default(),
-                rule.then
-            ));
-            out.push_str(&format!(
-                "        assert_eq!({}({}), {});\n",
-                spec.id, inputs, expected
-            ));
-            out.push_str("    }\n\n");
+        if self.config.mode == TestMode::TableDriven {
+            out.push_str(&self.generate_table_test(spec));
+        } else {
+            self.push_rule_and_exhaustive_tests(spec, &mut out);
         }
 
-        // Exhaustive tests
-        if self.config.exhaustive && can_enumerate(spec) {
+        // Boundary tests
+        if self.config.boundary && has_numeric_conditions(spec) {
             out.push_str(
                 "    // ═══════════════════════════════════════════════════════════════\n",
             );
-            out.push_str("    // Exhaustive tests (all input combinations)\n");
+            out.push_str("    // Boundary tests\n");
             out.push_str(
                 "    // ═══════════════════════════════════════════════════════════════\n\n",
             );
 
-            out.push_str("    #[test]\n");
-            out.push_str("    fn test_exhaustive() {\n");
-
-            let combinations = generate_combinations(spec);
-            for (inputs, rule_id, expected) in combinations {
-                let input_str = inputs
-                    .iter()
-                    .map(|v| self.to_rust_value(v))
-                    .collect::<Vec<_>>()
-                    .join(", ");
+            for case in boundary_cases(spec) {
+                out.push_str("    #[test]\n");
+                out.push_str(&format!("    fn test_boundary_{}() {{\n", case.name));
                 out.push_str(&format!(
-                    "        assert_eq!({}({}), {});  // {}\n",
-                    spec.id, input_str, expected, rule_id
+                    "        // {} around {}\n",
+                    case.input, case.threshold
                 ));
+                for point in &case.points {
+                    let inputs = self.rust_args(spec, &point.inputs);
+                    out.push_str(&format!(
+                        "        assert_eq!({}({}), {});  // {}\n",
+                        spec.id,
+                        inputs.join(", "),
+                        self.rust_value_for_spec(&point.expected, spec),
+                        point.label
+                    ));
+                }
+                out.push_str("    }\n\n");
             }
-
-            out.push_str("    }\n\n");
         }
 
-        // Boundary tests
-        if self.config.boundary && has_numeric_conditions(spec) {
+        // Postcondition tests
+        let cases = ensures_cases(spec);
+        if !cases.is_empty() {
             out.push_str(
                 "    // ═══════════════════════════════════════════════════════════════\n",
             );
-            out.push_str("    // Boundary tests\n");
+            out.push_str("    // Postcondition tests (ensures)\n");
             out.push_str(
                 "    // ═══════════════════════════════════════════════════════════════\n\n",
             );
 
-            let boundary_tests = self.generate_boundary_tests(spec);
-            for (name, tests) in boundary_tests {
-                out.push_str("    #[test]\n");
-                out.push_str(&format!("    fn test_boundary_{}() {{\n", name));
-                for (inputs, expected, comment) in tests {
-                    out.push_str(&format!(
-                        "        assert_eq!({}({}), {});  // {}\n",
-                        spec.id, inputs, expected, comment
-                    ));
-                }
-                out.push_str("    }\n\n");
+            out.push_str("    #[test]\n");
+            out.push_str("    fn test_ensures() {\n");
+            out.push_str("        let results = vec![\n");
+            for inputs in &cases {
+                out.push_str(&format!(
+                    "            {}({}),\n",
+                    spec.id,
+                    self.rust_args(spec, inputs).join(", ")
+                ));
+            }
+            out.push_str("        ];\n");
+            out.push_str("        for result in results {\n");
+            for ensure in &spec.ensures {
+                let view = EnsureView::from_cel(ensure, spec);
+                out.push_str(&format!(
+                    "            assert!({}, {});\n",
+                    view.rust, view.message_rust
+                ));
             }
+            out.push_str("        }\n");
+            out.push_str("    }\n\n");
         }
 
         // Property tests
-        if self.config.property {
+        if let Some(domains) = property_domains(spec).filter(|_| self.config.property) {
             out.push_str(
                 "    // ═══════════════════════════════════════════════════════════════\n",
             );
@@ -124,33 +133,34 @@ impl<'a> RustTestGen<'a> {
             out.push_str("        use super::*;\n");
             out.push_str("        use proptest::prelude::*;\n\n");
 
-            let valid_outputs = self.collect_outputs(spec);
-
             out.push_str("        proptest! {\n");
             out.push_str("            #[test]\n");
             // Build the function signature without format! to avoid brace interpretation
             out.push_str("            fn prop_always_valid_output(");
-            out.push_str(&self.proptest_args(spec));
+            out.push_str(&self.proptest_args(spec, &domains));
             out.push_str(") {\n");
 
             // Build the function call
-            out.push_str("                let result = ");
-            out.push_str(&spec.id);
-            out.push('(');
-            out.push_str(&self.function_args(spec));
-            out.push_str(");\n");
-
-            // Build the assertion - use a match or if-else to avoid format string issues
-            if valid_outputs.len() == 1 {
+            let call = format!("{}({})", spec.id, self.function_args(spec));
+            if spec.default.is_some() {
+                out.push_str(&format!("                let result = {};\n", call));
+            } else {
+                // Without a default, unmatched inputs panic by design
+                out.push_str(&format!(
+                    "                let Ok(result) = std::panic::catch_unwind(|| {}) else {{\n",
+                    call
+                ));
+                out.push_str("                    return Ok(());\n");
+                out.push_str("                };\n");
+            }
+
+            let valid_outputs = self.collect_outputs(spec);
+            if !outputs_are_literals(spec) {
+                // Computed outputs have no finite set to check against
+                out.push_str("                let _ = result;\n");
+            } else if valid_outputs.len() == 1 {
                 out.push_str("                prop_assert!(result == ");
-                // Write the output value directly, escaping quotes if needed
-                let output_val = &valid_outputs[0];
-                if output_val.starts_with('"') {
-                    // It's already a string literal, write it as-is
-                    out.push_str(output_val);
-                } else {
-                    out.push_str(output_val);
-                }
+                out.push_str(&valid_outputs[0]);
                 out.push_str(");\n");
             } else {
                 // Build vec![] with individual string literals
@@ -174,6 +184,333 @@ impl<'a> RustTestGen<'a> {
         out
     }
 
+    /// `cargo-fuzz` target: builds the inputs from fuzz bytes via `Arbitrary`
+    /// and checks every call returns a declared output without panicking
+    fn generate_fuzz(&self, spec: &Spec) -> String {
+        let mut out = String::new();
+
+        out.push_str(&format!(
+            "// GENERATED FUZZ TARGET FROM: {}.yaml\n",
+            spec.id
+        ));
+        out.push_str(&format!("// SPEC HASH: {}\n", spec.hash()));
+        out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
+        out.push_str("// DO NOT EDIT — regenerate from spec\n");
+        out.push_str(&format!(
+            "// Place in fuzz/fuzz_targets/{}.rs (deps: libfuzzer-sys, arbitrary with \"derive\")\n\n",
+            spec.id
+        ));
+
+        out.push_str("#![no_main]\n\n");
+        out.push_str("use arbitrary::Arbitrary;\n");
+        out.push_str("use libfuzzer_sys::fuzz_target;\n\n");
+        out.push_str("// Adjust the path to wherever the spec was rendered\n");
+        out.push_str(&format!("#[path = \"../../src/{}.rs\"]\n", spec.id));
+        out.push_str(&format!("mod {};\n", spec.id));
+        out.push_str(&format!("use {}::{};\n\n", spec.id, imported_items(spec)));
+
+        // Enum inputs are fuzzed as an index into their declared variants
+        for input in &spec.inputs {
+            if let VarType::Enum(variants) = &input.typ {
+                let (typ, items): (String, Vec<String>) = match native_enum(&input.name, &input.typ)
+                {
+                    Some(native) => (
+                        native.name.clone(),
+                        native
+                            .variants
+                            .iter()
+                            .map(|v| format!("{}::{}", native.name, v.ident))
+                            .collect(),
+                    ),
+                    None => (
+                        "&str".into(),
+                        variants
+                            .iter()
+                            .map(|v| {
+                                format!("\"{}\"", v.replace('\\', "\\\\").replace('"', "\\\""))
+                            })
+                            .collect(),
+                    ),
+                };
+                out.push_str(&format!(
+                    "const {}_VARIANTS: [{}; {}] = [{}];\n",
+                    input.name.to_uppercase(),
+                    typ,
+                    items.len(),
+                    items.join(", ")
+                ));
+            }
+        }
+
+        out.push_str("\n#[derive(Debug, Arbitrary)]\n");
+        out.push_str("struct FuzzInput {\n");
+        for input in &spec.inputs {
+            let Some(typ) = fuzz_field_type(&input.typ) else {
+                continue;
+            };
+            if input.optional {
+                out.push_str(&format!("    {}: Option<{}>,\n", input.name, typ));
+            } else {
+                out.push_str(&format!("    {}: {},\n", input.name, typ));
+            }
+        }
+        out.push_str("}\n\n");
+
+        let args: Vec<String> = spec
+            .inputs
+            .iter()
+            .map(|input| {
+                let field = format!("input.{}", input.name);
+                match &input.typ {
+                    VarType::Enum(_) => {
+                        let owned = match native_enum(&input.name, &input.typ) {
+                            Some(_) => "",
+                            None => ".to_string()",
+                        };
+                        let pick = |idx: &str| {
+                            format!(
+                                "{}_VARIANTS[{} as usize % {}_VARIANTS.len()]{}",
+                                input.name.to_uppercase(),
+                                idx,
+                                input.name.to_uppercase(),
+                                owned
+                            )
+                        };
+                        if input.optional {
+                            format!("{}.map(|i| {})", field, pick("i"))
+                        } else {
+                            pick(&field)
+                        }
+                    }
+                    // Lists and objects have no Arbitrary domain here
+                    VarType::List(_) | VarType::Object(_) => "Default::default()".into(),
+                    _ => field,
+                }
+            })
+            .collect();
+
+        out.push_str("fuzz_target!(|input: FuzzInput| {\n");
+        if spec.default.is_none() {
+            out.push_str("    // No default: a panic here is an input no rule covers\n");
+        }
+        out.push_str(&format!(
+            "    let result = {}({});\n",
+            spec.id,
+            args.join(", ")
+        ));
+
+        let valid_outputs = self.collect_outputs(spec);
+        if !outputs_are_literals(spec) {
+            // Computed outputs have no finite set to check against
+            out.push_str("    let _ = result;\n");
+        } else {
+            out.push_str(&format!(
+                "    let valid_outputs = [{}];\n",
+                valid_outputs.join(", ")
+            ));
+            out.push_str(
+                "    assert!(valid_outputs.contains(&result), \"undeclared output: {:?}\", result);\n",
+            );
+        }
+        out.push_str("});\n");
+        out
+    }
+
+    /// Criterion benchmark calling the function on each representative input
+    fn generate_bench(&self, spec: &Spec) -> String {
+        let mut out = String::new();
+
+        out.push_str(&format!("// GENERATED BENCHMARK FROM: {}.yaml\n", spec.id));
+        out.push_str(&format!("// SPEC HASH: {}\n", spec.hash()));
+        out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
+        out.push_str("// DO NOT EDIT — regenerate from spec\n");
+        out.push_str(&format!(
+            "// Place in benches/{}.rs with `harness = false` (dev-deps: criterion)\n\n",
+            spec.id
+        ));
+
+        out.push_str("use criterion::{criterion_group, criterion_main, Criterion};\n");
+        out.push_str("use std::hint::black_box;\n\n");
+        out.push_str("// Adjust the path to wherever the spec was rendered\n");
+        out.push_str(&format!("#[path = \"../src/{}.rs\"]\n", spec.id));
+        out.push_str(&format!("mod {};\n", spec.id));
+        out.push_str(&format!("use {}::{};\n\n", spec.id, imported_items(spec)));
+
+        out.push_str(&format!("fn bench_{}(c: &mut Criterion) {{\n", spec.id));
+        out.push_str("    let cases = vec![\n");
+        for case in bench_cases(spec) {
+            let values = self.rust_args(spec, &case);
+            out.push_str(&format!("        {},\n", rust_tuple(&values)));
+        }
+        out.push_str("    ];\n\n");
+
+        let names: Vec<String> = spec.inputs.iter().map(|i| i.name.clone()).collect();
+        out.push_str(&format!(
+            "    c.bench_function(\"{}\", |bencher| {{\n",
+            spec.id
+        ));
+        out.push_str("        bencher.iter(|| {\n");
+        out.push_str("            for case in &cases {\n");
+        out.push_str(&format!(
+            "                let {} = black_box(case.clone());\n",
+            rust_tuple(&names)
+        ));
+        out.push_str(&format!(
+            "                black_box({}({}));\n",
+            spec.id,
+            self.function_args(spec)
+        ));
+        out.push_str("            }\n");
+        out.push_str("        });\n");
+        out.push_str("    });\n");
+        out.push_str("}\n\n");
+
+        out.push_str(&format!("criterion_group!(benches, bench_{});\n", spec.id));
+        out.push_str("criterion_main!(benches);\n");
+        out
+    }
+
+    fn push_rule_and_exhaustive_tests(&self, spec: &Spec, out: &mut String) {
+        // Rule tests
+        out.push_str("    // ═══════════════════════════════════════════════════════════════\n");
+        out.push_str("    // Rule tests (one per rule)\n");
+        out.push_str("    // ═══════════════════════════════════════════════════════════════\n\n");
+
+        for rule in &spec.rules {
+            let test_name = format!("test_{}", rule.id.to_lowercase());
+            let inputs = self.generate_inputs(spec, rule);
+            let expected = self.rust_value_for_spec(&rule.then, spec);
+
+            out.push_str("    #[test]\n");
+            out.push_str(&format!("    fn {}() {{\n", test_name));
+            for line in rule_test_comment(rule, &spec.inputs) {
+                out.push_str(&format!("        // {}\n", line));
+            }
+            out.push_str(&format!(
+                "        assert_eq!({}({}), {});\n",
+                spec.id, inputs, expected
+            ));
+            out.push_str("    }\n\n");
+        }
+
+        // Exhaustive tests
+        if self.config.exhaustive && can_enumerate(spec) {
+            out.push_str(
+                "    // ═══════════════════════════════════════════════════════════════\n",
+            );
+            out.push_str("    // Exhaustive tests (all input combinations)\n");
+            out.push_str(
+                "    // ═══════════════════════════════════════════════════════════════\n\n",
+            );
+
+            out.push_str("    #[test]\n");
+            out.push_str("    fn test_exhaustive() {\n");
+
+            let combinations = generate_combinations(spec);
+            for (inputs, rule_id, expected) in combinations {
+                let input_str = self.rust_args(spec, &inputs).join(", ");
+                out.push_str(&format!(
+                    "        assert_eq!({}({}), {});  // {}\n",
+                    spec.id, input_str, expected, rule_id
+                ));
+            }
+
+            out.push_str("    }\n\n");
+        }
+    }
+
+    /// Rule and exhaustive cases as rows of one `test_table`
+    fn generate_table_test(&self, spec: &Spec) -> String {
+        let cases = table_cases(spec);
+        if cases.is_empty() {
+            return String::new();
+        }
+
+        let mut out = String::new();
+        out.push_str("    // ═══════════════════════════════════════════════════════════════\n");
+        out.push_str("    // Table-driven tests\n");
+        out.push_str("    // ═══════════════════════════════════════════════════════════════\n\n");
+
+        out.push_str("    #[test]\n");
+        out.push_str("    fn test_table() {\n");
+        self.push_table_cases(spec, &cases, &mut out);
+        out.push_str("    }\n\n");
+        out
+    }
+
+    /// Body of a table-driven test: the rows, then one assertion per row
+    fn push_table_cases(&self, spec: &Spec, cases: &[TableCase], out: &mut String) {
+        out.push_str("        let cases = vec![\n");
+        for case in cases {
+            let values = self.rust_args(spec, &case.inputs);
+            out.push_str(&format!(
+                "            (\"{}\", {}, {}),\n",
+                case.label,
+                rust_tuple(&values),
+                self.rust_value_for_spec(&case.expected, spec)
+            ));
+        }
+        out.push_str("        ];\n\n");
+
+        let names: Vec<String> = spec.inputs.iter().map(|i| i.name.clone()).collect();
+        out.push_str(
+            "        for (i, (rule, input, expected)) in cases.into_iter().enumerate() {\n",
+        );
+        out.push_str(&format!(
+            "            let {} = input;\n",
+            rust_tuple(&names)
+        ));
+        out.push_str(&format!(
+            "            assert_eq!({}({}), expected, \"case {{}} ({{}}) failed\", i, rule);\n",
+            spec.id,
+            self.function_args(spec)
+        ));
+        out.push_str("        }\n");
+    }
+
+    /// Every spec's table-driven test in one module, named by spec id
+    ///
+    /// Each test imports its spec's module inside the function body, so
+    /// enums of the same name in two specs don't collide.
+    fn generate_aggregate(&self, specs: &[Spec]) -> String {
+        let mut out = String::new();
+
+        out.push_str(&format!("// GENERATED TESTS FROM: {} specs\n", specs.len()));
+        for spec in specs {
+            out.push_str(&format!("// SPEC HASH: {} {}\n", spec.id, spec.hash()));
+        }
+        out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
+        out.push_str("// DO NOT EDIT — regenerate from specs\n");
+        out.push_str(
+            "// Include where the rendered spec modules are declared (`mod a; mod b;`)\n\n",
+        );
+
+        out.push_str("#[cfg(test)]\n");
+        out.push_str("mod spec_tests {\n");
+        for spec in specs {
+            let cases = table_cases(spec);
+            if cases.is_empty() {
+                continue;
+            }
+            out.push_str(
+                "    // ═══════════════════════════════════════════════════════════════\n",
+            );
+            out.push_str(&format!("    // {}\n", spec.id));
+            out.push_str(
+                "    // ═══════════════════════════════════════════════════════════════\n\n",
+            );
+
+            out.push_str("    #[test]\n");
+            out.push_str(&format!("    fn test_{}_table() {{\n", spec.id));
+            out.push_str("        #[allow(unused_imports)]\n");
+            out.push_str(&format!("        use super::{}::*;\n\n", spec.id));
+            self.push_table_cases(spec, &cases, &mut out);
+            out.push_str("    }\n\n");
+        }
+        out.push_str("}\n");
+        out
+    }
+
     fn generate_inputs(&self, spec: &Spec, rule: &Rule) -> String {
         let values = extract_test_values(rule, &spec.inputs);
         let inputs: Vec<String> = spec
@@ -185,12 +522,31 @@ impl<'a> RustTestGen<'a> {
                     .cloned()
                     .unwrap_or_else(|| self.default_value(&input.typ));
                 // Convert to Rust syntax if needed
-                self.to_rust_value(&value)
+                self.rust_input_value(input, &value)
             })
             .collect();
         inputs.join(", ")
     }
 
+    /// Rust arguments for a row of test literals, one per input
+    fn rust_args(&self, spec: &Spec, values: &[String]) -> Vec<String> {
+        spec.inputs
+            .iter()
+            .zip(values)
+            .map(|(input, v)| self.rust_input_value(input, v))
+            .collect()
+    }
+
+    /// Rust argument for a test literal; enum inputs take their variant
+    fn rust_input_value(&self, input: &Variable, v: &str) -> String {
+        let variant = native_enum(&input.name, &input.typ).and_then(|native| {
+            let value: String = serde_json::from_str(v).ok()?;
+            let ident = &native.variant(&value)?.ident;
+            Some(format!("{}::{}", native.name, ident))
+        });
+        variant.unwrap_or_else(|| self.to_rust_value(v))
+    }
+
     fn to_rust_value(&self, v: &str) -> String {
         if v == "true" || v == "false" {
             v.to_string()
@@ -214,16 +570,23 @@ impl<'a> RustTestGen<'a> {
         };
 
         if spec.outputs.len() > 1 {
-            let values: Vec<_> = spec
+            // Multi-output specs return a result struct
+            let fields: Vec<_> = spec
                 .outputs
                 .iter()
                 .map(|out_var| {
-                    map.get(&out_var.name)
+                    let value = map
+                        .get(&out_var.name)
                         .map(|v| self.rust_condition_value(v))
-                        .unwrap_or_else(|| "Default::default()".into())
+                        .unwrap_or_else(|| "Default::default()".into());
+                    format!("{}: {}", out_var.name, value)
                 })
                 .collect();
-            format!("({})", values.join(", "))
+            format!(
+                "{}Output {{ {} }}",
+                crate::util::to_pascal_case(&spec.id),
+                fields.join(", ")
+            )
         } else if let Some(first_output) = spec.outputs.first() {
             map.get(&first_output.name)
                 .map(|v| self.rust_condition_value(v))
@@ -257,65 +620,6 @@ impl<'a> RustTestGen<'a> {
         }
     }
 
-    #[allow(clippy::type_complexity)]
-    fn generate_boundary_tests(&self, spec: &Spec) -> Vec<(String, Vec<(String, String, String)>)> {
-        let mut results = Vec::new();
-
-        for rule in &spec.rules {
-            if let Some(conditions) = &rule.conditions {
-                for cond in conditions {
-                    if let ConditionValue::Int(threshold) = &cond.value {
-                        match cond.op {
-                            ConditionOp::Gt => {
-                                results.push((
-                                    format!("{}_{}", cond.var, threshold),
-                                    vec![
-                                        (
-                                            threshold.to_string(),
-                                            "/*below*/".into(),
-                                            format!("at threshold {}", threshold),
-                                        ),
-                                        (
-                                            (threshold + 1).to_string(),
-                                            self.rust_condition_value(&match &rule.then {
-                                                Output::Single(v) => v.clone(),
-                                                _ => ConditionValue::Int(0),
-                                            }),
-                                            format!("above threshold {}", threshold),
-                                        ),
-                                    ],
-                                ));
-                            }
-                            ConditionOp::Lt => {
-                                results.push((
-                                    format!("{}_{}", cond.var, threshold),
-                                    vec![
-                                        (
-                                            (threshold - 1).to_string(),
-                                            self.rust_condition_value(&match &rule.then {
-                                                Output::Single(v) => v.clone(),
-                                                _ => ConditionValue::Int(0),
-                                            }),
-                                            format!("below threshold {}", threshold),
-                                        ),
-                                        (
-                                            threshold.to_string(),
-                                            "/*at or above*/".into(),
-                                            format!("at threshold {}", threshold),
-                                        ),
-                                    ],
-                                ));
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-            }
-        }
-
-        results
-    }
-
     fn collect_outputs(&self, spec: &Spec) -> Vec<String> {
         let mut outputs: Vec<String> = spec
             .rules
@@ -332,17 +636,36 @@ impl<'a> RustTestGen<'a> {
         outputs
     }
 
-    fn proptest_args(&self, spec: &Spec) -> String {
+    fn proptest_args(&self, spec: &Spec, domains: &[PropertyDomain]) -> String {
         spec.inputs
             .iter()
-            .map(|i| {
-                let strategy: String = match &i.typ {
-                    VarType::Bool => "any::<bool>()".into(),
-                    VarType::Int => "any::<i64>()".into(),
-                    VarType::Float => "any::<f64>()".into(),
-                    VarType::String => "any::<String>()".into(),
-                    VarType::Enum(_) => "any::<String>()".into(),
-                    _ => "any::<String>()".into(),
+            .zip(domains)
+            .map(|(i, domain)| {
+                let strategy: String = match domain {
+                    PropertyDomain::Bool => "any::<bool>()".into(),
+                    PropertyDomain::Enum(variants) => match native_enum(&i.name, &i.typ) {
+                        Some(native) => format!(
+                            "prop::sample::select(vec![{}])",
+                            native
+                                .variants
+                                .iter()
+                                .map(|v| format!("{}::{}", native.name, v.ident))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                        None => {
+                            format!("prop::sample::select(vec![{}])", self.string_vec(variants))
+                        }
+                    },
+                    PropertyDomain::Int(lo, hi) => format!("{}i64..={}i64", lo, hi),
+                    PropertyDomain::Float(lo, hi) => format!("{:?}f64..={:?}f64", lo, hi),
+                    PropertyDomain::String(literals) if literals.is_empty() => {
+                        "any::<String>()".into()
+                    }
+                    PropertyDomain::String(literals) => format!(
+                        "prop_oneof![prop::sample::select(vec![{}]), any::<String>()]",
+                        self.string_vec(literals)
+                    ),
                 };
                 format!("{} in {}", i.name, strategy)
             })
@@ -350,6 +673,19 @@ impl<'a> RustTestGen<'a> {
             .join(", ")
     }
 
+    fn string_vec(&self, values: &[String]) -> String {
+        values
+            .iter()
+            .map(|v| {
+                format!(
+                    "\"{}\".to_string()",
+                    v.replace('\\', "\\\\").replace('"', "\\\"")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     fn function_args(&self, spec: &Spec) -> String {
         spec.inputs
             .iter()
@@ -358,3 +694,40 @@ impl<'a> RustTestGen<'a> {
             .join(", ")
     }
 }
+
+/// Items a harness outside the module imports: the function, plus the
+/// native enums its inputs take (`{check, MemberTier}`)
+fn imported_items(spec: &Spec) -> String {
+    let enums: Vec<String> = spec
+        .inputs
+        .iter()
+        .filter_map(|i| native_enum(&i.name, &i.typ))
+        .map(|native| native.name)
+        .collect();
+    if enums.is_empty() {
+        spec.id.clone()
+    } else {
+        format!("{{{}, {}}}", spec.id, enums.join(", "))
+    }
+}
+
+/// Field type in the fuzz input struct (`None` for inputs without one)
+fn fuzz_field_type(typ: &VarType) -> Option<&'static str> {
+    match typ {
+        VarType::Bool => Some("bool"),
+        VarType::Int => Some("i64"),
+        VarType::Float => Some("f64"),
+        VarType::String => Some("String"),
+        VarType::Enum(_) => Some("u8"),
+        VarType::List(_) | VarType::Object(_) => None,
+    }
+}
+
+/// Rust tuple literal/pattern (`(a,)` for one element)
+fn rust_tuple(items: &[String]) -> String {
+    if items.len() == 1 {
+        format!("({},)", items[0])
+    } else {
+        format!("({})", items.join(", "))
+    }
+}
@@ -0,0 +1,164 @@
+//! Swift test generation (XCTest)
+
+use crate::spec::*;
+use chrono::Utc;
+
+use super::{
+    boundary_cases, extract_test_values, has_numeric_conditions, rule_test_comment, to_camel_case,
+    to_pascal_case, TestConfig,
+};
+
+pub fn generate(spec: &Spec, config: &TestConfig) -> String {
+    let mut out = String::new();
+    let type_name = to_pascal_case(&spec.id);
+    let func_name = to_camel_case(&spec.id);
+
+    out.push_str(&format!("// GENERATED TESTS FROM: {}.yaml\n", spec.id));
+    out.push_str(&format!("// SPEC HASH: {}\n", spec.hash()));
+    out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
+    out.push_str("// DO NOT EDIT — regenerate from spec\n\n");
+
+    out.push_str("import XCTest\n\n");
+
+    out.push_str(&format!("final class {}Tests: XCTestCase {{\n", type_name));
+
+    for rule in &spec.rules {
+        let test_name = format!("test{}", to_pascal_case(&rule.id));
+        let inputs = generate_swift_input(spec, rule, &type_name);
+
+        out.push_str(&format!("    func {}() {{\n", test_name));
+        for line in rule_test_comment(rule, &spec.inputs) {
+            out.push_str(&format!("        // {}\n", line));
+        }
+        out.push_str(&format!("        let input = {}\n", inputs));
+        match &rule.then {
+            // Multi-output specs return a struct; compare it field by field
+            Output::Named(map) if spec.outputs.len() > 1 => {
+                out.push_str(&format!("        let result = {}(input)\n", func_name));
+                for output in &spec.outputs {
+                    if let Some(value) = map.get(&output.name) {
+                        out.push_str(&format!(
+                            "        XCTAssertEqual(result.{}, {})\n",
+                            to_camel_case(&output.name),
+                            swift_condition_value(value)
+                        ));
+                    }
+                }
+            }
+            output => out.push_str(&format!(
+                "        XCTAssertEqual({}(input), {})\n",
+                func_name,
+                swift_value(output)
+            )),
+        }
+        out.push_str("    }\n\n");
+    }
+
+    if config.boundary && has_numeric_conditions(spec) {
+        out.push_str(&generate_boundary_tests(spec, &type_name, &func_name));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// One test per numeric threshold, checking threshold - 1, threshold and + 1
+fn generate_boundary_tests(spec: &Spec, type_name: &str, func_name: &str) -> String {
+    let mut out = String::new();
+    for case in boundary_cases(spec) {
+        out.push_str(&format!(
+            "    func testBoundary{}() {{\n",
+            to_pascal_case(&case.name)
+        ));
+        out.push_str(&format!(
+            "        // {} around {}\n",
+            case.input, case.threshold
+        ));
+        for point in &case.points {
+            let fields: Vec<String> = spec
+                .inputs
+                .iter()
+                .zip(&point.inputs)
+                .map(|(input, value)| {
+                    format!(
+                        "{}: {}",
+                        to_camel_case(&input.name),
+                        swift_test_value(value, input)
+                    )
+                })
+                .collect();
+            out.push_str(&format!(
+                "        XCTAssertEqual({}({}Input({})), {}) // {}\n",
+                func_name,
+                type_name,
+                fields.join(", "),
+                swift_value(&point.expected),
+                point.label
+            ));
+        }
+        out.push_str("    }\n\n");
+    }
+    out
+}
+
+fn generate_swift_input(spec: &Spec, rule: &Rule, type_name: &str) -> String {
+    let values = extract_test_values(rule, &spec.inputs);
+    let fields: Vec<String> = spec
+        .inputs
+        .iter()
+        .map(|input| {
+            let value = values
+                .get(&input.name)
+                .map(|v| swift_test_value(v, input))
+                .unwrap_or_else(|| default_swift_value(&input.typ));
+            format!("{}: {}", to_camel_case(&input.name), value)
+        })
+        .collect();
+    format!("{}Input({})", type_name, fields.join(", "))
+}
+
+/// Adapt a shared test value string to Swift literal syntax
+fn swift_test_value(value: &str, input: &Variable) -> String {
+    match &input.typ {
+        _ if value == "null" && input.optional => "nil".into(),
+        // Non-optional properties cannot hold nil
+        typ if value == "null" => default_swift_value(typ),
+        VarType::Float if value.parse::<f64>().is_ok() && !value.contains('.') => {
+            format!("{}.0", value)
+        }
+        _ => value.to_string(),
+    }
+}
+
+fn default_swift_value(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "false".into(),
+        VarType::Int => "0".into(),
+        VarType::Float => "0.0".into(),
+        VarType::String => "\"\"".into(),
+        VarType::Enum(variants) => variants
+            .first()
+            .map(|v| format!("\"{}\"", v))
+            .unwrap_or("\"\"".into()),
+        VarType::List(_) => "[]".into(),
+        VarType::Object(_) => "[:]".into(),
+    }
+}
+
+fn swift_value(output: &Output) -> String {
+    match output {
+        Output::Single(v) => swift_condition_value(v),
+        Output::Named(_) => "nil".into(),
+    }
+}
+
+fn swift_condition_value(v: &ConditionValue) -> String {
+    match v {
+        ConditionValue::Bool(b) => b.to_string(),
+        ConditionValue::Int(i) => i.to_string(),
+        ConditionValue::Float(f) => format!("{:?}", f),
+        ConditionValue::String(s) => format!("\"{}\"", s.replace('"', "\\\"")),
+        ConditionValue::Null => "nil".into(),
+        _ => "nil".into(),
+    }
+}
@@ -1,9 +1,14 @@
 //! TypeScript test generation (Vitest)
 
 use crate::spec::*;
+use crate::templates::context::EnsureView;
 use chrono::Utc;
 
-use super::{can_enumerate, extract_test_values, generate_combinations, to_camel_case, TestConfig};
+use super::{
+    bench_cases, boundary_cases, can_enumerate, ensures_cases, extract_test_values,
+    generate_combinations, has_numeric_conditions, outputs_are_literals, property_domains,
+    rule_test_comment, table_cases, to_camel_case, PropertyDomain, TestConfig, TestMode,
+};
 
 pub fn generate(spec: &Spec, config: &TestConfig) -> String {
     TsTestGen { config }.generate(spec)
@@ -15,22 +20,85 @@ struct TsTestGen<'a> {
 
 impl<'a> TsTestGen<'a> {
     fn generate(&self, spec: &Spec) -> String {
-        let mut out = String::new();
         let func_name = to_camel_case(&spec.id);
+        if self.config.bench {
+            return self.generate_bench(spec, &func_name);
+        }
 
+        let mut out = String::new();
         out.push_str(&format!("// GENERATED TESTS FROM: {}.yaml\n", spec.id));
         out.push_str(&format!("// SPEC HASH: {}\n", spec.hash()));
         out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
         out.push_str("// DO NOT EDIT — regenerate from spec\n\n");
 
         out.push_str("import { describe, it, expect } from 'vitest';\n");
+        let domains = property_domains(spec).filter(|_| self.config.property);
+        if domains.is_some() {
+            out.push_str("import fc from 'fast-check';\n");
+        }
+        out.push_str(&format!(
+            "import {{ {} }} from './{}';\n\n",
+            func_name, spec.id
+        ));
+
+        out.push_str(&format!("describe('{}', () => {{\n", func_name));
+
+        if self.config.mode == TestMode::TableDriven {
+            out.push_str(&self.generate_table_test(spec, &func_name));
+        } else {
+            self.push_rule_and_exhaustive_tests(spec, &func_name, &mut out);
+        }
+
+        // Boundary tests
+        if self.config.boundary && has_numeric_conditions(spec) {
+            out.push_str(&self.generate_boundary_tests(spec, &func_name));
+        }
+
+        // Postcondition tests
+        out.push_str(&self.generate_ensures_test(spec, &func_name));
+
+        // Property tests
+        if let Some(domains) = domains {
+            out.push_str(&self.generate_property_tests(spec, &domains, &func_name));
+        }
+
+        out.push_str("});\n");
+        out
+    }
+
+    /// `vitest bench` suite calling the function on each representative input
+    fn generate_bench(&self, spec: &Spec, func_name: &str) -> String {
+        let mut out = String::new();
+
+        out.push_str(&format!("// GENERATED BENCHMARK FROM: {}.yaml\n", spec.id));
+        out.push_str(&format!("// SPEC HASH: {}\n", spec.hash()));
+        out.push_str(&format!("// GENERATED: {}\n", Utc::now().to_rfc3339()));
+        out.push_str("// DO NOT EDIT — regenerate from spec\n");
+        out.push_str("// Run with `vitest bench`\n\n");
+
+        out.push_str("import { bench, describe } from 'vitest';\n");
         out.push_str(&format!(
             "import {{ {} }} from './{}';\n\n",
             func_name, spec.id
         ));
 
+        out.push_str("const cases = [\n");
+        for case in bench_cases(spec) {
+            out.push_str(&format!("  {},\n", self.format_input_object(spec, &case)));
+        }
+        out.push_str("];\n\n");
+
         out.push_str(&format!("describe('{}', () => {{\n", func_name));
+        out.push_str(&format!("  bench('{}', () => {{\n", func_name));
+        out.push_str("    for (const input of cases) {\n");
+        out.push_str(&format!("      {}(input);\n", func_name));
+        out.push_str("    }\n");
+        out.push_str("  });\n");
+        out.push_str("});\n");
+        out
+    }
 
+    fn push_rule_and_exhaustive_tests(&self, spec: &Spec, func_name: &str, out: &mut String) {
         // Rule tests
         out.push_str("  describe('rules', () => {\n");
         for rule in &spec.rules {
@@ -44,6 +112,10 @@ impl<'a> TsTestGen<'a> {
                 "    it('{}: {} → {}', () => {{\n",
                 rule.id, cel_desc, rule.then
             ));
+            // The title already carries the condition and output
+            for line in rule_test_comment(rule, &spec.inputs).iter().skip(1) {
+                out.push_str(&format!("      // {}\n", line));
+            }
             out.push_str(&format!(
                 "      expect({}({})).toBe({});\n",
                 func_name, inputs, expected
@@ -74,11 +146,181 @@ impl<'a> TsTestGen<'a> {
             out.push_str("    });\n");
             out.push_str("  });\n");
         }
+    }
 
-        out.push_str("});\n");
+    /// One `it` checking every `ensures` postcondition on each enumerable input
+    fn generate_ensures_test(&self, spec: &Spec, func_name: &str) -> String {
+        let cases = ensures_cases(spec);
+        if cases.is_empty() {
+            return String::new();
+        }
+
+        let mut out = String::new();
+        out.push_str("\n  it('ensures', () => {\n");
+        out.push_str("    const inputs = [\n");
+        for inputs in &cases {
+            out.push_str(&format!(
+                "      {},\n",
+                self.format_input_object(spec, inputs)
+            ));
+        }
+        out.push_str("    ];\n\n");
+        out.push_str("    for (const input of inputs) {\n");
+        out.push_str(&format!("      const result = {}(input);\n", func_name));
+        for ensure in &spec.ensures {
+            let view = EnsureView::from_cel(ensure, spec);
+            out.push_str(&format!(
+                "      expect({}, {}).toBe(true);\n",
+                view.ts, view.message
+            ));
+        }
+        out.push_str("    }\n");
+        out.push_str("  });\n");
+        out
+    }
+
+    /// Rule and exhaustive cases as rows of one `it`
+    fn generate_table_test(&self, spec: &Spec, func_name: &str) -> String {
+        let mut out = String::new();
+        out.push_str("  it('table', () => {\n");
+        out.push_str("    const cases = [\n");
+        for case in table_cases(spec) {
+            out.push_str(&format!(
+                "      {{ rule: '{}', input: {}, expected: {} }},\n",
+                case.label,
+                self.format_input_object(spec, &case.inputs),
+                self.ts_value(&case.expected)
+            ));
+        }
+        out.push_str("    ];\n\n");
+        out.push_str("    cases.forEach(({ rule, input, expected }, i) => {\n");
+        out.push_str(&format!(
+            "      expect({}(input), `case ${{i}} (${{rule}})`).toBe(expected);\n",
+            func_name
+        ));
+        out.push_str("    });\n");
+        out.push_str("  });\n");
+        out
+    }
+
+    /// One `it` per numeric threshold, checking threshold - 1, threshold and + 1
+    fn generate_boundary_tests(&self, spec: &Spec, func_name: &str) -> String {
+        let cases = boundary_cases(spec);
+        if cases.is_empty() {
+            return String::new();
+        }
+
+        let mut out = String::new();
+        out.push_str("\n  describe('boundaries', () => {\n");
+        for case in cases {
+            out.push_str(&format!(
+                "    it('{} around {}', () => {{\n",
+                case.input, case.threshold
+            ));
+            for point in &case.points {
+                out.push_str(&format!(
+                    "      expect({}({})).toBe({}); // {}\n",
+                    func_name,
+                    self.format_input_object(spec, &point.inputs),
+                    self.ts_value(&point.expected),
+                    point.label
+                ));
+            }
+            out.push_str("    });\n");
+        }
+        out.push_str("  });\n");
         out
     }
 
+    fn generate_property_tests(
+        &self,
+        spec: &Spec,
+        domains: &[PropertyDomain],
+        func_name: &str,
+    ) -> String {
+        let mut out = String::new();
+        let names: Vec<String> = spec.inputs.iter().map(|i| to_camel_case(&i.name)).collect();
+
+        out.push_str("\n  describe('properties', () => {\n");
+        out.push_str("    it('always returns a declared output', () => {\n");
+        out.push_str("      fc.assert(\n");
+        out.push_str("        fc.property(\n");
+        for domain in domains {
+            out.push_str(&format!("          {},\n", self.arbitrary(domain)));
+        }
+        out.push_str(&format!("          ({}) => {{\n", names.join(", ")));
+
+        let call = format!("{}({{ {} }})", func_name, names.join(", "));
+        if spec.default.is_some() {
+            out.push_str(&format!("            const result = {};\n", call));
+        } else {
+            // Without a default, unmatched inputs throw by design
+            out.push_str("            let result;\n");
+            out.push_str("            try {\n");
+            out.push_str(&format!("              result = {};\n", call));
+            out.push_str("            } catch {\n");
+            out.push_str("              return;\n");
+            out.push_str("            }\n");
+        }
+
+        if outputs_are_literals(spec) {
+            let mut valid: Vec<String> = Vec::new();
+            for output in spec
+                .rules
+                .iter()
+                .map(|r| &r.then)
+                .chain(spec.default.as_ref())
+            {
+                let value = self.ts_value(output);
+                if !valid.contains(&value) {
+                    valid.push(value);
+                }
+            }
+            out.push_str(&format!(
+                "            expect([{}]).toContain(result);\n",
+                valid.join(", ")
+            ));
+        } else {
+            // Computed outputs have no finite set to check against
+            out.push_str("            expect(result).toBeDefined();\n");
+        }
+
+        out.push_str("          },\n");
+        out.push_str("        ),\n");
+        out.push_str("      );\n");
+        out.push_str("    });\n");
+        out.push_str("  });\n");
+        out
+    }
+
+    fn arbitrary(&self, domain: &PropertyDomain) -> String {
+        let quoted = |values: &[String]| -> String {
+            values
+                .iter()
+                .map(|v| format!("\"{}\"", v.replace('\\', "\\\\").replace('"', "\\\"")))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match domain {
+            PropertyDomain::Bool => "fc.boolean()".into(),
+            PropertyDomain::Enum(variants) => format!("fc.constantFrom({})", quoted(variants)),
+            // fast-check integers are 32-bit
+            PropertyDomain::Int(lo, hi) => format!(
+                "fc.integer({{ min: {}, max: {} }})",
+                (*lo).max(i32::MIN as i64),
+                (*hi).min(i32::MAX as i64)
+            ),
+            PropertyDomain::Float(lo, hi) => {
+                format!("fc.double({{ min: {:?}, max: {:?}, noNaN: true }})", lo, hi)
+            }
+            PropertyDomain::String(literals) if literals.is_empty() => "fc.string()".into(),
+            PropertyDomain::String(literals) => format!(
+                "fc.oneof(fc.constantFrom({}), fc.string())",
+                quoted(literals)
+            ),
+        }
+    }
+
     fn generate_input_object(&self, spec: &Spec, rule: &Rule) -> String {
         let values = extract_test_values(rule, &spec.inputs);
         let fields: Vec<String> = spec
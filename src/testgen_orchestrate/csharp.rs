@@ -27,7 +27,7 @@ pub fn generate_integration_tests(orch: &Orchestrator, _specs: &HashMap<String,
     // Branch tests
     for step in &orch.chain {
         if let ChainStep::Branch(branch) = step {
-            for case_name in branch.cases.keys() {
+            for (case_name, _) in branch.sorted_cases() {
                 out.push_str(&format!(
                     "        [Fact]\n        public async Task Test{}_Branch{}_Case{}()\n        {{\n            var input = new {}Input\n            {{\n{}\n            }};\n            var result = await {}.Evaluate(input);\n            Assert.NotNull(result);\n        }}\n\n",
                     class_name, to_pascal(&branch.id), to_pascal(case_name), class_name,
@@ -24,7 +24,7 @@ pub fn generate_integration_tests(orch: &Orchestrator, _specs: &HashMap<String,
     // Branch tests
     for step in &orch.chain {
         if let ChainStep::Branch(branch) = step {
-            for case_name in branch.cases.keys() {
+            for (case_name, _) in branch.sorted_cases() {
                 out.push_str(&format!(
                     "func Test{}_Branch{}_Case{}(t *testing.T) {{\n\tinput := {}Input{{}}\n\tresult, err := {}(input)\n\tassert.NoError(t, err)\n\tassert.NotNil(t, result)\n}}\n\n",
                     func_name, to_pascal(&branch.id), to_pascal(case_name), func_name, func_name
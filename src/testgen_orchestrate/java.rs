@@ -24,7 +24,7 @@ pub fn generate_integration_tests(orch: &Orchestrator, _specs: &HashMap<String,
     // Branch tests
     for step in &orch.chain {
         if let ChainStep::Branch(branch) = step {
-            for case_name in branch.cases.keys() {
+            for (case_name, _) in branch.sorted_cases() {
                 out.push_str(&format!(
                     "    @Test\n    public void test{}_branch{}_case{}() {{\n        var input = new {}Input();\n        var result = {}.evaluate(input);\n        assertNotNull(result);\n    }}\n\n",
                     class_name, to_pascal(&branch.id), to_pascal(case_name), class_name, class_name
@@ -8,8 +8,10 @@
 mod csharp;
 mod go;
 mod java;
+mod kotlin;
 mod python;
 mod rust;
+mod swift;
 mod typescript;
 
 use crate::cel::Target;
@@ -64,6 +66,11 @@ fn generate_integration_tests(
         Target::CSharp => csharp::generate_integration_tests(orch, specs),
         Target::Java => java::generate_integration_tests(orch, specs),
         Target::Go => go::generate_integration_tests(orch, specs),
+        Target::Kotlin => kotlin::generate_integration_tests(orch, specs),
+        Target::Swift => swift::generate_integration_tests(orch, specs),
+        Target::Php => unsupported_php(orch),
+        Target::Ruby => unsupported_ruby(orch),
+        Target::Elixir => unsupported_elixir(orch),
     }
 }
 
@@ -79,9 +86,38 @@ fn generate_contract_tests(
         Target::CSharp => csharp::generate_contract_tests(orch, specs),
         Target::Java => java::generate_contract_tests(orch, specs),
         Target::Go => go::generate_contract_tests(orch, specs),
+        Target::Kotlin => kotlin::generate_contract_tests(orch, specs),
+        Target::Swift => swift::generate_contract_tests(orch, specs),
+        Target::Php => unsupported_php(orch),
+        Target::Ruby => unsupported_ruby(orch),
+        Target::Elixir => unsupported_elixir(orch),
     }
 }
 
+/// PHP has no orchestrator template yet, so there is no flow to test
+fn unsupported_php(orch: &Orchestrator) -> String {
+    format!(
+        "<?php\n\n// Orchestrator tests for {} are not generated for PHP\n",
+        orch.id
+    )
+}
+
+/// Ruby has no orchestrator template yet, so there is no flow to test
+fn unsupported_ruby(orch: &Orchestrator) -> String {
+    format!(
+        "# Orchestrator tests for {} are not generated for Ruby\n",
+        orch.id
+    )
+}
+
+/// Elixir has no orchestrator template yet, so there is no flow to test
+fn unsupported_elixir(orch: &Orchestrator) -> String {
+    format!(
+        "# Orchestrator tests for {} are not generated for Elixir\n",
+        orch.id
+    )
+}
+
 // ============================================================================
 // Common utilities
 // ============================================================================
@@ -105,7 +141,9 @@ pub(crate) fn find_connections(steps: &[ChainStep]) -> Vec<(String, String, Stri
     for step in steps {
         if let ChainStep::Call(call) = step {
             if let Some(ref prev) = previous_spec {
-                for expr in call.inputs.values() {
+                let mut inputs: Vec<_> = call.inputs.iter().collect();
+                inputs.sort();
+                for (_, expr) in inputs {
                     if expr.starts_with(prev) || expr.contains(&format!("{}.", prev)) {
                         connections.push((prev.clone(), call.spec.clone(), expr.clone()));
                     }
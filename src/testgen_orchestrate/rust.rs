@@ -26,7 +26,7 @@ pub fn generate_integration_tests(orch: &Orchestrator, _specs: &HashMap<String,
     // Branch tests
     for step in &orch.chain {
         if let ChainStep::Branch(branch) = step {
-            for case_name in branch.cases.keys() {
+            for (case_name, _) in branch.sorted_cases() {
                 out.push_str(&format!(
                     "    #[test]\n    fn test_{}_branch_{}_case_{}() {{\n        let input = {}Input {{\n{}\n        }};\n        let result = {}(input);\n        assert!(result.is_ok());\n    }}\n\n",
                     orch.id, branch.id, case_name, to_pascal(&orch.id),
@@ -0,0 +1,417 @@
+//! Static type checking of rule conditions
+//!
+//! Walks each rule's CEL condition, resolves identifiers against the spec's
+//! declared inputs and flags operations whose operand types can never work
+//! together (e.g. `cart_total > "x"` with `cart_total: int`). Sub-expressions
+//! whose type cannot be inferred are skipped rather than reported.
+
+use crate::cel::{bitwise_symbol, CelCompiler, CelExpr};
+use crate::spec::{Spec, VarType};
+use cel_parser::ast::{operators, CallExpr, Expr};
+use cel_parser::reference::Val;
+use std::collections::HashMap;
+
+/// A type error found in a rule condition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeError {
+    /// Rule whose condition is ill-typed
+    pub rule_id: String,
+    /// The full CEL condition of the rule
+    pub expression: String,
+    /// Identifiers involved in the offending operation
+    pub variables: Vec<String>,
+    /// What is wrong, e.g. "cannot apply `>` to int and string"
+    pub message: String,
+}
+
+impl Spec {
+    /// Type-check every rule condition against the declared inputs
+    ///
+    /// Reports comparisons and operations between incompatible types and
+    /// references to identifiers that are not declared inputs. Conditions
+    /// that fail to parse are left to the parser's own diagnostics.
+    pub fn typecheck(&self) -> Vec<TypeError> {
+        let inputs: HashMap<&str, &VarType> = self
+            .inputs
+            .iter()
+            .map(|v| (v.name.as_str(), &v.typ))
+            .collect();
+
+        let mut errors = Vec::new();
+        for rule in &self.rules {
+            let Some(when) = rule.as_cel() else { continue };
+            let Ok(ast) = CelCompiler::parse(&when) else {
+                continue;
+            };
+            let mut checker = Checker {
+                inputs: &inputs,
+                allow_bitwise: self.allow_bitwise,
+                locals: Vec::new(),
+                found: Vec::new(),
+            };
+            checker.infer(&ast);
+            errors.extend(
+                checker
+                    .found
+                    .into_iter()
+                    .map(|(variables, message)| TypeError {
+                        rule_id: rule.id.clone(),
+                        expression: when.clone(),
+                        variables,
+                        message,
+                    }),
+            );
+        }
+        errors
+    }
+}
+
+struct Checker<'a> {
+    inputs: &'a HashMap<&'a str, &'a VarType>,
+    /// Whether the spec opted into the bitwise operator extension
+    allow_bitwise: bool,
+    /// Variables bound by comprehension macros, innermost last
+    locals: Vec<(String, Option<VarType>)>,
+    found: Vec<(Vec<String>, String)>,
+}
+
+impl Checker<'_> {
+    /// Infer the type of an expression, recording any errors on the way;
+    /// `None` means the type is unknown
+    fn infer(&mut self, expr: &CelExpr) -> Option<VarType> {
+        match &expr.expr {
+            Expr::Ident(name) => self.resolve(expr, name.as_str()),
+            Expr::Literal(val) => match val {
+                Val::Int(_) | Val::UInt(_) => Some(VarType::Int),
+                Val::Double(_) => Some(VarType::Float),
+                Val::String(_) => Some(VarType::String),
+                Val::Boolean(_) => Some(VarType::Bool),
+                _ => None,
+            },
+            Expr::Select(select) => {
+                let operand = self.infer(&select.operand);
+                if select.test {
+                    return Some(VarType::Bool);
+                }
+                match operand {
+                    Some(VarType::Object(fields)) => fields
+                        .into_iter()
+                        .find(|(name, _)| name.as_str() == select.field.as_str())
+                        .map(|(_, typ)| typ),
+                    _ => None,
+                }
+            }
+            Expr::List(list) => {
+                let types: Vec<_> = list.elements.iter().map(|e| self.infer(e)).collect();
+                let element = types.into_iter().flatten().next()?;
+                Some(VarType::List(Box::new(element)))
+            }
+            Expr::Comprehension(comp) => {
+                let element = match self.infer(&comp.iter_range) {
+                    Some(VarType::List(inner)) => Some(*inner),
+                    _ => None,
+                };
+                self.locals.push((comp.iter_var.to_string(), element));
+                self.locals.push((comp.accu_var.to_string(), None));
+                let step = self.infer(&comp.loop_step);
+                self.locals.truncate(self.locals.len() - 2);
+                // all/exists fold into a bool; map/filter into a list
+                match step {
+                    Some(VarType::Bool) => Some(VarType::Bool),
+                    _ => None,
+                }
+            }
+            Expr::Call(call) => self.infer_call(expr, call),
+            _ => None,
+        }
+    }
+
+    fn resolve(&mut self, expr: &CelExpr, name: &str) -> Option<VarType> {
+        if let Some((_, typ)) = self.locals.iter().rev().find(|(n, _)| n == name) {
+            return typ.clone();
+        }
+        match self.inputs.get(name) {
+            Some(typ) => Some((*typ).clone()),
+            None => {
+                self.report(expr, format!("undeclared identifier `{}`", name));
+                None
+            }
+        }
+    }
+
+    fn infer_call(&mut self, expr: &CelExpr, call: &CallExpr) -> Option<VarType> {
+        let target = call.target.as_ref().map(|t| self.infer(t));
+        let args: Vec<Option<VarType>> = call.args.iter().map(|a| self.infer(a)).collect();
+        let op = call.func_name.as_str();
+
+        match (op, args.as_slice()) {
+            (operators::LOGICAL_AND | operators::LOGICAL_OR | operators::LOGICAL_NOT, _) => {
+                for typ in args.iter().flatten() {
+                    if *typ != VarType::Bool {
+                        self.report(
+                            expr,
+                            format!("`{}` expects bool, found {}", symbol(op), type_name(typ)),
+                        );
+                    }
+                }
+                Some(VarType::Bool)
+            }
+            (operators::EQUALS | operators::NOT_EQUALS, [Some(l), Some(r)]) => {
+                if !equatable(l, r) {
+                    self.report_operands(expr, op, l, r);
+                }
+                Some(VarType::Bool)
+            }
+            (
+                operators::LESS
+                | operators::LESS_EQUALS
+                | operators::GREATER
+                | operators::GREATER_EQUALS,
+                [Some(l), Some(r)],
+            ) => {
+                let orderable =
+                    (is_numeric(l) && is_numeric(r)) || (is_string_like(l) && is_string_like(r));
+                if !orderable {
+                    self.report_operands(expr, op, l, r);
+                }
+                Some(VarType::Bool)
+            }
+            (
+                operators::EQUALS
+                | operators::NOT_EQUALS
+                | operators::LESS
+                | operators::LESS_EQUALS
+                | operators::GREATER
+                | operators::GREATER_EQUALS,
+                _,
+            ) => Some(VarType::Bool),
+            (operators::IN, [l, Some(r)]) => {
+                match (l, r) {
+                    (Some(l), VarType::List(element)) if !equatable(l, element) => {
+                        self.report_operands(expr, op, l, r)
+                    }
+                    (_, VarType::List(_) | VarType::Object(_)) => {}
+                    (_, r) => {
+                        self.report(expr, format!("`in` expects a list, found {}", type_name(r)))
+                    }
+                }
+                Some(VarType::Bool)
+            }
+            (operators::IN, _) => Some(VarType::Bool),
+            (
+                operators::ADD
+                | operators::SUBSTRACT
+                | operators::MULTIPLY
+                | operators::DIVIDE
+                | operators::MODULO,
+                [Some(l), Some(r)],
+            ) => match (l, r) {
+                (VarType::Int, VarType::Int) => Some(VarType::Int),
+                (l, r) if is_numeric(l) && is_numeric(r) && op != operators::MODULO => {
+                    Some(VarType::Float)
+                }
+                (VarType::String, VarType::String) if op == operators::ADD => Some(VarType::String),
+                (VarType::List(_), VarType::List(_)) if op == operators::ADD => Some(l.clone()),
+                _ => {
+                    self.report_operands(expr, op, l, r);
+                    None
+                }
+            },
+            (operators::NEGATE, [Some(typ)]) => {
+                if !is_numeric(typ) {
+                    self.report(
+                        expr,
+                        format!("`-` expects a number, found {}", type_name(typ)),
+                    );
+                }
+                Some(typ.clone())
+            }
+            (operators::CONDITIONAL, [cond, then, otherwise]) => {
+                if let Some(cond) = cond.as_ref().filter(|c| **c != VarType::Bool) {
+                    self.report(
+                        expr,
+                        format!("`?:` expects a bool condition, found {}", type_name(cond)),
+                    );
+                }
+                match (then, otherwise) {
+                    (Some(t), Some(o)) if t == o => Some(t.clone()),
+                    _ => None,
+                }
+            }
+            ("startsWith" | "endsWith" | "contains" | "matches", _) => {
+                if let Some(Some(typ)) = &target {
+                    if !is_string_like(typ) {
+                        self.report(
+                            expr,
+                            format!("`{}` expects a string, found {}", op, type_name(typ)),
+                        );
+                    }
+                }
+                Some(VarType::Bool)
+            }
+            (op, _) if bitwise_symbol(op).is_some() => {
+                let symbol = bitwise_symbol(op).unwrap_or(op);
+                if !self.allow_bitwise {
+                    self.report(
+                        expr,
+                        format!(
+                            "`{}` is a non-standard CEL extension; set `allow_bitwise: true`",
+                            symbol
+                        ),
+                    );
+                }
+                for typ in args.iter().flatten() {
+                    if *typ != VarType::Int {
+                        self.report(
+                            expr,
+                            format!("`{}` expects int, found {}", symbol, type_name(typ)),
+                        );
+                    }
+                }
+                Some(VarType::Int)
+            }
+            ("size" | "int", _) => Some(VarType::Int),
+            ("double", _) => Some(VarType::Float),
+            ("string", _) => Some(VarType::String),
+            _ => None,
+        }
+    }
+
+    fn report_operands(&mut self, expr: &CelExpr, op: &str, l: &VarType, r: &VarType) {
+        let message = format!(
+            "cannot apply `{}` to {} and {}",
+            symbol(op),
+            type_name(l),
+            type_name(r)
+        );
+        self.report(expr, message);
+    }
+
+    fn report(&mut self, expr: &CelExpr, message: String) {
+        let mut variables = Vec::new();
+        collect_idents(expr, &mut variables);
+        variables.dedup();
+        self.found.push((variables, message));
+    }
+}
+
+fn collect_idents(expr: &CelExpr, out: &mut Vec<String>) {
+    match &expr.expr {
+        Expr::Ident(name) => out.push(name.to_string()),
+        Expr::Select(select) => collect_idents(&select.operand, out),
+        Expr::Call(call) => {
+            if let Some(target) = &call.target {
+                collect_idents(target, out);
+            }
+            for arg in &call.args {
+                collect_idents(arg, out);
+            }
+        }
+        Expr::List(list) => {
+            for item in &list.elements {
+                collect_idents(item, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Operator symbol as written in CEL (`_>_` -> `>`)
+fn symbol(op: &str) -> &str {
+    op.trim_matches('_')
+}
+
+fn is_numeric(typ: &VarType) -> bool {
+    matches!(typ, VarType::Int | VarType::Float)
+}
+
+fn is_string_like(typ: &VarType) -> bool {
+    matches!(typ, VarType::String | VarType::Enum(_))
+}
+
+/// Whether values of the two types can be compared with `==`
+fn equatable(l: &VarType, r: &VarType) -> bool {
+    match (l, r) {
+        (VarType::List(l), VarType::List(r)) => equatable(l, r),
+        (VarType::Object(_), VarType::Object(_)) => true,
+        _ => (is_numeric(l) && is_numeric(r)) || (is_string_like(l) && is_string_like(r)) || l == r,
+    }
+}
+
+fn type_name(typ: &VarType) -> String {
+    match typ {
+        VarType::Bool => "bool".to_string(),
+        VarType::Int => "int".to_string(),
+        VarType::Float => "float".to_string(),
+        VarType::String => "string".to_string(),
+        VarType::Enum(_) => "enum".to_string(),
+        VarType::List(inner) => format!("list<{}>", type_name(inner)),
+        VarType::Object(_) => "object".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(when: &str) -> Spec {
+        let yaml = format!(
+            r#"
+id: shipping
+inputs:
+  - name: cart_total
+    type: int
+  - name: country
+    type: string
+outputs:
+  - name: free_shipping
+    type: bool
+rules:
+  - id: R1
+    when: '{}'
+    then: true
+default: false
+"#,
+            when
+        );
+        Spec::from_yaml(&yaml).unwrap()
+    }
+
+    #[test]
+    fn test_int_vs_string_comparison() {
+        let errors = spec(r#"cart_total > "x""#).typecheck();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].rule_id, "R1");
+        assert_eq!(errors[0].variables, vec!["cart_total"]);
+        assert_eq!(errors[0].message, "cannot apply `>` to int and string");
+    }
+
+    #[test]
+    fn test_int_vs_int_is_clean() {
+        assert!(spec("cart_total > 100").typecheck().is_empty());
+        assert!(spec(r#"cart_total + 5 >= 100 && country == "US""#)
+            .typecheck()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_undeclared_identifier() {
+        let errors = spec("cart_totl > 100").typecheck();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "undeclared identifier `cart_totl`");
+    }
+
+    #[test]
+    fn test_bitwise_requires_opt_in() {
+        let errors = spec("cart_total & 4 != 0").typecheck();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].message,
+            "`&` is a non-standard CEL extension; set `allow_bitwise: true`"
+        );
+
+        let mut allowed = spec("cart_total & 4 != 0");
+        allowed.allow_bitwise = true;
+        assert!(allowed.typecheck().is_empty());
+    }
+}
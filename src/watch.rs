@@ -0,0 +1,212 @@
+//! Watch mode for the IMACS CLI
+//!
+//! Watches every imacs folder of the project and regenerates specs as they
+//! are saved. Editors often write a file several times per save, so changes
+//! are debounced before anything is rendered.
+
+use crate::regenerate_folder;
+use imacs::render::RenderConfig;
+use imacs::{orchestrate::Orchestrator, Error, ImacFolder, ProjectStructure, Result, Spec};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+/// Quiet period after the last change to a file before it is regenerated
+pub const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Collects changed spec files until they have been quiet for a window
+pub struct Debouncer {
+    window: Duration,
+    pending: HashMap<PathBuf, Instant>,
+}
+
+impl Debouncer {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Note the spec files touched by `event` at time `at`
+    pub fn record(&mut self, event: &Event, at: Instant) {
+        if matches!(event.kind, EventKind::Access(_)) {
+            return;
+        }
+        for path in event.paths.iter().filter(|p| is_spec_file(p)) {
+            self.pending.insert(path.clone(), at);
+        }
+    }
+
+    /// Remove and return files that have not changed for a full window
+    pub fn take_ready(&mut self, now: Instant) -> Vec<PathBuf> {
+        let mut ready: Vec<PathBuf> = self
+            .pending
+            .iter()
+            .filter(|(_, at)| now.duration_since(**at) >= self.window)
+            .map(|(path, _)| path.clone())
+            .collect();
+        ready.sort();
+        for path in &ready {
+            self.pending.remove(path);
+        }
+        ready
+    }
+}
+
+/// YAML files other than `config.yaml` and `.imacs_root`
+fn is_spec_file(path: &Path) -> bool {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    name != "config.yaml"
+        && name != ".imacs_root"
+        && matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("yaml" | "yml")
+        )
+}
+
+/// Watch the project until the watcher shuts down
+pub fn run(structure: &ProjectStructure) -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)
+        .map_err(|e| Error::Other(format!("Could not start watcher: {}", e)))?;
+    for folder in structure.root.iter().chain(&structure.folders) {
+        watcher
+            .watch(&folder.path, RecursiveMode::NonRecursive)
+            .map_err(|e| {
+                Error::Other(format!("Could not watch {}: {}", folder.path.display(), e))
+            })?;
+        println!("👀 Watching {}", folder.path.display());
+    }
+
+    let mut debouncer = Debouncer::new(DEBOUNCE);
+    loop {
+        match rx.recv_timeout(DEBOUNCE) {
+            Ok(Ok(event)) => debouncer.record(&event, Instant::now()),
+            Ok(Err(e)) => eprintln!("Warning: watch error: {}", e),
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+        let ready = debouncer.take_ready(Instant::now());
+        if !ready.is_empty() {
+            handle_changes(structure, &ready);
+        }
+    }
+}
+
+/// Regenerate the folders holding the changed files
+///
+/// Files that no longer parse are reported and skipped; nothing here
+/// returns an error, so one bad save never stops the watcher.
+pub fn handle_changes(structure: &ProjectStructure, paths: &[PathBuf]) {
+    let mut folders: BTreeMap<&Path, &ImacFolder> = BTreeMap::new();
+    for path in paths {
+        let Some(folder) = folder_for(structure, path) else {
+            continue;
+        };
+        if !path.exists() {
+            println!("- {}: removed", path.display());
+            continue;
+        }
+        match check_parses(path) {
+            Ok(()) => {
+                println!("↻ {}", path.display());
+                folders.insert(&folder.path, folder);
+            }
+            Err(e) => eprintln!("✗ {}: {}", path.display(), e),
+        }
+    }
+
+    for folder in folders.values() {
+        if let Err(e) = regenerate_folder(folder, false, false, 0, &RenderConfig::default()) {
+            eprintln!("✗ {}: {}", folder.path.display(), e);
+        }
+    }
+}
+
+/// Innermost imacs folder containing `path`
+fn folder_for<'a>(structure: &'a ProjectStructure, path: &Path) -> Option<&'a ImacFolder> {
+    structure
+        .root
+        .iter()
+        .chain(&structure.folders)
+        .filter(|f| path.starts_with(&f.path))
+        .max_by_key(|f| f.path.components().count())
+}
+
+fn check_parses(path: &Path) -> Result<()> {
+    let content = fs::read_to_string(path).map_err(Error::Io)?;
+    // Orchestrators have a 'chain:' or 'uses:' key
+    if content.contains("\nchain:") || content.contains("\nuses:") {
+        Orchestrator::from_yaml(&content)?;
+    } else {
+        Spec::from_yaml_with_base(&content, path)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::ModifyKind;
+    use tempfile::TempDir;
+
+    fn threshold_spec(n: i64) -> String {
+        format!(
+            "id: threshold\ninputs:\n  - name: n\n    type: int\noutputs:\n  - name: r\n    type: int\nrules:\n  - id: R1\n    when: n > {n}\n    then: 1\ndefault: 0\n"
+        )
+    }
+
+    #[test]
+    fn test_debounced_change_regenerates_spec() {
+        let temp = TempDir::new().unwrap();
+        let imacs_dir = temp.path().join("imacs");
+        fs::create_dir_all(&imacs_dir).unwrap();
+        fs::write(
+            imacs_dir.join(".imacs_root"),
+            "version: 1\nproject:\n  name: test\ndefaults:\n  targets: [rust]\n",
+        )
+        .unwrap();
+        fs::write(imacs_dir.join("threshold.yaml"), threshold_spec(10)).unwrap();
+
+        let structure = imacs::load_project_structure(temp.path()).unwrap();
+        let spec_path = structure.root.as_ref().unwrap().path.join("threshold.yaml");
+        let code_path = temp.path().join("generated").join("threshold.rs");
+
+        let mut debouncer = Debouncer::new(DEBOUNCE);
+        let start = Instant::now();
+        let event = Event::new(EventKind::Modify(ModifyKind::Any)).add_path(spec_path.clone());
+
+        debouncer.record(&event, start);
+        assert!(debouncer.take_ready(start).is_empty());
+        let ready = debouncer.take_ready(start + DEBOUNCE);
+        assert_eq!(ready, [spec_path.clone()]);
+        handle_changes(&structure, &ready);
+        assert!(fs::read_to_string(&code_path).unwrap().contains("> 10"));
+
+        fs::write(&spec_path, threshold_spec(42)).unwrap();
+        debouncer.record(&event, start);
+        handle_changes(&structure, &debouncer.take_ready(start + DEBOUNCE));
+        assert!(fs::read_to_string(&code_path).unwrap().contains("> 42"));
+
+        // A broken save is reported and leaves the last good output alone
+        fs::write(&spec_path, "id: [").unwrap();
+        debouncer.record(&event, start);
+        handle_changes(&structure, &debouncer.take_ready(start + DEBOUNCE));
+        assert!(fs::read_to_string(&code_path).unwrap().contains("> 42"));
+    }
+
+    #[test]
+    fn test_config_files_are_ignored() {
+        let mut debouncer = Debouncer::new(DEBOUNCE);
+        let start = Instant::now();
+        let event = Event::new(EventKind::Modify(ModifyKind::Any))
+            .add_path(PathBuf::from("imacs/config.yaml"))
+            .add_path(PathBuf::from("imacs/.imacs_root"));
+        debouncer.record(&event, start);
+        assert!(debouncer.take_ready(start + DEBOUNCE).is_empty());
+    }
+}
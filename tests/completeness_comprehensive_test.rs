@@ -250,6 +250,11 @@ fn make_2_bool_spec(complete: bool) -> Spec {
 
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "test_2_bool".into(),
         name: None,
         description: None,
@@ -259,12 +264,16 @@ fn make_2_bool_spec(complete: bool) -> Spec {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             },
             Variable {
                 name: "b".into(),
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             },
         ],
         outputs: vec![Variable {
@@ -272,6 +281,8 @@ fn make_2_bool_spec(complete: bool) -> Spec {
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules,
         default: None,
@@ -282,6 +293,11 @@ fn make_2_bool_spec(complete: bool) -> Spec {
 fn make_3_bool_spec() -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "test_3_bool".into(),
         name: None,
         description: None,
@@ -291,18 +307,24 @@ fn make_3_bool_spec() -> Spec {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             },
             Variable {
                 name: "b".into(),
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             },
             Variable {
                 name: "c".into(),
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             },
         ],
         outputs: vec![Variable {
@@ -310,6 +332,8 @@ fn make_3_bool_spec() -> Spec {
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: (0..8)
             .map(|i| {
@@ -352,6 +376,11 @@ fn make_3_bool_spec() -> Spec {
 fn make_overlapping_spec() -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "overlap_test".into(),
         name: None,
         description: None,
@@ -360,12 +389,16 @@ fn make_overlapping_spec() -> Spec {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![
             Rule {
@@ -394,6 +427,11 @@ fn make_minimizable_spec() -> Spec {
     // (a && b) || (a && !b) → a
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "minimize_test".into(),
         name: None,
         description: None,
@@ -403,12 +441,16 @@ fn make_minimizable_spec() -> Spec {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             },
             Variable {
                 name: "b".into(),
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             },
         ],
         outputs: vec![Variable {
@@ -416,6 +458,8 @@ fn make_minimizable_spec() -> Spec {
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![
             Rule {
@@ -443,6 +487,11 @@ fn make_minimizable_spec() -> Spec {
 fn make_empty_spec() -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "empty".into(),
         name: None,
         description: None,
@@ -451,12 +500,16 @@ fn make_empty_spec() -> Spec {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![],
         default: None,
@@ -467,6 +520,11 @@ fn make_empty_spec() -> Spec {
 fn make_comparison_spec() -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "comparison_test".into(),
         name: None,
         description: None,
@@ -475,12 +533,16 @@ fn make_comparison_spec() -> Spec {
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![
             Rule {
@@ -508,6 +570,11 @@ fn make_comparison_spec() -> Spec {
 fn make_equality_spec() -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "equality_test".into(),
         name: None,
         description: None,
@@ -516,12 +583,16 @@ fn make_equality_spec() -> Spec {
             typ: VarType::String,
             description: None,
             values: Some(vec!["active".into(), "inactive".into()]),
+            optional: false,
+            range: None,
         }],
         outputs: vec![Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![
             Rule {
@@ -549,6 +620,11 @@ fn make_equality_spec() -> Spec {
 fn make_membership_spec() -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "membership_test".into(),
         name: None,
         description: None,
@@ -557,12 +633,16 @@ fn make_membership_spec() -> Spec {
             typ: VarType::String,
             description: None,
             values: Some(vec!["US".into(), "EU".into(), "APAC".into()]),
+            optional: false,
+            range: None,
         }],
         outputs: vec![Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![
             Rule {
@@ -590,6 +670,11 @@ fn make_membership_spec() -> Spec {
 fn make_spec_with_var(name: &str, values: Vec<String>) -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: format!("spec_{}", name),
         name: None,
         description: None,
@@ -598,12 +683,16 @@ fn make_spec_with_var(name: &str, values: Vec<String>) -> Spec {
             typ: VarType::String,
             description: None,
             values: Some(values),
+            optional: false,
+            range: None,
         }],
         outputs: vec![Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![],
         default: None,
@@ -614,6 +703,11 @@ fn make_spec_with_var(name: &str, values: Vec<String>) -> Spec {
 fn make_spec_with_input(name: &str, typ: VarType) -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: format!("spec_{}", name),
         name: None,
         description: None,
@@ -622,6 +716,8 @@ fn make_spec_with_input(name: &str, typ: VarType) -> Spec {
             typ,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![],
         rules: vec![],
@@ -633,6 +729,11 @@ fn make_spec_with_input(name: &str, typ: VarType) -> Spec {
 fn make_spec_with_output(name: &str, typ: VarType) -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: format!("spec_{}", name),
         name: None,
         description: None,
@@ -642,6 +743,8 @@ fn make_spec_with_output(name: &str, typ: VarType) -> Spec {
             typ,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![],
         default: None,
@@ -652,6 +755,11 @@ fn make_spec_with_output(name: &str, typ: VarType) -> Spec {
 fn make_spec_with_rule(when: &str) -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "spec_with_rule".into(),
         name: None,
         description: None,
@@ -660,12 +768,16 @@ fn make_spec_with_rule(when: &str) -> Spec {
             typ: VarType::String,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![Rule {
             id: "R1".into(),
@@ -683,6 +795,11 @@ fn make_spec_with_rule(when: &str) -> Spec {
 fn make_spec_with_vars(names: Vec<String>) -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "spec_with_vars".into(),
         name: None,
         description: None,
@@ -693,6 +810,8 @@ fn make_spec_with_vars(names: Vec<String>) -> Spec {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             })
             .collect(),
         outputs: vec![],
@@ -13,6 +13,11 @@ use imacs::spec::{ConditionValue, Output, Rule, Spec, VarType, Variable};
 fn test_analyze_empty_spec() {
     let spec = Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "empty".into(),
         name: None,
         description: None,
@@ -21,12 +26,16 @@ fn test_analyze_empty_spec() {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![],
         default: None,
@@ -45,6 +54,11 @@ fn test_analyze_empty_spec() {
 fn test_analyze_single_rule() {
     let spec = Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "single".into(),
         name: None,
         description: None,
@@ -53,12 +67,16 @@ fn test_analyze_single_rule() {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![Rule {
             id: "R1".into(),
@@ -82,6 +100,11 @@ fn test_analyze_single_rule() {
 fn test_analyze_no_predicates() {
     let spec = Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "no_preds".into(),
         name: None,
         description: None,
@@ -91,6 +114,8 @@ fn test_analyze_no_predicates() {
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![Rule {
             id: "R1".into(),
@@ -113,6 +138,11 @@ fn test_analyze_no_predicates() {
 fn test_analyze_invalid_cel() {
     let spec = Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "invalid".into(),
         name: None,
         description: None,
@@ -121,12 +151,16 @@ fn test_analyze_invalid_cel() {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![Rule {
             id: "R1".into(),
@@ -287,6 +321,11 @@ fn test_analyze_suite_empty() {
 fn test_analyze_suite_single() {
     let spec = Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "single".into(),
         name: None,
         description: None,
@@ -295,6 +334,8 @@ fn test_analyze_suite_single() {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![],
         rules: vec![],
@@ -311,6 +352,11 @@ fn test_analyze_suite_single() {
 fn test_analyze_suite_full_mode() {
     let spec = Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "test".into(),
         name: None,
         description: None,
@@ -319,6 +365,8 @@ fn test_analyze_suite_full_mode() {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![],
         rules: vec![],
@@ -348,6 +396,8 @@ fn test_detect_collisions_none() {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
         ),
         (
@@ -357,6 +407,8 @@ fn test_detect_collisions_none() {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
         ),
     ];
@@ -375,6 +427,8 @@ fn test_detect_collisions_ambiguous() {
                 typ: VarType::String,
                 description: None,
                 values: None, // No values = ambiguous
+                optional: false,
+                range: None,
             }],
         ),
         (
@@ -384,6 +438,8 @@ fn test_detect_collisions_ambiguous() {
                 typ: VarType::String,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
         ),
     ];
@@ -403,6 +459,11 @@ fn test_detect_collisions_ambiguous() {
 fn test_detect_duplicates_none() {
     let spec_a = Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "spec_a".into(),
         name: None,
         description: None,
@@ -411,6 +472,8 @@ fn test_detect_duplicates_none() {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![],
         rules: vec![Rule {
@@ -427,6 +490,11 @@ fn test_detect_duplicates_none() {
 
     let spec_b = Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "spec_b".into(),
         name: None,
         description: None,
@@ -435,6 +503,8 @@ fn test_detect_duplicates_none() {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![],
         rules: vec![Rule {
@@ -464,6 +534,11 @@ fn test_detect_duplicates_none() {
 fn test_detect_relationships_none() {
     let spec_a = Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "spec_a".into(),
         name: None,
         description: None,
@@ -472,6 +547,8 @@ fn test_detect_relationships_none() {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![],
         rules: vec![],
@@ -481,6 +558,11 @@ fn test_detect_relationships_none() {
 
     let spec_b = Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "spec_b".into(),
         name: None,
         description: None,
@@ -489,6 +571,8 @@ fn test_detect_relationships_none() {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![],
         rules: vec![],
@@ -517,6 +601,8 @@ fn test_match_variables_no_matches() {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
         ),
         (
@@ -526,6 +612,8 @@ fn test_match_variables_no_matches() {
                 typ: VarType::Bool,
                 description: None,
                 values: None,
+                optional: false,
+                range: None,
             }],
         ),
     ];
@@ -46,12 +46,16 @@ fn any_spec() -> impl Strategy<Value = Spec> {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }),
         Just(Variable {
             name: "b".into(),
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }),
     ];
 
@@ -87,6 +91,11 @@ fn any_spec() -> impl Strategy<Value = Spec> {
 
     (var_strategy, rule_strategy).prop_map(|(input, rules)| Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "test".into(),
         name: None,
         description: None,
@@ -96,6 +105,8 @@ fn any_spec() -> impl Strategy<Value = Spec> {
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules,
         default: None,
@@ -8,6 +8,11 @@ use imacs::spec::{ConditionValue, Output, Spec, VarType};
 fn make_test_spec() -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "test".into(),
         name: None,
         description: None,
@@ -16,12 +21,16 @@ fn make_test_spec() -> Spec {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![imacs::spec::Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![
             imacs::spec::Rule {
@@ -209,6 +218,11 @@ fn test_update_expression_fix() {
 fn test_rename_variable_fix() {
     let mut spec = Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "test".into(),
         name: None,
         description: None,
@@ -217,6 +231,8 @@ fn test_rename_variable_fix() {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![],
         rules: vec![imacs::spec::Rule {
@@ -265,3 +281,82 @@ fn test_fix_error_handling() {
     assert_eq!(result.errors.len(), 1);
     assert!(result.errors[0].contains("R999"));
 }
+
+#[test]
+fn test_add_default_fix_uses_modal_output() {
+    let yaml = r#"
+id: shipping
+inputs:
+  - name: express
+    type: bool
+  - name: heavy
+    type: bool
+outputs:
+  - name: cost
+    type: int
+rules:
+  - id: R1
+    when: "express && heavy"
+    then: 30
+  - id: R2
+    when: "express && !heavy"
+    then: 10
+  - id: R3
+    when: "!express && heavy"
+    then: 10
+"#;
+    let spec = Spec::from_yaml(yaml).unwrap();
+    let report = validate_spec(&spec, false);
+    let fix = report
+        .fixes
+        .iter()
+        .find(|f| matches!(f.operation, FixOperation::AddDefault { .. }))
+        .expect("incomplete spec without a default should get an AddDefault fix");
+    assert_eq!(fix.confidence, FixConfidence::Low);
+
+    // Heuristic, so plain --fix leaves it alone
+    let (unchanged, result) = apply_fixes_to_yaml(yaml, &report.fixes, false).unwrap();
+    assert_eq!(result.skipped.len(), 1);
+    assert!(Spec::from_yaml(&unchanged).unwrap().default.is_none());
+
+    // --fix --all adds the most common output
+    let (fixed, result) = apply_fixes_to_yaml(yaml, &report.fixes, true).unwrap();
+    assert_eq!(result.applied, vec![fix.issue_code.clone()]);
+    let fixed = Spec::from_yaml(&fixed).unwrap();
+    assert_eq!(fixed.default, Some(Output::Single(ConditionValue::Int(10))));
+    assert!(validate_spec(&fixed, false)
+        .fixes
+        .iter()
+        .all(|f| !matches!(f.operation, FixOperation::AddDefault { .. })));
+}
+
+#[test]
+fn test_remove_unused_input_fix() {
+    let yaml = r#"
+id: shipping
+inputs:
+  - name: express
+    type: bool
+  - name: region
+    type: string
+outputs:
+  - name: cost
+    type: int
+rules:
+  - id: R1
+    when: "express"
+    then: 10
+default: 5
+"#;
+    let report = validate_spec(&Spec::from_yaml(yaml).unwrap(), false);
+
+    // Low confidence: only applied with --all
+    let (_, result) = apply_fixes_to_yaml(yaml, &report.fixes, false).unwrap();
+    assert!(result.applied.is_empty());
+
+    let (fixed, result) = apply_fixes_to_yaml(yaml, &report.fixes, true).unwrap();
+    assert_eq!(result.applied, vec!["UNUSED_INPUT".to_string()]);
+    let fixed = Spec::from_yaml(&fixed).unwrap();
+    let names: Vec<_> = fixed.inputs.iter().map(|i| i.name.as_str()).collect();
+    assert_eq!(names, vec!["express"]);
+}
@@ -7,6 +7,11 @@ use imacs::spec::{ConditionValue, Output, Rule, Spec, VarType, Variable};
 fn smoke_test_basic_completeness() {
     let spec = Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "smoke".into(),
         name: None,
         description: None,
@@ -15,12 +20,16 @@ fn smoke_test_basic_completeness() {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![
             Rule {
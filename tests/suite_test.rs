@@ -99,3 +99,37 @@ fn test_suggestions_generated() {
         assert!(!result.suggestions.is_empty());
     }
 }
+
+#[test]
+fn test_suite_jsonl_one_line_per_record() {
+    let specs = vec![
+        ("pricing".into(), load_spec_fixture("pricing.yaml")),
+        ("discounts".into(), load_spec_fixture("discounts.yaml")),
+        ("billing".into(), load_spec_fixture("billing.yaml")),
+    ];
+
+    let result = analyze_suite(&specs, false);
+    let jsonl = result.to_jsonl().unwrap();
+    let lines: Vec<&str> = jsonl.lines().collect();
+
+    assert_eq!(
+        lines.len(),
+        result.individual_results.len()
+            + result.collisions.len()
+            + result.duplicates.len()
+            + result.suite_gaps.len()
+    );
+    let kinds: Vec<String> = lines
+        .iter()
+        .map(|line| {
+            let record: serde_json::Value = serde_json::from_str(line).unwrap();
+            record["kind"].as_str().unwrap().to_string()
+        })
+        .collect();
+    assert_eq!(kinds.iter().filter(|k| *k == "spec").count(), 3);
+    assert_eq!(
+        kinds.iter().filter(|k| *k == "collision").count(),
+        result.collisions.len()
+    );
+    assert!(!result.collisions.is_empty());
+}
@@ -1,11 +1,16 @@
 //! Tests for spec validation - impossible situation detection
 
-use imacs::completeness::{validate_spec, IssueType};
+use imacs::completeness::{validate_spec, FailOn, IssueType, Severity};
 use imacs::spec::{ConditionValue, Output, Rule, Spec, VarType, Variable};
 
 fn make_base_spec() -> Spec {
     Spec {
         scoping: None,
+        mode: Default::default(),
+        on_no_match: None,
+        ensures: Vec::new(),
+        allow_bitwise: false,
+        schema_version: None,
         id: "test".into(),
         name: None,
         description: None,
@@ -14,12 +19,16 @@ fn make_base_spec() -> Spec {
             typ: VarType::Bool,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         outputs: vec![Variable {
             name: "result".into(),
             typ: VarType::Int,
             description: None,
             values: None,
+            optional: false,
+            range: None,
         }],
         rules: vec![],
         default: None,
@@ -155,6 +164,106 @@ fn test_detect_tautology() {
         .any(|i| matches!(i.issue_type, IssueType::TautologyCondition)));
 }
 
+#[test]
+fn test_always_true_condition_shadows_later_rules() {
+    let yaml = r#"
+id: gate
+inputs:
+  - name: a
+    type: bool
+  - name: b
+    type: bool
+outputs:
+  - name: result
+    type: int
+rules:
+  - id: R1
+    when: "a || !a"
+    then: 1
+  - id: R2
+    when: "b"
+    then: 2
+  - id: R3
+    when: "!b"
+    then: 3
+    priority: -1
+"#;
+    let spec = Spec::from_yaml(yaml).unwrap();
+    let report = validate_spec(&spec, false);
+    let issue = report
+        .issues
+        .iter()
+        .find(|i| i.code == "ALWAYS_TRUE")
+        .expect("tautology reported");
+    assert_eq!(issue.severity, Severity::Warning);
+    assert_eq!(
+        issue.affected_rules,
+        vec!["R1".to_string(), "R2".to_string()]
+    );
+    // R3 is tried before R1, so only R2 is shadowed
+    assert!(issue.message.ends_with("(shadows R2)"));
+}
+
+#[test]
+fn test_always_false_condition_is_dead() {
+    let yaml = r#"
+id: gate
+inputs:
+  - name: x
+    type: int
+  - name: tier
+    type: !enum [free, pro]
+outputs:
+  - name: result
+    type: int
+rules:
+  - id: R1
+    when: "x > 5 && !(x > 5)"
+    then: 1
+  - id: R2
+    when: 'tier == "free" && tier == "pro"'
+    then: 2
+default: 0
+"#;
+    let spec = Spec::from_yaml(yaml).unwrap();
+    let report = validate_spec(&spec, false);
+    let dead: Vec<_> = report
+        .issues
+        .iter()
+        .filter(|i| i.code == "ALWAYS_FALSE")
+        .map(|i| i.affected_rules[0].as_str())
+        .collect();
+    assert_eq!(dead, vec!["R1", "R2"]);
+    assert!(!report.is_valid);
+}
+
+#[test]
+fn test_disjunction_is_not_constant() {
+    let mut spec = make_base_spec();
+    spec.inputs.push(Variable {
+        name: "b".into(),
+        typ: VarType::Bool,
+        description: None,
+        values: None,
+        optional: false,
+        range: None,
+    });
+    spec.rules = vec![Rule {
+        id: "R1".into(),
+        when: Some("a || b".into()),
+        conditions: None,
+        then: Output::Single(ConditionValue::Int(1)),
+        priority: 0,
+        description: None,
+    }];
+
+    let report = validate_spec(&spec, false);
+    assert!(!report
+        .issues
+        .iter()
+        .any(|i| i.code == "ALWAYS_TRUE" || i.code == "ALWAYS_FALSE"));
+}
+
 #[test]
 fn test_detect_type_mismatch() {
     let mut spec = make_base_spec();
@@ -163,6 +272,8 @@ fn test_detect_type_mismatch() {
         typ: VarType::Int,
         description: None,
         values: None,
+        optional: false,
+        range: None,
     }];
     spec.rules = vec![Rule {
         id: "R1".into(),
@@ -232,3 +343,93 @@ fn test_valid_spec() {
     assert!(report.is_valid);
     assert_eq!(report.error_count, 0);
 }
+
+#[test]
+fn test_detect_unused_input() {
+    let yaml = r#"
+id: discount
+inputs:
+  - name: member
+    type: bool
+  - name: total
+    type: int
+  - name: coupon
+    type: string
+outputs:
+  - name: percent
+    type: int
+rules:
+  - id: R1
+    when: "member && total > 100"
+    then: 10
+default: 0
+"#;
+    let spec = Spec::from_yaml(yaml).unwrap();
+    let report = validate_spec(&spec, false);
+    let unused: Vec<_> = report
+        .issues
+        .iter()
+        .filter(|i| matches!(i.issue_type, IssueType::UnusedInput))
+        .collect();
+    assert_eq!(unused.len(), 1);
+    assert_eq!(unused[0].code, "UNUSED_INPUT");
+    assert!(unused[0].message.contains("'coupon'"));
+    assert!(report.is_valid);
+}
+
+#[test]
+fn test_out_of_range_literal_warns() {
+    let yaml = r#"
+id: retry_delay
+inputs:
+  - name: attempts
+    type: int
+    range: [0, 3]
+outputs:
+  - name: delay
+    type: int
+rules:
+  - id: R1
+    when: "attempts <= 3"
+    then: 100
+  - id: R2
+    when: "attempts == 5"
+    then: 0
+default: 0
+"#;
+    let spec = Spec::from_yaml(yaml).unwrap();
+    let report = validate_spec(&spec, false);
+    let out_of_range: Vec<_> = report
+        .issues
+        .iter()
+        .filter(|i| matches!(i.issue_type, IssueType::OutOfRange))
+        .collect();
+    assert_eq!(out_of_range.len(), 1);
+    assert_eq!(out_of_range[0].code, "OUT_OF_RANGE");
+    assert_eq!(out_of_range[0].affected_rules, vec!["R2".to_string()]);
+    assert!(out_of_range[0].message.contains("[0, 3]"));
+}
+
+#[test]
+fn test_fail_on_threshold() {
+    let mut spec = make_base_spec();
+    spec.rules = vec![Rule {
+        id: "R1".into(),
+        when: Some("a || !a".into()), // Tautology (warning)
+        conditions: None,
+        then: Output::Single(ConditionValue::Int(1)),
+        priority: 0,
+        description: None,
+    }];
+
+    let report = validate_spec(&spec, false);
+    assert_eq!(report.max_severity(), Some(Severity::Warning));
+    assert!(!FailOn::Error.fails(report.max_severity()));
+    assert!(FailOn::Warning.fails(report.max_severity()));
+    assert!(!FailOn::Never.fails(report.max_severity()));
+
+    // Nothing to report passes any threshold
+    assert!(!FailOn::Warning.fails(None));
+    assert_eq!(FailOn::parse("error"), Some(FailOn::Error));
+    assert_eq!(FailOn::parse("sometimes"), None);
+}